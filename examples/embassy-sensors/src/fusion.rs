@@ -3,6 +3,7 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 use crate::aggregate::sensor_aggregate::SensorAggregate;
+use crate::sensors::SensorReceiver;
 use crate::types::{humidity::Humidity, pressure::Pressure, temperature::Temperature};
 use crate::{info, warn};
 use core::time::Duration;
@@ -20,9 +21,9 @@ use alloc::vec::Vec;
 
 #[embassy_executor::task]
 pub async fn fusion_task(
-    temp_rx: async_channel::Receiver<Temperature>,
-    pressure_rx: async_channel::Receiver<Pressure>,
-    humidity_rx: async_channel::Receiver<Humidity>,
+    temp_rx: SensorReceiver<Temperature>,
+    pressure_rx: SensorReceiver<Pressure>,
+    humidity_rx: SensorReceiver<Humidity>,
     _cancel: CancellationToken,
 ) {
     info!("Fusion task started - demonstrating operators and merge_with");
@@ -19,6 +19,7 @@
 //! # Features Demonstrated
 //!
 //! - Multi-task Embassy spawning
+//! - Static `embassy-sync` channels converted with `into_fluxion_stream`
 //! - All 5 time operators (debounce, throttle, sample, delay, timeout implied via cancel)
 //! - Stream transformations (map, filter, scan, distinct_until_changed, take)
 //! - Sensor fusion with combine_latest
@@ -49,9 +50,15 @@ use embassy_time::{Duration, Timer};
 use fluxion_core::CancellationToken;
 use fusion::fusion_task;
 use sensors::{humidity::humidity_sensor, pressure::pressure_sensor, temperature::temperature_sensor};
+use sensors::SensorChannel;
+use types::{humidity::Humidity, pressure::Pressure, temperature::Temperature};
 
 use panic_semihosting as _;
 
+static TEMPERATURE: SensorChannel<Temperature> = SensorChannel::new();
+static PRESSURE: SensorChannel<Pressure> = SensorChannel::new();
+static HUMIDITY: SensorChannel<Humidity> = SensorChannel::new();
+
 #[embassy_executor::main]
 async fn main(spawner: Spawner) {
     init_heap();
@@ -65,25 +72,21 @@ async fn main(spawner: Spawner) {
 
     let cancel = CancellationToken::new();
 
-    let (temp_tx, temp_rx) = async_channel::unbounded();
-    let (pressure_tx, pressure_rx) = async_channel::unbounded();
-    let (humidity_tx, humidity_rx) = async_channel::unbounded();
-
     spawner
-        .spawn(temperature_sensor(temp_tx, cancel.clone()))
+        .spawn(temperature_sensor(TEMPERATURE.sender(), cancel.clone()))
         .ok();
     spawner
-        .spawn(pressure_sensor(pressure_tx, cancel.clone()))
+        .spawn(pressure_sensor(PRESSURE.sender(), cancel.clone()))
         .ok();
     spawner
-        .spawn(humidity_sensor(humidity_tx, cancel.clone()))
+        .spawn(humidity_sensor(HUMIDITY.sender(), cancel.clone()))
         .ok();
 
     spawner
         .spawn(fusion_task(
-            temp_rx,
-            pressure_rx,
-            humidity_rx,
+            TEMPERATURE.receiver(),
+            PRESSURE.receiver(),
+            HUMIDITY.receiver(),
             cancel.clone(),
         ))
         .ok();
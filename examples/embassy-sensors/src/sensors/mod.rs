@@ -5,3 +5,14 @@
 pub mod humidity;
 pub mod pressure;
 pub mod temperature;
+
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::{Channel, Receiver, Sender};
+
+/// Readings buffered per sensor before a sensor task waits for the fusion task.
+const CAPACITY: usize = 8;
+
+/// Statically allocated queue from a sensor task to the fusion task.
+pub type SensorChannel<T> = Channel<CriticalSectionRawMutex, T, CAPACITY>;
+pub type SensorSender<T> = Sender<'static, CriticalSectionRawMutex, T, CAPACITY>;
+pub type SensorReceiver<T> = Receiver<'static, CriticalSectionRawMutex, T, CAPACITY>;
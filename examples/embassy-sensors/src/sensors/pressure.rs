@@ -2,6 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use super::SensorSender;
 use crate::info;
 use crate::types::pressure::Pressure;
 use embassy_time::Duration;
@@ -12,7 +13,7 @@ use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
 #[embassy_executor::task]
-pub async fn pressure_sensor(tx: async_channel::Sender<Pressure>, cancel: CancellationToken) {
+pub async fn pressure_sensor(tx: SensorSender<Pressure>, cancel: CancellationToken) {
     info!("Pressure sensor task started");
 
     let timer = EmbassyTimer;
@@ -29,10 +30,7 @@ pub async fn pressure_sensor(tx: async_channel::Sender<Pressure>, cancel: Cancel
         };
 
         info!("Sensor: {} hPa", pressure.value_hpa);
-        if tx.send(pressure).await.is_err() {
-            info!("Channel closed, stopping sensor");
-            break;
-        }
+        tx.send(pressure).await;
 
         let timeout = rng.random_range(100..=1000);
         embassy_time::Timer::after(Duration::from_millis(timeout)).await;
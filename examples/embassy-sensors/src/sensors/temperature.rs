@@ -2,6 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use super::SensorSender;
 use crate::info;
 use crate::types::temperature::Temperature;
 use embassy_time::Duration;
@@ -12,7 +13,7 @@ use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
 #[embassy_executor::task]
-pub async fn temperature_sensor(tx: async_channel::Sender<Temperature>, cancel: CancellationToken) {
+pub async fn temperature_sensor(tx: SensorSender<Temperature>, cancel: CancellationToken) {
     info!("Temperature sensor task started");
 
     let timer = EmbassyTimer;
@@ -29,10 +30,7 @@ pub async fn temperature_sensor(tx: async_channel::Sender<Temperature>, cancel:
         };
 
         info!("Sensor: {} C", temperature.value_kelvin);
-        if tx.send(temperature).await.is_err() {
-            info!("Channel closed, stopping sensor");
-            break;
-        }
+        tx.send(temperature).await;
 
         let timeout = rng.random_range(100..=1000);
         embassy_time::Timer::after(Duration::from_millis(timeout)).await;
@@ -2,7 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_core::SelfTimestamped;
 use fluxion_runtime::impls::embassy::EmbassyInstant;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -11,23 +11,15 @@ pub struct Humidity {
     pub timestamp: EmbassyInstant,
 }
 
-impl HasTimestamp for Humidity {
-    type Timestamp = EmbassyInstant;
+impl SelfTimestamped for Humidity {
+    type Time = EmbassyInstant;
 
-    fn timestamp(&self) -> Self::Timestamp {
+    fn time(&self) -> Self::Time {
         self.timestamp
     }
-}
-
-impl Timestamped for Humidity {
-    type Inner = Self;
-
-    fn with_timestamp(inner: Self::Inner, timestamp: Self::Timestamp) -> Self {
-        Self { timestamp, ..inner }
-    }
 
-    fn into_inner(self) -> Self::Inner {
-        self
+    fn set_time(&mut self, time: Self::Time) {
+        self.timestamp = time;
     }
 }
 
@@ -2,7 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use fluxion_rx::prelude::{HasTimestamp, Timestamped};
+use fluxion_rx::prelude::SelfTimestamped;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MetricData {
@@ -11,22 +11,14 @@ pub struct MetricData {
     pub value: u64,
 }
 
-impl HasTimestamp for MetricData {
-    type Timestamp = u64;
+impl SelfTimestamped for MetricData {
+    type Time = u64;
 
-    fn timestamp(&self) -> Self::Timestamp {
+    fn time(&self) -> Self::Time {
         self.timestamp
     }
-}
-
-impl Timestamped for MetricData {
-    type Inner = Self;
-
-    fn with_timestamp(inner: Self::Inner, timestamp: Self::Timestamp) -> Self {
-        Self { timestamp, ..inner }
-    }
 
-    fn into_inner(self) -> Self::Inner {
-        self
+    fn set_time(&mut self, time: Self::Time) {
+        self.timestamp = time;
     }
 }
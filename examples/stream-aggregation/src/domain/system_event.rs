@@ -4,7 +4,7 @@
 
 //! System event domain type
 
-use fluxion_rx::prelude::{HasTimestamp, Timestamped};
+use fluxion_rx::prelude::SelfTimestamped;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SystemEvent {
@@ -13,22 +13,14 @@ pub struct SystemEvent {
     pub severity: String,
 }
 
-impl HasTimestamp for SystemEvent {
-    type Timestamp = u64;
+impl SelfTimestamped for SystemEvent {
+    type Time = u64;
 
-    fn timestamp(&self) -> Self::Timestamp {
+    fn time(&self) -> Self::Time {
         self.timestamp
     }
-}
-
-impl Timestamped for SystemEvent {
-    type Inner = Self;
-
-    fn with_timestamp(inner: Self::Inner, timestamp: Self::Timestamp) -> Self {
-        Self { timestamp, ..inner }
-    }
 
-    fn into_inner(self) -> Self::Inner {
-        self
+    fn set_time(&mut self, time: Self::Time) {
+        self.timestamp = time;
     }
 }
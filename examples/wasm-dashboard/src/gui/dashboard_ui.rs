@@ -4,6 +4,7 @@
 
 use super::DashboardSink;
 use fluxion_core::CancellationToken;
+use fluxion_web::{clicks, Click, DomEventStream};
 use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
@@ -59,20 +60,12 @@ impl DashboardUI {
         Ok(ui_rc)
     }
 
-    pub fn wire_closure_to_start_button(&mut self, start_closure: Closure<dyn FnMut()>) {
-        self.start_button
-            .add_event_listener_with_callback("click", start_closure.as_ref().unchecked_ref())
-            .unwrap();
-
-        start_closure.forget();
+    pub fn start_clicks(&self) -> Result<DomEventStream<Click>, JsValue> {
+        clicks(&self.start_button)
     }
 
-    pub fn wire_closure_to_stop_button(&mut self, stop_closure: Closure<dyn FnMut()>) {
-        self.stop_button
-            .add_event_listener_with_callback("click", stop_closure.as_ref().unchecked_ref())
-            .unwrap();
-
-        stop_closure.forget();
+    pub fn stop_clicks(&self) -> Result<DomEventStream<Click>, JsValue> {
+        clicks(&self.stop_button)
     }
 
     fn wire_close_button_to_application_closure(&mut self, close_token: CancellationToken) {
@@ -8,15 +8,18 @@ mod source;
 
 use crate::source::{Sensors, SourceLayer};
 use crate::{
-    processing::{DashboardOrchestrator, ProcessingLayer},
+    processing::{DashboardOrchestrator, ProcessingLayer, SUM_TASK},
     source::SensorStreams,
 };
+use core::time::Duration;
 use fluxion_core::CancellationToken;
+use fluxion_web::ReplayStore;
+use futures::StreamExt;
 use gui::DashboardUI;
 use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
-use web_sys::{console, window};
+use web_sys::{console, window, UrlSearchParams};
 
 #[wasm_bindgen(start)]
 pub fn main() -> Result<(), JsValue> {
@@ -27,6 +30,16 @@ pub fn main() -> Result<(), JsValue> {
     Ok(())
 }
 
+/// Entry point of `www/worker.js`: runs the dashboard's heavy computations off the
+/// main thread.
+#[wasm_bindgen]
+pub fn worker_main() -> Result<(), JsValue> {
+    fluxion_web::register_task(SUM_TASK, |readings: Vec<u32>| {
+        readings.into_iter().sum::<u32>()
+    });
+    fluxion_web::serve()
+}
+
 #[wasm_bindgen]
 pub async fn start_dashboard() -> Result<(), JsValue> {
     let window = window().ok_or("No window")?;
@@ -39,24 +52,28 @@ pub async fn start_dashboard() -> Result<(), JsValue> {
 
     let stop_token = Rc::new(RefCell::new(Option::<CancellationToken>::None));
 
+    let mut start_clicks = ui.borrow().start_clicks()?;
     let ui_for_start = ui.clone();
-    let stop_token_for_stop = stop_token.clone();
-    ui.borrow_mut()
-        .wire_closure_to_start_button(Closure::wrap(Box::new(move || {
+    let stop_token_for_start = stop_token.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        while start_clicks.next().await.is_some() {
             let new_stop_token = CancellationToken::new();
-            *stop_token_for_stop.borrow_mut() = Some(new_stop_token.clone());
-            wasm_bindgen_futures::spawn_local(start(ui_for_start.clone(), new_stop_token.clone()));
-        })));
+            *stop_token_for_start.borrow_mut() = Some(new_stop_token.clone());
+            wasm_bindgen_futures::spawn_local(start(ui_for_start.clone(), new_stop_token));
+        }
+    });
 
+    let mut stop_clicks = ui.borrow().stop_clicks()?;
     let ui_for_stop = ui.clone();
     let stop_token_for_stop = stop_token.clone();
-    ui.borrow_mut()
-        .wire_closure_to_stop_button(Closure::wrap(Box::new(move || {
+    wasm_bindgen_futures::spawn_local(async move {
+        while stop_clicks.next().await.is_some() {
             if let Some(token) = stop_token_for_stop.borrow().as_ref() {
                 token.cancel();
                 ui_for_stop.borrow_mut().enable_start();
             }
-        })));
+        }
+    });
 
     console::log_1(&"✅ Dashboard running".into());
 
@@ -73,11 +90,20 @@ async fn start(ui: Rc<RefCell<DashboardUI>>, stop_token: CancellationToken) {
 
     console::log_1(&"✅ Started".into());
 
-    let sensors = Sensors::new(stop_token.clone());
+    let sensors = match feed_url() {
+        Some(url) => {
+            console::log_1(&format!("✅ Reading sensors from {url}").into());
+            Sensors::remote(&url, stop_token.clone())
+        }
+        None => Sensors::new(stop_token.clone()),
+    };
     let sensor_streams = SensorStreams::new(sensors);
     let source_layer = SourceLayer::new(sensor_streams);
 
-    let processing_layer = ProcessingLayer::new(&source_layer);
+    let history = ReplayStore::open("fluxion-dashboard-combined", 20, Duration::from_secs(600))
+        .await
+        .expect("IndexedDB should be available");
+    let processing_layer = ProcessingLayer::new(&source_layer, history);
 
     let orchestrator = DashboardOrchestrator::new(processing_layer, ui_clone, stop_token);
 
@@ -87,3 +113,9 @@ async fn start(ui: Rc<RefCell<DashboardUI>>, stop_token: CancellationToken) {
 
     console::log_1(&"✅ Dashboard shutdown complete".into());
 }
+
+/// The backend given as `?feed=<url>` in the page address, if any.
+fn feed_url() -> Option<String> {
+    let search = window()?.location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()?.get("feed")
+}
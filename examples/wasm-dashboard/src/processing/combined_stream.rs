@@ -7,13 +7,19 @@ use fluxion_core::{HasTimestamp, Timestamped};
 use fluxion_stream::share::SharedBoxStream;
 use fluxion_stream::{CombineLatestExt, FluxionShared, MapOrderedExt, ShareExt};
 use fluxion_stream_time::WasmTimestamped;
+use fluxion_web::{MapInWorkerExt, PersistExt, ReplayStore, WorkerPool};
+
+/// Name of the worker task that adds up the latest sensor readings.
+pub const SUM_TASK: &str = "sum";
 
 pub struct CombinedStream {
     combined: FluxionShared<WasmTimestamped<u32>>,
 }
 
 impl CombinedStream {
-    pub fn new(sensors: &SensorStreams) -> Self {
+    pub fn new(sensors: &SensorStreams, history: &ReplayStore) -> Self {
+        let pool = WorkerPool::new("./worker.js", 2).expect("Worker pool should start");
+
         let combined = sensors
             .sensor1()
             .subscribe()
@@ -30,10 +36,11 @@ impl CombinedStream {
                 },
             )
             .map_ordered(|state| {
-                let values = state.values();
-                let sum: u32 = values.iter().map(|v| v.value).sum();
-                WasmTimestamped::with_timestamp(sum, state.timestamp())
+                let readings: Vec<u32> = state.values().iter().map(|v| v.value).collect();
+                WasmTimestamped::with_timestamp(readings, state.timestamp())
             })
+            .map_in_worker::<WasmTimestamped<u32>>(&pool, SUM_TASK)
+            .persist(history)
             .share();
 
         Self { combined }
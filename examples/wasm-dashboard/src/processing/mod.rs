@@ -8,7 +8,7 @@ mod processing_layer;
 mod result_streams;
 mod stream_provider;
 
-pub use combined_stream::CombinedStream;
+pub use combined_stream::{CombinedStream, SUM_TASK};
 pub use dashboard_orchestrator::DashboardOrchestrator;
 pub use processing_layer::ProcessingLayer;
 pub use result_streams::{ResultStreams, WasmStream};
@@ -4,21 +4,27 @@
 
 use super::{CombinedStream, ResultStreams, StreamProvider, WasmStream};
 use crate::source::{SensorValue, SourceLayer};
+use fluxion_core::Timestamped;
+use fluxion_runtime::impls::wasm::WasmTimer;
+use fluxion_runtime::timer::Timer;
 use fluxion_stream::share::SharedBoxStream;
 use fluxion_stream_time::WasmTimestamped;
+use fluxion_web::ReplayStore;
 
 pub struct ProcessingLayer<'a> {
     source: &'a SourceLayer,
     combined_stream: CombinedStream,
+    history: ReplayStore,
 }
 
 impl<'a> ProcessingLayer<'a> {
-    pub fn new(source: &'a SourceLayer) -> Self {
-        let combined_stream = CombinedStream::new(source.sensor_streams());
+    pub fn new(source: &'a SourceLayer, history: ReplayStore) -> Self {
+        let combined_stream = CombinedStream::new(source.sensor_streams(), &history);
 
         Self {
             source,
             combined_stream,
+            history,
         }
     }
 }
@@ -49,7 +55,13 @@ impl<'a> StreamProvider for ProcessingLayer<'a> {
     }
 
     fn combined_stream(&self) -> SharedBoxStream<WasmTimestamped<u32>> {
-        self.combined_stream.subscribe()
+        // Sums from earlier runs, including before a page reload, come first.
+        Box::pin(
+            self.history
+                .replay(self.combined_stream.subscribe(), |sum| {
+                    WasmTimestamped::with_timestamp(sum, WasmTimer.now())
+                }),
+        )
     }
 
     fn debounce_stream(&self) -> WasmStream<u32> {
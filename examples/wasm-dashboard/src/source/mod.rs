@@ -6,9 +6,11 @@
 //!
 //! This module provides the foundational data sources for the dashboard.
 //! Three independent sensors generate random values at random frequencies
-//! (1-5 Hz) without timestamps.
+//! (1-5 Hz) without timestamps. Alternatively, a remote feed reads the three
+//! sensors' values from a WebSocket or a streaming HTTP backend.
 
 mod raw_streams;
+mod remote_feed;
 mod sensor;
 mod sensor_streams;
 mod sensor_value;
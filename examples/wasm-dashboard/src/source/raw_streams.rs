@@ -2,6 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use super::remote_feed::RemoteFeed;
 use super::sensor::Sensor;
 use fluxion_core::CancellationToken;
 
@@ -9,6 +10,7 @@ pub struct Sensors {
     pub sensor1: Sensor,
     pub sensor2: Sensor,
     pub sensor3: Sensor,
+    _feed: Option<RemoteFeed>,
 }
 
 impl Sensors {
@@ -17,6 +19,22 @@ impl Sensors {
             sensor1: Sensor::new((200, 1000), (1, 9), cancel_token.clone()),
             sensor2: Sensor::new((200, 1000), (10, 90), cancel_token.clone()),
             sensor3: Sensor::new((200, 1000), (100, 900), cancel_token),
+            _feed: None,
+        }
+    }
+
+    /// Sensors fed by the backend at `url` instead of simulated ones.
+    pub fn remote(url: &str, cancel_token: CancellationToken) -> Self {
+        let (sender1, receiver1) = async_channel::unbounded();
+        let (sender2, receiver2) = async_channel::unbounded();
+        let (sender3, receiver3) = async_channel::unbounded();
+        let feed = RemoteFeed::connect(url, [sender1, sender2, sender3], cancel_token);
+
+        Self {
+            sensor1: Sensor::from_receiver(receiver1),
+            sensor2: Sensor::from_receiver(receiver2),
+            sensor3: Sensor::from_receiver(receiver3),
+            _feed: Some(feed),
         }
     }
 }
@@ -0,0 +1,91 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{CancellationToken, FluxionTask, StreamItem};
+use fluxion_web::{fetch_lines, websocket, WebSocketMessage};
+use futures::stream::LocalBoxStream;
+use futures::StreamExt;
+use web_sys::console;
+
+/// Sensor readings received from a backend.
+///
+/// `ws://` and `wss://` URLs are opened as a WebSocket with one reading per text
+/// message; any other URL is fetched and its body read with one reading per line.
+/// A reading is the sensor number (1-3) and the value, separated by whitespace,
+/// for example `2 57`. Malformed readings are skipped.
+pub struct RemoteFeed {
+    _task: FluxionTask,
+}
+
+impl RemoteFeed {
+    pub fn connect(
+        url: &str,
+        senders: [async_channel::Sender<u32>; 3],
+        cancel_token: CancellationToken,
+    ) -> Self {
+        let url = url.to_string();
+
+        let task = FluxionTask::spawn(move |task_cancel| async move {
+            let mut readings = readings(&url).take_until(Box::pin(async move {
+                futures::future::select(
+                    Box::pin(cancel_token.cancelled()),
+                    Box::pin(task_cancel.cancelled()),
+                )
+                .await;
+            }));
+
+            while let Some(item) = readings.next().await {
+                match item {
+                    StreamItem::Value(text) => {
+                        let Some((sensor, value)) = parse(&text) else {
+                            continue;
+                        };
+                        if senders[sensor].send(value).await.is_err() {
+                            break;
+                        }
+                    }
+                    StreamItem::Error(error) => {
+                        console::warn_1(&format!("Remote feed {url}: {error}").into());
+                    }
+                }
+            }
+        });
+
+        Self { _task: task }
+    }
+}
+
+fn readings(url: &str) -> LocalBoxStream<'static, StreamItem<String>> {
+    if url.starts_with("ws://") || url.starts_with("wss://") {
+        match websocket(url) {
+            Ok((_outgoing, incoming)) => incoming
+                .filter_map(|item| async move {
+                    match item {
+                        StreamItem::Value(message) => match message.value {
+                            WebSocketMessage::Text(text) => Some(StreamItem::Value(text)),
+                            WebSocketMessage::Binary(_) => None,
+                        },
+                        StreamItem::Error(error) => Some(StreamItem::Error(error)),
+                    }
+                })
+                .boxed_local(),
+            Err(error) => {
+                console::warn_2(&"Remote feed: invalid WebSocket URL".into(), &error);
+                futures::stream::empty().boxed_local()
+            }
+        }
+    } else {
+        fetch_lines(url)
+            .map(|item| item.map(|line| line.value))
+            .boxed_local()
+    }
+}
+
+/// Parses `"<sensor> <value>"` into a sensor index (0-2) and a value.
+fn parse(reading: &str) -> Option<(usize, u32)> {
+    let mut parts = reading.split_whitespace();
+    let sensor = parts.next()?.parse::<usize>().ok()?.checked_sub(1)?;
+    let value = parts.next()?.parse().ok()?;
+    (sensor < 3).then_some((sensor, value))
+}
@@ -8,7 +8,7 @@ use js_sys::Math;
 
 pub struct Sensor {
     receiver: async_channel::Receiver<u32>,
-    _task: FluxionTask,
+    _task: Option<FluxionTask>,
 }
 
 impl Sensor {
@@ -45,7 +45,15 @@ impl Sensor {
 
         Self {
             receiver,
-            _task: task,
+            _task: Some(task),
+        }
+    }
+
+    /// A sensor whose readings come from elsewhere, such as a remote feed.
+    pub fn from_receiver(receiver: async_channel::Receiver<u32>) -> Self {
+        Self {
+            receiver,
+            _task: None,
         }
     }
 
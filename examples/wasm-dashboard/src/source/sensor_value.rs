@@ -2,7 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_core::SelfTimestamped;
 use fluxion_runtime::impls::wasm::WasmInstant;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -11,25 +11,14 @@ pub struct SensorValue {
     pub value: u32,
 }
 
-impl HasTimestamp for SensorValue {
-    type Timestamp = WasmInstant;
+impl SelfTimestamped for SensorValue {
+    type Time = WasmInstant;
 
-    fn timestamp(&self) -> Self::Timestamp {
+    fn time(&self) -> Self::Time {
         self.timestamp
     }
-}
-
-impl Timestamped for SensorValue {
-    type Inner = Self;
-
-    fn with_timestamp(value: Self, timestamp: WasmInstant) -> Self {
-        Self {
-            timestamp,
-            value: value.value,
-        }
-    }
 
-    fn into_inner(self) -> Self {
-        self
+    fn set_time(&mut self, time: Self::Time) {
+        self.timestamp = time;
     }
 }
@@ -0,0 +1,10 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Web Worker for fluxion_web::WorkerPool: loads the dashboard's wasm module and
+// answers the tasks registered in worker_main().
+import init, { worker_main } from './wasm-dashboard.js';
+
+await init();
+worker_main();
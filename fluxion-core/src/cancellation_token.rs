@@ -2,12 +2,15 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use alloc::sync::Arc;
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
 use core::future::Future;
 use core::pin::Pin;
-use core::sync::atomic::{AtomicBool, Ordering};
 use core::task::{Context, Poll};
+#[cfg(not(fluxion_loom))]
 use event_listener::{Event, EventListener};
+#[cfg(fluxion_loom)]
+use {crate::sync::Mutex, alloc::vec::Vec, core::task::Waker};
 
 /// Runtime-agnostic cancellation token.
 ///
@@ -41,7 +44,11 @@ pub struct CancellationToken {
 #[derive(Debug)]
 struct Inner {
     cancelled: AtomicBool,
+    #[cfg(not(fluxion_loom))]
     event: Event,
+    // event-listener blocks on a std mutex that loom cannot schedule around.
+    #[cfg(fluxion_loom)]
+    wakers: Mutex<Vec<Waker>>,
 }
 
 impl CancellationToken {
@@ -49,14 +56,22 @@ impl CancellationToken {
         Self {
             inner: Arc::new(Inner {
                 cancelled: AtomicBool::new(false),
+                #[cfg(not(fluxion_loom))]
                 event: Event::new(),
+                #[cfg(fluxion_loom)]
+                wakers: Mutex::new(Vec::new()),
             }),
         }
     }
 
     pub fn cancel(&self) {
         self.inner.cancelled.store(true, Ordering::Release);
+        #[cfg(not(fluxion_loom))]
         self.inner.event.notify(usize::MAX);
+        #[cfg(fluxion_loom)]
+        for waker in core::mem::take(&mut *self.inner.wakers.lock()) {
+            waker.wake();
+        }
     }
 
     pub fn is_cancelled(&self) -> bool {
@@ -66,6 +81,7 @@ impl CancellationToken {
     pub fn cancelled(&self) -> Cancelled<'_> {
         Cancelled {
             token: self,
+            #[cfg(not(fluxion_loom))]
             listener: None,
         }
     }
@@ -79,9 +95,11 @@ impl Default for CancellationToken {
 
 pub struct Cancelled<'a> {
     token: &'a CancellationToken,
+    #[cfg(not(fluxion_loom))]
     listener: Option<EventListener>,
 }
 
+#[cfg(not(fluxion_loom))]
 impl<'a> Future for Cancelled<'a> {
     type Output = ();
 
@@ -104,3 +122,22 @@ impl<'a> Future for Cancelled<'a> {
         }
     }
 }
+
+#[cfg(fluxion_loom)]
+impl<'a> Future for Cancelled<'a> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        self.token.inner.wakers.lock().push(cx.waker().clone());
+
+        if self.token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -21,15 +21,89 @@
 //!     Ok("processed".to_string())
 //! }
 //! ```
+//!
+//! # Domain Errors
+//!
+//! Errors of your own types can be wrapped with [`FluxionError::wrap`], which keeps
+//! them as the [`source`](core::error::Error::source) of the [`FluxionError`]. Context
+//! added later with [`ResultExt::context`] keeps the source, so handlers can recover the
+//! original error with [`FluxionError::downcast_ref`]:
+//!
+//! ```
+//! use fluxion_core::{FluxionError, Result, ResultExt};
+//! use std::fmt;
+//!
+//! #[derive(Debug)]
+//! struct OutOfStock(u32);
+//!
+//! impl fmt::Display for OutOfStock {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "item {} is out of stock", self.0)
+//!     }
+//! }
+//!
+//! impl std::error::Error for OutOfStock {}
+//!
+//! fn reserve(item: u32) -> Result<()> {
+//!     Err(FluxionError::wrap(OutOfStock(item))).context("reserving order")
+//! }
+//!
+//! let error = reserve(7).unwrap_err();
+//! assert_eq!(error.to_string(), "reserving order");
+//! assert_eq!(error.root_cause().to_string(), "item 7 is out of stock");
+//! assert_eq!(error.downcast_ref::<OutOfStock>().map(|e| e.0), Some(7));
+//! ```
+//!
+//! # Conversions
+//!
+//! The errors of `futures-channel` channels, of `async-channel` channels (feature
+//! `alloc`) and of tokio's channels (feature `runtime-tokio`) convert into stream
+//! processing errors, or unavailable errors for full, empty and lagging channels, and
+//! tokio's `JoinError` into a wrapped error of kind
+//! [`ErrorKind::StreamProcessing`], so `?` works on them in functions returning [`Result`].
 
+use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
+use alloc::sync::Arc;
+use core::error::Error;
 use core::fmt::{self, Display, Formatter};
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum FluxionError {
-    StreamProcessingError { context: String },
-    TimeoutError { context: String },
+    StreamProcessingError {
+        context: String,
+    },
+    TimeoutError {
+        context: String,
+    },
+    UserError {
+        context: String,
+    },
+    /// A resource could not be used right now, such as a full, empty or lagging channel
+    /// on a non-blocking operation. Trying again later may succeed.
+    Unavailable {
+        context: String,
+    },
+    /// A fixed-capacity buffer was too small. Built without allocating, so bounded
+    /// (heapless) operators can report it on targets without a heap to spare.
+    ResourceLimitExceeded {
+        resource: &'static str,
+        limit: usize,
+    },
+    /// An error of another type, kept as the [`source`](Error::source) of this one so it
+    /// can be recovered with [`downcast_ref`](FluxionError::downcast_ref).
+    ///
+    /// Displays only its context, so reporters walking the source chain print the wrapped
+    /// error once. Without a context, it displays the message of its source.
+    Wrapped {
+        /// Category reported by [`kind`](FluxionError::kind).
+        kind: ErrorKind,
+        /// Context added with [`ResultExt::context`], empty if none was added.
+        context: String,
+        source: Arc<dyn Error + Send + Sync>,
+    },
 }
 
 impl Display for FluxionError {
@@ -39,12 +113,54 @@ impl Display for FluxionError {
                 write!(f, "Stream processing error: {}", context)
             }
             Self::TimeoutError { context } => write!(f, "Timeout error: {}", context),
+            Self::UserError { context } => write!(f, "User error: {}", context),
+            Self::Unavailable { context } => write!(f, "Unavailable: {}", context),
+            Self::ResourceLimitExceeded { resource, limit } => {
+                write!(f, "Resource limit exceeded: {} (limit {})", resource, limit)
+            }
+            Self::Wrapped {
+                context, source, ..
+            } if context.is_empty() => Display::fmt(source, f),
+            Self::Wrapped { context, .. } => f.write_str(context),
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for FluxionError {}
+impl Error for FluxionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Wrapped { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Same messages as [`Display`], with the fixed parts interned by defmt.
+#[cfg(feature = "defmt")]
+impl defmt::Format for FluxionError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::StreamProcessingError { context } => {
+                defmt::write!(f, "Stream processing error: {=str}", context)
+            }
+            Self::TimeoutError { context } => defmt::write!(f, "Timeout error: {=str}", context),
+            Self::UserError { context } => defmt::write!(f, "User error: {=str}", context),
+            Self::Unavailable { context } => defmt::write!(f, "Unavailable: {=str}", context),
+            Self::ResourceLimitExceeded { resource, limit } => defmt::write!(
+                f,
+                "Resource limit exceeded: {=str} (limit {=usize})",
+                resource,
+                limit
+            ),
+            Self::Wrapped {
+                context, source, ..
+            } if context.is_empty() => {
+                defmt::write!(f, "{}", defmt::Display2Format(source.as_ref()))
+            }
+            Self::Wrapped { context, .. } => defmt::write!(f, "{=str}", context),
+        }
+    }
+}
 
 impl FluxionError {
     pub fn stream_error(context: impl Into<String>) -> Self {
@@ -59,6 +175,76 @@ impl FluxionError {
         }
     }
 
+    /// Creates an error raised by user code, such as a fallible closure passed to an operator.
+    pub fn user_error(context: impl Into<String>) -> Self {
+        Self::UserError {
+            context: context.into(),
+        }
+    }
+
+    /// Creates an error for a resource that cannot be used right now but may be later.
+    pub fn unavailable(context: impl Into<String>) -> Self {
+        Self::Unavailable {
+            context: context.into(),
+        }
+    }
+
+    /// Creates an error for a `resource` whose fixed capacity of `limit` was exceeded.
+    pub const fn resource_limit_exceeded(resource: &'static str, limit: usize) -> Self {
+        Self::ResourceLimitExceeded { resource, limit }
+    }
+
+    /// Wraps an error of another type, such as a domain error raised by user code,
+    /// keeping it as the source of the returned error. The error is of kind
+    /// [`ErrorKind::User`]; use [`wrap_with_kind`](Self::wrap_with_kind) for another one.
+    pub fn wrap(source: impl Error + Send + Sync + 'static) -> Self {
+        Self::wrap_with_kind(ErrorKind::User, source)
+    }
+
+    /// Wraps an error of another type as an error of the given `kind`, keeping it as the
+    /// source of the returned error.
+    pub fn wrap_with_kind(kind: ErrorKind, source: impl Error + Send + Sync + 'static) -> Self {
+        Self::Wrapped {
+            kind,
+            context: String::new(),
+            source: Arc::new(source),
+        }
+    }
+
+    /// Wraps a boxed error, such as the `tower::BoxError` returned by tower middleware, as
+    /// an error of kind [`ErrorKind::User`]. The boxed error becomes the source of the
+    /// returned error, so [`downcast_ref`](Self::downcast_ref) still finds its concrete type.
+    pub fn wrap_boxed(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self::Wrapped {
+            kind: ErrorKind::User,
+            context: String::new(),
+            source: Arc::from(source.into()),
+        }
+    }
+
+    /// Returns the first error of type `E` in the source chain, starting with this error.
+    #[must_use]
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        let mut current: Option<&(dyn Error + 'static)> = Some(self);
+        while let Some(error) = current {
+            if let Some(found) = error.downcast_ref::<E>() {
+                return Some(found);
+            }
+            current = error.source();
+        }
+        None
+    }
+
+    /// Returns the last error of the source chain, or this error if it has no source.
+    #[must_use]
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        let mut cause: &(dyn Error + 'static) = self;
+        while let Some(source) = cause.source() {
+            cause = source;
+        }
+        cause
+    }
+
     #[must_use]
     pub const fn is_recoverable(&self) -> bool {
         false
@@ -66,10 +252,103 @@ impl FluxionError {
 
     #[must_use]
     pub const fn is_permanent(&self) -> bool {
-        matches!(self, Self::StreamProcessingError { .. })
+        matches!(self.kind(), ErrorKind::StreamProcessing)
+    }
+
+    /// Returns the category of this error, for policies that must not depend on the message.
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::StreamProcessingError { .. } => ErrorKind::StreamProcessing,
+            Self::TimeoutError { .. } => ErrorKind::Timeout,
+            Self::UserError { .. } => ErrorKind::User,
+            Self::Unavailable { .. } => ErrorKind::Unavailable,
+            Self::ResourceLimitExceeded { .. } => ErrorKind::ResourceLimit,
+            Self::Wrapped { kind, .. } => *kind,
+        }
+    }
+
+    /// Returns the severity of this error's [`kind`](Self::kind).
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.kind().severity()
+    }
+
+    /// Returns the stable code of this error's [`kind`](Self::kind).
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        self.kind().code()
+    }
+}
+
+/// Category of a [`FluxionError`].
+///
+/// Each variant has its own kind, except [`FluxionError::Wrapped`], which carries the
+/// kind it was [wrapped with](FluxionError::wrap_with_kind).
+///
+/// Downstream systems can key their policies (retry, drop, page someone) on the kind,
+/// its [`severity`](Self::severity) or its [`code`](Self::code) instead of matching on
+/// error messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// [`FluxionError::StreamProcessingError`]
+    StreamProcessing,
+    /// [`FluxionError::TimeoutError`]
+    Timeout,
+    /// [`FluxionError::UserError`], and errors wrapped with [`FluxionError::wrap`]
+    User,
+    /// [`FluxionError::ResourceLimitExceeded`]
+    ResourceLimit,
+    /// [`FluxionError::Unavailable`]
+    Unavailable,
+}
+
+impl ErrorKind {
+    /// Returns the stable code of this kind, such as `"FLX001"`.
+    ///
+    /// Codes are never changed or reused, so they are safe to store, alert on and
+    /// compare across versions. New kinds get the next free number.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::StreamProcessing => "FLX001",
+            Self::Timeout => "FLX002",
+            Self::User => "FLX003",
+            Self::ResourceLimit => "FLX004",
+            Self::Unavailable => "FLX005",
+        }
+    }
+
+    /// Returns how serious errors of this kind are.
+    ///
+    /// - Timeouts and unavailable resources are [`Severity::Warning`]: the operation may
+    ///   well succeed if retried
+    /// - Errors of user code and exceeded capacities are [`Severity::Error`]
+    /// - Stream processing errors are [`Severity::Critical`], as they are
+    ///   [permanent](FluxionError::is_permanent)
+    #[must_use]
+    pub const fn severity(self) -> Severity {
+        match self {
+            Self::Timeout | Self::Unavailable => Severity::Warning,
+            Self::User | Self::ResourceLimit => Severity::Error,
+            Self::StreamProcessing => Severity::Critical,
+        }
     }
 }
 
+/// How serious a [`FluxionError`] is, ordered from least to most serious.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Severity {
+    /// Expected to resolve itself, e.g. by retrying.
+    Warning,
+    /// An operation failed; the pipeline can carry on.
+    Error,
+    /// The pipeline cannot be expected to recover without intervention.
+    Critical,
+}
+
 pub type Result<T> = core::result::Result<T, FluxionError>;
 
 pub trait ResultExt<T> {
@@ -84,36 +363,55 @@ where
     E: Into<FluxionError>,
 {
     fn context(self, context: impl Into<String>) -> Result<T> {
-        self.map_err(|e| {
-            let context = context.into();
-            let error = e.into();
-            match error {
-                FluxionError::StreamProcessingError { context: inner } => {
-                    FluxionError::StreamProcessingError {
-                        context: format!("{context}: {inner}"),
-                    }
-                }
-                other => other,
-            }
-        })
+        self.map_err(|e| add_context(e.into(), context.into()))
     }
 
     fn with_context<F>(self, f: F) -> Result<T>
     where
         F: FnOnce() -> String,
     {
-        self.map_err(|e| {
-            let context = f();
-            let error = e.into();
-            match error {
-                FluxionError::StreamProcessingError { context: inner } => {
-                    FluxionError::StreamProcessingError {
-                        context: format!("{context}: {inner}"),
-                    }
-                }
-                other => other,
+        self.map_err(|e| add_context(e.into(), f()))
+    }
+}
+
+/// Prefixes the context of errors that carry one, keeping their variant and, for wrapped
+/// errors, their source. A [`FluxionError::ResourceLimitExceeded`] has no context of its
+/// own, so it is wrapped with the context, keeping its kind.
+fn add_context(error: FluxionError, context: String) -> FluxionError {
+    let prefix = |inner: String| format!("{context}: {inner}");
+    match error {
+        FluxionError::StreamProcessingError { context: inner } => {
+            FluxionError::StreamProcessingError {
+                context: prefix(inner),
             }
-        })
+        }
+        FluxionError::TimeoutError { context: inner } => FluxionError::TimeoutError {
+            context: prefix(inner),
+        },
+        FluxionError::UserError { context: inner } => FluxionError::UserError {
+            context: prefix(inner),
+        },
+        FluxionError::Unavailable { context: inner } => FluxionError::Unavailable {
+            context: prefix(inner),
+        },
+        FluxionError::Wrapped {
+            kind,
+            context: inner,
+            source,
+        } => FluxionError::Wrapped {
+            kind,
+            context: if inner.is_empty() {
+                context
+            } else {
+                prefix(inner)
+            },
+            source,
+        },
+        other @ FluxionError::ResourceLimitExceeded { .. } => FluxionError::Wrapped {
+            kind: other.kind(),
+            context,
+            source: Arc::new(other),
+        },
     }
 }
 
@@ -126,6 +424,25 @@ impl Clone for FluxionError {
             Self::TimeoutError { context } => Self::TimeoutError {
                 context: context.clone(),
             },
+            Self::UserError { context } => Self::UserError {
+                context: context.clone(),
+            },
+            Self::Unavailable { context } => Self::Unavailable {
+                context: context.clone(),
+            },
+            Self::ResourceLimitExceeded { resource, limit } => Self::ResourceLimitExceeded {
+                resource,
+                limit: *limit,
+            },
+            Self::Wrapped {
+                kind,
+                context,
+                source,
+            } => Self::Wrapped {
+                kind: *kind,
+                context: context.clone(),
+                source: Arc::clone(source),
+            },
         }
     }
 }
@@ -0,0 +1,117 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Conversions of channel and task errors into [`FluxionError`], so connector and
+//! handler code can propagate them with `?`.
+//!
+//! A closed or disconnected channel becomes a [`FluxionError::StreamProcessingError`]
+//! whose context names the channel and the failed operation, e.g.
+//! `"tokio mpsc send: channel closed"`. Conditions that go away on their own, such as a
+//! full or empty channel on a `try_` operation or a lagging broadcast receiver, become a
+//! [`FluxionError::Unavailable`] instead, which is not
+//! [permanent](FluxionError::is_permanent). A value carried back by a failed send is
+//! dropped. A tokio [`JoinError`] is
+//! [wrapped](FluxionError::wrap), so `downcast_ref::<JoinError>()` can tell a panicked
+//! task from a cancelled one.
+//!
+//! Conversions are available for the channels of the enabled dependencies:
+//! `futures-channel` (`mpsc` with `std`), `async-channel` with `alloc`, and tokio's
+//! `mpsc`, `oneshot`, `broadcast` and `watch` channels with `runtime-tokio`.
+//!
+//! [`JoinError`]: https://docs.rs/tokio/latest/tokio/task/struct.JoinError.html
+
+use crate::FluxionError;
+use alloc::format;
+use core::fmt::Display;
+
+fn channel_error(operation: &str, error: impl Display) -> FluxionError {
+    FluxionError::stream_error(format!("{operation}: {error}"))
+}
+
+fn transient_channel_error(operation: &str, error: impl Display) -> FluxionError {
+    FluxionError::unavailable(format!("{operation}: {error}"))
+}
+
+/// Implements `From<$error>` for [`FluxionError`] as a [`channel_error`] of `$operation`,
+/// or as a [`transient_channel_error`] for the errors accepted by the `transient if` check.
+macro_rules! impl_from_channel_error {
+    ($operation:literal => $($error:ty $([$($generics:tt)*])? $(=> transient if $transient:expr)?),+ $(,)?) => {
+        $(
+            impl$(<$($generics)*>)? From<$error> for FluxionError {
+                fn from(error: $error) -> Self {
+                    $(
+                        let is_transient: fn(&$error) -> bool = $transient;
+                        if is_transient(&error) {
+                            return transient_channel_error($operation, error);
+                        }
+                    )?
+                    channel_error($operation, error)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+impl_from_channel_error!("futures mpsc send" =>
+    futures_channel::mpsc::SendError => transient if |error| error.is_full(),
+    futures_channel::mpsc::TrySendError<T> [T] => transient if |error| error.is_full(),
+);
+impl_from_channel_error!("futures oneshot receive" => futures_channel::oneshot::Canceled);
+
+#[cfg(feature = "alloc")]
+impl_from_channel_error!("async-channel send" =>
+    async_channel::SendError<T> [T],
+    async_channel::TrySendError<T> [T] => transient if |error| error.is_full(),
+);
+#[cfg(feature = "alloc")]
+impl_from_channel_error!("async-channel receive" =>
+    async_channel::RecvError,
+    async_channel::TryRecvError => transient if |error| error.is_empty(),
+);
+
+#[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+mod tokio_errors {
+    use super::{channel_error, transient_channel_error};
+    use crate::{ErrorKind, FluxionError};
+    use tokio::sync::{broadcast, mpsc, oneshot, watch};
+
+    impl_from_channel_error!("tokio mpsc send" =>
+        mpsc::error::SendError<T> [T],
+        mpsc::error::TrySendError<T> [T] => transient if |error| {
+            matches!(error, mpsc::error::TrySendError::Full(_))
+        },
+    );
+    impl_from_channel_error!("tokio mpsc receive" =>
+        mpsc::error::TryRecvError => transient if |error| {
+            matches!(error, mpsc::error::TryRecvError::Empty)
+        },
+    );
+    impl_from_channel_error!("tokio oneshot receive" =>
+        oneshot::error::RecvError,
+        oneshot::error::TryRecvError => transient if |error| {
+            matches!(error, oneshot::error::TryRecvError::Empty)
+        },
+    );
+    impl_from_channel_error!("tokio broadcast send" => broadcast::error::SendError<T> [T]);
+    impl_from_channel_error!("tokio broadcast receive" =>
+        broadcast::error::RecvError => transient if |error| {
+            matches!(error, broadcast::error::RecvError::Lagged(_))
+        },
+        broadcast::error::TryRecvError => transient if |error| {
+            matches!(
+                error,
+                broadcast::error::TryRecvError::Empty | broadcast::error::TryRecvError::Lagged(_)
+            )
+        },
+    );
+    impl_from_channel_error!("tokio watch send" => watch::error::SendError<T> [T]);
+    impl_from_channel_error!("tokio watch receive" => watch::error::RecvError);
+
+    impl From<tokio::task::JoinError> for FluxionError {
+        fn from(error: tokio::task::JoinError) -> Self {
+            FluxionError::wrap_with_kind(ErrorKind::StreamProcessing, error)
+        }
+    }
+}
@@ -2,8 +2,47 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", not(fluxion_loom)))]
 pub use parking_lot::Mutex;
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), not(fluxion_loom)))]
 pub use spin::Mutex;
+
+#[cfg(fluxion_loom)]
+pub use loom_mutex::Mutex;
+
+#[cfg(fluxion_loom)]
+mod loom_mutex {
+    use core::fmt;
+
+    /// A loom-instrumented mutex with the poison-free `lock` API of `parking_lot`.
+    pub struct Mutex<T> {
+        inner: loom::sync::Mutex<T>,
+    }
+
+    impl<T> Mutex<T> {
+        pub fn new(value: T) -> Self {
+            Self {
+                inner: loom::sync::Mutex::new(value),
+            }
+        }
+
+        pub fn lock(&self) -> loom::sync::MutexGuard<'_, T> {
+            self.inner
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+    }
+
+    impl<T: Default> Default for Mutex<T> {
+        fn default() -> Self {
+            Self::new(T::default())
+        }
+    }
+
+    impl<T> fmt::Debug for Mutex<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Mutex").finish_non_exhaustive()
+        }
+    }
+}
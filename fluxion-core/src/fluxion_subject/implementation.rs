@@ -4,7 +4,22 @@
 
 use crate::StreamItem;
 use alloc::vec::Vec;
-use async_channel::Sender;
+
+#[cfg(not(fluxion_loom))]
+pub(crate) use async_channel::{unbounded, Sender};
+
+// async-channel wakes receivers through event-listener, which blocks on a std mutex that
+// loom cannot schedule around; loom models use the lock-free futures channel instead.
+#[cfg(fluxion_loom)]
+pub(crate) use futures_channel::mpsc::{unbounded, UnboundedSender as Sender};
+
+pub(crate) fn try_deliver<T>(sender: &Sender<T>, item: T) -> bool {
+    #[cfg(not(fluxion_loom))]
+    let delivered = sender.try_send(item).is_ok();
+    #[cfg(fluxion_loom)]
+    let delivered = sender.unbounded_send(item).is_ok();
+    delivered
+}
 
 pub(crate) struct SubjectState<T> {
     pub(crate) closed: bool,
@@ -16,11 +31,11 @@ macro_rules! define_subject_impl {
         use crate::fluxion_mutex::Mutex;
         use crate::{FluxionError, StreamItem, SubjectError};
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
+        use crate::sync::Arc;
         use alloc::vec::Vec;
         use core::pin::Pin;
         use futures::stream::Stream;
-        use crate::fluxion_subject::implementation::SubjectState;
+        use crate::fluxion_subject::implementation::{try_deliver, unbounded, SubjectState};
 
         type SubjectBoxStream<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)* 'static>>;
 
@@ -45,7 +60,7 @@ macro_rules! define_subject_impl {
                     return Err(SubjectError::Closed);
                 }
 
-                let (tx, rx) = async_channel::unbounded();
+                let (tx, rx) = unbounded();
                 state.senders.push(tx);
                 Ok(Box::pin(rx))
             }
@@ -59,7 +74,7 @@ macro_rules! define_subject_impl {
                 let mut next_senders = Vec::with_capacity(state.senders.len());
 
                 for tx in state.senders.drain(..) {
-                    if tx.try_send(item.clone()).is_ok() {
+                    if try_deliver(&tx, item.clone()) {
                         next_senders.push(tx);
                     }
                 }
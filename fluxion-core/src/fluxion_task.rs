@@ -5,10 +5,29 @@
 //! Runtime-agnostic task spawning with cooperative cancellation.
 //!
 //! This module provides a unified abstraction for spawning background tasks
-//! that works across all async runtimes (Tokio, smol, async-std, WASM).
+//! that works across all async runtimes (Tokio, smol, async-std, WASM, Embassy),
+//! built on the [`Spawner`] implementations in `fluxion-runtime`.
 
 use crate::CancellationToken;
 use core::future::Future;
+use fluxion_runtime::spawner::{LocalSpawner, Spawner};
+
+#[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+use fluxion_runtime::impls::tokio::TokioSpawner;
+
+#[cfg(target_arch = "wasm32")]
+use fluxion_runtime::impls::wasm::WasmSpawner;
+
+#[cfg(all(
+    not(fluxion_loom),
+    not(target_arch = "wasm32"),
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    )
+))]
+use fluxion_runtime::default_runtime::DefaultSpawner;
 
 /// Runtime-agnostic task handle with automatic cancellation on drop.
 ///
@@ -24,12 +43,17 @@ use core::future::Future;
 ///
 /// - **Tokio**: `tokio::spawn` (default)
 /// - **smol**: `smol::spawn`
-/// - **async-std**: `async_core::task::spawn`
+/// - **async-std**: `async_std::task::spawn`
 /// - **WASM**: `wasm_bindgen_futures::spawn_local`
+/// - **Embassy**: no default; pass an `EmbassySpawner` to [`spawn_with`](Self::spawn_with)
 ///
 /// Select runtime via feature flags: `runtime-tokio`, `runtime-smol`,
 /// `runtime-async-std`, or automatic WASM detection.
 ///
+/// Tasks that are not `Send`, for example because they hold `Rc`-based state, can be
+/// spawned with [`spawn_local`](Self::spawn_local) on WASM and on Tokio inside a
+/// `LocalSet`.
+///
 /// # Example
 ///
 /// ```rust
@@ -58,56 +82,100 @@ pub struct FluxionTask {
 }
 
 impl FluxionTask {
-    #[cfg(not(target_arch = "wasm32"))]
+    /// Spawns a task on the runtime selected by feature flags.
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(
+            fluxion_loom,
+            feature = "runtime-tokio",
+            feature = "runtime-smol",
+            feature = "runtime-async-std"
+        )
+    ))]
     pub fn spawn<F, Fut>(f: F) -> Self
     where
         F: FnOnce(CancellationToken) -> Fut + Send + 'static,
         Fut: Future<Output = ()> + Send + 'static,
     {
-        let cancel = CancellationToken::new();
-        let cancel_clone = cancel.clone();
-        let _future = f(cancel_clone);
-
-        #[cfg(all(
-            feature = "runtime-tokio",
-            not(all(feature = "runtime-smol", not(feature = "runtime-tokio"))),
-            not(all(
-                feature = "runtime-async-std",
-                not(feature = "runtime-tokio"),
-                not(feature = "runtime-smol")
-            ))
-        ))]
-        tokio::spawn(_future);
-
-        #[cfg(all(feature = "runtime-smol", not(feature = "runtime-tokio")))]
-        smol::spawn(_future).detach();
-
-        #[cfg(all(
-            feature = "runtime-async-std",
-            not(target_arch = "wasm32"),
-            not(feature = "runtime-tokio"),
-            not(feature = "runtime-smol")
-        ))]
-        async_std::task::spawn(_future);
+        #[cfg(fluxion_loom)]
+        {
+            let cancel = CancellationToken::new();
+            let future = f(cancel.clone());
+            loom::thread::spawn(move || loom::future::block_on(future));
+            Self { cancel }
+        }
 
-        Self { cancel }
+        #[cfg(not(fluxion_loom))]
+        Self::spawn_with(&DefaultSpawner::default(), f)
     }
 
+    /// Spawns a task on the runtime selected by feature flags.
     #[cfg(target_arch = "wasm32")]
     pub fn spawn<F, Fut>(f: F) -> Self
     where
         F: FnOnce(CancellationToken) -> Fut + 'static,
         Fut: Future<Output = ()> + 'static,
+    {
+        Self::spawn_local_with(&WasmSpawner, f)
+    }
+
+    /// Spawns a task with an explicit [`Spawner`], for runtimes that need a handle to
+    /// spawn, such as Embassy.
+    pub fn spawn_with<S, F, Fut>(spawner: &S, f: F) -> Self
+    where
+        S: Spawner,
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
     {
         let cancel = CancellationToken::new();
-        let cancel_clone = cancel.clone();
-        let _future = f(cancel_clone);
+        spawner.spawn(f(cancel.clone()));
+
+        Self { cancel }
+    }
 
-        wasm_bindgen_futures::spawn_local(_future);
+    /// Spawns a task that does not need to be `Send` with an explicit [`LocalSpawner`].
+    pub fn spawn_local_with<S, F, Fut>(spawner: &S, f: F) -> Self
+    where
+        S: LocalSpawner,
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let cancel = CancellationToken::new();
+        spawner.spawn_local(f(cancel.clone()));
 
         Self { cancel }
     }
 
+    /// Spawns a task that does not need to be `Send`, on the current thread.
+    ///
+    /// On WASM this is the same as [`spawn`](Self::spawn). On Tokio the task is spawned
+    /// with `tokio::task::spawn_local`, so it must be called from inside a
+    /// `tokio::task::LocalSet`.
+    ///
+    /// # Panics
+    ///
+    /// On Tokio, panics if called outside a `LocalSet`.
+    #[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+    pub fn spawn_local<F, Fut>(f: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self::spawn_local_with(&TokioSpawner, f)
+    }
+
+    /// Spawns a task that does not need to be `Send`, on the current thread.
+    ///
+    /// On WASM this is the same as [`spawn`](Self::spawn).
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_local<F, Fut>(f: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self::spawn(f)
+    }
+
     pub fn cancel(&self) {
         self.cancel.cancel();
     }
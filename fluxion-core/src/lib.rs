@@ -8,24 +8,34 @@ extern crate alloc;
 pub mod cancellation_token;
 pub mod fluxion;
 pub mod fluxion_error;
+mod fluxion_error_conversions;
 pub mod fluxion_mutex;
 #[cfg(feature = "alloc")]
 pub mod fluxion_subject;
 pub mod fluxion_task;
 pub mod has_timestamp;
 pub mod into_stream;
+pub mod self_timestamped;
 pub mod stream_item;
 pub mod subject_error;
+pub mod sync;
 pub mod timestamped;
+#[cfg(feature = "tracing")]
+pub mod traced;
+
+fluxion_runtime::assert_runtime_features!();
 
 pub use self::cancellation_token::CancellationToken;
 pub use self::fluxion::Fluxion;
-pub use self::fluxion_error::{FluxionError, Result, ResultExt};
+pub use self::fluxion_error::{ErrorKind, FluxionError, Result, ResultExt, Severity};
 #[cfg(feature = "alloc")]
 pub use self::fluxion_subject::FluxionSubject;
 pub use self::fluxion_task::FluxionTask;
 pub use self::has_timestamp::HasTimestamp;
 pub use self::into_stream::IntoStream;
+pub use self::self_timestamped::SelfTimestamped;
 pub use self::stream_item::StreamItem;
 pub use self::subject_error::SubjectError;
 pub use self::timestamped::Timestamped;
+#[cfg(feature = "tracing")]
+pub use self::traced::{TraceContext, Traced};
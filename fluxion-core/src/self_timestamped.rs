@@ -0,0 +1,78 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::{HasTimestamp, Timestamped};
+use core::fmt::Debug;
+
+/// A domain type that carries its own timestamp, the one trait such a type implements.
+///
+/// Operators are written against [`Timestamped`], which serves wrapper types such as
+/// `Sequenced<T>` that pair an inner value with a timestamp. A domain event usually has
+/// a timestamp field of its own instead, and is its own inner value. Implementing
+/// `SelfTimestamped` for it provides [`HasTimestamp`] and [`Timestamped`] with
+/// `Inner = Self`, and with them [`Fluxion`](crate::Fluxion) once the type is also
+/// `Clone + Debug + Ord` (and `Send + Sync` on multi-threaded runtimes).
+///
+/// The methods are named `time` and `set_time` so that calls to
+/// [`HasTimestamp::timestamp`] stay unambiguous with both traits in scope.
+///
+/// # Examples
+///
+/// ```
+/// use fluxion_core::{HasTimestamp, SelfTimestamped, Timestamped};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct SensorReading {
+///     timestamp: u64,
+///     celsius: i32,
+/// }
+///
+/// impl SelfTimestamped for SensorReading {
+///     type Time = u64;
+///
+///     fn time(&self) -> u64 {
+///         self.timestamp
+///     }
+///
+///     fn set_time(&mut self, time: u64) {
+///         self.timestamp = time;
+///     }
+/// }
+///
+/// let reading = SensorReading { timestamp: 5, celsius: 21 };
+/// assert_eq!(reading.timestamp(), 5);
+///
+/// let moved = SensorReading::with_timestamp(reading, 9);
+/// assert_eq!(moved.into_inner(), SensorReading { timestamp: 9, celsius: 21 });
+/// ```
+pub trait SelfTimestamped: Clone {
+    type Time: Ord + Copy + Send + Sync + Debug;
+
+    /// Returns the timestamp the item is ordered by.
+    fn time(&self) -> Self::Time;
+
+    /// Replaces the timestamp, such as when an operator re-stamps an item.
+    fn set_time(&mut self, time: Self::Time);
+}
+
+impl<T: SelfTimestamped> HasTimestamp for T {
+    type Timestamp = T::Time;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.time()
+    }
+}
+
+impl<T: SelfTimestamped> Timestamped for T {
+    type Inner = T;
+
+    fn with_timestamp(mut value: Self::Inner, timestamp: Self::Timestamp) -> Self {
+        value.set_time(timestamp);
+        value
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self
+    }
+}
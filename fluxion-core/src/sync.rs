@@ -0,0 +1,64 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Synchronization primitives shared by operator implementations.
+//!
+//! Operators import `Arc`, `Mutex` and atomics from here rather than from `alloc`,
+//! `core` or `parking_lot` directly. When built with `--cfg fluxion_loom` these resolve
+//! to [loom](https://docs.rs/loom)'s instrumented types, so the interleavings of
+//! concurrent producers and consumers can be explored exhaustively in model tests:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg fluxion_loom" cargo test -p fluxion-stream --test loom_tests --release
+//! ```
+//!
+//! A dedicated cfg is used instead of the conventional `loom` so that dependencies with
+//! their own loom mode (tokio, event-listener) are built unchanged. Under
+//! `cfg(fluxion_loom)`, [`FluxionTask`](crate::FluxionTask) runs spawned work on a loom
+//! thread instead of the async runtime.
+
+#[cfg(not(fluxion_loom))]
+pub use alloc::sync::Arc;
+#[cfg(fluxion_loom)]
+pub use loom::sync::Arc;
+
+#[cfg(not(fluxion_loom))]
+pub use core::sync::atomic;
+#[cfg(fluxion_loom)]
+pub use loom::sync::atomic;
+
+pub use crate::fluxion_mutex::Mutex;
+
+/// Mutex for operator state that is locked inside the futures an operator yields.
+///
+/// This is [`Mutex`] by default. With the `async-mutex` feature it is
+/// `futures::lock::Mutex`, whose lock waits instead of blocking the executor thread
+/// when the state is contended. Lock it with [`lock_state!`](crate::lock_state), which
+/// awaits only when needed.
+#[cfg(any(not(feature = "async-mutex"), fluxion_loom))]
+pub use crate::fluxion_mutex::Mutex as StateMutex;
+#[cfg(all(feature = "async-mutex", not(fluxion_loom)))]
+pub use futures::lock::Mutex as StateMutex;
+
+/// Locks a [`StateMutex`], awaiting the lock with the `async-mutex` feature.
+///
+/// Must be used inside an `async` block.
+#[cfg(any(not(feature = "async-mutex"), fluxion_loom))]
+#[macro_export]
+macro_rules! lock_state {
+    ($mutex:expr) => {
+        $mutex.lock()
+    };
+}
+
+/// Locks a [`StateMutex`], awaiting the lock with the `async-mutex` feature.
+///
+/// Must be used inside an `async` block.
+#[cfg(all(feature = "async-mutex", not(fluxion_loom)))]
+#[macro_export]
+macro_rules! lock_state {
+    ($mutex:expr) => {
+        $mutex.lock().await
+    };
+}
@@ -13,6 +13,10 @@ use crate::HasTimestamp;
 /// For types that only need to provide a timestamp value without wrapping,
 /// implement [`HasTimestamp`] instead.
 ///
+/// Domain types that carry their own timestamp and are their own inner value implement
+/// [`SelfTimestamped`](crate::SelfTimestamped) instead, which provides this trait and
+/// [`HasTimestamp`].
+///
 /// This trait allows stream operators like `combine_latest` and `ordered_merge`
 /// to work with any type that can provide a timestamp value and wraps an inner value.
 ///
@@ -0,0 +1,204 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Trace context carried by stream items.
+//!
+//! [`Traced<T>`] pairs a timestamped item with a [`tracing::Span`]. Operators that are
+//! aware of the trace context create child spans of the item's span, so a single event
+//! can be followed from its source, through every instrumented operator, down to the
+//! subscribe handler. Exporting the spans to Jaeger or Tempo is left to the subscriber
+//! configured by the application (e.g. `tracing-opentelemetry`).
+//!
+//! Requires the `tracing` feature.
+
+use crate::{HasTimestamp, StreamItem, Timestamped};
+use core::cmp::Ordering;
+use core::future::Future;
+use core::ops::Deref;
+use tracing::instrument::Instrumented;
+use tracing::{Instrument, Span};
+
+/// A timestamped item carrying the span of the trace it belongs to.
+///
+/// Ordering, equality and the timestamp are delegated to the wrapped item; the span
+/// never takes part in comparisons.
+///
+/// # Example
+///
+/// ```
+/// use fluxion_core::{HasTimestamp, Timestamped, Traced};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Reading {
+///     value: i32,
+///     ts: u64,
+/// }
+///
+/// impl HasTimestamp for Reading {
+///     type Timestamp = u64;
+///     fn timestamp(&self) -> u64 { self.ts }
+/// }
+///
+/// impl Timestamped for Reading {
+///     type Inner = i32;
+///     fn with_timestamp(value: i32, ts: u64) -> Self { Self { value, ts } }
+///     fn into_inner(self) -> i32 { self.value }
+/// }
+///
+/// let item = Traced::new(Reading { value: 21, ts: 7 });
+/// let child = item.child_span("double");
+///
+/// assert_eq!(item.timestamp(), 7);
+/// child.in_scope(|| assert_eq!(item.inner().value * 2, 42));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Traced<T> {
+    inner: T,
+    span: Span,
+}
+
+impl<T: HasTimestamp> Traced<T> {
+    /// Wraps `inner` in a new `fluxion.item` span.
+    ///
+    /// The span is a child of the span that is current when `new` is called, which makes
+    /// items created inside an instrumented operator part of the same trace.
+    pub fn new(inner: T) -> Self {
+        let span = tracing::info_span!("fluxion.item", timestamp = ?inner.timestamp());
+        Self { inner, span }
+    }
+}
+
+impl<T> Traced<T> {
+    /// Wraps `inner` with an explicit span.
+    pub fn with_span(inner: T, span: Span) -> Self {
+        Self { inner, span }
+    }
+
+    /// Returns the span this item belongs to.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Returns a reference to the wrapped item.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Splits the item into the wrapped value and its span.
+    pub fn into_parts(self) -> (T, Span) {
+        (self.inner, self.span)
+    }
+
+    /// Creates a `fluxion.operator` span that is a child of this item's span.
+    pub fn child_span(&self, operator: &'static str) -> Span {
+        tracing::info_span!(parent: &self.span, "fluxion.operator", operator)
+    }
+
+    /// Replaces the span of this item, keeping the wrapped value.
+    pub fn rebind(self, span: Span) -> Self {
+        Self {
+            inner: self.inner,
+            span,
+        }
+    }
+
+    /// Runs `f` with this item's span entered.
+    pub fn in_scope<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.span.in_scope(f)
+    }
+
+    /// Instruments `future` with a child span of this item, named after `operator`.
+    ///
+    /// Useful inside subscribe handlers to attach the handler's work to the item's trace.
+    pub fn instrument<Fut>(&self, operator: &'static str, future: Fut) -> Instrumented<Fut>
+    where
+        Fut: Future,
+    {
+        future.instrument(self.child_span(operator))
+    }
+}
+
+/// Items that may carry the span of the trace they belong to.
+///
+/// Subscribers use it to run handlers inside the item's trace, e.g.
+/// `subscribe_traced` in `fluxion-exec`.
+pub trait TraceContext {
+    /// Returns the span of this item's trace, if it has one.
+    fn trace_span(&self) -> Option<&Span>;
+}
+
+impl<T> TraceContext for Traced<T> {
+    fn trace_span(&self) -> Option<&Span> {
+        Some(&self.span)
+    }
+}
+
+/// Errors carry no trace context.
+impl<T: TraceContext> TraceContext for StreamItem<T> {
+    fn trace_span(&self) -> Option<&Span> {
+        match self {
+            StreamItem::Value(value) => value.trace_span(),
+            StreamItem::Error(_) => None,
+        }
+    }
+}
+
+impl<T> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: HasTimestamp> HasTimestamp for Traced<T> {
+    type Timestamp = T::Timestamp;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.inner.timestamp()
+    }
+}
+
+/// The inner value keeps the span, so operators that take an item apart and stamp its
+/// value with a new timestamp emit it in the trace it arrived with.
+impl<T: Timestamped> Timestamped for Traced<T> {
+    type Inner = Traced<T::Inner>;
+
+    fn with_timestamp(value: Self::Inner, timestamp: Self::Timestamp) -> Self {
+        Self {
+            inner: T::with_timestamp(value.inner, timestamp),
+            span: value.span,
+        }
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        Traced {
+            inner: self.inner.into_inner(),
+            span: self.span,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Traced<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq> Eq for Traced<T> {}
+
+impl<T: PartialOrd> PartialOrd for Traced<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<T: Ord> Ord for Traced<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+
+use fluxion_core::{ErrorKind, FluxionError, Result};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+
+#[test]
+fn test_futures_mpsc_send_error_converts() {
+    // Arrange
+    let (tx, rx) = futures::channel::mpsc::unbounded::<i32>();
+    drop(rx);
+
+    // Act
+    let send = || -> Result<()> { Ok(tx.unbounded_send(1)?) };
+    let err = send().unwrap_err();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::StreamProcessing);
+    assert!(err.to_string().starts_with(
+        "Stream processing error: futures mpsc send: send failed because receiver is gone"
+    ));
+}
+
+#[test]
+fn test_futures_mpsc_full_is_transient() {
+    // Arrange
+    let (mut tx, _rx) = futures::channel::mpsc::channel::<i32>(0);
+    tx.try_send(1).unwrap();
+
+    // Act
+    let err: FluxionError = tx.try_send(2).unwrap_err().into();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::Unavailable);
+    assert!(!err.is_permanent());
+}
+
+#[test]
+fn test_futures_oneshot_canceled_converts() {
+    // Arrange
+    let (tx, mut rx) = futures::channel::oneshot::channel::<i32>();
+    drop(tx);
+
+    // Act
+    let err: FluxionError = rx.try_recv().unwrap_err().into();
+
+    // Assert
+    assert_eq!(
+        err.to_string(),
+        "Stream processing error: futures oneshot receive: oneshot canceled"
+    );
+}
+
+#[test]
+fn test_tokio_mpsc_errors_convert() {
+    // Arrange
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+
+    // Act
+    let empty: FluxionError = rx.try_recv().unwrap_err().into();
+    tx.try_send(1).unwrap();
+    let full: FluxionError = tx.try_send(2).unwrap_err().into();
+    drop(rx);
+    let closed: FluxionError = tx.try_send(3).unwrap_err().into();
+
+    // Assert
+    assert_eq!(
+        empty.to_string(),
+        "Unavailable: tokio mpsc receive: receiving on an empty channel"
+    );
+    assert_eq!(empty.kind(), ErrorKind::Unavailable);
+    assert_eq!(
+        full.to_string(),
+        "Unavailable: tokio mpsc send: no available capacity"
+    );
+    assert_eq!(full.kind(), ErrorKind::Unavailable);
+    assert_eq!(
+        closed.to_string(),
+        "Stream processing error: tokio mpsc send: channel closed"
+    );
+    assert_eq!(closed.kind(), ErrorKind::StreamProcessing);
+}
+
+#[test]
+fn test_tokio_mpsc_disconnected_receive_is_permanent() {
+    // Arrange
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+    drop(tx);
+
+    // Act
+    let err: FluxionError = rx.try_recv().unwrap_err().into();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::StreamProcessing);
+    assert!(err.is_permanent());
+}
+
+#[tokio::test]
+async fn test_tokio_oneshot_recv_error_converts() {
+    // Arrange
+    let (tx, rx) = oneshot::channel::<i32>();
+    drop(tx);
+
+    // Act
+    let receive = async { Ok::<_, FluxionError>(rx.await?) };
+    let err = receive.await.unwrap_err();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::StreamProcessing);
+    assert!(err.to_string().contains("tokio oneshot receive"));
+}
+
+#[test]
+fn test_tokio_oneshot_try_recv_errors_convert() {
+    // Arrange
+    let (tx, mut rx) = oneshot::channel::<i32>();
+
+    // Act
+    let empty: FluxionError = rx.try_recv().unwrap_err().into();
+    drop(tx);
+    let closed: FluxionError = rx.try_recv().unwrap_err().into();
+
+    // Assert
+    assert_eq!(empty.kind(), ErrorKind::Unavailable);
+    assert_eq!(closed.kind(), ErrorKind::StreamProcessing);
+}
+
+#[tokio::test]
+async fn test_tokio_broadcast_errors_convert() {
+    // Arrange
+    let (tx, mut rx) = broadcast::channel::<i32>(1);
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+
+    // Act
+    let lagged: FluxionError = rx.recv().await.unwrap_err().into();
+    drop(rx);
+    let closed: FluxionError = tx.send(3).unwrap_err().into();
+
+    // Assert
+    assert_eq!(
+        lagged.to_string(),
+        "Unavailable: tokio broadcast receive: channel lagged by 1"
+    );
+    assert_eq!(lagged.kind(), ErrorKind::Unavailable);
+    assert_eq!(
+        closed.to_string(),
+        "Stream processing error: tokio broadcast send: channel closed"
+    );
+    assert_eq!(closed.kind(), ErrorKind::StreamProcessing);
+}
+
+#[test]
+fn test_tokio_broadcast_try_recv_errors_convert() {
+    // Arrange
+    let (tx, mut rx) = broadcast::channel::<i32>(1);
+
+    // Act
+    let empty: FluxionError = rx.try_recv().unwrap_err().into();
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    let lagged: FluxionError = rx.try_recv().unwrap_err().into();
+    rx.try_recv().unwrap();
+    drop(tx);
+    let closed: FluxionError = rx.try_recv().unwrap_err().into();
+
+    // Assert
+    assert_eq!(empty.kind(), ErrorKind::Unavailable);
+    assert_eq!(lagged.kind(), ErrorKind::Unavailable);
+    assert_eq!(closed.kind(), ErrorKind::StreamProcessing);
+}
+
+#[tokio::test]
+async fn test_tokio_broadcast_closed_receive_is_permanent() {
+    // Arrange
+    let (tx, mut rx) = broadcast::channel::<i32>(1);
+    drop(tx);
+
+    // Act
+    let err: FluxionError = rx.recv().await.unwrap_err().into();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::StreamProcessing);
+}
+
+#[tokio::test]
+async fn test_tokio_watch_errors_convert() {
+    // Arrange
+    let (tx, mut rx) = watch::channel(0);
+
+    // Act
+    drop(tx);
+    let receive: FluxionError = rx.changed().await.unwrap_err().into();
+    let (tx, rx) = watch::channel(0);
+    drop(rx);
+    let send: FluxionError = tx.send(1).unwrap_err().into();
+
+    // Assert
+    assert!(receive.to_string().contains("tokio watch receive"));
+    assert!(send.to_string().contains("tokio watch send"));
+}
+
+#[tokio::test]
+async fn test_tokio_join_error_is_wrapped() {
+    // Arrange
+    let task = tokio::spawn(async { panic!("handler failed") });
+
+    // Act
+    let join = async { Ok::<_, FluxionError>(task.await?) };
+    let err = join.await.unwrap_err();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::StreamProcessing);
+    assert!(err
+        .downcast_ref::<tokio::task::JoinError>()
+        .is_some_and(tokio::task::JoinError::is_panic));
+}
+
+#[cfg(feature = "alloc")]
+#[tokio::test]
+async fn test_async_channel_errors_convert() {
+    // Arrange
+    let (tx, rx) = async_channel::bounded::<i32>(1);
+
+    // Act
+    let empty: FluxionError = rx.try_recv().unwrap_err().into();
+    tx.try_send(1).unwrap();
+    let full: FluxionError = tx.try_send(2).unwrap_err().into();
+    drop(rx);
+    let closed: FluxionError = tx.send(3).await.unwrap_err().into();
+
+    // Assert
+    assert!(empty.to_string().contains("async-channel receive"));
+    assert_eq!(empty.kind(), ErrorKind::Unavailable);
+    assert_eq!(full.kind(), ErrorKind::Unavailable);
+    assert!(closed.to_string().contains("async-channel send"));
+    assert_eq!(closed.kind(), ErrorKind::StreamProcessing);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_async_channel_closed_receive_is_permanent() {
+    // Arrange
+    let (tx, rx) = async_channel::bounded::<i32>(1);
+    drop(tx);
+
+    // Act
+    let err: FluxionError = rx.try_recv().unwrap_err().into();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::StreamProcessing);
+}
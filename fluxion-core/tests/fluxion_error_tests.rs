@@ -4,9 +4,37 @@
 
 #![cfg(feature = "std")]
 
-use fluxion_core::{FluxionError, Result, ResultExt};
+use fluxion_core::{ErrorKind, FluxionError, Result, ResultExt, Severity};
+use std::error::Error;
+use std::fmt;
 use std::mem::size_of;
 
+#[derive(Debug, PartialEq)]
+struct OutOfStock(u32);
+
+impl fmt::Display for OutOfStock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {} is out of stock", self.0)
+    }
+}
+
+impl Error for OutOfStock {}
+
+#[derive(Debug)]
+struct ReservationFailed(OutOfStock);
+
+impl fmt::Display for ReservationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "reservation failed")
+    }
+}
+
+impl Error for ReservationFailed {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
 #[test]
 fn test_error_display() {
     // Arrange
@@ -171,6 +199,40 @@ fn test_context_with_timeout_error() {
 
     // Assert
     assert!(matches!(err, FluxionError::TimeoutError { .. }));
+    assert_eq!(
+        err.to_string(),
+        "Timeout error: operation timed out: no response"
+    );
+}
+
+#[test]
+fn test_context_keeps_user_error_variant() {
+    // Arrange
+    let result: Result<()> = Err(FluxionError::user_error("invalid input"));
+
+    // Act
+    let err = result.context("parsing order").unwrap_err();
+
+    // Assert
+    assert!(matches!(err, FluxionError::UserError { .. }));
+    assert_eq!(err.to_string(), "User error: parsing order: invalid input");
+}
+
+#[test]
+fn test_context_wraps_resource_limit_error() {
+    // Arrange
+    let result: Result<()> = Err(FluxionError::resource_limit_exceeded("window", 8));
+
+    // Act
+    let err = result.context("buffering readings").unwrap_err();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::ResourceLimit);
+    assert_eq!(err.to_string(), "buffering readings");
+    assert!(matches!(
+        err.source().and_then(|e| e.downcast_ref::<FluxionError>()),
+        Some(FluxionError::ResourceLimitExceeded { limit: 8, .. })
+    ));
 }
 
 #[test]
@@ -351,3 +413,247 @@ fn test_display_formats_correctly() {
         "Timeout error: 5 seconds exceeded"
     );
 }
+
+#[test]
+fn test_user_error_constructor() {
+    // Arrange & Act
+    let err = FluxionError::user_error("invalid input");
+
+    // Assert
+    assert!(matches!(err, FluxionError::UserError { .. }));
+    assert_eq!(err.to_string(), "User error: invalid input");
+    assert!(!err.is_permanent());
+    assert!(!err.is_recoverable());
+}
+
+#[test]
+fn test_clone_user_error() {
+    // Arrange
+    let err = FluxionError::user_error("test user");
+
+    // Act
+    let cloned = err.clone();
+
+    // Assert
+    assert!(matches!(cloned, FluxionError::UserError { .. }));
+    assert_eq!(err.to_string(), cloned.to_string());
+}
+
+#[test]
+fn test_resource_limit_exceeded_constructor() {
+    // Arrange & Act
+    let err = FluxionError::resource_limit_exceeded("merge streams", 4);
+
+    // Assert
+    assert!(matches!(
+        err,
+        FluxionError::ResourceLimitExceeded {
+            resource: "merge streams",
+            limit: 4
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Resource limit exceeded: merge streams (limit 4)"
+    );
+    assert!(!err.is_permanent());
+    assert!(!err.is_recoverable());
+}
+
+#[test]
+fn test_clone_resource_limit_exceeded() {
+    // Arrange
+    let err = FluxionError::resource_limit_exceeded("window", 8);
+
+    // Act
+    let cloned = err.clone();
+
+    // Assert
+    assert_eq!(err.to_string(), cloned.to_string());
+}
+
+#[test]
+fn test_wrap_keeps_source() {
+    // Arrange & Act
+    let err = FluxionError::wrap(OutOfStock(7));
+
+    // Assert
+    assert_eq!(err.to_string(), "item 7 is out of stock");
+    assert_eq!(err.source().unwrap().to_string(), "item 7 is out of stock");
+    assert_eq!(err.downcast_ref::<OutOfStock>(), Some(&OutOfStock(7)));
+}
+
+#[test]
+fn test_wrap_boxed_keeps_concrete_source() {
+    // Arrange
+    let boxed: Box<dyn Error + Send + Sync> = Box::new(OutOfStock(3));
+
+    // Act
+    let err = FluxionError::wrap_boxed(boxed);
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::User);
+    assert_eq!(err.source().unwrap().to_string(), "item 3 is out of stock");
+    assert_eq!(err.downcast_ref::<OutOfStock>(), Some(&OutOfStock(3)));
+}
+
+#[test]
+fn test_downcast_ref_of_other_type_is_none() {
+    // Arrange
+    let wrapped = FluxionError::wrap(OutOfStock(7));
+    let plain = FluxionError::user_error("invalid input");
+
+    // Act & Assert
+    assert!(wrapped.downcast_ref::<ReservationFailed>().is_none());
+    assert!(plain.downcast_ref::<OutOfStock>().is_none());
+    assert!(plain.downcast_ref::<FluxionError>().is_some());
+}
+
+#[test]
+fn test_context_preserves_wrapped_source() {
+    // Arrange
+    let result: Result<()> = Err(FluxionError::wrap(OutOfStock(7)));
+
+    // Act
+    let err = result
+        .context("reserving order")
+        .with_context(|| String::from("checkout"))
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(err.to_string(), "checkout: reserving order");
+    assert_eq!(err.source().unwrap().to_string(), "item 7 is out of stock");
+    assert_eq!(err.downcast_ref::<OutOfStock>(), Some(&OutOfStock(7)));
+}
+
+#[test]
+fn test_downcast_ref_walks_source_chain() {
+    // Arrange
+    let err = FluxionError::wrap(ReservationFailed(OutOfStock(3)));
+
+    // Act & Assert
+    assert!(err.downcast_ref::<ReservationFailed>().is_some());
+    assert_eq!(err.downcast_ref::<OutOfStock>(), Some(&OutOfStock(3)));
+}
+
+#[test]
+fn test_root_cause() {
+    // Arrange
+    let chained = FluxionError::wrap(ReservationFailed(OutOfStock(3)));
+    let plain = FluxionError::stream_error("root");
+
+    // Act & Assert
+    assert_eq!(chained.root_cause().to_string(), "item 3 is out of stock");
+    assert_eq!(
+        plain.root_cause().to_string(),
+        "Stream processing error: root"
+    );
+}
+
+#[test]
+fn test_clone_wrapped_shares_source() {
+    // Arrange
+    let err = FluxionError::wrap(OutOfStock(7));
+
+    // Act
+    let cloned = err.clone();
+
+    // Assert
+    assert_eq!(err.to_string(), cloned.to_string());
+    assert_eq!(cloned.downcast_ref::<OutOfStock>(), Some(&OutOfStock(7)));
+}
+
+#[test]
+fn test_kind_per_variant() {
+    // Arrange & Act & Assert
+    assert_eq!(
+        FluxionError::stream_error("x").kind(),
+        ErrorKind::StreamProcessing
+    );
+    assert_eq!(FluxionError::timeout_error("x").kind(), ErrorKind::Timeout);
+    assert_eq!(FluxionError::user_error("x").kind(), ErrorKind::User);
+    assert_eq!(
+        FluxionError::resource_limit_exceeded("buffer", 4).kind(),
+        ErrorKind::ResourceLimit
+    );
+    assert_eq!(FluxionError::wrap(OutOfStock(1)).kind(), ErrorKind::User);
+}
+
+#[test]
+fn test_wrap_with_kind_sets_kind_and_keeps_source() {
+    // Arrange & Act
+    let err = FluxionError::wrap_with_kind(ErrorKind::Timeout, OutOfStock(4));
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::Timeout);
+    assert_eq!(err.severity(), Severity::Warning);
+    assert!(!err.is_permanent());
+    assert_eq!(err.downcast_ref::<OutOfStock>().map(|e| e.0), Some(4));
+    assert!(
+        FluxionError::wrap_with_kind(ErrorKind::StreamProcessing, OutOfStock(4)).is_permanent()
+    );
+}
+
+#[test]
+fn test_codes_are_stable_and_unique() {
+    // Arrange
+    let kinds = [
+        ErrorKind::StreamProcessing,
+        ErrorKind::Timeout,
+        ErrorKind::User,
+        ErrorKind::ResourceLimit,
+        ErrorKind::Unavailable,
+    ];
+
+    // Act
+    let codes: Vec<_> = kinds.iter().map(|kind| kind.code()).collect();
+
+    // Assert
+    assert_eq!(codes, ["FLX001", "FLX002", "FLX003", "FLX004", "FLX005"]);
+    assert_eq!(FluxionError::timeout_error("x").code(), "FLX002");
+}
+
+#[test]
+fn test_severity_per_kind() {
+    // Arrange & Act & Assert
+    assert_eq!(
+        FluxionError::timeout_error("x").severity(),
+        Severity::Warning
+    );
+    assert_eq!(FluxionError::unavailable("x").severity(), Severity::Warning);
+    assert_eq!(FluxionError::user_error("x").severity(), Severity::Error);
+    assert_eq!(
+        FluxionError::resource_limit_exceeded("buffer", 4).severity(),
+        Severity::Error
+    );
+    assert_eq!(
+        FluxionError::wrap(OutOfStock(1)).severity(),
+        Severity::Error
+    );
+    assert_eq!(
+        FluxionError::stream_error("x").severity(),
+        Severity::Critical
+    );
+}
+
+#[test]
+fn test_severity_is_ordered() {
+    // Arrange & Act & Assert
+    assert!(Severity::Warning < Severity::Error);
+    assert!(Severity::Error < Severity::Critical);
+}
+
+#[test]
+fn test_kind_survives_context() {
+    // Arrange
+    let result: Result<()> = Err(FluxionError::wrap_with_kind(
+        ErrorKind::ResourceLimit,
+        OutOfStock(7),
+    ));
+
+    // Act
+    let err = result.context("reserving order").unwrap_err();
+
+    // Assert
+    assert_eq!(err.kind(), ErrorKind::ResourceLimit);
+}
@@ -0,0 +1,63 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::fmt::Debug;
+use fluxion_core::{Fluxion, HasTimestamp, SelfTimestamped, StreamItem, Timestamped};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Reading {
+    ts: u64,
+    celsius: i32,
+}
+
+impl SelfTimestamped for Reading {
+    type Time = u64;
+
+    fn time(&self) -> u64 {
+        self.ts
+    }
+
+    fn set_time(&mut self, time: u64) {
+        self.ts = time;
+    }
+}
+
+fn assert_fluxion<T>()
+where
+    T: Fluxion,
+    T::Inner: Clone + Send + Sync + Unpin + 'static + Debug + Ord,
+{
+}
+
+#[test]
+fn test_self_timestamped_provides_timestamp() {
+    // Arrange
+    let reading = Reading { ts: 7, celsius: 21 };
+
+    // Act & Assert
+    assert_eq!(reading.timestamp(), 7);
+}
+
+#[test]
+fn test_self_timestamped_is_its_own_inner_value() {
+    // Arrange
+    let reading = Reading { ts: 7, celsius: 21 };
+
+    // Act
+    let restamped = Reading::with_timestamp(reading.clone(), 9);
+
+    // Assert
+    assert_eq!(restamped, Reading { ts: 9, celsius: 21 });
+    assert_eq!(reading.clone().into_inner(), reading);
+}
+
+#[test]
+fn test_self_timestamped_type_is_fluxion() {
+    // Arrange
+    let item = StreamItem::Value(Reading { ts: 3, celsius: 18 });
+
+    // Act & Assert
+    assert_fluxion::<Reading>();
+    assert_eq!(item.timestamp(), 3);
+}
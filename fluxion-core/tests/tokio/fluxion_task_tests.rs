@@ -3,7 +3,34 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 use fluxion_core::FluxionTask;
+use fluxion_runtime::spawner::Spawner;
 use futures::channel::oneshot;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use tokio::task::LocalSet;
+
+/// Spawner that queues futures instead of running them, to observe what is spawned.
+#[derive(Clone, Default)]
+struct QueueSpawner {
+    queue: Arc<Mutex<Vec<BoxFuture<'static, ()>>>>,
+}
+
+impl std::fmt::Debug for QueueSpawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueueSpawner").finish_non_exhaustive()
+    }
+}
+
+impl Spawner for QueueSpawner {
+    fn spawn<F>(&self, future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.queue.lock().unwrap().push(future.boxed());
+    }
+}
 
 #[tokio::test]
 async fn test_task_cancellation_on_drop() {
@@ -38,3 +65,44 @@ async fn test_task_manual_cancel() {
     assert!(task.is_cancelled());
     assert!(rx.await.is_ok());
 }
+
+#[tokio::test]
+async fn test_spawn_local_runs_non_send_task_until_cancelled() {
+    // Arrange
+    let local = LocalSet::new();
+    let (tx, rx) = oneshot::channel();
+
+    // Act
+    local
+        .run_until(async move {
+            let state = Rc::new(42);
+            let task = FluxionTask::spawn_local(move |cancel| async move {
+                cancel.cancelled().await;
+                let _ = tx.send(*state);
+            });
+            drop(task);
+
+            // Assert
+            assert_eq!(rx.await.unwrap(), 42);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn test_spawn_with_uses_given_spawner() {
+    // Arrange
+    let spawner = QueueSpawner::default();
+    let (tx, rx) = oneshot::channel();
+
+    // Act
+    let task = FluxionTask::spawn_with(&spawner, |cancel| async move {
+        cancel.cancelled().await;
+        let _ = tx.send(());
+    });
+    drop(task);
+
+    // Assert
+    let spawned = spawner.queue.lock().unwrap().pop().unwrap();
+    spawned.await;
+    assert!(rx.await.is_ok());
+}
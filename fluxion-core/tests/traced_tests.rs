@@ -0,0 +1,82 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg(feature = "tracing")]
+
+use fluxion_core::{HasTimestamp, Timestamped, Traced};
+use std::cmp::Ordering;
+use tracing::Span;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Event {
+    ts: u64,
+    value: i32,
+}
+
+impl HasTimestamp for Event {
+    type Timestamp = u64;
+
+    fn timestamp(&self) -> u64 {
+        self.ts
+    }
+}
+
+impl Timestamped for Event {
+    type Inner = i32;
+
+    fn with_timestamp(value: i32, ts: u64) -> Self {
+        Self { ts, value }
+    }
+
+    fn into_inner(self) -> i32 {
+        self.value
+    }
+}
+
+#[test]
+fn test_traced_delegates_timestamp() {
+    // Arrange & Act
+    let item = Traced::new(Event { ts: 7, value: 1 });
+
+    // Assert
+    assert_eq!(item.timestamp(), 7);
+    assert_eq!(item.inner().value, 1);
+}
+
+#[test]
+fn test_traced_with_timestamp_roundtrip() {
+    // Arrange & Act
+    let item =
+        <Traced<Event> as Timestamped>::with_timestamp(Traced::with_span(42, Span::none()), 3);
+
+    // Assert
+    assert_eq!(item.timestamp(), 3);
+    assert_eq!(*item.into_inner(), 42);
+}
+
+#[test]
+fn test_traced_ordering_ignores_span() {
+    // Arrange
+    let first = Traced::new(Event { ts: 1, value: 10 });
+    let second = Traced::new(Event { ts: 2, value: 10 });
+    let same = Traced::new(Event { ts: 1, value: 10 });
+
+    // Act & Assert
+    assert_eq!(first.cmp(&second), Ordering::Less);
+    assert_eq!(first, same);
+}
+
+#[test]
+fn test_traced_into_parts_and_rebind() {
+    // Arrange
+    let item = Traced::new(Event { ts: 5, value: 9 });
+    let child = item.child_span("stage");
+
+    // Act
+    let rebound = item.rebind(child);
+    let (inner, _span) = rebound.into_parts();
+
+    // Assert
+    assert_eq!(inner, Event { ts: 5, value: 9 });
+}
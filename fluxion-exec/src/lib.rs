@@ -103,6 +103,30 @@
 //! - Processing happens on the tokio runtime
 //! - Multiple streams can be processed concurrently
 //!
+//! ## Local Handlers
+//!
+//! Handlers that capture `Rc`/`RefCell` state, such as UI widgets, cannot be `Send`.
+//! [`SubscribeLocalExt::subscribe_local`] and
+//! [`SubscribeLatestLocalExt::subscribe_latest_local`] drop the `Send` requirement and
+//! run on the current thread: `subscribe_latest_local` spawns its handler task with
+//! `spawn_local`, which needs WASM or a `tokio::task::LocalSet`.
+//!
+//! ## Embedded (Embassy)
+//!
+//! With the `runtime-embassy` feature, `fluxion-exec` is `no_std` and
+//! [`subscribe_latest`] runs its handler inside the subscription future instead of a
+//! spawned task, since Embassy only spawns statically declared tasks. Awaiting the
+//! subscription from any Embassy task drives both the stream and the handler; no
+//! `Spawner` is needed.
+//!
+//! ## Supervision
+//!
+//! A [`Supervisor`] owns long-running subscriptions and restarts them when they end,
+//! according to a [`RestartPolicy`]: always, on errors only, or on errors up to a number
+//! of restarts within a time window, each with an optional exponential backoff. Its
+//! [`status`](Supervisor::status) aggregates the state of all children for health
+//! checks.
+//!
 //! # Performance Characteristics
 //!
 //! ## Sequential Processing (`subscribe`)
@@ -341,20 +365,60 @@ extern crate alloc;
 
 #[macro_use]
 mod logging;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub mod shutdown;
 pub mod subscribe;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
     feature = "runtime-async-std",
+    feature = "runtime-embassy",
     target_arch = "wasm32"
 ))]
 pub mod subscribe_latest;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    target_arch = "wasm32"
+))]
+pub mod subscribe_latest_local;
+pub mod subscribe_local;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub mod supervisor;
+
+fluxion_runtime::assert_runtime_features!();
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use shutdown::{ShutdownCoordinator, ShutdownReport};
 pub use subscribe::SubscribeExt;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
     feature = "runtime-async-std",
+    feature = "runtime-embassy",
     target_arch = "wasm32"
 ))]
 pub use subscribe_latest::SubscribeLatestExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    target_arch = "wasm32"
+))]
+pub use subscribe_latest_local::SubscribeLatestLocalExt;
+pub use subscribe_local::SubscribeLocalExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use supervisor::{ChildState, ChildStatus, RestartPolicy, Supervisor, SupervisorStatus};
@@ -0,0 +1,298 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Ordered, pipeline-wide graceful shutdown.
+//!
+//! A [`ShutdownCoordinator`] tracks what a pipeline is made of: the sources feeding it,
+//! the subscriptions consuming it, the sinks to flush and any other resources, such as
+//! shared streams, that must stay alive while it runs. [`shutdown`](ShutdownCoordinator::shutdown)
+//! then takes it down in order instead of cancelling a token and sleeping:
+//!
+//! 1. **Stop sources** - the token of every [`source`](ShutdownCoordinator::source) is
+//!    cancelled, so no new items enter the pipeline and its streams end
+//! 2. **Drain** - the subscriptions are given until the deadline to process what is
+//!    still buffered and complete on their own
+//! 3. **Flush** - the [`on_flush`](ShutdownCoordinator::on_flush) callbacks run in the
+//!    order they were registered
+//! 4. **Cancel** - subscriptions still running are cancelled and the held resources
+//!    are dropped
+//!
+//! The returned [`ShutdownReport`] tells which subscriptions drained, failed or had to
+//! be cancelled, and which flushes failed. Dropping the coordinator without calling
+//! `shutdown` cancels everything at once.
+//!
+//! Only available with a multi-threaded runtime.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_exec::shutdown::ShutdownCoordinator;
+//! use fluxion_exec::SubscribeExt;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let coordinator = ShutdownCoordinator::new();
+//! let (tx, numbers) = futures::channel::mpsc::unbounded::<i32>();
+//! tx.unbounded_send(1).unwrap();
+//! tx.unbounded_send(2).unwrap();
+//!
+//! // The source closes its channel once stopped; the items already sent still drain
+//! let stop = coordinator.source("numbers");
+//! tokio::spawn(async move {
+//!     stop.cancelled().await;
+//!     drop(tx);
+//! });
+//!
+//! let processed = Arc::new(AtomicUsize::new(0));
+//! let counter = processed.clone();
+//! coordinator.subscribe("counter", move |cancel| {
+//!     numbers.subscribe(
+//!         move |_, _| {
+//!             let counter = counter.clone();
+//!             async move {
+//!                 counter.fetch_add(1, Ordering::SeqCst);
+//!                 Ok::<(), std::io::Error>(())
+//!             }
+//!         },
+//!         |_| {},
+//!         Some(cancel),
+//!     )
+//! });
+//!
+//! let report = coordinator.shutdown(Duration::from_secs(1)).await;
+//!
+//! assert!(report.is_clean());
+//! assert_eq!(report.drained, vec!["counter"]);
+//! assert_eq!(processed.load(Ordering::SeqCst), 2);
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::future::Future;
+use core::pin::Pin;
+use core::time::Duration;
+use event_listener::Event;
+use fluxion_core::sync::Mutex;
+use fluxion_core::{CancellationToken, FluxionTask, Result};
+use fluxion_runtime::default_runtime::DefaultTimer;
+use fluxion_runtime::timer::Timer;
+use futures::future::{select, Either};
+
+type FlushFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+type Flush = Box<dyn FnOnce() -> FlushFuture + Send>;
+
+/// Outcome of a [`ShutdownCoordinator::shutdown`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Subscriptions that completed, before or while draining.
+    pub drained: Vec<String>,
+    /// Subscriptions that ended with an error, with its message.
+    pub failed: Vec<(String, String)>,
+    /// Subscriptions still running at the deadline, which were cancelled.
+    pub cancelled: Vec<String>,
+    /// Flushes that returned an error, with its message.
+    pub flush_failures: Vec<(String, String)>,
+}
+
+impl ShutdownReport {
+    /// Returns `true` if every subscription drained and every flush succeeded.
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty() && self.cancelled.is_empty() && self.flush_failures.is_empty()
+    }
+}
+
+enum Outcome {
+    Running,
+    Completed,
+    Failed(String),
+}
+
+struct Subscription {
+    name: String,
+    outcome: Outcome,
+    task: Option<FluxionTask>,
+}
+
+/// Subscriptions and the event notified whenever one of them ends.
+#[derive(Default)]
+struct Shared {
+    subscriptions: Mutex<Vec<Subscription>>,
+    ended: Event,
+}
+
+impl Shared {
+    fn running(&self) -> usize {
+        self.subscriptions
+            .lock()
+            .iter()
+            .filter(|subscription| matches!(subscription.outcome, Outcome::Running))
+            .count()
+    }
+}
+
+/// Tracks the sources, subscriptions, sinks and resources of a pipeline and shuts them
+/// down in order.
+///
+/// See the [module documentation](self) for the shutdown sequence and an example.
+#[derive(Default)]
+pub struct ShutdownCoordinator {
+    shared: Arc<Shared>,
+    sources: Mutex<Vec<(String, CancellationToken)>>,
+    flushes: Mutex<Vec<(String, Flush)>>,
+    resources: Mutex<Vec<Box<dyn Any + Send>>>,
+}
+
+impl ShutdownCoordinator {
+    /// Creates a coordinator tracking nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a source and returns the token cancelled when sources are stopped.
+    ///
+    /// The source is expected to stop producing, ending its stream, once the token is
+    /// cancelled.
+    pub fn source(&self, name: impl Into<String>) -> CancellationToken {
+        let token = CancellationToken::new();
+        self.sources.lock().push((name.into(), token.clone()));
+        token
+    }
+
+    /// Starts the subscription built by `factory` on its own task.
+    ///
+    /// The factory receives a token cancelled in the last shutdown step, to pass on to
+    /// `subscribe`. The subscription is expected to complete once its sources end.
+    pub fn subscribe<F, Fut>(&self, name: impl Into<String>, factory: F)
+    where
+        F: FnOnce(CancellationToken) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let index = {
+            let mut subscriptions = self.shared.subscriptions.lock();
+            subscriptions.push(Subscription {
+                name: name.into(),
+                outcome: Outcome::Running,
+                task: None,
+            });
+            subscriptions.len() - 1
+        };
+
+        let shared = Arc::clone(&self.shared);
+        let task = FluxionTask::spawn(move |cancel| async move {
+            let subscription = core::pin::pin!(factory(cancel.clone()));
+            let outcome = match select(subscription, core::pin::pin!(cancel.cancelled())).await {
+                Either::Left((Ok(()), _)) => Outcome::Completed,
+                Either::Left((Err(error), _)) => Outcome::Failed(error.to_string()),
+                Either::Right(_) => return,
+            };
+            shared.subscriptions.lock()[index].outcome = outcome;
+            shared.ended.notify(usize::MAX);
+        });
+        self.shared.subscriptions.lock()[index].task = Some(task);
+    }
+
+    /// Registers a callback run after the subscriptions drained, to flush a sink.
+    pub fn on_flush<F, Fut>(&self, name: impl Into<String>, flush: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.flushes.lock().push((
+            name.into(),
+            Box::new(move || Box::pin(flush()) as FlushFuture),
+        ));
+    }
+
+    /// Keeps `resource`, such as a shared stream, alive until the last shutdown step.
+    pub fn hold<R: Send + 'static>(&self, resource: R) {
+        self.resources.lock().push(Box::new(resource));
+    }
+
+    /// Cancels every source token without waiting for anything, starting the shutdown
+    /// of the pipeline.
+    pub fn stop_sources(&self) {
+        for (_, token) in self.sources.lock().iter() {
+            token.cancel();
+        }
+    }
+
+    /// Returns the number of subscriptions still running.
+    pub fn running(&self) -> usize {
+        self.shared.running()
+    }
+
+    /// Shuts the pipeline down, giving the subscriptions up to `drain_deadline` to
+    /// complete after the sources are stopped.
+    pub async fn shutdown(self, drain_deadline: Duration) -> ShutdownReport {
+        self.stop_sources();
+
+        let drained = core::pin::pin!(self.drained());
+        let deadline = core::pin::pin!(DefaultTimer::default().sleep_future(drain_deadline));
+        select(drained, deadline).await;
+
+        let mut report = ShutdownReport::default();
+        let flushes = core::mem::take(&mut *self.flushes.lock());
+        for (name, flush) in flushes {
+            if let Err(error) = flush().await {
+                warn!(operator = "shutdown", sink = name.as_str(), error = error; "flush failed");
+                report.flush_failures.push((name, error.to_string()));
+            }
+        }
+
+        for subscription in self.shared.subscriptions.lock().iter_mut() {
+            subscription.task.take();
+            let name = subscription.name.clone();
+            match &subscription.outcome {
+                Outcome::Running => report.cancelled.push(name),
+                Outcome::Completed => report.drained.push(name),
+                Outcome::Failed(error) => report.failed.push((name, error.clone())),
+            }
+        }
+        self.resources.lock().clear();
+
+        report
+    }
+
+    async fn drained(&self) {
+        loop {
+            let listener = self.shared.ended.listen();
+            if self.shared.running() == 0 {
+                return;
+            }
+            listener.await;
+        }
+    }
+}
+
+impl Drop for ShutdownCoordinator {
+    fn drop(&mut self) {
+        self.stop_sources();
+        // The subscription tasks hold the subscriptions, so they are cancelled explicitly
+        for subscription in self.shared.subscriptions.lock().iter_mut() {
+            subscription.task.take();
+        }
+    }
+}
+
+impl core::fmt::Debug for ShutdownCoordinator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sources: Vec<String> = self
+            .sources
+            .lock()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        f.debug_struct("ShutdownCoordinator")
+            .field("sources", &sources)
+            .field("running", &self.running())
+            .finish()
+    }
+}
@@ -7,11 +7,36 @@ use core::future::Future;
 use fluxion_core::{CancellationToken, Result};
 use futures::stream::{Stream, StreamExt};
 
-pub async fn subscribe_impl<S, T, F, Fut, E, OnError>(
+/// Span a handler runs in; the unit type when tracing is disabled.
+#[cfg(feature = "tracing")]
+pub(crate) type HandlerSpan = tracing::Span;
+#[cfg(not(feature = "tracing"))]
+pub(crate) type HandlerSpan = ();
+
+/// Creates the `fluxion.subscribe` span of a handler, ignoring any trace context of `item`.
+#[cfg(feature = "tracing")]
+pub(crate) fn subscribe_span<T>(_item: &T) -> HandlerSpan {
+    operator_span!("subscribe")
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn subscribe_span<T>(_item: &T) -> HandlerSpan {}
+
+/// Creates the `fluxion.subscribe` span of a handler as a child of the span of `item`.
+#[cfg(feature = "tracing")]
+pub(crate) fn traced_subscribe_span<T: fluxion_core::TraceContext>(item: &T) -> HandlerSpan {
+    match item.trace_span() {
+        Some(parent) => operator_span!(parent: parent, "subscribe"),
+        None => operator_span!("subscribe"),
+    }
+}
+
+pub async fn subscribe_impl<S, T, F, Fut, E, OnError, H>(
     mut stream: S,
     on_next_func: F,
     on_error_callback: OnError,
     cancellation_token: Option<CancellationToken>,
+    handler_span: H,
 ) -> Result<()>
 where
     S: Stream<Item = T> + Unpin,
@@ -19,6 +44,7 @@ where
     Fut: Future<Output = core::result::Result<(), E>>,
     OnError: Fn(E) + Clone,
     T: Debug + Clone,
+    H: Fn(&T) -> HandlerSpan,
 {
     let cancellation_token = cancellation_token.unwrap_or_default();
 
@@ -27,7 +53,18 @@ where
             break;
         }
 
-        let result = on_next_func(item.clone(), cancellation_token.clone()).await;
+        let handler = on_next_func(item.clone(), cancellation_token.clone());
+
+        #[cfg(feature = "tracing")]
+        let result = {
+            use tracing::Instrument;
+            handler.instrument(handler_span(&item)).await
+        };
+        #[cfg(not(feature = "tracing"))]
+        let result = {
+            handler_span(&item);
+            handler.await
+        };
 
         if let Err(error) = result {
             on_error_callback(error);
@@ -45,7 +82,11 @@ macro_rules! define_subscribe_impl {
         use core::future::Future;
         use fluxion_core::{CancellationToken, Result};
         use futures::stream::Stream;
-        use crate::subscribe::implementation::subscribe_impl;
+        use crate::subscribe::implementation::{subscribe_impl, subscribe_span};
+        #[cfg(feature = "tracing")]
+        use crate::subscribe::implementation::traced_subscribe_span;
+        #[cfg(feature = "tracing")]
+        use fluxion_core::TraceContext;
 
         #[$attr]
         pub trait SubscribeExt<T>: Stream<Item = T> + Sized {
@@ -71,6 +112,7 @@ macro_rules! define_subscribe_impl {
             /// # See Also
             ///
             /// - [`subscribe_latest`](crate::SubscribeLatestExt::subscribe_latest) - Cancels old work for new items
+            /// - [`subscribe_traced`](Self::subscribe_traced) - Runs handlers in the trace of each item
             async fn subscribe<F, Fut, E, OnError>(
                 self,
                 on_next_func: F,
@@ -83,6 +125,28 @@ macro_rules! define_subscribe_impl {
                 OnError: Fn(E) + Clone + $($bounds)* 'static,
                 T: Debug + Clone + $($bounds)* 'static,
                 E: $($bounds)* 'static;
+
+            /// Subscribes like [`subscribe`](Self::subscribe), running each handler in a
+            /// `fluxion.subscribe` span that is a child of the item's span.
+            ///
+            /// Use it with streams of [`Traced`](fluxion_core::Traced) items so the handler
+            /// joins the trace the item carries through the operator chain. Items without a
+            /// trace context, such as errors, get a span of their own.
+            ///
+            /// Requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            async fn subscribe_traced<F, Fut, E, OnError>(
+                self,
+                on_next_func: F,
+                on_error_callback: OnError,
+                cancellation_token: Option<CancellationToken>,
+            ) -> Result<()>
+            where
+                F: Fn(T, CancellationToken) -> Fut + Clone + $($bounds)* 'static,
+                Fut: Future<Output = core::result::Result<(), E>> + $($bounds)* 'static,
+                OnError: Fn(E) + Clone + $($bounds)* 'static,
+                T: TraceContext + Debug + Clone + $($bounds)* 'static,
+                E: $($bounds)* 'static;
         }
 
         #[$attr]
@@ -104,7 +168,38 @@ macro_rules! define_subscribe_impl {
                 T: Debug + Clone + $($bounds)* 'static,
                 E: $($bounds)* 'static,
             {
-                subscribe_impl(self, on_next_func, on_error_callback, cancellation_token).await
+                subscribe_impl(
+                    self,
+                    on_next_func,
+                    on_error_callback,
+                    cancellation_token,
+                    subscribe_span,
+                )
+                .await
+            }
+
+            #[cfg(feature = "tracing")]
+            async fn subscribe_traced<F, Fut, E, OnError>(
+                self,
+                on_next_func: F,
+                on_error_callback: OnError,
+                cancellation_token: Option<CancellationToken>,
+            ) -> Result<()>
+            where
+                F: Fn(T, CancellationToken) -> Fut + Clone + $($bounds)* 'static,
+                Fut: Future<Output = core::result::Result<(), E>> + $($bounds)* 'static,
+                OnError: Fn(E) + Clone + $($bounds)* 'static,
+                T: TraceContext + Debug + Clone + $($bounds)* 'static,
+                E: $($bounds)* 'static,
+            {
+                subscribe_impl(
+                    self,
+                    on_next_func,
+                    on_error_callback,
+                    cancellation_token,
+                    traced_subscribe_span,
+                )
+                .await
             }
         }
     };
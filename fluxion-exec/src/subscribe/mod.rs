@@ -3,7 +3,7 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 #[macro_use]
-mod implementation;
+pub(crate) mod implementation;
 
 #[cfg(any(
     all(feature = "runtime-tokio", not(target_arch = "wasm32")),
@@ -0,0 +1,237 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! `subscribe_latest` for Embassy and other executors without dynamic spawning.
+//!
+//! Embassy tasks are statically allocated, non-generic functions, so a library cannot
+//! spawn a task per subscription. Instead the handler future lives inside the
+//! subscription future, which polls the source stream and the running handler side by
+//! side. The subscription runs on whichever Embassy task awaits it and needs no
+//! `Spawner` or task pool.
+
+use crate::subscribe::implementation::HandlerSpan;
+use alloc::boxed::Box;
+use async_trait::async_trait;
+use core::fmt::Debug;
+use core::future::{poll_fn, Future};
+use core::pin::{pin, Pin};
+use core::task::Poll;
+#[cfg(feature = "tracing")]
+use fluxion_core::TraceContext;
+use fluxion_core::{CancellationToken, Result};
+use futures::{Stream, StreamExt};
+
+#[async_trait(?Send)]
+pub trait SubscribeLatestExt<T>: Stream<Item = T> + Sized {
+    /// Subscribes to the stream, automatically cancelling processing of older items when new items arrive.
+    ///
+    /// This method is ideal for scenarios where you only care about processing the most recent
+    /// value and want to abandon work on outdated values.
+    ///
+    /// # Behavior
+    ///
+    /// - Only one handler runs at a time per stream
+    /// - When a new item arrives during processing, it queues as "latest"
+    /// - After current processing completes, the latest queued item is processed
+    /// - Intermediate items between current and latest are discarded
+    ///
+    /// The handler runs inside the returned future rather than in a spawned task, so
+    /// the stream keeps being drained while a handler is awaiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_next_func` - Async function called for each item
+    /// * `on_error_callback` - Error handler for processing failures
+    /// * `cancellation_token` - Optional token to stop all processing
+    ///
+    /// # See Also
+    ///
+    /// - [`subscribe`](crate::SubscribeExt::subscribe) - Sequential processing of all items
+    /// - [`subscribe_latest_traced`](Self::subscribe_latest_traced) - Runs handlers in the trace of each item
+    async fn subscribe_latest<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        E: 'static,
+        T: Debug + Clone + 'static;
+
+    /// Subscribes like [`subscribe_latest`](Self::subscribe_latest), running each handler
+    /// in a `fluxion.subscribe_latest` span that is a child of the item's span.
+    ///
+    /// Use it with streams of [`Traced`](fluxion_core::Traced) items so the handler joins
+    /// the trace the item carries through the operator chain. Items without a trace
+    /// context, such as errors, get a span of their own.
+    ///
+    /// Requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    async fn subscribe_latest_traced<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        E: 'static,
+        T: TraceContext + Debug + Clone + 'static;
+}
+
+#[async_trait(?Send)]
+impl<S, T> SubscribeLatestExt<T> for S
+where
+    S: Stream<Item = T> + Unpin + 'static,
+    T: Debug + Clone + 'static,
+{
+    async fn subscribe_latest<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        E: 'static,
+        T: Debug + Clone + 'static,
+    {
+        subscribe_latest_with_span(
+            self,
+            on_next_func,
+            on_error_callback,
+            cancellation_token,
+            latest_span,
+        )
+        .await
+    }
+
+    #[cfg(feature = "tracing")]
+    async fn subscribe_latest_traced<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        E: 'static,
+        T: TraceContext + Debug + Clone + 'static,
+    {
+        subscribe_latest_with_span(
+            self,
+            on_next_func,
+            on_error_callback,
+            cancellation_token,
+            traced_latest_span,
+        )
+        .await
+    }
+}
+
+type HandlerFuture<E> = Pin<Box<dyn Future<Output = core::result::Result<(), E>>>>;
+
+/// Creates the `fluxion.subscribe_latest` span of a handler, ignoring any trace context
+/// of `item`.
+#[cfg(feature = "tracing")]
+fn latest_span<T>(_item: &T) -> HandlerSpan {
+    operator_span!("subscribe_latest")
+}
+
+#[cfg(not(feature = "tracing"))]
+fn latest_span<T>(_item: &T) -> HandlerSpan {}
+
+/// Creates the `fluxion.subscribe_latest` span of a handler as a child of the span of
+/// `item`.
+#[cfg(feature = "tracing")]
+fn traced_latest_span<T: TraceContext>(item: &T) -> HandlerSpan {
+    match item.trace_span() {
+        Some(parent) => operator_span!(parent: parent, "subscribe_latest"),
+        None => operator_span!("subscribe_latest"),
+    }
+}
+
+async fn subscribe_latest_with_span<S, T, F, Fut, E, OnError>(
+    mut stream: S,
+    on_next_func: F,
+    on_error_callback: OnError,
+    cancellation_token: Option<CancellationToken>,
+    handler_span: fn(&T) -> HandlerSpan,
+) -> Result<()>
+where
+    S: Stream<Item = T> + Unpin + 'static,
+    F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+    Fut: Future<Output = core::result::Result<(), E>> + 'static,
+    OnError: Fn(E) + Clone + 'static,
+    E: 'static,
+    T: Debug + Clone + 'static,
+{
+    let cancellation_token = cancellation_token.unwrap_or_default();
+    let mut latest: Option<T> = None;
+    let mut running: Option<HandlerFuture<E>> = None;
+    let mut stream_done = false;
+    let mut cancelled = pin!(cancellation_token.cancelled());
+
+    poll_fn(|cx| loop {
+        // Registers for a wake-up on cancellation while the stream is idle.
+        let _ = cancelled.as_mut().poll(cx);
+
+        while !stream_done && !cancellation_token.is_cancelled() {
+            match stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => latest = Some(item),
+                Poll::Ready(None) => stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if running.is_none() && !cancellation_token.is_cancelled() {
+            running = latest.take().map(|item| {
+                #[cfg(feature = "tracing")]
+                let span = handler_span(&item);
+                #[cfg(not(feature = "tracing"))]
+                handler_span(&item);
+
+                let handler = on_next_func(item, cancellation_token.clone());
+
+                #[cfg(feature = "tracing")]
+                let handler = {
+                    use tracing::Instrument;
+                    handler.instrument(span)
+                };
+
+                Box::pin(handler) as HandlerFuture<E>
+            });
+        }
+
+        let Some(handler) = running.as_mut() else {
+            return if stream_done || cancellation_token.is_cancelled() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            };
+        };
+
+        match handler.as_mut().poll(cx) {
+            Poll::Ready(result) => {
+                running = None;
+                if let Err(error) = result {
+                    on_error_callback(error);
+                }
+            }
+            Poll::Pending => return Poll::Pending,
+        }
+    })
+    .await;
+
+    Ok(())
+}
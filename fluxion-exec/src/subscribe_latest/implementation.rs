@@ -2,9 +2,12 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use crate::subscribe::implementation::HandlerSpan;
+use alloc::sync::Arc;
 use core::fmt::Debug;
+use core::future::Future;
 use event_listener::Event;
-use fluxion_core::FluxionTask;
+use fluxion_core::{CancellationToken, FluxionTask};
 use futures::lock::Mutex as FutureMutex;
 
 #[derive(Debug)]
@@ -40,12 +43,10 @@ impl<T> Context<T> {
         let mut state = self.state.lock().await;
         state.item.take().map_or_else(
             || {
-                #[cfg(feature = "tracing")]
-                tracing::error!(
-                    "subscribe_latest: get_item called with no current item; marking idle"
+                error!(
+                    operator = "subscribe_latest";
+                    "get_item called with no current item; marking idle"
                 );
-                #[cfg(not(feature = "tracing"))]
-                eprintln!("subscribe_latest: get_item called with no current item; marking idle");
                 state.is_processing = false;
                 None
             },
@@ -95,6 +96,72 @@ impl<T> Default for Context<T> {
     }
 }
 
+/// Creates the `fluxion.subscribe_latest` span of a handler, ignoring any trace context
+/// of `item`.
+#[cfg(feature = "tracing")]
+pub(crate) fn latest_span<T>(_item: &T) -> HandlerSpan {
+    operator_span!("subscribe_latest")
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn latest_span<T>(_item: &T) -> HandlerSpan {}
+
+/// Creates the `fluxion.subscribe_latest` span of a handler as a child of the span of
+/// `item`.
+#[cfg(feature = "tracing")]
+pub(crate) fn traced_latest_span<T: fluxion_core::TraceContext>(item: &T) -> HandlerSpan {
+    match item.trace_span() {
+        Some(parent) => operator_span!(parent: parent, "subscribe_latest"),
+        None => operator_span!("subscribe_latest"),
+    }
+}
+
+/// Runs the handler for the latest item until no newer item is waiting, then marks
+/// `state` idle. This is the body of the task spawned by `subscribe_latest`.
+pub(crate) async fn process_latest<T, F, Fut, E, OnError, H>(
+    state: Arc<Context<T>>,
+    on_next_func: F,
+    on_error_callback: OnError,
+    cancellation_token: CancellationToken,
+    task_cancel: CancellationToken,
+    handler_span: H,
+) where
+    T: Clone,
+    F: Fn(T, CancellationToken) -> Fut,
+    Fut: Future<Output = core::result::Result<(), E>>,
+    OnError: Fn(E),
+    H: Fn(&T) -> HandlerSpan,
+{
+    while let Some(item) = state.get_item().await {
+        if task_cancel.is_cancelled() || cancellation_token.is_cancelled() {
+            break;
+        }
+
+        let handler = on_next_func(item.clone(), cancellation_token.clone());
+
+        #[cfg(feature = "tracing")]
+        let result = {
+            use tracing::Instrument;
+            handler.instrument(handler_span(&item)).await
+        };
+        #[cfg(not(feature = "tracing"))]
+        let result = {
+            handler_span(&item);
+            handler.await
+        };
+
+        if let Err(error) = result {
+            on_error_callback(error);
+        }
+
+        if !state.finish_processing_and_check_for_next().await {
+            break;
+        }
+    }
+
+    state.notify_task_complete();
+}
+
 macro_rules! define_subscribe_latest_impl {
     (@step #[$attr:meta], $($bounds:tt)*) => {
         use alloc::sync::Arc;
@@ -103,7 +170,70 @@ macro_rules! define_subscribe_latest_impl {
         use core::future::Future;
         use fluxion_core::{FluxionTask, CancellationToken, Result};
         use futures::{Stream, StreamExt};
-        use crate::subscribe_latest::implementation::Context;
+        use crate::subscribe::implementation::HandlerSpan;
+        #[cfg(feature = "tracing")]
+        use crate::subscribe_latest::implementation::traced_latest_span;
+        use crate::subscribe_latest::implementation::{latest_span, process_latest, Context};
+        #[cfg(feature = "tracing")]
+        use fluxion_core::TraceContext;
+
+        async fn subscribe_latest_with_span<S, T, F, Fut, E, OnError>(
+            stream: S,
+            on_next_func: F,
+            on_error_callback: OnError,
+            cancellation_token: Option<CancellationToken>,
+            handler_span: fn(&T) -> HandlerSpan,
+        ) -> Result<()>
+        where
+            S: Stream<Item = T> + Unpin + $($bounds)* 'static,
+            F: Fn(T, CancellationToken) -> Fut + Clone + $($bounds)* 'static,
+            Fut: Future<Output = core::result::Result<(), E>> + $($bounds)* 'static,
+            OnError: Fn(E) + Clone + $($bounds)* 'static,
+            E: $($bounds)* 'static,
+            T: Debug + Clone + $($bounds)* 'static,
+        {
+            let state = Arc::new(Context::default());
+            let cancellation_token = cancellation_token.unwrap_or_default();
+            let state_for_wait = state.clone();
+
+            stream
+                .for_each(move |new_data| {
+                    let on_next_func = on_next_func.clone();
+                    let state = state.clone();
+                    let cancellation_token = cancellation_token.clone();
+                    let on_error_callback = on_error_callback.clone();
+                    async move {
+                        if cancellation_token.is_cancelled() {
+                            return;
+                        }
+
+                        if state.enqueue_and_try_start_processing(new_data).await {
+                            let state_for_task = state.clone();
+                            let on_next_func = on_next_func.clone();
+                            let on_error_callback = on_error_callback.clone();
+                            let cancellation_token = cancellation_token.clone();
+
+                            let task = FluxionTask::spawn(move |task_cancel| {
+                                process_latest(
+                                    state_for_task,
+                                    on_next_func,
+                                    on_error_callback,
+                                    cancellation_token,
+                                    task_cancel,
+                                    handler_span,
+                                )
+                            });
+
+                            *state.task.lock().await = Some(task);
+                        }
+                    }
+                })
+                .await;
+
+            state_for_wait.wait_for_processing_complete().await;
+
+            Ok(())
+        }
 
         #[$attr]
         pub trait SubscribeLatestExt<T>: Stream<Item = T> + Sized {
@@ -128,6 +258,7 @@ macro_rules! define_subscribe_latest_impl {
             /// # See Also
             ///
             /// - [`subscribe`](crate::SubscribeExt::subscribe) - Sequential processing of all items
+            /// - [`subscribe_latest_traced`](Self::subscribe_latest_traced) - Runs handlers in the trace of each item
             async fn subscribe_latest<F, Fut, E, OnError>(
                 self,
                 on_next_func: F,
@@ -140,6 +271,28 @@ macro_rules! define_subscribe_latest_impl {
                 OnError: Fn(E) + Clone + $($bounds)* 'static,
                 E: $($bounds)* 'static,
                 T: Debug + Clone + $($bounds)* 'static;
+
+            /// Subscribes like [`subscribe_latest`](Self::subscribe_latest), running each
+            /// handler in a `fluxion.subscribe_latest` span that is a child of the item's span.
+            ///
+            /// Use it with streams of [`Traced`](fluxion_core::Traced) items so the handler
+            /// joins the trace the item carries through the operator chain. Items without a
+            /// trace context, such as errors, get a span of their own.
+            ///
+            /// Requires the `tracing` feature.
+            #[cfg(feature = "tracing")]
+            async fn subscribe_latest_traced<F, Fut, E, OnError>(
+                self,
+                on_next_func: F,
+                on_error_callback: OnError,
+                cancellation_token: Option<CancellationToken>,
+            ) -> Result<()>
+            where
+                F: Fn(T, CancellationToken) -> Fut + Clone + $($bounds)* 'static,
+                Fut: Future<Output = core::result::Result<(), E>> + $($bounds)* 'static,
+                OnError: Fn(E) + Clone + $($bounds)* 'static,
+                E: $($bounds)* 'static,
+                T: TraceContext + Debug + Clone + $($bounds)* 'static;
         }
 
         #[$attr]
@@ -161,55 +314,38 @@ macro_rules! define_subscribe_latest_impl {
                 E: $($bounds)* 'static,
                 T: Debug + Clone + $($bounds)* 'static,
             {
-                let state = Arc::new(Context::default());
-                let cancellation_token = cancellation_token.unwrap_or_default();
-                let state_for_wait = state.clone();
-
-                self.for_each(move |new_data| {
-                    let on_next_func = on_next_func.clone();
-                    let state = state.clone();
-                    let cancellation_token = cancellation_token.clone();
-                    let on_error_callback = on_error_callback.clone();
-                    async move {
-                        if cancellation_token.is_cancelled() {
-                            return;
-                        }
-
-                        if state.enqueue_and_try_start_processing(new_data).await {
-                            let state_for_task = state.clone();
-                            let on_next_func = on_next_func.clone();
-                            let on_error_callback = on_error_callback.clone();
-                            let cancellation_token = cancellation_token.clone();
-
-                            let task = FluxionTask::spawn(|task_cancel| async move {
-                                while let Some(item) = state_for_task.get_item().await {
-                                    if task_cancel.is_cancelled() || cancellation_token.is_cancelled() {
-                                        break;
-                                    }
-
-                                    if let Err(error) =
-                                        on_next_func(item.clone(), cancellation_token.clone()).await
-                                    {
-                                        on_error_callback(error);
-                                    }
-
-                                    if !state_for_task.finish_processing_and_check_for_next().await {
-                                        break;
-                                    }
-                                }
-
-                                state_for_task.notify_task_complete();
-                            });
-
-                            *state.task.lock().await = Some(task);
-                        }
-                    }
-                })
-                .await;
-
-                state_for_wait.wait_for_processing_complete().await;
+                subscribe_latest_with_span(
+                    self,
+                    on_next_func,
+                    on_error_callback,
+                    cancellation_token,
+                    latest_span,
+                )
+                .await
+            }
 
-                Ok(())
+            #[cfg(feature = "tracing")]
+            async fn subscribe_latest_traced<F, Fut, E, OnError>(
+                self,
+                on_next_func: F,
+                on_error_callback: OnError,
+                cancellation_token: Option<CancellationToken>,
+            ) -> Result<()>
+            where
+                F: Fn(T, CancellationToken) -> Fut + Clone + $($bounds)* 'static,
+                Fut: Future<Output = core::result::Result<(), E>> + $($bounds)* 'static,
+                OnError: Fn(E) + Clone + $($bounds)* 'static,
+                E: $($bounds)* 'static,
+                T: TraceContext + Debug + Clone + $($bounds)* 'static,
+            {
+                subscribe_latest_with_span(
+                    self,
+                    on_next_func,
+                    on_error_callback,
+                    cancellation_token,
+                    traced_latest_span,
+                )
+                .await
             }
         }
     };
@@ -2,8 +2,14 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
 #[macro_use]
-mod implementation;
+pub(crate) mod implementation;
 
 #[cfg(any(
     all(feature = "runtime-tokio", not(target_arch = "wasm32")),
@@ -19,16 +25,36 @@ mod multi_threaded;
 ))]
 pub use multi_threaded::SubscribeLatestExt;
 
-#[cfg(not(any(
-    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
-    feature = "runtime-smol",
-    feature = "runtime-async-std"
-)))]
+#[cfg(all(
+    target_arch = "wasm32",
+    not(any(feature = "runtime-smol", feature = "runtime-async-std"))
+))]
 mod single_threaded;
 
-#[cfg(not(any(
-    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
-    feature = "runtime-smol",
-    feature = "runtime-async-std"
-)))]
+#[cfg(all(
+    target_arch = "wasm32",
+    not(any(feature = "runtime-smol", feature = "runtime-async-std"))
+))]
 pub use single_threaded::SubscribeLatestExt;
+
+#[cfg(all(
+    feature = "runtime-embassy",
+    not(target_arch = "wasm32"),
+    not(any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    ))
+))]
+mod embassy;
+
+#[cfg(all(
+    feature = "runtime-embassy",
+    not(target_arch = "wasm32"),
+    not(any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    ))
+))]
+pub use embassy::SubscribeLatestExt;
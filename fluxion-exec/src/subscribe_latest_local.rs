@@ -0,0 +1,106 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Latest-value subscription for handlers that are not `Send`.
+
+use crate::subscribe_latest::implementation::{latest_span, process_latest, Context};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use async_trait::async_trait;
+use core::fmt::Debug;
+use core::future::Future;
+use fluxion_core::{CancellationToken, FluxionTask, Result};
+use futures::{Stream, StreamExt};
+
+#[async_trait(?Send)]
+pub trait SubscribeLatestLocalExt<T>: Stream<Item = T> + Sized {
+    /// Subscribes to the stream like
+    /// [`subscribe_latest`](crate::SubscribeLatestExt::subscribe_latest), but without
+    /// requiring the stream, items, handlers or errors to be `Send`.
+    ///
+    /// Handlers run in a task spawned with
+    /// [`FluxionTask::spawn_local`]: with `wasm_bindgen_futures::spawn_local` on WASM,
+    /// and with `tokio::task::spawn_local` on Tokio, where the subscription must run
+    /// inside a `tokio::task::LocalSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_next_func` - Async function called for each item
+    /// * `on_error_callback` - Error handler for processing failures
+    /// * `cancellation_token` - Optional token to stop all processing
+    ///
+    /// # Panics
+    ///
+    /// On Tokio, panics if polled outside a `LocalSet`.
+    async fn subscribe_latest_local<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        E: 'static,
+        T: Debug + Clone + 'static;
+}
+
+#[async_trait(?Send)]
+impl<S, T> SubscribeLatestLocalExt<T> for S
+where
+    S: Stream<Item = T> + Unpin + 'static,
+    T: Debug + Clone + 'static,
+{
+    async fn subscribe_latest_local<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        E: 'static,
+        T: Debug + Clone + 'static,
+    {
+        let state = Arc::new(Context::default());
+        let cancellation_token = cancellation_token.unwrap_or_default();
+        let state_for_wait = state.clone();
+
+        self.for_each(move |new_data| {
+            let on_next_func = on_next_func.clone();
+            let state = state.clone();
+            let cancellation_token = cancellation_token.clone();
+            let on_error_callback = on_error_callback.clone();
+            async move {
+                if cancellation_token.is_cancelled() {
+                    return;
+                }
+
+                if state.enqueue_and_try_start_processing(new_data).await {
+                    let state_for_task = state.clone();
+                    let task = FluxionTask::spawn_local(|task_cancel| {
+                        process_latest(
+                            state_for_task,
+                            on_next_func,
+                            on_error_callback,
+                            cancellation_token,
+                            task_cancel,
+                            latest_span,
+                        )
+                    });
+
+                    *state.task.lock().await = Some(task);
+                }
+            }
+        })
+        .await;
+
+        state_for_wait.wait_for_processing_complete().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Sequential subscription for handlers that are not `Send`.
+
+use crate::subscribe::implementation::{subscribe_impl, subscribe_span};
+use alloc::boxed::Box;
+use async_trait::async_trait;
+use core::fmt::Debug;
+use core::future::Future;
+use fluxion_core::{CancellationToken, Result};
+use futures::stream::Stream;
+
+#[async_trait(?Send)]
+pub trait SubscribeLocalExt<T>: Stream<Item = T> + Sized {
+    /// Subscribes to the stream like [`subscribe`](crate::SubscribeExt::subscribe), but
+    /// without requiring the stream, items, handlers or errors to be `Send`.
+    ///
+    /// Use it to capture `Rc`/`RefCell` state, such as UI widgets, in handlers. The
+    /// returned future is not `Send` either, so drive it on the current thread: with
+    /// `wasm_bindgen_futures::spawn_local`, inside a `tokio::task::LocalSet`, or by
+    /// awaiting it directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_next_func` - Async function called for each stream item
+    /// * `on_error_callback` - Error handler called when handler returns an error
+    /// * `cancellation_token` - Optional token to stop processing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluxion_exec::SubscribeLocalExt;
+    /// use futures::stream;
+    /// use std::cell::RefCell;
+    /// use std::convert::Infallible;
+    /// use std::rc::Rc;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let rendered = Rc::new(RefCell::new(Vec::new()));
+    /// let sink = rendered.clone();
+    ///
+    /// stream::iter(vec![1, 2, 3])
+    ///     .subscribe_local(
+    ///         move |value, _| {
+    ///             let sink = sink.clone();
+    ///             async move {
+    ///                 sink.borrow_mut().push(value);
+    ///                 Ok::<(), Infallible>(())
+    ///             }
+    ///         },
+    ///         |_| {},
+    ///         None,
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*rendered.borrow(), vec![1, 2, 3]);
+    /// # });
+    /// ```
+    async fn subscribe_local<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        T: Debug + Clone + 'static,
+        E: 'static;
+}
+
+#[async_trait(?Send)]
+impl<S, T> SubscribeLocalExt<T> for S
+where
+    S: Stream<Item = T> + Unpin + 'static,
+    T: 'static,
+{
+    async fn subscribe_local<F, Fut, E, OnError>(
+        self,
+        on_next_func: F,
+        on_error_callback: OnError,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<()>
+    where
+        F: Fn(T, CancellationToken) -> Fut + Clone + 'static,
+        Fut: Future<Output = core::result::Result<(), E>> + 'static,
+        OnError: Fn(E) + Clone + 'static,
+        T: Debug + Clone + 'static,
+        E: 'static,
+    {
+        subscribe_impl(
+            self,
+            on_next_func,
+            on_error_callback,
+            cancellation_token,
+            subscribe_span,
+        )
+        .await
+    }
+}
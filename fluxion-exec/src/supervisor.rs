@@ -0,0 +1,399 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Supervision of long-running subscriptions with restart policies.
+//!
+//! A [`Supervisor`] owns a set of named children, each built by a factory that returns
+//! a subscription future, typically `stream.subscribe(...)`. Every child runs on its own
+//! task; when its subscription ends, the child's [`RestartPolicy`] decides whether the
+//! factory is called again, optionally after an exponential backoff.
+//!
+//! # Behavior
+//!
+//! - [`RestartPolicy::always`] restarts after both errors and completion
+//! - [`RestartPolicy::on_error`] restarts after errors; completion is final
+//! - [`RestartPolicy::max_restarts`] restarts after errors unless the child already
+//!   restarted `max` times within `window`, in which case it is marked failed
+//! - [`with_backoff`](RestartPolicy::with_backoff) waits before each restart, doubling
+//!   the delay after every consecutive error up to a maximum; a completion resets it
+//! - Without a backoff, children restart immediately after yielding to the executor
+//! - The factory receives a [`CancellationToken`] that is cancelled when the child is
+//!   stopped, to pass on to `subscribe`
+//! - Dropping the supervisor stops all children
+//!
+//! Only available with a multi-threaded runtime.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_exec::supervisor::{ChildState, RestartPolicy, Supervisor};
+//! use fluxion_core::FluxionError;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let supervisor = Supervisor::new();
+//! let attempts = Arc::new(AtomicUsize::new(0));
+//!
+//! let counter = attempts.clone();
+//! supervisor.supervise("ingest", RestartPolicy::on_error(), move |_cancel| {
+//!     let attempt = counter.fetch_add(1, Ordering::SeqCst);
+//!     async move {
+//!         // Fails twice, then completes
+//!         if attempt < 2 {
+//!             Err(FluxionError::stream_error("connection lost"))
+//!         } else {
+//!             Ok(())
+//!         }
+//!     }
+//! });
+//!
+//! supervisor.join().await;
+//!
+//! let child = &supervisor.children()[0];
+//! assert_eq!(child.state, ChildState::Completed);
+//! assert_eq!(child.restarts, 2);
+//! # }
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::future::{poll_fn, Future};
+use core::task::Poll;
+use core::time::Duration;
+use event_listener::Event;
+use fluxion_core::sync::Mutex;
+use fluxion_core::{CancellationToken, FluxionTask, Result};
+use fluxion_runtime::default_runtime::DefaultTimer;
+use fluxion_runtime::timer::Timer;
+use futures::future::{select, Either};
+
+type Instant = <DefaultTimer as Timer>::Instant;
+
+/// When a [`Supervisor`] calls the factory of a child again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RestartPolicy {
+    on_completion: bool,
+    limit: Option<(usize, Duration)>,
+    backoff: Option<(Duration, Duration)>,
+}
+
+impl RestartPolicy {
+    /// Restarts the child whenever its subscription ends, with or without an error.
+    pub fn always() -> Self {
+        Self {
+            on_completion: true,
+            limit: None,
+            backoff: None,
+        }
+    }
+
+    /// Restarts the child when its subscription ends with an error.
+    pub fn on_error() -> Self {
+        Self {
+            on_completion: false,
+            limit: None,
+            backoff: None,
+        }
+    }
+
+    /// Restarts the child when its subscription ends with an error, unless it already
+    /// restarted `max` times within the last `window`.
+    pub fn max_restarts(max: usize, window: Duration) -> Self {
+        Self {
+            on_completion: false,
+            limit: Some((max, window)),
+            backoff: None,
+        }
+    }
+
+    /// Waits `initial` before restarting the child and doubles the wait after each
+    /// further consecutive error, up to `max`.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = Some((initial, max));
+        self
+    }
+
+    fn delay(&self, consecutive_errors: u32) -> Duration {
+        match self.backoff {
+            Some((initial, max)) => initial
+                .checked_mul(1 << consecutive_errors.saturating_sub(1).min(31))
+                .map_or(max, |delay| delay.min(max)),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+/// Lifecycle state of a supervised child.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChildState {
+    /// The subscription is running.
+    Running,
+    /// The subscription ended and the child waits for its backoff before restarting.
+    BackingOff,
+    /// The subscription completed and the policy does not restart it.
+    Completed,
+    /// The subscription ended with an error and the policy gave up on it.
+    Failed,
+    /// The child was stopped.
+    Stopped,
+}
+
+/// Status of one supervised child, as returned by [`Supervisor::children`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChildStatus {
+    pub name: String,
+    pub state: ChildState,
+    /// Number of times the factory was called again.
+    pub restarts: usize,
+    /// Message of the last error the subscription ended with.
+    pub last_error: Option<String>,
+}
+
+/// Number of children in each state, as returned by [`Supervisor::status`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SupervisorStatus {
+    pub running: usize,
+    pub backing_off: usize,
+    pub completed: usize,
+    pub failed: usize,
+    pub stopped: usize,
+}
+
+impl SupervisorStatus {
+    /// Returns `true` if no child has failed.
+    pub fn is_healthy(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+struct Child {
+    status: ChildStatus,
+    task: Option<FluxionTask>,
+}
+
+/// Children and the event notified whenever one of them changes state.
+#[derive(Default)]
+struct Shared {
+    children: Mutex<Vec<Child>>,
+    changed: Event,
+}
+
+impl Shared {
+    fn update(&self, index: usize, update: impl FnOnce(&mut ChildStatus)) {
+        let mut children = self.children.lock();
+        let status = &mut children[index].status;
+        // The task of a stopped child may not have seen the cancellation yet
+        if status.state == ChildState::Stopped {
+            return;
+        }
+        update(status);
+        drop(children);
+        self.changed.notify(usize::MAX);
+    }
+}
+
+/// Owner of a set of subscriptions that restarts them according to their
+/// [`RestartPolicy`] and reports their aggregated status.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Default)]
+pub struct Supervisor {
+    shared: Arc<Shared>,
+}
+
+impl Supervisor {
+    /// Creates a supervisor without children.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a child that runs the subscription built by `factory` and restarts it
+    /// according to `policy`.
+    pub fn supervise<F, Fut>(&self, name: impl Into<String>, policy: RestartPolicy, factory: F)
+    where
+        F: Fn(CancellationToken) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let index = {
+            let mut children = self.shared.children.lock();
+            children.push(Child {
+                status: ChildStatus {
+                    name: name.into(),
+                    state: ChildState::Running,
+                    restarts: 0,
+                    last_error: None,
+                },
+                task: None,
+            });
+            children.len() - 1
+        };
+
+        let shared = Arc::clone(&self.shared);
+        let task =
+            FluxionTask::spawn(move |cancel| run_child(shared, index, policy, factory, cancel));
+        self.shared.children.lock()[index].task = Some(task);
+    }
+
+    /// Stops every child named `name`, returning `false` if there is none.
+    pub fn stop(&self, name: &str) -> bool {
+        let mut stopped = false;
+        for child in self.shared.children.lock().iter_mut() {
+            if child.status.name == name {
+                child.task.take();
+                if matches!(
+                    child.status.state,
+                    ChildState::Running | ChildState::BackingOff
+                ) {
+                    child.status.state = ChildState::Stopped;
+                }
+                stopped = true;
+            }
+        }
+        self.shared.changed.notify(usize::MAX);
+        stopped
+    }
+
+    /// Returns the status of every child, in the order they were supervised.
+    pub fn children(&self) -> Vec<ChildStatus> {
+        self.shared
+            .children
+            .lock()
+            .iter()
+            .map(|child| child.status.clone())
+            .collect()
+    }
+
+    /// Returns the number of children in each state.
+    pub fn status(&self) -> SupervisorStatus {
+        let mut status = SupervisorStatus::default();
+        for child in self.shared.children.lock().iter() {
+            match child.status.state {
+                ChildState::Running => status.running += 1,
+                ChildState::BackingOff => status.backing_off += 1,
+                ChildState::Completed => status.completed += 1,
+                ChildState::Failed => status.failed += 1,
+                ChildState::Stopped => status.stopped += 1,
+            }
+        }
+        status
+    }
+
+    /// Waits until no child is running or backing off.
+    pub async fn join(&self) {
+        loop {
+            let listener = self.shared.changed.listen();
+            let status = self.status();
+            if status.running == 0 && status.backing_off == 0 {
+                return;
+            }
+            listener.await;
+        }
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        // The child tasks hold the children, so they are cancelled explicitly
+        for child in self.shared.children.lock().iter_mut() {
+            child.task.take();
+        }
+    }
+}
+
+impl core::fmt::Debug for Supervisor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("children", &self.children())
+            .finish()
+    }
+}
+
+async fn run_child<F, Fut>(
+    shared: Arc<Shared>,
+    index: usize,
+    policy: RestartPolicy,
+    factory: F,
+    cancel: CancellationToken,
+) where
+    F: Fn(CancellationToken) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let timer = DefaultTimer::default();
+    let mut restarts_in_window: VecDeque<Instant> = VecDeque::new();
+    let mut consecutive_errors = 0;
+
+    loop {
+        let subscription = core::pin::pin!(factory(cancel.clone()));
+        let result = match select(subscription, core::pin::pin!(cancel.cancelled())).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => return,
+        };
+        if cancel.is_cancelled() {
+            return;
+        }
+
+        match result {
+            Ok(()) if !policy.on_completion => {
+                shared.update(index, |status| status.state = ChildState::Completed);
+                return;
+            }
+            Ok(()) => consecutive_errors = 0,
+            Err(error) => {
+                warn!(operator = "supervisor", child = index, error = error; "subscription failed");
+                consecutive_errors += 1;
+                shared.update(index, |status| status.last_error = Some(error.to_string()));
+            }
+        }
+
+        if let Some((max, window)) = policy.limit {
+            let now = timer.now();
+            while restarts_in_window
+                .front()
+                .is_some_and(|&restart| now - restart > window)
+            {
+                restarts_in_window.pop_front();
+            }
+            if restarts_in_window.len() >= max {
+                shared.update(index, |status| status.state = ChildState::Failed);
+                return;
+            }
+            restarts_in_window.push_back(now);
+        }
+
+        let delay = policy.delay(consecutive_errors);
+        if delay.is_zero() {
+            // A subscription that ends at once must not keep the executor to itself
+            yield_now().await;
+        } else {
+            shared.update(index, |status| status.state = ChildState::BackingOff);
+            let sleep = core::pin::pin!(timer.sleep_future(delay));
+            if let Either::Right(_) = select(sleep, core::pin::pin!(cancel.cancelled())).await {
+                return;
+            }
+        }
+
+        shared.update(index, |status| {
+            status.state = ChildState::Running;
+            status.restarts += 1;
+        });
+    }
+}
+
+async fn yield_now() {
+    let mut yielded = false;
+    poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await;
+}
@@ -0,0 +1,227 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::FluxionError;
+use fluxion_exec::shutdown::ShutdownCoordinator;
+use fluxion_exec::SubscribeExt;
+use futures::future::pending;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_shutdown_stops_sources_and_drains_subscriptions() -> anyhow::Result<()> {
+    // Arrange
+    let coordinator = ShutdownCoordinator::new();
+    let (tx, numbers) = futures::channel::mpsc::unbounded::<i32>();
+    let stop = coordinator.source("numbers");
+    for value in 1..=3 {
+        tx.unbounded_send(value)?;
+    }
+    tokio::spawn(async move {
+        // The producer closes its channel once stopped, leaving the buffered items
+        stop.cancelled().await;
+        drop(tx);
+    });
+    let processed = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = processed.clone();
+    coordinator.subscribe("slow", move |cancel| {
+        numbers.subscribe(
+            move |value, _| {
+                let seen = seen.clone();
+                async move {
+                    sleep(Duration::from_millis(20)).await;
+                    seen.lock().unwrap().push(value);
+                    Ok::<(), FluxionError>(())
+                }
+            },
+            |_| {},
+            Some(cancel),
+        )
+    });
+
+    // Act
+    let report = coordinator.shutdown(Duration::from_secs(1)).await;
+
+    // Assert
+    assert!(report.is_clean());
+    assert_eq!(report.drained, vec!["slow"]);
+    assert_eq!(*processed.lock().unwrap(), vec![1, 2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_cancels_subscriptions_after_deadline() -> anyhow::Result<()> {
+    // Arrange
+    let coordinator = ShutdownCoordinator::new();
+    let cancelled = Arc::new(AtomicBool::new(false));
+
+    let flag = cancelled.clone();
+    coordinator.subscribe("stuck", move |cancel| async move {
+        tokio::spawn(async move {
+            cancel.cancelled().await;
+            flag.store(true, Ordering::SeqCst);
+        });
+        pending::<fluxion_core::Result<()>>().await
+    });
+    coordinator.subscribe("done", |_| async { Ok(()) });
+    sleep(Duration::from_millis(10)).await;
+
+    // Act
+    let started = Instant::now();
+    let report = coordinator.shutdown(Duration::from_millis(50)).await;
+
+    // Assert
+    assert!(started.elapsed() >= Duration::from_millis(50));
+    assert!(!report.is_clean());
+    assert_eq!(report.cancelled, vec!["stuck"]);
+    assert_eq!(report.drained, vec!["done"]);
+    sleep(Duration::from_millis(10)).await;
+    assert!(cancelled.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_does_not_wait_for_deadline_once_drained() -> anyhow::Result<()> {
+    // Arrange
+    let coordinator = ShutdownCoordinator::new();
+    let stop = coordinator.source("ticks");
+    coordinator.subscribe("ticks", move |_| async move {
+        stop.cancelled().await;
+        Ok(())
+    });
+
+    // Act
+    let started = Instant::now();
+    let report = coordinator.shutdown(Duration::from_secs(5)).await;
+
+    // Assert
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(report.drained, vec!["ticks"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_flushes_after_draining_in_registration_order() -> anyhow::Result<()> {
+    // Arrange
+    let coordinator = ShutdownCoordinator::new();
+    let steps = Arc::new(Mutex::new(Vec::new()));
+    let stop = coordinator.source("events");
+
+    let log = steps.clone();
+    coordinator.subscribe("writer", move |_| async move {
+        stop.cancelled().await;
+        sleep(Duration::from_millis(20)).await;
+        log.lock().unwrap().push("drained");
+        Ok(())
+    });
+    for sink in ["database", "metrics"] {
+        let log = steps.clone();
+        coordinator.on_flush(sink, move || async move {
+            log.lock().unwrap().push(sink);
+            Ok(())
+        });
+    }
+
+    // Act
+    let report = coordinator.shutdown(Duration::from_secs(1)).await;
+
+    // Assert
+    assert!(report.is_clean());
+    assert_eq!(
+        *steps.lock().unwrap(),
+        vec!["drained", "database", "metrics"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_reports_failures() -> anyhow::Result<()> {
+    // Arrange
+    let coordinator = ShutdownCoordinator::new();
+    let flushed = Arc::new(AtomicUsize::new(0));
+    coordinator.subscribe("ingest", |_| async {
+        Err(FluxionError::stream_error("connection lost"))
+    });
+    coordinator.on_flush("database", || async {
+        Err(FluxionError::stream_error("disk full"))
+    });
+    let counter = flushed.clone();
+    coordinator.on_flush("metrics", move || async move {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    });
+    sleep(Duration::from_millis(10)).await;
+
+    // Act
+    let report = coordinator.shutdown(Duration::from_secs(1)).await;
+
+    // Assert
+    assert!(!report.is_clean());
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, "ingest");
+    assert!(report.failed[0].1.contains("connection lost"));
+    assert_eq!(report.flush_failures.len(), 1);
+    assert_eq!(report.flush_failures[0].0, "database");
+    assert!(report.flush_failures[0].1.contains("disk full"));
+    assert_eq!(flushed.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_drops_held_resources_last() -> anyhow::Result<()> {
+    // Arrange
+    struct Resource(Arc<AtomicBool>);
+
+    impl Drop for Resource {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let coordinator = ShutdownCoordinator::new();
+    let dropped = Arc::new(AtomicBool::new(false));
+    let dropped_at_flush = Arc::new(AtomicBool::new(true));
+    coordinator.hold(Resource(dropped.clone()));
+
+    let (at_flush, resource) = (dropped_at_flush.clone(), dropped.clone());
+    coordinator.on_flush("sink", move || async move {
+        at_flush.store(resource.load(Ordering::SeqCst), Ordering::SeqCst);
+        Ok(())
+    });
+
+    // Act
+    coordinator.shutdown(Duration::from_secs(1)).await;
+
+    // Assert
+    assert!(!dropped_at_flush.load(Ordering::SeqCst));
+    assert!(dropped.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dropping_coordinator_stops_sources() -> anyhow::Result<()> {
+    // Arrange
+    let coordinator = ShutdownCoordinator::new();
+    let stop = coordinator.source("numbers");
+    coordinator.subscribe("stuck", |_| pending());
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(coordinator.running(), 1);
+
+    // Act
+    drop(coordinator);
+
+    // Assert
+    assert!(stop.is_cancelled());
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! `subscribe_latest` as built for Embassy, where the handler runs inside the
+//! subscription future. Run with
+//! `cargo test -p fluxion-exec --no-default-features --features runtime-embassy`.
+
+#![cfg(all(
+    feature = "runtime-embassy",
+    not(feature = "runtime-tokio"),
+    not(feature = "runtime-smol"),
+    not(feature = "runtime-async-std")
+))]
+
+use fluxion_core::CancellationToken;
+use fluxion_exec::SubscribeLatestExt;
+use futures::channel::mpsc::unbounded;
+use futures::lock::Mutex as FutureMutex;
+use futures::{join, StreamExt};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, thiserror::Error)]
+#[error("Test error: {0}")]
+struct TestError(String);
+
+#[tokio::test]
+async fn test_subscribe_latest_embassy_skips_items_arriving_during_processing() -> anyhow::Result<()>
+{
+    // Arrange
+    let processed = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = unbounded::<u32>();
+    let (started_tx, mut started_rx) = unbounded::<()>();
+    let (release_tx, release_rx) = unbounded::<()>();
+    let release_rx = Rc::new(FutureMutex::new(release_rx));
+
+    let subscription = rx.subscribe_latest(
+        {
+            let processed = processed.clone();
+            move |item, _| {
+                let processed = processed.clone();
+                let started_tx = started_tx.clone();
+                let release_rx = release_rx.clone();
+                async move {
+                    let _ = started_tx.unbounded_send(());
+                    release_rx.lock().await.next().await;
+                    processed.borrow_mut().push(item);
+                    Ok::<(), TestError>(())
+                }
+            }
+        },
+        |_| {},
+        None,
+    );
+
+    // Act
+    let driver = async {
+        tx.unbounded_send(1).unwrap();
+        started_rx.next().await;
+        tx.unbounded_send(2).unwrap();
+        tx.unbounded_send(3).unwrap();
+        tx.close_channel();
+        release_tx.unbounded_send(()).unwrap();
+        release_tx.unbounded_send(()).unwrap();
+    };
+    let (result, ()) = join!(subscription, driver);
+
+    // Assert
+    result?;
+    assert_eq!(*processed.borrow(), vec![1, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_latest_embassy_reports_errors_and_continues() -> anyhow::Result<()> {
+    // Arrange
+    let processed = Rc::new(RefCell::new(Vec::new()));
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = unbounded::<u32>();
+    let (done_tx, mut done_rx) = unbounded::<()>();
+
+    let subscription = rx.subscribe_latest(
+        {
+            let processed = processed.clone();
+            move |item, _| {
+                let processed = processed.clone();
+                let done_tx = done_tx.clone();
+                async move {
+                    let _ = done_tx.unbounded_send(());
+                    if item % 2 == 0 {
+                        return Err(TestError(format!("even {item}")));
+                    }
+                    processed.borrow_mut().push(item);
+                    Ok(())
+                }
+            }
+        },
+        {
+            let errors = errors.clone();
+            move |error: TestError| errors.borrow_mut().push(error.0)
+        },
+        None,
+    );
+
+    // Act
+    let driver = async {
+        for item in 1..=3 {
+            tx.unbounded_send(item).unwrap();
+            done_rx.next().await;
+        }
+        tx.close_channel();
+    };
+    let (result, ()) = join!(subscription, driver);
+
+    // Assert
+    result?;
+    assert_eq!(*processed.borrow(), vec![1, 3]);
+    assert_eq!(*errors.borrow(), vec!["even 2".to_string()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_latest_embassy_stops_when_cancelled() -> anyhow::Result<()> {
+    // Arrange
+    let processed = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = unbounded::<u32>();
+    let (done_tx, mut done_rx) = unbounded::<()>();
+    let cancel = CancellationToken::new();
+
+    let subscription = rx.subscribe_latest(
+        {
+            let processed = processed.clone();
+            move |item, _| {
+                let processed = processed.clone();
+                let done_tx = done_tx.clone();
+                async move {
+                    processed.borrow_mut().push(item);
+                    let _ = done_tx.unbounded_send(());
+                    Ok::<(), TestError>(())
+                }
+            }
+        },
+        |_| {},
+        Some(cancel.clone()),
+    );
+
+    // Act
+    let driver = async {
+        tx.unbounded_send(1).unwrap();
+        done_rx.next().await;
+        cancel.cancel();
+    };
+    let (result, ()) = join!(subscription, driver);
+
+    // Assert
+    result?;
+    assert_eq!(*processed.borrow(), vec![1]);
+    assert!(
+        tx.is_closed(),
+        "the subscription should have dropped the stream"
+    );
+
+    Ok(())
+}
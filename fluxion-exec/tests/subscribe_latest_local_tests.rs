@@ -0,0 +1,107 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_exec::SubscribeLatestLocalExt;
+use fluxion_test_utils::test_data::{
+    person_alice, person_bob, person_charlie, person_dave, TestData,
+};
+use futures::channel::mpsc::unbounded;
+use futures::channel::oneshot;
+use futures::StreamExt;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tokio::task::{spawn_local, LocalSet};
+
+#[derive(Debug, thiserror::Error)]
+#[error("Test error: {0}")]
+struct TestError(String);
+
+#[tokio::test]
+async fn test_subscribe_latest_local_handler_captures_rc_state() -> anyhow::Result<()> {
+    LocalSet::new()
+        .run_until(async {
+            // Arrange
+            let rendered = Rc::new(RefCell::new(Vec::new()));
+            let sink = rendered.clone();
+            let (notify_tx, mut notify_rx) = unbounded::<()>();
+            let (tx, rx) = unbounded::<TestData>();
+
+            let subscription = spawn_local(rx.subscribe_latest_local(
+                move |item, _| {
+                    let sink = sink.clone();
+                    let notify_tx = notify_tx.clone();
+                    async move {
+                        sink.borrow_mut().push(item);
+                        let _ = notify_tx.unbounded_send(());
+                        Ok::<(), TestError>(())
+                    }
+                },
+                |_| {},
+                None,
+            ));
+
+            // Act
+            tx.unbounded_send(person_alice())?;
+            notify_rx.next().await.unwrap();
+            tx.unbounded_send(person_bob())?;
+            notify_rx.next().await.unwrap();
+            drop(tx);
+            subscription.await??;
+
+            // Assert
+            assert_eq!(*rendered.borrow(), vec![person_alice(), person_bob()]);
+
+            Ok(())
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_subscribe_latest_local_skips_items_superseded_while_busy() -> anyhow::Result<()> {
+    LocalSet::new()
+        .run_until(async {
+            // Arrange
+            let rendered = Rc::new(RefCell::new(Vec::new()));
+            let sink = rendered.clone();
+            let (started_tx, mut started_rx) = unbounded::<()>();
+            let (release_tx, release_rx) = oneshot::channel::<()>();
+            let release_rx = Rc::new(RefCell::new(Some(release_rx)));
+            let (tx, rx) = unbounded::<TestData>();
+
+            let subscription = spawn_local(rx.subscribe_latest_local(
+                move |item, _| {
+                    let sink = sink.clone();
+                    let started_tx = started_tx.clone();
+                    let release_rx = release_rx.borrow_mut().take();
+                    async move {
+                        let _ = started_tx.unbounded_send(());
+                        if let Some(release_rx) = release_rx {
+                            let _ = release_rx.await;
+                        }
+                        sink.borrow_mut().push(item);
+                        Ok::<(), TestError>(())
+                    }
+                },
+                |_| {},
+                None,
+            ));
+
+            // Act
+            tx.unbounded_send(person_alice())?;
+            started_rx.next().await.unwrap();
+            tx.unbounded_send(person_bob())?;
+            tx.unbounded_send(person_charlie())?;
+            tx.unbounded_send(person_dave())?;
+            tokio::task::yield_now().await;
+            release_tx.send(()).unwrap();
+            drop(tx);
+            subscription.await??;
+
+            // Assert
+            assert_eq!(*rendered.borrow(), vec![person_alice(), person_dave()]);
+
+            Ok(())
+        })
+        .await
+}
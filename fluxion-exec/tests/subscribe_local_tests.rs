@@ -0,0 +1,107 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::CancellationToken;
+use fluxion_exec::SubscribeLocalExt;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+use futures::channel::mpsc::unbounded;
+use futures::stream;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, thiserror::Error)]
+#[error("Test error: {0}")]
+struct TestError(String);
+
+#[tokio::test]
+async fn test_subscribe_local_handler_captures_rc_state() -> anyhow::Result<()> {
+    // Arrange
+    let rendered = Rc::new(RefCell::new(Vec::new()));
+    let sink = rendered.clone();
+    let stream = stream::iter(vec![person_alice(), person_bob(), animal_dog()]);
+
+    // Act
+    stream
+        .subscribe_local(
+            move |item: TestData, _| {
+                let sink = sink.clone();
+                async move {
+                    sink.borrow_mut().push(item);
+                    Ok::<(), TestError>(())
+                }
+            },
+            |_| {},
+            None,
+        )
+        .await?;
+
+    // Assert
+    assert_eq!(
+        *rendered.borrow(),
+        vec![person_alice(), person_bob(), animal_dog()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_local_reports_errors_to_non_send_callback() -> anyhow::Result<()> {
+    // Arrange
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let errors_sink = errors.clone();
+    let stream = stream::iter(vec![person_alice(), animal_dog(), person_bob()]);
+
+    // Act
+    stream
+        .subscribe_local(
+            |item: TestData, _| async move {
+                match item {
+                    TestData::Animal(_) => Err(TestError(item.to_string())),
+                    _ => Ok(()),
+                }
+            },
+            move |error: TestError| errors_sink.borrow_mut().push(error.0),
+            None,
+        )
+        .await?;
+
+    // Assert
+    assert_eq!(*errors.borrow(), vec![animal_dog().to_string()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_local_stops_after_cancellation() -> anyhow::Result<()> {
+    // Arrange
+    let processed = Rc::new(RefCell::new(Vec::new()));
+    let sink = processed.clone();
+    let token = CancellationToken::new();
+    let token_for_handler = token.clone();
+    let (tx, rx) = unbounded::<TestData>();
+    tx.unbounded_send(person_alice())?;
+    tx.unbounded_send(person_bob())?;
+    drop(tx);
+
+    // Act
+    rx.subscribe_local(
+        move |item, _| {
+            let sink = sink.clone();
+            let token = token_for_handler.clone();
+            async move {
+                sink.borrow_mut().push(item);
+                token.cancel();
+                Ok::<(), TestError>(())
+            }
+        },
+        |_| {},
+        Some(token),
+    )
+    .await?;
+
+    // Assert
+    assert_eq!(*processed.borrow(), vec![person_alice()]);
+
+    Ok(())
+}
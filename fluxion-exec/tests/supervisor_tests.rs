@@ -0,0 +1,281 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{CancellationToken, FluxionError};
+use fluxion_exec::supervisor::{ChildState, RestartPolicy, Supervisor, SupervisorStatus};
+use futures::future::pending;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::{sleep, timeout};
+
+/// Factory whose subscription fails `failures` times, then completes.
+fn failing_then_completing(
+    failures: usize,
+    attempts: Arc<AtomicUsize>,
+) -> impl Fn(CancellationToken) -> futures::future::Ready<fluxion_core::Result<()>> {
+    move |_| {
+        let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+        futures::future::ready(if attempt < failures {
+            Err(FluxionError::stream_error(format!(
+                "attempt {attempt} failed"
+            )))
+        } else {
+            Ok(())
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_supervisor_on_error_restarts_until_completion() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicUsize::new(0));
+
+    // Act
+    supervisor.supervise(
+        "ingest",
+        RestartPolicy::on_error(),
+        failing_then_completing(3, attempts.clone()),
+    );
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    // Assert
+    let child = &supervisor.children()[0];
+    assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    assert_eq!(child.name, "ingest");
+    assert_eq!(child.state, ChildState::Completed);
+    assert_eq!(child.restarts, 3);
+    assert!(child
+        .last_error
+        .as_deref()
+        .is_some_and(|error| error.contains("attempt 2 failed")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_always_restarts_after_completion() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let policy =
+        RestartPolicy::always().with_backoff(Duration::from_millis(5), Duration::from_millis(5));
+
+    // Act
+    supervisor.supervise(
+        "poller",
+        policy,
+        failing_then_completing(1, attempts.clone()),
+    );
+    timeout(Duration::from_secs(1), async {
+        while attempts.load(Ordering::SeqCst) < 5 {
+            sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await?;
+    let stopped = supervisor.stop("poller");
+
+    // Assert
+    assert!(stopped);
+    assert_eq!(supervisor.children()[0].state, ChildState::Stopped);
+    assert!(supervisor.children()[0].restarts >= 4);
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_max_restarts_marks_child_failed() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicUsize::new(0));
+
+    // Act
+    supervisor.supervise(
+        "flaky",
+        RestartPolicy::max_restarts(2, Duration::from_secs(10)),
+        failing_then_completing(usize::MAX, attempts.clone()),
+    );
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    // Assert
+    let child = &supervisor.children()[0];
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(child.state, ChildState::Failed);
+    assert_eq!(child.restarts, 2);
+    assert!(!supervisor.status().is_healthy());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_max_restarts_forgets_restarts_outside_window() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let policy = RestartPolicy::max_restarts(1, Duration::from_millis(20))
+        .with_backoff(Duration::from_millis(40), Duration::from_millis(40));
+
+    // Act
+    supervisor.supervise("slow", policy, failing_then_completing(4, attempts.clone()));
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    // Assert
+    let child = &supervisor.children()[0];
+    assert_eq!(child.state, ChildState::Completed);
+    assert_eq!(child.restarts, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_backoff_doubles_up_to_max() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let policy = RestartPolicy::on_error()
+        .with_backoff(Duration::from_millis(20), Duration::from_millis(40));
+    let start = Instant::now();
+
+    // Act
+    supervisor.supervise(
+        "backoff",
+        policy,
+        failing_then_completing(3, attempts.clone()),
+    );
+    sleep(Duration::from_millis(10)).await;
+    let backing_off = supervisor.status();
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    // Assert
+    assert_eq!(backing_off.backing_off, 1);
+    assert!(start.elapsed() >= Duration::from_millis(20 + 40 + 40));
+    assert_eq!(supervisor.children()[0].state, ChildState::Completed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_stop_cancels_running_subscription() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let token = Arc::new(std::sync::Mutex::new(None::<CancellationToken>));
+    let captured = token.clone();
+    supervisor.supervise("server", RestartPolicy::always(), move |cancel| {
+        *captured.lock().unwrap() = Some(cancel);
+        pending()
+    });
+    sleep(Duration::from_millis(20)).await;
+
+    // Act
+    let stopped = supervisor.stop("server");
+
+    // Assert
+    assert!(stopped);
+    assert!(!supervisor.stop("missing"));
+    assert!(token
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|t| t.is_cancelled()));
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_supervisor_stop_during_backoff_keeps_child_stopped() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let policy = RestartPolicy::on_error()
+        .with_backoff(Duration::from_millis(30), Duration::from_millis(30));
+    supervisor.supervise(
+        "backoff",
+        policy,
+        failing_then_completing(usize::MAX, attempts.clone()),
+    );
+    timeout(Duration::from_secs(1), async {
+        while supervisor.status().backing_off == 0 {
+            sleep(Duration::from_millis(1)).await;
+        }
+    })
+    .await?;
+
+    // Act
+    let stopped = supervisor.stop("backoff");
+    sleep(Duration::from_millis(60)).await;
+
+    // Assert
+    assert!(stopped);
+    let child = &supervisor.children()[0];
+    assert_eq!(child.state, ChildState::Stopped);
+    assert_eq!(child.restarts, 0);
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    timeout(Duration::from_secs(1), supervisor.join()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_status_aggregates_children() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+
+    // Act
+    supervisor.supervise("running", RestartPolicy::on_error(), |_| pending());
+    supervisor.supervise(
+        "completed",
+        RestartPolicy::on_error(),
+        failing_then_completing(0, Arc::default()),
+    );
+    supervisor.supervise(
+        "failed",
+        RestartPolicy::max_restarts(0, Duration::from_secs(1)),
+        failing_then_completing(1, Arc::default()),
+    );
+    supervisor.supervise("stopped", RestartPolicy::on_error(), |_| pending());
+    supervisor.stop("stopped");
+    sleep(Duration::from_millis(20)).await;
+
+    // Assert
+    assert_eq!(
+        supervisor.status(),
+        SupervisorStatus {
+            running: 1,
+            backing_off: 0,
+            completed: 1,
+            failed: 1,
+            stopped: 1,
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_supervisor_drop_cancels_children() -> anyhow::Result<()> {
+    // Arrange
+    let supervisor = Supervisor::new();
+    let token = Arc::new(std::sync::Mutex::new(None::<CancellationToken>));
+    let captured = token.clone();
+    supervisor.supervise("server", RestartPolicy::always(), move |cancel| {
+        *captured.lock().unwrap() = Some(cancel);
+        pending()
+    });
+    sleep(Duration::from_millis(20)).await;
+
+    // Act
+    drop(supervisor);
+
+    // Assert
+    assert!(token
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|t| t.is_cancelled()));
+
+    Ok(())
+}
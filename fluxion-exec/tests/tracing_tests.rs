@@ -0,0 +1,129 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg(feature = "tracing")]
+
+use fluxion_core::{CancellationToken, StreamItem, Traced};
+use fluxion_exec::subscribe::SubscribeExt;
+use fluxion_exec::subscribe_latest::SubscribeLatestExt;
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::span_recorder::SpanRecorder;
+use futures::stream;
+
+#[tokio::test]
+async fn test_subscribe_handler_span_records_operator() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let source = stream::iter(vec![1, 2]);
+
+    // Act
+    source
+        .subscribe(
+            |_item: i32, _ctx: CancellationToken| async move {
+                let _span = tracing::info_span!("handler").entered();
+                Ok::<(), std::io::Error>(())
+            },
+            |_| {},
+            None,
+        )
+        .await?;
+
+    // Assert
+    let spans = recorder.spans_named("fluxion.subscribe");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[0].field("operator"), Some("subscribe"));
+
+    let handler = recorder.find("handler").expect("handler span recorded");
+    assert_eq!(
+        recorder.ancestry(handler.id),
+        vec!["handler", "fluxion.subscribe"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_traced_parents_handler_span_to_item_span() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let source = stream::iter(vec![StreamItem::Value(Traced::new(Sequenced::new(1)))]);
+
+    // Act
+    source
+        .subscribe_traced(
+            |_item: StreamItem<Traced<Sequenced<i32>>>, _ctx: CancellationToken| async move {
+                let _span = tracing::info_span!("handler").entered();
+                Ok::<(), std::io::Error>(())
+            },
+            |_| {},
+            None,
+        )
+        .await?;
+
+    // Assert
+    let handler = recorder.find("handler").expect("handler span recorded");
+    assert_eq!(
+        recorder.ancestry(handler.id),
+        vec!["handler", "fluxion.subscribe", "fluxion.item"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_traced_error_gets_own_span() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let source = stream::iter(vec![StreamItem::<Traced<Sequenced<i32>>>::Error(
+        fluxion_core::FluxionError::stream_error("boom"),
+    )]);
+
+    // Act
+    source
+        .subscribe_traced(
+            |_item, _ctx: CancellationToken| async move { Ok::<(), std::io::Error>(()) },
+            |_| {},
+            None,
+        )
+        .await?;
+
+    // Assert
+    let spans = recorder.spans_named("fluxion.subscribe");
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].parent, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_latest_traced_parents_handler_span_to_item_span() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let source = stream::iter(vec![Traced::new(Sequenced::new(1))]);
+
+    // Act
+    source
+        .subscribe_latest_traced(
+            |_item: Traced<Sequenced<i32>>, _ctx: CancellationToken| async move {
+                let _span = tracing::info_span!("handler").entered();
+                Ok::<(), std::io::Error>(())
+            },
+            |_| {},
+            None,
+        )
+        .await?;
+
+    // Assert
+    let handler = recorder.find("handler").expect("handler span recorded");
+    assert_eq!(
+        recorder.ancestry(handler.id),
+        vec!["handler", "fluxion.subscribe_latest", "fluxion.item"]
+    );
+
+    Ok(())
+}
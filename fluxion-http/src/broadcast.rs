@@ -0,0 +1,110 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use fluxion_core::sync::Mutex;
+use fluxion_core::{FluxionSubject, FluxionTask, StreamItem};
+use futures::future::{select, Either};
+use futures::{stream, Stream, StreamExt};
+
+/// Replay buffer and live subject, locked together so that a new connection neither
+/// misses nor repeats a value.
+struct BroadcastState<T: Clone + Send + Sync + 'static> {
+    replay: VecDeque<T>,
+    capacity: usize,
+    subject: FluxionSubject<T>,
+}
+
+/// A stream shared by many connections, each starting with the latest values.
+///
+/// The source runs once on a background task, independent of how many connections
+/// subscribe. [`subscribe`](Self::subscribe) returns a stream that first emits the last
+/// `replay` values, then every value the source emits afterwards, so a browser that
+/// connects late still starts with the current state.
+///
+/// Errors are sent to the connections open at the time and are not replayed. When the
+/// source ends, open connections end and new ones receive the replay only. Dropping the
+/// broadcast stops the source.
+pub struct Broadcast<T: Clone + Send + Sync + 'static> {
+    state: Arc<Mutex<BroadcastState<T>>>,
+    _task: Arc<FluxionTask>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Broadcast<T> {
+    /// Starts draining `source`, keeping its last `replay` values for new connections.
+    pub fn new<S>(source: S, replay: usize) -> Self
+    where
+        S: Stream<Item = StreamItem<T>> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(BroadcastState {
+            replay: VecDeque::with_capacity(replay),
+            capacity: replay,
+            subject: FluxionSubject::new(),
+        }));
+        let task_state = Arc::clone(&state);
+
+        let task = FluxionTask::spawn(|cancel| async move {
+            let mut source = core::pin::pin!(source);
+            while let Either::Left((Some(item), _)) =
+                select(source.next(), cancel.cancelled()).await
+            {
+                let state = &mut *task_state.lock();
+                if let StreamItem::Value(value) = &item {
+                    if state.capacity > 0 {
+                        if state.replay.len() == state.capacity {
+                            state.replay.pop_front();
+                        }
+                        state.replay.push_back(value.clone());
+                    }
+                }
+                // Sending only fails once closed, which happens when the source ends
+                let _ = state.subject.send(item);
+            }
+            task_state.lock().subject.close();
+        });
+
+        Self {
+            state,
+            _task: Arc::new(task),
+        }
+    }
+
+    /// Returns a stream of the replayed values followed by the live ones.
+    pub fn subscribe(&self) -> impl Stream<Item = StreamItem<T>> + Send + 'static {
+        let state = self.state.lock();
+        let replay: Vec<_> = state
+            .replay
+            .iter()
+            .cloned()
+            .map(StreamItem::Value)
+            .collect();
+        let live = state.subject.subscribe().ok();
+        stream::iter(replay).chain(stream::iter(live).flatten())
+    }
+
+    /// Returns the number of open connections.
+    pub fn subscriber_count(&self) -> usize {
+        self.state.lock().subject.subscriber_count()
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Clone for Broadcast<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+            _task: Arc::clone(&self._task),
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Drop for Broadcast<T> {
+    fn drop(&mut self) {
+        // The last handle stops the task, which cannot close the subject once cancelled
+        if Arc::strong_count(&self._task) == 1 {
+            self.state.lock().subject.close();
+        }
+    }
+}
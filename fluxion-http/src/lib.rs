@@ -0,0 +1,76 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Axum integration for Fluxion streams.
+//!
+//! This crate publishes the output of a pipeline to browsers, as Server-Sent Events or
+//! over a WebSocket, from inside an [axum](https://docs.rs/axum) handler.
+//!
+//! # Responses
+//!
+//! - [`sse`] - a [`Sse`](axum::response::sse::Sse) response with one event per value,
+//!   built by a closure
+//! - [`sse_json`] - the same, with each value serialized to JSON as event data
+//! - [`send_to_websocket`] - sends each value over an upgraded WebSocket as the message
+//!   built by a closure
+//! - [`send_json_to_websocket`] - the same, with each value sent as a JSON text message
+//!
+//! Stream errors are sent as [`ERROR_EVENT`] events over SSE, and close a WebSocket with
+//! code 1011 and the error message as reason.
+//!
+//! # Sharing one pipeline
+//!
+//! Each connection consumes the stream it is given. To serve many connections from one
+//! pipeline, wrap it in a [`Broadcast`], which runs the pipeline once and hands every
+//! connection its own stream, starting with the last values so late connections begin
+//! with the current state.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use axum::extract::{State, WebSocketUpgrade};
+//! use axum::response::IntoResponse;
+//! use axum::routing::get;
+//! use axum::Router;
+//! use fluxion_http::{send_json_to_websocket, sse_json, Broadcast};
+//! use fluxion_test_utils::helpers::test_channel;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//!
+//! async fn events(State(prices): State<Broadcast<Sequenced<f64>>>) -> impl IntoResponse {
+//!     sse_json(prices.subscribe())
+//! }
+//!
+//! async fn ws(
+//!     State(prices): State<Broadcast<Sequenced<f64>>>,
+//!     upgrade: WebSocketUpgrade,
+//! ) -> impl IntoResponse {
+//!     upgrade.on_upgrade(move |socket| async move {
+//!         let _ = send_json_to_websocket(socket, prices.subscribe()).await;
+//!     })
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() -> std::io::Result<()> {
+//! let (tx, prices) = test_channel::<Sequenced<f64>>();
+//! # drop(tx);
+//! // Every connection starts with the latest price
+//! let prices = Broadcast::new(prices, 1);
+//!
+//! let app = Router::new()
+//!     .route("/prices/events", get(events))
+//!     .route("/prices/ws", get(ws))
+//!     .with_state(prices);
+//!
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await?;
+//! axum::serve(listener, app).await
+//! # }
+//! ```
+
+mod broadcast;
+mod sse;
+mod websocket;
+
+pub use broadcast::Broadcast;
+pub use sse::{sse, sse_json, ERROR_EVENT};
+pub use websocket::{send_json_to_websocket, send_to_websocket};
@@ -0,0 +1,62 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use axum::response::sse::{Event, KeepAlive, Sse};
+use core::convert::Infallible;
+use fluxion_core::{FluxionError, StreamItem, Timestamped};
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+
+/// Name of the SSE events that carry stream errors.
+pub const ERROR_EVENT: &str = "error";
+
+/// Response streaming every value of `stream` as a Server-Sent Event built by
+/// `to_event`.
+///
+/// Errors are sent as [`ERROR_EVENT`] events whose data is the error message, and the
+/// stream continues. The response sends keep-alive comments while the stream is idle
+/// and ends when the stream ends or the client disconnects.
+pub fn sse<S, T, F>(
+    stream: S,
+    mut to_event: F,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static>
+where
+    S: Stream<Item = StreamItem<T>> + Send + 'static,
+    T: Timestamped,
+    F: FnMut(T::Inner) -> Event + Send + 'static,
+{
+    Sse::new(stream.map(move |item| {
+        Ok(match item {
+            StreamItem::Value(value) => to_event(value.into_inner()),
+            StreamItem::Error(e) => error_event(&e),
+        })
+    }))
+    .keep_alive(KeepAlive::default())
+}
+
+/// Response streaming every value of `stream` as a Server-Sent Event whose data is
+/// the value serialized to JSON.
+///
+/// A value that fails to serialize is sent as an [`ERROR_EVENT`] event, like stream
+/// errors.
+pub fn sse_json<S, T>(
+    stream: S,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static>
+where
+    S: Stream<Item = StreamItem<T>> + Send + 'static,
+    T: Timestamped,
+    T::Inner: Serialize,
+{
+    sse(stream, |value| {
+        Event::default().json_data(value).unwrap_or_else(|e| {
+            error_event(&FluxionError::stream_error(format!(
+                "cannot serialize event: {e}"
+            )))
+        })
+    })
+}
+
+fn error_event(error: &FluxionError) -> Event {
+    Event::default().event(ERROR_EVENT).data(error.to_string())
+}
@@ -0,0 +1,124 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket};
+use core::pin::pin;
+use fluxion_core::{FluxionError, StreamItem, Timestamped};
+use futures::future::{select, Either};
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+
+/// Largest close reason in bytes, since a close frame payload holds at most 125 bytes
+/// including the 2-byte code.
+const MAX_CLOSE_REASON: usize = 123;
+
+/// What woke the sending loop up.
+enum Incoming<T> {
+    Item(Option<StreamItem<T>>),
+    Client(Option<Result<Message, axum::Error>>),
+}
+
+/// Sends every value of `stream` over `socket` as the message built by `to_message`,
+/// until the stream ends or the client disconnects.
+///
+/// When the stream ends, the socket is closed normally. A stream error closes it with
+/// code 1011 (internal error) and the error message as reason, since the client cannot
+/// tell an error message from a value otherwise. The reason is cut to the 123 bytes a
+/// close frame allows, on a character boundary. Messages from the client other than
+/// `Close` are ignored.
+///
+/// # Errors
+///
+/// Returns the error of a failed send, typically because the connection dropped.
+pub async fn send_to_websocket<S, T, F>(
+    socket: WebSocket,
+    stream: S,
+    mut to_message: F,
+) -> Result<(), axum::Error>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+    F: FnMut(T::Inner) -> Message,
+{
+    send_items(socket, stream, move |value| Ok(to_message(value))).await
+}
+
+/// Sends every value of `stream` over `socket` as a text message holding the value
+/// serialized to JSON, like [`send_to_websocket`].
+///
+/// A value that fails to serialize closes the socket like a stream error.
+///
+/// # Errors
+///
+/// Returns the error of a failed send, typically because the connection dropped.
+pub async fn send_json_to_websocket<S, T>(socket: WebSocket, stream: S) -> Result<(), axum::Error>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+    T::Inner: Serialize,
+{
+    send_items(socket, stream, |value| {
+        serde_json::to_string(&value)
+            .map(|json| Message::Text(json.into()))
+            .map_err(|e| FluxionError::stream_error(format!("cannot serialize message: {e}")))
+    })
+    .await
+}
+
+async fn send_items<S, T, F>(
+    mut socket: WebSocket,
+    stream: S,
+    mut to_message: F,
+) -> Result<(), axum::Error>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+    F: FnMut(T::Inner) -> Result<Message, FluxionError>,
+{
+    let mut stream = pin!(stream);
+
+    loop {
+        let incoming = match select(stream.next(), pin!(socket.recv())).await {
+            Either::Left((item, _)) => Incoming::Item(item),
+            Either::Right((message, _)) => Incoming::Client(message),
+        };
+
+        match incoming {
+            Incoming::Item(Some(StreamItem::Value(value))) => {
+                match to_message(value.into_inner()) {
+                    Ok(message) => socket.send(message).await?,
+                    Err(e) => return close_with_error(socket, &e).await,
+                }
+            }
+            Incoming::Item(Some(StreamItem::Error(e))) => {
+                return close_with_error(socket, &e).await;
+            }
+            Incoming::Item(None) => {
+                let frame = CloseFrame {
+                    code: close_code::NORMAL,
+                    reason: "".into(),
+                };
+                return socket.send(Message::Close(Some(frame))).await;
+            }
+            Incoming::Client(None | Some(Err(_)) | Some(Ok(Message::Close(_)))) => return Ok(()),
+            Incoming::Client(Some(Ok(_))) => {}
+        }
+    }
+}
+
+async fn close_with_error(mut socket: WebSocket, error: &FluxionError) -> Result<(), axum::Error> {
+    let mut reason = error.to_string();
+    if reason.len() > MAX_CLOSE_REASON {
+        let mut end = MAX_CLOSE_REASON;
+        while !reason.is_char_boundary(end) {
+            end -= 1;
+        }
+        reason.truncate(end);
+    }
+    let frame = CloseFrame {
+        code: close_code::ERROR,
+        reason: reason.into(),
+    };
+    socket.send(Message::Close(Some(frame))).await
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_http::Broadcast;
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, test_channel_with_errors,
+    unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::time::Duration;
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+}
+
+#[tokio::test]
+async fn test_broadcast_replays_latest_values_to_new_subscribers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let broadcast = Broadcast::new(stream, 2);
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+    tx.unbounded_send(Sequenced::new(3))?;
+    settle().await;
+
+    // Act
+    let mut late = Box::pin(broadcast.subscribe());
+    tx.unbounded_send(Sequenced::new(4))?;
+
+    // Assert
+    for expected in [2, 3, 4] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut late, 500).await)).into_inner(),
+            expected
+        );
+    }
+    assert_no_element_emitted(&mut late, 50).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_broadcast_without_replay_sends_live_values_to_every_subscriber() -> anyhow::Result<()>
+{
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let broadcast = Broadcast::new(stream, 0);
+    tx.unbounded_send(Sequenced::new(1))?;
+    settle().await;
+    let mut first = Box::pin(broadcast.subscribe());
+    let mut second = Box::pin(broadcast.clone().subscribe());
+
+    // Act
+    tx.unbounded_send(Sequenced::new(2))?;
+
+    // Assert
+    assert_eq!(broadcast.subscriber_count(), 2);
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut first, 500).await)).into_inner(),
+        2
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut second, 500).await)).into_inner(),
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_broadcast_forwards_errors_without_replaying_them() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let broadcast = Broadcast::new(stream, 5);
+    let mut live = Box::pin(broadcast.subscribe());
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("feed lost")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+    settle().await;
+    let mut late = Box::pin(broadcast.subscribe());
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut live, 500).await)).into_inner(),
+        1
+    );
+    assert!(matches!(
+        unwrap_stream(&mut live, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut live, 500).await)).into_inner(),
+        2
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut late, 500).await)).into_inner(),
+        1
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut late, 500).await)).into_inner(),
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_broadcast_ends_subscribers_when_source_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let broadcast = Broadcast::new(stream, 1);
+    let mut live = Box::pin(broadcast.subscribe());
+    tx.unbounded_send(Sequenced::new(1))?;
+
+    // Act
+    drop(tx);
+    settle().await;
+    let mut late = Box::pin(broadcast.subscribe());
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut live, 500).await)).into_inner(),
+        1
+    );
+    assert_stream_ended(&mut live, 500).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut late, 500).await)).into_inner(),
+        1
+    );
+    assert_stream_ended(&mut late, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_broadcast_drop_ends_subscribers() -> anyhow::Result<()> {
+    // Arrange
+    let (_tx, stream) = test_channel::<Sequenced<i32>>();
+    let broadcast = Broadcast::new(stream, 1);
+    let mut live = Box::pin(broadcast.subscribe());
+
+    // Act
+    drop(broadcast);
+
+    // Assert
+    assert_stream_ended(&mut live, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use axum::body::to_bytes;
+use axum::response::sse::Event;
+use axum::response::IntoResponse;
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_http::{sse, sse_json};
+use fluxion_test_utils::helpers::{test_channel, test_channel_with_errors};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::collections::BTreeMap;
+
+async fn body_of(response: impl IntoResponse) -> anyhow::Result<String> {
+    let body = to_bytes(response.into_response().into_body(), usize::MAX).await?;
+    Ok(String::from_utf8(body.to_vec())?)
+}
+
+#[tokio::test]
+async fn test_sse_sends_event_per_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+    drop(tx);
+
+    // Act
+    let response = sse(stream, |value| {
+        Event::default().event("count").data(value.to_string())
+    });
+
+    // Assert
+    assert_eq!(
+        body_of(response).await?,
+        "event: count\ndata: 1\n\nevent: count\ndata: 2\n\n"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sse_json_serializes_values() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<BTreeMap<String, i32>>>();
+    tx.unbounded_send(Sequenced::new(BTreeMap::from([("price".to_string(), 42)])))?;
+    drop(tx);
+
+    // Act
+    let response = sse_json(stream);
+
+    // Assert
+    assert_eq!(body_of(response).await?, "data: {\"price\":42}\n\n");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sse_sends_errors_as_error_events_and_continues() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("feed lost")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+    drop(tx);
+
+    // Act
+    let response = sse_json(stream);
+
+    // Assert
+    assert_eq!(
+        body_of(response).await?,
+        "data: 1\n\nevent: error\ndata: Stream processing error: feed lost\n\ndata: 2\n\n"
+    );
+
+    Ok(())
+}
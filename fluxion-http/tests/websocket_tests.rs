@@ -0,0 +1,171 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use axum::extract::ws::Message;
+use axum::extract::WebSocketUpgrade;
+use axum::routing::get;
+use axum::Router;
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_http::{send_json_to_websocket, send_to_websocket};
+use fluxion_test_utils::helpers::{test_channel, test_channel_with_errors};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::{SinkExt, Stream, StreamExt};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::Message as ClientMessage;
+
+/// Serves the JSON of `stream` at `/ws` on a local port, returning the URL.
+async fn serve_json<S>(stream: S) -> anyhow::Result<String>
+where
+    S: Stream<Item = StreamItem<Sequenced<i32>>> + Send + 'static,
+{
+    let stream = Arc::new(Mutex::new(Some(stream)));
+    let app = Router::new().route(
+        "/ws",
+        get(move |upgrade: WebSocketUpgrade| {
+            let stream = stream.lock().unwrap().take().unwrap();
+            async move {
+                upgrade.on_upgrade(move |socket| async move {
+                    let _ = send_json_to_websocket(socket, stream).await;
+                })
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}/ws", listener.local_addr()?);
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    Ok(url)
+}
+
+#[tokio::test]
+async fn test_send_json_to_websocket_sends_values_then_closes_normally() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let url = serve_json(stream).await?;
+    let (mut client, _) = tokio_tungstenite::connect_async(url).await?;
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+    drop(tx);
+    let messages: Vec<_> = timeout(Duration::from_secs(1), client.by_ref().collect::<Vec<_>>())
+        .await?
+        .into_iter()
+        .collect::<Result<_, _>>()?;
+
+    // Assert
+    assert_eq!(messages[0], ClientMessage::text("1"));
+    assert_eq!(messages[1], ClientMessage::text("2"));
+    match &messages[2] {
+        ClientMessage::Close(Some(frame)) => assert_eq!(frame.code, CloseCode::Normal),
+        other => panic!("expected close frame, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_json_to_websocket_closes_with_error_reason() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let url = serve_json(stream).await?;
+    let (mut client, _) = tokio_tungstenite::connect_async(url).await?;
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("feed lost")))?;
+    let first = timeout(Duration::from_secs(1), client.next()).await?;
+    let second = timeout(Duration::from_secs(1), client.next()).await?;
+
+    // Assert
+    assert_eq!(first.transpose()?, Some(ClientMessage::text("1")));
+    match second.transpose()? {
+        Some(ClientMessage::Close(Some(frame))) => {
+            assert_eq!(frame.code, CloseCode::Error);
+            assert_eq!(frame.reason.as_str(), "Stream processing error: feed lost");
+        }
+        other => panic!("expected close frame, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_json_to_websocket_truncates_long_error_reason() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let url = serve_json(stream).await?;
+    let (mut client, _) = tokio_tungstenite::connect_async(url).await?;
+
+    // Act
+    // "é" takes 2 bytes, so byte 123 falls inside a character
+    let message = "é".repeat(100);
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error(message)))?;
+    let closed = timeout(Duration::from_secs(1), client.next()).await?;
+
+    // Assert
+    match closed.transpose()? {
+        Some(ClientMessage::Close(Some(frame))) => {
+            let expected = format!("Stream processing error: {}", "é".repeat(49));
+            assert_eq!(frame.code, CloseCode::Error);
+            assert_eq!(frame.reason.as_str(), expected);
+        }
+        other => panic!("expected close frame, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_to_websocket_stops_when_client_closes() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let finished = Arc::new(Mutex::new(None));
+    let result = finished.clone();
+    let stream = Arc::new(Mutex::new(Some(stream)));
+    let app = Router::new().route(
+        "/ws",
+        get(move |upgrade: WebSocketUpgrade| {
+            let stream = stream.lock().unwrap().take().unwrap();
+            let result = result.clone();
+            async move {
+                upgrade.on_upgrade(move |socket| async move {
+                    let outcome = send_to_websocket(socket, stream, |value| {
+                        Message::Binary(value.to_le_bytes().to_vec().into())
+                    })
+                    .await;
+                    *result.lock().unwrap() = Some(outcome.is_ok());
+                })
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}/ws", listener.local_addr()?);
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    let (mut client, _) = tokio_tungstenite::connect_async(url).await?;
+    tx.unbounded_send(Sequenced::new(7))?;
+    let first = timeout(Duration::from_secs(1), client.next()).await?;
+
+    // Act
+    client.send(ClientMessage::text("ignored")).await?;
+    client.close(None).await?;
+    timeout(Duration::from_secs(1), async {
+        while finished.lock().unwrap().is_none() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await?;
+
+    // Assert
+    assert_eq!(
+        first.transpose()?,
+        Some(ClientMessage::binary(7i32.to_le_bytes().to_vec()))
+    );
+    assert_eq!(*finished.lock().unwrap(), Some(true));
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Operating-system sources for Fluxion streams.
+//!
+//! This crate turns files, directories, signals and child processes into timestamped
+//! streams that plug straight into `fluxion-stream` operators, for log-processing and
+//! file-ingestion pipelines and for services that shut down gracefully.
+//! Every item is an [`IoEvent`] stamped with the [`Instant`](std::time::Instant) it was
+//! observed, so it also works with the time-based operators of `fluxion-stream-time`.
+//!
+//! # Sources
+//!
+//! - [`tail`] / [`tail_with`] - the lines appended to a growing file, like `tail -F`,
+//!   following the file across rotation and truncation
+//! - [`watch`] - changes to a directory and its entries, as [`FsEvent`] items
+//! - [`watch_recursive`] - changes anywhere below a directory
+//! - [`signals`] - `SIGINT`, `SIGTERM` and `SIGHUP` as [`Signal`] items, to stop a
+//!   pipeline with `take_until`
+//! - [`ChildProcess`] - the stdout and stderr lines of a spawned command, separately or
+//!   merged into [`OutputLine`] items
+//!
+//! The sources run on Tokio. Dropping a stream closes the file, stops the watcher or
+//! kills the child process.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use fluxion_io::{tail, watch, FsEventKind};
+//! use fluxion_stream::prelude::*;
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> fluxion_core::Result<()> {
+//! // Error lines of a log, as they are written
+//! let mut errors = tail("/var/log/app.log").filter_ordered(|line| line.contains("ERROR"));
+//!
+//! // CSV files dropped into an inbox directory
+//! let mut arrivals = watch("/srv/inbox")?.filter_ordered(|event| {
+//!     event.kind == FsEventKind::Created
+//!         && event.paths.iter().any(|path| path.extension().is_some_and(|ext| ext == "csv"))
+//! });
+//!
+//! if let Some(line) = errors.next().await {
+//!     println!("{}", line.unwrap().value);
+//! }
+//! # drop(arrivals.next());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Graceful shutdown
+//!
+//! ```rust,no_run
+//! use fluxion_io::{ChildProcess, OutputSource};
+//! use futures::StreamExt;
+//! use std::process::Command;
+//!
+//! # async fn example() -> std::io::Result<()> {
+//! let mut shutdown = fluxion_io::signals()?;
+//! let mut server = Command::new("my-server");
+//! server.arg("--verbose");
+//!
+//! // Server log lines until Ctrl-C or SIGTERM
+//! let mut output = ChildProcess::spawn(server)?
+//!     .output()
+//!     .take_until(Box::pin(async move { shutdown.next().await }));
+//!
+//! while let Some(item) = output.next().await {
+//!     let line = item.unwrap().value;
+//!     if line.source == OutputSource::Stderr {
+//!         eprintln!("{}", line.line);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod process;
+mod signal;
+mod tail;
+mod watch;
+
+use fluxion_runtime::impls::tokio::TokioRuntime;
+use fluxion_stream_time::InstantTimestamped;
+
+pub use process::{ChildProcess, OutputLine, OutputSource};
+pub use signal::{signals, Signal};
+pub use tail::{tail, tail_with, TailConfig};
+pub use watch::{watch, watch_recursive, FsEvent, FsEventKind};
+
+/// A value read from the operating system, stamped with the time it was observed.
+pub type IoEvent<T> = InstantTimestamped<T, TokioRuntime>;
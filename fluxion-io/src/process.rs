@@ -0,0 +1,178 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::IoEvent;
+use fluxion_core::{FluxionError, StreamItem};
+use futures::{stream, Stream, StreamExt};
+use std::io;
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Child;
+
+/// The pipe an [`OutputLine`] was written to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OutputSource {
+    Stdout,
+    Stderr,
+}
+
+/// A line written by a child process, as emitted by [`ChildProcess::output`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OutputLine {
+    pub source: OutputSource,
+    pub line: String,
+}
+
+/// A running child process whose stdout and stderr are read as streams of lines.
+///
+/// Lines are emitted without their `\n` or `\r\n` terminator, with invalid UTF-8
+/// replaced by `U+FFFD`, stamped with the time they were read. The process is killed
+/// when the `ChildProcess` and the streams taken from it are dropped.
+pub struct ChildProcess {
+    child: Child,
+}
+
+impl ChildProcess {
+    /// Spawns `command` with its stdout and stderr piped, and its stdin closed unless
+    /// configured otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process cannot be spawned.
+    pub fn spawn(mut command: Command) -> io::Result<Self> {
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+        let mut command = tokio::process::Command::from(command);
+        command.kill_on_drop(true);
+        Ok(Self {
+            child: command.spawn()?,
+        })
+    }
+
+    /// Returns the OS-assigned process identifier, or `None` once the process was
+    /// waited for.
+    pub fn id(&self) -> Option<u32> {
+        self.child.id()
+    }
+
+    /// Takes the lines written to stdout, or `None` if they were already taken.
+    ///
+    /// The stream ends when the process closes its stdout. A read error is emitted as a
+    /// [`StreamProcessingError`](FluxionError::StreamProcessingError), then the stream
+    /// ends.
+    pub fn take_stdout(
+        &mut self,
+    ) -> Option<impl Stream<Item = StreamItem<IoEvent<String>>> + Send + Unpin> {
+        self.child
+            .stdout
+            .take()
+            .map(|stdout| lines(stdout, "stdout"))
+    }
+
+    /// Takes the lines written to stderr, or `None` if they were already taken, like
+    /// [`take_stdout`](Self::take_stdout).
+    pub fn take_stderr(
+        &mut self,
+    ) -> Option<impl Stream<Item = StreamItem<IoEvent<String>>> + Send + Unpin> {
+        self.child
+            .stderr
+            .take()
+            .map(|stderr| lines(stderr, "stderr"))
+    }
+
+    /// Merges the lines written to stdout and stderr in the order they were read, then
+    /// waits for the process to exit.
+    ///
+    /// The stream ends once both pipes are closed and the process has exited. An exit
+    /// with a failure status is emitted as a
+    /// [`StreamProcessingError`](FluxionError::StreamProcessingError) before the end.
+    /// Pipes already taken with [`take_stdout`](Self::take_stdout) or
+    /// [`take_stderr`](Self::take_stderr) are not read.
+    pub fn output(mut self) -> impl Stream<Item = StreamItem<IoEvent<OutputLine>>> + Send + Unpin {
+        let stdout = stream::iter(self.take_stdout()).flatten();
+        let stderr = stream::iter(self.take_stderr()).flatten();
+        let exit = stream::once(async move { self.wait().await })
+            .filter_map(|status| futures::future::ready(exit_error(status)));
+
+        Box::pin(
+            stream::select(
+                stdout.map(tag(OutputSource::Stdout)),
+                stderr.map(tag(OutputSource::Stderr)),
+            )
+            .chain(exit),
+        )
+    }
+
+    /// Waits for the process to exit, closing its stdin first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process cannot be waited for.
+    pub async fn wait(&mut self) -> io::Result<ExitStatus> {
+        self.child.wait().await
+    }
+
+    /// Kills the process and waits for it to exit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process cannot be killed, for example because it already
+    /// exited and was waited for.
+    pub async fn kill(&mut self) -> io::Result<()> {
+        self.child.kill().await
+    }
+}
+
+fn lines<R>(
+    reader: R,
+    pipe: &'static str,
+) -> impl Stream<Item = StreamItem<IoEvent<String>>> + Send + Unpin
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    Box::pin(stream::unfold(
+        Some((BufReader::new(reader), Vec::new())),
+        move |state| async move {
+            let (mut reader, mut buf) = state?;
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf).await {
+                Ok(0) => None,
+                Ok(_) => {
+                    let line = buf.strip_suffix(b"\n").unwrap_or(&buf);
+                    let line = line.strip_suffix(b"\r").unwrap_or(line);
+                    let line = String::from_utf8_lossy(line).into_owned();
+                    let item = StreamItem::Value(IoEvent::new(line, Instant::now()));
+                    Some((item, Some((reader, buf))))
+                }
+                Err(e) => {
+                    let error = FluxionError::stream_error(format!("child process {pipe}: {e}"));
+                    Some((StreamItem::Error(error), None))
+                }
+            }
+        },
+    ))
+}
+
+fn tag(
+    source: OutputSource,
+) -> impl Fn(StreamItem<IoEvent<String>>) -> StreamItem<IoEvent<OutputLine>> {
+    move |item| {
+        item.map(|line| {
+            let output = OutputLine {
+                source,
+                line: line.value,
+            };
+            IoEvent::new(output, line.timestamp)
+        })
+    }
+}
+
+fn exit_error(status: io::Result<ExitStatus>) -> Option<StreamItem<IoEvent<OutputLine>>> {
+    let message = match status {
+        Ok(status) if status.success() => return None,
+        Ok(status) => format!("child process exited with {status}"),
+        Err(e) => format!("child process: {e}"),
+    };
+    Some(StreamItem::Error(FluxionError::stream_error(message)))
+}
@@ -0,0 +1,121 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::IoEvent;
+use core::task::Poll;
+use fluxion_core::StreamItem;
+use futures::{stream, Stream};
+use std::io;
+use std::time::Instant;
+
+/// A shutdown-related signal sent to the process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Signal {
+    /// `SIGINT` on Unix, Ctrl-C or Ctrl-Break on Windows.
+    Interrupt,
+    /// `SIGTERM` on Unix, the console being closed or the system shutting down on
+    /// Windows.
+    Terminate,
+    /// `SIGHUP`, only raised on Unix.
+    Hangup,
+}
+
+/// Emits every `SIGINT`, `SIGTERM` and `SIGHUP` the process receives (Ctrl-C,
+/// Ctrl-Break, console close and shutdown on Windows), stamped with the time it
+/// arrived.
+///
+/// Once this is called, these signals no longer terminate the process, even after the
+/// stream is dropped, so the pipeline can shut down gracefully, typically with
+/// `take_until` on the next signal. The stream never ends.
+///
+/// # Errors
+///
+/// Returns an error if the signal handlers cannot be registered.
+pub fn signals() -> io::Result<impl Stream<Item = StreamItem<IoEvent<Signal>>> + Send + Unpin> {
+    let mut listeners = imp::Listeners::new()?;
+    Ok(stream::poll_fn(move |cx| match listeners.poll_recv(cx) {
+        Poll::Ready(signal) => Poll::Ready(Some(StreamItem::Value(IoEvent::new(
+            signal,
+            Instant::now(),
+        )))),
+        Poll::Pending => Poll::Pending,
+    }))
+}
+
+#[cfg(unix)]
+mod imp {
+    use super::Signal;
+    use core::task::{Context, Poll};
+    use std::io;
+    use tokio::signal::unix::{signal, SignalKind};
+
+    pub(super) struct Listeners {
+        listeners: [(Signal, tokio::signal::unix::Signal); 3],
+    }
+
+    impl Listeners {
+        pub(super) fn new() -> io::Result<Self> {
+            Ok(Self {
+                listeners: [
+                    (Signal::Interrupt, signal(SignalKind::interrupt())?),
+                    (Signal::Terminate, signal(SignalKind::terminate())?),
+                    (Signal::Hangup, signal(SignalKind::hangup())?),
+                ],
+            })
+        }
+
+        pub(super) fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Signal> {
+            for (kind, listener) in &mut self.listeners {
+                // Receivers only end when the runtime shuts down
+                if let Poll::Ready(Some(())) = listener.poll_recv(cx) {
+                    return Poll::Ready(*kind);
+                }
+            }
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::Signal;
+    use core::task::{Context, Poll};
+    use std::io;
+    use tokio::signal::windows::{ctrl_break, ctrl_c, ctrl_close, ctrl_shutdown};
+    use tokio::signal::windows::{CtrlBreak, CtrlC, CtrlClose, CtrlShutdown};
+
+    pub(super) struct Listeners {
+        ctrl_c: CtrlC,
+        ctrl_break: CtrlBreak,
+        ctrl_close: CtrlClose,
+        ctrl_shutdown: CtrlShutdown,
+    }
+
+    impl Listeners {
+        pub(super) fn new() -> io::Result<Self> {
+            Ok(Self {
+                ctrl_c: ctrl_c()?,
+                ctrl_break: ctrl_break()?,
+                ctrl_close: ctrl_close()?,
+                ctrl_shutdown: ctrl_shutdown()?,
+            })
+        }
+
+        pub(super) fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Signal> {
+            if let Poll::Ready(Some(())) = self.ctrl_c.poll_recv(cx) {
+                return Poll::Ready(Signal::Interrupt);
+            }
+            if let Poll::Ready(Some(())) = self.ctrl_break.poll_recv(cx) {
+                return Poll::Ready(Signal::Interrupt);
+            }
+            if let Poll::Ready(Some(())) = self.ctrl_close.poll_recv(cx) {
+                return Poll::Ready(Signal::Terminate);
+            }
+            if let Poll::Ready(Some(())) = self.ctrl_shutdown.poll_recv(cx) {
+                return Poll::Ready(Signal::Terminate);
+            }
+            Poll::Pending
+        }
+    }
+}
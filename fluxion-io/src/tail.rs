@@ -0,0 +1,213 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::IoEvent;
+use fluxion_core::{FluxionError, StreamItem};
+use futures::{stream, Stream};
+use std::collections::VecDeque;
+use std::fs::Metadata;
+use std::io::{self, SeekFrom};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// How [`tail_with`] follows a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TailConfig {
+    poll_interval: Duration,
+    from_start: bool,
+}
+
+impl TailConfig {
+    /// Checks the file for new lines every 250 ms, starting at its current end.
+    pub fn new() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(250),
+            from_start: false,
+        }
+    }
+
+    /// Checks the file for new lines every `interval`.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Emits the lines already in the file before the ones appended later.
+    pub fn from_start(mut self) -> Self {
+        self.from_start = true;
+        self
+    }
+}
+
+impl Default for TailConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Follows the file at `path` like `tail -F`, emitting every line appended to it.
+///
+/// Equivalent to [`tail_with`] with the default [`TailConfig`].
+pub fn tail(
+    path: impl Into<PathBuf>,
+) -> impl Stream<Item = StreamItem<IoEvent<String>>> + Send + Unpin {
+    tail_with(path, TailConfig::default())
+}
+
+/// Follows the file at `path` like `tail -F`, emitting every line appended to it with
+/// the time it was read.
+///
+/// - Lines are emitted without their `\n` or `\r\n` terminator, and invalid UTF-8 is
+///   replaced with `U+FFFD`; a line is held back until its terminator is written
+/// - If the file does not exist yet, the stream waits for it to be created and follows
+///   it from its start
+/// - When the file is rotated (renamed or removed and created again) or truncated, the
+///   rest of the old file is emitted and the new one is followed from its start; a
+///   final unterminated line of the old file is emitted as is
+/// - Any other I/O error is emitted as a
+///   [`StreamProcessingError`](FluxionError::StreamProcessingError), then the stream
+///   ends
+pub fn tail_with(
+    path: impl Into<PathBuf>,
+    config: TailConfig,
+) -> impl Stream<Item = StreamItem<IoEvent<String>>> + Send + Unpin {
+    let tail = Tail {
+        path: path.into(),
+        config,
+        file: None,
+        from_start: config.from_start,
+        partial: Vec::new(),
+        lines: VecDeque::new(),
+    };
+
+    Box::pin(stream::unfold(Some(tail), |tail| async move {
+        let mut tail = tail?;
+        match tail.next_line().await {
+            Ok(line) => Some((StreamItem::Value(line), Some(tail))),
+            Err(e) => {
+                let error =
+                    FluxionError::stream_error(format!("tail {}: {e}", tail.path.display()));
+                Some((StreamItem::Error(error), None))
+            }
+        }
+    }))
+}
+
+/// The file currently followed, and what identifies it on disk.
+struct OpenFile {
+    file: File,
+    id: Option<(u64, u64)>,
+    position: u64,
+}
+
+struct Tail {
+    path: PathBuf,
+    config: TailConfig,
+    file: Option<OpenFile>,
+    // Whether the next file opened is read from its start, which holds for every file
+    // except one already at the path when tailing begins
+    from_start: bool,
+    partial: Vec<u8>,
+    lines: VecDeque<IoEvent<String>>,
+}
+
+impl Tail {
+    async fn next_line(&mut self) -> io::Result<IoEvent<String>> {
+        loop {
+            if let Some(line) = self.lines.pop_front() {
+                return Ok(line);
+            }
+            self.poll_file().await?;
+            if self.lines.is_empty() {
+                tokio::time::sleep(self.config.poll_interval).await;
+            }
+        }
+    }
+
+    async fn poll_file(&mut self) -> io::Result<()> {
+        if self.file.is_none() && !self.open().await? {
+            return Ok(());
+        }
+        let Some(open) = &mut self.file else {
+            return Ok(());
+        };
+
+        let mut buf = Vec::new();
+        open.position += open.file.read_to_end(&mut buf).await? as u64;
+        let replaced = match tokio::fs::metadata(&self.path).await {
+            Ok(metadata) => file_id(&metadata) != open.id || metadata.len() < open.position,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => true,
+            Err(e) => return Err(e),
+        };
+
+        self.push_bytes(&buf);
+        if replaced {
+            self.file = None;
+            if !self.partial.is_empty() {
+                let line = core::mem::take(&mut self.partial);
+                self.push_line(&line);
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens the file at the path, returning `false` if it does not exist.
+    async fn open(&mut self) -> io::Result<bool> {
+        let opened = File::open(&self.path).await;
+        let from_start = core::mem::replace(&mut self.from_start, true);
+        let mut file = match opened {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        let metadata = file.metadata().await?;
+        let position = if from_start {
+            0
+        } else {
+            file.seek(SeekFrom::End(0)).await?
+        };
+        self.file = Some(OpenFile {
+            file,
+            id: file_id(&metadata),
+            position,
+        });
+        Ok(true)
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        let mut rest = bytes;
+        while let Some(end) = rest.iter().position(|&b| b == b'\n') {
+            let (line, after) = rest.split_at(end);
+            rest = &after[1..];
+            if self.partial.is_empty() {
+                self.push_line(line);
+            } else {
+                let mut joined = core::mem::take(&mut self.partial);
+                joined.extend_from_slice(line);
+                self.push_line(&joined);
+            }
+        }
+        self.partial.extend_from_slice(rest);
+    }
+
+    fn push_line(&mut self, line: &[u8]) {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let line = String::from_utf8_lossy(line).into_owned();
+        self.lines.push_back(IoEvent::new(line, Instant::now()));
+    }
+}
+
+#[cfg(unix)]
+fn file_id(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+// Without a stable file identity, rotation is only detected when the new file is shorter
+#[cfg(not(unix))]
+fn file_id(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
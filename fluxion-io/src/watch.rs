@@ -0,0 +1,105 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::IoEvent;
+use fluxion_core::{FluxionError, StreamItem};
+use futures::channel::mpsc;
+use futures::{future, Stream, StreamExt};
+use notify::event::ModifyKind;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// What happened to the paths of an [`FsEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FsEventKind {
+    /// A file or directory was created.
+    Created,
+    /// The content or metadata of a file or directory changed.
+    Modified,
+    /// A file or directory was renamed; when both names are known, the event holds the
+    /// old path followed by the new one.
+    Renamed,
+    /// A file or directory was removed.
+    Removed,
+    /// The platform reported a change it could not classify.
+    Other,
+}
+
+/// A change to the watched paths, as reported by the platform.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FsEvent {
+    pub kind: FsEventKind,
+    pub paths: Vec<PathBuf>,
+}
+
+/// Watches the directory or file at `path`, emitting every change to it or to the
+/// entries directly inside it.
+///
+/// # Errors
+///
+/// Returns a [`StreamProcessingError`](FluxionError::StreamProcessingError) if the path
+/// cannot be watched, for example because it does not exist.
+pub fn watch(
+    path: impl AsRef<Path>,
+) -> Result<impl Stream<Item = StreamItem<IoEvent<FsEvent>>> + Send + Unpin, FluxionError> {
+    watch_with_mode(path.as_ref(), RecursiveMode::NonRecursive)
+}
+
+/// Watches the directory at `path` and everything below it, like [`watch`].
+///
+/// # Errors
+///
+/// Returns a [`StreamProcessingError`](FluxionError::StreamProcessingError) if the path
+/// cannot be watched, for example because it does not exist.
+pub fn watch_recursive(
+    path: impl AsRef<Path>,
+) -> Result<impl Stream<Item = StreamItem<IoEvent<FsEvent>>> + Send + Unpin, FluxionError> {
+    watch_with_mode(path.as_ref(), RecursiveMode::Recursive)
+}
+
+fn watch_with_mode(
+    path: &Path,
+    mode: RecursiveMode,
+) -> Result<impl Stream<Item = StreamItem<IoEvent<FsEvent>>> + Send + Unpin, FluxionError> {
+    let watch_error =
+        |e: notify::Error| FluxionError::stream_error(format!("watch {}: {e}", path.display()));
+
+    let (tx, rx) = mpsc::unbounded();
+    // Events are stamped in the watcher callback, as close to the change as possible
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        let _ = tx.unbounded_send((result, Instant::now()));
+    })
+    .map_err(watch_error)?;
+    watcher.watch(path, mode).map_err(watch_error)?;
+
+    Ok(rx.filter_map(move |(result, timestamp)| {
+        // The stream owns the watcher, which stops watching when dropped
+        let _watcher = &watcher;
+        future::ready(match result {
+            Ok(event) => fs_event_kind(event.kind).map(|kind| {
+                let event = FsEvent {
+                    kind,
+                    paths: event.paths,
+                };
+                StreamItem::Value(IoEvent::new(event, timestamp))
+            }),
+            Err(e) => Some(StreamItem::Error(FluxionError::stream_error(format!(
+                "watch: {e}"
+            )))),
+        })
+    }))
+}
+
+/// Maps a notify event kind, skipping accesses, which change nothing.
+fn fs_event_kind(kind: EventKind) -> Option<FsEventKind> {
+    match kind {
+        EventKind::Access(_) => None,
+        EventKind::Create(_) => Some(FsEventKind::Created),
+        EventKind::Modify(ModifyKind::Name(_)) => Some(FsEventKind::Renamed),
+        EventKind::Modify(_) => Some(FsEventKind::Modified),
+        EventKind::Remove(_) => Some(FsEventKind::Removed),
+        EventKind::Any | EventKind::Other => Some(FsEventKind::Other),
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg(unix)]
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_io::{ChildProcess, OutputLine, OutputSource};
+use fluxion_test_utils::helpers::{assert_stream_ended, unwrap_stream, unwrap_value};
+use futures::StreamExt;
+use std::process::Command;
+use std::time::Duration;
+
+fn shell(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+fn stdout(line: &str) -> OutputLine {
+    OutputLine {
+        source: OutputSource::Stdout,
+        line: line.to_string(),
+    }
+}
+
+fn stderr(line: &str) -> OutputLine {
+    OutputLine {
+        source: OutputSource::Stderr,
+        line: line.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_child_process_take_stdout_and_stderr_separately() -> anyhow::Result<()> {
+    // Arrange
+    let mut child = ChildProcess::spawn(shell("echo out; echo err >&2; printf 'last'"))?;
+
+    // Act
+    let mut out = child.take_stdout().unwrap();
+    let mut err = child.take_stderr().unwrap();
+
+    // Assert
+    assert!(child.take_stdout().is_none());
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut out, 1000).await)).value,
+        "out"
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut out, 1000).await)).value,
+        "last"
+    );
+    assert_stream_ended(&mut out, 1000).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut err, 1000).await)).value,
+        "err"
+    );
+    assert_stream_ended(&mut err, 1000).await;
+    assert!(child.wait().await?.success());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_child_process_output_merges_pipes_in_order() -> anyhow::Result<()> {
+    // Arrange
+    let child = ChildProcess::spawn(shell(
+        "echo one; sleep 0.05; echo two >&2; sleep 0.05; printf 'three\\r\\n'",
+    ))?;
+
+    // Act
+    let lines: Vec<_> =
+        tokio::time::timeout(Duration::from_secs(5), child.output().collect::<Vec<_>>())
+            .await?
+            .into_iter()
+            .map(|item| item.unwrap())
+            .collect();
+
+    // Assert
+    assert_eq!(
+        lines
+            .iter()
+            .map(|line| line.value.clone())
+            .collect::<Vec<_>>(),
+        vec![stdout("one"), stderr("two"), stdout("three")]
+    );
+    assert!(lines
+        .windows(2)
+        .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_child_process_output_emits_error_on_failure_status() -> anyhow::Result<()> {
+    // Arrange
+    let child = ChildProcess::spawn(shell("echo partial; exit 3"))?;
+
+    // Act
+    let mut output = child.output();
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut output, 1000).await)).value,
+        stdout("partial")
+    );
+    match unwrap_stream(&mut output, 1000).await {
+        StreamItem::Error(FluxionError::StreamProcessingError { context }) => {
+            assert!(context.contains("exit status: 3"), "{context}");
+        }
+        other => panic!("expected exit error, got {other:?}"),
+    }
+    assert_stream_ended(&mut output, 1000).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_child_process_kill_ends_output() -> anyhow::Result<()> {
+    // Arrange
+    let mut child = ChildProcess::spawn(shell("echo ready; exec sleep 30"))?;
+    let mut out = child.take_stdout().unwrap();
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut out, 1000).await)).value,
+        "ready"
+    );
+
+    // Act
+    child.kill().await?;
+
+    // Assert
+    assert_stream_ended(&mut out, 1000).await;
+    assert!(child.id().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_child_process_spawn_missing_program_fails() {
+    // Arrange
+    let command = Command::new("fluxion-io-missing-program");
+
+    // Act
+    let result = tokio::runtime::Runtime::new()
+        .map(|runtime| runtime.block_on(async { ChildProcess::spawn(command).map(|_| ()) }));
+
+    // Assert
+    assert!(matches!(result, Ok(Err(_))));
+}
@@ -0,0 +1,44 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg(unix)]
+
+use fluxion_io::{signals, Signal};
+use fluxion_test_utils::helpers::{assert_no_element_emitted, unwrap_stream, unwrap_value};
+use futures::StreamExt;
+use std::process::Command;
+use std::time::Instant;
+
+fn raise(signal: &str) -> anyhow::Result<()> {
+    let status = Command::new("kill")
+        .args([signal, &std::process::id().to_string()])
+        .status()?;
+    anyhow::ensure!(status.success(), "kill {signal} failed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_signals_emits_received_signals_and_stops_pipeline() -> anyhow::Result<()> {
+    // Arrange
+    let mut shutdown = signals()?;
+    let before = Instant::now();
+    assert_no_element_emitted(&mut shutdown, 50).await;
+
+    // Act
+    raise("-HUP")?;
+    let hangup = unwrap_value(Some(unwrap_stream(&mut shutdown, 1000).await));
+    raise("-TERM")?;
+    let mut pipeline =
+        futures::stream::pending::<()>().take_until(Box::pin(async move { shutdown.next().await }));
+
+    // Assert
+    assert_eq!(hangup.value, Signal::Hangup);
+    assert!(hangup.timestamp >= before);
+    assert_eq!(
+        tokio::time::timeout(std::time::Duration::from_secs(1), pipeline.next()).await?,
+        None
+    );
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_io::{tail_with, IoEvent, TailConfig};
+use fluxion_test_utils::helpers::{assert_no_element_emitted, unwrap_stream, unwrap_value};
+use futures::Stream;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn test_dir(test: &str) -> anyhow::Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("fluxion-io-tail-{test}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn append(path: &Path, text: &str) -> anyhow::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(text.as_bytes())?;
+    Ok(())
+}
+
+fn config() -> TailConfig {
+    TailConfig::new().with_poll_interval(Duration::from_millis(10))
+}
+
+async fn next_line<S>(lines: &mut S) -> String
+where
+    S: Stream<Item = StreamItem<IoEvent<String>>> + Unpin,
+{
+    unwrap_value(Some(unwrap_stream(lines, 1000).await)).value
+}
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[tokio::test]
+async fn test_tail_emits_appended_lines_only() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("appended")?;
+    let path = dir.join("app.log");
+    append(&path, "old\n")?;
+    let mut lines = tail_with(&path, config());
+    assert_no_element_emitted(&mut lines, 50).await;
+
+    // Act
+    append(&path, "first\nsecond\r\n")?;
+
+    // Assert
+    assert_eq!(next_line(&mut lines).await, "first");
+    assert_eq!(next_line(&mut lines).await, "second");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_from_start_emits_existing_lines() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("from_start")?;
+    let path = dir.join("app.log");
+    append(&path, "one\ntwo\n")?;
+
+    // Act
+    let mut lines = tail_with(&path, config().from_start());
+
+    // Assert
+    assert_eq!(next_line(&mut lines).await, "one");
+    assert_eq!(next_line(&mut lines).await, "two");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_holds_back_unterminated_line() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("partial")?;
+    let path = dir.join("app.log");
+    append(&path, "")?;
+    let mut lines = tail_with(&path, config());
+    assert_no_element_emitted(&mut lines, 50).await;
+
+    // Act
+    append(&path, "hel")?;
+    assert_no_element_emitted(&mut lines, 50).await;
+    append(&path, "lo\n")?;
+
+    // Assert
+    assert_eq!(next_line(&mut lines).await, "hello");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_waits_for_missing_file() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("missing")?;
+    let path = dir.join("app.log");
+    let mut lines = tail_with(&path, config());
+    assert_no_element_emitted(&mut lines, 50).await;
+
+    // Act
+    append(&path, "created\n")?;
+
+    // Assert
+    assert_eq!(next_line(&mut lines).await, "created");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_follows_rotated_file() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("rotated")?;
+    let path = dir.join("app.log");
+    append(&path, "")?;
+    let mut lines = tail_with(&path, config());
+    assert_no_element_emitted(&mut lines, 50).await;
+    append(&path, "before\n")?;
+    assert_eq!(next_line(&mut lines).await, "before");
+
+    // Act
+    fs::rename(&path, dir.join("app.log.1"))?;
+    settle().await;
+    append(&path, "after\n")?;
+
+    // Assert
+    assert_eq!(next_line(&mut lines).await, "after");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_restarts_truncated_file_from_start() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("truncated")?;
+    let path = dir.join("app.log");
+    append(&path, "")?;
+    let mut lines = tail_with(&path, config());
+    assert_no_element_emitted(&mut lines, 50).await;
+    append(&path, "a long line before truncation\n")?;
+    assert_eq!(next_line(&mut lines).await, "a long line before truncation");
+
+    // Act
+    fs::write(&path, "short\n")?;
+
+    // Assert
+    assert_eq!(next_line(&mut lines).await, "short");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_emits_error_and_ends_when_path_is_unreadable() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("unreadable")?;
+
+    // Act
+    let mut lines = tail_with(&dir, config());
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut lines, 1000).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    fluxion_test_utils::helpers::assert_stream_ended(&mut lines, 1000).await;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_io::{watch, watch_recursive, FsEvent, FsEventKind, IoEvent};
+use fluxion_test_utils::helpers::{unwrap_stream, unwrap_value};
+use futures::Stream;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn test_dir(test: &str) -> anyhow::Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("fluxion-io-watch-{test}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+    Ok(dir.canonicalize()?)
+}
+
+/// Skips events until one of `kind` about `path` arrives.
+async fn next_event_for<S>(events: &mut S, kind: FsEventKind, path: &Path) -> FsEvent
+where
+    S: Stream<Item = StreamItem<IoEvent<FsEvent>>> + Unpin,
+{
+    loop {
+        let event = unwrap_value(Some(unwrap_stream(events, 1000).await)).value;
+        if event.kind == kind && event.paths.iter().any(|p| p == path) {
+            return event;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_watch_reports_created_modified_and_removed_files() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("lifecycle")?;
+    let path = dir.join("orders.csv");
+    let mut events = watch(&dir)?;
+
+    // Act
+    fs::write(&path, "id,qty\n")?;
+    let created = next_event_for(&mut events, FsEventKind::Created, &path).await;
+    fs::write(&path, "id,qty\n1,5\n")?;
+    let modified = next_event_for(&mut events, FsEventKind::Modified, &path).await;
+    fs::remove_file(&path)?;
+    let removed = next_event_for(&mut events, FsEventKind::Removed, &path).await;
+
+    // Assert
+    assert_eq!(created.paths, vec![path.clone()]);
+    assert_eq!(modified.paths, vec![path.clone()]);
+    assert_eq!(removed.paths, vec![path]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_reports_renamed_files() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("renamed")?;
+    let from = dir.join("orders.tmp");
+    let to = dir.join("orders.csv");
+    fs::write(&from, "id,qty\n")?;
+    let mut events = watch(&dir)?;
+
+    // Act
+    fs::rename(&from, &to)?;
+
+    // Assert
+    let renamed = next_event_for(&mut events, FsEventKind::Renamed, &to).await;
+    assert!(renamed.paths.contains(&to));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_recursive_reports_nested_files() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("recursive")?;
+    let nested = dir.join("2025").join("01");
+    fs::create_dir_all(&nested)?;
+    let path = nested.join("orders.csv");
+    let mut events = watch_recursive(&dir)?;
+
+    // Act
+    fs::write(&path, "id,qty\n")?;
+
+    // Assert
+    let created = next_event_for(&mut events, FsEventKind::Created, &path).await;
+    assert_eq!(created.paths, vec![path]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_stamps_events_in_order() -> anyhow::Result<()> {
+    // Arrange
+    let dir = test_dir("ordered")?;
+    let mut events = watch(&dir)?;
+
+    // Act
+    fs::write(dir.join("a.csv"), "")?;
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    fs::write(dir.join("b.csv"), "")?;
+    let first = unwrap_value(Some(unwrap_stream(&mut events, 1000).await));
+    let mut last = first.clone();
+    while last.value.paths != vec![dir.join("b.csv")] {
+        last = unwrap_value(Some(unwrap_stream(&mut events, 1000).await));
+    }
+
+    // Assert
+    assert_eq!(first.value.paths, vec![dir.join("a.csv")]);
+    assert!(first.timestamp < last.timestamp);
+
+    Ok(())
+}
+
+#[test]
+fn test_watch_missing_path_returns_error() {
+    // Arrange
+    let path = std::env::temp_dir().join("fluxion-io-watch-missing/does-not-exist");
+
+    // Act
+    let result = watch(&path);
+
+    // Assert
+    assert!(matches!(
+        result.err(),
+        Some(FluxionError::StreamProcessingError { .. })
+    ));
+}
@@ -0,0 +1,11 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::mutex_bench::bench_mutex;
+use criterion::{criterion_group, criterion_main};
+
+mod mutex_bench;
+
+criterion_group!(benches, bench_mutex);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use fluxion_runtime::impls::tokio::TokioRuntime;
+use fluxion_runtime::mutex::{AsyncMutexLike, MutexLike};
+use fluxion_runtime::runtime::Runtime;
+use std::hint::black_box;
+use tokio::runtime::Builder;
+
+type BlockingMutex = <TokioRuntime as Runtime>::Mutex<Vec<u64>>;
+type AsyncMutex = <TokioRuntime as Runtime>::AsyncMutex<Vec<u64>>;
+
+const LOCKS_PER_TASK: usize = 1000;
+
+/// Compares the blocking and async runtime mutexes with `tasks` tasks locking the same
+/// state on a multi-threaded executor, yielding to the scheduler every 64 locks.
+pub fn bench_mutex(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mutex");
+    let task_counts = [1usize, 4, 16, 64];
+
+    for &tasks in &task_counts {
+        group.throughput(Throughput::Elements((tasks * LOCKS_PER_TASK) as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("blocking", format!("tasks_{tasks}")),
+            &tasks,
+            |bencher, &tasks| {
+                let rt = Builder::new_multi_thread().enable_all().build().unwrap();
+                bencher.iter(|| {
+                    rt.block_on(async {
+                        let state = <BlockingMutex as MutexLike<_>>::new(Vec::new());
+                        let handles: Vec<_> = (0..tasks)
+                            .map(|task| {
+                                let state = state.clone();
+                                tokio::spawn(async move {
+                                    for i in 0..LOCKS_PER_TASK {
+                                        state.lock().push((task * i) as u64);
+                                        if i % 64 == 0 {
+                                            tokio::task::yield_now().await;
+                                        }
+                                    }
+                                })
+                            })
+                            .collect();
+                        for handle in handles {
+                            handle.await.unwrap();
+                        }
+                        black_box(state.lock().len());
+                    });
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("async", format!("tasks_{tasks}")),
+            &tasks,
+            |bencher, &tasks| {
+                let rt = Builder::new_multi_thread().enable_all().build().unwrap();
+                bencher.iter(|| {
+                    rt.block_on(async {
+                        let state = <AsyncMutex as AsyncMutexLike<_>>::new(Vec::new());
+                        let handles: Vec<_> = (0..tasks)
+                            .map(|task| {
+                                let state = state.clone();
+                                tokio::spawn(async move {
+                                    for i in 0..LOCKS_PER_TASK {
+                                        state.lock().await.push((task * i) as u64);
+                                        if i % 64 == 0 {
+                                            tokio::task::yield_now().await;
+                                        }
+                                    }
+                                })
+                            })
+                            .collect();
+                        for handle in handles {
+                            handle.await.unwrap();
+                        }
+                        black_box(state.lock().await.len());
+                    });
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
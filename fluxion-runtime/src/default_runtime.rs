@@ -0,0 +1,52 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! The runtime Fluxion uses when none is passed explicitly.
+//!
+//! The choice follows the enabled `runtime-*` features in the order Tokio (native
+//! targets only), smol, async-std, Embassy and WASM (`wasm32` only). Cargo unifies the
+//! features of this crate across every crate that depends on it, so all Fluxion crates
+//! in a build resolve to the same runtime.
+
+use crate::runtime::Runtime;
+
+#[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+pub type DefaultRuntime = crate::impls::tokio::TokioRuntime;
+
+#[cfg(all(
+    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
+    feature = "runtime-smol"
+))]
+pub type DefaultRuntime = crate::impls::smol::SmolRuntime;
+
+#[cfg(all(
+    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
+    not(feature = "runtime-smol"),
+    feature = "runtime-async-std"
+))]
+pub type DefaultRuntime = crate::impls::async_std::AsyncStdRuntime;
+
+#[cfg(all(
+    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
+    not(feature = "runtime-smol"),
+    not(feature = "runtime-async-std"),
+    feature = "runtime-embassy"
+))]
+pub type DefaultRuntime = crate::impls::embassy::EmbassyRuntime;
+
+#[cfg(all(
+    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
+    not(feature = "runtime-smol"),
+    not(feature = "runtime-async-std"),
+    not(feature = "runtime-embassy"),
+    feature = "runtime-wasm",
+    target_arch = "wasm32"
+))]
+pub type DefaultRuntime = crate::impls::wasm::WasmRuntime;
+
+/// Timer of the [`DefaultRuntime`].
+pub type DefaultTimer = <DefaultRuntime as Runtime>::Timer;
+
+/// Spawner of the [`DefaultRuntime`].
+pub type DefaultSpawner = <DefaultRuntime as Runtime>::Spawner;
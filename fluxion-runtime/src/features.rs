@@ -0,0 +1,63 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Compile-time checks of the runtime feature flags.
+//!
+//! Every Fluxion crate mirrors the `runtime-*` features of this crate, and an invalid
+//! combination used to surface as "trait not found" or "cannot find type
+//! `DefaultRuntime`" errors deep inside the operator modules. The macros below turn
+//! those combinations into a single `compile_error!` naming the crate and the fix.
+//!
+//! They expand to `#[cfg(feature = ...)]` items, so the features are those of the crate
+//! invoking the macro, not of `fluxion-runtime`.
+//!
+//! # `runtime-auto`
+//!
+//! Every crate with runtime features also has `runtime-auto`, which enables both
+//! `runtime-tokio` and `runtime-wasm`. The crates already prefer Tokio when both are
+//! enabled and never use it on `wasm32`, where its dependency is not built, so this
+//! selects Tokio on native targets and the WASM runtime in the browser. Use it for
+//! libraries and examples that are built for both.
+
+/// Rejects runtime features that cannot work on the target being compiled for.
+///
+/// smol and async-std need OS threads and are not available on `wasm32`.
+#[macro_export]
+macro_rules! assert_runtime_features {
+    () => {
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "runtime-smol", feature = "runtime-async-std")
+        ))]
+        compile_error!(
+            "the `runtime-smol` and `runtime-async-std` features need OS threads and do not \
+             support wasm32; enable `runtime-wasm` or `runtime-auto` instead"
+        );
+    };
+}
+
+/// Requires a runtime feature, for crates that are empty without one.
+///
+/// `runtime-tokio` alone does not count on `wasm32`.
+#[macro_export]
+macro_rules! require_runtime {
+    ($crate_name:literal) => {
+        $crate::assert_runtime_features!();
+
+        #[cfg(not(any(
+            all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+            feature = "runtime-smol",
+            feature = "runtime-async-std",
+            feature = "runtime-wasm",
+            feature = "runtime-embassy"
+        )))]
+        compile_error!(concat!(
+            "`",
+            $crate_name,
+            "` needs a runtime: enable one of the `runtime-tokio`, `runtime-smol`, \
+             `runtime-async-std`, `runtime-wasm` or `runtime-embassy` features, or \
+             `runtime-auto` to use Tokio on native targets and WASM on wasm32"
+        ));
+    };
+}
@@ -9,7 +9,11 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 
 #[cfg(feature = "runtime-async-std")]
-use crate::{runtime::Runtime, timer::Timer};
+use crate::{
+    runtime::Runtime,
+    spawner::{BlockingSpawner, Spawner},
+    timer::Timer,
+};
 
 #[cfg(feature = "runtime-async-std")]
 #[derive(Debug)]
@@ -18,7 +22,9 @@ pub struct AsyncStdRuntime;
 #[cfg(feature = "runtime-async-std")]
 impl Runtime for AsyncStdRuntime {
     type Mutex<T: ?Sized> = Arc<Mutex<T>>;
+    type AsyncMutex<T: ?Sized> = Arc<futures::lock::Mutex<T>>;
     type Timer = AsyncStdTimer;
+    type Spawner = AsyncStdSpawner;
     type Instant = std::time::Instant;
 }
 
@@ -56,13 +62,69 @@ impl core::future::Future for AsyncStdSleep {
 impl Timer for AsyncStdTimer {
     type Sleep = AsyncStdSleep;
 
+    type Interval = AsyncStdInterval;
+
     type Instant = std::time::Instant;
 
     fn sleep_future(&self, duration: std::time::Duration) -> Self::Sleep {
         AsyncStdSleep::new(duration)
     }
 
+    fn interval(&self, period: std::time::Duration) -> Self::Interval {
+        AsyncStdInterval {
+            timer: async_io::Timer::interval(period),
+        }
+    }
+
     fn now(&self) -> Self::Instant {
         std::time::Instant::now()
     }
 }
+
+/// Tick stream returned by [`AsyncStdTimer::interval`], backed by `async_io::Timer`.
+#[cfg(feature = "runtime-async-std")]
+pub struct AsyncStdInterval {
+    timer: async_io::Timer,
+}
+
+#[cfg(feature = "runtime-async-std")]
+impl futures::Stream for AsyncStdInterval {
+    type Item = ();
+
+    fn poll_next(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<()>> {
+        core::pin::Pin::new(&mut self.timer)
+            .poll_next(cx)
+            .map(|tick| tick.map(|_| ()))
+    }
+}
+
+#[cfg(feature = "runtime-async-std")]
+#[derive(Clone, Debug, Default)]
+pub struct AsyncStdSpawner;
+
+#[cfg(feature = "runtime-async-std")]
+impl Spawner for AsyncStdSpawner {
+    fn spawn<F>(&self, future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        async_std::task::spawn(future);
+    }
+}
+
+#[cfg(feature = "runtime-async-std")]
+impl BlockingSpawner for AsyncStdSpawner {
+    fn spawn_blocking<F, T>(
+        &self,
+        f: F,
+    ) -> impl std::future::Future<Output = T> + Send + Sync + use<F, T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        async_std::task::spawn_blocking(f)
+    }
+}
@@ -58,3 +58,39 @@ impl<T: ?Sized> MutexLike<T> for Arc<Mutex<T>> {
         self.as_ref().lock()
     }
 }
+
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-wasm",
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+use crate::mutex::AsyncMutexLike;
+
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-wasm",
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+impl<T: ?Sized> AsyncMutexLike<T> for Arc<futures::lock::Mutex<T>> {
+    type Guard<'a>
+        = futures::lock::MutexGuard<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn new(value: T) -> Self
+    where
+        T: Sized,
+    {
+        Arc::new(futures::lock::Mutex::new(value))
+    }
+
+    fn lock<'a>(&'a self) -> impl core::future::Future<Output = Self::Guard<'a>>
+    where
+        T: 'a,
+    {
+        self.as_ref().lock()
+    }
+}
@@ -3,7 +3,7 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 #[cfg(feature = "runtime-embassy")]
-use crate::{runtime::Runtime, timer::Timer};
+use crate::{runtime::Runtime, spawner::Spawner, timer::Timer};
 
 #[cfg(feature = "runtime-embassy")]
 extern crate alloc;
@@ -27,7 +27,7 @@ use core::default::Default;
 use core::cmp::{Eq, Ord, PartialEq, PartialOrd};
 
 #[cfg(feature = "runtime-embassy")]
-use crate::mutex::MutexLike;
+use crate::mutex::{AsyncMutexLike, MutexLike};
 
 #[cfg(feature = "runtime-embassy")]
 use spin::{Mutex, MutexGuard};
@@ -52,6 +52,39 @@ impl<T: ?Sized> MutexLike<T> for alloc::sync::Arc<Mutex<T>> {
     }
 }
 
+/// Async mutex used by [`EmbassyRuntime`]; a critical-section implementation must be
+/// linked in, as for any `embassy-sync` primitive.
+#[cfg(feature = "runtime-embassy")]
+pub type AsyncMutex<T> =
+    embassy_sync::mutex::Mutex<embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex, T>;
+
+#[cfg(feature = "runtime-embassy")]
+impl<T: ?Sized> AsyncMutexLike<T> for alloc::sync::Arc<AsyncMutex<T>> {
+    type Guard<'a>
+        = embassy_sync::mutex::MutexGuard<
+        'a,
+        embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
+        T,
+    >
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn new(value: T) -> Self
+    where
+        T: Sized,
+    {
+        alloc::sync::Arc::new(AsyncMutex::new(value))
+    }
+
+    fn lock<'a>(&'a self) -> impl core::future::Future<Output = Self::Guard<'a>>
+    where
+        T: 'a,
+    {
+        (**self).lock()
+    }
+}
+
 #[cfg(feature = "runtime-embassy")]
 #[derive(Debug)]
 pub struct EmbassyRuntime;
@@ -59,7 +92,9 @@ pub struct EmbassyRuntime;
 #[cfg(feature = "runtime-embassy")]
 impl Runtime for EmbassyRuntime {
     type Mutex<T: ?Sized> = alloc::sync::Arc<Mutex<T>>;
+    type AsyncMutex<T: ?Sized> = alloc::sync::Arc<AsyncMutex<T>>;
     type Timer = EmbassyTimer;
+    type Spawner = EmbassySpawner;
     type Instant = EmbassyInstant;
 }
 
@@ -136,13 +171,230 @@ pub struct EmbassyTimer;
 #[cfg(feature = "runtime-embassy")]
 impl Timer for EmbassyTimer {
     type Sleep = embassy_time::Timer;
+    type Interval = embassy_time::Ticker;
     type Instant = EmbassyInstant;
 
     fn sleep_future(&self, duration: core::time::Duration) -> Self::Sleep {
         embassy_time::Timer::after(to_embassy_duration(duration))
     }
 
+    fn sleep_coalesced(
+        &self,
+        duration: core::time::Duration,
+        slack: core::time::Duration,
+    ) -> Self::Sleep {
+        // Embassy ticks count from boot, so deadlines align to multiples of `slack`
+        // and coincide across tasks without any shared state.
+        embassy_time::Timer::at(coalesced_deadline(to_embassy_duration(duration), slack))
+    }
+
+    fn interval(&self, period: core::time::Duration) -> Self::Interval {
+        embassy_time::Ticker::every(to_embassy_duration(period))
+    }
+
+    fn interval_coalesced(
+        &self,
+        period: core::time::Duration,
+        slack: core::time::Duration,
+    ) -> Self::Interval {
+        let period = to_embassy_duration(period);
+        let period = match period
+            .as_ticks()
+            .checked_next_multiple_of(to_embassy_duration(slack).as_ticks())
+        {
+            core::option::Option::Some(ticks) => embassy_time::Duration::from_ticks(ticks),
+            _ => period,
+        };
+
+        // `reset_at` schedules the first tick one period after the given instant
+        let mut ticker = embassy_time::Ticker::every(period);
+        ticker.reset_at(coalesced_deadline(period, slack) - period);
+        ticker
+    }
+
     fn now(&self) -> Self::Instant {
         EmbassyInstant(embassy_time::Instant::now())
     }
 }
+
+/// Returns `now + duration` rounded up to the next multiple of `slack`.
+#[cfg(feature = "runtime-embassy")]
+fn coalesced_deadline(
+    duration: embassy_time::Duration,
+    slack: core::time::Duration,
+) -> embassy_time::Instant {
+    let deadline = embassy_time::Instant::now() + duration;
+    let slack = to_embassy_duration(slack).as_ticks();
+
+    match deadline.as_ticks().checked_next_multiple_of(slack) {
+        core::option::Option::Some(ticks) => embassy_time::Instant::from_ticks(ticks),
+        _ => deadline,
+    }
+}
+
+/// Raw 64-bit Embassy tick count with wraparound-aware ordering.
+///
+/// `EmbassyInstant` compares tick counts directly, which is fine until the counter
+/// wraps: a device that restores its tick offset from flash, or a driver whose counter
+/// is narrower than it claims, sees a fresh value suddenly ordered before everything
+/// emitted just earlier. `EmbassyTicks` compares with serial-number arithmetic
+/// (RFC 1982) instead: `a < b` when `b - a`, computed with wrapping, is less than
+/// 2<sup>63</sup> ticks. Ordering is therefore correct across the wrap as long as every
+/// pair of timestamps compared in the same stream is less than 2<sup>63</sup> ticks
+/// apart, which at 1 MHz is roughly 292,000 years.
+///
+/// Outside that window the ordering is not transitive, so timestamps from unrelated
+/// boots or devices must not be merged.
+#[cfg(feature = "runtime-embassy")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmbassyTicks(u64);
+
+#[cfg(feature = "runtime-embassy")]
+impl EmbassyTicks {
+    /// Reads the current tick count.
+    pub fn now() -> Self {
+        Self(embassy_time::Instant::now().as_ticks())
+    }
+
+    pub const fn from_ticks(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    pub const fn as_ticks(self) -> u64 {
+        self.0
+    }
+
+    /// Ticks elapsed from `earlier` to `self`, wrapping through `u64::MAX`.
+    pub const fn ticks_since(self, earlier: Self) -> u64 {
+        self.0.wrapping_sub(earlier.0)
+    }
+
+    /// Time elapsed from `earlier` to `self`, wrapping through `u64::MAX`.
+    pub fn duration_since(self, earlier: Self) -> core::time::Duration {
+        to_core_duration(embassy_time::Duration::from_ticks(
+            self.ticks_since(earlier),
+        ))
+    }
+
+    /// Returns true if `self` comes before `other` under wraparound ordering.
+    pub const fn is_before(self, other: Self) -> bool {
+        (other.0.wrapping_sub(self.0) as i64) > 0
+    }
+
+    /// Returns true if `self` comes after `other` under wraparound ordering.
+    pub const fn is_after(self, other: Self) -> bool {
+        other.is_before(self)
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl Ord for EmbassyTicks {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        0.cmp(&(other.0.wrapping_sub(self.0) as i64))
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl PartialOrd for EmbassyTicks {
+    fn partial_cmp(&self, other: &Self) -> core::option::Option<core::cmp::Ordering> {
+        core::option::Option::Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl core::convert::From<EmbassyInstant> for EmbassyTicks {
+    fn from(instant: EmbassyInstant) -> Self {
+        Self(instant.0.as_ticks())
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl core::ops::Add<core::time::Duration> for EmbassyTicks {
+    type Output = EmbassyTicks;
+
+    fn add(self, duration: core::time::Duration) -> Self::Output {
+        EmbassyTicks(
+            self.0
+                .wrapping_add(to_embassy_duration(duration).as_ticks()),
+        )
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl core::ops::Sub<core::time::Duration> for EmbassyTicks {
+    type Output = EmbassyTicks;
+
+    fn sub(self, duration: core::time::Duration) -> Self::Output {
+        EmbassyTicks(
+            self.0
+                .wrapping_sub(to_embassy_duration(duration).as_ticks()),
+        )
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl core::ops::Sub<EmbassyTicks> for EmbassyTicks {
+    type Output = core::time::Duration;
+
+    fn sub(self, other: EmbassyTicks) -> Self::Output {
+        self.duration_since(other)
+    }
+}
+
+/// Number of tasks spawned through [`EmbassySpawner`] that can run at the same time,
+/// shared by all instances.
+#[cfg(feature = "runtime-embassy")]
+pub const EMBASSY_SPAWNER_POOL_SIZE: usize = 8;
+
+#[cfg(feature = "runtime-embassy")]
+type BoxedTask =
+    core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = ()> + core::marker::Send>>;
+
+#[cfg(feature = "runtime-embassy")]
+static TASK_POOL: embassy_executor::raw::TaskPool<BoxedTask, EMBASSY_SPAWNER_POOL_SIZE> =
+    embassy_executor::raw::TaskPool::new();
+
+/// Spawns tasks on an Embassy executor.
+///
+/// Embassy tasks are statically allocated, so arbitrary futures cannot be spawned
+/// directly. `EmbassySpawner` boxes each future and runs it in one of
+/// [`EMBASSY_SPAWNER_POOL_SIZE`] pre-allocated task slots. Build it from the
+/// `Spawner` passed to the Embassy `main` or to any task.
+#[cfg(feature = "runtime-embassy")]
+#[derive(Clone)]
+pub struct EmbassySpawner {
+    spawner: embassy_executor::SendSpawner,
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl EmbassySpawner {
+    pub fn new(spawner: embassy_executor::Spawner) -> Self {
+        Self {
+            spawner: spawner.make_send(),
+        }
+    }
+}
+
+#[cfg(feature = "runtime-embassy")]
+impl Debug for EmbassySpawner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EmbassySpawner").finish_non_exhaustive()
+    }
+}
+
+/// # Panics
+///
+/// Panics if [`EMBASSY_SPAWNER_POOL_SIZE`] spawned tasks are already running.
+#[cfg(feature = "runtime-embassy")]
+impl Spawner for EmbassySpawner {
+    fn spawn<F>(&self, future: F)
+    where
+        F: core::future::Future<Output = ()> + core::marker::Send + 'static,
+    {
+        let task: BoxedTask = alloc::boxed::Box::pin(future);
+        if let core::result::Result::Err(error) = self.spawner.spawn(TASK_POOL.spawn(move || task))
+        {
+            core::panic!("EmbassySpawner: {error}");
+        }
+    }
+}
@@ -9,7 +9,11 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 
 #[cfg(feature = "runtime-smol")]
-use crate::{runtime::Runtime, timer::Timer};
+use crate::{
+    runtime::Runtime,
+    spawner::{BlockingSpawner, Spawner},
+    timer::Timer,
+};
 
 #[cfg(feature = "runtime-smol")]
 #[derive(Debug)]
@@ -18,7 +22,9 @@ pub struct SmolRuntime;
 #[cfg(feature = "runtime-smol")]
 impl Runtime for SmolRuntime {
     type Mutex<T: ?Sized> = Arc<Mutex<T>>;
+    type AsyncMutex<T: ?Sized> = Arc<futures::lock::Mutex<T>>;
     type Timer = SmolTimer;
+    type Spawner = SmolSpawner;
     type Instant = std::time::Instant;
 }
 
@@ -56,13 +62,69 @@ impl core::future::Future for SmolSleep {
 impl Timer for SmolTimer {
     type Sleep = SmolSleep;
 
+    type Interval = SmolInterval;
+
     type Instant = std::time::Instant;
 
     fn sleep_future(&self, duration: std::time::Duration) -> Self::Sleep {
         SmolSleep::new(duration)
     }
 
+    fn interval(&self, period: std::time::Duration) -> Self::Interval {
+        SmolInterval {
+            timer: async_io::Timer::interval(period),
+        }
+    }
+
     fn now(&self) -> Self::Instant {
         std::time::Instant::now()
     }
 }
+
+/// Tick stream returned by [`SmolTimer::interval`], backed by `async_io::Timer`.
+#[cfg(feature = "runtime-smol")]
+pub struct SmolInterval {
+    timer: async_io::Timer,
+}
+
+#[cfg(feature = "runtime-smol")]
+impl futures::Stream for SmolInterval {
+    type Item = ();
+
+    fn poll_next(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<()>> {
+        core::pin::Pin::new(&mut self.timer)
+            .poll_next(cx)
+            .map(|tick| tick.map(|_| ()))
+    }
+}
+
+#[cfg(feature = "runtime-smol")]
+#[derive(Clone, Debug, Default)]
+pub struct SmolSpawner;
+
+#[cfg(feature = "runtime-smol")]
+impl Spawner for SmolSpawner {
+    fn spawn<F>(&self, future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        smol::spawn(future).detach();
+    }
+}
+
+#[cfg(feature = "runtime-smol")]
+impl BlockingSpawner for SmolSpawner {
+    fn spawn_blocking<F, T>(
+        &self,
+        f: F,
+    ) -> impl std::future::Future<Output = T> + Send + Sync + use<F, T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        smol::unblock(f)
+    }
+}
@@ -3,13 +3,29 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 #[cfg(feature = "runtime-tokio")]
-use std::{sync::Arc, time::Duration};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+#[cfg(feature = "runtime-tokio")]
+use futures::Stream;
+
+#[cfg(feature = "runtime-tokio")]
+use tokio::time::MissedTickBehavior;
 
 #[cfg(feature = "runtime-tokio")]
 use parking_lot::Mutex;
 
 #[cfg(feature = "runtime-tokio")]
-use crate::{runtime::Runtime, timer::Timer};
+use crate::{
+    runtime::Runtime,
+    spawner::{BlockingSpawner, LocalSpawner, Spawner},
+    timer::Timer,
+};
 
 #[cfg(feature = "runtime-tokio")]
 #[derive(Debug)]
@@ -18,7 +34,9 @@ pub struct TokioRuntime;
 #[cfg(feature = "runtime-tokio")]
 impl Runtime for TokioRuntime {
     type Mutex<T: ?Sized> = Arc<Mutex<T>>;
+    type AsyncMutex<T: ?Sized> = Arc<futures::lock::Mutex<T>>;
     type Timer = TokioTimer;
+    type Spawner = TokioSpawner;
     type Instant = std::time::Instant;
 }
 
@@ -30,13 +48,136 @@ pub struct TokioTimer;
 impl Timer for TokioTimer {
     type Sleep = tokio::time::Sleep;
 
+    type Interval = TokioInterval;
+
     type Instant = std::time::Instant;
 
     fn sleep_future(&self, duration: Duration) -> Self::Sleep {
         tokio::time::sleep(duration)
     }
 
+    fn sleep_coalesced(&self, duration: Duration, slack: Duration) -> Self::Sleep {
+        tokio::time::sleep_until(coalesced_deadline(duration, slack))
+    }
+
+    fn interval(&self, period: Duration) -> Self::Interval {
+        TokioInterval::starting_at(tokio::time::Instant::now() + period, period)
+    }
+
+    fn interval_coalesced(&self, period: Duration, slack: Duration) -> Self::Interval {
+        let period = period + coalescing_extra(period, slack);
+        TokioInterval::starting_at(coalesced_deadline(period, slack), period)
+    }
+
     fn now(&self) -> Self::Instant {
         std::time::Instant::now()
     }
 }
+
+#[cfg(feature = "runtime-tokio")]
+#[derive(Clone, Debug, Default)]
+pub struct TokioSpawner;
+
+#[cfg(feature = "runtime-tokio")]
+impl Spawner for TokioSpawner {
+    fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        tokio::spawn(future);
+    }
+}
+
+/// Spawns with `tokio::task::spawn_local`, so it must be used inside a
+/// `tokio::task::LocalSet`.
+#[cfg(feature = "runtime-tokio")]
+impl LocalSpawner for TokioSpawner {
+    fn spawn_local<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        tokio::task::spawn_local(future);
+    }
+}
+
+/// Runs closures with `tokio::task::spawn_blocking`.
+#[cfg(feature = "runtime-tokio")]
+impl BlockingSpawner for TokioSpawner {
+    fn spawn_blocking<F, T>(&self, f: F) -> impl Future<Output = T> + Send + Sync + use<F, T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let handle = tokio::task::spawn_blocking(f);
+        async move {
+            match handle.await {
+                Ok(value) => value,
+                Err(error) => match error.try_into_panic() {
+                    Ok(payload) => std::panic::resume_unwind(payload),
+                    Err(error) => panic!("blocking task did not complete: {error}"),
+                },
+            }
+        }
+    }
+}
+
+/// Tick stream returned by [`TokioTimer::interval`], backed by `tokio::time::Interval`.
+#[cfg(feature = "runtime-tokio")]
+#[derive(Debug)]
+pub struct TokioInterval {
+    interval: tokio::time::Interval,
+}
+
+#[cfg(feature = "runtime-tokio")]
+impl TokioInterval {
+    fn starting_at(start: tokio::time::Instant, period: Duration) -> Self {
+        // Tokio rejects a zero period; its timer resolves to milliseconds anyway
+        let period = period.max(Duration::from_nanos(1));
+        let mut interval = tokio::time::interval_at(start, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self { interval }
+    }
+}
+
+#[cfg(feature = "runtime-tokio")]
+impl Stream for TokioInterval {
+    type Item = ();
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.interval.poll_tick(cx).map(|_| Some(()))
+    }
+}
+
+/// Returns `now + duration` rounded up to the next multiple of `slack`.
+///
+/// Tokio instants have no absolute origin, so deadlines are aligned to the first
+/// instant any coalesced timer was created at.
+#[cfg(feature = "runtime-tokio")]
+fn coalesced_deadline(duration: Duration, slack: Duration) -> tokio::time::Instant {
+    static ORIGIN: OnceLock<tokio::time::Instant> = OnceLock::new();
+
+    let now = tokio::time::Instant::now();
+    let origin = *ORIGIN.get_or_init(|| now);
+    let deadline = now + duration;
+
+    match deadline.checked_duration_since(origin) {
+        Some(offset) => deadline + coalescing_extra(offset, slack),
+        None => deadline,
+    }
+}
+
+/// Returns how much longer than `offset` to wait so that the deadline lands on the
+/// next multiple of `slack`, measured from the same origin as `offset`.
+#[cfg(feature = "runtime-tokio")]
+fn coalescing_extra(offset: Duration, slack: Duration) -> Duration {
+    let slack = slack.as_nanos();
+    if slack == 0 {
+        return Duration::ZERO;
+    }
+    let remainder = offset.as_nanos() % slack;
+    if remainder == 0 {
+        Duration::ZERO
+    } else {
+        Duration::from_nanos((slack - remainder) as u64)
+    }
+}
@@ -14,10 +14,26 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 #[cfg(feature = "runtime-wasm")]
-use core::{ops, time::Duration};
+use core::{
+    future::Future,
+    ops,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 #[cfg(feature = "runtime-wasm")]
-use crate::{runtime::Runtime, timer::Timer};
+use gloo_timers::future::{IntervalStream, TimeoutFuture};
+
+#[cfg(feature = "runtime-wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[cfg(feature = "runtime-wasm")]
+use crate::{
+    runtime::Runtime,
+    spawner::{LocalSpawner, Spawner},
+    timer::Timer,
+};
 
 #[cfg(feature = "runtime-wasm")]
 #[derive(Debug)]
@@ -26,10 +42,22 @@ pub struct WasmRuntime;
 #[cfg(feature = "runtime-wasm")]
 impl Runtime for WasmRuntime {
     type Mutex<T: ?Sized> = Arc<parking_lot::Mutex<T>>;
+    type AsyncMutex<T: ?Sized> = Arc<futures::lock::Mutex<T>>;
     type Timer = WasmTimer;
+    type Spawner = WasmSpawner;
     type Instant = WasmInstant;
 }
 
+#[cfg(feature = "runtime-wasm")]
+#[wasm_bindgen]
+extern "C" {
+    // `globalThis.performance.now()`, available in windows, workers and Node.js
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+}
+
+/// A monotonic instant read from `performance.now()`, stored in microseconds since the
+/// time origin of the page, worker or process.
 #[cfg(feature = "runtime-wasm")]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct WasmInstant(u64);
@@ -37,7 +65,7 @@ pub struct WasmInstant(u64);
 #[cfg(feature = "runtime-wasm")]
 impl WasmInstant {
     fn now() -> Self {
-        WasmInstant(js_sys::Date::now() as u64)
+        WasmInstant((performance_now() * 1000.0) as u64)
     }
 }
 
@@ -46,7 +74,7 @@ impl ops::Sub for WasmInstant {
     type Output = Duration;
 
     fn sub(self, other: WasmInstant) -> Duration {
-        Duration::from_millis(self.0.saturating_sub(other.0))
+        Duration::from_micros(self.0.saturating_sub(other.0))
     }
 }
 
@@ -55,7 +83,7 @@ impl ops::Add<Duration> for WasmInstant {
     type Output = WasmInstant;
 
     fn add(self, duration: Duration) -> WasmInstant {
-        WasmInstant(self.0 + duration.as_millis() as u64)
+        WasmInstant(self.0 + duration.as_micros() as u64)
     }
 }
 
@@ -64,21 +92,21 @@ impl ops::Sub<Duration> for WasmInstant {
     type Output = WasmInstant;
 
     fn sub(self, duration: Duration) -> WasmInstant {
-        WasmInstant(self.0.saturating_sub(duration.as_millis() as u64))
+        WasmInstant(self.0.saturating_sub(duration.as_micros() as u64))
     }
 }
 
 #[cfg(feature = "runtime-wasm")]
 impl ops::AddAssign<Duration> for WasmInstant {
     fn add_assign(&mut self, duration: Duration) {
-        self.0 += duration.as_millis() as u64;
+        self.0 += duration.as_micros() as u64;
     }
 }
 
 #[cfg(feature = "runtime-wasm")]
 impl ops::SubAssign<Duration> for WasmInstant {
     fn sub_assign(&mut self, duration: Duration) {
-        self.0 = self.0.saturating_sub(duration.as_millis() as u64);
+        self.0 = self.0.saturating_sub(duration.as_micros() as u64);
     }
 }
 
@@ -88,14 +116,101 @@ pub struct WasmTimer;
 
 #[cfg(feature = "runtime-wasm")]
 impl Timer for WasmTimer {
-    type Sleep = gloo_timers::future::TimeoutFuture;
+    type Sleep = WasmSleep;
+    type Interval = IntervalStream;
     type Instant = WasmInstant;
 
     fn sleep_future(&self, duration: Duration) -> Self::Sleep {
-        gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32)
+        WasmSleep::until(WasmInstant::now() + duration)
+    }
+
+    /// Ticks with `setInterval`, whose period is whole milliseconds, so `period` is
+    /// rounded up to the next millisecond.
+    fn interval(&self, period: Duration) -> Self::Interval {
+        let millis = period.as_micros().div_ceil(1000).min(i32::MAX as u128) as u32;
+        IntervalStream::new(millis)
     }
 
     fn now(&self) -> Self::Instant {
         WasmInstant::now()
     }
 }
+
+/// Sleep future returned by [`WasmTimer`].
+///
+/// `setTimeout` only takes whole milliseconds and may fire slightly before the deadline
+/// as measured by `performance.now()`, so the future re-arms a timeout for the remaining
+/// time until the deadline has actually passed. The timeout is rounded up, never down,
+/// so a sleep never completes early.
+#[cfg(feature = "runtime-wasm")]
+#[derive(Debug)]
+pub struct WasmSleep {
+    deadline: WasmInstant,
+    timeout: Option<TimeoutFuture>,
+}
+
+#[cfg(feature = "runtime-wasm")]
+impl WasmSleep {
+    fn until(deadline: WasmInstant) -> Self {
+        Self {
+            deadline,
+            timeout: None,
+        }
+    }
+
+    fn remaining_millis(&self, now: WasmInstant) -> u32 {
+        let micros = self.deadline.0.saturating_sub(now.0);
+        // setTimeout delays are signed 32-bit; longer sleeps re-arm when they fire
+        micros.div_ceil(1000).min(i32::MAX as u64) as u32
+    }
+}
+
+#[cfg(feature = "runtime-wasm")]
+impl Future for WasmSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            if let Some(timeout) = self.timeout.as_mut() {
+                if Pin::new(timeout).poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                self.timeout = None;
+            }
+
+            let now = WasmInstant::now();
+            if now >= self.deadline {
+                return Poll::Ready(());
+            }
+
+            let millis = self.remaining_millis(now);
+            self.timeout = Some(TimeoutFuture::new(millis));
+        }
+    }
+}
+
+/// Spawns with `wasm_bindgen_futures::spawn_local`; WASM is single-threaded, so `Send`
+/// and non-`Send` futures run the same way.
+#[cfg(feature = "runtime-wasm")]
+#[derive(Clone, Debug, Default)]
+pub struct WasmSpawner;
+
+#[cfg(feature = "runtime-wasm")]
+impl Spawner for WasmSpawner {
+    fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
+
+#[cfg(feature = "runtime-wasm")]
+impl LocalSpawner for WasmSpawner {
+    fn spawn_local<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
@@ -7,7 +7,19 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    all(feature = "runtime-wasm", target_arch = "wasm32")
+))]
+pub mod default_runtime;
+pub mod features;
 pub mod impls;
 pub mod mutex;
 pub mod runtime;
+pub mod spawner;
 pub mod timer;
+
+assert_runtime_features!();
@@ -3,6 +3,7 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 use core::clone::Clone;
+use core::future::Future;
 use core::marker::Sized;
 use core::ops::{Deref, DerefMut};
 
@@ -18,3 +19,22 @@ pub trait MutexLike<T: ?Sized>: Clone {
 
     fn lock(&self) -> Self::Guard<'_>;
 }
+
+/// Mutex whose `lock` waits asynchronously instead of blocking the thread.
+///
+/// Suited to state that is locked inside futures polled concurrently with other
+/// lockers, where a blocking mutex would park the executor thread under contention.
+pub trait AsyncMutexLike<T: ?Sized>: Clone {
+    type Guard<'a>: Deref<Target = T> + DerefMut
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn new(value: T) -> Self
+    where
+        T: Sized;
+
+    fn lock<'a>(&'a self) -> impl Future<Output = Self::Guard<'a>>
+    where
+        T: 'a;
+}
@@ -2,7 +2,11 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use crate::{mutex::MutexLike, timer::Timer};
+use crate::{
+    mutex::{AsyncMutexLike, MutexLike},
+    spawner::Spawner,
+    timer::Timer,
+};
 use core::cmp::Ord;
 use core::default::Default;
 use core::fmt::Debug;
@@ -11,6 +15,8 @@ use core::marker::{Copy, Send, Sync};
 
 pub trait Runtime: 'static {
     type Mutex<T: ?Sized>: MutexLike<T>;
+    type AsyncMutex<T: ?Sized>: AsyncMutexLike<T>;
     type Timer: Timer<Instant = Self::Instant> + Default;
+    type Spawner: Spawner;
     type Instant: Copy + Ord + Send + Sync + Debug;
 }
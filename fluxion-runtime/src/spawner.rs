@@ -0,0 +1,43 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::clone::Clone;
+use core::fmt::Debug;
+use core::future::Future;
+use core::marker::Send;
+
+/// Runtime-agnostic way to run a future as a detached background task.
+///
+/// Operators that need a background task, such as `partition` and `share`, spawn it
+/// through this trait rather than calling a runtime directly.
+pub trait Spawner: Clone + Debug + 'static {
+    /// Spawns `future` as a detached task that runs to completion on its own.
+    fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static;
+}
+
+/// Spawns futures that are not `Send` on the current thread.
+pub trait LocalSpawner: Clone + Debug + 'static {
+    /// Spawns `future` as a detached task on the current thread.
+    fn spawn_local<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + 'static;
+}
+
+/// Runs blocking or CPU-heavy closures on threads set aside for them, so they do not
+/// stall the async executor.
+///
+/// Only runtimes with a thread pool implement this; WASM and Embassy run everything on
+/// one thread.
+pub trait BlockingSpawner: Clone + Debug + 'static {
+    /// Runs `f` on a thread where blocking is allowed; the returned future resolves to
+    /// its result.
+    ///
+    /// A panic in `f` is resumed when the returned future is polled.
+    fn spawn_blocking<F, T>(&self, f: F) -> impl Future<Output = T> + Send + Sync + use<Self, F, T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static;
+}
@@ -6,13 +6,17 @@ use core::clone::Clone;
 use core::cmp::Ord;
 use core::fmt::Debug;
 use core::future::Future;
-use core::marker::{Copy, Send, Sync};
+use core::marker::{Copy, Send, Sync, Unpin};
 use core::ops::{Add, Sub};
 use core::time::Duration;
+use futures::Stream;
 
 pub trait Timer: Clone + Send + Sync + Debug + 'static {
     type Sleep: Future<Output = ()>;
 
+    /// Stream of periodic ticks returned by [`interval`](Self::interval).
+    type Interval: Stream<Item = ()> + Unpin;
+
     type Instant: Copy
         + Debug
         + Ord
@@ -24,5 +28,39 @@ pub trait Timer: Clone + Send + Sync + Debug + 'static {
 
     fn sleep_future(&self, duration: Duration) -> Self::Sleep;
 
+    /// Sleeps for at least `duration`, rounding the deadline up to the next multiple
+    /// of `slack` on the timer's clock.
+    ///
+    /// Deadlines that fall into the same `slack` window end on the same instant, so
+    /// the executor serves them with a single wakeup. A sleep may therefore end up to
+    /// `slack` late, but never early. A zero `slack` behaves like
+    /// [`sleep_future`](Self::sleep_future), which is also what timers that cannot
+    /// align deadlines fall back to.
+    fn sleep_coalesced(&self, duration: Duration, slack: Duration) -> Self::Sleep {
+        let _ = slack;
+        self.sleep_future(duration)
+    }
+
+    /// Ticks every `period`, starting one `period` from now.
+    ///
+    /// The tick schedule is kept by the runtime, so a periodic operator holds a single
+    /// interval instead of re-arming a sleep after every tick. The stream never ends.
+    /// Whether ticks missed by a slow consumer are skipped or delivered back to back
+    /// depends on the runtime. A zero `period` ticks as fast as the runtime's timer
+    /// allows.
+    fn interval(&self, period: Duration) -> Self::Interval;
+
+    /// Ticks every `period` with each tick aligned to a multiple of `slack` on the
+    /// timer's clock, like [`sleep_coalesced`](Self::sleep_coalesced).
+    ///
+    /// The first tick is rounded up to the next multiple of `slack` and `period` is
+    /// rounded up to a multiple of `slack`, so every tick stays on the shared grid. A
+    /// zero `slack` behaves like [`interval`](Self::interval), which is also what
+    /// timers that cannot align ticks fall back to.
+    fn interval_coalesced(&self, period: Duration, slack: Duration) -> Self::Interval {
+        let _ = slack;
+        self.interval(period)
+    }
+
     fn now(&self) -> Self::Instant;
 }
@@ -9,12 +9,14 @@ mod delay_bench;
 mod sample_bench;
 mod throttle_bench;
 mod timeout_bench;
+mod timer_wheel_bench;
 
 use debounce_bench::bench_debounce;
 use delay_bench::bench_delay;
 use sample_bench::bench_sample;
 use throttle_bench::bench_throttle;
 use timeout_bench::bench_timeout;
+use timer_wheel_bench::bench_timer_wheel;
 
 criterion_group!(
     benches,
@@ -22,6 +24,7 @@ criterion_group!(
     bench_delay,
     bench_sample,
     bench_throttle,
-    bench_timeout
+    bench_timeout,
+    bench_timer_wheel
 );
 criterion_main!(benches);
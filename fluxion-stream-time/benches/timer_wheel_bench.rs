@@ -0,0 +1,123 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream::IntoFluxionStream;
+use fluxion_stream_time::{QuotaExt, QuotaPolicy, RateLimitExt, TimerWheel, TokioTimestamped};
+use futures::StreamExt;
+use std::hint::black_box;
+use std::time::Duration;
+use tokio::runtime::Builder;
+
+const KEYS: u32 = 100_000;
+
+/// Deadlines spread over a minute, so that they land on every level of the wheel.
+fn deadline(key: u32) -> Duration {
+    Duration::from_micros(u64::from(key.wrapping_mul(2_654_435_761)) % 60_000_000)
+}
+
+pub fn bench_timer_wheel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("timer_wheel");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(u64::from(KEYS)));
+
+    group.bench_with_input(
+        BenchmarkId::new("insert_expire", KEYS),
+        &KEYS,
+        |bencher, &keys| {
+            bencher.iter(|| {
+                let mut wheel = TimerWheel::new();
+                for key in 0..keys {
+                    wheel.insert(key, deadline(key));
+                }
+                let mut now = Duration::ZERO;
+                while let Some(next) = wheel.next_deadline() {
+                    now = next;
+                    while let Some(expired) = wheel.poll_expired(now) {
+                        black_box(expired);
+                    }
+                }
+                black_box(now);
+            });
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new("reschedule", KEYS),
+        &KEYS,
+        |bencher, &keys| {
+            let mut wheel = TimerWheel::new();
+            for key in 0..keys {
+                wheel.insert(key, deadline(key));
+            }
+            let mut round = 0;
+            bencher.iter(|| {
+                // Every key sees activity and pushes its deadline back, as in a debounce
+                round += 1;
+                for key in 0..keys {
+                    wheel.insert(key, deadline(key.wrapping_add(round)));
+                }
+                black_box(wheel.next_deadline());
+            });
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new("quota_delay", KEYS),
+        &KEYS,
+        |bencher, &keys| {
+            bencher.iter(|| {
+                paused_runtime().block_on(async {
+                    let (stream, _violations) = keyed_items(keys).into_fluxion_stream().quota(
+                        1,
+                        Duration::from_millis(100),
+                        move |value: &u32| value % keys,
+                        QuotaPolicy::Delay,
+                    );
+                    black_box(stream.count().await);
+                });
+            });
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new("rate_limit_by_key", KEYS),
+        &KEYS,
+        |bencher, &keys| {
+            bencher.iter(|| {
+                paused_runtime().block_on(async {
+                    let stream = keyed_items(keys).into_fluxion_stream().rate_limit_by_key(
+                        10,
+                        1,
+                        move |value: &u32| value % keys,
+                    );
+                    black_box(stream.count().await);
+                });
+            });
+        },
+    );
+
+    group.finish();
+}
+
+fn paused_runtime() -> tokio::runtime::Runtime {
+    Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .unwrap()
+}
+
+/// Two items for each of `keys` keys, so that through a keyed operator the second one
+/// waits on a deadline of its key.
+fn keyed_items(keys: u32) -> async_channel::Receiver<TokioTimestamped<u32>> {
+    let (tx, rx) = async_channel::unbounded();
+    for value in 0..2 * keys {
+        tx.try_send(TokioTimestamped::new(value, TokioTimer.now()))
+            .unwrap();
+    }
+    rx
+}
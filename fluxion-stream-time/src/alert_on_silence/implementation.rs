@@ -0,0 +1,135 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_alert_on_silence_impl {
+    ($($bounds:tt)*) => {
+        use crate::DefaultRuntime;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        use fluxion_core::{Fluxion, StreamItem, Timestamped};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+
+        pub trait AlertOnSilenceExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Emits an alert value whenever no value has arrived for `silence`, acting as a
+            /// dead man's switch for sources that are expected to report regularly.
+            ///
+            /// Unlike [`timeout`](crate::TimeoutExt::timeout), the stream does not end: the
+            /// alert is emitted as a regular value built by `alert` and timestamped with the
+            /// runtime's current instant, and values pass through again as soon as the source
+            /// resumes.
+            ///
+            /// - The silence is measured from the creation of the stream and from each value
+            /// - One alert is emitted per silence; the next value re-arms the switch
+            /// - Errors pass through and do not count as a sign of life
+            /// - The stream ends when the source ends
+            ///
+            /// # Arguments
+            ///
+            /// * `silence` - How long the source may go without a value
+            /// * `alert` - Builds the value emitted when the silence is exceeded
+            fn alert_on_silence<F>(self, silence: Duration, alert: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut() -> T::Inner + $($bounds)* 'static;
+        }
+
+        impl<S, T> AlertOnSilenceExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion<Timestamp = <DefaultRuntime as Runtime>::Instant> + $($bounds)*,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+        {
+            fn alert_on_silence<F>(self, silence: Duration, alert: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut() -> T::Inner + $($bounds)* 'static,
+            {
+                let timer = <DefaultRuntime as Runtime>::Timer::default();
+                Box::pin(AlertOnSilenceStream::<S, F, DefaultRuntime> {
+                    stream: self,
+                    silence,
+                    alert,
+                    sleep: Some(timer.sleep_future(silence)),
+                    timer,
+                    is_done: false,
+                })
+            }
+        }
+
+        #[pin_project]
+        struct AlertOnSilenceStream<S, F, R>
+        where
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            silence: Duration,
+            alert: F,
+            timer: R::Timer,
+            /// Fires when the silence is exceeded; `None` once the alert for the current
+            /// silence was emitted.
+            #[pin]
+            sleep: Option<<R::Timer as Timer>::Sleep>,
+            is_done: bool,
+        }
+
+        impl<S, T, F, R> Stream for AlertOnSilenceStream<S, F, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Timestamped<Timestamp = R::Instant>,
+            F: FnMut() -> T::Inner,
+            R: Runtime,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                if *this.is_done {
+                    return Poll::Ready(None);
+                }
+
+                match this.stream.poll_next(cx) {
+                    Poll::Ready(Some(StreamItem::Value(value))) => {
+                        this.sleep
+                            .set(Some(this.timer.sleep_future(*this.silence)));
+                        return Poll::Ready(Some(StreamItem::Value(value)));
+                    }
+                    Poll::Ready(Some(StreamItem::Error(err))) => {
+                        return Poll::Ready(Some(StreamItem::Error(err)));
+                    }
+                    Poll::Ready(None) => {
+                        *this.is_done = true;
+                        return Poll::Ready(None);
+                    }
+                    Poll::Pending => {}
+                }
+
+                if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                    if sleep.poll(cx).is_ready() {
+                        this.sleep.set(None);
+                        let alert = T::with_timestamp((this.alert)(), this.timer.now());
+                        return Poll::Ready(Some(StreamItem::Value(alert)));
+                    }
+                }
+
+                Poll::Pending
+            }
+        }
+    };
+}
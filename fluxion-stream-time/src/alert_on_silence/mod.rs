@@ -0,0 +1,59 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::AlertOnSilenceExt;
+
+#[cfg(all(
+    not(any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    )),
+    any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        feature = "runtime-embassy",
+        feature = "runtime-wasm"
+    )
+))]
+mod single_threaded;
+
+#[cfg(all(
+    not(any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    )),
+    any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        feature = "runtime-embassy",
+        feature = "runtime-wasm"
+    )
+))]
+pub use single_threaded::AlertOnSilenceExt;
@@ -0,0 +1,6 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#[rustfmt::skip]
+define_alert_on_silence_impl!(Send + Sync +);
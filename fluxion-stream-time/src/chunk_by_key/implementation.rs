@@ -0,0 +1,243 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_chunk_by_key_impl {
+    ($($bounds:tt)*) => {
+        use crate::{DefaultRuntime, TimerWheel};
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        #[cfg(not(feature = "std"))]
+        use alloc::{collections::{BTreeMap, VecDeque}, vec::Vec};
+        use fluxion_core::{Fluxion, StreamItem, Timestamped};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+        #[cfg(feature = "std")]
+        use std::collections::{BTreeMap, VecDeque};
+
+        pub trait ChunkByKeyExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Groups the items of each key into chunks and emits every chunk as
+            /// `(key, items)` once it holds `max_size` items or `max_latency` has passed
+            /// since its first item.
+            ///
+            /// Batching per key amortizes the cost of writing to keyed sinks, such as
+            /// partitioned topics or sharded databases, while `max_latency` bounds how
+            /// long an item of a quiet key can wait.
+            ///
+            /// - A key's chunk opens with its first item; items keep their order and
+            ///   timestamps within the chunk
+            /// - Chunks of different keys fill and flush independently, so chunks are
+            ///   emitted in the order they complete, not in the order they opened
+            /// - When the stream ends, the open chunks are flushed in the order they
+            ///   opened
+            /// - Latency deadlines run on a shared [`TimerWheel`], so many open keys
+            ///   still cost one runtime timer
+            ///
+            /// Errors pass through immediately and leave the open chunks untouched.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - Extracts the key an item is chunked by
+            /// * `max_size` - The number of items that completes a chunk
+            /// * `max_latency` - How long a chunk stays open after its first item
+            ///
+            /// # Panics
+            ///
+            /// Panics if `max_size` is zero.
+            fn chunk_by_key<K, F>(
+                self,
+                key: F,
+                max_size: usize,
+                max_latency: Duration,
+            ) -> impl Stream<Item = StreamItem<(K, Vec<T>)>> + $($bounds)*
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static;
+        }
+
+        impl<S, T> ChunkByKeyExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion + $($bounds)*,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn chunk_by_key<K, F>(
+                self,
+                key: F,
+                max_size: usize,
+                max_latency: Duration,
+            ) -> impl Stream<Item = StreamItem<(K, Vec<T>)>> + $($bounds)*
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+            {
+                assert!(max_size > 0, "chunk_by_key requires max_size > 0");
+
+                let timer = <DefaultRuntime as Runtime>::Timer::default();
+                Box::pin(ChunkByKeyStream::<S, T, K, F, DefaultRuntime> {
+                    stream: self,
+                    key,
+                    max_size,
+                    max_latency,
+                    chunks: BTreeMap::new(),
+                    flushes: TimerWheel::new(),
+                    ready: VecDeque::new(),
+                    observed_at: timer.now(),
+                    timer,
+                    sleep: None,
+                    clock: Duration::ZERO,
+                    deadline: Duration::ZERO,
+                    opened: 0,
+                    done: false,
+                })
+            }
+        }
+
+        /// The open chunk of a key.
+        struct Chunk<T> {
+            /// Number of chunks opened before this one, to flush in order at the end.
+            opened: u64,
+            items: Vec<T>,
+        }
+
+        #[pin_project]
+        struct ChunkByKeyStream<S, T, K, F, R>
+        where
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            key: F,
+            max_size: usize,
+            max_latency: Duration,
+            chunks: BTreeMap<K, Chunk<T>>,
+            /// Keys with an open chunk, until its latency elapses.
+            flushes: TimerWheel<K>,
+            ready: VecDeque<StreamItem<(K, Vec<T>)>>,
+            timer: R::Timer,
+            #[pin]
+            sleep: Option<<R::Timer as Timer>::Sleep>,
+            /// Time passed since the stream was created, which latencies are measured on.
+            clock: Duration,
+            observed_at: R::Instant,
+            /// Value of `clock` at which the armed `sleep` expires.
+            deadline: Duration,
+            opened: u64,
+            done: bool,
+        }
+
+        impl<S, T, K, F, R> Stream for ChunkByKeyStream<S, T, K, F, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Timestamped + Clone,
+            K: Ord + Clone,
+            F: Fn(&T::Inner) -> K,
+            R: Runtime,
+            R::Instant: Sub<Output = Duration>,
+        {
+            type Item = StreamItem<(K, Vec<T>)>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                loop {
+                    let now = this.timer.now();
+                    *this.clock += now - *this.observed_at;
+                    *this.observed_at = now;
+                    let clock = *this.clock;
+
+                    while !*this.done && this.ready.is_empty() {
+                        match this.stream.as_mut().poll_next(cx) {
+                            Poll::Ready(Some(StreamItem::Value(value))) => {
+                                let timestamp = value.timestamp();
+                                let inner = value.into_inner();
+                                let key = (this.key)(&inner);
+                                let value = T::with_timestamp(inner, timestamp);
+                                let chunk = this.chunks.entry(key.clone()).or_insert_with(|| {
+                                    this.flushes.insert(key.clone(), clock + *this.max_latency);
+                                    *this.opened += 1;
+                                    Chunk {
+                                        opened: *this.opened,
+                                        items: Vec::new(),
+                                    }
+                                });
+                                chunk.items.push(value);
+                                if chunk.items.len() >= *this.max_size {
+                                    if let Some(chunk) = this.chunks.remove(&key) {
+                                        this.flushes.remove(&key);
+                                        this.ready.push_back(StreamItem::Value((key, chunk.items)));
+                                    }
+                                }
+                            }
+                            Poll::Ready(Some(StreamItem::Error(err))) => {
+                                this.ready.push_back(StreamItem::Error(err));
+                            }
+                            Poll::Ready(None) => *this.done = true,
+                            Poll::Pending => break,
+                        }
+                    }
+
+                    while let Some((key, _)) = this.flushes.poll_expired(clock) {
+                        if let Some(chunk) = this.chunks.remove(&key) {
+                            this.ready.push_back(StreamItem::Value((key, chunk.items)));
+                        }
+                    }
+
+                    if *this.done && !this.chunks.is_empty() {
+                        let mut open: Vec<_> = core::mem::take(this.chunks).into_iter().collect();
+                        open.sort_by_key(|(_, chunk)| chunk.opened);
+                        this.ready.extend(
+                            open.into_iter()
+                                .map(|(key, chunk)| StreamItem::Value((key, chunk.items))),
+                        );
+                        *this.flushes = TimerWheel::new();
+                    }
+
+                    if let Some(item) = this.ready.pop_front() {
+                        return Poll::Ready(Some(item));
+                    }
+                    if *this.done {
+                        return Poll::Ready(None);
+                    }
+
+                    let Some(next) = this.flushes.next_deadline() else {
+                        this.sleep.set(None);
+                        return Poll::Pending;
+                    };
+
+                    if this.sleep.is_none() || next < *this.deadline {
+                        *this.deadline = next;
+                        this.sleep.set(Some(this.timer.sleep_future(next.saturating_sub(clock))));
+                    }
+                    if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                        if sleep.poll(cx).is_pending() {
+                            return Poll::Pending;
+                        }
+                    }
+                    this.sleep.set(None);
+
+                    // The timer may run on another clock than `now`, such as a paused
+                    // test clock, so its firing alone proves that the wait has passed
+                    *this.clock = core::cmp::max(*this.clock, *this.deadline);
+                }
+            }
+        }
+    };
+}
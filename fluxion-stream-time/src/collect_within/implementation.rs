@@ -0,0 +1,102 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_collect_within_impl {
+    ($($bounds:tt)*) => {
+        use crate::DefaultRuntime;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::mem;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        use alloc::vec::Vec;
+        use fluxion_core::{Fluxion, StreamItem};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+
+        pub trait CollectWithinExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Collects the items the stream emits within `duration` and resolves to them,
+            /// without waiting for the stream to end.
+            ///
+            /// The window starts when `collect_within` is called and is measured with the
+            /// runtime's timer, so it follows a paused or advanced virtual clock in tests.
+            ///
+            /// - Items ready when the window closes are still collected.
+            /// - If the stream ends first, the future resolves right away.
+            /// - Errors are collected like values.
+            ///
+            /// Called on `&mut stream`, the stream can be read on afterwards.
+            ///
+            /// # Arguments
+            ///
+            /// * `duration` - How long to collect for
+            fn collect_within(self, duration: Duration) -> impl Future<Output = Vec<StreamItem<T>>> + $($bounds)*;
+        }
+
+        impl<S, T> CollectWithinExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn collect_within(self, duration: Duration) -> impl Future<Output = Vec<StreamItem<T>>> + $($bounds)* {
+                CollectWithin::<S, T, DefaultRuntime> {
+                    stream: self,
+                    sleep: <DefaultRuntime as Runtime>::Timer::default().sleep_future(duration),
+                    items: Vec::new(),
+                }
+            }
+        }
+
+        #[pin_project]
+        struct CollectWithin<S, T, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            #[pin]
+            sleep: <R::Timer as Timer>::Sleep,
+            items: Vec<StreamItem<T>>,
+        }
+
+        impl<S, T, R> Future for CollectWithin<S, T, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            R: Runtime,
+        {
+            type Output = Vec<StreamItem<T>>;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                let mut this = self.project();
+
+                loop {
+                    match this.stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(item)) => this.items.push(item),
+                        Poll::Ready(None) => return Poll::Ready(mem::take(this.items)),
+                        Poll::Pending => break,
+                    }
+                }
+
+                match this.sleep.poll(cx) {
+                    Poll::Ready(()) => Poll::Ready(mem::take(this.items)),
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+        }
+    };
+}
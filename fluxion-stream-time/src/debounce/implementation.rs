@@ -47,6 +47,19 @@ macro_rules! define_debounce_impl {
             ///
             /// * `duration` - The duration of required inactivity before emitting a value
             fn debounce(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Debounces the stream like [`debounce`](Self::debounce), without boxing.
+            ///
+            /// The returned stream holds the source, the pending value and the timer
+            /// inline, so it never allocates and suits targets without a heap. It is not
+            /// `Unpin`; pin it on the stack with [`core::pin::pin!`] before polling.
+            fn debounce_heapless(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Debounces the stream like [`debounce`](Self::debounce), rounding every
+            /// deadline up to the next multiple of `slack` on the runtime's clock (see
+            /// [`Timer::sleep_coalesced`]), so debounces that share a slack wake the core
+            /// once. Values are emitted up to `slack` late, never early.
+            fn debounce_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
         }
 
         impl<S, T> DebounceExt<T, DefaultRuntime> for S
@@ -56,9 +69,25 @@ macro_rules! define_debounce_impl {
             T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
         {
             fn debounce(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                Box::pin(self.debounce_heapless(duration))
+            }
+
+            fn debounce_heapless(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                DebounceStream::<S, T, DefaultRuntime> {
+                    stream: self,
+                    duration,
+                    slack: Duration::ZERO,
+                    pending_value: None,
+                    sleep: None,
+                    stream_ended: false,
+                }
+            }
+
+            fn debounce_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
                 Box::pin(DebounceStream::<S, T, DefaultRuntime> {
                     stream: self,
                     duration,
+                    slack,
                     pending_value: None,
                     sleep: None,
                     stream_ended: false,
@@ -77,6 +106,7 @@ macro_rules! define_debounce_impl {
             #[pin]
             stream: S,
             duration: Duration,
+            slack: Duration,
             pending_value: Option<StreamItem<T>>,
             #[pin]
             sleep: Option<<R::Timer as Timer>::Sleep>,
@@ -118,7 +148,7 @@ macro_rules! define_debounce_impl {
                     match this.stream.as_mut().poll_next(cx) {
                         Poll::Ready(Some(StreamItem::Value(value))) => {
                             let timer = R::Timer::default();
-                            this.sleep.set(Some(timer.sleep_future(*this.duration)));
+                            this.sleep.set(Some(timer.sleep_coalesced(*this.duration, *this.slack)));
 
                             *this.pending_value = Some(StreamItem::Value(value));
 
@@ -36,6 +36,11 @@ macro_rules! define_delay_impl {
             ///
             /// * `duration` - The duration by which to delay each emission
             fn delay(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Delays each emission like [`delay`](Self::delay), rounding every deadline
+            /// up to the next multiple of `slack` on the runtime's clock (see
+            /// [`Timer::sleep_coalesced`]). Items are delayed by up to `slack` more.
+            fn delay_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
         }
 
         impl<S, T> DelayExt<T, DefaultRuntime> for S
@@ -45,9 +50,14 @@ macro_rules! define_delay_impl {
             T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
         {
             fn delay(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                self.delay_with_slack(duration, Duration::ZERO)
+            }
+
+            fn delay_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
                 DelayStream::<S, T, DefaultRuntime> {
                     stream: self,
                     duration,
+                    slack,
                     in_flight: FuturesOrdered::new(),
                     upstream_done: false,
                 }
@@ -97,6 +107,7 @@ macro_rules! define_delay_impl {
             #[pin]
             stream: S,
             duration: Duration,
+            slack: Duration,
             in_flight: FuturesOrdered<DelayFuture<T, R>>,
             upstream_done: bool,
         }
@@ -118,7 +129,7 @@ macro_rules! define_delay_impl {
                         match this.stream.as_mut().poll_next(cx) {
                             Poll::Ready(Some(StreamItem::Value(value))) => {
                                 let future = DelayFuture {
-                                    delay: R::Timer::default().sleep_future(*this.duration),
+                                    delay: R::Timer::default().sleep_coalesced(*this.duration, *this.slack),
                                     value: Some(value),
                                 };
                                 this.in_flight.push_back(future);
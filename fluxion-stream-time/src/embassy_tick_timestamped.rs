@@ -0,0 +1,96 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::cmp::Ordering;
+use core::ops::Deref;
+use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_runtime::impls::embassy::EmbassyTicks;
+
+/// A value stamped with the raw Embassy tick count.
+///
+/// Unlike [`EmbassyTimestamped`](crate::EmbassyTimestamped), ordering follows
+/// [`EmbassyTicks`], which stays correct when the tick counter wraps through
+/// `u64::MAX`. Ordering operators such as `ordered_merge` and `combine_latest` can
+/// therefore run indefinitely on long-lived devices, provided all timestamps in flight
+/// are less than 2<sup>63</sup> ticks apart.
+///
+/// The time-based operators in this crate work on
+/// [`EmbassyTimestamped`](crate::EmbassyTimestamped) values; convert with
+/// [`EmbassyTicks::from`] where both are needed.
+#[derive(Debug, Clone)]
+pub struct EmbassyTickTimestamped<T> {
+    pub value: T,
+    pub timestamp: EmbassyTicks,
+}
+
+impl<T> EmbassyTickTimestamped<T> {
+    pub fn new(value: T, timestamp: EmbassyTicks) -> Self {
+        Self { value, timestamp }
+    }
+
+    /// Stamps `value` with the current tick count.
+    pub fn now(value: T) -> Self {
+        Self::new(value, EmbassyTicks::now())
+    }
+}
+
+impl<T> HasTimestamp for EmbassyTickTimestamped<T> {
+    type Timestamp = EmbassyTicks;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.timestamp
+    }
+}
+
+impl<T> Timestamped for EmbassyTickTimestamped<T>
+where
+    T: Clone,
+{
+    type Inner = T;
+
+    fn into_inner(self) -> Self::Inner {
+        self.value
+    }
+
+    fn with_timestamp(inner: Self::Inner, timestamp: Self::Timestamp) -> Self {
+        Self::new(inner, timestamp)
+    }
+}
+
+impl<T> PartialEq for EmbassyTickTimestamped<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp && self.value == other.value
+    }
+}
+
+impl<T> Eq for EmbassyTickTimestamped<T> where T: Eq {}
+
+impl<T> PartialOrd for EmbassyTickTimestamped<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.timestamp.partial_cmp(&other.timestamp)
+    }
+}
+
+impl<T> Ord for EmbassyTickTimestamped<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp.cmp(&other.timestamp)
+    }
+}
+
+impl<T> Deref for EmbassyTickTimestamped<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
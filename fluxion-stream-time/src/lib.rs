@@ -4,12 +4,70 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+fluxion_runtime::require_runtime!("fluxion-stream-time");
+
 mod instant_timestamped;
+mod timer_wheel;
 pub use instant_timestamped::InstantTimestamped;
+pub use timer_wheel::TimerWheel;
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod alert_on_silence;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use alert_on_silence::AlertOnSilenceExt;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod chunk_by_key;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use chunk_by_key::ChunkByKeyExt;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod collect_within;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use collect_within::CollectWithinExt;
+
 #[cfg(any(
     all(feature = "runtime-tokio", not(target_arch = "wasm32")),
     feature = "runtime-smol",
@@ -46,6 +104,42 @@ mod delay;
 ))]
 pub use delay::DelayExt;
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod quota;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use quota::{QuotaExt, QuotaPolicy, QuotaViolations};
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod rate_limit;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use rate_limit::RateLimitExt;
+
 #[cfg(any(
     all(feature = "runtime-tokio", not(target_arch = "wasm32")),
     feature = "runtime-smol",
@@ -64,6 +158,72 @@ mod sample;
 ))]
 pub use sample::SampleExt;
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod schedule;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use schedule::{schedule_with_clock, Cron, CronError};
+
+#[cfg(all(
+    feature = "std",
+    not(target_arch = "wasm32"),
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        feature = "runtime-embassy"
+    )
+))]
+pub use schedule::schedule;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod summarize_errors;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use summarize_errors::{ErrorReport, ErrorReports, SummarizeErrorsExt};
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod tap_ring;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use tap_ring::{RingEntry, TapRing, TapRingExt};
+
 #[cfg(any(
     all(feature = "runtime-tokio", not(target_arch = "wasm32")),
     feature = "runtime-smol",
@@ -100,6 +260,24 @@ mod timeout;
 ))]
 pub use timeout::TimeoutExt;
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+mod watchdog;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    feature = "runtime-wasm"
+))]
+pub use watchdog::{StallEvent, StallKind, WatchdogExt};
+
 #[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
 pub use fluxion_runtime::impls::tokio::TokioRuntime;
 
@@ -142,35 +320,13 @@ pub use fluxion_runtime::impls::embassy::EmbassyRuntime;
 #[cfg(feature = "runtime-embassy")]
 pub type EmbassyTimestamped<T> = InstantTimestamped<T, EmbassyRuntime>;
 
-#[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
-pub type DefaultRuntime = fluxion_runtime::impls::tokio::TokioRuntime;
-
-#[cfg(all(
-    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
-    feature = "runtime-smol"
-))]
-pub type DefaultRuntime = fluxion_runtime::impls::smol::SmolRuntime;
+#[cfg(feature = "runtime-embassy")]
+mod embassy_tick_timestamped;
 
-#[cfg(all(
-    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
-    not(feature = "runtime-smol"),
-    feature = "runtime-async-std"
-))]
-pub type DefaultRuntime = fluxion_runtime::impls::async_std::AsyncStdRuntime;
+#[cfg(feature = "runtime-embassy")]
+pub use embassy_tick_timestamped::EmbassyTickTimestamped;
 
-#[cfg(all(
-    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
-    not(feature = "runtime-smol"),
-    not(feature = "runtime-async-std"),
-    feature = "runtime-embassy"
-))]
-pub type DefaultRuntime = fluxion_runtime::impls::embassy::EmbassyRuntime;
+#[cfg(feature = "runtime-embassy")]
+pub use fluxion_runtime::impls::embassy::EmbassyTicks;
 
-#[cfg(all(
-    not(all(feature = "runtime-tokio", not(target_arch = "wasm32"))),
-    not(feature = "runtime-smol"),
-    not(feature = "runtime-async-std"),
-    not(feature = "runtime-embassy"),
-    feature = "runtime-wasm"
-))]
-pub type DefaultRuntime = fluxion_runtime::impls::wasm::WasmRuntime;
+pub use fluxion_runtime::default_runtime::DefaultRuntime;
@@ -0,0 +1,389 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::DefaultRuntime;
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use fluxion_core::StreamItem;
+use fluxion_runtime::mutex::MutexLike;
+use fluxion_runtime::runtime::Runtime;
+use futures::Stream;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// What [`quota`](crate::QuotaExt::quota) does with items beyond the limit of their key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaPolicy {
+    /// Discards the excess items.
+    Drop,
+    /// Holds the excess items until the window of their key ends, and emits them as
+    /// part of the next window.
+    Delay,
+    /// Sends the excess items to the [`QuotaViolations`] stream.
+    Divert,
+}
+
+pub(crate) struct ViolationState<T> {
+    items: VecDeque<T>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// Stream of the items diverted by [`quota`](crate::QuotaExt::quota) under
+/// [`QuotaPolicy::Divert`].
+///
+/// Items are diverted while the main stream is polled, in arrival order. The stream
+/// ends once the main stream has ended or was dropped and every diverted item was
+/// taken. Under the other policies it ends without emitting anything.
+pub struct QuotaViolations<T, R: Runtime = DefaultRuntime> {
+    state: R::Mutex<ViolationState<T>>,
+}
+
+impl<T, R: Runtime> QuotaViolations<T, R> {
+    pub(crate) fn new() -> (Self, ViolationSender<T, R>) {
+        let state = R::Mutex::new(ViolationState {
+            items: VecDeque::new(),
+            waker: None,
+            closed: false,
+        });
+        let sender = ViolationSender {
+            state: state.clone(),
+        };
+        (Self { state }, sender)
+    }
+}
+
+impl<T, R: Runtime> Stream for QuotaViolations<T, R> {
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock();
+        if let Some(item) = state.items.pop_front() {
+            Poll::Ready(Some(StreamItem::Value(item)))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T, R: Runtime> Unpin for QuotaViolations<T, R> {}
+
+impl<T, R: Runtime> Debug for QuotaViolations<T, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let state = self.state.lock();
+        f.debug_struct("QuotaViolations")
+            .field("pending", &state.items.len())
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+/// Feeds [`QuotaViolations`], and ends it when dropped with the main stream.
+pub(crate) struct ViolationSender<T, R: Runtime> {
+    state: R::Mutex<ViolationState<T>>,
+}
+
+impl<T, R: Runtime> ViolationSender<T, R> {
+    pub(crate) fn send(&self, item: T) {
+        let mut state = self.state.lock();
+        state.items.push_back(item);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    pub(crate) fn close(&self) {
+        let mut state = self.state.lock();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T, R: Runtime> Drop for ViolationSender<T, R> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+macro_rules! define_quota_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{QuotaPolicy, QuotaViolations, ViolationSender};
+        use crate::{DefaultRuntime, TimerWheel};
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        #[cfg(not(feature = "std"))]
+        use alloc::{collections::{BTreeMap, VecDeque}, vec::Vec};
+        use fluxion_core::{Fluxion, StreamItem, Timestamped};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+        #[cfg(feature = "std")]
+        use std::collections::{BTreeMap, VecDeque};
+
+        pub trait QuotaExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Lets at most `limit` items per key through in every `window`, and handles
+            /// the excess items according to `policy`.
+            ///
+            /// A key's window starts with its first item and lasts `window`; the next
+            /// item of the key after that starts a new window with a fresh count. Items
+            /// beyond the limit are dropped, delayed to the next window of their key, or
+            /// diverted to the returned [`QuotaViolations`] stream.
+            ///
+            /// Items of a key held under [`QuotaPolicy::Delay`] do not hold back other
+            /// keys, so items of different keys may be emitted out of arrival order.
+            /// Held items are still emitted when the stream ends.
+            ///
+            /// Errors pass through immediately and are not counted.
+            ///
+            /// # Arguments
+            ///
+            /// * `limit` - The number of items a key may emit per window
+            /// * `window` - The length of a key's window
+            /// * `key` - Extracts the key an item is counted against
+            /// * `policy` - What happens to items beyond the limit
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is zero.
+            fn quota<K, F>(
+                self,
+                limit: u32,
+                window: Duration,
+                key: F,
+                policy: QuotaPolicy,
+            ) -> (impl Stream<Item = StreamItem<T>> + $($bounds)*, QuotaViolations<T, R>)
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static;
+        }
+
+        impl<S, T> QuotaExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion + $($bounds)*,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn quota<K, F>(
+                self,
+                limit: u32,
+                window: Duration,
+                key: F,
+                policy: QuotaPolicy,
+            ) -> (impl Stream<Item = StreamItem<T>> + $($bounds)*, QuotaViolations<T, DefaultRuntime>)
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+            {
+                assert!(limit > 0, "quota requires limit > 0");
+
+                let (violations, sender) = QuotaViolations::new();
+                let timer = <DefaultRuntime as Runtime>::Timer::default();
+                let stream = Box::pin(QuotaStream::<S, T, K, F, DefaultRuntime> {
+                    stream: self,
+                    key,
+                    limit,
+                    window,
+                    policy,
+                    violations: sender,
+                    observed_at: timer.now(),
+                    timer,
+                    windows: BTreeMap::new(),
+                    releases: TimerWheel::new(),
+                    expiries: TimerWheel::new(),
+                    ready: VecDeque::new(),
+                    sleep: None,
+                    clock: Duration::ZERO,
+                    deadline: Duration::ZERO,
+                    arrivals: 0,
+                    done: false,
+                });
+                (stream, violations)
+            }
+        }
+
+        /// The current window of a key.
+        struct KeyWindow<T> {
+            started: Duration,
+            count: u32,
+            held: VecDeque<(u64, T)>,
+        }
+
+        #[pin_project]
+        struct QuotaStream<S, T, K, F, R>
+        where
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            key: F,
+            limit: u32,
+            window: Duration,
+            policy: QuotaPolicy,
+            violations: ViolationSender<T, R>,
+            timer: R::Timer,
+            windows: BTreeMap<K, KeyWindow<T>>,
+            /// Keys with held items, until their window ends.
+            releases: TimerWheel<K>,
+            /// Every key, until its window ends and it can be discarded.
+            expiries: TimerWheel<K>,
+            ready: VecDeque<StreamItem<T>>,
+            #[pin]
+            sleep: Option<<R::Timer as Timer>::Sleep>,
+            /// Time passed since the stream was created, which windows are measured on.
+            clock: Duration,
+            observed_at: R::Instant,
+            /// Value of `clock` at which the armed `sleep` expires.
+            deadline: Duration,
+            arrivals: u64,
+            done: bool,
+        }
+
+        impl<S, T, K, F, R> Stream for QuotaStream<S, T, K, F, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Timestamped + Clone,
+            K: Ord + Clone,
+            F: Fn(&T::Inner) -> K,
+            R: Runtime,
+            R::Instant: Sub<Output = Duration>,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                loop {
+                    let now = this.timer.now();
+                    *this.clock += now - *this.observed_at;
+                    *this.observed_at = now;
+                    let clock = *this.clock;
+                    let window = *this.window;
+
+                    while !*this.done && this.ready.is_empty() {
+                        match this.stream.as_mut().poll_next(cx) {
+                            Poll::Ready(Some(StreamItem::Value(value))) => {
+                                let timestamp = value.timestamp();
+                                let inner = value.into_inner();
+                                let key = (this.key)(&inner);
+                                let value = T::with_timestamp(inner, timestamp);
+                                let current = this.windows.entry(key.clone()).or_insert_with(|| KeyWindow {
+                                    started: clock,
+                                    count: 0,
+                                    held: VecDeque::new(),
+                                });
+                                if current.held.is_empty() && clock - current.started >= window {
+                                    current.started = clock;
+                                    current.count = 0;
+                                }
+                                if current.count == 0 {
+                                    // The window starts with this item
+                                    this.expiries.insert(key.clone(), current.started + window);
+                                }
+
+                                if current.held.is_empty() && current.count < *this.limit {
+                                    current.count += 1;
+                                    this.ready.push_back(StreamItem::Value(value));
+                                } else {
+                                    match this.policy {
+                                        QuotaPolicy::Drop => {}
+                                        QuotaPolicy::Delay => {
+                                            if current.held.is_empty() {
+                                                this.releases.insert(key, current.started + window);
+                                            }
+                                            current.held.push_back((*this.arrivals, value));
+                                        }
+                                        QuotaPolicy::Divert => this.violations.send(value),
+                                    }
+                                }
+                                *this.arrivals += 1;
+                            }
+                            Poll::Ready(Some(StreamItem::Error(err))) => {
+                                this.ready.push_back(StreamItem::Error(err));
+                            }
+                            Poll::Ready(None) => *this.done = true,
+                            Poll::Pending => break,
+                        }
+                    }
+
+                    // Start a new window for every key whose held items can go now
+                    let mut released = Vec::new();
+                    while let Some((key, _)) = this.releases.poll_expired(clock) {
+                        let Some(current) = this.windows.get_mut(&key) else {
+                            continue;
+                        };
+                        current.started = clock;
+                        let count = current.held.len().min(*this.limit as usize);
+                        current.count = count as u32;
+                        released.extend(current.held.drain(..count));
+                        this.expiries.insert(key.clone(), clock + window);
+                        if !current.held.is_empty() {
+                            this.releases.insert(key, clock + window);
+                        }
+                    }
+                    released.sort_by_key(|(arrival, _)| *arrival);
+                    this.ready
+                        .extend(released.into_iter().map(|(_, value)| StreamItem::Value(value)));
+
+                    while let Some((key, _)) = this.expiries.poll_expired(clock) {
+                        if this.windows.get(&key).is_some_and(|current| current.held.is_empty()) {
+                            this.windows.remove(&key);
+                        }
+                    }
+
+                    if let Some(item) = this.ready.pop_front() {
+                        return Poll::Ready(Some(item));
+                    }
+
+                    let Some(next) = this.releases.next_deadline() else {
+                        this.sleep.set(None);
+                        if *this.done {
+                            this.violations.close();
+                            return Poll::Ready(None);
+                        }
+                        return Poll::Pending;
+                    };
+
+                    if this.sleep.is_none() || next < *this.deadline {
+                        *this.deadline = next;
+                        this.sleep.set(Some(this.timer.sleep_future(next.saturating_sub(clock))));
+                    }
+                    if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                        if sleep.poll(cx).is_pending() {
+                            return Poll::Pending;
+                        }
+                    }
+                    this.sleep.set(None);
+
+                    // The timer may run on another clock than `now`, such as a paused
+                    // test clock, so its firing alone proves that the wait has passed
+                    *this.clock = core::cmp::max(*this.clock, *this.deadline);
+                }
+            }
+        }
+    };
+}
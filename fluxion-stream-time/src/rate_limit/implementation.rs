@@ -0,0 +1,312 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_rate_limit_impl {
+    ($($bounds:tt)*) => {
+        use crate::{DefaultRuntime, TimerWheel};
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        #[cfg(not(feature = "std"))]
+        use alloc::collections::{BTreeMap, VecDeque};
+        use fluxion_core::{Fluxion, FluxionError, StreamItem, Timestamped};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+        #[cfg(feature = "std")]
+        use std::collections::{BTreeMap, VecDeque};
+
+        /// Smallest number of items a key may hold before the source stops being polled.
+        const MIN_HELD_PER_KEY: usize = 64;
+
+        pub trait RateLimitExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Delays items so that at most `permits_per_sec` are emitted per second on
+            /// average, with bursts of up to `burst` items.
+            ///
+            /// Implements a token bucket that starts full: every emitted item takes a
+            /// token, and tokens are refilled at `permits_per_sec`, up to `burst`. An item
+            /// arriving while the bucket is empty is held until a token is available.
+            /// Unlike [`throttle`](crate::ThrottleExt::throttle), no item is dropped;
+            /// held items are buffered and emitted in arrival order. Once `burst` items,
+            /// or at least 64, are held, the source is not polled until one of them is
+            /// emitted, so a fast producer is slowed down instead of filling memory.
+            ///
+            /// Errors take no token and are emitted right after the items held before
+            /// them.
+            ///
+            /// # Arguments
+            ///
+            /// * `permits_per_sec` - The sustained number of items per second
+            /// * `burst` - The number of items that may be emitted back to back
+            ///
+            /// # Panics
+            ///
+            /// Panics if `permits_per_sec` or `burst` is zero.
+            fn rate_limit(self, permits_per_sec: u32, burst: u32) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Like [`rate_limit`](Self::rate_limit), with a separate token bucket for
+            /// every key returned by `key`.
+            ///
+            /// Items of a key waiting for a token do not hold back items of other keys,
+            /// so items of different keys may be emitted out of arrival order. Items of
+            /// the same key keep their order. The source is not polled while any key holds
+            /// `burst` items, or at least 64. A key's bucket is discarded once it is full again and none
+            /// of its items are held.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `permits_per_sec` or `burst` is zero.
+            fn rate_limit_by_key<K, F>(
+                self,
+                permits_per_sec: u32,
+                burst: u32,
+                key: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static;
+        }
+
+        impl<S, T> RateLimitExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion + $($bounds)*,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn rate_limit(self, permits_per_sec: u32, burst: u32) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                self.rate_limit_by_key(permits_per_sec, burst, |_| ())
+            }
+
+            fn rate_limit_by_key<K, F>(
+                self,
+                permits_per_sec: u32,
+                burst: u32,
+                key: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+            {
+                assert!(permits_per_sec > 0, "rate_limit requires permits_per_sec > 0");
+                assert!(burst > 0, "rate_limit requires burst > 0");
+
+                let cost = Duration::from_secs(1) / permits_per_sec;
+                let timer = <DefaultRuntime as Runtime>::Timer::default();
+                Box::pin(RateLimitStream::<S, T, K, F, DefaultRuntime> {
+                    stream: self,
+                    key,
+                    cost,
+                    capacity: cost * burst,
+                    held_limit: (burst as usize).max(MIN_HELD_PER_KEY),
+                    full_keys: 0,
+                    errors: VecDeque::new(),
+                    observed_at: timer.now(),
+                    timer,
+                    buckets: BTreeMap::new(),
+                    ready: BTreeMap::new(),
+                    refills: TimerWheel::new(),
+                    expiries: TimerWheel::new(),
+                    sleep: None,
+                    clock: Duration::ZERO,
+                    deadline: Duration::ZERO,
+                    arrivals: 0,
+                    done: false,
+                })
+            }
+        }
+
+        /// A token bucket, with the tokens expressed as the time they took to refill.
+        struct Bucket<T> {
+            credit: Duration,
+            refilled_at: Duration,
+            held: VecDeque<(u64, T)>,
+        }
+
+        impl<T> Bucket<T> {
+            fn refill(&mut self, clock: Duration, capacity: Duration) {
+                self.credit = core::cmp::min(capacity, self.credit + (clock - self.refilled_at));
+                self.refilled_at = clock;
+            }
+        }
+
+        #[pin_project]
+        struct RateLimitStream<S, T, K, F, R>
+        where
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            key: F,
+            cost: Duration,
+            capacity: Duration,
+            /// Number of items a key may hold before the source stops being polled.
+            held_limit: usize,
+            /// Number of keys holding `held_limit` items.
+            full_keys: usize,
+            /// Errors waiting for the items held before them, by their arrival.
+            errors: VecDeque<(u64, FluxionError)>,
+            timer: R::Timer,
+            buckets: BTreeMap<K, Bucket<T>>,
+            /// Keys whose first held item has a token, by the arrival of that item.
+            ready: BTreeMap<u64, K>,
+            /// Keys whose first held item waits for a token, until it is refilled.
+            refills: TimerWheel<K>,
+            /// Keys without held items, until their bucket is full and can be discarded.
+            expiries: TimerWheel<K>,
+            #[pin]
+            sleep: Option<<R::Timer as Timer>::Sleep>,
+            /// Time passed since the stream was created, which buckets are refilled by.
+            clock: Duration,
+            observed_at: R::Instant,
+            /// Value of `clock` at which the armed `sleep` expires.
+            deadline: Duration,
+            arrivals: u64,
+            done: bool,
+        }
+
+        impl<S, T, K, F, R> Stream for RateLimitStream<S, T, K, F, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Timestamped + Clone,
+            K: Ord + Clone,
+            F: Fn(&T::Inner) -> K,
+            R: Runtime,
+            R::Instant: Sub<Output = Duration>,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                loop {
+                    let now = this.timer.now();
+                    *this.clock += now - *this.observed_at;
+                    *this.observed_at = now;
+
+                    let clock = *this.clock;
+                    let cost = *this.cost;
+                    let capacity = *this.capacity;
+
+                    while !*this.done && *this.full_keys == 0 && this.errors.is_empty() {
+                        match this.stream.as_mut().poll_next(cx) {
+                            Poll::Ready(Some(StreamItem::Value(value))) => {
+                                let timestamp = value.timestamp();
+                                let inner = value.into_inner();
+                                let key = (this.key)(&inner);
+                                let value = T::with_timestamp(inner, timestamp);
+                                let bucket = this.buckets.entry(key.clone()).or_insert_with(|| Bucket {
+                                    credit: capacity,
+                                    refilled_at: clock,
+                                    held: VecDeque::new(),
+                                });
+                                bucket.held.push_back((*this.arrivals, value));
+                                if bucket.held.len() == *this.held_limit {
+                                    *this.full_keys += 1;
+                                }
+                                if bucket.held.len() == 1 {
+                                    this.expiries.remove(&key);
+                                    bucket.refill(clock, capacity);
+                                    if bucket.credit >= cost {
+                                        this.ready.insert(*this.arrivals, key);
+                                    } else {
+                                        this.refills.insert(key, clock + (cost - bucket.credit));
+                                    }
+                                }
+                                *this.arrivals += 1;
+                            }
+                            Poll::Ready(Some(StreamItem::Error(err))) => {
+                                this.errors.push_back((*this.arrivals, err));
+                                *this.arrivals += 1;
+                            }
+                            Poll::Ready(None) => *this.done = true,
+                            Poll::Pending => break,
+                        }
+                    }
+
+                    while let Some((key, _)) = this.refills.poll_expired(clock) {
+                        if let Some((arrival, _)) = this.buckets.get(&key).and_then(|bucket| bucket.held.front()) {
+                            this.ready.insert(*arrival, key);
+                        }
+                    }
+                    while let Some((key, _)) = this.expiries.poll_expired(clock) {
+                        this.buckets.remove(&key);
+                    }
+
+                    // Emit an error once every item held before it has been emitted
+                    if let Some((arrival, _)) = this.errors.front() {
+                        let held_before = this.buckets.values().any(|bucket| {
+                            bucket.held.front().is_some_and(|(held, _)| held < arrival)
+                        });
+                        if !held_before {
+                            if let Some((_, err)) = this.errors.pop_front() {
+                                return Poll::Ready(Some(StreamItem::Error(err)));
+                            }
+                        }
+                    }
+
+                    // Emit the earliest held arrival whose bucket has a token
+                    if let Some((_, key)) = this.ready.pop_first() {
+                        if let Some(bucket) = this.buckets.get_mut(&key) {
+                            bucket.refill(clock, capacity);
+                            if let Some((_, value)) = bucket.held.pop_front() {
+                                if bucket.held.len() + 1 == *this.held_limit {
+                                    *this.full_keys -= 1;
+                                }
+                                bucket.credit -= cost;
+                                match bucket.held.front() {
+                                    Some((arrival, _)) if bucket.credit >= cost => {
+                                        this.ready.insert(*arrival, key);
+                                    }
+                                    Some(_) => {
+                                        this.refills.insert(key, clock + (cost - bucket.credit));
+                                    }
+                                    None => {
+                                        this.expiries.insert(key, clock + (capacity - bucket.credit));
+                                    }
+                                }
+                                return Poll::Ready(Some(StreamItem::Value(value)));
+                            }
+                        }
+                        continue;
+                    }
+
+                    let Some(next) = this.refills.next_deadline() else {
+                        this.sleep.set(None);
+                        return if *this.done { Poll::Ready(None) } else { Poll::Pending };
+                    };
+
+                    if this.sleep.is_none() || next < *this.deadline {
+                        *this.deadline = next;
+                        this.sleep.set(Some(this.timer.sleep_future(next.saturating_sub(clock))));
+                    }
+                    if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                        if sleep.poll(cx).is_pending() {
+                            return Poll::Pending;
+                        }
+                    }
+                    this.sleep.set(None);
+
+                    // The timer may run on another clock than `now`, such as a paused
+                    // test clock, so its firing alone proves that the wait has passed
+                    *this.clock = core::cmp::max(*this.clock, *this.deadline);
+                }
+            }
+        }
+    };
+}
@@ -6,7 +6,6 @@ macro_rules! define_sample_impl {
     ($($bounds:tt)*) => {
         use crate::DefaultRuntime;
         use core::fmt::Debug;
-        use core::future::Future;
         use core::pin::Pin;
         use core::task::{Context, Poll};
         use core::time::Duration;
@@ -17,7 +16,7 @@ macro_rules! define_sample_impl {
         use fluxion_core::{Fluxion, HasTimestamp, StreamItem};
         use fluxion_runtime::runtime::Runtime;
         use fluxion_runtime::timer::Timer;
-        use futures::Stream;
+        use futures::{Stream, StreamExt};
         use pin_project::pin_project;
 
         pub trait SampleExt<T, R>: Stream<Item = StreamItem<T>> + Sized
@@ -40,6 +39,12 @@ macro_rules! define_sample_impl {
             ///
             /// * `duration` - The sampling interval
             fn sample(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Samples the stream like [`sample`](Self::sample), with every tick aligned to
+            /// a multiple of `slack` on the runtime's clock (see
+            /// [`Timer::interval_coalesced`]). The interval is rounded up to a multiple of
+            /// `slack`.
+            fn sample_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
         }
 
         impl<S, T> SampleExt<T, DefaultRuntime> for S
@@ -49,10 +54,13 @@ macro_rules! define_sample_impl {
             T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
         {
             fn sample(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                self.sample_with_slack(duration, Duration::ZERO)
+            }
+
+            fn sample_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
                 Box::pin(SampleStream::<S, T, DefaultRuntime> {
                     stream: self,
-                    duration,
-                    sleep: Some(<DefaultRuntime as Runtime>::Timer::default().sleep_future(duration)),
+                    interval: <DefaultRuntime as Runtime>::Timer::default().interval_coalesced(duration, slack),
                     pending_value: None,
                     is_done: false,
                 })
@@ -68,9 +76,7 @@ macro_rules! define_sample_impl {
         {
             #[pin]
             stream: S,
-            duration: Duration,
-            #[pin]
-            sleep: Option<<R::Timer as Timer>::Sleep>,
+            interval: <R::Timer as Timer>::Interval,
             pending_value: Option<StreamItem<T>>,
             is_done: bool,
         }
@@ -112,22 +118,15 @@ macro_rules! define_sample_impl {
                     }
                 }
 
-                if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
-                    match sleep.poll(cx) {
+                loop {
+                    match this.interval.poll_next_unpin(cx) {
                         Poll::Ready(_) => {
-                            this.sleep
-                                .set(Some(R::Timer::default().sleep_future(*this.duration)));
-
                             if let Some(value) = this.pending_value.take() {
-                                Poll::Ready(Some(value))
-                            } else {
-                                Poll::Pending
+                                return Poll::Ready(Some(value));
                             }
                         }
-                        Poll::Pending => Poll::Pending,
+                        Poll::Pending => return Poll::Pending,
                     }
-                } else {
-                    unreachable!("sleep future should always be Some after initialization")
                 }
             }
         }
@@ -0,0 +1,382 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::fmt;
+use core::str::FromStr;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+// A schedule that matches at all matches within this many years, since the calendar
+// repeats its leap years and weekdays every 28 years within a century
+const SEARCH_YEARS: u64 = 28;
+
+/// Error returned when parsing a [`Cron`] expression fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CronError {
+    /// The expression has this many fields instead of 5 or 6.
+    FieldCount(usize),
+    /// The named field holds a value that cannot be parsed or is out of range.
+    InvalidField(&'static str),
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldCount(count) => {
+                write!(f, "cron expression has {count} fields, expected 5 or 6")
+            }
+            Self::InvalidField(field) => write!(f, "invalid {field} field in cron expression"),
+        }
+    }
+}
+
+impl core::error::Error for CronError {}
+
+/// A parsed cron expression, matching times in UTC.
+///
+/// The expression has five fields, `minute hour day-of-month month day-of-week`, or six
+/// with a leading `second` field; with five fields, times match at second 0. Each field
+/// is `*`, a value, a range `a-b`, a step `*/n`, `a/n` or `a-b/n`, or a comma-separated
+/// list of those. Months accept `JAN`-`DEC` and days of the week `SUN`-`SAT`, with both
+/// 0 and 7 meaning Sunday. `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly` are
+/// accepted as shorthands.
+///
+/// As in Vixie cron, when both the day of the month and the day of the week are
+/// restricted, a day matches if either of them does.
+///
+/// # Example
+///
+/// ```
+/// use fluxion_stream_time::Cron;
+///
+/// // Every weekday at 09:30 UTC
+/// let cron: Cron = "30 9 * * MON-FRI".parse().unwrap();
+///
+/// // Friday 2025-01-03 09:30:00 UTC is followed by Monday 2025-01-06 09:30:00 UTC
+/// assert_eq!(cron.next_after(1_735_896_600), Some(1_736_155_800));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cron {
+    seconds: u64,
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    // Day fields given as `*`, which leave the other day field alone in charge
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const SECOND: Field = Field {
+    name: "second",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const MINUTE: Field = Field {
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const HOUR: Field = Field {
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+};
+const DAY_OF_MONTH: Field = Field {
+    name: "day-of-month",
+    min: 1,
+    max: 31,
+    names: &[],
+};
+const MONTH: Field = Field {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &[
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ],
+};
+// 7 is accepted as Sunday and folded onto 0 after parsing
+const DAY_OF_WEEK: Field = Field {
+    name: "day-of-week",
+    min: 0,
+    max: 7,
+    names: &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+};
+
+impl Field {
+    /// Parses the field into a bitset with bit `n` set for every matching value `n`.
+    fn parse(&self, text: &str) -> Result<u64, CronError> {
+        let mut bits = 0u64;
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, Some(self.number(step)?)),
+                None => (part, None),
+            };
+            let (first, last) = if range == "*" {
+                (self.min, self.max)
+            } else if let Some((first, last)) = range.split_once('-') {
+                (self.value(first)?, self.value(last)?)
+            } else {
+                let first = self.value(range)?;
+                // `a/n` runs from `a` to the end of the range
+                (first, if step.is_some() { self.max } else { first })
+            };
+            let step = step.unwrap_or(1);
+            if first > last || step == 0 {
+                return Err(CronError::InvalidField(self.name));
+            }
+            for value in (first..=last).step_by(step as usize) {
+                bits |= 1 << value;
+            }
+        }
+        Ok(bits)
+    }
+
+    fn value(&self, text: &str) -> Result<u32, CronError> {
+        let value = match self
+            .names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(text))
+        {
+            Some(index) => self.min + index as u32,
+            None => self.number(text)?,
+        };
+        if (self.min..=self.max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(CronError::InvalidField(self.name))
+        }
+    }
+
+    fn number(&self, text: &str) -> Result<u32, CronError> {
+        text.parse().map_err(|_| CronError::InvalidField(self.name))
+    }
+}
+
+impl Cron {
+    /// Parses a cron expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression does not have 5 or 6 fields, or if a field
+    /// cannot be parsed or holds a value out of range.
+    pub fn parse(expr: &str) -> Result<Self, CronError> {
+        let expr = match expr.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expr => expr,
+        };
+
+        let mut fields = [""; 6];
+        let mut count = 0;
+        for field in expr.split_whitespace() {
+            if count < fields.len() {
+                fields[count] = field;
+            }
+            count += 1;
+        }
+        let [second, minute, hour, day_of_month, month, day_of_week] = match count {
+            5 => ["0", fields[0], fields[1], fields[2], fields[3], fields[4]],
+            6 => fields,
+            _ => return Err(CronError::FieldCount(count)),
+        };
+
+        let days_of_week = DAY_OF_WEEK.parse(day_of_week)?;
+        Ok(Self {
+            seconds: SECOND.parse(second)?,
+            minutes: MINUTE.parse(minute)?,
+            hours: HOUR.parse(hour)? as u32,
+            days_of_month: DAY_OF_MONTH.parse(day_of_month)? as u32,
+            months: MONTH.parse(month)? as u16,
+            days_of_week: ((days_of_week | days_of_week >> 7) & 0x7f) as u8,
+            any_day_of_month: day_of_month.starts_with('*'),
+            any_day_of_week: day_of_week.starts_with('*'),
+        })
+    }
+
+    /// Returns the first matching time strictly after `unix_seconds`, in seconds since
+    /// the Unix epoch, or `None` if the expression never matches, like `0 0 30 2 *`.
+    pub fn next_after(&self, unix_seconds: u64) -> Option<u64> {
+        let mut time = unix_seconds.checked_add(1)?;
+        let (start_year, _, _) = civil_from_days(time / SECONDS_PER_DAY);
+
+        loop {
+            let days = time / SECONDS_PER_DAY;
+            let (year, month, day) = civil_from_days(days);
+            if year > start_year + SEARCH_YEARS {
+                return None;
+            }
+            if self.months & (1 << month) == 0 {
+                let (year, month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                time = days_from_civil(year, month, 1) * SECONDS_PER_DAY;
+                continue;
+            }
+            if !self.matches_day(day, (days + 4) % 7) {
+                time = (days + 1) * SECONDS_PER_DAY;
+                continue;
+            }
+
+            let second_of_day = time % SECONDS_PER_DAY;
+            let (hour, minute, second) = (
+                second_of_day / 3600,
+                second_of_day / 60 % 60,
+                second_of_day % 60,
+            );
+            let start_of_hour = days * SECONDS_PER_DAY + hour * 3600;
+            if self.hours & (1 << hour) == 0 {
+                time = start_of_hour + 3600;
+            } else if self.minutes & (1 << minute) == 0 {
+                time = start_of_hour + (minute + 1) * 60;
+            } else if self.seconds & (1 << second) == 0 {
+                time += 1;
+            } else {
+                return Some(time);
+            }
+        }
+    }
+
+    fn matches_day(&self, day_of_month: u64, day_of_week: u64) -> bool {
+        let by_month = self.days_of_month & (1 << day_of_month) != 0;
+        let by_week = self.days_of_week & (1 << day_of_week) != 0;
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => by_month || by_week,
+            _ => by_month && by_week,
+        }
+    }
+}
+
+impl FromStr for Cron {
+    type Err = CronError;
+
+    fn from_str(expr: &str) -> Result<Self, Self::Err> {
+        Self::parse(expr)
+    }
+}
+
+/// Converts days since 1970-01-01 to a `(year, month, day)` date in the proleptic
+/// Gregorian calendar.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // Shifted so that eras start on 0000-03-01, putting leap days at the end of a year
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a date in the proleptic Gregorian calendar to days since 1970-01-01.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = year - u64::from(month <= 2);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+macro_rules! define_schedule_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::Cron;
+        use crate::{DefaultRuntime, InstantTimestamped};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        use fluxion_core::StreamItem;
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::{stream, Stream};
+
+        /// Emits a tick at every time matching the cron expression `expr`, reading the
+        /// time of day from the system clock.
+        ///
+        /// See [`schedule_with_clock`] for the behavior of the ticks.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if `expr` is not a valid [`Cron`] expression.
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        pub fn schedule(
+            expr: &str,
+        ) -> Result<
+            impl Stream<Item = StreamItem<InstantTimestamped<u64, DefaultRuntime>>> + $($bounds)*,
+            super::implementation::CronError,
+        > {
+            let cron = Cron::parse(expr)?;
+            Ok(schedule_with_clock(cron, || {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+            }))
+        }
+
+        /// Emits a tick at every time matching `cron`, reading the time since the Unix
+        /// epoch from `clock`, for targets without a system clock.
+        ///
+        /// Each tick holds the scheduled time in seconds since the Unix epoch and is
+        /// stamped with the runtime's [`Instant`](Runtime::Instant) when it fires. The
+        /// wait for the next tick is measured with the runtime's timer and checked
+        /// against `clock` on wake-up, so adjustments of the clock are followed. Ticks
+        /// missed while the consumer was busy are skipped, as in cron. The stream ends
+        /// if the expression never matches again.
+        pub fn schedule_with_clock<C>(
+            cron: Cron,
+            clock: C,
+        ) -> impl Stream<Item = StreamItem<InstantTimestamped<u64, DefaultRuntime>>> + $($bounds)*
+        where
+            C: FnMut() -> Duration + $($bounds)* 'static,
+        {
+            let timer = <DefaultRuntime as Runtime>::Timer::default();
+            let state = (clock, timer, None::<u64>);
+
+            Box::pin(stream::unfold(state, move |(mut clock, timer, last)| async move {
+                let now = clock();
+                let next = cron.next_after(last.map_or(now.as_secs(), |last| last.max(now.as_secs())))?;
+                let due = Duration::from_secs(next);
+
+                let mut now = now;
+                while now < due {
+                    timer
+                        .sleep_future(due - now)
+                        .await;
+                    now = clock();
+                }
+
+                let tick = InstantTimestamped::new(next, timer.now());
+                Some((StreamItem::Value(tick), (clock, timer, Some(next))))
+            }))
+        }
+    };
+}
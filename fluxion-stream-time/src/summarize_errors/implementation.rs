@@ -0,0 +1,355 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::DefaultRuntime;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    format,
+};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_runtime::mutex::MutexLike;
+use fluxion_runtime::runtime::Runtime;
+use futures::Stream;
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, VecDeque};
+
+/// Summary of the errors a stream emitted within one window of
+/// [`summarize_errors`](crate::SummarizeErrorsExt::summarize_errors).
+#[derive(Debug, Clone)]
+pub struct ErrorReport<I> {
+    /// Number of errors in the window.
+    pub total: usize,
+    /// Number of errors per [`FluxionError`] variant, by variant name.
+    pub counts: BTreeMap<&'static str, usize>,
+    /// When the first error of the window was seen, on the runtime's clock.
+    pub first_seen: I,
+    /// When the last error of the window was seen, on the runtime's clock.
+    pub last_seen: I,
+    /// The last error of the window.
+    pub last_error: FluxionError,
+}
+
+impl<I: Copy> ErrorReport<I> {
+    pub(crate) fn new(error: FluxionError, now: I) -> Self {
+        let mut counts = BTreeMap::new();
+        counts.insert(variant_name(&error), 1);
+        Self {
+            total: 1,
+            counts,
+            first_seen: now,
+            last_seen: now,
+            last_error: error,
+        }
+    }
+
+    pub(crate) fn record(&mut self, error: FluxionError, now: I) {
+        self.total += 1;
+        *self.counts.entry(variant_name(&error)).or_insert(0) += 1;
+        self.last_seen = now;
+        self.last_error = error;
+    }
+}
+
+impl<I> Display for ErrorReport<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} errors (", self.total)?;
+        for (index, (variant, count)) in self.counts.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{variant}: {count}")?;
+        }
+        write!(f, "), last: {}", self.last_error)
+    }
+}
+
+fn variant_name(error: &FluxionError) -> &'static str {
+    match error {
+        FluxionError::StreamProcessingError { .. } => "StreamProcessingError",
+        FluxionError::TimeoutError { .. } => "TimeoutError",
+        FluxionError::UserError { .. } => "UserError",
+        FluxionError::ResourceLimitExceeded { .. } => "ResourceLimitExceeded",
+        FluxionError::Unavailable { .. } => "Unavailable",
+        FluxionError::Wrapped { .. } => "Wrapped",
+        _ => "Other",
+    }
+}
+
+pub(crate) struct ReportState<I> {
+    reports: VecDeque<ErrorReport<I>>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// Stream of the [`ErrorReport`]s of
+/// [`summarize_errors_aside`](crate::SummarizeErrorsExt::summarize_errors_aside).
+///
+/// Reports are sent while the main stream is polled. The stream ends once the main
+/// stream has ended or was dropped and every report was taken.
+pub struct ErrorReports<I, R: Runtime = DefaultRuntime> {
+    state: R::Mutex<ReportState<I>>,
+}
+
+impl<I, R: Runtime> ErrorReports<I, R> {
+    pub(crate) fn new() -> (Self, ReportSender<I, R>) {
+        let state = R::Mutex::new(ReportState {
+            reports: VecDeque::new(),
+            waker: None,
+            closed: false,
+        });
+        let sender = ReportSender {
+            state: state.clone(),
+        };
+        (Self { state }, sender)
+    }
+}
+
+impl<I, R: Runtime> Stream for ErrorReports<I, R> {
+    type Item = StreamItem<ErrorReport<I>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock();
+        if let Some(report) = state.reports.pop_front() {
+            Poll::Ready(Some(StreamItem::Value(report)))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<I, R: Runtime> Unpin for ErrorReports<I, R> {}
+
+impl<I, R: Runtime> Debug for ErrorReports<I, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock();
+        f.debug_struct("ErrorReports")
+            .field("pending", &state.reports.len())
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+/// Feeds [`ErrorReports`], and ends it when dropped with the main stream.
+pub(crate) struct ReportSender<I, R: Runtime> {
+    state: R::Mutex<ReportState<I>>,
+}
+
+impl<I, R: Runtime> ReportSender<I, R> {
+    pub(crate) fn send(&self, report: ErrorReport<I>) {
+        let mut state = self.state.lock();
+        state.reports.push_back(report);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<I, R: Runtime> Drop for ReportSender<I, R> {
+    fn drop(&mut self) {
+        let mut state = self.state.lock();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Where the reports of a summarizing stream go.
+pub(crate) enum ReportSink<I, R: Runtime> {
+    /// Downstream, as a single error.
+    Downstream,
+    /// To an [`ErrorReports`] stream.
+    Aside(ReportSender<I, R>),
+}
+
+impl<I, R: Runtime> ReportSink<I, R> {
+    /// Sends `report` aside, or returns the error to emit downstream in its place.
+    pub(crate) fn deliver<T>(&self, report: ErrorReport<I>) -> Option<StreamItem<T>> {
+        match self {
+            Self::Downstream => Some(StreamItem::Error(FluxionError::stream_error(format!(
+                "{report}"
+            )))),
+            Self::Aside(sender) => {
+                sender.send(report);
+                None
+            }
+        }
+    }
+}
+
+macro_rules! define_summarize_errors_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{ErrorReport, ErrorReports, ReportSink};
+        use crate::DefaultRuntime;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        use fluxion_core::{Fluxion, StreamItem};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+
+        pub trait SummarizeErrorsExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Collects the errors of the stream over a window and emits a single error
+            /// summarizing them, so an error storm does not flood downstream alerting.
+            ///
+            /// The window opens with the first error and closes `window` later; the
+            /// summary is then emitted as a stream processing error whose message is
+            /// the [`ErrorReport`]: the number of errors, the count per variant and the
+            /// last error. The next error opens a new window.
+            ///
+            /// - Values pass through immediately
+            /// - A window still open when the stream ends is summarized right away
+            ///
+            /// # Arguments
+            ///
+            /// * `window` - How long errors are collected after the first one
+            fn summarize_errors(self, window: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Removes the errors from the stream and sends an [`ErrorReport`] per
+            /// window to the returned [`ErrorReports`] stream instead, with the count per
+            /// variant and when the first and last errors were seen.
+            ///
+            /// Windows work as in [`summarize_errors`](Self::summarize_errors).
+            ///
+            /// # Arguments
+            ///
+            /// * `window` - How long errors are collected after the first one
+            fn summarize_errors_aside(
+                self,
+                window: Duration,
+            ) -> (impl Stream<Item = StreamItem<T>> + $($bounds)*, ErrorReports<R::Instant, R>);
+        }
+
+        impl<S, T> SummarizeErrorsExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion + $($bounds)*,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn summarize_errors(self, window: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                Box::pin(SummarizeErrorsStream::<S, DefaultRuntime>::new(self, window, ReportSink::Downstream))
+            }
+
+            fn summarize_errors_aside(
+                self,
+                window: Duration,
+            ) -> (
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+                ErrorReports<<DefaultRuntime as Runtime>::Instant, DefaultRuntime>,
+            ) {
+                let (reports, sender) = ErrorReports::new();
+                let stream = Box::pin(SummarizeErrorsStream::<S, DefaultRuntime>::new(
+                    self,
+                    window,
+                    ReportSink::Aside(sender),
+                ));
+                (stream, reports)
+            }
+        }
+
+        #[pin_project]
+        struct SummarizeErrorsStream<S, R>
+        where
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            window: Duration,
+            sink: ReportSink<R::Instant, R>,
+            timer: R::Timer,
+            report: Option<ErrorReport<R::Instant>>,
+            /// Closes the open window.
+            #[pin]
+            sleep: Option<<R::Timer as Timer>::Sleep>,
+            done: bool,
+        }
+
+        impl<S, R: Runtime> SummarizeErrorsStream<S, R> {
+            fn new(stream: S, window: Duration, sink: ReportSink<R::Instant, R>) -> Self {
+                Self {
+                    stream,
+                    window,
+                    sink,
+                    timer: R::Timer::default(),
+                    report: None,
+                    sleep: None,
+                    done: false,
+                }
+            }
+        }
+
+        impl<S, T, R> Stream for SummarizeErrorsStream<S, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            R: Runtime,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                loop {
+                    if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                        if sleep.poll(cx).is_ready() {
+                            this.sleep.set(None);
+                            if let Some(item) = this.report.take().and_then(|report| this.sink.deliver(report)) {
+                                return Poll::Ready(Some(item));
+                            }
+                        }
+                    }
+
+                    if *this.done {
+                        this.sleep.set(None);
+                        if let Some(item) = this.report.take().and_then(|report| this.sink.deliver(report)) {
+                            return Poll::Ready(Some(item));
+                        }
+                        // Ends the report stream along with this one
+                        *this.sink = ReportSink::Downstream;
+                        return Poll::Ready(None);
+                    }
+
+                    match this.stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(StreamItem::Value(value))) => {
+                            return Poll::Ready(Some(StreamItem::Value(value)));
+                        }
+                        Poll::Ready(Some(StreamItem::Error(err))) => {
+                            let now = this.timer.now();
+                            match this.report.as_mut() {
+                                Some(report) => report.record(err, now),
+                                None => {
+                                    *this.report = Some(ErrorReport::new(err, now));
+                                    this.sleep.set(Some(this.timer.sleep_future(*this.window)));
+                                }
+                            }
+                        }
+                        Poll::Ready(None) => *this.done = true,
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,179 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::DefaultRuntime;
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, vec::Vec};
+use core::fmt::Debug;
+use fluxion_core::HasTimestamp;
+use fluxion_runtime::mutex::MutexLike;
+use fluxion_runtime::runtime::Runtime;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// An item captured by [`TapRing`], together with the instant it passed the tap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingEntry<T, I> {
+    pub item: T,
+    pub arrived_at: I,
+}
+
+impl<T: HasTimestamp, I> RingEntry<T, I> {
+    /// Returns the timestamp carried by the captured item.
+    pub fn timestamp(&self) -> T::Timestamp {
+        self.item.timestamp()
+    }
+}
+
+struct RingState<T, I> {
+    entries: VecDeque<RingEntry<T, I>>,
+    seen: u64,
+}
+
+/// A bounded, shared buffer holding the last items observed by
+/// [`tap_ring`](crate::TapRingExt::tap_ring).
+///
+/// Cloning a `TapRing` yields another handle to the same buffer, so one clone can be
+/// handed to the pipeline while another is queried from a diagnostics task. When the
+/// buffer is full, the oldest entry is evicted.
+pub struct TapRing<T, R: Runtime = DefaultRuntime> {
+    state: R::Mutex<RingState<T, R::Instant>>,
+    capacity: usize,
+}
+
+impl<T, R: Runtime> TapRing<T, R> {
+    /// Creates a ring buffer that keeps the last `capacity` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity >= 1, "tap_ring: capacity must be at least 1");
+        Self {
+            state: R::Mutex::new(RingState {
+                entries: VecDeque::with_capacity(capacity),
+                seen: 0,
+            }),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries currently held.
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    /// Returns `true` if no item has been captured since creation or the last [`clear`](Self::clear).
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().entries.is_empty()
+    }
+
+    /// Returns the total number of items observed, including evicted ones.
+    pub fn total_seen(&self) -> u64 {
+        self.state.lock().seen
+    }
+
+    /// Removes all entries. The total count is preserved.
+    pub fn clear(&self) {
+        self.state.lock().entries.clear();
+    }
+
+    pub(crate) fn push(&self, item: T, arrived_at: R::Instant) {
+        let mut state = self.state.lock();
+        if state.entries.len() == self.capacity {
+            state.entries.pop_front();
+        }
+        state.entries.push_back(RingEntry { item, arrived_at });
+        state.seen += 1;
+    }
+}
+
+impl<T: Clone, R: Runtime> TapRing<T, R> {
+    /// Returns a copy of the held entries, oldest first.
+    pub fn snapshot(&self) -> Vec<RingEntry<T, R::Instant>> {
+        self.state.lock().entries.iter().cloned().collect()
+    }
+
+    /// Returns the most recently captured entry.
+    pub fn latest(&self) -> Option<RingEntry<T, R::Instant>> {
+        self.state.lock().entries.back().cloned()
+    }
+}
+
+impl<T, R: Runtime> Clone for TapRing<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<T, R: Runtime> Debug for TapRing<T, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TapRing")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+macro_rules! define_tap_ring_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::TapRing;
+        use crate::DefaultRuntime;
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::{Stream, StreamExt};
+
+        pub trait TapRingExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Records the last items of the stream in a shared ring buffer.
+            ///
+            /// Every value is cloned into `ring` together with the instant it passed the
+            /// tap, measured by the runtime clock. The buffer keeps only the last
+            /// [`capacity`](TapRing::capacity) entries. Pass a clone of the ring and keep
+            /// the original to query it from any other task, which makes it possible to inspect
+            /// recent traffic of a production pipeline without logging every item.
+            ///
+            /// Values and errors pass through unchanged. Errors are not recorded.
+            ///
+            /// # Arguments
+            ///
+            /// * `ring` - The buffer receiving the captured items
+            fn tap_ring(self, ring: TapRing<T, R>) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+        }
+
+        impl<S, T> TapRingExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn tap_ring(self, ring: TapRing<T, DefaultRuntime>) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                let timer = <DefaultRuntime as Runtime>::Timer::default();
+
+                self.map(move |item| {
+                    if let StreamItem::Value(value) = &item {
+                        ring.push(value.clone(), timer.now());
+                    }
+                    item
+                })
+            }
+        }
+    };
+}
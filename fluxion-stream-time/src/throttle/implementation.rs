@@ -6,7 +6,6 @@ macro_rules! define_throttle_impl {
     ($($bounds:tt)*) => {
         use crate::DefaultRuntime;
         use core::fmt::Debug;
-        use core::future::Future;
         use core::pin::Pin;
         use core::task::{Context, Poll};
         use core::time::Duration;
@@ -17,7 +16,7 @@ macro_rules! define_throttle_impl {
         use fluxion_core::{Fluxion, HasTimestamp, StreamItem};
         use fluxion_runtime::runtime::Runtime;
         use fluxion_runtime::timer::Timer;
-        use futures::Stream;
+        use futures::{Stream, StreamExt};
         use pin_project::pin_project;
 
         pub trait ThrottleExt<T, R>: Stream<Item = StreamItem<T>> + Sized
@@ -48,6 +47,12 @@ macro_rules! define_throttle_impl {
             ///
             /// * `duration` - The duration to ignore values after an emission
             fn throttle(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Throttles the stream like [`throttle`](Self::throttle), aligning each window
+            /// to a multiple of `slack` on the runtime's clock (see
+            /// [`Timer::interval_coalesced`]). Windows last up to `slack` longer, never
+            /// shorter.
+            fn throttle_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
         }
 
         impl<S, T> ThrottleExt<T, DefaultRuntime> for S
@@ -57,10 +62,15 @@ macro_rules! define_throttle_impl {
             T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
         {
             fn throttle(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                self.throttle_with_slack(duration, Duration::ZERO)
+            }
+
+            fn throttle_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
                 Box::pin(ThrottleStream::<S, T, DefaultRuntime> {
                     stream: self,
                     duration,
-                    sleep: Some(<DefaultRuntime as Runtime>::Timer::default().sleep_future(duration)),
+                    slack,
+                    interval: None,
                     throttling: false,
                 })
             }
@@ -76,8 +86,8 @@ macro_rules! define_throttle_impl {
             #[pin]
             stream: S,
             duration: Duration,
-            #[pin]
-            sleep: Option<<R::Timer as Timer>::Sleep>,
+            slack: Duration,
+            interval: Option<<R::Timer as Timer>::Interval>,
             throttling: bool,
         }
 
@@ -91,15 +101,14 @@ macro_rules! define_throttle_impl {
 
             fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
                 let mut this = self.project();
+                let mut window_ended = false;
 
                 loop {
                     if *this.throttling {
-                        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
-                            match sleep.poll(cx) {
-                                Poll::Ready(_) => {
-                                    *this.throttling = false;
-                                }
-                                Poll::Pending => {}
+                        if let Some(interval) = this.interval.as_mut() {
+                            if interval.poll_next_unpin(cx).is_ready() {
+                                *this.throttling = false;
+                                window_ended = true;
                             }
                         }
                     }
@@ -107,8 +116,14 @@ macro_rules! define_throttle_impl {
                     match this.stream.as_mut().poll_next(cx) {
                         Poll::Ready(Some(StreamItem::Value(value))) => {
                             if !*this.throttling {
-                                this.sleep
-                                    .set(Some(R::Timer::default().sleep_future(*this.duration)));
+                                // A value taken as its window ends starts the next window on
+                                // the same interval; otherwise the window starts now.
+                                if !window_ended || this.interval.is_none() {
+                                    *this.interval = Some(
+                                        R::Timer::default()
+                                            .interval_coalesced(*this.duration, *this.slack),
+                                    );
+                                }
                                 *this.throttling = true;
                                 return Poll::Ready(Some(StreamItem::Value(value)));
                             } else {
@@ -122,6 +137,9 @@ macro_rules! define_throttle_impl {
                             return Poll::Ready(None);
                         }
                         Poll::Pending => {
+                            if !*this.throttling {
+                                *this.interval = None;
+                            }
                             return Poll::Pending;
                         }
                     }
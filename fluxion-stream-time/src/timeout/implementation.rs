@@ -41,6 +41,12 @@ macro_rules! define_timeout_impl {
             ///
             /// * `duration` - The timeout duration
             fn timeout(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Errors like [`timeout`](Self::timeout), rounding every deadline up to the
+            /// next multiple of `slack` on the runtime's clock (see
+            /// [`Timer::sleep_coalesced`]). The timeout fires up to `slack` late, never
+            /// early.
+            fn timeout_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
         }
 
         impl<S, T> TimeoutExt<T, DefaultRuntime> for S
@@ -50,10 +56,15 @@ macro_rules! define_timeout_impl {
             T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
         {
             fn timeout(self, duration: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                self.timeout_with_slack(duration, Duration::ZERO)
+            }
+
+            fn timeout_with_slack(self, duration: Duration, slack: Duration) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
                 Box::pin(TimeoutStream::<S, T, DefaultRuntime> {
                     stream: self,
                     duration,
-                    sleep: Some(<DefaultRuntime as Runtime>::Timer::default().sleep_future(duration)),
+                    slack,
+                    sleep: Some(<DefaultRuntime as Runtime>::Timer::default().sleep_coalesced(duration, slack)),
                     is_done: false,
                 })
             }
@@ -69,6 +80,7 @@ macro_rules! define_timeout_impl {
             #[pin]
             stream: S,
             duration: Duration,
+            slack: Duration,
             #[pin]
             sleep: Option<<R::Timer as Timer>::Sleep>,
             is_done: bool,
@@ -92,7 +104,7 @@ macro_rules! define_timeout_impl {
                 match this.stream.poll_next(cx) {
                     Poll::Ready(Some(item)) => {
                         this.sleep
-                            .set(Some(R::Timer::default().sleep_future(*this.duration)));
+                            .set(Some(R::Timer::default().sleep_coalesced(*this.duration, *this.slack)));
                         return Poll::Ready(Some(item));
                     }
                     Poll::Ready(None) => {
@@ -0,0 +1,366 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Hierarchical timer wheel holding one deadline per key.
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+const SLOT_BITS: u32 = 6;
+const SLOTS: usize = 1 << SLOT_BITS;
+const LEVELS: usize = 6;
+
+/// Ticks covered by all levels together; later deadlines are parked in the top level
+/// and moved down as the wheel gets closer to them.
+const RANGE: u64 = 1 << (SLOT_BITS * LEVELS as u32);
+
+/// List of the entries that expired and were not taken yet, after the slot lists.
+const EXPIRED: usize = LEVELS * SLOTS;
+const NIL: usize = usize::MAX;
+
+struct Entry<K> {
+    key: K,
+    deadline: Duration,
+    tick: u64,
+    list: usize,
+    prev: usize,
+    next: usize,
+}
+
+#[derive(Clone, Copy)]
+struct List {
+    head: usize,
+    tail: usize,
+    /// Smallest tick in the list, `None` once the entry holding it was removed.
+    min_tick: Option<u64>,
+}
+
+const EMPTY: List = List {
+    head: NIL,
+    tail: NIL,
+    min_tick: Some(u64::MAX),
+};
+
+/// A hierarchical timer wheel holding one deadline per key, for operators that track
+/// a deadline for each of many keys with a single runtime timer.
+///
+/// Deadlines are [`Duration`]s on the caller's clock, typically the time passed since
+/// the operator was created, and are rounded up to the wheel's resolution. Inserting,
+/// rescheduling and removing a key take `O(log n)` time for the key lookup and `O(1)`
+/// for the wheel itself, and every entry moves down at most five times between the
+/// six levels of 64 slots before it expires. Deadlines beyond the range of the levels,
+/// about 19 hours at the default resolution of 1 µs, are parked in the top level until
+/// they come within range.
+///
+/// The owner arms one runtime timer for [`next_deadline`](Self::next_deadline) and
+/// takes the expired keys with [`poll_expired`](Self::poll_expired) when it fires.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use fluxion_stream_time::TimerWheel;
+///
+/// let mut wheel = TimerWheel::new();
+/// wheel.insert("session-a", Duration::from_secs(30));
+/// wheel.insert("session-b", Duration::from_secs(10));
+///
+/// // Activity on session-b pushes its deadline back
+/// wheel.insert("session-b", Duration::from_secs(40));
+/// assert_eq!(wheel.next_deadline(), Some(Duration::from_secs(30)));
+///
+/// assert_eq!(wheel.poll_expired(Duration::from_secs(35)), Some(("session-a", Duration::from_secs(30))));
+/// assert_eq!(wheel.poll_expired(Duration::from_secs(35)), None);
+/// ```
+pub struct TimerWheel<K> {
+    resolution: u64,
+    /// Tick up to which the wheel has been advanced.
+    elapsed: u64,
+    entries: Vec<Option<Entry<K>>>,
+    vacant: Vec<usize>,
+    keys: BTreeMap<K, usize>,
+    lists: Vec<List>,
+    occupied: [u64; LEVELS],
+}
+
+impl<K: Ord + Clone> TimerWheel<K> {
+    /// Creates an empty wheel with a resolution of 1 µs.
+    pub fn new() -> Self {
+        Self::with_resolution(Duration::from_micros(1))
+    }
+
+    /// Creates an empty wheel rounding deadlines up to multiples of `resolution`.
+    ///
+    /// A coarser resolution extends the range of the levels, so that long deadlines
+    /// move down fewer times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is zero.
+    pub fn with_resolution(resolution: Duration) -> Self {
+        assert!(!resolution.is_zero(), "TimerWheel requires resolution > 0");
+        Self {
+            resolution: u64::try_from(resolution.as_nanos()).unwrap_or(u64::MAX),
+            elapsed: 0,
+            entries: Vec::new(),
+            vacant: Vec::new(),
+            keys: BTreeMap::new(),
+            lists: vec![EMPTY; EXPIRED + 1],
+            occupied: [0; LEVELS],
+        }
+    }
+
+    /// Sets the deadline of `key`, returning its previous deadline if it had one.
+    ///
+    /// A deadline that already passed expires on the next
+    /// [`poll_expired`](Self::poll_expired).
+    pub fn insert(&mut self, key: K, deadline: Duration) -> Option<Duration> {
+        let previous = self.remove(&key);
+        let tick = self.ceil_tick(deadline);
+        let index = match self.vacant.pop() {
+            Some(index) => index,
+            None => {
+                self.entries.push(None);
+                self.entries.len() - 1
+            }
+        };
+        self.entries[index] = Some(Entry {
+            key: key.clone(),
+            deadline,
+            tick,
+            list: NIL,
+            prev: NIL,
+            next: NIL,
+        });
+        self.keys.insert(key, index);
+        self.place(index);
+        previous
+    }
+
+    /// Removes the deadline of `key`, returning it if it had one.
+    pub fn remove(&mut self, key: &K) -> Option<Duration> {
+        let index = self.keys.remove(key)?;
+        self.unlink(index);
+        self.vacant.push(index);
+        self.entries[index].take().map(|entry| entry.deadline)
+    }
+
+    /// Returns the deadline of `key`, if it has one.
+    pub fn deadline(&self, key: &K) -> Option<Duration> {
+        let index = *self.keys.get(key)?;
+        self.entries[index].as_ref().map(|entry| entry.deadline)
+    }
+
+    /// Returns the number of keys with a deadline.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if no key has a deadline.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the earliest deadline, rounded up to the resolution, or `None` if the
+    /// wheel is empty.
+    ///
+    /// Takes `&mut self` to cache the earliest deadline of a slot.
+    pub fn next_deadline(&mut self) -> Option<Duration> {
+        if let Some(index) = self.first(EXPIRED) {
+            return Some(self.time(self.entry(index).tick));
+        }
+        let (level, slot, _) = self.next_slot()?;
+        let list = level * SLOTS + slot;
+        let min_tick = match self.lists[list].min_tick {
+            Some(tick) => tick,
+            None => {
+                let mut tick = u64::MAX;
+                let mut index = self.lists[list].head;
+                while index != NIL {
+                    let entry = self.entry(index);
+                    tick = tick.min(entry.tick);
+                    index = entry.next;
+                }
+                self.lists[list].min_tick = Some(tick);
+                tick
+            }
+        };
+        Some(self.time(min_tick))
+    }
+
+    /// Advances the wheel to `now` and takes one key whose deadline is at or before
+    /// `now`, with its deadline, or returns `None` if there is none.
+    ///
+    /// Keys are returned in the order of their deadlines, rounded to the resolution.
+    pub fn poll_expired(&mut self, now: Duration) -> Option<(K, Duration)> {
+        self.advance(self.floor_tick(now));
+        let index = self.first(EXPIRED)?;
+        self.unlink(index);
+        self.vacant.push(index);
+        let entry = self.entries[index].take()?;
+        self.keys.remove(&entry.key);
+        Some((entry.key, entry.deadline))
+    }
+
+    fn advance(&mut self, target: u64) {
+        while let Some((level, slot, start)) = self.next_slot() {
+            if start > target {
+                break;
+            }
+            self.elapsed = start;
+
+            let list = level * SLOTS + slot;
+            let mut index = self.lists[list].head;
+            self.lists[list] = EMPTY;
+            self.occupied[level] &= !(1 << slot);
+            // A slot of the lowest level holds a single tick, which is due; the entries
+            // of a higher slot move down so that they expire in order
+            while index != NIL {
+                let entry = self.entry_mut(index);
+                let next = entry.next;
+                entry.list = NIL;
+                if level == 0 {
+                    self.push(EXPIRED, index);
+                } else {
+                    self.place(index);
+                }
+                index = next;
+            }
+        }
+        self.elapsed = self.elapsed.max(target);
+    }
+
+    /// Finds the occupied slot the wheel reaches first, with the tick it starts at.
+    ///
+    /// The entries of a level are all later than those of the levels below it, so the
+    /// first slot of the lowest occupied level holds the earliest deadlines.
+    fn next_slot(&self) -> Option<(usize, usize, u64)> {
+        let level = self.occupied.iter().position(|&occupied| occupied != 0)?;
+        let shift = SLOT_BITS * level as u32;
+        let slot_range = 1u64 << shift;
+        let level_range = slot_range << SLOT_BITS;
+
+        let now_slot = (self.elapsed >> shift) as u32 % SLOTS as u32;
+        let distance = self.occupied[level].rotate_right(now_slot).trailing_zeros();
+        let slot = (now_slot + distance) as usize % SLOTS;
+
+        let mut start = (self.elapsed & !(level_range - 1)) + slot as u64 * slot_range;
+        // Only entries parked beyond the range share the current slot of a higher level,
+        // and they belong to its next turn
+        if start < self.elapsed || (level > 0 && start == self.elapsed) {
+            start += level_range;
+        }
+        Some((level, slot, start))
+    }
+
+    /// Puts an unlinked entry into the slot matching its tick relative to `elapsed`.
+    fn place(&mut self, index: usize) {
+        let tick = self.entry(index).tick;
+        let when = tick.clamp(self.elapsed, self.elapsed.saturating_add(RANGE - 1));
+        let differing = ((self.elapsed ^ when) | (SLOTS as u64 - 1)).min(RANGE - 1);
+        let level = ((63 - differing.leading_zeros()) / SLOT_BITS) as usize;
+        let slot = (when >> (SLOT_BITS * level as u32)) as usize % SLOTS;
+        self.occupied[level] |= 1 << slot;
+        self.push(level * SLOTS + slot, index);
+    }
+
+    fn push(&mut self, list: usize, index: usize) {
+        let tail = self.lists[list].tail;
+        let entry = self.entry_mut(index);
+        let tick = entry.tick;
+        entry.list = list;
+        entry.prev = tail;
+        entry.next = NIL;
+        match tail {
+            NIL => self.lists[list].head = index,
+            tail => self.entry_mut(tail).next = index,
+        }
+        let list = &mut self.lists[list];
+        list.tail = index;
+        list.min_tick = list.min_tick.map(|min| min.min(tick));
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let entry = self.entry(index);
+        let (list, prev, next, tick) = (entry.list, entry.prev, entry.next, entry.tick);
+        if list == NIL {
+            return;
+        }
+        match prev {
+            NIL => self.lists[list].head = next,
+            prev => self.entry_mut(prev).next = next,
+        }
+        match next {
+            NIL => self.lists[list].tail = prev,
+            next => self.entry_mut(next).prev = prev,
+        }
+        if self.lists[list].head == NIL {
+            self.lists[list] = EMPTY;
+            if list != EXPIRED {
+                self.occupied[list / SLOTS] &= !(1 << (list % SLOTS));
+            }
+        } else if self.lists[list].min_tick == Some(tick) {
+            self.lists[list].min_tick = None;
+        }
+        let entry = self.entry_mut(index);
+        entry.list = NIL;
+        entry.prev = NIL;
+        entry.next = NIL;
+    }
+
+    fn first(&self, list: usize) -> Option<usize> {
+        match self.lists[list].head {
+            NIL => None,
+            index => Some(index),
+        }
+    }
+
+    fn entry(&self, index: usize) -> &Entry<K> {
+        self.entries[index]
+            .as_ref()
+            .expect("timer wheel lists only link occupied entries")
+    }
+
+    fn entry_mut(&mut self, index: usize) -> &mut Entry<K> {
+        self.entries[index]
+            .as_mut()
+            .expect("timer wheel lists only link occupied entries")
+    }
+
+    /// Converts a deadline to the first tick at or after it.
+    fn ceil_tick(&self, deadline: Duration) -> u64 {
+        let tick = deadline.as_nanos().div_ceil(u128::from(self.resolution));
+        u64::try_from(tick).unwrap_or(u64::MAX)
+    }
+
+    /// Converts a time to the last tick at or before it.
+    fn floor_tick(&self, now: Duration) -> u64 {
+        let tick = now.as_nanos() / u128::from(self.resolution);
+        u64::try_from(tick).unwrap_or(u64::MAX)
+    }
+
+    fn time(&self, tick: u64) -> Duration {
+        let nanos = u128::from(tick) * u128::from(self.resolution);
+        let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        Duration::new(secs, (nanos % 1_000_000_000) as u32)
+    }
+}
+
+impl<K: Ord + Clone> Default for TimerWheel<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: core::fmt::Debug> core::fmt::Debug for TimerWheel<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TimerWheel")
+            .field("len", &self.keys.len())
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
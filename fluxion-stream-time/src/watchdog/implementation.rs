@@ -0,0 +1,213 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+
+/// Where a stall detected by [`watchdog`](crate::WatchdogExt::watchdog) originates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallKind {
+    /// The source has not produced an item while being polled.
+    Source,
+    /// The consumer did not poll for the next item after receiving the previous one,
+    /// e.g. because a subscribe handler is stuck.
+    Consumer,
+}
+
+/// Diagnostic event passed to the stall callback of
+/// [`watchdog`](crate::WatchdogExt::watchdog).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StallEvent {
+    pub kind: StallKind,
+    /// How long no progress was made.
+    pub idle: Duration,
+    /// Number of items (values and errors) that passed the watchdog so far.
+    pub items: u64,
+}
+
+macro_rules! define_watchdog_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{StallEvent, StallKind};
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use core::time::Duration;
+
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use alloc::boxed::Box;
+        use fluxion_core::{Fluxion, StreamItem};
+        use fluxion_runtime::runtime::Runtime;
+        use fluxion_runtime::timer::Timer;
+        use futures::Stream;
+        use pin_project::pin_project;
+        use crate::DefaultRuntime;
+
+        pub trait WatchdogExt<T, R>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+            R: Runtime,
+        {
+            /// Reports stalls of the pipeline to `on_stall`.
+            ///
+            /// The watchdog detects two kinds of stalls:
+            /// - [`StallKind::Source`]: the stream is being polled but the source has not
+            ///   produced an item for `timeout`. The callback is invoked as soon as the
+            ///   timer expires.
+            /// - [`StallKind::Consumer`]: after an item was handed out, the consumer did not
+            ///   ask for the next one within `timeout` (for example a stuck subscribe
+            ///   handler). Since a consumer that does not poll cannot be observed from
+            ///   inside the stream, this stall is reported when the consumer resumes.
+            ///
+            /// Each stall is reported once; the watchdog re-arms when the next item
+            /// arrives. Items and errors pass through unchanged and both count as progress.
+            ///
+            /// # Arguments
+            ///
+            /// * `timeout` - The maximum time without progress before a stall is reported
+            /// * `on_stall` - Callback receiving a [`StallEvent`] for every detected stall
+            fn watchdog<F>(self, timeout: Duration, on_stall: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut(StallEvent) + $($bounds)* 'static;
+
+            /// Reports stalls like [`watchdog`](Self::watchdog), rounding the source stall
+            /// deadline up to the next multiple of `slack` on the runtime's clock (see
+            /// [`Timer::sleep_coalesced`]). Source stalls are reported up to `slack` late.
+            fn watchdog_with_slack<F>(
+                self,
+                timeout: Duration,
+                slack: Duration,
+                on_stall: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut(StallEvent) + $($bounds)* 'static;
+        }
+
+        impl<S, T> WatchdogExt<T, DefaultRuntime> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)*,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + 'static,
+        {
+            fn watchdog<F>(self, timeout: Duration, on_stall: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut(StallEvent) + $($bounds)* 'static,
+            {
+                self.watchdog_with_slack(timeout, Duration::ZERO, on_stall)
+            }
+
+            fn watchdog_with_slack<F>(
+                self,
+                timeout: Duration,
+                slack: Duration,
+                on_stall: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut(StallEvent) + $($bounds)* 'static,
+            {
+                Box::pin(WatchdogStream::<S, F, DefaultRuntime> {
+                    stream: self,
+                    timeout,
+                    slack,
+                    on_stall,
+                    timer: <DefaultRuntime as Runtime>::Timer::default(),
+                    sleep: None,
+                    waiting_since: None,
+                    emitted_at: None,
+                    source_reported: false,
+                    items: 0,
+                })
+            }
+        }
+
+        #[pin_project]
+        struct WatchdogStream<S, F, R>
+        where
+            R: Runtime,
+        {
+            #[pin]
+            stream: S,
+            timeout: Duration,
+            slack: Duration,
+            on_stall: F,
+            timer: R::Timer,
+            #[pin]
+            sleep: Option<<R::Timer as Timer>::Sleep>,
+            waiting_since: Option<R::Instant>,
+            emitted_at: Option<R::Instant>,
+            source_reported: bool,
+            items: u64,
+        }
+
+        impl<S, T, F, R> Stream for WatchdogStream<S, F, R>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            F: FnMut(StallEvent),
+            R: Runtime,
+            R::Instant: Sub<Output = Duration>,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+                let now = this.timer.now();
+
+                if let Some(emitted_at) = this.emitted_at.take() {
+                    let idle = now - emitted_at;
+                    if idle >= *this.timeout {
+                        (this.on_stall)(StallEvent {
+                            kind: StallKind::Consumer,
+                            idle,
+                            items: *this.items,
+                        });
+                    }
+                }
+
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        *this.items += 1;
+                        *this.source_reported = false;
+                        *this.waiting_since = None;
+                        *this.emitted_at = Some(this.timer.now());
+                        this.sleep.set(None);
+                        Poll::Ready(Some(item))
+                    }
+                    Poll::Ready(None) => {
+                        this.sleep.set(None);
+                        Poll::Ready(None)
+                    }
+                    Poll::Pending => {
+                        if *this.source_reported {
+                            return Poll::Pending;
+                        }
+
+                        if this.sleep.is_none() {
+                            *this.waiting_since = Some(now);
+                            this.sleep.set(Some(this.timer.sleep_coalesced(*this.timeout, *this.slack)));
+                        }
+
+                        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                            if sleep.poll(cx).is_ready() {
+                                let waited = this.waiting_since.map_or(*this.timeout, |since| now - since);
+                                (this.on_stall)(StallEvent {
+                                    kind: StallKind::Source,
+                                    idle: core::cmp::max(waited, *this.timeout),
+                                    items: *this.items,
+                                });
+                                *this.source_reported = true;
+                                this.sleep.set(None);
+                            }
+                        }
+
+                        Poll::Pending
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,72 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_stream_time::{EmbassyTickTimestamped, EmbassyTicks};
+use std::cmp::Ordering;
+use std::time::Duration;
+
+#[test]
+fn test_ticks_order_without_wrap() {
+    let earlier = EmbassyTicks::from_ticks(100);
+    let later = EmbassyTicks::from_ticks(200);
+
+    assert_eq!(earlier.cmp(&later), Ordering::Less);
+    assert_eq!(later.cmp(&earlier), Ordering::Greater);
+    assert_eq!(earlier.cmp(&earlier), Ordering::Equal);
+    assert!(earlier.is_before(later));
+    assert!(later.is_after(earlier));
+}
+
+#[test]
+fn test_ticks_order_across_wrap() {
+    let before_wrap = EmbassyTicks::from_ticks(u64::MAX - 5);
+    let after_wrap = EmbassyTicks::from_ticks(10);
+
+    assert!(before_wrap < after_wrap);
+    assert!(before_wrap.is_before(after_wrap));
+    assert!(after_wrap.is_after(before_wrap));
+    assert_eq!(after_wrap.ticks_since(before_wrap), 16);
+}
+
+#[test]
+fn test_ticks_arithmetic_wraps() {
+    let before_wrap = EmbassyTicks::from_ticks(u64::MAX);
+    let tick = EmbassyTicks::from_ticks(1).duration_since(EmbassyTicks::from_ticks(0));
+
+    let after_wrap = before_wrap + tick;
+
+    assert_eq!(after_wrap.as_ticks(), 0);
+    assert_eq!(after_wrap - tick, before_wrap);
+    assert_eq!(after_wrap - before_wrap, tick);
+    assert!(after_wrap - Duration::ZERO > before_wrap);
+}
+
+#[test]
+fn test_tick_timestamped_accessors() {
+    let timestamp = EmbassyTicks::from_ticks(42);
+    let item = EmbassyTickTimestamped::new("reading".to_string(), timestamp);
+
+    assert_eq!(item.timestamp(), timestamp);
+    assert_eq!(item.len(), 7);
+
+    let restamped =
+        EmbassyTickTimestamped::with_timestamp(item.into_inner(), timestamp + Duration::ZERO);
+    assert_eq!(restamped.value, "reading");
+    assert_eq!(restamped.timestamp, timestamp);
+}
+
+#[test]
+fn test_tick_timestamped_sorts_across_wrap() {
+    let mut items = vec![
+        EmbassyTickTimestamped::new(3, EmbassyTicks::from_ticks(2)),
+        EmbassyTickTimestamped::new(1, EmbassyTicks::from_ticks(u64::MAX - 1)),
+        EmbassyTickTimestamped::new(2, EmbassyTicks::from_ticks(u64::MAX)),
+    ];
+
+    items.sort();
+
+    let values: Vec<_> = items.into_iter().map(Timestamped::into_inner).collect();
+    assert_eq!(values, vec![1, 2, 3]);
+}
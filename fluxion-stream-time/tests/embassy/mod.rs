@@ -2,5 +2,6 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+pub mod embassy_tick_timestamped_tests;
 pub mod helpers;
 pub mod single_threaded;
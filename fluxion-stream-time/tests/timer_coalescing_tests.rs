@@ -0,0 +1,90 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{DebounceExt, ThrottleExt, TokioTimestamped};
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, test_channel, unwrap_stream},
+    test_data::{person_alice, person_bob, person_charlie, TestData},
+};
+use futures::{FutureExt, StreamExt};
+use std::time::Duration;
+use tokio::time::{advance, pause};
+
+#[tokio::test]
+async fn test_operators_share_coalesced_deadline() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let slack = Duration::from_millis(100);
+
+    let (tx_fast, fast) = test_channel::<TokioTimestamped<TestData>>();
+    let (tx_slow, slow) = test_channel::<TokioTimestamped<TestData>>();
+    let mut fast = fast.debounce_with_slack(Duration::from_millis(10), slack);
+    let mut slow = slow.debounce_with_slack(Duration::from_millis(20), slack);
+
+    // Act
+    tx_fast.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    assert_no_element_emitted(&mut fast, 0).await;
+    advance(Duration::from_millis(30)).await;
+    tx_slow.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    assert_no_element_emitted(&mut slow, 0).await;
+    advance(Duration::from_millis(60)).await;
+
+    // Assert
+    assert_no_element_emitted(&mut fast, 0).await;
+    assert_no_element_emitted(&mut slow, 0).await;
+
+    // Act
+    advance(Duration::from_millis(10)).await;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut fast, 100).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut slow, 100).await.unwrap().value,
+        person_bob()
+    );
+
+    // Arrange
+    let (tx, throttled) = test_channel::<TokioTimestamped<TestData>>();
+    let mut throttled = throttled.throttle(Duration::from_millis(10));
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    assert_eq!(
+        unwrap_stream(&mut throttled, 100).await.unwrap().value,
+        person_alice()
+    );
+    assert_no_element_emitted(&mut throttled, 0).await;
+    advance(Duration::from_millis(10)).await;
+    tx.unbounded_send(TokioTimestamped::new(person_charlie(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut throttled, 100).await.unwrap().value,
+        person_charlie()
+    );
+
+    // Arrange
+    let mut first = timer.interval_coalesced(Duration::from_millis(30), slack);
+    advance(Duration::from_millis(40)).await;
+    let mut second = timer.interval_coalesced(Duration::from_millis(50), slack);
+
+    // Act & Assert
+    for _ in 0..2 {
+        while first.next().now_or_never().is_none() {
+            assert!(second.next().now_or_never().is_none());
+            advance(Duration::from_millis(10)).await;
+        }
+        assert_eq!(second.next().now_or_never(), Some(Some(())));
+    }
+
+    Ok(())
+}
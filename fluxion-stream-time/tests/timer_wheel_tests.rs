@@ -0,0 +1,203 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream_time::TimerWheel;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+fn drain(wheel: &mut TimerWheel<u32>, now: Duration) -> Vec<(u32, Duration)> {
+    core::iter::from_fn(|| wheel.poll_expired(now)).collect()
+}
+
+#[test]
+fn test_timer_wheel_expires_keys_in_deadline_order() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    wheel.insert(1, Duration::from_secs(3));
+    wheel.insert(2, Duration::from_millis(5));
+    wheel.insert(3, Duration::from_secs(3600));
+    wheel.insert(4, Duration::from_millis(200));
+
+    // Act
+    let expired = drain(&mut wheel, Duration::from_secs(10));
+
+    // Assert
+    assert_eq!(
+        expired,
+        vec![
+            (2, Duration::from_millis(5)),
+            (4, Duration::from_millis(200)),
+            (1, Duration::from_secs(3)),
+        ]
+    );
+    assert_eq!(wheel.len(), 1);
+    assert_eq!(wheel.next_deadline(), Some(Duration::from_secs(3600)));
+}
+
+#[test]
+fn test_timer_wheel_does_not_expire_before_deadline() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    wheel.insert(1, Duration::from_millis(100));
+
+    // Act & Assert
+    assert_eq!(wheel.poll_expired(Duration::from_micros(99_999)), None);
+    assert_eq!(
+        wheel.poll_expired(Duration::from_millis(100)),
+        Some((1, Duration::from_millis(100)))
+    );
+    assert!(wheel.is_empty());
+}
+
+#[test]
+fn test_timer_wheel_insert_replaces_deadline() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    wheel.insert(1, Duration::from_millis(10));
+
+    // Act
+    let previous = wheel.insert(1, Duration::from_millis(50));
+
+    // Assert
+    assert_eq!(previous, Some(Duration::from_millis(10)));
+    assert_eq!(wheel.len(), 1);
+    assert_eq!(wheel.deadline(&1), Some(Duration::from_millis(50)));
+    assert_eq!(wheel.poll_expired(Duration::from_millis(20)), None);
+    assert_eq!(
+        wheel.poll_expired(Duration::from_millis(50)),
+        Some((1, Duration::from_millis(50)))
+    );
+}
+
+#[test]
+fn test_timer_wheel_remove_cancels_deadline() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    wheel.insert(1, Duration::from_millis(10));
+    wheel.insert(2, Duration::from_millis(20));
+
+    // Act
+    let removed = wheel.remove(&1);
+
+    // Assert
+    assert_eq!(removed, Some(Duration::from_millis(10)));
+    assert_eq!(wheel.remove(&1), None);
+    assert_eq!(wheel.next_deadline(), Some(Duration::from_millis(20)));
+    assert_eq!(
+        drain(&mut wheel, Duration::from_secs(1)),
+        vec![(2, Duration::from_millis(20))]
+    );
+}
+
+#[test]
+fn test_timer_wheel_next_deadline_follows_removal_of_earliest() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    wheel.insert(1, Duration::from_millis(700));
+    wheel.insert(2, Duration::from_millis(650));
+    wheel.insert(3, Duration::from_millis(690));
+    assert_eq!(wheel.next_deadline(), Some(Duration::from_millis(650)));
+
+    // Act
+    wheel.remove(&2);
+
+    // Assert
+    assert_eq!(wheel.next_deadline(), Some(Duration::from_millis(690)));
+}
+
+#[test]
+fn test_timer_wheel_expires_past_deadline_immediately() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    assert_eq!(wheel.poll_expired(Duration::from_secs(5)), None);
+
+    // Act
+    wheel.insert(1, Duration::from_secs(1));
+
+    // Assert
+    assert_eq!(
+        wheel.poll_expired(Duration::from_secs(5)),
+        Some((1, Duration::from_secs(1)))
+    );
+}
+
+#[test]
+fn test_timer_wheel_rounds_deadlines_up_to_resolution() {
+    // Arrange
+    let mut wheel = TimerWheel::with_resolution(Duration::from_millis(10));
+    wheel.insert(1, Duration::from_millis(42));
+
+    // Act & Assert
+    assert_eq!(wheel.next_deadline(), Some(Duration::from_millis(50)));
+    assert_eq!(wheel.poll_expired(Duration::from_millis(49)), None);
+    assert_eq!(
+        wheel.poll_expired(Duration::from_millis(50)),
+        Some((1, Duration::from_millis(42)))
+    );
+}
+
+#[test]
+fn test_timer_wheel_handles_deadlines_beyond_range() {
+    // Arrange
+    let day = Duration::from_secs(86_400);
+    let mut wheel = TimerWheel::new();
+    wheel.insert(1, 3 * day);
+    wheel.insert(2, day);
+
+    // Act & Assert
+    assert_eq!(wheel.next_deadline(), Some(day));
+    assert_eq!(drain(&mut wheel, 2 * day), vec![(2, day)]);
+    assert_eq!(wheel.poll_expired(3 * day - Duration::from_micros(1)), None);
+    assert_eq!(drain(&mut wheel, 3 * day), vec![(1, 3 * day)]);
+}
+
+#[test]
+fn test_timer_wheel_matches_sorted_map_model() {
+    // Arrange
+    let mut wheel = TimerWheel::new();
+    let mut model = BTreeMap::<u32, Duration>::new();
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut random = move |bound: u64| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed % bound
+    };
+    let mut now = Duration::ZERO;
+
+    for _ in 0..20_000 {
+        // Act
+        let key = random(500) as u32;
+        match random(10) {
+            0..=5 => {
+                // Spread deadlines over every level
+                let scale = 10u64.pow(random(10) as u32);
+                let deadline = now + Duration::from_nanos(random(scale) * 1_000);
+                assert_eq!(wheel.insert(key, deadline), model.insert(key, deadline));
+            }
+            6 => assert_eq!(wheel.remove(&key), model.remove(&key)),
+            _ => {
+                now += Duration::from_micros(random(50_000));
+                let expired = drain(&mut wheel, now);
+                let mut due: Vec<_> = model
+                    .iter()
+                    .filter(|(_, &deadline)| deadline <= now)
+                    .map(|(&key, &deadline)| (key, deadline))
+                    .collect();
+                due.sort_by_key(|&(key, deadline)| (deadline, key));
+                for (key, _) in &due {
+                    model.remove(key);
+                }
+
+                // Assert
+                let mut sorted = expired.clone();
+                sorted.sort_by_key(|&(key, deadline)| (deadline, key));
+                assert_eq!(sorted, due);
+                assert!(expired.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+            }
+        }
+        assert_eq!(wheel.len(), model.len());
+        assert_eq!(wheel.next_deadline(), model.values().min().copied());
+    }
+}
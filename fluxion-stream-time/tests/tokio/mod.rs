@@ -5,3 +5,4 @@
 pub mod instant_timestamped_tests;
 pub mod multi_threaded;
 pub mod single_threaded;
+pub mod timer_interval_tests;
@@ -4,3 +4,4 @@
 
 pub mod debounce;
 pub mod delay;
+pub mod tap_ring;
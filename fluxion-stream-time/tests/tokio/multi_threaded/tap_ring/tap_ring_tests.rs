@@ -0,0 +1,43 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{TapRing, TapRingExt, TokioTimestamped};
+use fluxion_test_utils::{
+    helpers::test_channel,
+    test_data::{person_alice, person_bob, TestData},
+};
+use futures::StreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_tap_ring_queried_from_another_task() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(4);
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let tapped = stream.tap_ring(ring.clone());
+
+    let pipeline = tokio::spawn(async move { tapped.count().await });
+    let inspector = ring.clone();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    drop(tx);
+    let count = pipeline.await?;
+
+    // Assert
+    let snapshot = tokio::spawn(async move { inspector.snapshot() }).await?;
+    assert_eq!(count, 2);
+    assert_eq!(
+        snapshot
+            .into_iter()
+            .map(|entry| entry.item.value)
+            .collect::<Vec<_>>(),
+        vec![person_alice(), person_bob()]
+    );
+
+    Ok(())
+}
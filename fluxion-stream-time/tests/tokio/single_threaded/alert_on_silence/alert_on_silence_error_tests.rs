@@ -0,0 +1,31 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream_time::{AlertOnSilenceExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream};
+use std::time::Duration;
+use tokio::time::{pause, Instant};
+
+#[tokio::test]
+async fn test_alert_on_silence_errors_pass_through_without_rearming() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<u32>>();
+    let mut result = stream.alert_on_silence(Duration::from_secs(1), || 0);
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(unwrap_stream(&mut result, 2000).await.unwrap().value, 0);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
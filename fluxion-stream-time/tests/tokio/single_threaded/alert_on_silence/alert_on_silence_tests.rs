@@ -0,0 +1,92 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{AlertOnSilenceExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream,
+};
+use std::time::Duration;
+use tokio::time::{pause, Instant};
+
+const ALERT: u32 = 0;
+
+#[tokio::test]
+async fn test_alert_on_silence_emits_one_alert_per_silence() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (_tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let start = Instant::now();
+
+    // Act
+    let mut result = stream.alert_on_silence(Duration::from_secs(1), || ALERT);
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 2000).await.unwrap().value, ALERT);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_no_element_emitted(&mut result, 5000).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alert_on_silence_passes_values_within_silence() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.alert_on_silence(Duration::from_secs(1), || ALERT);
+
+    for value in [1, 2, 3] {
+        // Act
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+
+        // Assert
+        assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, value);
+        assert_no_element_emitted(&mut result, 900).await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alert_on_silence_resumes_and_rearms_when_values_return() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.alert_on_silence(Duration::from_secs(1), || ALERT);
+    assert_eq!(unwrap_stream(&mut result, 2000).await.unwrap().value, ALERT);
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(7, timer.now()))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 7);
+    let resumed = Instant::now();
+    assert_eq!(unwrap_stream(&mut result, 2000).await.unwrap().value, ALERT);
+    assert!(resumed.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alert_on_silence_ends_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.alert_on_silence(Duration::from_secs(1), || ALERT);
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(1, timer.now()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 1);
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
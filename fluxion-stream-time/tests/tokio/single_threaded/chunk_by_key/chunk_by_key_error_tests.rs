@@ -0,0 +1,42 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem, Timestamped};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{ChunkByKeyExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream};
+use std::time::Duration;
+use tokio::time::pause;
+
+#[tokio::test]
+async fn test_chunk_by_key_errors_pass_through_without_flushing() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<u32>>();
+    let mut result = stream.chunk_by_key(|value: &u32| value % 2, 2, Duration::from_secs(1));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(1, timer.now())))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(3, timer.now())))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    let (key, items) = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!(key, 1);
+    assert_eq!(
+        items
+            .into_iter()
+            .map(Timestamped::into_inner)
+            .collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{StreamItem, Timestamped};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{ChunkByKeyExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream,
+};
+use std::time::Duration;
+use tokio::time::{pause, Instant};
+
+fn chunk(item: StreamItem<(u32, Vec<TokioTimestamped<u32>>)>) -> (u32, Vec<u32>) {
+    let (key, items) = item.unwrap();
+    (
+        key,
+        items.into_iter().map(Timestamped::into_inner).collect(),
+    )
+}
+
+#[tokio::test]
+async fn test_chunk_by_key_emits_full_chunks_immediately() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.chunk_by_key(|value: &u32| value % 2, 2, Duration::from_secs(1));
+    let start = Instant::now();
+
+    // Act
+    for value in [1, 2, 3, 5, 4] {
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+    }
+
+    // Assert
+    assert_eq!(
+        chunk(unwrap_stream(&mut result, 500).await),
+        (1, vec![1, 3])
+    );
+    assert_eq!(
+        chunk(unwrap_stream(&mut result, 500).await),
+        (0, vec![2, 4])
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert_no_element_emitted(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunk_by_key_flushes_partial_chunk_after_max_latency() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.chunk_by_key(|value: &u32| value % 2, 10, Duration::from_secs(1));
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(1, timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(3, timer.now()))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 900).await;
+    assert_eq!(
+        chunk(unwrap_stream(&mut result, 2000).await),
+        (1, vec![1, 3])
+    );
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunk_by_key_measures_latency_per_key() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.chunk_by_key(|value: &u32| value % 2, 10, Duration::from_millis(100));
+
+    tx.unbounded_send(TokioTimestamped::new(1, timer.now()))?;
+    assert_no_element_emitted(&mut result, 60).await;
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(2, timer.now()))?;
+
+    // Assert
+    assert_eq!(chunk(unwrap_stream(&mut result, 500).await), (1, vec![1]));
+    assert_no_element_emitted(&mut result, 20).await;
+    assert_eq!(chunk(unwrap_stream(&mut result, 500).await), (0, vec![2]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunk_by_key_starts_new_chunk_after_flush() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.chunk_by_key(|_: &u32| 0, 2, Duration::from_secs(1));
+
+    // Act
+    for value in [1, 2, 3] {
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+    }
+
+    // Assert
+    assert_eq!(
+        chunk(unwrap_stream(&mut result, 500).await),
+        (0, vec![1, 2])
+    );
+    assert_eq!(chunk(unwrap_stream(&mut result, 2000).await), (0, vec![3]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunk_by_key_flushes_open_chunks_in_order_at_end() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.chunk_by_key(|value: &u32| value % 10, 10, Duration::from_secs(60));
+    let start = Instant::now();
+
+    // Act
+    for value in [7, 3, 17, 5] {
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+    }
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        chunk(unwrap_stream(&mut result, 500).await),
+        (7, vec![7, 17])
+    );
+    assert_eq!(chunk(unwrap_stream(&mut result, 500).await), (3, vec![3]));
+    assert_eq!(chunk(unwrap_stream(&mut result, 500).await), (5, vec![5]));
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "chunk_by_key requires max_size > 0")]
+async fn test_chunk_by_key_panics_on_zero_max_size() {
+    let (_tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let _ = stream.chunk_by_key(|value: &u32| *value, 0, Duration::from_secs(1));
+}
@@ -0,0 +1,141 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{CollectWithinExt, TokioTimestamped};
+use fluxion_test_utils::{
+    helpers::{test_channel, test_channel_with_errors},
+    test_data::{animal_dog, person_alice, person_bob, TestData},
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::spawn;
+use tokio::time::{pause, sleep, Instant};
+
+fn values(items: Vec<StreamItem<TokioTimestamped<TestData>>>) -> Vec<TestData> {
+    items.into_iter().map(|item| item.unwrap().value).collect()
+}
+
+#[tokio::test]
+async fn test_collect_within_returns_items_of_window() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    spawn(async move {
+        sleep(Duration::from_millis(50)).await;
+        tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))
+            .unwrap();
+        sleep(Duration::from_millis(100)).await;
+        tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))
+            .unwrap();
+        sleep(Duration::from_secs(10)).await;
+    });
+
+    // Act
+    let start = Instant::now();
+    let items = stream.collect_within(Duration::from_millis(100)).await;
+
+    // Assert
+    assert_eq!(values(items), vec![person_alice()]);
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed >= Duration::from_millis(100) && elapsed < Duration::from_millis(150),
+        "{elapsed:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_within_resolves_when_stream_ends() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    drop(tx);
+
+    // Act
+    let start = Instant::now();
+    let items = stream.collect_within(Duration::from_secs(60)).await;
+
+    // Assert
+    assert_eq!(values(items), vec![person_alice(), person_bob()]);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_within_of_silent_stream_is_empty() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+
+    let (_tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+
+    // Act
+    let items = stream.collect_within(Duration::from_millis(100)).await;
+
+    // Assert
+    assert!(items.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_within_leaves_later_items_in_stream() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+
+    let (tx, mut stream) = test_channel::<TokioTimestamped<TestData>>();
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+
+    // Act
+    let first = (&mut stream)
+        .collect_within(Duration::from_millis(100))
+        .await;
+    tx.unbounded_send(TokioTimestamped::new(animal_dog(), timer.now()))?;
+    let second = (&mut stream)
+        .collect_within(Duration::from_millis(100))
+        .await;
+
+    // Assert
+    assert_eq!(values(first), vec![person_alice()]);
+    assert_eq!(values(second), vec![animal_dog()]);
+    drop(tx);
+    assert!(stream.next().await.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_within_collects_errors() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_alice(),
+        timer.now(),
+    )))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Act
+    let items = stream.collect_within(Duration::from_millis(100)).await;
+
+    // Assert
+    assert_eq!(items.len(), 2);
+    assert!(items[0].is_value());
+    assert!(items[1].is_error());
+
+    Ok(())
+}
@@ -5,5 +5,6 @@
 pub mod debounce_composition_error_tests;
 pub mod debounce_composition_tests;
 pub mod debounce_error_tests;
+pub mod debounce_heapless_tests;
 pub mod debounce_poll_tests;
 pub mod debounce_tests;
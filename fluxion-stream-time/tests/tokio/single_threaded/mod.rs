@@ -2,8 +2,17 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+pub mod alert_on_silence;
+pub mod chunk_by_key;
+pub mod collect_within;
 pub mod debounce;
 pub mod delay;
+pub mod quota;
+pub mod rate_limit;
 pub mod sample;
+pub mod schedule;
+pub mod summarize_errors;
+pub mod tap_ring;
 pub mod throttle;
 pub mod timeout;
+pub mod watchdog;
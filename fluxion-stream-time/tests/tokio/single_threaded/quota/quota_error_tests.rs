@@ -0,0 +1,53 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{QuotaExt, QuotaPolicy, TokioTimestamped};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, test_channel_with_errors, unwrap_stream,
+};
+use fluxion_test_utils::test_data::{person_alice, TestData};
+use std::time::Duration;
+use tokio::time::pause;
+
+#[tokio::test]
+async fn test_quota_errors_pass_through_without_counting() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let (mut result, mut violations) = stream.quota(
+        1,
+        Duration::from_secs(1),
+        |data: &TestData| data.clone(),
+        QuotaPolicy::Divert,
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("first")))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_alice(),
+        timer.now(),
+    )))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("second")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_no_element_emitted(&mut violations, 100).await;
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{QuotaExt, QuotaPolicy, TokioTimestamped};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream,
+};
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+use std::time::Duration;
+use tokio::time::{pause, sleep, Instant};
+
+#[tokio::test]
+async fn test_quota_drop_discards_items_beyond_limit_per_key() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let (mut result, _violations) = stream.quota(
+        2,
+        Duration::from_secs(1),
+        |data: &TestData| data.clone(),
+        QuotaPolicy::Drop,
+    );
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_no_element_emitted(&mut result, 2000).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_quota_divert_sends_excess_items_to_violations() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let (mut result, mut violations) = stream.quota(
+        1,
+        Duration::from_secs(1),
+        |data: &TestData| data.clone(),
+        QuotaPolicy::Divert,
+    );
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_stream(&mut violations, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_no_element_emitted(&mut violations, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_quota_delay_holds_excess_items_until_next_window() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let (mut result, _violations) = stream.quota(
+        1,
+        Duration::from_secs(1),
+        |data: &TestData| data.clone(),
+        QuotaPolicy::Delay,
+    );
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert_eq!(
+        unwrap_stream(&mut result, 2000).await.unwrap().value,
+        person_alice()
+    );
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_quota_starts_new_window_after_window_elapsed() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let (mut result, _violations) = stream.quota(
+        1,
+        Duration::from_millis(50),
+        |data: &TestData| data.clone(),
+        QuotaPolicy::Drop,
+    );
+
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    sleep(Duration::from_millis(60)).await;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_quota_ends_violations_with_main_stream() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let (mut result, mut violations) = stream.quota(
+        1,
+        Duration::from_secs(1),
+        |data: &TestData| data.clone(),
+        QuotaPolicy::Divert,
+    );
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(
+        unwrap_stream(&mut violations, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_stream_ended(&mut violations, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_quota_delay_releases_many_keys_in_arrival_order() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let (mut result, _violations) = stream.quota(
+        1,
+        Duration::from_millis(100),
+        |value: &u32| value % 1_000,
+        QuotaPolicy::Delay,
+    );
+    let start = Instant::now();
+
+    // Act
+    for value in 0..3_000 {
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+    }
+
+    // Assert
+    for value in 0..3_000 {
+        assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, value);
+        // The first item of every key goes immediately, the others wait
+        assert_eq!(start.elapsed() == Duration::ZERO, value < 1_000);
+    }
+    drop(tx);
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
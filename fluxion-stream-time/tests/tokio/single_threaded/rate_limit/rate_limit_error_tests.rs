@@ -0,0 +1,64 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{RateLimitExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream};
+use fluxion_test_utils::test_data::{person_alice, person_bob, person_charlie, TestData};
+use std::time::Duration;
+use tokio::time::{pause, Instant};
+
+#[tokio::test]
+async fn test_rate_limit_errors_follow_held_items_without_taking_tokens() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.rate_limit(10, 1);
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_alice(),
+        timer.now(),
+    )))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_bob(),
+        timer.now(),
+    )))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_charlie(),
+        timer.now(),
+    )))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    let released = start.elapsed();
+    assert!(released >= Duration::from_millis(100));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(start.elapsed(), released);
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_charlie()
+    );
+    // The error took no token, so charlie only waits for bob's
+    assert!(start.elapsed() >= released + Duration::from_millis(100));
+    assert!(start.elapsed() < released + Duration::from_millis(200));
+
+    Ok(())
+}
@@ -0,0 +1,228 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{RateLimitExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream,
+};
+use fluxion_test_utils::test_data::{person_alice, person_bob, person_charlie, TestData};
+use futures::StreamExt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{pause, sleep, Instant};
+
+#[tokio::test]
+async fn test_rate_limit_emits_burst_immediately() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.rate_limit(10, 3);
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_charlie(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_charlie()
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_delays_items_beyond_burst() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.rate_limit(10, 1);
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_charlie(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_no_element_emitted(&mut result, 50).await;
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_charlie()
+    );
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_refills_tokens_while_idle() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.rate_limit(10, 2);
+
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    sleep(Duration::from_millis(100)).await;
+    let resumed = Instant::now();
+    tx.unbounded_send(TokioTimestamped::new(person_charlie(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_charlie()
+    );
+    assert!(resumed.elapsed() < Duration::from_millis(50));
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert!(resumed.elapsed() >= Duration::from_millis(50));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_by_key_limits_keys_independently() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.rate_limit_by_key(1, 1, |data: &TestData| data.clone());
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert_eq!(
+        unwrap_stream(&mut result, 2000).await.unwrap().value,
+        person_alice()
+    );
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_emits_held_items_before_ending() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.rate_limit(10, 1);
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_by_key_releases_many_keys_in_arrival_order() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let mut result = stream.rate_limit_by_key(10, 1, |value: &u32| value % 1_000);
+    let start = Instant::now();
+
+    // Act
+    for value in 0..3_000 {
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+    }
+
+    // Assert
+    for value in 0..3_000 {
+        assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, value);
+        // The first item of every key goes immediately, the others wait
+        assert_eq!(start.elapsed() == Duration::ZERO, value < 1_000);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_stops_polling_source_while_key_holds_limit() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel::<TokioTimestamped<u32>>();
+    let pulled = Arc::new(AtomicUsize::new(0));
+    let counter = pulled.clone();
+    let mut result = stream
+        .inspect(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })
+        .rate_limit(10, 1);
+
+    // Act
+    for value in 0..100 {
+        tx.unbounded_send(TokioTimestamped::new(value, timer.now()))?;
+    }
+
+    // Assert
+    // A key holds at most 64 items, so the rest stay in the source
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 0);
+    assert_eq!(pulled.load(Ordering::SeqCst), 64);
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 1);
+    assert_eq!(pulled.load(Ordering::SeqCst), 65);
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream_time::{schedule, Cron, CronError};
+
+#[test]
+fn test_cron_rejects_wrong_field_count() {
+    // Act & Assert
+    assert_eq!(Cron::parse("* * * *"), Err(CronError::FieldCount(4)));
+    assert_eq!(Cron::parse("* * * * * * *"), Err(CronError::FieldCount(7)));
+    assert_eq!(Cron::parse(""), Err(CronError::FieldCount(0)));
+}
+
+#[test]
+fn test_cron_rejects_out_of_range_values() {
+    // Act & Assert
+    assert_eq!(
+        Cron::parse("60 * * * *"),
+        Err(CronError::InvalidField("minute"))
+    );
+    assert_eq!(
+        Cron::parse("* 24 * * *"),
+        Err(CronError::InvalidField("hour"))
+    );
+    assert_eq!(
+        Cron::parse("* * 0 * *"),
+        Err(CronError::InvalidField("day-of-month"))
+    );
+    assert_eq!(
+        Cron::parse("* * * 13 *"),
+        Err(CronError::InvalidField("month"))
+    );
+    assert_eq!(
+        Cron::parse("* * * * 8"),
+        Err(CronError::InvalidField("day-of-week"))
+    );
+    assert_eq!(
+        Cron::parse("60 * * * * *"),
+        Err(CronError::InvalidField("second"))
+    );
+}
+
+#[test]
+fn test_cron_rejects_malformed_fields() {
+    // Act & Assert
+    assert_eq!(
+        Cron::parse("*/0 * * * *"),
+        Err(CronError::InvalidField("minute"))
+    );
+    assert_eq!(
+        Cron::parse("30-10 * * * *"),
+        Err(CronError::InvalidField("minute"))
+    );
+    assert_eq!(
+        Cron::parse("a * * * *"),
+        Err(CronError::InvalidField("minute"))
+    );
+    assert_eq!(
+        Cron::parse("1,,2 * * * *"),
+        Err(CronError::InvalidField("minute"))
+    );
+    assert_eq!(
+        Cron::parse("* * * FOO *"),
+        Err(CronError::InvalidField("month"))
+    );
+}
+
+#[test]
+fn test_cron_error_display() {
+    // Act & Assert
+    assert_eq!(
+        CronError::FieldCount(4).to_string(),
+        "cron expression has 4 fields, expected 5 or 6"
+    );
+    assert_eq!(
+        CronError::InvalidField("hour").to_string(),
+        "invalid hour field in cron expression"
+    );
+}
+
+#[tokio::test]
+async fn test_schedule_rejects_invalid_expression() {
+    // Act
+    let result = schedule("every minute");
+
+    // Assert
+    assert!(matches!(result, Err(CronError::FieldCount(2))));
+}
@@ -0,0 +1,264 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{schedule, schedule_with_clock, Cron};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, unwrap_stream, unwrap_value,
+};
+use std::time::Duration;
+use tokio::time::{pause, Instant};
+
+// 2025-01-01 00:00:00 UTC, a Wednesday
+const NEW_YEAR_2025: u64 = 1_735_689_600;
+const MINUTE: u64 = 60;
+const HOUR: u64 = 3_600;
+const DAY: u64 = 86_400;
+
+/// A wall clock starting at `start` seconds since the Unix epoch and following Tokio's
+/// paused time.
+fn clock_from(start: u64) -> impl FnMut() -> Duration + Send + Sync + 'static {
+    let origin = Instant::now();
+    move || Duration::from_secs(start) + origin.elapsed()
+}
+
+#[tokio::test]
+async fn test_schedule_emits_at_each_matching_minute() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let cron = Cron::parse("* * * * *")?;
+    let mut result = schedule_with_clock(cron, clock_from(NEW_YEAR_2025));
+
+    // Act & Assert
+    assert_no_element_emitted(&mut result, 59_000).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 2_000).await)).value,
+        NEW_YEAR_2025 + MINUTE
+    );
+    assert_no_element_emitted(&mut result, 59_000).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 2_000).await)).value,
+        NEW_YEAR_2025 + 2 * MINUTE
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schedule_with_seconds_field() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let cron = Cron::parse("*/15 * * * * *")?;
+    let mut result = schedule_with_clock(cron, clock_from(NEW_YEAR_2025 + 5));
+
+    // Act & Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 11_000).await)).value,
+        NEW_YEAR_2025 + 15
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 16_000).await)).value,
+        NEW_YEAR_2025 + 30
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schedule_stamps_ticks_when_they_fire() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let start = TokioTimer.now();
+    let cron = Cron::parse("* * * * *")?;
+    let mut result = schedule_with_clock(cron, clock_from(NEW_YEAR_2025 + 30));
+
+    // Act
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 31_000).await));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 61_000).await));
+
+    // Assert
+    assert!(start <= first.timestamp);
+    assert!(first.timestamp <= second.timestamp);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schedule_skips_ticks_missed_by_a_slow_consumer() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let cron = Cron::parse("* * * * *")?;
+    let mut result = schedule_with_clock(cron, clock_from(NEW_YEAR_2025));
+    unwrap_stream(&mut result, 61_000).await;
+
+    // Act
+    tokio::time::advance(Duration::from_secs(150)).await;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 31_000).await)).value,
+        NEW_YEAR_2025 + 4 * MINUTE
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schedule_ends_when_expression_never_matches() -> anyhow::Result<()> {
+    // Arrange
+    let cron = Cron::parse("0 0 30 2 *")?;
+
+    // Act
+    let mut result = schedule_with_clock(cron, clock_from(NEW_YEAR_2025));
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schedule_from_system_clock_waits_for_next_match() -> anyhow::Result<()> {
+    // Arrange
+    let mut result = schedule("0 0 1 1 *")?;
+
+    // Act & Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_next_after_hours_and_minutes() -> anyhow::Result<()> {
+    // Arrange
+    let cron = Cron::parse("30 9,17 * * *")?;
+
+    // Act & Assert
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025),
+        Some(NEW_YEAR_2025 + 9 * HOUR + 30 * MINUTE)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 9 * HOUR + 30 * MINUTE),
+        Some(NEW_YEAR_2025 + 17 * HOUR + 30 * MINUTE)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 17 * HOUR + 30 * MINUTE),
+        Some(NEW_YEAR_2025 + DAY + 9 * HOUR + 30 * MINUTE)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_next_after_weekdays_skips_weekend() -> anyhow::Result<()> {
+    // Arrange
+    let cron = Cron::parse("0 8 * * MON-FRI")?;
+    // Friday 2025-01-03 08:00
+    let friday = NEW_YEAR_2025 + 2 * DAY + 8 * HOUR;
+
+    // Act & Assert
+    assert_eq!(cron.next_after(friday), Some(friday + 3 * DAY));
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_next_after_matches_either_restricted_day_field() -> anyhow::Result<()> {
+    // Arrange
+    let cron = Cron::parse("0 0 15 * SUN")?;
+
+    // Act & Assert
+    // Sunday 2025-01-05, then Sunday 2025-01-12, then the 15th
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025),
+        Some(NEW_YEAR_2025 + 4 * DAY)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 4 * DAY),
+        Some(NEW_YEAR_2025 + 11 * DAY)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 11 * DAY),
+        Some(NEW_YEAR_2025 + 14 * DAY)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_next_after_leap_day() -> anyhow::Result<()> {
+    // Arrange
+    let cron = Cron::parse("0 12 29 FEB *")?;
+
+    // Act & Assert
+    // 2028-02-29 12:00 UTC
+    assert_eq!(cron.next_after(NEW_YEAR_2025), Some(1_835_438_400));
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_next_after_sunday_as_seven() -> anyhow::Result<()> {
+    // Arrange
+    let by_seven = Cron::parse("0 0 * * 7")?;
+    let by_zero = Cron::parse("0 0 * * 0")?;
+
+    // Act & Assert
+    assert_eq!(by_seven, by_zero);
+    assert_eq!(
+        by_seven.next_after(NEW_YEAR_2025),
+        Some(NEW_YEAR_2025 + 4 * DAY)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_macros() -> anyhow::Result<()> {
+    // Arrange
+    let hourly = Cron::parse("@hourly")?;
+    let daily = Cron::parse("@daily")?;
+    let monthly = Cron::parse("@monthly")?;
+    let yearly = Cron::parse("@yearly")?;
+
+    // Act & Assert
+    assert_eq!(hourly.next_after(NEW_YEAR_2025), Some(NEW_YEAR_2025 + HOUR));
+    assert_eq!(daily.next_after(NEW_YEAR_2025), Some(NEW_YEAR_2025 + DAY));
+    assert_eq!(
+        monthly.next_after(NEW_YEAR_2025),
+        Some(NEW_YEAR_2025 + 31 * DAY)
+    );
+    // 2026-01-01 00:00 UTC
+    assert_eq!(yearly.next_after(NEW_YEAR_2025), Some(1_767_225_600));
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_ranges_with_steps() -> anyhow::Result<()> {
+    // Arrange
+    let cron = Cron::parse("10-40/15 * * * *")?;
+
+    // Act & Assert
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025),
+        Some(NEW_YEAR_2025 + 10 * MINUTE)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 10 * MINUTE),
+        Some(NEW_YEAR_2025 + 25 * MINUTE)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 25 * MINUTE),
+        Some(NEW_YEAR_2025 + 40 * MINUTE)
+    );
+    assert_eq!(
+        cron.next_after(NEW_YEAR_2025 + 40 * MINUTE),
+        Some(NEW_YEAR_2025 + HOUR + 10 * MINUTE)
+    );
+
+    Ok(())
+}
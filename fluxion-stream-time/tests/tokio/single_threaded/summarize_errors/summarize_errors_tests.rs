@@ -0,0 +1,177 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{SummarizeErrorsExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel_with_errors, unwrap_stream,
+};
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+use std::time::Duration;
+use tokio::time::{pause, Instant};
+
+#[tokio::test]
+async fn test_summarize_errors_emits_one_error_per_window() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.summarize_errors(Duration::from_secs(1));
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("first")))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::timeout_error("second")))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::timeout_error("third")))?;
+
+    // Assert
+    let StreamItem::Error(summary) = unwrap_stream(&mut result, 2000).await else {
+        panic!("expected a summary error");
+    };
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    let message = summary.to_string();
+    assert!(message.contains("3 errors"));
+    assert!(message.contains("StreamProcessingError: 1"));
+    assert!(message.contains("TimeoutError: 2"));
+    assert!(message.contains("third"));
+    assert_no_element_emitted(&mut result, 2000).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_summarize_errors_passes_values_through_immediately() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.summarize_errors(Duration::from_secs(1));
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_alice(),
+        timer.now(),
+    )))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert!(matches!(
+        unwrap_stream(&mut result, 2000).await,
+        StreamItem::Error(_)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_summarize_errors_opens_new_window_after_report() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.summarize_errors(Duration::from_secs(1));
+
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("first")))?;
+    unwrap_stream(&mut result, 2000).await;
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::user_error("second")))?;
+
+    // Assert
+    let StreamItem::Error(summary) = unwrap_stream(&mut result, 2000).await else {
+        panic!("expected a summary error");
+    };
+    assert!(summary.to_string().contains("1 errors (UserError: 1)"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_summarize_errors_reports_open_window_when_stream_ends() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.summarize_errors(Duration::from_secs(60));
+    let start = Instant::now();
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    drop(tx);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_summarize_errors_aside_sends_reports_to_side_stream() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let (mut result, mut reports) = stream.summarize_errors_aside(Duration::from_secs(1));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("first")))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_alice(),
+        timer.now(),
+    )))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::timeout_error("second")))?;
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_bob(),
+        timer.now(),
+    )))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_no_element_emitted(&mut result, 2000).await;
+
+    let report = unwrap_stream(&mut reports, 500).await.unwrap();
+    assert_eq!(report.total, 2);
+    assert_eq!(report.counts["StreamProcessingError"], 1);
+    assert_eq!(report.counts["TimeoutError"], 1);
+    assert!(report.first_seen <= report.last_seen);
+    assert!(report.last_error.to_string().contains("second"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_summarize_errors_aside_ends_reports_with_main_stream() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let (mut result, mut reports) = stream.summarize_errors_aside(Duration::from_secs(60));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    drop(tx);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(unwrap_stream(&mut reports, 500).await.unwrap().total, 1);
+    assert_stream_ended(&mut reports, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream::FilterOrderedExt;
+use fluxion_stream_time::{TapRing, TapRingExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_tap_ring_before_and_after_filter() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let before: TapRing<TokioTimestamped<TestData>> = TapRing::new(8);
+    let after: TapRing<TokioTimestamped<TestData>> = TapRing::new(8);
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream
+        .tap_ring(before.clone())
+        .filter_ordered(|data| matches!(data, TestData::Person(_)))
+        .tap_ring(after.clone());
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(animal_dog(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_bob()
+    );
+    assert_eq!(before.total_seen(), 3);
+    assert_eq!(after.total_seen(), 2);
+    assert_eq!(
+        after.latest().map(|entry| entry.item.value),
+        Some(person_bob())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{TapRing, TapRingExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream, unwrap_value};
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_tap_ring_passes_through_errors_without_recording() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(4);
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.tap_ring(ring.clone());
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_alice(),
+        timer.now(),
+    )))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(ring.total_seen(), 1);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(TokioTimestamped::new(
+        person_bob(),
+        timer.now(),
+    )))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_bob()
+    );
+    let values: Vec<_> = ring
+        .snapshot()
+        .into_iter()
+        .map(|entry| entry.item.value)
+        .collect();
+    assert_eq!(values, vec![person_alice(), person_bob()]);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{TapRing, TapRingExt, TokioTimestamped};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::test_data::{
+    animal_dog, person_alice, person_bob, person_charlie, TestData,
+};
+
+#[tokio::test]
+async fn test_tap_ring_values_pass_through_unchanged() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(4);
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.tap_ring(ring.clone());
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(animal_dog(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tap_ring_records_items_with_timestamps_and_arrival() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(4);
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.tap_ring(ring.clone());
+    let produced_at = timer.now();
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), produced_at))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    let entry = ring.latest().expect("entry recorded");
+    assert_eq!(entry.item.value, person_alice());
+    assert_eq!(entry.timestamp(), produced_at);
+    assert!(entry.arrived_at >= produced_at);
+    assert_eq!(ring.len(), 1);
+    assert_eq!(ring.total_seen(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tap_ring_keeps_last_n_items() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(2);
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.tap_ring(ring.clone());
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+    tx.unbounded_send(TokioTimestamped::new(person_charlie(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    let values: Vec<_> = ring
+        .snapshot()
+        .into_iter()
+        .map(|entry| entry.item.value)
+        .collect();
+    assert_eq!(values, vec![person_bob(), person_charlie()]);
+    assert_eq!(ring.capacity(), 2);
+    assert_eq!(ring.total_seen(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tap_ring_clear_keeps_total_seen() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(2);
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.tap_ring(ring.clone());
+
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    ring.clear();
+
+    // Assert
+    assert!(ring.is_empty());
+    assert!(ring.latest().is_none());
+    assert_eq!(ring.total_seen(), 1);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "tap_ring: capacity must be at least 1")]
+fn test_tap_ring_zero_capacity_panics() {
+    let _ring: TapRing<TokioTimestamped<TestData>> = TapRing::new(0);
+}
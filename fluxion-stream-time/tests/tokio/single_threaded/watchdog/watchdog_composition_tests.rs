@@ -0,0 +1,42 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream::FilterOrderedExt;
+use fluxion_stream_time::{StallEvent, StallKind, TokioTimestamped, WatchdogExt};
+use fluxion_test_utils::helpers::{assert_no_element_emitted, test_channel};
+use fluxion_test_utils::test_data::{animal_dog, TestData};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{advance, pause};
+
+#[tokio::test]
+async fn test_watchdog_after_filter_detects_starvation() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let events = Arc::new(Mutex::new(Vec::<StallEvent>::new()));
+    let sink = events.clone();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream
+        .filter_ordered(|data| matches!(data, TestData::Person(_)))
+        .watchdog(Duration::from_millis(100), move |event| {
+            sink.lock().unwrap().push(event)
+        });
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(animal_dog(), timer.now()))?;
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(100)).await;
+    assert_no_element_emitted(&mut result, 0).await;
+
+    // Assert
+    let recorded = events.lock().unwrap().clone();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].kind, StallKind::Source);
+    assert_eq!(recorded[0].items, 0);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream_time::{StallEvent, TokioTimestamped, WatchdogExt};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, test_channel_with_errors, unwrap_stream,
+};
+use fluxion_test_utils::test_data::TestData;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{advance, pause};
+
+#[tokio::test]
+async fn test_watchdog_errors_pass_through_and_count_as_progress() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let events = Arc::new(Mutex::new(Vec::<StallEvent>::new()));
+    let sink = events.clone();
+    let (tx, stream) = test_channel_with_errors::<TokioTimestamped<TestData>>();
+    let mut result = stream.watchdog(Duration::from_millis(100), move |event| {
+        sink.lock().unwrap().push(event)
+    });
+
+    // Act
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(60)).await;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(60)).await;
+    assert_no_element_emitted(&mut result, 0).await;
+
+    // Assert
+    assert!(events.lock().unwrap().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use fluxion_stream_time::{StallEvent, StallKind, TokioTimestamped, WatchdogExt};
+use fluxion_test_utils::helpers::{assert_no_element_emitted, test_channel, unwrap_stream};
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{advance, pause};
+
+fn recorder() -> (
+    Arc<Mutex<Vec<StallEvent>>>,
+    impl FnMut(StallEvent) + Send + Sync,
+) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    (events, move |event| sink.lock().unwrap().push(event))
+}
+
+#[tokio::test]
+async fn test_watchdog_values_pass_through_without_stall() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let (events, on_stall) = recorder();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.watchdog(Duration::from_secs(1), on_stall);
+
+    // Act
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert!(events.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watchdog_reports_stalled_source_once() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (events, on_stall) = recorder();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.watchdog(Duration::from_millis(100), on_stall);
+
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(100)).await;
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(300)).await;
+    assert_no_element_emitted(&mut result, 0).await;
+
+    // Assert
+    let recorded = events.lock().unwrap().clone();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].kind, StallKind::Source);
+    assert!(recorded[0].idle >= Duration::from_millis(100));
+    assert_eq!(recorded[0].items, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watchdog_rearms_after_progress() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    pause();
+    let (events, on_stall) = recorder();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.watchdog(Duration::from_millis(100), on_stall);
+
+    // Act
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(100)).await;
+    assert_no_element_emitted(&mut result, 0).await;
+
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    assert_no_element_emitted(&mut result, 0).await;
+    advance(Duration::from_millis(100)).await;
+    assert_no_element_emitted(&mut result, 0).await;
+
+    // Assert
+    let recorded = events.lock().unwrap().clone();
+    assert_eq!(recorded.len(), 2);
+    assert_eq!(recorded[0].items, 0);
+    assert_eq!(recorded[1].items, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watchdog_reports_slow_consumer() -> anyhow::Result<()> {
+    // Arrange
+    let timer = TokioTimer;
+    let (events, on_stall) = recorder();
+    let (tx, stream) = test_channel::<TokioTimestamped<TestData>>();
+    let mut result = stream.watchdog(Duration::from_millis(20), on_stall);
+
+    tx.unbounded_send(TokioTimestamped::new(person_alice(), timer.now()))?;
+    tx.unbounded_send(TokioTimestamped::new(person_bob(), timer.now()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    std::thread::sleep(Duration::from_millis(30));
+    let item = unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert_eq!(item.unwrap().value, person_bob());
+    let recorded = events.lock().unwrap().clone();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].kind, StallKind::Consumer);
+    assert!(recorded[0].idle >= Duration::from_millis(30));
+
+    Ok(())
+}
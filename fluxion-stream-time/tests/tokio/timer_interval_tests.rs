@@ -0,0 +1,40 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::tokio::TokioTimer;
+use fluxion_runtime::timer::Timer;
+use futures::{FutureExt, StreamExt};
+use std::time::Duration;
+use tokio::time::{advance, pause};
+
+#[tokio::test]
+async fn test_interval_ticks_every_period() {
+    // Arrange
+    pause();
+    let mut interval = TokioTimer.interval(Duration::from_millis(100));
+
+    // Act & Assert
+    advance(Duration::from_millis(90)).await;
+    assert!(interval.next().now_or_never().is_none());
+
+    advance(Duration::from_millis(15)).await;
+    assert_eq!(interval.next().now_or_never(), Some(Some(())));
+    assert!(interval.next().now_or_never().is_none());
+
+    advance(Duration::from_millis(100)).await;
+    assert_eq!(interval.next().now_or_never(), Some(Some(())));
+}
+
+#[tokio::test]
+async fn test_interval_with_zero_period_keeps_ticking() {
+    // Arrange
+    pause();
+    let mut interval = TokioTimer.interval(Duration::ZERO);
+
+    // Act & Assert
+    for _ in 0..3 {
+        advance(Duration::from_millis(1)).await;
+        assert_eq!(interval.next().now_or_never(), Some(Some(())));
+    }
+}
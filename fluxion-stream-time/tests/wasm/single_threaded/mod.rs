@@ -7,3 +7,4 @@ pub mod delay;
 pub mod sample;
 pub mod throttle;
 pub mod timeout;
+pub mod timer;
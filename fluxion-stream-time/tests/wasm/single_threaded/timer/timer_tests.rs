@@ -0,0 +1,39 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_runtime::impls::wasm::WasmTimer;
+use fluxion_runtime::timer::Timer;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+async fn test_wasm_sleep_never_completes_early() {
+    // Arrange
+    let timer = WasmTimer;
+
+    for micros in [500, 1_500, 4_200] {
+        let duration = Duration::from_micros(micros);
+        let start = timer.now();
+
+        // Act
+        timer.sleep_future(duration).await;
+
+        // Assert
+        assert!(timer.now() - start >= duration);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_instant_keeps_sub_millisecond_arithmetic() {
+    // Arrange
+    let timer = WasmTimer;
+    let now = timer.now();
+
+    // Act
+    let later = now + Duration::from_micros(1_500);
+
+    // Assert
+    assert_eq!(later - now, Duration::from_micros(1_500));
+    assert!(timer.now() >= now);
+}
@@ -6,10 +6,8 @@ use criterion::{BenchmarkId, Criterion, Throughput};
 use fluxion_core::StreamItem;
 use fluxion_stream::OrderedStreamExt;
 use fluxion_test_utils::sequenced::Sequenced;
-use futures::{
-    stream::{self, StreamExt},
-    Stream,
-};
+use fluxion_test_utils::test_data::TestStreamBuilder;
+use futures::{stream::StreamExt, Stream};
 use std::hint::black_box;
 use tokio::runtime::Runtime;
 
@@ -17,10 +15,9 @@ fn make_stream(
     size: usize,
     payload_size: usize,
 ) -> impl Stream<Item = StreamItem<Sequenced<Vec<u8>>>> {
-    let items: Vec<Sequenced<Vec<u8>>> = (0..size)
-        .map(|_i| Sequenced::new(vec![0u8; payload_size]))
-        .collect();
-    stream::iter(items).map(StreamItem::Value)
+    TestStreamBuilder::new(size)
+        .values(move |_| vec![0u8; payload_size])
+        .build_stream()
 }
 
 pub fn bench_ordered_merge(c: &mut Criterion) {
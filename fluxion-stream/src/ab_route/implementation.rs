@@ -0,0 +1,248 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::hash::{Hash, Hasher};
+use fluxion_core::sync::atomic::{AtomicU64, Ordering};
+use fluxion_core::sync::Arc;
+use fluxion_core::FluxionTask;
+
+/// Share of items routed to the B stream, adjustable while the router runs.
+///
+/// Clones share the same ratio, so one clone can be kept to change the split after the
+/// other has been passed to [`ab_route`](crate::AbRouteExt::ab_route) or
+/// [`ab_route_by_key`](crate::AbRouteExt::ab_route_by_key).
+#[derive(Clone, Debug)]
+pub struct SplitRatio {
+    bits: Arc<AtomicU64>,
+}
+
+impl SplitRatio {
+    /// Creates a split sending `ratio` of the items to B.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not between `0.0` and `1.0`.
+    pub fn new(ratio: f64) -> Self {
+        check_ratio(ratio);
+        Self {
+            bits: Arc::new(AtomicU64::new(ratio.to_bits())),
+        }
+    }
+
+    /// Changes the share of items routed to B, starting with the next item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not between `0.0` and `1.0`.
+    pub fn set(&self, ratio: f64) {
+        check_ratio(ratio);
+        self.bits.store(ratio.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Returns the share of items currently routed to B.
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+}
+
+fn check_ratio(ratio: f64) {
+    assert!(
+        (0.0..=1.0).contains(&ratio),
+        "ab_route: ratio must be between 0.0 and 1.0, got {ratio}"
+    );
+}
+
+/// Maps a key to a stable point in `[0, 1)` with FNV-1a, independent of the platform.
+pub(super) fn key_point<K: Hash>(key: &K) -> f64 {
+    let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+    key.hash(&mut hasher);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TaskGuard {
+    pub(crate) task: FluxionTask,
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        self.task.cancel();
+    }
+}
+
+macro_rules! define_ab_route_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{key_point, SplitRatio, TaskGuard};
+        use crate::coop::CoopBudget;
+        use alloc::boxed::Box;
+        use core::fmt::Debug;
+        use core::hash::Hash;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use fluxion_core::sync::Arc;
+        use fluxion_core::{Fluxion, FluxionSubject, FluxionTask, StreamItem};
+        use futures::future::{select, Either};
+        use futures::{Stream, StreamExt};
+
+        type InnerStream<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)* 'static>>;
+
+        /// One of the two outputs of [`ab_route`](AbRouteExt::ab_route).
+        pub struct RoutedStream<T: Fluxion>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            inner: InnerStream<T>,
+            _guard: Arc<TaskGuard>,
+        }
+
+        impl<T: Fluxion> Debug for RoutedStream<T>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("RoutedStream")
+                    .field("inner", &"<stream>")
+                    .finish()
+            }
+        }
+
+        impl<T: Fluxion> Stream for RoutedStream<T>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.inner.as_mut().poll_next(cx)
+            }
+        }
+
+        pub trait AbRouteExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Routes each item to B with the probability held by `split`, drawn from a
+            /// generator seeded with `seed`, and to A otherwise.
+            fn ab_route(self, split: SplitRatio, seed: u64) -> (RoutedStream<T>, RoutedStream<T>)
+            where
+                Self: Unpin + $($bounds)* 'static;
+
+            /// Routes each item by the hash of its key, so items sharing a key take the
+            /// same route while the ratio is unchanged.
+            fn ab_route_by_key<K, F>(self, split: SplitRatio, key: F) -> (RoutedStream<T>, RoutedStream<T>)
+            where
+                Self: Unpin + $($bounds)* 'static,
+                K: Hash,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static;
+        }
+
+        impl<S, T> AbRouteExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn ab_route(self, split: SplitRatio, seed: u64) -> (RoutedStream<T>, RoutedStream<T>)
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                let mut rng = fastrand::Rng::with_seed(seed);
+                route(self, move |_| rng.f64() < split.get())
+            }
+
+            fn ab_route_by_key<K, F>(self, split: SplitRatio, key: F) -> (RoutedStream<T>, RoutedStream<T>)
+            where
+                Self: Unpin + $($bounds)* 'static,
+                K: Hash,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+            {
+                route(self, move |inner| key_point(&key(inner)) < split.get())
+            }
+        }
+
+        fn route<S, T, F>(stream: S, mut to_b: F) -> (RoutedStream<T>, RoutedStream<T>)
+        where
+            S: Stream<Item = StreamItem<T>> + Unpin + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+            F: FnMut(&T::Inner) -> bool + $($bounds)* 'static,
+        {
+            let a_subject = FluxionSubject::<T>::new();
+            let b_subject = FluxionSubject::<T>::new();
+
+            let a_stream = a_subject
+                .subscribe()
+                .unwrap_or_else(|_| unreachable!("fresh subject should allow subscription"));
+            let b_stream = b_subject
+                .subscribe()
+                .unwrap_or_else(|_| unreachable!("fresh subject should allow subscription"));
+
+            let task = FluxionTask::spawn(|cancel| async move {
+                let mut stream = stream;
+                let mut budget = CoopBudget::new();
+                while let Either::Left((stream_item, _)) =
+                    select(stream.next(), cancel.cancelled()).await
+                {
+                    match stream_item {
+                        Some(StreamItem::Value(value)) => {
+                            let inner = value.clone().into_inner();
+                            let _ = if to_b(&inner) {
+                                b_subject.next(value)
+                            } else {
+                                a_subject.next(value)
+                            };
+                            budget.consume();
+                            budget.proceed().await;
+                        }
+                        Some(StreamItem::Error(e)) => {
+                            let _ = a_subject.error(e.clone());
+                            let _ = b_subject.error(e);
+                            break;
+                        }
+                        None => {
+                            break;
+                        }
+                    }
+                }
+                a_subject.close();
+                b_subject.close();
+            });
+
+            let guard = Arc::new(TaskGuard { task });
+
+            (
+                RoutedStream {
+                    inner: Box::pin(a_stream),
+                    _guard: guard.clone(),
+                },
+                RoutedStream {
+                    inner: Box::pin(b_stream),
+                    _guard: guard,
+                },
+            )
+        }
+    };
+}
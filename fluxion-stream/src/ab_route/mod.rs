@@ -0,0 +1,93 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! A/B router that splits a stream into two by an adjustable ratio.
+//!
+//! [`ab_route`](AbRouteExt::ab_route) and [`ab_route_by_key`](AbRouteExt::ab_route_by_key)
+//! send each item to either the A or the B output stream. The share of items going to B
+//! is held by a [`SplitRatio`] that can be changed while the pipeline runs, so new
+//! downstream logic can be rolled out to a small canary share of the traffic first and
+//! widened (or rolled back) without rebuilding the pipeline.
+//!
+//! # Runtime Requirements
+//!
+//! Like [`partition`](crate::PartitionExt::partition), this operator spawns a task and
+//! requires `runtime-tokio`, `runtime-smol`, `runtime-async-std` or a `wasm32` target.
+//!
+//! ## Routing
+//!
+//! - **`ab_route`**: Each item goes to B with probability equal to the ratio, drawn from a
+//!   generator seeded with `seed` so runs can be reproduced
+//! - **`ab_route_by_key`**: Each key is hashed to a fixed point in `[0, 1)` and goes to B
+//!   when that point is below the ratio. Items sharing a key always take the same route,
+//!   and raising the ratio only moves keys from A to B, never back
+//! - A ratio change applies from the next item routed
+//!
+//! ## Characteristics
+//!
+//! - **Chain-breaking**: Returns two streams, cannot chain further on the original
+//! - **Spawns task**: Routing runs in a background task, cancelled when both outputs are
+//!   dropped
+//! - **Timestamp-preserving**: Original timestamps are preserved in both output streams
+//! - **Error propagation**: Errors are sent to both output streams, which then end
+//! - **Unbounded buffers**: Like `partition`, items are buffered until consumed
+//!
+//! ## Example
+//!
+//! ```rust
+//! use fluxion_stream::{AbRouteExt, SplitRatio};
+//! use fluxion_test_utils::{helpers::test_channel, sequenced::Sequenced};
+//! use futures::StreamExt;
+//!
+//! # async fn example() {
+//! let (tx, requests) = test_channel::<Sequenced<u32>>();
+//!
+//! // Start with all traffic on A, the current logic
+//! let split = SplitRatio::new(0.0);
+//! let (mut stable, mut canary) = requests.ab_route_by_key(split.clone(), |user_id| *user_id);
+//!
+//! tx.unbounded_send(Sequenced::new(7)).unwrap();
+//! assert_eq!(stable.next().await.unwrap().unwrap().into_inner(), 7);
+//!
+//! // Send every user to the new logic
+//! split.set(1.0);
+//! tx.unbounded_send(Sequenced::new(7)).unwrap();
+//! assert_eq!(canary.next().await.unwrap().unwrap().into_inner(), 7);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::SplitRatio;
+
+// Multi-threaded runtime (tokio, smol, async-std)
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{AbRouteExt, RoutedStream};
+
+// Single-threaded runtime (wasm32, embassy)
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{AbRouteExt, RoutedStream};
@@ -0,0 +1,179 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_align_by_timestamp_impl {
+    ($($bounds:tt)*) => {
+        use crate::ordered_merge::ordered_merge_with_index;
+        use crate::types::Alignment;
+        use alloc::boxed::Box;
+        use alloc::collections::VecDeque;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        const LEFT: usize = 0;
+
+        /// Unpaired items of both streams, in arrival order, and the highest timestamp
+        /// seen.
+        struct AlignState<I, TS> {
+            left: VecDeque<(TS, I)>,
+            right: VecDeque<(TS, I)>,
+            watermark: Option<TS>,
+        }
+
+        impl<I, TS: Ord + Copy> AlignState<I, TS> {
+            /// Removes the unpaired items of both streams, oldest first.
+            fn drain_unpaired(&mut self, expired: impl Fn(TS) -> bool) -> Vec<(TS, Alignment<I>)> {
+                let mut unpaired = Vec::new();
+                while self.left.front().is_some_and(|(timestamp, _)| expired(*timestamp)) {
+                    if let Some((timestamp, item)) = self.left.pop_front() {
+                        unpaired.push((timestamp, Alignment::Left(item)));
+                    }
+                }
+                while self.right.front().is_some_and(|(timestamp, _)| expired(*timestamp)) {
+                    if let Some((timestamp, item)) = self.right.pop_front() {
+                        unpaired.push((timestamp, Alignment::Right(item)));
+                    }
+                }
+                // Stable, so a left item stays ahead of a right item with the same timestamp
+                unpaired.sort_by_key(|(timestamp, _)| *timestamp);
+                unpaired
+            }
+        }
+
+        pub trait AlignByTimestampExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Pairs every item with the item of `other` closest to it in time, if their
+            /// timestamps are at most `tolerance` apart, and emits the items left
+            /// without a partner as [`Alignment::Left`] or [`Alignment::Right`].
+            fn align_by_timestamp<Out, IS, D>(
+                self,
+                other: IS,
+                tolerance: D,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Out: Fluxion<Inner = Alignment<T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> AlignByTimestampExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn align_by_timestamp<Out, IS, D>(
+                self,
+                other: IS,
+                tolerance: D,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Out: Fluxion<Inner = Alignment<T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
+                    vec![Box::pin(self), Box::pin(other.into_stream())];
+
+                let state = Arc::new(Mutex::new(AlignState {
+                    left: VecDeque::new(),
+                    right: VecDeque::new(),
+                    watermark: None::<T::Timestamp>,
+                }));
+                let state_clone = Arc::clone(&state);
+
+                let distance = |a: T::Timestamp, b: T::Timestamp| if a >= b { a - b } else { b - a };
+                let emit = |alignment: Alignment<T::Inner>, watermark: T::Timestamp| {
+                    StreamItem::Value(Out::with_timestamp(alignment, watermark.into()))
+                };
+
+                let aligned = ordered_merge_with_index(streams).flat_map(move |(item, index)| {
+                    let mut guard = state_clone.lock();
+                    let state = &mut *guard;
+
+                    let outputs: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let inner = value.into_inner();
+                            let watermark = state.watermark.map_or(timestamp, |w| w.max(timestamp));
+                            state.watermark = Some(watermark);
+
+                            // Items too old to be paired with any later item
+                            let mut outputs: Vec<StreamItem<Out>> = state
+                                .drain_unpaired(|ts| ts < watermark && watermark - ts > tolerance)
+                                .into_iter()
+                                .map(|(_, alignment)| emit(alignment, watermark))
+                                .collect();
+
+                            let (own, others) = if index == LEFT {
+                                (&mut state.left, &mut state.right)
+                            } else {
+                                (&mut state.right, &mut state.left)
+                            };
+
+                            // The closest unpaired item of the other stream, the latest on ties
+                            let closest = others
+                                .iter()
+                                .enumerate()
+                                .map(|(position, (ts, _))| (distance(*ts, timestamp), position))
+                                .filter(|(gap, _)| *gap <= tolerance)
+                                .min_by(|(a, pa), (b, pb)| a.cmp(b).then(pb.cmp(pa)));
+
+                            match closest.and_then(|(_, position)| others.remove(position)) {
+                                Some((_, other)) if index == LEFT => {
+                                    outputs.push(emit(Alignment::Pair(inner, other), watermark));
+                                }
+                                Some((_, other)) => {
+                                    outputs.push(emit(Alignment::Pair(other, inner), watermark));
+                                }
+                                None => own.push_back((timestamp, inner)),
+                            }
+
+                            outputs
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(outputs)
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let mut state = state.lock();
+
+                    match state.watermark {
+                        Some(watermark) => state
+                            .drain_unpaired(|_| true)
+                            .into_iter()
+                            .map(|(_, alignment)| emit(alignment, watermark))
+                            .collect::<Vec<_>>(),
+                        None => Vec::new(),
+                    }
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(aligned.chain(flush_stream))
+            }
+        }
+    };
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Alignment of two streams by timestamp within a tolerance.
+//!
+//! [`align_by_timestamp`](AlignByTimestampExt::align_by_timestamp) merges the two streams
+//! in timestamp order and pairs each item with the closest unpaired item of the other
+//! stream whose timestamp is at most `tolerance` away, such as readings of two sensors
+//! sampled at slightly different instants. Items that find no partner are emitted on
+//! their own, so they can be routed elsewhere, for example with
+//! [`partition`](crate::PartitionExt::partition).
+//!
+//! # Behavior
+//!
+//! - Each item is paired at most once; a pair is emitted as
+//!   [`Alignment::Pair`](crate::Alignment::Pair) of `(item of self, item of other)`
+//! - An arriving item takes the closest candidate, the latest one on ties; if there is
+//!   none, it waits for a partner
+//! - A waiting item is emitted as [`Alignment::Left`](crate::Alignment::Left) or
+//!   [`Alignment::Right`](crate::Alignment::Right) once the watermark is more than
+//!   `tolerance` past it, or when both streams end
+//! - The watermark is the highest timestamp seen so far; all outputs carry it as their
+//!   timestamp, so they stay in order
+//! - Errors from either stream are propagated without discarding waiting items
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{AlignByTimestampExt, Alignment};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx_gps, gps) = test_channel::<Sequenced<u32>>();
+//! let (tx_imu, imu) = test_channel::<Sequenced<u32>>();
+//!
+//! let mut fused = gps.align_by_timestamp::<Sequenced<_>, _, _>(imu, 2u64);
+//!
+//! tx_gps.unbounded_send((1, 10).into()).unwrap();
+//! tx_imu.unbounded_send((2, 11).into()).unwrap();
+//!
+//! let pair = unwrap_value(Some(unwrap_stream(&mut fused, 500).await));
+//! assert_eq!(pair.value, Alignment::Pair(1, 2));
+//!
+//! tx_gps.unbounded_send((3, 20).into()).unwrap();
+//! tx_imu.unbounded_send((4, 30).into()).unwrap(); // too far from the reading at 20
+//!
+//! let unmatched = unwrap_value(Some(unwrap_stream(&mut fused, 500).await));
+//! assert_eq!(unmatched.value, Alignment::Left(3));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::AlignByTimestampExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::AlignByTimestampExt;
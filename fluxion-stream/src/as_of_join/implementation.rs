@@ -0,0 +1,102 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_as_of_join_impl {
+    ($($bounds:tt)*) => {
+        use crate::ordered_merge::ordered_merge_with_index;
+        use alloc::boxed::Box;
+        use alloc::collections::BTreeMap;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{future::ready, Stream, StreamExt};
+
+        const LEFT: usize = 0;
+
+        pub trait AsOfJoinExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Pairs every value with the latest earlier value of `other` that has the
+            /// same key, emitting `(self, other)` pairs.
+            ///
+            /// This is an inner join: a value of `self` with no earlier value of `other`
+            /// for its key is dropped, and is not paired with a value of `other` that
+            /// arrives later.
+            // "As of" names the join, it is not a conversion
+            #[allow(clippy::wrong_self_convention)]
+            fn as_of_join<Out, IS, K>(
+                self,
+                other: IS,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                K: Ord + $($bounds)* 'static,
+                Out: Fluxion<Inner = (T::Inner, T::Inner)>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> AsOfJoinExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn as_of_join<Out, IS, K>(
+                self,
+                other: IS,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                K: Ord + $($bounds)* 'static,
+                Out: Fluxion<Inner = (T::Inner, T::Inner)>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
+                    vec![Box::pin(self), Box::pin(other.into_stream())];
+
+                // Latest value of `other` per key
+                let latest = Arc::new(Mutex::new(BTreeMap::<K, T::Inner>::new()));
+
+                let joined = ordered_merge_with_index(streams).filter_map(move |(item, index)| {
+                    let mut latest = latest.lock();
+
+                    ready(match item {
+                        StreamItem::Value(value) if index == LEFT => {
+                            let timestamp = value.timestamp();
+                            let inner = value.into_inner();
+                            latest.get(&key(&inner)).map(|other| {
+                                StreamItem::Value(Out::with_timestamp(
+                                    (inner, other.clone()),
+                                    timestamp.into(),
+                                ))
+                            })
+                        }
+                        StreamItem::Value(value) => {
+                            let inner = value.into_inner();
+                            latest.insert(key(&inner), inner);
+                            None
+                        }
+                        StreamItem::Error(e) => Some(StreamItem::Error(e)),
+                    })
+                });
+
+                Box::pin(joined)
+            }
+        }
+    };
+}
@@ -0,0 +1,80 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! As-of join of two streams by key.
+//!
+//! [`as_of_join`](AsOfJoinExt::as_of_join) merges the two streams in timestamp order and
+//! remembers the latest value of `other` for every key. Each value of `self` is paired
+//! with the value of `other` that was current for its key at the time, such as a trade
+//! with the quote in force when it executed, or a sensor reading with its latest
+//! calibration.
+//!
+//! # Behavior
+//!
+//! - Each pair is emitted as `(item of self, item of other)` with the timestamp of the
+//!   item of `self`
+//! - Values of `other` never emit on their own; they replace the value kept for their key
+//! - Values of `self` with no earlier value of `other` for their key are dropped, as in
+//!   an inner join; a later value of `other` does not bring them back
+//! - For items with equal timestamps, the order of the merge decides whether a value of
+//!   `other` counts as earlier
+//! - Memory grows with the number of distinct keys seen on `other`
+//! - Errors from either stream are propagated without discarding the kept values
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::AsOfJoinExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! // (symbol, price)
+//! let (tx_trades, trades) = test_channel::<Sequenced<(&str, u32)>>();
+//! let (tx_quotes, quotes) = test_channel::<Sequenced<(&str, u32)>>();
+//!
+//! let mut priced = trades.as_of_join::<Sequenced<_>, _, _>(quotes, |(symbol, _)| *symbol);
+//!
+//! tx_quotes.unbounded_send((("ACME", 100), 1).into()).unwrap();
+//! tx_quotes.unbounded_send((("ACME", 101), 2).into()).unwrap();
+//! tx_trades.unbounded_send((("ACME", 102), 3).into()).unwrap();
+//!
+//! let trade = unwrap_value(Some(unwrap_stream(&mut priced, 500).await));
+//! assert_eq!(trade.value, (("ACME", 102), ("ACME", 101)));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::AsOfJoinExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::AsOfJoinExt;
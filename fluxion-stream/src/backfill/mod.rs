@@ -0,0 +1,154 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Constructors that replay historical data before switching to live updates.
+//!
+//! Applications that load state from a database and then subscribe to updates face a
+//! gap: updates published between the query and the subscription are lost. Subscribing
+//! first closes the gap but opens an overlap, as updates received while the query runs
+//! may already be part of its result. [`backfill`] emits the whole history stream, then
+//! the live stream, and drops the live items that the history already covered.
+//!
+//! # Behavior
+//!
+//! - The live stream is not polled until the history stream has ended; subscribe to it
+//!   before starting the history query so its updates are buffered meanwhile
+//! - [`backfill`] drops live items whose timestamp is not after the latest history
+//!   timestamp
+//! - [`backfill_by_key`] drops live items whose key appeared in the history with the same
+//!   or a later timestamp, so keys the history did not contain are never dropped
+//! - Deduplication only covers the boundary: it stops at the first live item newer than
+//!   all of the history, and the keys tracked for [`backfill_by_key`] are then released
+//! - Errors from either stream are emitted as they are and do not end the stream
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{backfill, from_timestamped_iter};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let history = from_timestamped_iter::<Sequenced<&str>, _>([("a", 1), ("b", 2)]);
+//! let live = from_timestamped_iter::<Sequenced<&str>, _>([("b", 2), ("c", 3)]);
+//!
+//! let values: Vec<_> = backfill(history, live)
+//!     .map(|item| item.unwrap().into_inner())
+//!     .collect()
+//!     .await;
+//!
+//! assert_eq!(values, vec!["a", "b", "c"]);
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{HasTimestamp, StreamItem};
+use futures::Stream;
+
+/// Creates a stream that emits `history`, then the items of `live` that are newer than
+/// the latest history item.
+pub fn backfill<T, H, L>(history: H, live: L) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    T: HasTimestamp,
+    H: Stream<Item = StreamItem<T>>,
+    L: Stream<Item = StreamItem<T>>,
+{
+    backfill_by_key(history, live, |_: &T| ())
+}
+
+/// Creates a stream that emits `history`, then the items of `live`, dropping live items
+/// whose key `history` already emitted with the same or a later timestamp.
+pub fn backfill_by_key<T, H, L, K, F>(
+    history: H,
+    live: L,
+    key: F,
+) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    T: HasTimestamp,
+    H: Stream<Item = StreamItem<T>>,
+    L: Stream<Item = StreamItem<T>>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Backfill {
+        history: Some(Box::pin(history)),
+        live: Box::pin(live),
+        key,
+        latest: BTreeMap::new(),
+        boundary: None,
+    }
+}
+
+struct Backfill<H, L, F, K, TS> {
+    history: Option<Pin<Box<H>>>,
+    live: Pin<Box<L>>,
+    key: F,
+    // Latest history timestamp per key, until the overlap is over
+    latest: BTreeMap<K, TS>,
+    // Latest history timestamp of any key
+    boundary: Option<TS>,
+}
+
+// The key function is only called through `&mut`, and the streams are pinned on the heap
+impl<H, L, F, K, TS> Unpin for Backfill<H, L, F, K, TS> {}
+
+impl<T, H, L, F, K> Stream for Backfill<H, L, F, K, T::Timestamp>
+where
+    T: HasTimestamp,
+    H: Stream<Item = StreamItem<T>>,
+    L: Stream<Item = StreamItem<T>>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(history) = this.history.as_mut() {
+            match history.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => {
+                    let timestamp = value.timestamp();
+                    let latest = this.latest.entry((this.key)(&value)).or_insert(timestamp);
+                    *latest = (*latest).max(timestamp);
+                    this.boundary = Some(this.boundary.map_or(timestamp, |b| b.max(timestamp)));
+                    return Poll::Ready(Some(StreamItem::Value(value)));
+                }
+                Poll::Ready(Some(error)) => return Poll::Ready(Some(error)),
+                Poll::Ready(None) => this.history = None,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        loop {
+            let value = match this.live.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => value,
+                other => return other,
+            };
+
+            let Some(boundary) = this.boundary else {
+                return Poll::Ready(Some(StreamItem::Value(value)));
+            };
+
+            let timestamp = value.timestamp();
+            if timestamp > boundary {
+                this.boundary = None;
+                this.latest = BTreeMap::new();
+                return Poll::Ready(Some(StreamItem::Value(value)));
+            }
+
+            let covered = this
+                .latest
+                .get(&(this.key)(&value))
+                .is_some_and(|latest| timestamp <= *latest);
+            if !covered {
+                return Poll::Ready(Some(StreamItem::Value(value)));
+            }
+        }
+    }
+}
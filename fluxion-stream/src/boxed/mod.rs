@@ -0,0 +1,78 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Type-erased fluxion streams.
+//!
+//! Chained operators produce deeply nested, often unnameable types.
+//! [`into_boxed`](BoxedExt::into_boxed) erases them into a [`BoxedFluxionStream`], which
+//! can be stored in struct fields, returned from trait methods and mixed in collections.
+//! [`into_boxed_local`](BoxedExt::into_boxed_local) does the same for streams that are
+//! not `Send + Sync`, producing a [`LocalBoxedFluxionStream`].
+//!
+//! Unlike `futures::StreamExt::boxed`, the boxed stream is also `Sync`, so it can be
+//! passed straight back into fluxion operators. The methods are named differently to
+//! avoid ambiguity with `StreamExt`, which is almost always in scope.
+//!
+//! Boxing costs one allocation and a dynamic call per poll.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_stream::boxed::{BoxedExt, BoxedFluxionStream};
+//! use fluxion_stream::{from_iter, FilterOrderedExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! struct Pipeline {
+//!     output: BoxedFluxionStream<Sequenced<i32>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let mut pipeline = Pipeline {
+//!     output: from_iter::<Sequenced<i32>, _>(vec![1, -2, 3])
+//!         .filter_ordered(|value| *value > 0)
+//!         .into_boxed(),
+//! };
+//!
+//! let values: Vec<i32> = (&mut pipeline.output)
+//!     .map(|item| item.unwrap().value)
+//!     .collect()
+//!     .await;
+//! assert_eq!(values, vec![1, 3]);
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use core::pin::Pin;
+use fluxion_core::StreamItem;
+use futures::Stream;
+
+/// A type-erased, `Send + Sync` fluxion stream.
+pub type BoxedFluxionStream<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + Send + Sync + 'static>>;
+
+/// A type-erased fluxion stream that is not required to be `Send` or `Sync`.
+pub type LocalBoxedFluxionStream<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + 'static>>;
+
+/// Extension trait providing [`into_boxed`](BoxedExt::into_boxed) and
+/// [`into_boxed_local`](BoxedExt::into_boxed_local).
+pub trait BoxedExt<T>: Stream<Item = StreamItem<T>> + Sized {
+    /// Erases the stream type into a [`BoxedFluxionStream`].
+    fn into_boxed(self) -> BoxedFluxionStream<T>
+    where
+        Self: Send + Sync + 'static,
+    {
+        Box::pin(self)
+    }
+
+    /// Erases the stream type into a [`LocalBoxedFluxionStream`].
+    fn into_boxed_local(self) -> LocalBoxedFluxionStream<T>
+    where
+        Self: 'static,
+    {
+        Box::pin(self)
+    }
+}
+
+impl<S, T> BoxedExt<T> for S where S: Stream<Item = StreamItem<T>> {}
@@ -0,0 +1,96 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_buffer_when_impl {
+    ($($bounds:tt)*) => {
+        use crate::ordered_merge::ordered_merge_with_index;
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        /// Whether the gate is open, and the values held while it is closed.
+        struct BufferState<T> {
+            open: bool,
+            held: Vec<T>,
+        }
+
+        pub trait BufferWhenExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Holds source values while `open` returns `false` for the latest value of
+            /// `control_stream`, and releases them in order once it returns `true`.
+            fn buffer_when<IS>(
+                self,
+                control_stream: IS,
+                open: impl Fn(&T::Inner) -> bool + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static;
+        }
+
+        impl<T, S> BufferWhenExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn buffer_when<IS>(
+                self,
+                control_stream: IS,
+                open: impl Fn(&T::Inner) -> bool + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+            {
+                let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
+                    vec![Box::pin(self), Box::pin(control_stream.into_stream())];
+
+                let state = Arc::new(Mutex::new(BufferState {
+                    open: false,
+                    held: Vec::new(),
+                }));
+
+                let gated = ordered_merge_with_index(streams).flat_map(move |(item, index)| {
+                    let mut state = state.lock();
+
+                    let released: Vec<StreamItem<T>> = match item {
+                        StreamItem::Value(value) if index == 0 => {
+                            if state.open {
+                                vec![StreamItem::Value(value)]
+                            } else {
+                                state.held.push(value);
+                                Vec::new()
+                            }
+                        }
+                        StreamItem::Value(control) => {
+                            state.open = open(&control.into_inner());
+                            if state.open {
+                                state.held.drain(..).map(StreamItem::Value).collect()
+                            } else {
+                                Vec::new()
+                            }
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(released)
+                });
+
+                Box::pin(gated)
+            }
+        }
+    };
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Gating operator that holds source values while a control stream says so.
+//!
+//! [`buffer_when`](BufferWhenExt::buffer_when) evaluates `open` on every value of the
+//! control stream. While the latest result is `false`, source values are held; when it
+//! turns `true`, the held values are released in order, with their original timestamps,
+//! and later values pass straight through until the control closes the gate again. This
+//! fits "hold events while offline, replay on reconnect" flows.
+//!
+//! # Behavior
+//!
+//! - The gate starts closed: values are held until the control stream first opens it
+//! - Held values are emitted in source order, before any value that arrives later
+//! - The buffer is unbounded
+//! - Errors from either stream pass through immediately and keep the held values
+//! - Values still held when the streams end are dropped, since the gate never opened
+//!   for them
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::BufferWhenExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx_events, events) = test_channel::<Sequenced<u32>>();
+//! let (tx_online, online) = test_channel::<Sequenced<u32>>();
+//!
+//! let mut delivered = events.buffer_when(online, |connected| *connected == 1);
+//!
+//! tx_events.unbounded_send((10, 1).into()).unwrap(); // held, not connected yet
+//! tx_events.unbounded_send((20, 2).into()).unwrap(); // held
+//! tx_online.unbounded_send((1, 3).into()).unwrap(); // connected: replay
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut delivered, 500).await)).value, 10);
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut delivered, 500).await)).value, 20);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::BufferWhenExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::BufferWhenExt;
@@ -0,0 +1,183 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_cache_latest_impl {
+    ($($bounds:tt)*) => {
+        use alloc::collections::{BTreeMap, VecDeque};
+        use core::fmt::Debug;
+        use core::ops::Sub;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        /// Latest value and timestamp per key, the keys in the order they were written,
+        /// and the highest timestamp seen.
+        struct CacheState<K, V, TS, D> {
+            entries: BTreeMap<K, (TS, V)>,
+            writes: VecDeque<(TS, K)>,
+            watermark: Option<TS>,
+            ttl: D,
+        }
+
+        impl<K, V, TS, D> CacheState<K, V, TS, D>
+        where
+            K: Ord + Clone,
+            TS: Ord + Copy + Sub<Output = D>,
+            D: Ord + Copy,
+        {
+            fn is_live(&self, timestamp: TS) -> bool {
+                self.watermark
+                    .is_none_or(|watermark| watermark - timestamp <= self.ttl)
+            }
+
+            fn write(&mut self, key: K, timestamp: TS, value: V) {
+                self.watermark = Some(self.watermark.map_or(timestamp, |w| w.max(timestamp)));
+
+                let newer = self
+                    .entries
+                    .get(&key)
+                    .is_none_or(|(written, _)| *written <= timestamp);
+                if newer && self.is_live(timestamp) {
+                    self.writes.push_back((timestamp, key.clone()));
+                    self.entries.insert(key, (timestamp, value));
+                }
+
+                // Evict expired entries that have not been overwritten since
+                while let Some((written, _)) = self.writes.front() {
+                    if self.is_live(*written) {
+                        break;
+                    }
+                    let Some((written, key)) = self.writes.pop_front() else {
+                        break;
+                    };
+                    if self.entries.get(&key).is_some_and(|(latest, _)| *latest == written) {
+                        self.entries.remove(&key);
+                    }
+                }
+            }
+        }
+
+        /// Query handle returned by [`cache_latest`](CacheLatestExt::cache_latest).
+        ///
+        /// Clones share the same cache, which is updated as the stream returned alongside
+        /// the handle is polled.
+        pub struct LatestCache<K, V, TS, D> {
+            state: Arc<Mutex<CacheState<K, V, TS, D>>>,
+        }
+
+        impl<K, V, TS, D> LatestCache<K, V, TS, D>
+        where
+            K: Ord + Clone,
+            V: Clone,
+            TS: Ord + Copy + Sub<Output = D>,
+            D: Ord + Copy,
+        {
+            /// Returns the latest value for `key`, unless it is more than the TTL older
+            /// than the newest item seen.
+            pub fn get(&self, key: &K) -> Option<V> {
+                let state = self.state.lock();
+                state
+                    .entries
+                    .get(key)
+                    .filter(|(timestamp, _)| state.is_live(*timestamp))
+                    .map(|(_, value)| value.clone())
+            }
+
+            /// Returns the number of keys with a value that has not expired.
+            pub fn len(&self) -> usize {
+                let state = self.state.lock();
+                state
+                    .entries
+                    .values()
+                    .filter(|(timestamp, _)| state.is_live(*timestamp))
+                    .count()
+            }
+
+            /// Returns `true` if no key has a value that has not expired.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+        }
+
+        impl<K, V, TS, D> Clone for LatestCache<K, V, TS, D> {
+            fn clone(&self) -> Self {
+                Self {
+                    state: Arc::clone(&self.state),
+                }
+            }
+        }
+
+        impl<K, V, TS, D> Debug for LatestCache<K, V, TS, D> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("LatestCache").finish_non_exhaustive()
+            }
+        }
+
+        pub trait CacheLatestExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Passes every item through while keeping the latest value per key in a
+            /// cache that the returned [`LatestCache`] handle can query synchronously.
+            ///
+            /// Values expire once the stream has seen an item more than `ttl` newer.
+            fn cache_latest<K, D>(
+                self,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+                ttl: D,
+            ) -> (
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+                LatestCache<K, T::Inner, T::Timestamp, D>,
+            )
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>;
+        }
+
+        impl<T, S> CacheLatestExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn cache_latest<K, D>(
+                self,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+                ttl: D,
+            ) -> (
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+                LatestCache<K, T::Inner, T::Timestamp, D>,
+            )
+            where
+                K: Ord + Clone + $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+            {
+                let cache = LatestCache {
+                    state: Arc::new(Mutex::new(CacheState {
+                        entries: BTreeMap::new(),
+                        writes: VecDeque::new(),
+                        watermark: None,
+                        ttl,
+                    })),
+                };
+                let state = Arc::clone(&cache.state);
+
+                let stream = self.map(move |item| {
+                    if let StreamItem::Value(value) = &item {
+                        let inner = value.clone().into_inner();
+                        state.lock().write(key(&inner), value.timestamp(), inner);
+                    }
+                    item
+                });
+
+                (stream, cache)
+            }
+        }
+    };
+}
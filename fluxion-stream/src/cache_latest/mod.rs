@@ -0,0 +1,76 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Keyed cache of the latest values of a stream, queryable outside the stream.
+//!
+//! [`cache_latest`](CacheLatestExt::cache_latest) passes every item through unchanged and
+//! returns a [`LatestCache`] handle next to the stream. The handle keeps the latest value
+//! per key and answers [`get`](LatestCache::get) synchronously, so code outside the
+//! pipeline, such as a request handler, can read streaming state without subscribing.
+//!
+//! # Behavior
+//!
+//! - The cache is updated as the returned stream is polled; nothing is cached until then
+//! - A value replaces the cached one for its key unless it has an earlier timestamp
+//! - The TTL is measured on item timestamps: a value expires once the stream has seen an
+//!   item more than `ttl` newer, and expired values are evicted as newer items arrive
+//! - Errors are passed through and leave the cache untouched
+//! - The handle keeps answering from the last state after the stream ends or is dropped
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::CacheLatestExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, prices) = test_channel::<Sequenced<(&str, u32)>>();
+//!
+//! // Latest price per symbol, valid for 60 ticks
+//! let (mut prices, cache) = prices.cache_latest(|(symbol, _)| *symbol, 60u64);
+//!
+//! tx.unbounded_send((("ACME", 100), 1).into()).unwrap();
+//! tx.unbounded_send((("ACME", 105), 2).into()).unwrap();
+//! unwrap_stream(&mut prices, 500).await;
+//! unwrap_stream(&mut prices, 500).await;
+//!
+//! assert_eq!(cache.get(&"ACME"), Some(("ACME", 105)));
+//! assert_eq!(cache.get(&"INIT"), None);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{CacheLatestExt, LatestCache};
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{CacheLatestExt, LatestCache};
@@ -0,0 +1,136 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_call_service_impl {
+    ($($bounds:tt)*) => {
+        use alloc::boxed::Box;
+        use core::error::Error;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::pin::Pin;
+        use core::task::Poll;
+        use fluxion_core::{Fluxion, FluxionError, StreamItem};
+        use futures::stream::FuturesOrdered;
+        use futures::{FutureExt, Stream, StreamExt};
+        use tower_service::Service;
+
+        pub trait CallServiceExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Sends each value to `service` as a request, one request at a time, and
+            /// emits each response with the timestamp of its request.
+            fn call_service<Out, Svc>(self, service: Svc) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                Svc: Service<T::Inner, Response = Out::Inner> + $($bounds)* 'static,
+                Svc::Error: Into<Box<dyn Error + Send + Sync>>,
+                Svc::Future: $($bounds)* 'static,
+                Out: Fluxion,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+
+            /// Sends each value to `service` as a request, keeping up to `limit` requests
+            /// in flight while emitting the responses in source order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is 0.
+            fn call_service_concurrent<Out, Svc>(
+                self,
+                limit: usize,
+                service: Svc,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                Svc: Service<T::Inner, Response = Out::Inner> + $($bounds)* 'static,
+                Svc::Error: Into<Box<dyn Error + Send + Sync>>,
+                Svc::Future: $($bounds)* 'static,
+                Out: Fluxion,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<S, T> CallServiceExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn call_service<Out, Svc>(self, service: Svc) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                Svc: Service<T::Inner, Response = Out::Inner> + $($bounds)* 'static,
+                Svc::Error: Into<Box<dyn Error + Send + Sync>>,
+                Svc::Future: $($bounds)* 'static,
+                Out: Fluxion,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                self.call_service_concurrent(1, service)
+            }
+
+            fn call_service_concurrent<Out, Svc>(
+                self,
+                limit: usize,
+                mut service: Svc,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                Svc: Service<T::Inner, Response = Out::Inner> + $($bounds)* 'static,
+                Svc::Error: Into<Box<dyn Error + Send + Sync>>,
+                Svc::Future: $($bounds)* 'static,
+                Out: Fluxion,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                assert!(limit > 0, "call_service_concurrent requires a limit of at least 1");
+
+                type Response<Out> = Pin<Box<dyn Future<Output = StreamItem<Out>> + $($bounds)* 'static>>;
+
+                let mut source = self;
+                let mut in_flight: FuturesOrdered<Response<Out>> = FuturesOrdered::new();
+                // The source ended, or the service failed and cannot take more requests
+                let mut done = false;
+
+                futures::stream::poll_fn(move |cx| {
+                    while !done && in_flight.len() < limit {
+                        // Only pull an item once the service can take it
+                        match service.poll_ready(cx) {
+                            Poll::Pending => break,
+                            Poll::Ready(Err(e)) => {
+                                let error = FluxionError::wrap_boxed(e);
+                                in_flight.push_back(Box::pin(futures::future::ready(StreamItem::Error(error))));
+                                done = true;
+                            }
+                            Poll::Ready(Ok(())) => match source.poll_next_unpin(cx) {
+                                Poll::Pending => break,
+                                Poll::Ready(Some(StreamItem::Value(value))) => {
+                                    let timestamp = value.timestamp();
+                                    let response = service.call(value.into_inner()).map(move |result| match result {
+                                        Ok(response) => StreamItem::Value(Out::with_timestamp(response, timestamp.into())),
+                                        Err(e) => StreamItem::Error(FluxionError::wrap_boxed(e)),
+                                    });
+                                    in_flight.push_back(Box::pin(response));
+                                }
+                                Poll::Ready(Some(StreamItem::Error(e))) => {
+                                    in_flight.push_back(Box::pin(futures::future::ready(StreamItem::Error(e))));
+                                }
+                                Poll::Ready(None) => done = true,
+                            },
+                        }
+                    }
+
+                    match in_flight.poll_next_unpin(cx) {
+                        Poll::Ready(None) if !done => Poll::Pending,
+                        poll => poll,
+                    }
+                })
+            }
+        }
+    };
+}
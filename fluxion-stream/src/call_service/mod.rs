@@ -0,0 +1,82 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Transformation operator that drives items through a tower `Service`.
+//!
+//! [`call_service`](CallServiceExt::call_service) sends each value as a request to a
+//! [`tower_service::Service`] and emits the responses, so pipelines can reuse tower
+//! middleware such as retry, rate limiting and load shedding.
+//! [`call_service_concurrent`](CallServiceExt::call_service_concurrent) keeps up to
+//! `limit` requests in flight.
+//!
+//! Requires the `tower` feature.
+//!
+//! # Behavior
+//!
+//! - The next item is only pulled from the source once `poll_ready` reports that the
+//!   service can take it, so a service applying backpressure slows the source down
+//! - Responses are emitted in source order with the timestamp of their request
+//! - A failed request is emitted as a [`FluxionError::Wrapped`](fluxion_core::FluxionError::Wrapped)
+//!   in place of its response, and the stream continues; the service error is its source
+//! - Service errors only need to convert into `Box<dyn Error + Send + Sync>`, so services
+//!   built with tower middleware, whose error is `tower::BoxError`, can be used directly
+//! - A failed `poll_ready` means the service cannot take requests anymore: the error is
+//!   emitted after the responses in flight, then the stream ends
+//! - Source errors are passed through and keep their position relative to the responses
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::CallServiceExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//! use std::convert::Infallible;
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<u32>>();
+//!
+//! let service = tower::service_fn(|request: u32| async move {
+//!     Ok::<_, Infallible>(request * 2)
+//! });
+//! let mut responses = stream.call_service::<Sequenced<u32>, _>(service);
+//!
+//! tx.unbounded_send((21, 1).into()).unwrap();
+//!
+//! let response = unwrap_value(Some(unwrap_stream(&mut responses, 500).await));
+//! assert_eq!(response.value, 42);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::CallServiceExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::CallServiceExt;
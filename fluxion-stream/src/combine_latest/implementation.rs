@@ -4,16 +4,17 @@
 
 macro_rules! define_combine_latest_impl {
     ($($bounds:tt)*) => {
-        use $crate::ordered_merge::ordered_merge_with_index;
+        use $crate::ordered_merge::ordered_merge_with_index_and_mode;
+        use $crate::ordering::OrderingMode;
         use $crate::types::CombinedState;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec;
         use alloc::vec::Vec;
         use core::fmt::Debug;
         use core::pin::Pin;
-        use fluxion_core::fluxion_mutex::Mutex;
         use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::lock_state;
+        use fluxion_core::sync::{Arc, StateMutex};
         use fluxion_core::{Fluxion, StreamItem, Timestamped};
         use futures::future::ready;
         use futures::{Stream, StreamExt};
@@ -37,6 +38,21 @@ macro_rules! define_combine_latest_impl {
                 CombinedState<T::Inner, T::Timestamp>:
                     Timestamped<Inner = CombinedState<T::Inner, T::Timestamp>, Timestamp = T::Timestamp>
                     + $($bounds)* 'static;
+
+            /// Combines the streams like [`combine_latest`](Self::combine_latest), merging
+            /// them in the given [`OrderingMode`].
+            fn combine_latest_with_mode<IS>(
+                self,
+                others: Vec<IS>,
+                filter: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> bool + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<CombinedState<T::Inner, T::Timestamp>>> + Unpin + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+                CombinedState<T::Inner, T::Timestamp>:
+                    Timestamped<Inner = CombinedState<T::Inner, T::Timestamp>, Timestamp = T::Timestamp>
+                    + $($bounds)* 'static;
         }
 
         impl<T, S> CombineLatestExt<T> for S
@@ -53,6 +69,21 @@ macro_rules! define_combine_latest_impl {
                 others: Vec<IS>,
                 filter: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> bool + $($bounds)* 'static,
             ) -> impl Stream<Item = StreamItem<CombinedState<T::Inner, T::Timestamp>>> + Unpin + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+                CombinedState<T::Inner, T::Timestamp>:
+                    Timestamped<Inner = CombinedState<T::Inner, T::Timestamp>, Timestamp = T::Timestamp>,
+            {
+                self.combine_latest_with_mode(others, filter, OrderingMode::Ordered)
+            }
+
+            fn combine_latest_with_mode<IS>(
+                self,
+                others: Vec<IS>,
+                filter: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> bool + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<CombinedState<T::Inner, T::Timestamp>>> + Unpin + $($bounds)*
             where
                 IS: IntoStream<Item = StreamItem<T>>,
                 IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
@@ -69,9 +100,9 @@ macro_rules! define_combine_latest_impl {
                 }
 
                 let num_streams = streams.len();
-                let state = Arc::new(Mutex::new(IntermediateState::new(num_streams)));
+                let state = Arc::new(StateMutex::new(IntermediateState::new(num_streams)));
 
-                let combined_stream = ordered_merge_with_index(streams)
+                let combined_stream = ordered_merge_with_index_and_mode(streams, mode)
                     .filter_map({
                         let state = Arc::clone(&state);
 
@@ -80,7 +111,7 @@ macro_rules! define_combine_latest_impl {
                             async move {
                                 match item {
                                     StreamItem::Value(value) => {
-                                        let mut guard = state.lock();
+                                        let mut guard = lock_state!(state);
                                         guard.insert(index, value);
 
                                         if guard.is_complete() {
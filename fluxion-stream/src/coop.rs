@@ -0,0 +1,83 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Per-poll work budget that makes busy operators yield to other tasks.
+
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+use core::future::poll_fn;
+use core::task::{Context, Poll};
+
+/// Number of items an operator produces in a row before it yields to the executor.
+/// Matches the budget Tokio gives each task.
+///
+/// When their sources are always ready, operators that drain them in a loop would
+/// otherwise keep the executor thread until the sources run dry, starving every other
+/// task on it. After this many items they wake their task and return `Pending` once, so
+/// the executor can run other tasks before resuming them. The budget is refilled after
+/// every yield, and by the merges also whenever their sources are pending.
+///
+/// [`ordered_merge_with_budget`](crate::OrderedStreamExt::ordered_merge_with_budget),
+/// [`share_with_budget`](crate::ShareExt::share_with_budget) and
+/// [`partition_with_budget`](crate::PartitionExt::partition_with_budget) take another
+/// limit for one operator; `0` disables yielding.
+pub const DEFAULT_COOP_BUDGET: u32 = 128;
+
+/// The budget of one operator instance.
+#[derive(Debug)]
+pub(crate) struct CoopBudget {
+    limit: u32,
+    remaining: u32,
+}
+
+impl CoopBudget {
+    pub(crate) fn new() -> Self {
+        Self::with_limit(DEFAULT_COOP_BUDGET)
+    }
+
+    /// Creates a budget of `limit` items, where `0` disables yielding.
+    pub(crate) fn with_limit(limit: u32) -> Self {
+        Self {
+            limit,
+            remaining: limit,
+        }
+    }
+
+    /// Returns `Pending` after waking the task if the budget is spent, refilling it for
+    /// the next poll.
+    pub(crate) fn poll_proceed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if self.remaining > 0 || self.limit == 0 {
+            return Poll::Ready(());
+        }
+
+        self.reset();
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+
+    /// Yields to the executor once if the budget is spent.
+    #[cfg(any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ))]
+    pub(crate) async fn proceed(&mut self) {
+        poll_fn(|cx| self.poll_proceed(cx)).await;
+    }
+
+    /// Charges one produced item.
+    pub(crate) fn consume(&mut self) {
+        self.remaining = self.remaining.saturating_sub(1);
+    }
+
+    /// Refills the budget, for when the operator yields on its own.
+    pub(crate) fn reset(&mut self) {
+        self.remaining = self.limit;
+    }
+}
@@ -5,9 +5,9 @@
 macro_rules! define_distinct_until_changed_by_impl {
     ($($bounds:tt)*) => {
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use core::fmt::Debug;
-        use fluxion_core::fluxion_mutex::Mutex;
+        use fluxion_core::lock_state;
+        use fluxion_core::sync::{Arc, StateMutex};
         use fluxion_core::{Fluxion, StreamItem};
         use futures::stream::StreamExt;
         use futures::Stream;
@@ -40,7 +40,7 @@ macro_rules! define_distinct_until_changed_by_impl {
             where
                 F: Fn(&T::Inner, &T::Inner) -> bool + $($bounds)* 'static,
             {
-                let last_value: Arc<Mutex<Option<T::Inner>>> = Arc::new(Mutex::new(None));
+                let last_value: Arc<StateMutex<Option<T::Inner>>> = Arc::new(StateMutex::new(None));
                 let compare = Arc::new(compare);
 
                 let stream = self.filter_map(move |item| {
@@ -52,7 +52,7 @@ macro_rules! define_distinct_until_changed_by_impl {
                             StreamItem::Value(value) => {
                                 let current_inner = value.clone().into_inner();
 
-                                let mut last = last_value.lock();
+                                let mut last = lock_state!(last_value);
 
                                 let should_emit = match last.as_ref() {
                                     None => true,
@@ -0,0 +1,253 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+/// Summarizes the values of one interval of
+/// [`downsample`](crate::DownsampleExt::downsample).
+///
+/// The built-in aggregators are [`First`], [`Last`], [`Min`], [`Max`] and [`Ohlc`].
+/// Implement it for other summaries such as sums or counts.
+pub trait Aggregator<V> {
+    /// The summary of an interval.
+    type Output;
+
+    /// Starts the summary of an interval with its first value.
+    fn start(&self, value: V) -> Self::Output;
+
+    /// Adds a later value of the interval to its summary.
+    fn update(&self, summary: &mut Self::Output, value: V);
+}
+
+/// Keeps the first value of each interval.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct First;
+
+impl<V> Aggregator<V> for First {
+    type Output = V;
+
+    fn start(&self, value: V) -> V {
+        value
+    }
+
+    fn update(&self, _summary: &mut V, _value: V) {}
+}
+
+/// Keeps the last value of each interval.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Last;
+
+impl<V> Aggregator<V> for Last {
+    type Output = V;
+
+    fn start(&self, value: V) -> V {
+        value
+    }
+
+    fn update(&self, summary: &mut V, value: V) {
+        *summary = value;
+    }
+}
+
+/// Keeps the smallest value of each interval, the first one on ties.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Min;
+
+impl<V: Ord> Aggregator<V> for Min {
+    type Output = V;
+
+    fn start(&self, value: V) -> V {
+        value
+    }
+
+    fn update(&self, summary: &mut V, value: V) {
+        if value < *summary {
+            *summary = value;
+        }
+    }
+}
+
+/// Keeps the largest value of each interval, the first one on ties.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Max;
+
+impl<V: Ord> Aggregator<V> for Max {
+    type Output = V;
+
+    fn start(&self, value: V) -> V {
+        value
+    }
+
+    fn update(&self, summary: &mut V, value: V) {
+        if value > *summary {
+            *summary = value;
+        }
+    }
+}
+
+/// Summarizes each interval as an [`OhlcBar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ohlc;
+
+/// First, highest, lowest and last value of an interval, as emitted by [`Ohlc`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OhlcBar<V> {
+    pub open: V,
+    pub high: V,
+    pub low: V,
+    pub close: V,
+}
+
+impl<V: Ord + Clone> Aggregator<V> for Ohlc {
+    type Output = OhlcBar<V>;
+
+    fn start(&self, value: V) -> OhlcBar<V> {
+        OhlcBar {
+            open: value.clone(),
+            high: value.clone(),
+            low: value.clone(),
+            close: value,
+        }
+    }
+
+    fn update(&self, bar: &mut OhlcBar<V>, value: V) {
+        if value > bar.high {
+            bar.high = value.clone();
+        }
+        if value < bar.low {
+            bar.low = value.clone();
+        }
+        bar.close = value;
+    }
+}
+
+macro_rules! define_downsample_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::Aggregator;
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::Add;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        /// End of the open interval and its summary so far.
+        struct DownsampleState<S, TS> {
+            end: Option<TS>,
+            summary: Option<S>,
+        }
+
+        pub trait DownsampleExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Summarizes the values of every `interval` of timestamps with `aggregator`,
+            /// emitting one item per interval with the end of the interval as its
+            /// timestamp.
+            ///
+            /// # Panics
+            ///
+            /// Panics on the first item if `interval` is zero.
+            fn downsample<Out, A, D>(
+                self,
+                interval: D,
+                aggregator: A,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                A: Aggregator<T::Inner> + $($bounds)* 'static,
+                A::Output: $($bounds)* 'static,
+                D: Copy + $($bounds)* 'static,
+                T::Timestamp: Add<D, Output = T::Timestamp>,
+                Out: Fluxion<Inner = A::Output>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> DownsampleExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn downsample<Out, A, D>(
+                self,
+                interval: D,
+                aggregator: A,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                A: Aggregator<T::Inner> + $($bounds)* 'static,
+                A::Output: $($bounds)* 'static,
+                D: Copy + $($bounds)* 'static,
+                T::Timestamp: Add<D, Output = T::Timestamp>,
+                Out: Fluxion<Inner = A::Output>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let state = Arc::new(Mutex::new(DownsampleState {
+                    end: None::<T::Timestamp>,
+                    summary: None::<A::Output>,
+                }));
+                let state_clone = Arc::clone(&state);
+
+                let summarized = move |summary: A::Output, end: T::Timestamp| {
+                    StreamItem::Value(Out::with_timestamp(summary, end.into()))
+                };
+
+                let main_stream = self.flat_map(move |item| {
+                    let mut guard = state_clone.lock();
+                    let state = &mut *guard;
+
+                    let summaries: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let mut end = *state.end.get_or_insert_with(|| {
+                                assert!(timestamp + interval > timestamp, "downsample requires a non-zero interval");
+                                timestamp + interval
+                            });
+                            let mut summaries = Vec::new();
+
+                            // Close the open interval, skipping the empty ones after it
+                            if timestamp >= end {
+                                if let Some(summary) = state.summary.take() {
+                                    summaries.push(summarized(summary, end));
+                                }
+                                while timestamp >= end {
+                                    end = end + interval;
+                                }
+                                state.end = Some(end);
+                            }
+
+                            // Items before the open interval are too late for their own
+                            if timestamp + interval >= end {
+                                match &mut state.summary {
+                                    Some(summary) => aggregator.update(summary, value.into_inner()),
+                                    None => state.summary = Some(aggregator.start(value.into_inner())),
+                                }
+                            }
+
+                            summaries
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(summaries)
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let mut state = state.lock();
+                    match (state.summary.take(), state.end) {
+                        (Some(summary), Some(end)) => vec![summarized(summary, end)],
+                        _ => Vec::new(),
+                    }
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(main_stream.chain(flush_stream))
+            }
+        }
+    };
+}
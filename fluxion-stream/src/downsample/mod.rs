@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Downsampling of streams into one summary per interval.
+//!
+//! [`downsample`](DownsampleExt::downsample) splits the timestamps into consecutive
+//! intervals of length `interval`, starting at the first item, and emits one item per
+//! interval that summarizes its values, such as the OHLC bars of financial ticks or the
+//! peaks of high-rate telemetry. The summary is computed by an [`Aggregator`]; the
+//! built-in ones are [`First`], [`Last`], [`Min`], [`Max`] and [`Ohlc`].
+//!
+//! # Behavior
+//!
+//! - Intervals start at the first timestamp plus whole multiples of `interval`, and each
+//!   output carries the end of its interval as its timestamp
+//! - An interval is emitted once an item past its end arrives, so outputs lag the input
+//!   by up to one `interval`
+//! - Intervals without items emit nothing
+//! - Items older than the open interval are dropped
+//! - When the source ends, the open interval is emitted
+//! - Errors are propagated without affecting the intervals
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::downsample::Ohlc;
+//! use fluxion_stream::{DownsampleExt, OhlcBar};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, ticks) = test_channel::<Sequenced<u32>>();
+//!
+//! // One bar for every 60 ticks of time
+//! let mut bars = ticks.downsample::<Sequenced<OhlcBar<u32>>, _, _>(60u64, Ohlc);
+//!
+//! tx.unbounded_send((100, 0).into()).unwrap();
+//! tx.unbounded_send((120, 20).into()).unwrap();
+//! tx.unbounded_send((90, 40).into()).unwrap();
+//! tx.unbounded_send((110, 70).into()).unwrap();
+//!
+//! let bar = unwrap_value(Some(unwrap_stream(&mut bars, 500).await));
+//! assert_eq!(
+//!     bar.value,
+//!     OhlcBar { open: 100, high: 120, low: 90, close: 90 }
+//! );
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::{Aggregator, First, Last, Max, Min, Ohlc, OhlcBar};
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::DownsampleExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::DownsampleExt;
@@ -0,0 +1,173 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::StreamItem;
+use futures::Stream;
+use pin_project::pin_project;
+
+/// Applies `f` to each item of `stream` with the latest configuration read from `config`.
+///
+/// Every configuration update that is ready is applied before the next item is read, so
+/// an item always sees a complete configuration and never one that changes while `f`
+/// runs on it.
+#[pin_project]
+pub(super) struct Configured<S, CS, C, F> {
+    #[pin]
+    stream: S,
+    #[pin]
+    config: Option<CS>,
+    current: C,
+    f: F,
+    done: bool,
+}
+
+impl<S, CS, C, F> Configured<S, CS, C, F> {
+    pub(super) fn new(stream: S, config: CS, initial: C, f: F) -> Self {
+        Self {
+            stream,
+            config: Some(config),
+            current: initial,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<S, CS, C, F, T, U> Stream for Configured<S, CS, C, F>
+where
+    S: Stream<Item = StreamItem<T>>,
+    CS: Stream<Item = StreamItem<C>>,
+    F: FnMut(&C, T) -> Option<U>,
+{
+    type Item = StreamItem<U>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if *this.done {
+                return Poll::Ready(None);
+            }
+
+            // Apply every update that is ready; the configuration outlives its stream
+            while let Some(config) = this.config.as_mut().as_pin_mut() {
+                match config.poll_next(cx) {
+                    Poll::Ready(Some(StreamItem::Value(update))) => *this.current = update,
+                    Poll::Ready(Some(StreamItem::Error(e))) => {
+                        return Poll::Ready(Some(StreamItem::Error(e)));
+                    }
+                    Poll::Ready(None) => this.config.set(None),
+                    Poll::Pending => break,
+                }
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => {
+                    if let Some(output) = (this.f)(this.current, value) {
+                        return Poll::Ready(Some(StreamItem::Value(output)));
+                    }
+                }
+                Poll::Ready(Some(StreamItem::Error(e))) => {
+                    return Poll::Ready(Some(StreamItem::Error(e)));
+                }
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+macro_rules! define_dynamic_config_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::Configured;
+        use core::fmt::Debug;
+        use fluxion_core::{into_stream::IntoStream, Fluxion, StreamItem};
+        use futures::Stream;
+
+        pub trait DynamicConfigExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Filters items with `predicate`, which also receives the latest value of the
+            /// `config` stream, starting from `initial`.
+            fn dynamic_filter<C, CS, F>(
+                self,
+                config: CS,
+                initial: C,
+                predicate: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                CS: IntoStream<Item = StreamItem<C>>,
+                CS::Stream: $($bounds)* 'static,
+                C: $($bounds)* 'static,
+                F: FnMut(&C, &T::Inner) -> bool + $($bounds)* 'static;
+
+            /// Transforms items with `f`, which also receives the latest value of the
+            /// `config` stream, starting from `initial`.
+            fn dynamic_map<C, CS, U, F>(
+                self,
+                config: CS,
+                initial: C,
+                f: F,
+            ) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                CS: IntoStream<Item = StreamItem<C>>,
+                CS::Stream: $($bounds)* 'static,
+                C: $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(&C, T) -> U + $($bounds)* 'static;
+        }
+
+        impl<T, S> DynamicConfigExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn dynamic_filter<C, CS, F>(
+                self,
+                config: CS,
+                initial: C,
+                mut predicate: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                CS: IntoStream<Item = StreamItem<C>>,
+                CS::Stream: $($bounds)* 'static,
+                C: $($bounds)* 'static,
+                F: FnMut(&C, &T::Inner) -> bool + $($bounds)* 'static,
+            {
+                Configured::new(self, config.into_stream(), initial, move |config: &C, value: T| {
+                    predicate(config, &value.clone().into_inner()).then_some(value)
+                })
+            }
+
+            fn dynamic_map<C, CS, U, F>(
+                self,
+                config: CS,
+                initial: C,
+                mut f: F,
+            ) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                CS: IntoStream<Item = StreamItem<C>>,
+                CS::Stream: $($bounds)* 'static,
+                C: $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(&C, T) -> U + $($bounds)* 'static,
+            {
+                Configured::new(self, config.into_stream(), initial, move |config: &C, value: T| {
+                    Some(f(config, value))
+                })
+            }
+        }
+    };
+}
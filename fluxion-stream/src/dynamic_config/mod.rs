@@ -0,0 +1,83 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Operators whose parameters are updated at runtime by a configuration stream.
+//!
+//! [`dynamic_filter`](DynamicConfigExt::dynamic_filter) and
+//! [`dynamic_map`](DynamicConfigExt::dynamic_map) work like
+//! [`filter_ordered`](crate::FilterOrderedExt::filter_ordered) and
+//! [`map_ordered`](crate::MapOrderedExt::map_ordered), but their closure also receives
+//! the latest value of a second stream, such as thresholds pushed from an admin API. A
+//! running pipeline can be tuned without restarting it.
+//!
+//! # Behavior
+//!
+//! - The closure starts with the `initial` configuration
+//! - Every update that is ready is applied before the next item is read, so each item
+//!   sees one complete configuration, never one that changes while it is processed
+//! - Updates are applied as they arrive; they are not ordered against items by timestamp
+//! - Configuration values need no timestamp; a channel of plain values converted with
+//!   [`into_fluxion_stream`](crate::IntoFluxionStream::into_fluxion_stream) will do
+//! - Errors of either stream are passed on
+//! - When the configuration stream ends, the last configuration stays in force; the
+//!   operator ends with the source
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::DynamicConfigExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # async fn example() {
+//! let (reading_tx, readings) = test_channel::<Sequenced<u32>>();
+//! let (threshold_tx, thresholds) = test_channel::<u32>();
+//!
+//! let mut alerts = readings.dynamic_filter(thresholds, 80, |threshold, reading| {
+//!     reading > threshold
+//! });
+//!
+//! reading_tx.unbounded_send(Sequenced::new(85)).unwrap();
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut alerts, 500).await)).value, 85);
+//!
+//! // Raise the threshold at runtime
+//! threshold_tx.unbounded_send(90).unwrap();
+//! reading_tx.unbounded_send(Sequenced::new(85)).unwrap();
+//! reading_tx.unbounded_send(Sequenced::new(95)).unwrap();
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut alerts, 500).await)).value, 95);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::DynamicConfigExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::DynamicConfigExt;
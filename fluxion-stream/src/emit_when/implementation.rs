@@ -4,21 +4,22 @@
 
 macro_rules! define_emit_when_impl {
     ($($bounds:tt)*) => {
-        use $crate::ordered_merge::ordered_merge_with_index;
+        use $crate::ordered_merge::ordered_merge_with_index_and_mode;
+        use $crate::ordering::OrderingMode;
         use $crate::types::CombinedState;
         use $crate::warn;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec;
         use alloc::vec::Vec;
         use core::fmt::Debug;
         use core::pin::Pin;
-        use fluxion_core::fluxion_mutex::Mutex;
         use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::lock_state;
+        use fluxion_core::sync::{Arc, StateMutex};
         use fluxion_core::{Fluxion, StreamItem};
         use futures::{Stream, StreamExt};
 
-        type SharedState<V, TS> = Arc<Mutex<Option<(V, TS)>>>;
+        type SharedState<V, TS> = Arc<StateMutex<Option<(V, TS)>>>;
 
         pub trait EmitWhenExt<T>: Stream<Item = StreamItem<T>> + Sized
         where
@@ -34,6 +35,18 @@ macro_rules! define_emit_when_impl {
             where
                 IS: IntoStream<Item = fluxion_core::StreamItem<T>>,
                 IS::Stream: $($bounds)* 'static;
+
+            /// Gates the source like [`emit_when`](Self::emit_when), merging the streams
+            /// in the given [`OrderingMode`].
+            fn emit_when_with_mode<IS>(
+                self,
+                filter_stream: IS,
+                filter: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> bool + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<T>>
+            where
+                IS: IntoStream<Item = fluxion_core::StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static;
         }
 
         impl<T, S> EmitWhenExt<T> for S
@@ -48,6 +61,19 @@ macro_rules! define_emit_when_impl {
                 filter_stream: IS,
                 filter: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> bool + $($bounds)* 'static,
             ) -> impl Stream<Item = StreamItem<T>>
+            where
+                IS: IntoStream<Item = fluxion_core::StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+            {
+                self.emit_when_with_mode(filter_stream, filter, OrderingMode::Ordered)
+            }
+
+            fn emit_when_with_mode<IS>(
+                self,
+                filter_stream: IS,
+                filter: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> bool + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<T>>
             where
                 IS: IntoStream<Item = fluxion_core::StreamItem<T>>,
                 IS::Stream: $($bounds)* 'static,
@@ -55,11 +81,11 @@ macro_rules! define_emit_when_impl {
                 let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
                     vec![Box::pin(self), Box::pin(filter_stream.into_stream())];
 
-                let source_value: SharedState<T::Inner, T::Timestamp> = Arc::new(Mutex::new(None));
-                let filter_value: SharedState<T::Inner, T::Timestamp> = Arc::new(Mutex::new(None));
+                let source_value: SharedState<T::Inner, T::Timestamp> = Arc::new(StateMutex::new(None));
+                let filter_value: SharedState<T::Inner, T::Timestamp> = Arc::new(StateMutex::new(None));
                 let filter = Arc::new(filter);
 
-                let combined_stream = ordered_merge_with_index(streams).filter_map(move |(item, index)| {
+                let combined_stream = ordered_merge_with_index_and_mode(streams, mode).filter_map(move |(item, index)| {
                     let source_value = Arc::clone(&source_value);
                     let filter_value = Arc::clone(&filter_value);
                     let filter = Arc::clone(&filter);
@@ -67,8 +93,8 @@ macro_rules! define_emit_when_impl {
                         match item {
                             StreamItem::Value(ordered_value) => match index {
                                 0 => {
-                                    let mut source = source_value.lock();
-                                    let filter_val = filter_value.lock();
+                                    let mut source = lock_state!(source_value);
+                                    let filter_val = lock_state!(filter_value);
 
                                     let timestamp = ordered_value.timestamp();
                                     *source = Some((ordered_value.clone().into_inner(), timestamp));
@@ -95,8 +121,8 @@ macro_rules! define_emit_when_impl {
                                     }
                                 }
                                 1 => {
-                                    let mut filter_val = filter_value.lock();
-                                    let source = source_value.lock();
+                                    let mut filter_val = lock_state!(filter_value);
+                                    let source = lock_state!(source_value);
 
                                     let timestamp = ordered_value.timestamp();
                                     *filter_val = Some((ordered_value.clone().into_inner(), timestamp));
@@ -123,7 +149,12 @@ macro_rules! define_emit_when_impl {
                                     }
                                 }
                                 _ => {
-                                    warn!("emit_when: unexpected stream index {} — ignoring", index);
+                                    warn!(
+                                        operator = "emit_when",
+                                        stream = index,
+                                        timestamp = ordered_value.timestamp();
+                                        "unexpected stream index, ignoring"
+                                    );
                                     None
                                 }
                             },
@@ -0,0 +1,153 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_event_timers_impl {
+    ($($bounds:tt)*) => {
+        use super::timers::EventTimers;
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        /// State shared by the item and flush stages of `process_with_timers`.
+        struct TimerState<St, TS, P, F> {
+            state: St,
+            timers: EventTimers<TS, P>,
+            on_timer: F,
+        }
+
+        impl<St, TS, P, F, U> TimerState<St, TS, P, F>
+        where
+            TS: Ord + Copy,
+            F: FnMut(&mut St, TS, P, &mut EventTimers<TS, P>) -> Vec<U>,
+        {
+            /// Advances the watermark and fires the timers it passed, including those
+            /// registered by the timers firing, collecting their outputs.
+            fn fire_due(&mut self, watermark: TS, outputs: &mut Vec<(U, TS)>) {
+                loop {
+                    let fired = self.timers.advance_watermark(watermark);
+                    if fired.is_empty() {
+                        break;
+                    }
+                    for (at, payload) in fired {
+                        let values = (self.on_timer)(&mut self.state, at, payload, &mut self.timers);
+                        outputs.extend(values.into_iter().map(|value| (value, watermark)));
+                    }
+                }
+            }
+
+            /// Fires every remaining timer once the input has ended.
+            fn fire_all(&mut self, outputs: &mut Vec<(U, TS)>) {
+                let mut latest = self.timers.watermark();
+                while !self.timers.is_empty() {
+                    for (at, payload) in self.timers.fire_all() {
+                        let timestamp = latest.map_or(at, |latest| latest.max(at));
+                        latest = Some(timestamp);
+                        let values = (self.on_timer)(&mut self.state, at, payload, &mut self.timers);
+                        outputs.extend(values.into_iter().map(|value| (value, timestamp)));
+                    }
+                }
+            }
+        }
+
+        pub trait ProcessWithTimersExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Runs custom stateful logic that can set event-time timers.
+            ///
+            /// `on_item` is called with every item and may register timers; the watermark
+            /// then advances to the item's timestamp and `on_timer` is called for each
+            /// timer it passed. Both return the values to emit, which carry the watermark
+            /// as their timestamp.
+            fn process_with_timers<Out, St, P>(
+                self,
+                state: St,
+                on_item: impl FnMut(&mut St, T, &mut EventTimers<T::Timestamp, P>) -> Vec<Out::Inner>
+                    + $($bounds)* 'static,
+                on_timer: impl FnMut(&mut St, T::Timestamp, P, &mut EventTimers<T::Timestamp, P>) -> Vec<Out::Inner>
+                    + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                St: $($bounds)* 'static,
+                P: $($bounds)* 'static,
+                Out: Fluxion,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> ProcessWithTimersExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn process_with_timers<Out, St, P>(
+                self,
+                state: St,
+                mut on_item: impl FnMut(&mut St, T, &mut EventTimers<T::Timestamp, P>) -> Vec<Out::Inner>
+                    + $($bounds)* 'static,
+                on_timer: impl FnMut(&mut St, T::Timestamp, P, &mut EventTimers<T::Timestamp, P>) -> Vec<Out::Inner>
+                    + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                St: $($bounds)* 'static,
+                P: $($bounds)* 'static,
+                Out: Fluxion,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let shared = Arc::new(Mutex::new(TimerState {
+                    state,
+                    timers: EventTimers::new(),
+                    on_timer,
+                }));
+                let shared_clone = Arc::clone(&shared);
+
+                let emit = |(value, timestamp): (Out::Inner, T::Timestamp)| {
+                    StreamItem::Value(Out::with_timestamp(value, timestamp.into()))
+                };
+
+                let main_stream = self.flat_map(move |item| {
+                    let mut guard = shared_clone.lock();
+                    let shared = &mut *guard;
+
+                    let updates: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let watermark = shared
+                                .timers
+                                .watermark()
+                                .map_or(timestamp, |w: T::Timestamp| w.max(timestamp));
+                            let values = on_item(&mut shared.state, value, &mut shared.timers);
+
+                            let mut outputs: Vec<_> =
+                                values.into_iter().map(|value| (value, watermark)).collect();
+                            shared.fire_due(watermark, &mut outputs);
+                            outputs.into_iter().map(emit).collect()
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(updates)
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let mut outputs = Vec::new();
+                    shared.lock().fire_all(&mut outputs);
+                    outputs.into_iter().map(emit).collect::<Vec<_>>()
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(main_stream.chain(flush_stream))
+            }
+        }
+    };
+}
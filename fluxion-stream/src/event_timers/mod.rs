@@ -0,0 +1,106 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Event-time timers for custom stateful operators.
+//!
+//! Operators such as [`window_by_time_aggregate`](crate::WindowByTimeExt::window_by_time_aggregate)
+//! act when event time passes a point, not when a wall clock does: the watermark, the
+//! highest timestamp seen so far, decides when a window is complete. [`EventTimers`]
+//! makes that mechanism available to user-defined logic. Timers are registered for an
+//! event time with a payload and are returned, in time order, once the watermark
+//! reaches them.
+//!
+//! [`process_with_timers`](ProcessWithTimersExt::process_with_timers) drives
+//! [`EventTimers`] from a stream, so custom logic only has to say what to do with each
+//! item and each timer. Operators written by hand can own an [`EventTimers`] and call
+//! [`advance_watermark`](EventTimers::advance_watermark) themselves.
+//!
+//! # Behavior
+//!
+//! - For each item, `on_item` runs first, then the watermark advances to the item's
+//!   timestamp and `on_timer` runs for every timer it passed
+//! - A timer registered at or before the watermark fires right after the call that
+//!   registered it; timers may register further timers
+//! - Late items do not move the watermark back
+//! - Emitted values carry the watermark as their timestamp, so they stay in order
+//! - When the source ends, the remaining timers fire in time order
+//! - Errors are propagated without affecting the state or the timers
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::ProcessWithTimersExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//! use std::collections::BTreeSet;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! // Orders are placed with a positive id and paid with its negation
+//! let (tx, events) = test_channel::<Sequenced<i32>>();
+//!
+//! // Report orders not paid within 10 ticks
+//! let mut overdue = events.process_with_timers::<Sequenced<i32>, _, _>(
+//!     BTreeSet::new(),
+//!     |unpaid, event, timers| {
+//!         let id = event.value;
+//!         if id > 0 {
+//!             unpaid.insert(id);
+//!             timers.register(event.timestamp() + 10, id);
+//!         } else {
+//!             unpaid.remove(&-id);
+//!         }
+//!         Vec::new()
+//!     },
+//!     |unpaid, _, id, _| if unpaid.remove(&id) { vec![id] } else { Vec::new() },
+//! );
+//!
+//! tx.unbounded_send((1, 0).into()).unwrap();
+//! tx.unbounded_send((2, 3).into()).unwrap();
+//! tx.unbounded_send((-2, 5).into()).unwrap();
+//! tx.unbounded_send((3, 12).into()).unwrap(); // passes the deadline of order 1
+//!
+//! let late = unwrap_value(Some(unwrap_stream(&mut overdue, 500).await));
+//! assert_eq!((late.value, late.timestamp()), (1, 12));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+mod timers;
+
+pub use timers::{EventTimers, TimerId};
+
+// Multi-threaded runtime (tokio, smol, async-std)
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::ProcessWithTimersExt;
+
+// Single-threaded runtime (wasm32, embassy)
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::ProcessWithTimersExt;
@@ -0,0 +1,104 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Handle of a timer registered with [`EventTimers::register`], used to cancel it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(u64);
+
+/// Timers that fire when the event-time watermark passes their time.
+///
+/// Each timer carries a payload, such as the key or window it was set for. The owner
+/// moves the watermark forward with [`advance_watermark`](Self::advance_watermark), which
+/// returns the timers due in the order of their times, and of registration for equal
+/// times. A timer registered at or before the current watermark fires on the next
+/// advance.
+#[derive(Clone, Debug)]
+pub struct EventTimers<TS, P> {
+    timers: BTreeMap<(TS, TimerId), P>,
+    times: BTreeMap<TimerId, TS>,
+    watermark: Option<TS>,
+    next_id: u64,
+}
+
+impl<TS: Ord + Copy, P> EventTimers<TS, P> {
+    pub fn new() -> Self {
+        Self {
+            timers: BTreeMap::new(),
+            times: BTreeMap::new(),
+            watermark: None,
+            next_id: 0,
+        }
+    }
+
+    /// Registers a timer firing once the watermark reaches `at`.
+    pub fn register(&mut self, at: TS, payload: P) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        self.timers.insert((at, id), payload);
+        self.times.insert(id, at);
+        id
+    }
+
+    /// Cancels a timer that has not fired yet, returning its payload.
+    pub fn cancel(&mut self, id: TimerId) -> Option<P> {
+        let at = self.times.remove(&id)?;
+        self.timers.remove(&(at, id))
+    }
+
+    /// Moves the watermark to `to`, unless it is already later, and returns the timers
+    /// now due as `(time, payload)` pairs.
+    pub fn advance_watermark(&mut self, to: TS) -> Vec<(TS, P)> {
+        let watermark = self.watermark.map_or(to, |current| current.max(to));
+        self.watermark = Some(watermark);
+
+        let mut fired = Vec::new();
+        while let Some(entry) = self.timers.first_entry() {
+            if entry.key().0 > watermark {
+                break;
+            }
+            let ((at, id), payload) = entry.remove_entry();
+            self.times.remove(&id);
+            fired.push((at, payload));
+        }
+        fired
+    }
+
+    /// Removes every pending timer, in firing order, as when the input has ended.
+    pub fn fire_all(&mut self) -> Vec<(TS, P)> {
+        self.times.clear();
+        core::mem::take(&mut self.timers)
+            .into_iter()
+            .map(|((at, _), payload)| (at, payload))
+            .collect()
+    }
+
+    /// Returns the current watermark, or `None` before the first advance.
+    pub fn watermark(&self) -> Option<TS> {
+        self.watermark
+    }
+
+    /// Returns the time of the next timer to fire.
+    pub fn next_timer(&self) -> Option<TS> {
+        self.timers.keys().next().map(|(at, _)| *at)
+    }
+
+    /// Returns the number of pending timers.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns whether no timer is pending.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+}
+
+impl<TS: Ord + Copy, P> Default for EventTimers<TS, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_filter_map_ordered_impl {
+    ($($bounds:tt)*) => {
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::future::ready;
+        use futures::Stream;
+        use futures::StreamExt;
+
+        pub trait FilterMapOrderedExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn filter_map_ordered<U, F>(self, f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(T) -> Option<U> + $($bounds)* 'static;
+        }
+
+        impl<S, T> FilterMapOrderedExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn filter_map_ordered<U, F>(self, mut f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(T) -> Option<U> + $($bounds)* 'static,
+            {
+                self.filter_map(move |item| {
+                    ready(match item {
+                        StreamItem::Value(value) => f(value).map(StreamItem::Value),
+                        StreamItem::Error(e) => Some(StreamItem::Error(e)),
+                    })
+                })
+            }
+        }
+    };
+}
@@ -0,0 +1,90 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Combined filter and transformation operator.
+//!
+//! The [`filter_map_ordered`](FilterMapOrderedExt::filter_map_ordered) operator applies a
+//! function to each value and emits the result when it is `Some`, dropping the value when
+//! it is `None`.
+//!
+//! # Behavior
+//!
+//! - The function receives the full item (`T`), like [`map_ordered`](crate::MapOrderedExt::map_ordered)
+//! - `Some(u)` is emitted, `None` drops the item
+//! - Emitted items keep the order of the source stream
+//! - Errors are passed through unchanged
+//!
+//! Timestamps are whatever the function puts into the returned item; reuse the source
+//! timestamp to keep the stream's temporal ordering intact.
+//!
+//! # See Also
+//!
+//! - [`filter_ordered`](crate::FilterOrderedExt::filter_ordered) - Filter without transforming
+//! - [`map_ordered`](crate::MapOrderedExt::map_ordered) - Transform without filtering
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::FilterMapOrderedExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<&str>>();
+//!
+//! // Parse numbers, dropping anything that is not one
+//! let mut numbers = stream.filter_map_ordered(|item| {
+//!     let timestamp = item.timestamp();
+//!     item.value.parse::<i32>().ok().map(|n| Sequenced::with_timestamp(n, timestamp))
+//! });
+//!
+//! tx.unbounded_send(("1", 1).into()).unwrap();
+//! tx.unbounded_send(("x", 2).into()).unwrap();
+//! tx.unbounded_send(("3", 3).into()).unwrap();
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut numbers, 500).await)).value, 1);
+//! let third = unwrap_value(Some(unwrap_stream(&mut numbers, 500).await));
+//! assert_eq!((third.value, third.timestamp()), (3, 3));
+//! # }
+//! ```
+//!
+//! # Use Cases
+//!
+//! - Parsing, keeping only values that parse
+//! - Extracting one enum variant together with its payload
+//! - Lookups that may miss
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::FilterMapOrderedExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::FilterMapOrderedExt;
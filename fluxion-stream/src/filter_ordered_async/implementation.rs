@@ -0,0 +1,51 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_filter_ordered_async_impl {
+    ($($bounds:tt)*) => {
+        use alloc::boxed::Box;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::future::{ready, Either};
+        use futures::Stream;
+        use futures::{FutureExt, StreamExt};
+
+        pub trait FilterOrderedAsyncExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn filter_ordered_async<F, Fut>(self, predicate: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                F: FnMut(&T::Inner) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = bool> + $($bounds)* 'static;
+        }
+
+        impl<S, T> FilterOrderedAsyncExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn filter_ordered_async<F, Fut>(self, mut predicate: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                F: FnMut(&T::Inner) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = bool> + $($bounds)* 'static,
+            {
+                Box::pin(self.filter_map(move |item| match item {
+                    StreamItem::Value(value) => Either::Left(
+                        predicate(&value.clone().into_inner())
+                            .map(move |keep| keep.then_some(StreamItem::Value(value))),
+                    ),
+                    StreamItem::Error(e) => Either::Right(ready(Some(StreamItem::Error(e)))),
+                }))
+            }
+        }
+    };
+}
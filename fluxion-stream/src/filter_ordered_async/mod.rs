@@ -0,0 +1,80 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Filtering operator with an asynchronous predicate.
+//!
+//! The [`filter_ordered_async`](FilterOrderedAsyncExt::filter_ordered_async) operator works
+//! like [`filter_ordered`](crate::FilterOrderedExt::filter_ordered), but the predicate
+//! returns a future, for checks that need I/O such as an async permission lookup.
+//!
+//! # Behavior
+//!
+//! - Predicate receives a reference to the **inner value** (`&T::Inner`)
+//! - Predicates run one at a time; the next item is not pulled until the
+//!   current predicate resolves, so emitted items keep their original order and timestamps
+//! - Errors are passed through unchanged, after any predicate still in progress
+//!
+//! Because predicates are sequential, a slow predicate delays every item behind it.
+//!
+//! # See Also
+//!
+//! - [`filter_ordered`](crate::FilterOrderedExt::filter_ordered) - Synchronous predicate
+//! - [`filter_map_ordered`](crate::FilterMapOrderedExt::filter_map_ordered) - Filter and transform in one step
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_stream::FilterOrderedAsyncExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//!
+//! async fn is_allowed(user: &str) -> bool {
+//!     user != "mallory"
+//! }
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<&'static str>>();
+//! let mut allowed = stream.filter_ordered_async(|user: &&'static str| is_allowed(user));
+//!
+//! tx.unbounded_send(("alice", 1).into()).unwrap();
+//! tx.unbounded_send(("mallory", 2).into()).unwrap();
+//! tx.unbounded_send(("bob", 3).into()).unwrap();
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut allowed, 500).await)).value, "alice");
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut allowed, 500).await)).value, "bob");
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::FilterOrderedAsyncExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::FilterOrderedAsyncExt;
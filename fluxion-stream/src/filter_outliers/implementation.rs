@@ -0,0 +1,201 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::types::OutlierTest;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Rolling window of measurements that each new measurement is tested against.
+pub(crate) struct OutlierWindow {
+    capacity: usize,
+    test: OutlierTest,
+    measurements: VecDeque<f64>,
+}
+
+impl OutlierWindow {
+    pub(crate) fn new(capacity: usize, test: OutlierTest) -> Self {
+        assert!(
+            capacity >= 2,
+            "filter_outliers requires a window of at least two items"
+        );
+        Self {
+            capacity,
+            test,
+            measurements: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns whether `measurement` is an outlier of the window, then adds it to the
+    /// window. Nothing is an outlier until the window is full.
+    pub(crate) fn admit(&mut self, measurement: f64) -> bool {
+        let full = self.measurements.len() == self.capacity;
+        let outlier = full && self.deviates(measurement);
+        if full {
+            self.measurements.pop_front();
+        }
+        self.measurements.push_back(measurement);
+        outlier
+    }
+
+    fn deviates(&self, measurement: f64) -> bool {
+        match self.test {
+            OutlierTest::ZScore(threshold) => {
+                let count = self.measurements.len() as f64;
+                let mean = self.measurements.iter().sum::<f64>() / count;
+                let variance = self
+                    .measurements
+                    .iter()
+                    .map(|m| (m - mean) * (m - mean))
+                    .sum::<f64>()
+                    / count;
+                // Compared squared, as `f64::sqrt` needs `std`
+                (measurement - mean) * (measurement - mean) > threshold * threshold * variance
+            }
+            OutlierTest::MedianDeviation(threshold) => {
+                let center = median(self.measurements.iter().copied().collect());
+                let spread = median(
+                    self.measurements
+                        .iter()
+                        .map(|m| (m - center).abs())
+                        .collect(),
+                );
+                (measurement - center).abs() > threshold * spread
+            }
+        }
+    }
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
+
+macro_rules! define_filter_outliers_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::OutlierWindow;
+        use crate::types::OutlierTest;
+        use alloc::boxed::Box;
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, FluxionSubject, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        pub trait FilterOutliersExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Drops items whose measurement fails `test` against the measurements of the
+            /// previous `window` items.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `window` is less than two.
+            fn filter_outliers<F>(
+                self,
+                window: usize,
+                test: OutlierTest,
+                measure: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: Fn(&T::Inner) -> f64 + $($bounds)* 'static;
+
+            /// Like [`filter_outliers`](Self::filter_outliers), but sends the outliers to
+            /// the second stream instead of dropping them.
+            ///
+            /// Outliers are sent as the first stream is polled, and the second stream ends
+            /// when the first one does.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `window` is less than two.
+            fn divert_outliers<F>(
+                self,
+                window: usize,
+                test: OutlierTest,
+                measure: F,
+            ) -> (
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+            )
+            where
+                F: Fn(&T::Inner) -> f64 + $($bounds)* 'static;
+        }
+
+        impl<T, S> FilterOutliersExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn filter_outliers<F>(
+                self,
+                window: usize,
+                test: OutlierTest,
+                measure: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: Fn(&T::Inner) -> f64 + $($bounds)* 'static,
+            {
+                let mut window = OutlierWindow::new(window, test);
+
+                self.filter_map(move |item| {
+                    let kept = match item {
+                        StreamItem::Value(value) => {
+                            (!window.admit(measure(&value.clone().into_inner()))).then_some(StreamItem::Value(value))
+                        }
+                        StreamItem::Error(e) => Some(StreamItem::Error(e)),
+                    };
+                    futures::future::ready(kept)
+                })
+            }
+
+            fn divert_outliers<F>(
+                self,
+                window: usize,
+                test: OutlierTest,
+                measure: F,
+            ) -> (
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+                impl Stream<Item = StreamItem<T>> + $($bounds)*,
+            )
+            where
+                F: Fn(&T::Inner) -> f64 + $($bounds)* 'static,
+            {
+                let mut window = OutlierWindow::new(window, test);
+                let outliers = FluxionSubject::<T>::new();
+                let outlier_stream = outliers
+                    .subscribe()
+                    .unwrap_or_else(|_| unreachable!("fresh subject should allow subscription"));
+                let outliers_clone = outliers.clone();
+
+                let main_stream = self.filter_map(move |item| {
+                    let kept = match item {
+                        StreamItem::Value(value) if window.admit(measure(&value.clone().into_inner())) => {
+                            // A dropped side stream only loses the outliers
+                            let _ = outliers_clone.next(value);
+                            None
+                        }
+                        item => Some(item),
+                    };
+                    futures::future::ready(kept)
+                });
+
+                let close_stream = futures::stream::once(async move {
+                    outliers.close();
+                    None::<StreamItem<T>>
+                })
+                .filter_map(futures::future::ready);
+
+                (Box::pin(main_stream.chain(close_stream)), outlier_stream)
+            }
+        }
+    };
+}
@@ -0,0 +1,80 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Statistical outlier filtering of noisy measurements.
+//!
+//! [`filter_outliers`](FilterOutliersExt::filter_outliers) keeps a rolling window of the
+//! measurements of the latest items and drops items whose measurement deviates too far
+//! from it, cleaning spikes out of sensor streams before they are fused with others.
+//! [`divert_outliers`](FilterOutliersExt::divert_outliers) sends them to a side stream
+//! instead, to count or inspect them. The measurement of an item is computed by a
+//! closure, so readings can be judged by any of their fields.
+//!
+//! # Behavior
+//!
+//! - Each measurement is tested against the `window` measurements before it with an
+//!   [`OutlierTest`](crate::OutlierTest): the z-score, or the median absolute deviation
+//!   which a few outliers in the window barely move
+//! - Every measurement enters the window, outliers included, so a lasting change of
+//!   level stops being an outlier once the window has moved onto it
+//! - Nothing is an outlier until the window is full
+//! - A window whose measurements are all equal flags any other measurement
+//! - Errors are propagated without affecting the window, and only on the first stream
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{FilterOutliersExt, OutlierTest};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, readings) = test_channel::<Sequenced<i32>>();
+//!
+//! // Drop readings more than 3 median deviations away from the latest 4
+//! let mut clean = readings.filter_outliers(4, OutlierTest::MedianDeviation(3.0), |r| *r as f64);
+//!
+//! for reading in [20, 21, 19, 20, 95, 21] {
+//!     tx.unbounded_send(Sequenced::new(reading)).unwrap();
+//! }
+//!
+//! for expected in [20, 21, 19, 20, 21] {
+//!     assert_eq!(unwrap_value(Some(unwrap_stream(&mut clean, 500).await)).value, expected);
+//! }
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::FilterOutliersExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::FilterOutliersExt;
@@ -0,0 +1,125 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+/// How a stream wrapped by [`on_terminate`](super::FinalizeExt::on_terminate) stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    /// The source ended after a value, or without emitting anything.
+    Completed,
+    /// The source ended right after emitting an error.
+    Errored,
+    /// The stream was dropped before the source ended.
+    Cancelled,
+}
+
+macro_rules! define_finalize_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::Termination;
+        use alloc::boxed::Box;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::Stream;
+
+        type Hook = Box<dyn FnOnce(Termination) + $($bounds)* 'static>;
+
+        /// Runs its hook once, when the source ends or when it is dropped first.
+        struct Finalize<S> {
+            source: Pin<Box<S>>,
+            hook: Option<Hook>,
+            last_was_error: bool,
+        }
+
+        impl<S> Finalize<S> {
+            fn finish(&mut self, termination: Termination) {
+                if let Some(hook) = self.hook.take() {
+                    hook(termination);
+                }
+            }
+        }
+
+        impl<S, T> Stream for Finalize<S>
+        where
+            S: Stream<Item = StreamItem<T>>,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let polled = self.source.as_mut().poll_next(cx);
+                match &polled {
+                    Poll::Ready(Some(item)) => {
+                        self.last_was_error = matches!(item, StreamItem::Error(_));
+                    }
+                    Poll::Ready(None) => {
+                        let termination = if self.last_was_error {
+                            Termination::Errored
+                        } else {
+                            Termination::Completed
+                        };
+                        self.finish(termination);
+                    }
+                    Poll::Pending => {}
+                }
+                polled
+            }
+        }
+
+        impl<S> Drop for Finalize<S> {
+            fn drop(&mut self) {
+                self.finish(Termination::Cancelled);
+            }
+        }
+
+        pub trait FinalizeExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Calls `f` once when the source ends, unless its last item was an error.
+            fn on_complete(
+                self,
+                f: impl FnOnce() + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+
+            /// Calls `f` exactly once with how the stream stopped: when the source ends,
+            /// or when the stream is dropped before that.
+            fn on_terminate(
+                self,
+                f: impl FnOnce(Termination) + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*;
+        }
+
+        impl<T, S> FinalizeExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn on_complete(
+                self,
+                f: impl FnOnce() + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                self.on_terminate(move |termination| {
+                    if termination == Termination::Completed {
+                        f();
+                    }
+                })
+            }
+
+            fn on_terminate(
+                self,
+                f: impl FnOnce(Termination) + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                Finalize {
+                    source: Box::pin(self),
+                    hook: Some(Box::new(f)),
+                    last_was_error: false,
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,74 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Finalization hooks that run when a stream stops, however it stops.
+//!
+//! Deep in a long operator chain, a stream can stop because its source ended, or because
+//! something downstream dropped it: a `take_items`, a `select`, a cancelled task.
+//! [`on_terminate`](FinalizeExt::on_terminate) runs a callback exactly once in either
+//! case and tells it which [`Termination`] happened, so file handles, connections and
+//! metrics flushes are released reliably. [`on_complete`](FinalizeExt::on_complete) only
+//! runs on normal completion.
+//!
+//! # Behavior
+//!
+//! - Items pass through unchanged
+//! - When the source ends, the hook runs before the end of the stream is reported
+//! - Errors do not end fluxion streams; if the source ends right after an error, the
+//!   termination is [`Termination::Errored`]
+//! - If the stream is dropped first, the hook runs during the drop with
+//!   [`Termination::Cancelled`]; it should not block
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{FinalizeExt, Termination};
+//! use fluxion_test_utils::{helpers::test_channel, sequenced::Sequenced};
+//! use std::sync::{Arc, Mutex};
+//!
+//! let (_tx, readings) = test_channel::<Sequenced<u32>>();
+//! let outcome = Arc::new(Mutex::new(None));
+//! let recorded = Arc::clone(&outcome);
+//!
+//! let readings = readings.on_terminate(move |termination| {
+//!     *recorded.lock().unwrap() = Some(termination);
+//! });
+//!
+//! // Dropped before the source ended
+//! drop(readings);
+//! assert_eq!(*outcome.lock().unwrap(), Some(Termination::Cancelled));
+//! ```
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::Termination;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::FinalizeExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::FinalizeExt;
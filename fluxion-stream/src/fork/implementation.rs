@@ -0,0 +1,249 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_fork_impl {
+    ($($bounds:tt)*) => {
+        use alloc::boxed::Box;
+        use alloc::collections::VecDeque;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use core::task::{Context, Poll, Waker};
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::task::{waker_ref, ArcWake};
+        use futures::Stream;
+
+        type Source<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)* 'static>>;
+
+        /// Wakers of all copies. Used as the waker of the source, so whichever copy polled
+        /// the source last, every copy is woken when it makes progress.
+        struct WakerSet {
+            wakers: Mutex<Vec<Option<Waker>>>,
+        }
+
+        impl WakerSet {
+            fn register(&self, index: usize, waker: &Waker) {
+                match &mut self.wakers.lock()[index] {
+                    Some(existing) if existing.will_wake(waker) => {}
+                    slot => *slot = Some(waker.clone()),
+                }
+            }
+
+            fn wake_one(&self, index: usize) {
+                let waker = self.wakers.lock()[index].take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }
+
+            fn wake_all(&self) {
+                let wakers: Vec<Waker> = self.wakers.lock().iter_mut().filter_map(Option::take).collect();
+                for waker in wakers {
+                    waker.wake();
+                }
+            }
+        }
+
+        impl ArcWake for WakerSet {
+            fn wake_by_ref(arc_self: &alloc::sync::Arc<Self>) {
+                arc_self.wake_all();
+            }
+        }
+
+        struct ForkState<T> {
+            source: Option<Source<T>>,
+            buffers: Vec<VecDeque<StreamItem<T>>>,
+            alive: Vec<bool>,
+            capacity: usize,
+        }
+
+        impl<T> ForkState<T> {
+            fn is_blocked(&self) -> bool {
+                self.buffers
+                    .iter()
+                    .zip(&self.alive)
+                    .any(|(buffer, alive)| *alive && buffer.len() >= self.capacity)
+            }
+        }
+
+        struct Shared<T> {
+            state: Mutex<ForkState<T>>,
+            wakers: alloc::sync::Arc<WakerSet>,
+        }
+
+        /// One of the copies produced by [`fork`](ForkExt::fork) or [`tee`](ForkExt::tee).
+        pub struct ForkedStream<T: Fluxion>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            shared: Arc<Shared<T>>,
+            index: usize,
+        }
+
+        impl<T: Fluxion> Debug for ForkedStream<T>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("ForkedStream")
+                    .field("index", &self.index)
+                    .finish_non_exhaustive()
+            }
+        }
+
+        impl<T: Fluxion> Stream for ForkedStream<T>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let index = self.index;
+                let shared = &*self.shared;
+                let mut state = shared.state.lock();
+
+                if let Some(item) = state.buffers[index].pop_front() {
+                    if state.buffers[index].len() + 1 == state.capacity {
+                        shared.wakers.wake_all();
+                    }
+                    return Poll::Ready(Some(item));
+                }
+
+                if state.source.is_none() {
+                    return Poll::Ready(None);
+                }
+
+                shared.wakers.register(index, cx.waker());
+                if state.is_blocked() {
+                    return Poll::Pending;
+                }
+
+                let ForkState {
+                    source,
+                    buffers,
+                    alive,
+                    ..
+                } = &mut *state;
+                let Some(stream) = source.as_mut() else {
+                    return Poll::Ready(None);
+                };
+                let waker = waker_ref(&shared.wakers);
+                let mut source_cx = Context::from_waker(&waker);
+
+                match stream.as_mut().poll_next(&mut source_cx) {
+                    Poll::Ready(Some(item)) => {
+                        for (other, buffer) in buffers.iter_mut().enumerate() {
+                            if other != index && alive[other] {
+                                buffer.push_back(item.clone());
+                                shared.wakers.wake_one(other);
+                            }
+                        }
+                        Poll::Ready(Some(item))
+                    }
+                    Poll::Ready(None) => {
+                        *source = None;
+                        drop(state);
+                        shared.wakers.wake_all();
+                        Poll::Ready(None)
+                    }
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+        }
+
+        impl<T: Fluxion> Drop for ForkedStream<T>
+        where
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn drop(&mut self) {
+                {
+                    let mut state = self.shared.state.lock();
+                    state.alive[self.index] = false;
+                    state.buffers[self.index].clear();
+                    if !state.alive.contains(&true) {
+                        state.source = None;
+                    }
+                }
+                self.shared.wakers.wake_all();
+            }
+        }
+
+        pub trait ForkExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Splits the stream into `copies` identical streams, each buffering at most
+            /// `capacity` items ahead of the slowest copy.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `copies` or `capacity` is zero.
+            fn fork(self, copies: usize, capacity: usize) -> Vec<ForkedStream<T>>
+            where
+                Self: $($bounds)* 'static;
+
+            /// Splits the stream into two identical streams; shorthand for
+            /// [`fork`](ForkExt::fork) with two copies.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `capacity` is zero.
+            fn tee(self, capacity: usize) -> (ForkedStream<T>, ForkedStream<T>)
+            where
+                Self: $($bounds)* 'static;
+        }
+
+        impl<S, T> ForkExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn fork(self, copies: usize, capacity: usize) -> Vec<ForkedStream<T>>
+            where
+                Self: $($bounds)* 'static,
+            {
+                assert!(copies >= 1, "fork: at least one copy is required");
+                assert!(capacity >= 1, "fork: capacity must be at least 1");
+
+                let shared = Arc::new(Shared {
+                    state: Mutex::new(ForkState {
+                        source: Some(Box::pin(self)),
+                        buffers: (0..copies).map(|_| VecDeque::with_capacity(capacity)).collect(),
+                        alive: alloc::vec![true; copies],
+                        capacity,
+                    }),
+                    wakers: alloc::sync::Arc::new(WakerSet {
+                        wakers: Mutex::new(alloc::vec![None; copies]),
+                    }),
+                });
+
+                (0..copies)
+                    .map(|index| ForkedStream {
+                        shared: Arc::clone(&shared),
+                        index,
+                    })
+                    .collect()
+            }
+
+            fn tee(self, capacity: usize) -> (ForkedStream<T>, ForkedStream<T>)
+            where
+                Self: $($bounds)* 'static,
+            {
+                let mut copies = self.fork(2, capacity);
+                let second = copies.pop().unwrap_or_else(|| unreachable!("fork returns two copies"));
+                let first = copies.pop().unwrap_or_else(|| unreachable!("fork returns two copies"));
+                (first, second)
+            }
+        }
+    };
+}
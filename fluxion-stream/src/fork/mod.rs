@@ -0,0 +1,88 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Fork operator that splits a stream into N identical, bounded copies.
+//!
+//! [`fork`](ForkExt::fork) returns `copies` streams that each receive every item of the
+//! source, values and errors alike, in source order. [`tee`](ForkExt::tee) is the
+//! two-copy shorthand for the common "write to the database and also update the cache"
+//! pattern.
+//!
+//! # Fork vs Share
+//!
+//! [`share`](crate::ShareExt::share) runs the source on a spawned task and hands out
+//! subscriptions from a shared handle; late subscribers miss earlier items and slow
+//! subscribers buffer without bound. `fork` spawns nothing: the copies drive the source
+//! themselves, all of them exist from the start, and each buffers at most `capacity`
+//! items. It works on every runtime, including `no_std`.
+//!
+//! # Backpressure
+//!
+//! Whenever a copy pulls a new item from the source, the item is also queued for every
+//! other copy. Once any copy has `capacity` items queued, the source is not polled again
+//! until that copy catches up, so the fastest copy can run at most `capacity` items ahead
+//! of the slowest one. A copy that is never polled therefore stalls the others: drop
+//! copies you do not need. Dropped copies stop receiving items and no longer hold the
+//! others back.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::prelude::*;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, rx) = async_channel::unbounded();
+//! let (to_database, to_cache) = rx.into_fluxion_stream().tee(16);
+//!
+//! tx.try_send(Sequenced::new(1)).unwrap();
+//! tx.try_send(Sequenced::new(2)).unwrap();
+//! drop(tx);
+//!
+//! let (written, cached) = futures::join!(
+//!     to_database.map(|item| item.unwrap().into_inner()).collect::<Vec<_>>(),
+//!     to_cache.map(|item| item.unwrap().into_inner()).collect::<Vec<_>>(),
+//! );
+//!
+//! assert_eq!(written, vec![1, 2]);
+//! assert_eq!(cached, vec![1, 2]);
+//! # }
+//! ```
+//!
+//! # Error Handling
+//!
+//! Errors are delivered to every copy like values and do not end the streams.
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{ForkExt, ForkedStream};
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{ForkExt, ForkedStream};
@@ -0,0 +1,68 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Constructors that turn iterators into fluxion streams.
+//!
+//! [`from_iter`] wraps plain values and assigns them increasing timestamps `0, 1, 2, ...`
+//! in iteration order. [`from_timestamped_iter`] takes `(value, timestamp)` pairs and keeps
+//! the given timestamps as they are. Both emit every item as a
+//! [`StreamItem::Value`] and end when the iterator is exhausted, which makes them handy
+//! for quick pipelines and tests that do not need a channel.
+//!
+//! Items are produced lazily, one per poll.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::{from_iter, from_timestamped_iter, OrderedStreamExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let evens = from_iter::<Sequenced<i32>, _>([0, 2, 4]);
+//! let odds = from_timestamped_iter::<Sequenced<i32>, _>([(1, 1), (3, 2)]);
+//!
+//! let merged: Vec<_> = evens
+//!     .ordered_merge(vec![odds])
+//!     .map(|item| item.unwrap().timestamp())
+//!     .collect()
+//!     .await;
+//!
+//! assert_eq!(merged, vec![0, 1, 1, 2, 2]);
+//! # }
+//! ```
+
+use fluxion_core::{StreamItem, Timestamped};
+use futures::{stream, Stream};
+
+/// Creates a stream emitting `values` with timestamps `0, 1, 2, ...`.
+pub fn from_iter<T, I>(values: I) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    T: Timestamped,
+    T::Timestamp: From<u64>,
+    I: IntoIterator<Item = T::Inner>,
+{
+    stream::iter(
+        (0u64..).zip(values).map(|(timestamp, value)| {
+            StreamItem::Value(T::with_timestamp(value, timestamp.into()))
+        }),
+    )
+}
+
+/// Creates a stream emitting each `(value, timestamp)` pair with its own timestamp.
+///
+/// Timestamps are not checked: out-of-order input is emitted out of order.
+pub fn from_timestamped_iter<T, I>(items: I) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    T: Timestamped,
+    I: IntoIterator<Item = (T::Inner, T::Timestamp)>,
+{
+    stream::iter(
+        items
+            .into_iter()
+            .map(|(value, timestamp)| StreamItem::Value(T::with_timestamp(value, timestamp))),
+    )
+}
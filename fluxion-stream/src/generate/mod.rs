@@ -0,0 +1,86 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Source constructors driven by an async step function.
+//!
+//! [`generate`] replaces the usual hand-written source task, a loop that produces items
+//! and pushes them into a channel. It calls an async `step` with the current state; the
+//! step returns the next item together with the state for the following call, or `None`
+//! to end the stream. [`generate_cancellable`] additionally ends the stream when a
+//! [`CancellationToken`] is cancelled, even while a step is in progress.
+//!
+//! # Behavior
+//!
+//! - The step is called lazily, once per poll that needs an item, so a slow consumer
+//!   slows the source down instead of filling a channel
+//! - Items are emitted with the timestamps the step gives them
+//! - A step in progress when the token is cancelled, or when the stream is dropped, is
+//!   dropped with it
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::generate;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! // Fetch pages until one comes back empty
+//! let pages = generate(0u64, |page| async move {
+//!     (page < 3).then(|| (Sequenced::with_timestamp(format!("page {page}"), page), page + 1))
+//! });
+//!
+//! let pages: Vec<_> = pages.map(|item| item.unwrap().into_inner()).collect().await;
+//! assert_eq!(pages, vec!["page 0", "page 1", "page 2"]);
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use core::future::Future;
+use fluxion_core::{CancellationToken, StreamItem};
+use futures::future::{select, Either};
+use futures::Stream;
+
+/// Creates a stream of the items produced by calling `step` on `initial_state` and then
+/// on each state it returns, until it returns `None`.
+pub fn generate<T, S, F, Fut>(
+    initial_state: S,
+    step: F,
+) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    F: FnMut(S) -> Fut,
+    Fut: Future<Output = Option<(T, S)>>,
+{
+    generate_cancellable(initial_state, step, CancellationToken::new())
+}
+
+/// Like [`generate`], but also ends the stream as soon as `cancel` is cancelled.
+pub fn generate_cancellable<T, S, F, Fut>(
+    initial_state: S,
+    step: F,
+    cancel: CancellationToken,
+) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    F: FnMut(S) -> Fut,
+    Fut: Future<Output = Option<(T, S)>>,
+{
+    Box::pin(futures::stream::unfold(
+        Some((initial_state, step, cancel)),
+        |generator| async move {
+            let (state, mut step, cancel) = generator?;
+            if cancel.is_cancelled() {
+                return None;
+            }
+
+            let next = Box::pin(step(state));
+            match select(next, cancel.cancelled()).await {
+                Either::Left((Some((item, state)), _)) => {
+                    Some((StreamItem::Value(item), Some((state, step, cancel))))
+                }
+                _ => None,
+            }
+        },
+    ))
+}
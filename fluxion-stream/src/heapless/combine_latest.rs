@@ -0,0 +1,179 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use super::ordered_merge::OrderedMergeHeapless;
+use core::array;
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{HasTimestamp, StreamItem, Timestamped};
+use futures::Stream;
+
+/// Extension trait providing [`combine_latest_heapless`](Self::combine_latest_heapless).
+pub trait CombineLatestHeaplessExt<T>: Stream<Item = StreamItem<T>> + Unpin + Sized
+where
+    T: Timestamped + Ord + Unpin,
+    T::Inner: Clone + Debug + Ord,
+{
+    /// Combines the latest values of this stream and `others`, like
+    /// [`combine_latest`](crate::CombineLatestExt::combine_latest), for at most `N`
+    /// streams and without allocating.
+    ///
+    /// Once every stream has emitted, each new value emits a [`HeaplessCombinedState`]
+    /// holding the latest value of every stream, in stream order, and stamped with the
+    /// timestamp of the value that triggered it. States rejected by `filter` are
+    /// skipped.
+    ///
+    /// All streams must have the same type and be `Unpin`. If there are more than `N`,
+    /// a [`FluxionError::ResourceLimitExceeded`](fluxion_core::FluxionError::ResourceLimitExceeded)
+    /// is emitted first and only the first `N` are combined.
+    ///
+    /// Errors are passed through as soon as they arrive.
+    fn combine_latest_heapless<const N: usize, F>(
+        self,
+        others: impl IntoIterator<Item = Self>,
+        filter: F,
+    ) -> CombineLatestHeapless<Self, T, F, N>
+    where
+        F: Fn(&HeaplessCombinedState<T::Inner, T::Timestamp, N>) -> bool;
+}
+
+impl<S, T> CombineLatestHeaplessExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped + Ord + Unpin,
+    T::Inner: Clone + Debug + Ord,
+{
+    fn combine_latest_heapless<const N: usize, F>(
+        self,
+        others: impl IntoIterator<Item = Self>,
+        filter: F,
+    ) -> CombineLatestHeapless<Self, T, F, N>
+    where
+        F: Fn(&HeaplessCombinedState<T::Inner, T::Timestamp, N>) -> bool,
+    {
+        CombineLatestHeapless {
+            merged: OrderedMergeHeapless::new(core::iter::once(self).chain(others)),
+            latest: array::from_fn(|_| None),
+            filter,
+        }
+    }
+}
+
+/// Stream returned by
+/// [`combine_latest_heapless`](CombineLatestHeaplessExt::combine_latest_heapless).
+pub struct CombineLatestHeapless<S, T, F, const N: usize> {
+    merged: OrderedMergeHeapless<S, T, N>,
+    latest: [Option<T>; N],
+    filter: F,
+}
+
+impl<S, T, F, const N: usize> Stream for CombineLatestHeapless<S, T, F, N>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped + Ord + Unpin,
+    T::Inner: Clone + Debug + Ord,
+    F: Fn(&HeaplessCombinedState<T::Inner, T::Timestamp, N>) -> bool + Unpin,
+{
+    type Item = StreamItem<HeaplessCombinedState<T::Inner, T::Timestamp, N>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let (value, index) = match this.merged.poll_next_indexed(cx) {
+                Poll::Ready(Some((StreamItem::Value(value), index))) => (value, index),
+                Poll::Ready(Some((StreamItem::Error(e), _))) => {
+                    return Poll::Ready(Some(StreamItem::Error(e)))
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let timestamp = value.timestamp();
+            this.latest[index] = Some(value);
+
+            let latest = &this.latest[..this.merged.len()];
+            if latest.iter().any(Option::is_none) {
+                continue;
+            }
+
+            let state = HeaplessCombinedState {
+                state: latest
+                    .iter()
+                    .flatten()
+                    .map(|value| (value.clone().into_inner(), value.timestamp()))
+                    .collect(),
+                timestamp,
+            };
+
+            if (this.filter)(&state) {
+                return Poll::Ready(Some(StreamItem::Value(state)));
+            }
+        }
+    }
+}
+
+/// Fixed-capacity counterpart of [`CombinedState`](crate::CombinedState), emitted by
+/// [`combine_latest_heapless`](CombineLatestHeaplessExt::combine_latest_heapless).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HeaplessCombinedState<V, TS, const N: usize> {
+    /// Values paired with their individual timestamps
+    state: ::heapless::Vec<(V, TS), N>,
+    /// Timestamp of the value that produced this state
+    timestamp: TS,
+}
+
+impl<V, TS, const N: usize> HeaplessCombinedState<V, TS, N> {
+    /// Returns the values, in stream order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.state.iter().map(|(value, _)| value)
+    }
+
+    /// Returns the value-timestamp pairs, in stream order.
+    pub fn pairs(&self) -> &[(V, TS)] {
+        &self.state
+    }
+
+    /// Returns the number of streams in the combined state.
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns true if there are no streams in the combined state.
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+}
+
+impl<V, TS, const N: usize> HasTimestamp for HeaplessCombinedState<V, TS, N>
+where
+    V: Clone + Debug + Ord,
+    TS: Clone + Debug + Ord + Copy + Send + Sync,
+{
+    type Timestamp = TS;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.timestamp
+    }
+}
+
+impl<V, TS, const N: usize> Timestamped for HeaplessCombinedState<V, TS, N>
+where
+    V: Clone + Debug + Ord,
+    TS: Clone + Debug + Ord + Copy + Send + Sync,
+{
+    type Inner = Self;
+
+    fn with_timestamp(value: Self::Inner, timestamp: Self::Timestamp) -> Self {
+        Self {
+            state: value.state,
+            timestamp,
+        }
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self
+    }
+}
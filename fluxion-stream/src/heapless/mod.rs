@@ -0,0 +1,58 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Fixed-capacity operator variants that never allocate.
+//!
+//! The regular operators box their streams and grow `Vec`s as needed, which is fine
+//! with a heap but not on microcontrollers that have none, or too little to trust. The
+//! operators in this module keep all of their state inline, sized by a const-generic
+//! capacity `N`, and return concrete stream types that can be pinned on the stack with
+//! [`core::pin::pin!`].
+//!
+//! | Operator | Capacity `N` bounds | On overflow |
+//! |----------|---------------------|-------------|
+//! | [`ordered_merge_heapless`](OrderedMergeHeaplessExt::ordered_merge_heapless) | Number of merged streams | Emits [`ResourceLimitExceeded`](fluxion_core::FluxionError::ResourceLimitExceeded), merges the first `N` |
+//! | [`combine_latest_heapless`](CombineLatestHeaplessExt::combine_latest_heapless) | Number of combined streams | Emits [`ResourceLimitExceeded`](fluxion_core::FluxionError::ResourceLimitExceeded), combines the first `N` |
+//! | [`window_by_count_heapless`](WindowByCountHeaplessExt::window_by_count_heapless) | Window size | Cannot overflow; a full window is emitted |
+//!
+//! Because nothing is boxed, the streams passed to `ordered_merge_heapless` and
+//! `combine_latest_heapless` must all have the same type, such as several receivers of
+//! the same channel type. A time-based `debounce` without allocation is available as
+//! `debounce_heapless` in `fluxion-stream-time`.
+//!
+//! Enable with the `heapless` feature. It works with `default-features = false`;
+//! `fluxion-core` still links `alloc` for error messages, but none of these operators
+//! allocate while running.
+//!
+//! # Example
+//!
+//! ```
+//! use core::pin::pin;
+//! use fluxion_stream::heapless::OrderedMergeHeaplessExt;
+//! use fluxion_test_utils::{helpers::test_channel, sequenced::Sequenced};
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx1, rx1) = test_channel::<Sequenced<i32>>();
+//! let (tx2, rx2) = test_channel::<Sequenced<i32>>();
+//!
+//! let mut merged = pin!(rx1.ordered_merge_heapless::<2>([rx2]));
+//!
+//! tx2.unbounded_send((20, 1).into()).unwrap();
+//! tx1.unbounded_send((10, 2).into()).unwrap();
+//! drop((tx1, tx2));
+//!
+//! assert_eq!(merged.next().await.unwrap().unwrap().value, 20);
+//! assert_eq!(merged.next().await.unwrap().unwrap().value, 10);
+//! # }
+//! ```
+
+mod combine_latest;
+mod ordered_merge;
+mod window_by_count;
+
+pub use combine_latest::{CombineLatestHeapless, CombineLatestHeaplessExt, HeaplessCombinedState};
+pub use ordered_merge::{OrderedMergeHeapless, OrderedMergeHeaplessExt};
+pub use window_by_count::{WindowByCountHeapless, WindowByCountHeaplessExt};
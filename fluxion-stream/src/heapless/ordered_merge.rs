@@ -0,0 +1,145 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::array;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{FluxionError, StreamItem};
+use futures::{Stream, StreamExt};
+
+/// Extension trait providing [`ordered_merge_heapless`](Self::ordered_merge_heapless).
+pub trait OrderedMergeHeaplessExt<T>: Stream<Item = StreamItem<T>> + Unpin + Sized
+where
+    T: Ord + Unpin,
+{
+    /// Merges this stream with `others` in temporal order, like
+    /// [`ordered_merge`](crate::OrderedStreamExt::ordered_merge), holding at most `N`
+    /// streams and one buffered item per stream inline.
+    ///
+    /// All streams must have the same type and be `Unpin`; a `!Unpin` stream can be
+    /// pinned on the stack with [`core::pin::pin!`] first.
+    ///
+    /// If this stream and `others` add up to more than `N` streams, the merged stream
+    /// first emits a [`FluxionError::ResourceLimitExceeded`] and then merges the first
+    /// `N` of them, dropping the rest.
+    ///
+    /// Errors are passed through as soon as they arrive.
+    fn ordered_merge_heapless<const N: usize>(
+        self,
+        others: impl IntoIterator<Item = Self>,
+    ) -> OrderedMergeHeapless<Self, T, N>;
+}
+
+impl<S, T> OrderedMergeHeaplessExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Ord + Unpin,
+{
+    fn ordered_merge_heapless<const N: usize>(
+        self,
+        others: impl IntoIterator<Item = Self>,
+    ) -> OrderedMergeHeapless<Self, T, N> {
+        OrderedMergeHeapless::new(core::iter::once(self).chain(others))
+    }
+}
+
+/// Stream returned by
+/// [`ordered_merge_heapless`](OrderedMergeHeaplessExt::ordered_merge_heapless).
+pub struct OrderedMergeHeapless<S, T, const N: usize> {
+    streams: ::heapless::Vec<S, N>,
+    buffered: [Option<T>; N],
+    finished: [bool; N],
+    overflowed: bool,
+}
+
+impl<S, T, const N: usize> OrderedMergeHeapless<S, T, N>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Ord,
+{
+    pub(super) fn new(streams: impl IntoIterator<Item = S>) -> Self {
+        let mut kept = ::heapless::Vec::new();
+        let mut overflowed = false;
+        for stream in streams {
+            if kept.push(stream).is_err() {
+                overflowed = true;
+            }
+        }
+
+        Self {
+            streams: kept,
+            buffered: array::from_fn(|_| None),
+            finished: [false; N],
+            overflowed,
+        }
+    }
+
+    /// Number of streams being merged, at most `N`.
+    pub(super) fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Polls for the next item together with the index of the stream it came from.
+    ///
+    /// The overflow error has no source stream and is reported with index `N`.
+    pub(super) fn poll_next_indexed(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(StreamItem<T>, usize)>> {
+        if self.overflowed {
+            self.overflowed = false;
+            return Poll::Ready(Some((
+                StreamItem::Error(FluxionError::resource_limit_exceeded(
+                    "ordered_merge_heapless streams",
+                    N,
+                )),
+                N,
+            )));
+        }
+
+        let mut any_pending = false;
+
+        for (i, stream) in self.streams.iter_mut().enumerate() {
+            if self.finished[i] || self.buffered[i].is_some() {
+                continue;
+            }
+            match stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(StreamItem::Error(e))) => {
+                    return Poll::Ready(Some((StreamItem::Error(e), i)));
+                }
+                Poll::Ready(Some(StreamItem::Value(item))) => self.buffered[i] = Some(item),
+                Poll::Ready(None) => self.finished[i] = true,
+                Poll::Pending => any_pending = true,
+            }
+        }
+
+        let min_idx = self
+            .buffered
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| item.as_ref().map(|item| (i, item)))
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(i, _)| i);
+
+        match min_idx.and_then(|i| self.buffered[i].take().map(|item| (item, i))) {
+            Some((item, i)) => Poll::Ready(Some((StreamItem::Value(item), i))),
+            None if any_pending => Poll::Pending,
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<S, T, const N: usize> Stream for OrderedMergeHeapless<S, T, N>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Ord + Unpin,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut()
+            .poll_next_indexed(cx)
+            .map(|next| next.map(|(item, _)| item))
+    }
+}
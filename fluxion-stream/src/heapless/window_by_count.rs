@@ -0,0 +1,128 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::marker::PhantomData;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{StreamItem, Timestamped};
+use futures::Stream;
+use pin_project::pin_project;
+
+/// Extension trait providing [`window_by_count_heapless`](Self::window_by_count_heapless).
+pub trait WindowByCountHeaplessExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: Timestamped,
+{
+    /// Groups items into windows of `N`, like
+    /// [`window_by_count`](crate::WindowByCountExt::window_by_count), collecting them
+    /// into a fixed-capacity [`heapless::Vec`](::heapless::Vec).
+    ///
+    /// Each window carries the timestamp of its last item. A partial window is emitted
+    /// when the source ends. An error discards the current window and is passed through.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    fn window_by_count_heapless<const N: usize, Out>(
+        self,
+    ) -> WindowByCountHeapless<Self, T, Out, N>
+    where
+        Out: Timestamped<Inner = ::heapless::Vec<T::Inner, N>>,
+        Out::Timestamp: From<T::Timestamp>;
+}
+
+impl<S, T> WindowByCountHeaplessExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+{
+    fn window_by_count_heapless<const N: usize, Out>(self) -> WindowByCountHeapless<Self, T, Out, N>
+    where
+        Out: Timestamped<Inner = ::heapless::Vec<T::Inner, N>>,
+        Out::Timestamp: From<T::Timestamp>,
+    {
+        assert!(
+            N >= 1,
+            "window_by_count_heapless: window size must be at least 1"
+        );
+
+        WindowByCountHeapless {
+            stream: self,
+            window: ::heapless::Vec::new(),
+            last_timestamp: None,
+            finished: false,
+            _out: PhantomData,
+        }
+    }
+}
+
+/// Stream returned by
+/// [`window_by_count_heapless`](WindowByCountHeaplessExt::window_by_count_heapless).
+#[pin_project]
+pub struct WindowByCountHeapless<S, T, Out, const N: usize>
+where
+    T: Timestamped,
+{
+    #[pin]
+    stream: S,
+    window: ::heapless::Vec<T::Inner, N>,
+    last_timestamp: Option<T::Timestamp>,
+    finished: bool,
+    _out: PhantomData<fn() -> Out>,
+}
+
+impl<S, T, Out, const N: usize> Stream for WindowByCountHeapless<S, T, Out, N>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+    Out: Timestamped<Inner = ::heapless::Vec<T::Inner, N>>,
+    Out::Timestamp: From<T::Timestamp>,
+{
+    type Item = StreamItem<Out>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.finished {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => {
+                    *this.last_timestamp = Some(value.timestamp());
+                    if this.window.push(value.into_inner()).is_err() {
+                        unreachable!("a full window is emitted before the next push");
+                    }
+
+                    if this.window.is_full() {
+                        return Poll::Ready(emit::<T, Out, N>(this.window, this.last_timestamp));
+                    }
+                }
+                Poll::Ready(Some(StreamItem::Error(e))) => {
+                    this.window.clear();
+                    *this.last_timestamp = None;
+                    return Poll::Ready(Some(StreamItem::Error(e)));
+                }
+                Poll::Ready(None) => *this.finished = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(emit::<T, Out, N>(this.window, this.last_timestamp))
+    }
+}
+
+fn emit<T, Out, const N: usize>(
+    window: &mut ::heapless::Vec<T::Inner, N>,
+    last_timestamp: &mut Option<T::Timestamp>,
+) -> Option<StreamItem<Out>>
+where
+    T: Timestamped,
+    Out: Timestamped<Inner = ::heapless::Vec<T::Inner, N>>,
+    Out::Timestamp: From<T::Timestamp>,
+{
+    let timestamp = last_timestamp.take()?;
+    Some(StreamItem::Value(Out::with_timestamp(
+        take(window),
+        timestamp.into(),
+    )))
+}
@@ -0,0 +1,251 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Interceptors wrapping the input and output of every stage of a chain.
+//!
+//! An [`Interceptor`] observes, and may rewrite or drop, the items crossing stage
+//! boundaries. It is installed once with [`intercept`](InterceptExt::intercept); each
+//! operator applied afterwards through [`stage`](Intercepted::stage) is then wrapped on
+//! both sides, so logging, metrics or policies such as "drop items older than the
+//! watermark" are written once instead of at every call site. Like tower layers,
+//! interceptors compose: a tuple `(outer, inner)` is itself an interceptor, with `outer`
+//! seeing inputs first and outputs last.
+//!
+//! # Behavior
+//!
+//! - [`on_input`](Interceptor::on_input) runs for every item a stage pulls from its
+//!   input, [`on_output`](Interceptor::on_output) for every item it emits
+//! - Returning `None` drops the item at that boundary
+//! - An item leaving one stage crosses the output of that stage and then the input of
+//!   the next
+//! - A stage is a closure given to [`stage`](Intercepted::stage), or any [`Pipe`], such
+//!   as a [`Pipeline`](crate::Pipeline), given to [`stage_with`](Intercepted::stage_with)
+//! - [`Intercepted`] is itself a stream; consume it directly or take the stream out
+//!   with [`into_inner`](Intercepted::into_inner)
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::{HasTimestamp, StreamItem};
+//! use fluxion_stream::intercept::{InterceptExt, Interceptor};
+//! use fluxion_stream::{from_iter, FilterOrderedExt, MapOrderedExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//! use std::fmt::Debug;
+//! use std::sync::{Arc, Mutex};
+//!
+//! #[derive(Clone, Default)]
+//! struct Log(Arc<Mutex<Vec<String>>>);
+//!
+//! impl Interceptor for Log {
+//!     fn on_output<T: HasTimestamp + Debug>(&self, stage: &'static str, item: StreamItem<T>) -> Option<StreamItem<T>> {
+//!         self.0.lock().unwrap().push(format!("{stage}: {item:?}"));
+//!         Some(item)
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let log = Log::default();
+//!
+//! let values: Vec<_> = from_iter::<Sequenced<i32>, _>([1, -2, 3])
+//!     .intercept(log.clone())
+//!     .stage("positive", |s| s.filter_ordered(|x| *x > 0))
+//!     .stage("doubled", |s| s.map_ordered(|x: Sequenced<i32>| Sequenced::new(x.value * 2)))
+//!     .map(|item| item.unwrap().value)
+//!     .collect()
+//!     .await;
+//!
+//! assert_eq!(values, vec![2, 6]);
+//! assert_eq!(log.0.lock().unwrap().len(), 4);
+//! # }
+//! ```
+
+use crate::pipe::Pipe;
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{HasTimestamp, StreamItem};
+use futures::Stream;
+use pin_project::pin_project;
+
+/// Hooks called at the boundaries of every stage of an [`Intercepted`] chain.
+///
+/// Both hooks receive the name the stage was given and the item, and return the item to
+/// pass on, or `None` to drop it. They are generic over the item type because the stages
+/// of a chain may change it; the item's timestamp and `Debug` output are available.
+pub trait Interceptor: Clone {
+    /// Called for every item the stage pulls from its input.
+    fn on_input<T: HasTimestamp + Debug>(
+        &self,
+        stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        let _ = stage;
+        Some(item)
+    }
+
+    /// Called for every item the stage emits.
+    fn on_output<T: HasTimestamp + Debug>(
+        &self,
+        stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        let _ = stage;
+        Some(item)
+    }
+}
+
+impl<A: Interceptor, B: Interceptor> Interceptor for (A, B) {
+    fn on_input<T: HasTimestamp + Debug>(
+        &self,
+        stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        let item = self.0.on_input(stage, item)?;
+        self.1.on_input(stage, item)
+    }
+
+    fn on_output<T: HasTimestamp + Debug>(
+        &self,
+        stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        let item = self.1.on_output(stage, item)?;
+        self.0.on_output(stage, item)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Boundary {
+    Input,
+    Output,
+}
+
+/// A stream passing its items through an [`Interceptor`] at one stage boundary.
+#[pin_project]
+#[derive(Debug)]
+pub struct InterceptedStream<S, I> {
+    #[pin]
+    stream: S,
+    interceptor: I,
+    stage: &'static str,
+    boundary: Boundary,
+}
+
+impl<S, I, T> Stream for InterceptedStream<S, I>
+where
+    S: Stream<Item = StreamItem<T>>,
+    I: Interceptor,
+    T: HasTimestamp + Debug,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(item) = futures::ready!(this.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            let item = match this.boundary {
+                Boundary::Input => this.interceptor.on_input(this.stage, item),
+                Boundary::Output => this.interceptor.on_output(this.stage, item),
+            };
+            if item.is_some() {
+                return Poll::Ready(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+/// A stream with an [`Interceptor`] installed, wrapping every stage added with
+/// [`stage`](Self::stage).
+#[pin_project]
+#[derive(Debug)]
+pub struct Intercepted<S, I> {
+    #[pin]
+    stream: S,
+    interceptor: I,
+}
+
+impl<S, I: Interceptor> Intercepted<S, I> {
+    /// Applies `f` as a stage called `name`, with the interceptor on its input and
+    /// output.
+    pub fn stage<R, F>(self, name: &'static str, f: F) -> Intercepted<InterceptedStream<R, I>, I>
+    where
+        F: FnOnce(InterceptedStream<S, I>) -> R,
+    {
+        self.stage_with(name, f)
+    }
+
+    /// Applies a [`Pipe`], such as a [`Pipeline`](crate::Pipeline), as a stage called
+    /// `name`, with the interceptor on its input and output.
+    pub fn stage_with<P, R>(
+        self,
+        name: &'static str,
+        pipe: P,
+    ) -> Intercepted<InterceptedStream<R, I>, I>
+    where
+        P: Pipe<InterceptedStream<S, I>, Output = R>,
+    {
+        let input = InterceptedStream {
+            stream: self.stream,
+            interceptor: self.interceptor.clone(),
+            stage: name,
+            boundary: Boundary::Input,
+        };
+        Intercepted {
+            stream: InterceptedStream {
+                stream: pipe.apply(input),
+                interceptor: self.interceptor.clone(),
+                stage: name,
+                boundary: Boundary::Output,
+            },
+            interceptor: self.interceptor,
+        }
+    }
+
+    /// Returns the stream, ending the intercepted part of the chain.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Stream, I> Stream for Intercepted<S, I> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// Extension trait providing [`intercept`](InterceptExt::intercept) for every stream.
+pub trait InterceptExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: HasTimestamp + Debug,
+{
+    /// Installs `interceptor` on the stages applied to this stream with
+    /// [`stage`](Intercepted::stage).
+    fn intercept<I: Interceptor>(self, interceptor: I) -> Intercepted<Self, I> {
+        Intercepted {
+            stream: self,
+            interceptor,
+        }
+    }
+}
+
+impl<S, T> InterceptExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: HasTimestamp + Debug,
+{
+}
@@ -0,0 +1,120 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! [`IntoFluxionStream`] for receivers of other channel libraries.
+//!
+//! Async receivers are polled directly. Blocking receivers (`std::sync::mpsc`,
+//! `crossbeam-channel` and kanal, whose receive futures are not `Sync`) are drained by a
+//! dedicated thread that forwards each item through a one-slot async channel, so a slow consumer
+//! still pushes back on the original channel. The thread exits when the original channel
+//! disconnects, or on the next item after the stream is dropped.
+
+use super::IntoFluxionStream;
+use alloc::boxed::Box;
+use core::fmt::Debug;
+use core::pin::Pin;
+use fluxion_core::{StreamItem, Timestamped};
+use futures::{Stream, StreamExt};
+
+macro_rules! impl_into_fluxion_stream {
+    ($(#[$meta:meta])* $receiver:ty => $convert:path) => {
+        $(#[$meta])*
+        impl<T: Send + Sync + 'static> IntoFluxionStream<T> for $receiver {
+            fn into_fluxion_stream(self) -> impl Stream<Item = StreamItem<T>> + Send + Sync {
+                Box::pin($convert(self).map(StreamItem::Value))
+            }
+
+            fn into_fluxion_stream_map<U, F>(
+                self,
+                mut mapper: F,
+            ) -> Pin<Box<dyn Stream<Item = StreamItem<U>> + Send + Sync>>
+            where
+                F: FnMut(T) -> U + 'static + Send + Sync,
+                U: Timestamped + Clone + Debug + Ord + Unpin + 'static + Send + Sync,
+            {
+                Box::pin($convert(self).map(move |value| StreamItem::Value(mapper(value))))
+            }
+        }
+    };
+}
+
+/// Forwards the items returned by the blocking `recv` into an async channel from a
+/// dedicated thread, until `recv` returns `None` or the async side is dropped.
+fn bridge<T: Send + 'static>(
+    mut recv: impl FnMut() -> Option<T> + Send + 'static,
+) -> async_channel::Receiver<T> {
+    let (tx, rx) = async_channel::bounded(1);
+    std::thread::Builder::new()
+        .name("fluxion-channel-bridge".into())
+        .spawn(move || {
+            while let Some(value) = recv() {
+                if tx.send_blocking(value).is_err() {
+                    break;
+                }
+            }
+        })
+        .expect("into_fluxion_stream: failed to spawn channel bridge thread");
+    rx
+}
+
+fn std_mpsc<T: Send + 'static>(rx: std::sync::mpsc::Receiver<T>) -> async_channel::Receiver<T> {
+    bridge(move || rx.recv().ok())
+}
+
+impl_into_fluxion_stream!(std::sync::mpsc::Receiver<T> => std_mpsc);
+
+#[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+fn tokio_mpsc<T>(mut rx: tokio::sync::mpsc::Receiver<T>) -> impl Stream<Item = T> {
+    futures::stream::poll_fn(move |cx| rx.poll_recv(cx))
+}
+
+#[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+fn tokio_unbounded_mpsc<T>(
+    mut rx: tokio::sync::mpsc::UnboundedReceiver<T>,
+) -> impl Stream<Item = T> {
+    futures::stream::poll_fn(move |cx| rx.poll_recv(cx))
+}
+
+impl_into_fluxion_stream!(
+    #[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+    tokio::sync::mpsc::Receiver<T> => tokio_mpsc
+);
+impl_into_fluxion_stream!(
+    #[cfg(all(feature = "runtime-tokio", not(target_arch = "wasm32")))]
+    tokio::sync::mpsc::UnboundedReceiver<T> => tokio_unbounded_mpsc
+);
+
+impl_into_fluxion_stream!(
+    #[cfg(feature = "flume")]
+    flume::Receiver<T> => flume::Receiver::into_stream
+);
+
+#[cfg(feature = "crossbeam-channel")]
+fn crossbeam<T: Send + 'static>(rx: crossbeam_channel::Receiver<T>) -> async_channel::Receiver<T> {
+    bridge(move || rx.recv().ok())
+}
+
+impl_into_fluxion_stream!(
+    #[cfg(feature = "crossbeam-channel")]
+    crossbeam_channel::Receiver<T> => crossbeam
+);
+
+#[cfg(feature = "kanal")]
+fn kanal_sync<T: Send + 'static>(rx: kanal::Receiver<T>) -> async_channel::Receiver<T> {
+    bridge(move || rx.recv().ok())
+}
+
+#[cfg(feature = "kanal")]
+fn kanal_async<T: Send + 'static>(rx: kanal::AsyncReceiver<T>) -> async_channel::Receiver<T> {
+    kanal_sync(rx.to_sync())
+}
+
+impl_into_fluxion_stream!(
+    #[cfg(feature = "kanal")]
+    kanal::Receiver<T> => kanal_sync
+);
+impl_into_fluxion_stream!(
+    #[cfg(feature = "kanal")]
+    kanal::AsyncReceiver<T> => kanal_async
+);
@@ -39,5 +39,92 @@ macro_rules! define_into_fluxion_stream_impl {
                 Box::pin(self.map(move |value| StreamItem::Value(mapper(value))))
             }
         }
+
+        #[cfg(feature = "embassy-sync")]
+        impl<M, T, const N: usize> IntoFluxionStream<T> for embassy_sync::channel::Receiver<'static, M, T, N>
+        where
+            M: embassy_sync::blocking_mutex::raw::RawMutex + 'static + $($bounds)*,
+            T: 'static + $($bounds)*,
+        {
+            fn into_fluxion_stream(self) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                Box::pin(self.map(StreamItem::Value))
+            }
+
+            fn into_fluxion_stream_map<U, F>(
+                self,
+                mut mapper: F,
+            ) -> Pin<Box<dyn Stream<Item = StreamItem<U>> + $($bounds)*>>
+            where
+                F: FnMut(T) -> U + 'static + $($bounds)*,
+                U: Timestamped + Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
+            {
+                Box::pin(self.map(move |value| StreamItem::Value(mapper(value))))
+            }
+        }
+
+        #[cfg(feature = "embassy-sync")]
+        impl<M, T> IntoFluxionStream<T> for &'static embassy_sync::signal::Signal<M, T>
+        where
+            M: embassy_sync::blocking_mutex::raw::RawMutex + 'static + $($bounds)*,
+            T: 'static + $($bounds)*,
+        {
+            fn into_fluxion_stream(self) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                Box::pin(futures::stream::unfold(self, |signal| async move {
+                    Some((StreamItem::Value(signal.wait().await), signal))
+                }))
+            }
+
+            fn into_fluxion_stream_map<U, F>(
+                self,
+                mut mapper: F,
+            ) -> Pin<Box<dyn Stream<Item = StreamItem<U>> + $($bounds)*>>
+            where
+                F: FnMut(T) -> U + 'static + $($bounds)*,
+                U: Timestamped + Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
+            {
+                Box::pin(self.into_fluxion_stream().map(move |item| match item {
+                    StreamItem::Value(value) => StreamItem::Value(mapper(value)),
+                    StreamItem::Error(e) => StreamItem::Error(e),
+                }))
+            }
+        }
+
+        #[cfg(feature = "embassy-sync")]
+        impl<M, T, const CAP: usize, const SUBS: usize, const PUBS: usize> IntoFluxionStream<T>
+            for embassy_sync::pubsub::Subscriber<'static, M, T, CAP, SUBS, PUBS>
+        where
+            M: embassy_sync::blocking_mutex::raw::RawMutex + 'static + $($bounds)*,
+            T: Clone + 'static + $($bounds)*,
+        {
+            fn into_fluxion_stream(self) -> impl Stream<Item = StreamItem<T>> + $($bounds)* {
+                use embassy_sync::pubsub::WaitResult;
+
+                Box::pin(futures::stream::unfold(self, |mut subscriber| async move {
+                    let item = match subscriber.next_message().await {
+                        WaitResult::Message(value) => StreamItem::Value(value),
+                        WaitResult::Lagged(missed) => {
+                            StreamItem::Error(fluxion_core::FluxionError::stream_error(
+                                alloc::format!("pubsub subscriber lagged, {missed} messages missed"),
+                            ))
+                        }
+                    };
+                    Some((item, subscriber))
+                }))
+            }
+
+            fn into_fluxion_stream_map<U, F>(
+                self,
+                mut mapper: F,
+            ) -> Pin<Box<dyn Stream<Item = StreamItem<U>> + $($bounds)*>>
+            where
+                F: FnMut(T) -> U + 'static + $($bounds)*,
+                U: Timestamped + Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
+            {
+                Box::pin(self.into_fluxion_stream().map(move |item| match item {
+                    StreamItem::Value(value) => StreamItem::Value(mapper(value)),
+                    StreamItem::Error(e) => StreamItem::Error(e),
+                }))
+            }
+        }
     };
 }
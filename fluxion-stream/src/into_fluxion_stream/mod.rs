@@ -36,6 +36,66 @@
 //! let stream = rx.into_fluxion_stream_map(|val| Sequenced::new(val));
 //! # }
 //! ```
+//!
+//! # Supported Channels
+//!
+//! On multi-threaded runtimes, receivers of other channel libraries convert the same way,
+//! so the rest of a codebase can keep its channel of choice:
+//!
+//! | Receiver | Requires |
+//! |----------|----------|
+//! | `async_channel::Receiver` | - |
+//! | `std::sync::mpsc::Receiver` | - |
+//! | `tokio::sync::mpsc::Receiver` / `UnboundedReceiver` | `runtime-tokio` |
+//! | `flume::Receiver` | `flume` feature |
+//! | `crossbeam_channel::Receiver` | `crossbeam-channel` feature |
+//! | `kanal::Receiver` / `AsyncReceiver` | `kanal` feature |
+//!
+//! Blocking receivers (`std::sync::mpsc`, crossbeam and kanal) are drained by a
+//! dedicated bridge thread per stream, which hands items over one at a time so the
+//! original channel keeps its backpressure. The thread ends when every sender is
+//! dropped.
+//!
+//! ```rust
+//! use fluxion_stream::IntoFluxionStream;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, rx) = tokio::sync::mpsc::channel::<Sequenced<i32>>(8);
+//! let mut stream = rx.into_fluxion_stream();
+//!
+//! tx.send(Sequenced::new(1)).await.unwrap();
+//! assert_eq!(stream.next().await.unwrap().unwrap().into_inner(), 1);
+//! # }
+//! ```
+//!
+//! # Embassy
+//!
+//! With the `embassy-sync` feature, the primitives embedded firmware already uses
+//! convert on every runtime, including `runtime-embassy`, so an interrupt handler or
+//! driver task can feed a pipeline directly:
+//!
+//! | Source | Emits |
+//! |--------|-------|
+//! | `embassy_sync::channel::Receiver<'static, ..>` | Every received value |
+//! | `&'static embassy_sync::signal::Signal<..>` | The latest signalled value each time it is awaited |
+//! | `embassy_sync::pubsub::Subscriber<'static, ..>` | Every message; a lag becomes a [`StreamProcessingError`](fluxion_core::FluxionError::StreamProcessingError) with the number of missed messages |
+//!
+//! These streams never end, as the primitives have no notion of closing. The raw mutex
+//! must be `Send + Sync` on multi-threaded runtimes, for example
+//! `CriticalSectionRawMutex`.
+//!
+//! ```rust,ignore
+//! use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+//! use embassy_sync::channel::Channel;
+//! use fluxion_stream::IntoFluxionStream;
+//!
+//! static READINGS: Channel<CriticalSectionRawMutex, Reading, 8> = Channel::new();
+//!
+//! let stream = READINGS.receiver().into_fluxion_stream();
+//! ```
 
 #[macro_use]
 mod implementation;
@@ -54,6 +114,13 @@ mod multi_threaded;
 ))]
 pub use multi_threaded::IntoFluxionStream;
 
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod channels;
+
 #[cfg(all(
     not(any(
         all(feature = "runtime-tokio", not(target_arch = "wasm32")),
@@ -0,0 +1,106 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{StreamItem, Timestamped};
+use futures::Stream;
+use pin_project::pin_project;
+
+/// What [`into_values`](super::IntoValuesExt::into_values) does with an error item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Drops the error and keeps emitting values.
+    Skip,
+    /// Panics with the error.
+    Panic,
+    /// Ends the stream at the first error, dropping it.
+    Terminate,
+}
+
+/// Unwraps each value into its inner value, handling errors by an [`ErrorPolicy`].
+#[pin_project]
+pub(super) struct IntoValues<S> {
+    #[pin]
+    stream: S,
+    policy: ErrorPolicy,
+    done: bool,
+}
+
+impl<S> IntoValues<S> {
+    pub(super) fn new(stream: S, policy: ErrorPolicy) -> Self {
+        Self {
+            stream,
+            policy,
+            done: false,
+        }
+    }
+}
+
+impl<S, T> Stream for IntoValues<S>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+{
+    type Item = T::Inner;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.done {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => {
+                    return Poll::Ready(Some(value.into_inner()));
+                }
+                Poll::Ready(Some(StreamItem::Error(error))) => match this.policy {
+                    ErrorPolicy::Skip => {}
+                    ErrorPolicy::Panic => panic!("into_values: stream error: {error}"),
+                    ErrorPolicy::Terminate => *this.done = true,
+                },
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(None)
+    }
+}
+
+macro_rules! define_into_values_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{ErrorPolicy, IntoValues};
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::Stream;
+
+        /// Extension trait providing the [`into_values`](Self::into_values) operator.
+        pub trait IntoValuesExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Strips timestamps and the [`StreamItem`] wrapper, emitting plain inner values.
+            ///
+            /// Errors are skipped, panic or end the stream, as `policy` decides.
+            ///
+            /// # Panics
+            ///
+            /// With [`ErrorPolicy::Panic`], panics when the source emits an error.
+            fn into_values(self, policy: ErrorPolicy) -> impl Stream<Item = T::Inner> + $($bounds)*;
+        }
+
+        impl<T, S> IntoValuesExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn into_values(self, policy: ErrorPolicy) -> impl Stream<Item = T::Inner> + $($bounds)* {
+                IntoValues::new(self, policy)
+            }
+        }
+    };
+}
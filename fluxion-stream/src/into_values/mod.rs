@@ -0,0 +1,80 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Adapter from fluxion streams to plain value streams.
+//!
+//! Pipelines end in code that knows nothing about timestamps or [`StreamItem`]s: a channel
+//! to a UI, a sink, a `futures` combinator. [`into_values`](IntoValuesExt::into_values)
+//! strips both, emitting each value's [`Inner`](fluxion_core::Timestamped::Inner), and
+//! handles errors by an [`ErrorPolicy`] instead of per-call-site `map`/`unwrap`
+//! boilerplate.
+//!
+//! ## Characteristics
+//!
+//! - **[`ErrorPolicy::Skip`]**: Errors are dropped and values keep flowing
+//! - **[`ErrorPolicy::Panic`]**: The first error panics, for pipelines where an error is a bug
+//! - **[`ErrorPolicy::Terminate`]**: The stream ends at the first error; it stays ended
+//!   when polled again
+//! - **Order-preserving**: Values are emitted in the order they arrive
+//!
+//! To log or count errors before they are dropped, put
+//! [`on_error`](crate::OnErrorExt::on_error) in front.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use fluxion_core::{FluxionError, StreamItem};
+//! use fluxion_stream::{ErrorPolicy, IntoValuesExt};
+//! use fluxion_test_utils::{helpers::test_channel_with_errors, sequenced::Sequenced};
+//! use futures::StreamExt;
+//!
+//! # async fn example() {
+//! let (tx, readings) = test_channel_with_errors::<Sequenced<u32>>();
+//!
+//! let mut values = readings.into_values(ErrorPolicy::Terminate);
+//!
+//! tx.unbounded_send(StreamItem::Value(Sequenced::new(7))).unwrap();
+//! tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("sensor lost")))
+//!     .unwrap();
+//! tx.unbounded_send(StreamItem::Value(Sequenced::new(8))).unwrap();
+//!
+//! assert_eq!(values.next().await, Some(7));
+//! assert_eq!(values.next().await, None);
+//! # }
+//! ```
+//!
+//! [`StreamItem`]: fluxion_core::StreamItem
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::ErrorPolicy;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::IntoValuesExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::IntoValuesExt;
@@ -35,13 +35,21 @@
 //! - **[`combine_latest`](CombineLatestExt::combine_latest)**: Emits when any stream emits, combining latest values from all streams
 //! - **[`with_latest_from`](WithLatestFromExt::with_latest_from)**: Samples secondary streams only when primary emits
 //! - **[`ordered_merge`](OrderedStreamExt::ordered_merge)**: Merges multiple streams preserving temporal order
+//! - **[`priority_merge`](PriorityMergeExt::priority_merge)**: Merges streams, emitting the ready item of the highest-priority stream first
+//! - **[`weighted_merge`](WeightedMergeExt::weighted_merge)**: Merges streams in turns, giving each a share of the output proportional to its weight
+//! - **[`as_of_join`](AsOfJoinExt::as_of_join)**: Pairs each item with the latest earlier item of another stream for the same key
+//! - **[`align_by_timestamp`](AlignByTimestampExt::align_by_timestamp)**: Pairs items of two streams whose timestamps are within a tolerance
 //!
 //! ### Filtering Operators
 //!
 //! - **[`emit_when`](EmitWhenExt::emit_when)**: Gates source emissions based on filter stream conditions
 //! - **[`take_latest_when`](TakeLatestWhenExt::take_latest_when)**: Samples source when filter condition is met
 //! - **[`take_while_with`](TakeWhileExt::take_while_with)**: Emits while condition holds, terminates when false
+//! - **[`buffer_when`](BufferWhenExt::buffer_when)**: Holds items while a control stream is closed, releasing them in order when it opens
 //! - **[`filter_ordered`](FilterOrderedExt::filter_ordered)**: Filters items based on predicate
+//! - **[`filter_ordered_async`](FilterOrderedAsyncExt::filter_ordered_async)**: Filters items with an async predicate
+//! - **[`filter_outliers`](FilterOutliersExt::filter_outliers)**: Drops measurements that deviate from a rolling window, or diverts them to a side stream with [`divert_outliers`](FilterOutliersExt::divert_outliers)
+//! - **[`filter_map_ordered`](FilterMapOrderedExt::filter_map_ordered)**: Filters and transforms items in one step
 //! - **[`distinct_until_changed`](DistinctUntilChangedExt::distinct_until_changed)**: Filters consecutive duplicates
 //!
 //! ### Transformation Operators
@@ -49,7 +57,57 @@
 //! - **[`scan_ordered`](ScanOrderedExt::scan_ordered)**: Accumulates state across stream items, emitting intermediate results
 //! - **[`combine_with_previous`](CombineWithPreviousExt::combine_with_previous)**: Pairs each value with previous value
 //! - **[`map_ordered`](MapOrderedExt::map_ordered)**: Transforms each item
+//! - **[`map_batch`](MapBatchExt::map_batch)**: Transforms the items ready upstream in batches, amortizing per-item overhead, or filters them with [`filter_batch`](MapBatchExt::filter_batch)
+//! - **[`map_ordered_blocking`](MapOrderedBlockingExt::map_ordered_blocking)**: Transforms each item on the runtime's blocking thread pool
+//! - **[`par_map_ordered`](ParMapOrderedExt::par_map_ordered)**: Transforms items in parallel on a dedicated worker pool
+//! - **[`try_map_ordered`](TryMapOrderedExt::try_map_ordered)**: Transforms each item with a fallible closure
+//! - **[`dynamic_map`](DynamicConfigExt::dynamic_map)**: Transforms each item with the latest value of a configuration stream, or filters with [`dynamic_filter`](DynamicConfigExt::dynamic_filter)
+//! - **[`then_ordered`](ThenOrderedExt::then_ordered)**: Transforms each item asynchronously, preserving order
+//! - **`call_service`**: Sends each item to a tower `Service`, respecting its readiness, and emits the responses in order (feature `tower`)
+//! - **[`lookup_join`](LookupJoinExt::lookup_join)**: Pairs each item with the cached result of an async lookup of its key
+//! - **[`cache_latest`](CacheLatestExt::cache_latest)**: Passes items through while keeping the latest value per key in a queryable cache
+//! - **[`to_snapshot`](ToSnapshotExt::to_snapshot)**: Keeps the latest value per key and emits the whole map every period, or on a trigger with [`to_snapshot_when`](ToSnapshotExt::to_snapshot_when)
+//! - **[`reorder`](ReorderExt::reorder)**: Restores timestamp order within a bounded buffer of items or time
+//! - **[`resample`](ResampleExt::resample)**: Emits items at a fixed rate of timestamps, holding, interpolating or averaging values
+//! - **[`with_index`](WithIndexExt::with_index)**: Pairs each value with its ordinal index
 //! - **[`start_with`](StartWithExt::start_with)**: Prepends initial values
+//! - **[`start_with_latest_of`](StartWithLatestOfExt::start_with_latest_of)**: Waits for the latest value of another stream and prepends it, seeding pipelines that would stall on a slow configuration stream
+//! - **[`pipe`](PipeExt::pipe)**: Applies a reusable operator chain
+//! - **[`intercept`](InterceptExt::intercept)**: Installs an [`Interceptor`] wrapping the input and output of every following [`stage`](Intercepted::stage), for logging, metrics or policies
+//! - **[`within_memory_budget`](MemoryBudgetExt::within_memory_budget)**: Caps the memory a buffering stage holds with a budget shared across the pipeline
+//! - **[`spill_to_disk`](SpillToDiskExt::spill_to_disk)**: Buffers a backlog for a slow consumer, spilling overflow to disk
+//! - **[`into_boxed`](BoxedExt::into_boxed)**: Erases the stream type into a [`BoxedFluxionStream`]
+//! - **[`into_values`](IntoValuesExt::into_values)**: Strips timestamps and the `StreamItem` wrapper for consumers outside fluxion, skipping errors, panicking or ending at the first one
+//!
+//! ### Windowing Operators
+//!
+//! - **[`window_by_count`](WindowByCountExt::window_by_count)**: Batches items into fixed-size windows, or folds them into an aggregate with [`window_by_count_aggregate`](WindowByCountExt::window_by_count_aggregate)
+//! - **[`window_by_time_aggregate`](WindowByTimeExt::window_by_time_aggregate)**: Aggregates event-time windows, retracting and re-emitting results corrected by late items
+//! - **[`downsample`](DownsampleExt::downsample)**: Summarizes each interval of timestamps into one item, such as an OHLC bar
+//! - **[`window_toggle`](WindowToggleExt::window_toggle)**: Collects items between opening and closing trigger streams, or aggregates them with [`window_toggle_aggregate`](WindowToggleExt::window_toggle_aggregate)
+//! - **[`window_join`](WindowJoinExt::window_join)**: Pairs items of two streams with the same key in the same time window
+//! - **[`match_pattern`](MatchPatternExt::match_pattern)**: Detects ordered sequences of events within a time window
+//! - **[`process_with_timers`](ProcessWithTimersExt::process_with_timers)**: Runs custom stateful logic with [`EventTimers`] that fire when the watermark passes their event time
+//!
+//! ### Constructors
+//!
+//! - **[`backfill`]**: Emits a history stream, then a live stream without the items the history already covered, or deduplicates per key with [`backfill_by_key`]
+//! - **[`from_iter`]**: Emits values from an iterator with increasing timestamps
+//! - **[`from_timestamped_iter`]**: Emits `(value, timestamp)` pairs from an iterator
+//! - **[`generate`]**: Emits the items of an async step function threading a state, until it ends or is cancelled
+//! - **[`repeat`]**: Runs a source built by a factory a fixed number of times
+//! - **[`repeat_when`]**: Runs a source again whenever a companion stream emits after it completes
+//!
+//! ### Terminal Operators
+//!
+//! - **[`first`](QueryExt::first)**, **[`last`](QueryExt::last)**, **[`nth`](QueryExt::nth)**, **[`any_value`](QueryExt::any_value)**, **[`all_values`](QueryExt::all_values)**, **[`contains`](QueryExt::contains)**: Resolve to a single answer, stopping as soon as it is known, with stream errors as `Err`
+//! - **[`sequence_equal`](SequenceEqualExt::sequence_equal)**: Resolves to whether two streams emit equal sequences, within a timestamp tolerance, or to their first [`Divergence`] with [`sequence_diff`](SequenceEqualExt::sequence_diff)
+//!
+//! ### Fixed-Capacity Operators
+//!
+//! With the `heapless` feature, the `heapless` module provides variants of
+//! `ordered_merge`, `combine_latest` and `window_by_count` whose capacity is a const
+//! generic and which never allocate, for embedded targets without a heap.
 //!
 //! # Temporal Ordering Explained
 //!
@@ -140,6 +198,39 @@
 //! - No blocking operations in hot paths
 //! - Efficient polling with `futures::StreamExt`
 //!
+//! ## Fairness
+//!
+//! [`ordered_merge`], [`priority_merge`](PriorityMergeExt::priority_merge),
+//! [`weighted_merge`](WeightedMergeExt::weighted_merge),
+//! [`share`](ShareExt::share), [`partition`](PartitionExt::partition),
+//! [`to_state_cell`](StateCellExt::to_state_cell) and
+//! [`materialize`](MaterializeExt::materialize) yield to the executor after producing
+//! [`DEFAULT_COOP_BUDGET`] items in a row, so sources that are always ready cannot starve
+//! other tasks on the same thread.
+//! [`ordered_merge_with_budget`](OrderedStreamExt::ordered_merge_with_budget),
+//! [`share_with_budget`](ShareExt::share_with_budget) and
+//! [`partition_with_budget`](PartitionExt::partition_with_budget) tune or disable it for
+//! one operator.
+//!
+//! ## Ordering Mode
+//!
+//! [`ordered_merge`] and the operators built on it emit the ready item with the smallest
+//! timestamp first. [`ordered_merge_with_mode`](OrderedStreamExt::ordered_merge_with_mode),
+//! [`combine_latest_with_mode`](CombineLatestExt::combine_latest_with_mode),
+//! [`with_latest_from_with_mode`](WithLatestFromExt::with_latest_from_with_mode),
+//! [`emit_when_with_mode`](EmitWhenExt::emit_when_with_mode),
+//! [`take_latest_when_with_mode`](TakeLatestWhenExt::take_latest_when_with_mode),
+//! [`take_while_with_with_mode`](TakeWhileExt::take_while_with_with_mode) and
+//! [`merge_with_with_mode`](MergedStream::merge_with_with_mode) take an
+//! [`OrderingMode`]; [`OrderingMode::Unordered`] emits items without waiting for the other
+//! inputs, trading temporal correctness for latency. The mode is an argument of the
+//! operator, so the same pipeline code can be built in either mode and pipelines in one
+//! process do not affect each other. All other merging operators, including the
+//! timestamp joins and the fixed-capacity `heapless` operators, always merge in timestamp
+//! order.
+//! To tolerate bounded disorder at lower latency, merge in arrival order and restore order
+//! within a window with [`reorder`](ReorderExt::reorder).
+//!
 //! # Return Type Patterns
 //!
 //! Fluxion operators use two different return type patterns, each chosen for specific
@@ -684,18 +775,86 @@
 
 extern crate alloc;
 
+fluxion_runtime::assert_runtime_features!();
+
+#[cfg(not(any(feature = "alloc", feature = "std")))]
+compile_error!(
+    "`fluxion-stream` needs an allocator: enable the `alloc` or `std` feature, or a runtime feature"
+);
+
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub mod ab_route;
+pub mod align_by_timestamp;
+pub mod as_of_join;
+pub mod backfill;
+pub mod boxed;
+pub mod buffer_when;
+pub mod cache_latest;
+#[cfg(feature = "tower")]
+pub mod call_service;
 pub mod combine_latest;
 pub mod combine_with_previous;
+mod coop;
 pub mod distinct_until_changed;
 pub mod distinct_until_changed_by;
+pub mod downsample;
+pub mod dynamic_config;
 pub mod emit_when;
+pub mod event_timers;
+pub mod filter_map_ordered;
 pub mod filter_ordered;
+pub mod filter_ordered_async;
+pub mod filter_outliers;
+pub mod finalize;
+pub mod fork;
+pub mod from_iter;
+pub mod generate;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+pub mod intercept;
 pub mod into_fluxion_stream;
+pub mod into_values;
 mod logging;
+pub mod lookup_join;
+pub mod map_batch;
 pub mod map_ordered;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub mod map_ordered_blocking;
+pub mod match_pattern;
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub mod materialized_view;
+pub mod memory_budget;
 pub mod merge_with;
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub mod named;
 pub mod on_error;
 pub mod ordered_merge;
+mod ordering;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub mod par_map_ordered;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
@@ -703,9 +862,16 @@ pub mod ordered_merge;
     target_arch = "wasm32"
 ))]
 pub mod partition;
+pub mod pipe;
 pub mod prelude;
+pub mod priority_merge;
+pub mod query;
+pub mod reorder;
+pub mod repeat;
+pub mod resample;
 pub mod sample_ratio;
 pub mod scan_ordered;
+pub mod sequence_equal;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
@@ -714,26 +880,109 @@ pub mod scan_ordered;
 ))]
 pub mod share;
 pub mod skip_items;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub mod spill_to_disk;
 pub mod start_with;
+pub mod start_with_latest_of;
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub mod state_cell;
 pub mod take_items;
 pub mod take_latest_when;
 pub mod take_while_with;
 pub mod tap;
+pub mod then_ordered;
+pub mod to_snapshot;
+#[cfg(feature = "tracing")]
+pub mod traced;
+pub mod try_map_ordered;
 pub mod types;
+pub mod weighted_merge;
 pub mod window_by_count;
+pub mod window_by_time;
+pub mod window_join;
+pub mod window_toggle;
+pub mod with_index;
 pub mod with_latest_from;
 
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use ab_route::{AbRouteExt, RoutedStream, SplitRatio};
+pub use align_by_timestamp::AlignByTimestampExt;
+pub use as_of_join::AsOfJoinExt;
+pub use backfill::{backfill, backfill_by_key};
+pub use boxed::{BoxedExt, BoxedFluxionStream, LocalBoxedFluxionStream};
+pub use buffer_when::BufferWhenExt;
+pub use cache_latest::{CacheLatestExt, LatestCache};
+#[cfg(feature = "tower")]
+pub use call_service::CallServiceExt;
 pub use combine_latest::CombineLatestExt;
 pub use combine_with_previous::CombineWithPreviousExt;
+pub use coop::DEFAULT_COOP_BUDGET;
 pub use distinct_until_changed::DistinctUntilChangedExt;
 pub use distinct_until_changed_by::DistinctUntilChangedByExt;
+pub use downsample::{Aggregator, DownsampleExt, OhlcBar};
+pub use dynamic_config::DynamicConfigExt;
 pub use emit_when::EmitWhenExt;
+pub use event_timers::{EventTimers, ProcessWithTimersExt, TimerId};
+pub use filter_map_ordered::FilterMapOrderedExt;
 pub use filter_ordered::FilterOrderedExt;
+pub use filter_ordered_async::FilterOrderedAsyncExt;
+pub use filter_outliers::FilterOutliersExt;
+pub use finalize::{FinalizeExt, Termination};
+pub use fork::{ForkExt, ForkedStream};
+pub use from_iter::{from_iter, from_timestamped_iter};
+pub use generate::{generate, generate_cancellable};
+pub use intercept::{InterceptExt, Intercepted, InterceptedStream, Interceptor};
 pub use into_fluxion_stream::IntoFluxionStream;
+pub use into_values::{ErrorPolicy, IntoValuesExt};
+pub use lookup_join::LookupJoinExt;
+pub use map_batch::MapBatchExt;
 pub use map_ordered::MapOrderedExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use map_ordered_blocking::MapOrderedBlockingExt;
+pub use match_pattern::{MatchPatternExt, Pattern, SkipStrategy};
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use materialized_view::{MaterializeExt, MaterializedView, ViewChange};
+pub use memory_budget::{BudgetedStream, MemoryBudget, MemoryBudgetExt, MemoryPolicy};
 pub use merge_with::MergedStream;
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use named::{NamedExt, PipelineRegistry};
 pub use on_error::OnErrorExt;
 pub use ordered_merge::OrderedStreamExt;
+pub use ordering::OrderingMode;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use par_map_ordered::ParMapOrderedExt;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
@@ -741,8 +990,15 @@ pub use ordered_merge::OrderedStreamExt;
     target_arch = "wasm32"
 ))]
 pub use partition::{PartitionExt, PartitionedStream};
+pub use pipe::{Pipe, PipeExt, Pipeline};
+pub use priority_merge::PriorityMergeExt;
+pub use query::QueryExt;
+pub use reorder::ReorderExt;
+pub use repeat::{repeat, repeat_when};
+pub use resample::ResampleExt;
 pub use sample_ratio::SampleRatioExt;
 pub use scan_ordered::ScanOrderedExt;
+pub use sequence_equal::SequenceEqualExt;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
@@ -751,11 +1007,38 @@ pub use scan_ordered::ScanOrderedExt;
 ))]
 pub use share::{FluxionShared, ShareExt};
 pub use skip_items::SkipItemsExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use spill_to_disk::{SpillCodec, SpillConfig, SpillToDiskExt};
 pub use start_with::StartWithExt;
+pub use start_with_latest_of::{StartWithLatestOf, StartWithLatestOfExt};
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use state_cell::{StateCell, StateCellExt};
 pub use take_items::TakeItemsExt;
 pub use take_latest_when::TakeLatestWhenExt;
 pub use take_while_with::TakeWhileExt;
 pub use tap::TapExt;
-pub use types::{CombinedState, WithPrevious};
+pub use then_ordered::ThenOrderedExt;
+pub use to_snapshot::ToSnapshotExt;
+#[cfg(feature = "tracing")]
+pub use traced::{TraceSpanExt, TracedExt};
+pub use try_map_ordered::TryMapOrderedExt;
+pub use types::{
+    Alignment, CombinedState, Divergence, Indexed, Interpolate, JoinWindow, OutlierTest,
+    ReorderWindow, ResampleStrategy, SpanRatio, WindowSpan, WindowUpdate, WithPrevious,
+};
+pub use weighted_merge::WeightedMergeExt;
 pub use window_by_count::WindowByCountExt;
+pub use window_by_time::WindowByTimeExt;
+pub use window_join::WindowJoinExt;
+pub use window_toggle::WindowToggleExt;
+pub use with_index::WithIndexExt;
 pub use with_latest_from::WithLatestFromExt;
@@ -2,33 +2,91 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+//! Logging macros used by the operators of this crate.
+//!
+//! Each level macro accepts either a plain format string or a structured form that
+//! names the emitting operator and attaches fields:
+//!
+//! ```ignore
+//! warn!("plain message {}", value);
+//! warn!(operator = "emit_when", stream = index, timestamp = ts; "unexpected stream index");
+//! ```
+//!
+//! - With the `tracing` feature, events are emitted through `tracing` with the target
+//!   `fluxion_stream::<operator>`, so levels can be controlled per operator
+//!   (e.g. `RUST_LOG=fluxion_stream::emit_when=debug`). Fields are recorded with `Debug`.
+//! - With `defmt` (and without `tracing`), events go to the matching defmt level as
+//!   `fluxion_stream::<operator>: <message>`, with both parts interned so only their
+//!   indices cross the wire. The message is sent as its literal text; fields and format
+//!   arguments are generic and not `defmt::Format`, so they are dropped.
+//! - Without `tracing` or `defmt` but with `std`, errors and warnings go to stderr and
+//!   info to stdout, with the fields appended as `name=value`. Debug and trace are
+//!   discarded.
+//! - In `no_std` builds every macro is a no-op that still type-checks its arguments.
+
 #[cfg(feature = "tracing")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! error {
-    ($($arg:tt)*) => {{
-        tracing::error!($($arg)*);
+macro_rules! __fluxion_log {
+    ($level:ident, operator = $op:literal $(, $field:ident = $value:expr)* ; $($arg:tt)+) => {{
+        tracing::event!(
+            target: concat!("fluxion_stream::", $op),
+            tracing::Level::$level,
+            operator = $op,
+            $($field = ?$value,)*
+            $($arg)+
+        );
+    }};
+    ($level:ident, $($arg:tt)+) => {{
+        tracing::event!(tracing::Level::$level, $($arg)+);
     }};
 }
 
-#[cfg(feature = "tracing")]
+// With defmt (embedded targets), unless tracing is enabled
+#[cfg(all(feature = "defmt", not(feature = "tracing")))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! warn {
-    ($($arg:tt)*) => {{
-        tracing::warn!($($arg)*);
+macro_rules! __fluxion_log {
+    ($level:ident, operator = $op:literal $(, $field:ident = $value:expr)* ; $msg:literal $(, $arg:expr)* $(,)?) => {{
+        $(let _ = &$value;)*
+        $(let _ = &$arg;)*
+        $crate::__fluxion_defmt!(
+            $level,
+            "fluxion_stream::{=istr}: {=istr}",
+            defmt::intern!($op),
+            defmt::intern!($msg)
+        );
+    }};
+    ($level:ident, $($arg:tt)+) => {{
+        $crate::__fluxion_defmt!($level, $($arg)+);
     }};
 }
 
-#[cfg(feature = "tracing")]
+#[cfg(all(feature = "defmt", not(feature = "tracing")))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! info {
-    ($($arg:tt)*) => {{
-        tracing::info!($($arg)*);
-    }};
+macro_rules! __fluxion_defmt {
+    (ERROR, $($arg:tt)+) => {
+        defmt::error!($($arg)+)
+    };
+    (WARN, $($arg:tt)+) => {
+        defmt::warn!($($arg)+)
+    };
+    (INFO, $($arg:tt)+) => {
+        defmt::info!($($arg)+)
+    };
+    (DEBUG, $($arg:tt)+) => {
+        defmt::debug!($($arg)+)
+    };
+    (TRACE, $($arg:tt)+) => {
+        defmt::trace!($($arg)+)
+    };
 }
 
 // With std available (runtime features enabled)
 #[cfg(all(
     not(feature = "tracing"),
+    not(feature = "defmt"),
     any(
         feature = "runtime-tokio",
         feature = "runtime-smol",
@@ -36,31 +94,25 @@ macro_rules! info {
         target_arch = "wasm32"
     )
 ))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! error {
-    ($($arg:tt)*) => {{
-        eprintln!($($arg)*);
+macro_rules! __fluxion_log {
+    ($level:ident, operator = $op:literal $(, $field:ident = $value:expr)* ; $($arg:tt)+) => {{
+        $crate::__fluxion_print!(
+            $level,
+            concat!("fluxion_stream::", $op, ": {}" $(, " ", stringify!($field), "={:?}")*),
+            format_args!($($arg)+)
+            $(, $value)*
+        );
     }};
-}
-
-#[cfg(all(
-    not(feature = "tracing"),
-    any(
-        feature = "runtime-tokio",
-        feature = "runtime-smol",
-        feature = "runtime-async-std",
-        target_arch = "wasm32"
-    )
-))]
-#[macro_export]
-macro_rules! warn {
-    ($($arg:tt)*) => {{
-        eprintln!($($arg)*);
+    ($level:ident, $($arg:tt)+) => {{
+        $crate::__fluxion_print!($level, $($arg)+);
     }};
 }
 
 #[cfg(all(
     not(feature = "tracing"),
+    not(feature = "defmt"),
     any(
         feature = "runtime-tokio",
         feature = "runtime-smol",
@@ -68,16 +120,29 @@ macro_rules! warn {
         target_arch = "wasm32"
     )
 ))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! info {
-    ($($arg:tt)*) => {{
-        println!($($arg)*);
+macro_rules! __fluxion_print {
+    (ERROR, $($arg:tt)+) => {{
+        eprintln!($($arg)+);
+    }};
+    (WARN, $($arg:tt)+) => {{
+        eprintln!($($arg)+);
+    }};
+    (INFO, $($arg:tt)+) => {{
+        println!($($arg)+);
+    }};
+    ($level:ident, $($arg:tt)+) => {{
+        if false {
+            let _ = format_args!($($arg)+);
+        }
     }};
 }
 
 // In no_std mode (no runtime features), logging is a no-op
 #[cfg(all(
     not(feature = "tracing"),
+    not(feature = "defmt"),
     not(any(
         feature = "runtime-tokio",
         feature = "runtime-smol",
@@ -85,37 +150,55 @@ macro_rules! info {
         target_arch = "wasm32"
     ))
 ))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fluxion_log {
+    ($level:ident, operator = $op:literal $(, $field:ident = $value:expr)* ; $($arg:tt)+) => {{
+        if false {
+            let _ = format_args!($($arg)+);
+            $(let _ = &$value;)*
+        }
+    }};
+    ($level:ident, $($arg:tt)+) => {{
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! error {
-    ($($arg:tt)*) => {{}};
+    ($($arg:tt)+) => {
+        $crate::__fluxion_log!(ERROR, $($arg)+)
+    };
 }
 
-#[cfg(all(
-    not(feature = "tracing"),
-    not(any(
-        feature = "runtime-tokio",
-        feature = "runtime-smol",
-        feature = "runtime-async-std",
-        target_arch = "wasm32"
-    ))
-))]
 #[macro_export]
 macro_rules! warn {
-    ($($arg:tt)*) => {{}};
+    ($($arg:tt)+) => {
+        $crate::__fluxion_log!(WARN, $($arg)+)
+    };
 }
 
-#[cfg(all(
-    not(feature = "tracing"),
-    not(any(
-        feature = "runtime-tokio",
-        feature = "runtime-smol",
-        feature = "runtime-async-std",
-        target_arch = "wasm32"
-    ))
-))]
 #[macro_export]
 macro_rules! info {
-    ($($arg:tt)*) => {{}};
+    ($($arg:tt)+) => {
+        $crate::__fluxion_log!(INFO, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::__fluxion_log!(DEBUG, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::__fluxion_log!(TRACE, $($arg)+)
+    };
 }
 
 // Note: consider adding lightweight metrics hooks here later
@@ -0,0 +1,132 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_lookup_join_impl {
+    ($($bounds:tt)*) => {
+        use alloc::boxed::Box;
+        use alloc::collections::BTreeMap;
+        use core::error::Error;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::ops::Sub;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, FluxionError, StreamItem};
+        use futures::future::{ready, Either};
+        use futures::{Stream, StreamExt};
+
+        pub trait LookupJoinExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Pairs each value with the result of an async `lookup` of its key, caching
+            /// results for `ttl` and running up to `limit` lookups at once.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is 0.
+            fn lookup_join<Out, K, V, E, D, Fut>(
+                self,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+                lookup: impl Fn(K) -> Fut + $($bounds)* 'static,
+                ttl: D,
+                limit: usize,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                Self: $($bounds)* 'static,
+                K: Ord + Clone + $($bounds)* 'static,
+                V: Clone + $($bounds)* 'static,
+                E: Error + Send + Sync + 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Fut: Future<Output = Result<V, E>> + $($bounds)* 'static,
+                Out: Fluxion<Inner = (T::Inner, V)>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<S, T> LookupJoinExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn lookup_join<Out, K, V, E, D, Fut>(
+                self,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+                lookup: impl Fn(K) -> Fut + $($bounds)* 'static,
+                ttl: D,
+                limit: usize,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                Self: $($bounds)* 'static,
+                K: Ord + Clone + $($bounds)* 'static,
+                V: Clone + $($bounds)* 'static,
+                E: Error + Send + Sync + 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Fut: Future<Output = Result<V, E>> + $($bounds)* 'static,
+                Out: Fluxion<Inner = (T::Inner, V)>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                assert!(limit > 0, "lookup_join requires a limit of at least 1");
+
+                // Entries are timestamped with the item whose lookup produced them
+                let cache = Arc::new(Mutex::new(BTreeMap::<K, (T::Timestamp, V)>::new()));
+                let is_fresh = move |fetched: T::Timestamp, now: T::Timestamp| {
+                    now <= fetched || now - fetched <= ttl
+                };
+
+                Box::pin(
+                    self.map(move |item| match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let inner = value.into_inner();
+                            let item_key = key(&inner);
+
+                            let cached = cache
+                                .lock()
+                                .get(&item_key)
+                                .filter(|(fetched, _)| is_fresh(*fetched, timestamp))
+                                .map(|(_, looked_up)| looked_up.clone());
+
+                            match cached {
+                                Some(looked_up) => Either::Left(ready(StreamItem::Value(
+                                    Out::with_timestamp((inner, looked_up), timestamp.into()),
+                                ))),
+                                None => {
+                                    let cache = Arc::clone(&cache);
+                                    let pending = lookup(item_key.clone());
+
+                                    Either::Right(Either::Left(async move {
+                                        match pending.await {
+                                            Ok(looked_up) => {
+                                                let mut cache = cache.lock();
+                                                cache.retain(|_, (fetched, _)| {
+                                                    is_fresh(*fetched, timestamp)
+                                                });
+                                                cache.insert(item_key, (timestamp, looked_up.clone()));
+
+                                                StreamItem::Value(Out::with_timestamp(
+                                                    (inner, looked_up),
+                                                    timestamp.into(),
+                                                ))
+                                            }
+                                            Err(e) => StreamItem::Error(FluxionError::wrap(e)),
+                                        }
+                                    }))
+                                }
+                            }
+                        }
+                        StreamItem::Error(e) => Either::Right(Either::Right(ready(StreamItem::Error(e)))),
+                    })
+                    .buffered(limit),
+                )
+            }
+        }
+    };
+}
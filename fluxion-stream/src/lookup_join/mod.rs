@@ -0,0 +1,90 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Enrichment join backed by an async lookup and a cache.
+//!
+//! [`lookup_join`](LookupJoinExt::lookup_join) extracts a key from each value, resolves it
+//! with an async `lookup` such as a database query or an HTTP request, and emits the value
+//! paired with the result. Results are cached per key, so a burst of items for the same
+//! key costs a single lookup.
+//!
+//! # Behavior
+//!
+//! - Each value is emitted as `(value, looked up)` with its own timestamp, in source order
+//! - Up to `limit` lookups run at once; the operator stops pulling from the source until
+//!   the oldest one completes
+//! - The TTL is measured on item timestamps: a cached result serves items at most `ttl`
+//!   after the item that looked it up, later items look the key up again
+//! - Items are checked against the cache when they are pulled, so items for a key whose
+//!   lookup is still running start a lookup of their own
+//! - A failed lookup is emitted as [`FluxionError::Wrapped`](fluxion_core::FluxionError::Wrapped)
+//!   in place of the value and is not cached; the lookup error is its source
+//! - Upstream errors are passed through unchanged and keep their position
+//!
+//! # See Also
+//!
+//! - [`then_ordered_concurrent`](crate::ThenOrderedExt::then_ordered_concurrent) - Async
+//!   transformation without caching
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::LookupJoinExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! async fn fetch_user(id: u32) -> Result<String, std::io::Error> {
+//!     Ok(format!("user-{id}"))
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, clicks) = test_channel::<Sequenced<u32>>();
+//!
+//! let mut enriched = clicks.lookup_join::<Sequenced<_>, _, _, _, _, _>(
+//!     |user_id| *user_id,
+//!     fetch_user,
+//!     100u64, // reuse a lookup for items up to 100 ticks later
+//!     4,
+//! );
+//!
+//! tx.unbounded_send((7, 1).into()).unwrap();
+//!
+//! let click = unwrap_value(Some(unwrap_stream(&mut enriched, 500).await));
+//! assert_eq!(click.value, (7, "user-7".to_string()));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::LookupJoinExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::LookupJoinExt;
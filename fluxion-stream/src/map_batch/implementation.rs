@@ -0,0 +1,158 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::StreamItem;
+use futures::Stream;
+use pin_project::pin_project;
+
+/// Collects the items that are ready upstream into a batch, hands it to `f` together with
+/// an output buffer, and emits what `f` put into the output one item at a time.
+#[pin_project]
+pub(super) struct BatchStream<S, T, U, F> {
+    #[pin]
+    stream: S,
+    f: F,
+    max_batch: usize,
+    input: Vec<StreamItem<T>>,
+    output: Vec<StreamItem<U>>,
+    ready: VecDeque<StreamItem<U>>,
+    done: bool,
+}
+
+impl<S, T, U, F> BatchStream<S, T, U, F> {
+    pub(super) fn new(stream: S, max_batch: usize, f: F) -> Self {
+        assert!(max_batch >= 1, "map_batch: batch size must be at least 1");
+
+        Self {
+            stream,
+            f,
+            max_batch,
+            input: Vec::with_capacity(max_batch),
+            output: Vec::with_capacity(max_batch),
+            ready: VecDeque::with_capacity(max_batch),
+            done: false,
+        }
+    }
+}
+
+impl<S, T, U, F> Stream for BatchStream<S, T, U, F>
+where
+    S: Stream<Item = StreamItem<T>>,
+    F: FnMut(&mut Vec<StreamItem<T>>, &mut Vec<StreamItem<U>>),
+{
+    type Item = StreamItem<U>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(item) = this.ready.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+
+            // Take whatever is ready without waiting for a full batch
+            while !*this.done && this.input.len() < *this.max_batch {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => this.input.push(item),
+                    Poll::Ready(None) => *this.done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            if this.input.is_empty() {
+                return if *this.done {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                };
+            }
+
+            (this.f)(this.input, this.output);
+            this.input.clear();
+            this.ready.extend(this.output.drain(..));
+        }
+    }
+}
+
+macro_rules! define_map_batch_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::BatchStream;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::Stream;
+
+        pub trait MapBatchExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Transforms the items in batches of up to `max_batch` items.
+            ///
+            /// Every call of `f` receives the items that were ready upstream, errors
+            /// included, and an empty output buffer. `f` moves or transforms them into the
+            /// output, which is emitted in order. Items it leaves in the input are dropped.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `max_batch` is zero.
+            fn map_batch<U, F>(self, max_batch: usize, f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(&mut Vec<StreamItem<T>>, &mut Vec<StreamItem<U>>) + $($bounds)* 'static;
+
+            /// Filters the items in batches of up to `max_batch` items.
+            ///
+            /// Every call of `f` receives the items that were ready upstream, errors
+            /// included, and removes the ones to drop, such as with [`Vec::retain`]. The
+            /// items left in the batch are emitted in order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `max_batch` is zero.
+            fn filter_batch<F>(self, max_batch: usize, f: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                Self: $($bounds)* 'static,
+                F: FnMut(&mut Vec<StreamItem<T>>) + $($bounds)* 'static;
+        }
+
+        impl<S, T> MapBatchExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn map_batch<U, F>(self, max_batch: usize, f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(&mut Vec<StreamItem<T>>, &mut Vec<StreamItem<U>>) + $($bounds)* 'static,
+            {
+                BatchStream::new(self, max_batch, f)
+            }
+
+            fn filter_batch<F>(self, max_batch: usize, mut f: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                Self: $($bounds)* 'static,
+                F: FnMut(&mut Vec<StreamItem<T>>) + $($bounds)* 'static,
+            {
+                BatchStream::new(self, max_batch, move |batch: &mut Vec<StreamItem<T>>, output: &mut Vec<StreamItem<T>>| {
+                    f(batch);
+                    output.append(batch);
+                })
+            }
+        }
+    };
+}
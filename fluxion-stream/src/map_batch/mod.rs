@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Batched map and filter operators for throughput-critical stages.
+//!
+//! [`map_ordered`](crate::MapOrderedExt::map_ordered) and
+//! [`filter_ordered`](crate::FilterOrderedExt::filter_ordered) call their closure once
+//! per item. [`map_batch`](MapBatchExt::map_batch) and
+//! [`filter_batch`](MapBatchExt::filter_batch) instead hand the closure every item that
+//! is ready upstream at once, as a `&mut Vec<StreamItem<T>>`, so that per-item overhead
+//! is paid once per batch and the closure can run tight, vectorizable loops over it.
+//!
+//! ## Characteristics
+//!
+//! - **No added latency**: A batch holds the items ready when the stream is polled, up to
+//!   `max_batch`; the operator never waits for a batch to fill
+//! - **Reused buffers**: The batch and output vectors keep their allocations across calls
+//! - **Order-preserving**: Output items are emitted in the order the closure produced them
+//! - **Errors in batches**: Errors are part of the batch, so the closure decides whether
+//!   to pass them on
+//!
+//! ## Example
+//!
+//! ```rust
+//! use fluxion_core::{HasTimestamp, StreamItem};
+//! use fluxion_stream::{IntoFluxionStream, MapBatchExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # async fn example() {
+//! let (tx, rx) = async_channel::unbounded();
+//!
+//! let mut stream = rx
+//!     .into_fluxion_stream()
+//!     .filter_batch(64, |batch: &mut Vec<StreamItem<Sequenced<i32>>>| {
+//!         batch.retain(|item| matches!(item, StreamItem::Value(value) if value.value % 2 == 0))
+//!     })
+//!     .map_batch(64, |batch, output| {
+//!         output.extend(batch.drain(..).map(|item| {
+//!             item.map(|value| Sequenced::with_timestamp(value.value * 10, value.timestamp()))
+//!         }))
+//!     });
+//!
+//! for n in 1..=4 {
+//!     tx.try_send(Sequenced::new(n)).unwrap();
+//! }
+//! drop(tx);
+//!
+//! assert_eq!(stream.next().await.unwrap().unwrap().into_inner(), 20);
+//! assert_eq!(stream.next().await.unwrap().unwrap().into_inner(), 40);
+//! assert!(stream.next().await.is_none());
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::MapBatchExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::MapBatchExt;
@@ -0,0 +1,90 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::ThenOrderedExt;
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use fluxion_core::{Fluxion, StreamItem};
+use fluxion_runtime::spawner::BlockingSpawner;
+use futures::Stream;
+
+pub(crate) use fluxion_runtime::default_runtime::DefaultSpawner as DefaultBlockingSpawner;
+
+pub trait MapOrderedBlockingExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: Fluxion,
+    T::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+    T::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+{
+    /// Transforms each value on the blocking thread pool, one value at a time.
+    fn map_ordered_blocking<U, F>(self, f: F) -> impl Stream<Item = StreamItem<U>> + Send + Sync
+    where
+        Self: Unpin + Send + Sync + 'static,
+        U: Fluxion,
+        U::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+        U::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static;
+
+    /// Transforms each value on the blocking thread pool, running up to `limit`
+    /// transformations at once while emitting results in source order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn map_ordered_blocking_concurrent<U, F>(
+        self,
+        limit: usize,
+        f: F,
+    ) -> impl Stream<Item = StreamItem<U>> + Send + Sync
+    where
+        Self: Unpin + Send + Sync + 'static,
+        U: Fluxion,
+        U::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+        U::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static;
+}
+
+impl<S, T> MapOrderedBlockingExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Fluxion,
+    T::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+    T::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+{
+    fn map_ordered_blocking<U, F>(self, f: F) -> impl Stream<Item = StreamItem<U>> + Send + Sync
+    where
+        Self: Unpin + Send + Sync + 'static,
+        U: Fluxion,
+        U::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+        U::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static,
+    {
+        self.map_ordered_blocking_concurrent(1, f)
+    }
+
+    fn map_ordered_blocking_concurrent<U, F>(
+        self,
+        limit: usize,
+        f: F,
+    ) -> impl Stream<Item = StreamItem<U>> + Send + Sync
+    where
+        Self: Unpin + Send + Sync + 'static,
+        U: Fluxion,
+        U::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+        U::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static,
+    {
+        assert!(
+            limit > 0,
+            "map_ordered_blocking_concurrent requires a limit of at least 1"
+        );
+
+        let spawner = DefaultBlockingSpawner::default();
+        let f = Arc::new(f);
+        self.then_ordered_concurrent(limit, move |value| {
+            let f = Arc::clone(&f);
+            spawner.spawn_blocking(move || f(value))
+        })
+    }
+}
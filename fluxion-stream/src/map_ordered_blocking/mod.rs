@@ -0,0 +1,64 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Transformation operator that runs the closure off the async executor.
+//!
+//! [`map_ordered_blocking`](MapOrderedBlockingExt::map_ordered_blocking) is
+//! [`map_ordered`](crate::MapOrderedExt::map_ordered) for CPU-heavy or blocking
+//! closures: each value is transformed on the runtime's blocking thread pool through
+//! its [`BlockingSpawner`](fluxion_runtime::spawner::BlockingSpawner), so the executor
+//! threads stay free to drive other streams.
+//! [`map_ordered_blocking_concurrent`](MapOrderedBlockingExt::map_ordered_blocking_concurrent)
+//! transforms up to `limit` values in parallel.
+//!
+//! | Runtime | Blocking pool |
+//! |---------|---------------|
+//! | Tokio | `tokio::task::spawn_blocking` |
+//! | smol | `smol::unblock` |
+//! | async-std | `async_std::task::spawn_blocking` |
+//!
+//! Only available with a multi-threaded runtime; WASM and Embassy have no thread to
+//! offload to.
+//!
+//! # Behavior
+//!
+//! - Results are emitted strictly in source order, like
+//!   [`then_ordered`](crate::ThenOrderedExt::then_ordered)
+//! - With `limit` transformations in flight, the operator stops pulling from the source
+//!   until the oldest one completes
+//! - Errors are passed through unchanged and keep their position relative to the values
+//! - A panic in the closure is resumed on the task polling the stream
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::MapOrderedBlockingExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//!
+//! fn checksum(data: &[u8]) -> u32 {
+//!     data.iter().fold(0u32, |acc, &b| acc.rotate_left(5) ^ u32::from(b))
+//! }
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<Vec<u8>>>();
+//!
+//! let mut checksums = stream.map_ordered_blocking_concurrent(4, |item: Sequenced<Vec<u8>>| {
+//!     Sequenced::with_timestamp(checksum(&item.value), item.timestamp())
+//! });
+//!
+//! tx.unbounded_send((vec![1, 2, 3], 1).into()).unwrap();
+//!
+//! let first = unwrap_value(Some(unwrap_stream(&mut checksums, 500).await));
+//! assert_eq!(first.value, checksum(&[1, 2, 3]));
+//! # }
+//! ```
+
+mod implementation;
+
+pub(crate) use implementation::DefaultBlockingSpawner;
+pub use implementation::MapOrderedBlockingExt;
@@ -0,0 +1,213 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+/// What happens to the other partial matches when a pattern matches.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SkipStrategy {
+    /// Every partial match keeps going, so matches may share events.
+    #[default]
+    NoSkip,
+    /// All partial matches are discarded and the completing event starts none, so no
+    /// event is part of two matches. When one event completes several partial matches,
+    /// only the oldest is emitted.
+    SkipPastLastEvent,
+}
+
+macro_rules! define_match_pattern_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::SkipStrategy;
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::Sub;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        type Predicate<I> = Box<dyn Fn(&I) -> bool + $($bounds)* 'static>;
+
+        /// One step of a [`Pattern`]: the event it accepts and the events that must not
+        /// occur before it.
+        struct Step<I> {
+            accept: Predicate<I>,
+            forbidden: Vec<Predicate<I>>,
+        }
+
+        /// A sequence of predicates for [`match_pattern`](MatchPatternExt::match_pattern).
+        ///
+        /// Each step is matched by the next event that satisfies it; events in between
+        /// that satisfy none of the steps are ignored.
+        pub struct Pattern<I, D> {
+            steps: Vec<Step<I>>,
+            forbidden: Vec<Predicate<I>>,
+            window: Option<D>,
+            skip: SkipStrategy,
+        }
+
+        impl<I, D> Pattern<I, D> {
+            /// Starts a pattern whose first event satisfies `predicate`.
+            pub fn begin(predicate: impl Fn(&I) -> bool + $($bounds)* 'static) -> Self {
+                Self {
+                    steps: vec![Step {
+                        accept: Box::new(predicate),
+                        forbidden: Vec::new(),
+                    }],
+                    forbidden: Vec::new(),
+                    window: None,
+                    skip: SkipStrategy::default(),
+                }
+            }
+
+            /// Adds a step matched by the next event that satisfies `predicate`.
+            pub fn then(mut self, predicate: impl Fn(&I) -> bool + $($bounds)* 'static) -> Self {
+                self.steps.push(Step {
+                    accept: Box::new(predicate),
+                    forbidden: core::mem::take(&mut self.forbidden),
+                });
+                self
+            }
+
+            /// Discards the partial match if an event satisfying `predicate` occurs
+            /// before the next step.
+            pub fn without(mut self, predicate: impl Fn(&I) -> bool + $($bounds)* 'static) -> Self {
+                self.forbidden.push(Box::new(predicate));
+                self
+            }
+
+            /// Requires the last event to follow the first by at most `window`, measured
+            /// on item timestamps.
+            pub fn within(mut self, window: D) -> Self {
+                self.window = Some(window);
+                self
+            }
+
+            /// Sets what happens to the other partial matches when the pattern matches.
+            pub fn skip(mut self, strategy: SkipStrategy) -> Self {
+                self.skip = strategy;
+                self
+            }
+        }
+
+        impl<I, D: Debug> Debug for Pattern<I, D> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("Pattern")
+                    .field("steps", &self.steps.len())
+                    .field("window", &self.window)
+                    .field("skip", &self.skip)
+                    .finish()
+            }
+        }
+
+        /// Events matched so far, and the timestamp of the first.
+        struct PartialMatch<I, TS> {
+            start: TS,
+            events: Vec<I>,
+        }
+
+        pub trait MatchPatternExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Emits the events of every occurrence of `pattern`, in pattern order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `pattern` ends with [`without`](Pattern::without).
+            fn match_pattern<Out, D>(
+                self,
+                pattern: Pattern<T::Inner, D>,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Out: Fluxion<Inner = Vec<T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> MatchPatternExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn match_pattern<Out, D>(
+                self,
+                pattern: Pattern<T::Inner, D>,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Out: Fluxion<Inner = Vec<T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                assert!(
+                    pattern.forbidden.is_empty(),
+                    "match_pattern requires a step after `without`"
+                );
+
+                let mut partials: Vec<PartialMatch<T::Inner, T::Timestamp>> = Vec::new();
+
+                Box::pin(self.flat_map(move |item| {
+                    let matches: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let inner = value.into_inner();
+
+                            if let Some(window) = pattern.window {
+                                partials.retain(|partial| {
+                                    timestamp <= partial.start || timestamp - partial.start <= window
+                                });
+                            }
+
+                            let mut completed = Vec::new();
+                            partials.retain_mut(|partial| {
+                                let step = &pattern.steps[partial.events.len()];
+                                if step.forbidden.iter().any(|forbidden| forbidden(&inner)) {
+                                    return false;
+                                }
+                                if !(step.accept)(&inner) {
+                                    return true;
+                                }
+
+                                partial.events.push(inner.clone());
+                                if partial.events.len() < pattern.steps.len() {
+                                    return true;
+                                }
+                                completed.push(core::mem::take(&mut partial.events));
+                                false
+                            });
+
+                            if pattern.skip == SkipStrategy::SkipPastLastEvent && !completed.is_empty() {
+                                partials.clear();
+                                completed.truncate(1);
+                            } else if (pattern.steps[0].accept)(&inner) {
+                                if pattern.steps.len() == 1 {
+                                    completed.push(vec![inner]);
+                                } else {
+                                    partials.push(PartialMatch {
+                                        start: timestamp,
+                                        events: vec![inner],
+                                    });
+                                }
+                            }
+
+                            completed
+                                .into_iter()
+                                .map(|events| StreamItem::Value(Out::with_timestamp(events, timestamp.into())))
+                                .collect()
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(matches)
+                }))
+            }
+        }
+    };
+}
@@ -0,0 +1,87 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Sequence pattern matching over a stream, in the style of complex event processing.
+//!
+//! [`match_pattern`](MatchPatternExt::match_pattern) detects ordered sequences of events
+//! described by a [`Pattern`], such as "a login, then a password change, without a
+//! logout in between, within 5 seconds", and emits the events of each match as one
+//! `Vec`.
+//!
+//! # Behavior
+//!
+//! - Every event satisfying the first step starts a partial match
+//! - A partial match takes the next event that satisfies its next step; events that
+//!   satisfy neither its next step nor a [`without`](Pattern::without) predicate are
+//!   skipped
+//! - A partial match is discarded when an event satisfying a `without` predicate of its
+//!   next step occurs, or when an event arrives more than the
+//!   [`within`](Pattern::within) window after its first event
+//! - A match is emitted when its last step is matched, with the timestamp of that event
+//! - [`SkipStrategy`] decides whether the other partial matches survive a match
+//! - Without a window, partial matches that never complete are kept forever
+//! - Errors are propagated without discarding partial matches
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{MatchPatternExt, Pattern};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, events) = test_channel::<Sequenced<&str>>();
+//!
+//! let pattern = Pattern::begin(|event: &&str| *event == "login")
+//!     .without(|event: &&str| *event == "logout")
+//!     .then(|event: &&str| *event == "change_password")
+//!     .within(5u64);
+//! let mut alerts = events.match_pattern::<Sequenced<Vec<&str>>, _>(pattern);
+//!
+//! tx.unbounded_send(("login", 1).into()).unwrap();
+//! tx.unbounded_send(("logout", 2).into()).unwrap(); // discards the first login
+//! tx.unbounded_send(("login", 3).into()).unwrap();
+//! tx.unbounded_send(("view", 4).into()).unwrap();
+//! tx.unbounded_send(("change_password", 5).into()).unwrap();
+//!
+//! let alert = unwrap_value(Some(unwrap_stream(&mut alerts, 500).await));
+//! assert_eq!(alert.value, vec!["login", "change_password"]);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::SkipStrategy;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{MatchPatternExt, Pattern};
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{MatchPatternExt, Pattern};
@@ -0,0 +1,210 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+/// A change applied to a [`MaterializedView`](crate::MaterializedView), as sent to its
+/// subscribers.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ViewChange<K, V> {
+    /// The key was inserted or its value replaced.
+    Upsert(K, V),
+    /// The key was removed, with the value it held.
+    Delete(K, V),
+}
+
+macro_rules! define_materialized_view_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::ViewChange;
+        use crate::coop::CoopBudget;
+        use alloc::collections::BTreeMap;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::RangeBounds;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, FluxionSubject, FluxionTask, StreamItem, SubjectError};
+        use futures::future::{select, Either};
+        use futures::{Stream, StreamExt};
+
+        /// Current table and whether the source has ended.
+        struct ViewState<K, V> {
+            table: BTreeMap<K, V>,
+            closed: bool,
+        }
+
+        /// Table of the latest value per key of a stream of upserts and deletes, created
+        /// by [`materialize`](MaterializeExt::materialize).
+        ///
+        /// Clones share the same table, subscribers and background task, which runs until
+        /// the source ends or the last clone is dropped.
+        pub struct MaterializedView<K: Clone + $($bounds)* 'static, V: Clone + $($bounds)* 'static> {
+            state: Arc<Mutex<ViewState<K, V>>>,
+            changes: FluxionSubject<ViewChange<K, V>>,
+            _task: Arc<FluxionTask>,
+        }
+
+        impl<K, V> MaterializedView<K, V>
+        where
+            K: Ord + Clone + $($bounds)* 'static,
+            V: Clone + $($bounds)* 'static,
+        {
+            /// Returns the current value of `key`.
+            pub async fn get(&self, key: &K) -> Option<V> {
+                self.state.lock().table.get(key).cloned()
+            }
+
+            /// Returns the current entries whose keys are in `range`, ordered by key.
+            pub async fn range<R: RangeBounds<K>>(&self, range: R) -> Vec<(K, V)> {
+                self.state
+                    .lock()
+                    .table
+                    .range(range)
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            }
+
+            /// Returns the number of keys in the view.
+            pub fn len(&self) -> usize {
+                self.state.lock().table.len()
+            }
+
+            /// Returns `true` if the view holds no key.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns a stream of the changes applied from now on, which ends with the
+            /// source and carries the error that ended it.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`SubjectError::Closed`] once the source has ended.
+            pub fn subscribe(
+                &self,
+            ) -> Result<impl Stream<Item = StreamItem<ViewChange<K, V>>> + $($bounds)*, SubjectError> {
+                self.changes.subscribe()
+            }
+
+            /// Returns `true` once the source has ended.
+            pub fn is_closed(&self) -> bool {
+                self.state.lock().closed
+            }
+        }
+
+        impl<K, V> Clone for MaterializedView<K, V>
+        where
+            K: Clone + $($bounds)* 'static,
+            V: Clone + $($bounds)* 'static,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    state: Arc::clone(&self.state),
+                    changes: self.changes.clone(),
+                    _task: Arc::clone(&self._task),
+                }
+            }
+        }
+
+        impl<K, V> Debug for MaterializedView<K, V>
+        where
+            K: Clone + $($bounds)* 'static,
+            V: Clone + $($bounds)* 'static,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let state = self.state.lock();
+                f.debug_struct("MaterializedView")
+                    .field("len", &state.table.len())
+                    .field("closed", &state.closed)
+                    .finish_non_exhaustive()
+            }
+        }
+
+        pub trait MaterializeExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Drives the stream on a background task and applies each item to a
+            /// [`MaterializedView`]: `change` maps it to a key and either the new value of
+            /// the key or `None` to delete it.
+            fn materialize<K, V, F>(self, change: F) -> MaterializedView<K, V>
+            where
+                Self: Unpin + $($bounds)* 'static,
+                K: Ord + Clone + $($bounds)* 'static,
+                V: Clone + $($bounds)* 'static,
+                F: Fn(T::Inner) -> (K, Option<V>) + $($bounds)* 'static;
+        }
+
+        impl<T, S> MaterializeExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn materialize<K, V, F>(self, change: F) -> MaterializedView<K, V>
+            where
+                Self: Unpin + $($bounds)* 'static,
+                K: Ord + Clone + $($bounds)* 'static,
+                V: Clone + $($bounds)* 'static,
+                F: Fn(T::Inner) -> (K, Option<V>) + $($bounds)* 'static,
+            {
+                let state = Arc::new(Mutex::new(ViewState {
+                    table: BTreeMap::new(),
+                    closed: false,
+                }));
+                let changes = FluxionSubject::new();
+                let task_state = Arc::clone(&state);
+                let task_changes = changes.clone();
+
+                let task = FluxionTask::spawn(|cancel| async move {
+                    let mut stream = self;
+                    let mut budget = CoopBudget::new();
+                    while let Either::Left((stream_item, _)) =
+                        select(stream.next(), cancel.cancelled()).await
+                    {
+                        match stream_item {
+                            Some(StreamItem::Value(value)) => {
+                                let applied = {
+                                    let mut state = task_state.lock();
+                                    match change(value.into_inner()) {
+                                        (key, Some(value)) => {
+                                            state.table.insert(key.clone(), value.clone());
+                                            Some(ViewChange::Upsert(key, value))
+                                        }
+                                        (key, None) => state
+                                            .table
+                                            .remove(&key)
+                                            .map(|previous| ViewChange::Delete(key, previous)),
+                                    }
+                                };
+                                // Subscribers that went away only miss the change
+                                if let Some(applied) = applied {
+                                    let _ = task_changes.next(applied);
+                                }
+                                budget.consume();
+                                budget.proceed().await;
+                            }
+                            Some(StreamItem::Error(e)) => {
+                                let _ = task_changes.error(e);
+                                break;
+                            }
+                            None => {
+                                break;
+                            }
+                        }
+                    }
+
+                    task_state.lock().closed = true;
+                    task_changes.close();
+                });
+
+                MaterializedView {
+                    state,
+                    changes,
+                    _task: Arc::new(task),
+                }
+            }
+        }
+    };
+}
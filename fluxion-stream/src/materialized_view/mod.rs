@@ -0,0 +1,94 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Materialized view of a keyed stream of changes, like a table built from a changelog.
+//!
+//! [`materialize`](MaterializeExt::materialize) consumes a stream on a background task
+//! and applies each item to a [`MaterializedView`] as an upsert or a delete of a key. The
+//! view answers point queries with [`get`](MaterializedView::get) and range queries with
+//! [`range`](MaterializedView::range), and [`subscribe`](MaterializedView::subscribe)
+//! returns a stream of the [`ViewChange`]s applied from then on, so downstream consumers
+//! can follow the table without replaying its changelog.
+//!
+//! # Runtime Requirements
+//!
+//! Like [`to_state_cell`](crate::StateCellExt::to_state_cell), this adapter spawns a task
+//! and requires `runtime-tokio`, `runtime-smol`, `runtime-async-std` or a `wasm32` target.
+//!
+//! # Behavior
+//!
+//! - The source is consumed as fast as it produces, whether or not anyone queries the view
+//! - Changes are applied in arrival order; timestamps are not compared
+//! - Deleting a key that is not in the view changes nothing and is not sent to
+//!   subscribers
+//! - Subscribers only receive the changes applied after they subscribed
+//! - An error ends the source like completion does; the view keeps its last state and
+//!   the error is sent to subscribers
+//! - The task is cancelled when the last clone of the view is dropped
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::StreamItem;
+//! use fluxion_stream::{MaterializeExt, ViewChange};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, orders) = test_channel::<Sequenced<(u32, Option<&str>)>>();
+//!
+//! // Open orders by id; `None` closes an order
+//! let view = orders.materialize(|(id, status)| (id, status));
+//! let mut changes = view.subscribe().unwrap();
+//!
+//! tx.unbounded_send(Sequenced::new((1, Some("placed")))).unwrap();
+//! tx.unbounded_send(Sequenced::new((2, Some("placed")))).unwrap();
+//! tx.unbounded_send(Sequenced::new((1, None))).unwrap();
+//!
+//! let mut last = None;
+//! for _ in 0..3 {
+//!     last = Some(unwrap_stream(&mut changes, 500).await);
+//! }
+//! assert!(matches!(last, Some(StreamItem::Value(ViewChange::Delete(1, "placed")))));
+//!
+//! assert_eq!(view.get(&1).await, None);
+//! assert_eq!(view.range(..).await, vec![(2, "placed")]);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::ViewChange;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{MaterializeExt, MaterializedView};
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{MaterializeExt, MaterializedView};
@@ -0,0 +1,312 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use fluxion_core::sync::{Arc, Mutex};
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use futures::Stream;
+use pin_project::pin_project;
+
+/// What a [`MemoryBudget`] does with an item that does not fit in what is left of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryPolicy {
+    /// Stops pulling from the source until the stages holding items release enough.
+    Backpressure,
+    /// Drops the item.
+    Drop,
+    /// Replaces the item with a
+    /// [`FluxionError::ResourceLimitExceeded`](fluxion_core::FluxionError::ResourceLimitExceeded).
+    Error,
+}
+
+/// Bytes reserved against the limit, and the stages waiting for room.
+struct BudgetState {
+    limit: usize,
+    used: usize,
+    policy: MemoryPolicy,
+    waiting: Vec<Waker>,
+}
+
+/// Memory budget shared by the buffering stages of a pipeline.
+///
+/// Each stage wrapped with
+/// [`within_memory_budget`](crate::MemoryBudgetExt::within_memory_budget) reserves the
+/// estimated size of the items it holds and releases it as they leave, so the stages
+/// together never hold more than the limit. Clones share the same budget.
+#[derive(Clone)]
+pub struct MemoryBudget {
+    state: Arc<Mutex<BudgetState>>,
+}
+
+impl MemoryBudget {
+    /// Creates a budget of `limit` bytes, applying `policy` to items that do not fit.
+    pub fn new(limit: usize, policy: MemoryPolicy) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(BudgetState {
+                limit,
+                used: 0,
+                policy,
+                waiting: Vec::new(),
+            })),
+        }
+    }
+
+    /// Returns the limit in bytes.
+    pub fn limit(&self) -> usize {
+        self.state.lock().limit
+    }
+
+    /// Returns the bytes currently reserved.
+    pub fn used(&self) -> usize {
+        self.state.lock().used
+    }
+
+    /// Returns the policy applied to items that do not fit.
+    pub fn policy(&self) -> MemoryPolicy {
+        self.state.lock().policy
+    }
+
+    /// Reserves `bytes` if they fit in what is left of the budget, and returns whether
+    /// they did.
+    pub fn try_reserve(&self, bytes: usize) -> bool {
+        let mut state = self.state.lock();
+        let fits = state.used.saturating_add(bytes) <= state.limit;
+        if fits {
+            state.used += bytes;
+        }
+        fits
+    }
+
+    /// Returns `bytes` reserved before to the budget.
+    pub fn release(&self, bytes: usize) {
+        let mut state = self.state.lock();
+        state.used = state.used.saturating_sub(bytes);
+        for waker in state.waiting.drain(..) {
+            waker.wake();
+        }
+    }
+
+    /// Reserves `bytes` once they fit, or at once if nothing is reserved, so that an item
+    /// larger than the whole budget still gets through on its own.
+    fn poll_reserve(&self, bytes: usize, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock();
+        if state.used == 0 || state.used.saturating_add(bytes) <= state.limit {
+            state.used = state.used.saturating_add(bytes);
+            return Poll::Ready(());
+        }
+        if !state
+            .waiting
+            .iter()
+            .any(|waker| waker.will_wake(cx.waker()))
+        {
+            state.waiting.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl Debug for MemoryBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock();
+        f.debug_struct("MemoryBudget")
+            .field("limit", &state.limit)
+            .field("used", &state.used)
+            .field("policy", &state.policy)
+            .finish()
+    }
+}
+
+/// Reservations of one budgeted stage, returned to the budget when the stage is dropped.
+///
+/// Each item entering the stage is recorded with its timestamp and the bytes reserved for
+/// it, so exactly those bytes are released whatever the stage does with the item.
+pub(crate) struct StageAccount<E, TS> {
+    budget: MemoryBudget,
+    estimate: E,
+    reserved: Mutex<VecDeque<(TS, usize)>>,
+}
+
+impl<E, TS: Ord + Copy> StageAccount<E, TS> {
+    pub(crate) fn new(budget: MemoryBudget, estimate: E) -> Self {
+        Self {
+            budget,
+            estimate,
+            reserved: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn charge(&self, timestamp: TS, bytes: usize) {
+        self.reserved.lock().push_back((timestamp, bytes));
+    }
+
+    /// Releases the item at `timestamp` leaving the stage, together with every item the
+    /// stage took before it with an older timestamp: those have left it or were dropped.
+    pub(crate) fn refund(&self, timestamp: TS) {
+        let mut released = 0;
+        self.reserved.lock().retain(|(reserved, bytes)| {
+            let leaves = *reserved <= timestamp;
+            if leaves {
+                released += bytes;
+            }
+            !leaves
+        });
+        if released > 0 {
+            self.budget.release(released);
+        }
+    }
+}
+
+impl<E, TS> Drop for StageAccount<E, TS> {
+    fn drop(&mut self) {
+        let released = self.reserved.lock().iter().map(|(_, bytes)| bytes).sum();
+        self.budget.release(released);
+    }
+}
+
+/// Source of a stage wrapped with
+/// [`within_memory_budget`](crate::MemoryBudgetExt::within_memory_budget), reserving the
+/// estimated size of each item it yields.
+#[pin_project]
+pub struct BudgetedStream<S, T: HasTimestamp, E> {
+    #[pin]
+    source: S,
+    account: Arc<StageAccount<E, T::Timestamp>>,
+    waiting: Option<(T, usize)>,
+}
+
+impl<S, T: HasTimestamp, E> BudgetedStream<S, T, E> {
+    pub(crate) fn new(source: S, account: Arc<StageAccount<E, T::Timestamp>>) -> Self {
+        Self {
+            source,
+            account,
+            waiting: None,
+        }
+    }
+}
+
+impl<S, T, E> Stream for BudgetedStream<S, T, E>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: HasTimestamp,
+    T::Timestamp: Ord + Copy,
+    E: Fn(&T) -> usize,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let budget = &this.account.budget;
+
+        // An item held back by backpressure goes first
+        if let Some((item, bytes)) = this.waiting.take() {
+            if budget.poll_reserve(bytes, cx).is_pending() {
+                *this.waiting = Some((item, bytes));
+                return Poll::Pending;
+            }
+            this.account.charge(item.timestamp(), bytes);
+            return Poll::Ready(Some(StreamItem::Value(item)));
+        }
+
+        loop {
+            let item = match this.source.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(item))) => item,
+                other => return other,
+            };
+            let bytes = (this.account.estimate)(&item);
+
+            match budget.policy() {
+                MemoryPolicy::Backpressure => {
+                    if budget.poll_reserve(bytes, cx).is_pending() {
+                        *this.waiting = Some((item, bytes));
+                        return Poll::Pending;
+                    }
+                }
+                MemoryPolicy::Drop => {
+                    if !budget.try_reserve(bytes) {
+                        continue;
+                    }
+                }
+                MemoryPolicy::Error => {
+                    if !budget.try_reserve(bytes) {
+                        return Poll::Ready(Some(StreamItem::Error(
+                            FluxionError::resource_limit_exceeded("memory budget", budget.limit()),
+                        )));
+                    }
+                }
+            }
+            this.account.charge(item.timestamp(), bytes);
+            return Poll::Ready(Some(StreamItem::Value(item)));
+        }
+    }
+}
+
+macro_rules! define_memory_budget_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{BudgetedStream, MemoryBudget, StageAccount};
+        use core::fmt::Debug;
+        use fluxion_core::sync::Arc;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        pub trait MemoryBudgetExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Runs the buffering `stage` on this stream within `budget`: every item
+            /// entering the stage reserves `estimate(item)` bytes, released when the item
+            /// leaves it.
+            ///
+            /// Items that do not fit are handled by the [`policy`](MemoryBudget::policy)
+            /// of the budget. An item leaving the stage releases what was reserved for it
+            /// and for every older item the stage took, so items the stage filters out are
+            /// released once a later item leaves it or the stage is dropped.
+            fn within_memory_budget<E, F, Out>(
+                self,
+                budget: &MemoryBudget,
+                estimate: E,
+                stage: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                E: Fn(&T) -> usize + $($bounds)* 'static,
+                F: FnOnce(BudgetedStream<Self, T, E>) -> Out,
+                Out: Stream<Item = StreamItem<T>> + $($bounds)* 'static;
+        }
+
+        impl<T, S> MemoryBudgetExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn within_memory_budget<E, F, Out>(
+                self,
+                budget: &MemoryBudget,
+                estimate: E,
+                stage: F,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                E: Fn(&T) -> usize + $($bounds)* 'static,
+                F: FnOnce(BudgetedStream<Self, T, E>) -> Out,
+                Out: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            {
+                let account = Arc::new(StageAccount::new(budget.clone(), estimate));
+                let stage = stage(BudgetedStream::new(self, Arc::clone(&account)));
+
+                stage.map(move |item| {
+                    if let StreamItem::Value(value) = &item {
+                        account.refund(value.timestamp());
+                    }
+                    item
+                })
+            }
+        }
+    };
+}
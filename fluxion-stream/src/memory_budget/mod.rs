@@ -0,0 +1,113 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Memory accounting for the buffering stages of a pipeline.
+//!
+//! Operators such as [`buffer_when`](crate::BufferWhenExt::buffer_when) or
+//! [`reorder`](crate::ReorderExt::reorder) hold items for as long as their conditions
+//! require, so a stalled control stream or a slow consumer can grow them without bound.
+//! A [`MemoryBudget`] caps what such stages hold together:
+//! [`within_memory_budget`](MemoryBudgetExt::within_memory_budget) wraps a stage so that
+//! every item entering it reserves its estimated size in the shared budget, and releases
+//! it when the item leaves. Once the budget is exhausted, its [`MemoryPolicy`] applies.
+//!
+//! # Behavior
+//!
+//! - Sizes are estimated per item by a closure, such as `size_of` plus the heap
+//!   allocations the item owns
+//! - [`Backpressure`](MemoryPolicy::Backpressure) stops pulling from the source until
+//!   enough is released; the stage must be able to release items without more input from
+//!   it, and an item larger than the whole budget is admitted once nothing is reserved
+//! - [`Drop`](MemoryPolicy::Drop) discards items that do not fit
+//! - [`Error`](MemoryPolicy::Error) replaces items that do not fit with a
+//!   [`ResourceLimitExceeded`](fluxion_core::FluxionError::ResourceLimitExceeded) error,
+//!   which flows through the stage like other errors
+//! - An item leaving the stage releases exactly what was reserved for it, together with
+//!   every older item the stage took: items the stage filters out, deduplicates or
+//!   aggregates are released once a later item leaves it
+//! - What a stage still holds is released when it is dropped
+//! - Errors of the source pass into the stage without reserving anything
+//!
+//! # Spilling to disk
+//!
+//! There is no spill policy: spilling is done by putting
+//! [`spill_to_disk`](crate::SpillToDiskExt::spill_to_disk) in front of a stage whose
+//! budget uses [`Backpressure`](MemoryPolicy::Backpressure). While the budget is
+//! exhausted the stage stops pulling, and `spill_to_disk` keeps draining the source,
+//! writing what the stage cannot take yet to disk:
+//!
+//! ```rust,ignore
+//! let budget = MemoryBudget::new(64 * 1024 * 1024, MemoryPolicy::Backpressure);
+//!
+//! let delivered = events
+//!     .spill_to_disk(SpillConfig::new("/var/spool/events", 10_000), EventCodec)
+//!     .within_memory_budget(&budget, |event| event.size_in_bytes(), |events| {
+//!         events.buffer_when(online, |connected| *connected)
+//!     });
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::StreamItem;
+//! use fluxion_stream::{MemoryBudget, MemoryBudgetExt, MemoryPolicy, ReorderExt, ReorderWindow};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel_with_errors, unwrap_stream},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, events) = test_channel_with_errors::<Sequenced<u64>>();
+//!
+//! // Room for two items of the reorder buffer
+//! let budget = MemoryBudget::new(2 * size_of::<Sequenced<u64>>(), MemoryPolicy::Error);
+//! let mut ordered = events.within_memory_budget(&budget, |_| size_of::<Sequenced<u64>>(), |events| {
+//!     events.reorder(ReorderWindow::Items(8))
+//! });
+//!
+//! for (value, timestamp) in [(1, 10), (2, 20), (3, 30)] {
+//!     tx.unbounded_send(StreamItem::Value((value, timestamp).into())).unwrap();
+//! }
+//!
+//! assert!(matches!(
+//!     unwrap_stream(&mut ordered, 500).await,
+//!     StreamItem::Error(_)
+//! ));
+//! assert_eq!(budget.used(), budget.limit());
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+pub use implementation::{BudgetedStream, MemoryBudget, MemoryPolicy};
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::MemoryBudgetExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::MemoryBudgetExt;
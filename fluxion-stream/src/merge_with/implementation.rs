@@ -4,14 +4,14 @@
 
 macro_rules! define_merge_with_impl {
     ($($bounds:tt)*) => {
-        use crate::ordered_merge::ordered_merge_with_index;
+        use crate::ordered_merge::ordered_merge_with_index_and_mode;
+        use crate::ordering::OrderingMode;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec;
         use core::fmt::Debug;
         use core::marker::PhantomData;
         use core::pin::Pin;
-        use fluxion_core::fluxion_mutex::Mutex;
+        use fluxion_core::sync::{Arc, Mutex};
         use fluxion_core::{Fluxion, HasTimestamp, StreamItem, Timestamped};
         use futures::stream::{empty, Empty, Stream, StreamExt};
         use futures::task::{Context, Poll};
@@ -57,6 +57,30 @@ macro_rules! define_merge_with_impl {
                 new_stream: NewStream,
                 process_fn: F,
             ) -> MergedStream<impl Stream<Item = StreamItem<Item>>, State, Item>
+            where
+                NewStream: Stream<Item = StreamItem<NewItem>> + $($bounds)* 'static,
+                NewItem: Fluxion,
+                <NewItem as Timestamped>::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                <NewItem as HasTimestamp>::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(<NewItem as Timestamped>::Inner, &mut State) -> <Item as Timestamped>::Inner
+                    + Clone
+                    + $($bounds)* 'static,
+                Item: Fluxion,
+                <Item as Timestamped>::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                <Item as HasTimestamp>::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                <NewItem as HasTimestamp>::Timestamp: Into<<Item as HasTimestamp>::Timestamp> + Copy,
+            {
+                self.merge_with_with_mode(new_stream, process_fn, OrderingMode::Ordered)
+            }
+
+            /// Merges `new_stream` like [`merge_with`](Self::merge_with), in the given
+            /// [`OrderingMode`].
+            pub fn merge_with_with_mode<NewStream, NewItem, F>(
+                self,
+                new_stream: NewStream,
+                process_fn: F,
+                mode: OrderingMode,
+            ) -> MergedStream<impl Stream<Item = StreamItem<Item>>, State, Item>
             where
                 NewStream: Stream<Item = StreamItem<NewItem>> + $($bounds)* 'static,
                 NewItem: Fluxion,
@@ -95,7 +119,7 @@ macro_rules! define_merge_with_impl {
                         as Pin<Box<dyn Stream<Item = StreamItem<Item>> + $($bounds)*>>,
                 ];
 
-                let merged_stream = ordered_merge_with_index(streams).map(|(item, _index)| item);
+                let merged_stream = ordered_merge_with_index_and_mode(streams, mode).map(|(item, _index)| item);
 
                 MergedStream {
                     inner: merged_stream,
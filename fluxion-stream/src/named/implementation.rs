@@ -0,0 +1,153 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use super::recording::RecordedItem;
+use super::registry::{NodeId, NodeStats, PipelineRegistry, PollScope};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::sync::atomic::Ordering;
+use core::task::{Context, Poll};
+use fluxion_core::{StreamItem, Timestamped};
+use futures::{Stream, StreamExt};
+
+/// A stream registered as a named stage of a [`PipelineRegistry`].
+///
+/// Items pass through unchanged. The stage is removed from the registry when the
+/// stream is dropped.
+pub struct NamedStream<S> {
+    inner: S,
+    registry: PipelineRegistry,
+    id: NodeId,
+    stats: Arc<NodeStats>,
+    linked: BTreeSet<NodeId>,
+}
+
+impl<S> NamedStream<S> {
+    pub(crate) fn new(inner: S, registry: &PipelineRegistry, name: &str) -> Self {
+        let (id, stats) = registry.register(name);
+        Self {
+            inner,
+            registry: registry.clone(),
+            id,
+            stats,
+            linked: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the id of this stage within its registry.
+    pub fn node_id(&self) -> NodeId {
+        self.id
+    }
+}
+
+impl<S> Debug for NamedStream<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NamedStream")
+            .field("id", &self.id)
+            .field("inner", &"<stream>")
+            .finish()
+    }
+}
+
+impl<S, T> Stream for NamedStream<S>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped,
+    T::Inner: Debug,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let scope = PollScope::enter(&this.registry, this.id, &mut this.linked);
+
+        let poll = this.inner.poll_next_unpin(cx);
+        match &poll {
+            Poll::Ready(Some(StreamItem::Value(_))) => {
+                this.stats.items.fetch_add(1, Ordering::Relaxed);
+            }
+            Poll::Ready(Some(StreamItem::Error(_))) => {
+                this.stats.errors.fetch_add(1, Ordering::Relaxed);
+            }
+            _ => {}
+        }
+
+        if let Poll::Ready(Some(item)) = &poll {
+            if this.registry.is_recording() {
+                let item = match item {
+                    StreamItem::Value(value) => RecordedItem::Value {
+                        timestamp: format!("{:?}", value.timestamp()),
+                        value: format!("{:?}", value.clone().into_inner()),
+                    },
+                    StreamItem::Error(error) => RecordedItem::Error(error.to_string()),
+                };
+                this.registry.record(this.id, scope.downstream, item);
+            }
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> Drop for NamedStream<S> {
+    fn drop(&mut self) {
+        self.registry.unregister(self.id);
+    }
+}
+
+macro_rules! define_named_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::NamedStream;
+        use super::registry::PipelineRegistry;
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::Stream;
+
+        pub trait NamedExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Registers this stream as a stage called `name` in the global registry.
+            fn named(self, name: &str) -> NamedStream<Self>
+            where
+                Self: Unpin + $($bounds)* 'static;
+
+            /// Registers this stream as a stage called `name` in `registry`.
+            fn named_in(self, registry: &PipelineRegistry, name: &str) -> NamedStream<Self>
+            where
+                Self: Unpin + $($bounds)* 'static;
+        }
+
+        impl<S, T> NamedExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn named(self, name: &str) -> NamedStream<Self>
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                NamedStream::new(self, PipelineRegistry::global(), name)
+            }
+
+            fn named_in(self, registry: &PipelineRegistry, name: &str) -> NamedStream<Self>
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                NamedStream::new(self, registry, name)
+            }
+        }
+    };
+}
@@ -0,0 +1,147 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Named pipeline stages and topology introspection.
+//!
+//! This module provides the [`named`](NamedExt::named) operator, which registers a
+//! stream as a labelled stage of a [`PipelineRegistry`]. The registry can export the
+//! live topology of a composed pipeline, including fan-in edges created by operators
+//! such as `ordered_merge`, either as a [`PipelineGraph`] or as Graphviz DOT text.
+//!
+//! # Overview
+//!
+//! - [`named`](NamedExt::named) registers the stage in the process-wide
+//!   [`PipelineRegistry::global`] registry.
+//! - [`named_in`](NamedExt::named_in) registers the stage in an explicit registry,
+//!   which keeps unrelated pipelines (and tests) apart.
+//!
+//! Items pass through unchanged. Each stage counts the values and errors it emits.
+//! Edges are discovered as items flow: when a named stage is polled by another named
+//! stage further downstream, the edge between them is recorded. A freshly built
+//! pipeline therefore shows its edges once it has been polled.
+//!
+//! Stages are removed from the registry when the named stream is dropped.
+//!
+//! # Example
+//!
+//! ```
+//! use fluxion_stream::named::PipelineRegistry;
+//! use fluxion_stream::prelude::*;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let registry = PipelineRegistry::new();
+//! let (orders_tx, orders) = async_channel::unbounded::<Sequenced<i32>>();
+//! let (refunds_tx, refunds) = async_channel::unbounded::<Sequenced<i32>>();
+//!
+//! let orders = orders.into_fluxion_stream().named_in(&registry, "orders");
+//! let refunds = refunds.into_fluxion_stream().named_in(&registry, "refunds");
+//!
+//! let mut pipeline = orders
+//!     .ordered_merge(vec![refunds])
+//!     .filter_ordered(|x| *x > 0)
+//!     .named_in(&registry, "enrich-orders");
+//!
+//! orders_tx.try_send(Sequenced::new(42)).unwrap();
+//! refunds_tx.try_send(Sequenced::new(7)).unwrap();
+//! pipeline.next().await.unwrap().unwrap();
+//!
+//! let graph = registry.graph();
+//! assert_eq!(graph.upstream("enrich-orders"), vec!["orders", "refunds"]);
+//!
+//! println!("{}", graph.to_dot());
+//! # }
+//! ```
+//!
+//! # Recording and Replay
+//!
+//! For post-mortem analysis of misordered or dropped events, a registry can record every
+//! item crossing its stages. [`start_recording`](PipelineRegistry::start_recording)
+//! captures each item as it leaves the stage that emitted it and as it enters the named
+//! stage polling that one, together with its timestamp.
+//! [`stop_recording`](PipelineRegistry::stop_recording) returns the [`Recording`], whose
+//! [`Replay`] steps through the events one stage at a time, forwards or backwards.
+//!
+//! ```
+//! use fluxion_stream::named::{PipelineRegistry, RecordedItem};
+//! use fluxion_stream::prelude::*;
+//! use fluxion_test_utils::{helpers::test_channel, sequenced::Sequenced};
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let registry = PipelineRegistry::new();
+//! let (tx, orders) = test_channel::<Sequenced<i32>>();
+//! let mut pipeline = orders
+//!     .named_in(&registry, "orders")
+//!     .filter_ordered(|x| *x > 0)
+//!     .named_in(&registry, "valid-orders");
+//!
+//! registry.start_recording();
+//! tx.unbounded_send(Sequenced::with_timestamp(-1, 1)).unwrap();
+//! tx.unbounded_send(Sequenced::with_timestamp(5, 2)).unwrap();
+//! pipeline.next().await.unwrap().unwrap();
+//! let recording = registry.stop_recording();
+//!
+//! // Two orders entered "valid-orders" but only one left it
+//! assert_eq!(recording.entered("valid-orders").len(), 2);
+//! assert_eq!(recording.left("valid-orders").len(), 1);
+//!
+//! let mut replay = recording.replay();
+//! let event = replay.step_stage("valid-orders").unwrap();
+//! assert_eq!(
+//!     event.item,
+//!     RecordedItem::Value { timestamp: "1".into(), value: "-1".into() }
+//! );
+//! # }
+//! ```
+//!
+//! # Limitations
+//!
+//! Edges are tracked within a single poll call chain. Stages separated by a spawned
+//! task, such as the subscribers of [`share`](crate::ShareExt::share) or the halves
+//! of [`partition`](crate::PartitionExt::partition), appear as disconnected nodes.
+//!
+//! # Error Handling
+//!
+//! Errors pass through unchanged and are counted in [`PipelineNode::errors`].
+
+#[macro_use]
+mod implementation;
+mod recording;
+mod registry;
+
+pub use implementation::NamedStream;
+pub use recording::{RecordedEvent, RecordedItem, Recording, Replay, Transition};
+pub use registry::{NodeId, PipelineEdge, PipelineGraph, PipelineNode, PipelineRegistry};
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::NamedExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::NamedExt;
@@ -0,0 +1,147 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use super::registry::NodeId;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Whether a recorded item was entering or leaving a stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// The item was emitted by an upstream named stage while this stage was polling it.
+    Entered,
+    /// The item was emitted by this stage.
+    Left,
+}
+
+/// An item captured by a recording, rendered with its `Debug` implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordedItem {
+    Value { timestamp: String, value: String },
+    Error(String),
+}
+
+/// One item crossing the boundary of a named stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedEvent {
+    /// Position of the event in the recording, starting at 0.
+    pub sequence: usize,
+    pub node: NodeId,
+    pub stage: String,
+    pub transition: Transition,
+    pub item: RecordedItem,
+}
+
+/// Items captured by [`PipelineRegistry::start_recording`](super::PipelineRegistry::start_recording),
+/// in the order they crossed the stages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    /// Returns the events of the stage called `name`, in order.
+    pub fn stage(&self, name: &str) -> Vec<&RecordedEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.stage == name)
+            .collect()
+    }
+
+    /// Returns the items that entered the stage called `name`.
+    pub fn entered(&self, name: &str) -> Vec<&RecordedItem> {
+        self.items(name, Transition::Entered)
+    }
+
+    /// Returns the items that left the stage called `name`.
+    pub fn left(&self, name: &str) -> Vec<&RecordedItem> {
+        self.items(name, Transition::Left)
+    }
+
+    /// Starts a replay positioned before the first event.
+    pub fn replay(self) -> Replay {
+        Replay {
+            recording: self,
+            position: 0,
+        }
+    }
+
+    fn items(&self, name: &str, transition: Transition) -> Vec<&RecordedItem> {
+        self.events
+            .iter()
+            .filter(|event| event.stage == name && event.transition == transition)
+            .map(|event| &event.item)
+            .collect()
+    }
+}
+
+/// Steps forwards and backwards through a [`Recording`].
+///
+/// The replay sits between events: [`position`](Self::position) events have been
+/// replayed, and [`step`](Self::step) returns the next one.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    recording: Recording,
+    position: usize,
+}
+
+impl Replay {
+    /// Replays the next event.
+    pub fn step(&mut self) -> Option<&RecordedEvent> {
+        let event = self.recording.events.get(self.position)?;
+        self.position += 1;
+        Some(event)
+    }
+
+    /// Undoes the last replayed event and returns it.
+    pub fn step_back(&mut self) -> Option<&RecordedEvent> {
+        self.position = self.position.checked_sub(1)?;
+        self.recording.events.get(self.position)
+    }
+
+    /// Replays events up to and including the next one of the stage called `name`.
+    ///
+    /// Returns `None`, leaving the position unchanged, if that stage has no further events.
+    pub fn step_stage(&mut self, name: &str) -> Option<&RecordedEvent> {
+        let offset = self.recording.events[self.position..]
+            .iter()
+            .position(|event| event.stage == name)?;
+        self.position += offset + 1;
+        self.recording.events.get(self.position - 1)
+    }
+
+    /// Moves to just after the event with the given sequence number, or to the end.
+    pub fn seek(&mut self, sequence: usize) {
+        self.position = (sequence + 1).min(self.recording.events.len());
+    }
+
+    /// Returns the number of events replayed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the events replayed so far.
+    pub fn history(&self) -> &[RecordedEvent] {
+        &self.recording.events[..self.position]
+    }
+
+    /// Returns what the stage called `name` has seen so far: the items that entered it
+    /// and the items that left it.
+    pub fn stage_state(&self, name: &str) -> (Vec<&RecordedItem>, Vec<&RecordedItem>) {
+        let mut entered = Vec::new();
+        let mut left = Vec::new();
+        for event in self.history().iter().filter(|event| event.stage == name) {
+            match event.transition {
+                Transition::Entered => entered.push(&event.item),
+                Transition::Left => left.push(&event.item),
+            }
+        }
+        (entered, left)
+    }
+
+    /// Returns the recording being replayed.
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use super::recording::{RecordedEvent, RecordedItem, Recording, Transition};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use fluxion_core::fluxion_mutex::Mutex;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+static NEXT_REGISTRY_ID: AtomicUsize = AtomicUsize::new(0);
+
+std::thread_local! {
+    static POLL_STACK: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Identifier of a named stage within a [`PipelineRegistry`].
+pub type NodeId = usize;
+
+/// A named stage of a pipeline, as captured by [`PipelineRegistry::graph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineNode {
+    pub id: NodeId,
+    pub name: String,
+    pub items: u64,
+    pub errors: u64,
+}
+
+/// A data-flow edge between two named stages (`from` feeds `to`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PipelineEdge {
+    pub from: NodeId,
+    pub to: NodeId,
+}
+
+/// Snapshot of the live pipeline topology.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PipelineGraph {
+    pub nodes: Vec<PipelineNode>,
+    pub edges: Vec<PipelineEdge>,
+}
+
+impl PipelineGraph {
+    /// Returns the first node with the given name.
+    pub fn node(&self, name: &str) -> Option<&PipelineNode> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+
+    /// Returns the names of the stages feeding the stage called `name` (fan-in).
+    pub fn upstream(&self, name: &str) -> Vec<&str> {
+        self.neighbours(name, |edge| edge.to, |edge| edge.from)
+    }
+
+    /// Returns the names of the stages fed by the stage called `name` (fan-out).
+    pub fn downstream(&self, name: &str) -> Vec<&str> {
+        self.neighbours(name, |edge| edge.from, |edge| edge.to)
+    }
+
+    /// Renders the graph in Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph pipeline {\n    rankdir=LR;\n");
+        for node in &self.nodes {
+            let _ = writeln!(
+                dot,
+                "    n{} [label=\"{}\\nitems: {}, errors: {}\"];",
+                node.id,
+                node.name.replace('"', "\\\""),
+                node.items,
+                node.errors
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(dot, "    n{} -> n{};", edge.from, edge.to);
+        }
+        dot.push('}');
+        dot.push('\n');
+        dot
+    }
+
+    fn neighbours(
+        &self,
+        name: &str,
+        anchor: impl Fn(&PipelineEdge) -> NodeId,
+        other: impl Fn(&PipelineEdge) -> NodeId,
+    ) -> Vec<&str> {
+        let ids: Vec<NodeId> = self
+            .nodes
+            .iter()
+            .filter(|node| node.name == name)
+            .map(|node| node.id)
+            .collect();
+
+        self.edges
+            .iter()
+            .filter(|edge| ids.contains(&anchor(edge)))
+            .filter_map(|edge| self.nodes.iter().find(|node| node.id == other(edge)))
+            .map(|node| node.name.as_str())
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct NodeStats {
+    pub(crate) items: AtomicU64,
+    pub(crate) errors: AtomicU64,
+}
+
+#[derive(Debug)]
+struct NodeEntry {
+    name: String,
+    stats: Arc<NodeStats>,
+}
+
+#[derive(Debug, Default)]
+struct Topology {
+    next_node: NodeId,
+    nodes: BTreeMap<NodeId, NodeEntry>,
+    edges: BTreeSet<PipelineEdge>,
+}
+
+#[derive(Debug)]
+struct RegistryInner {
+    id: usize,
+    topology: Mutex<Topology>,
+    recording: AtomicBool,
+    events: Mutex<Vec<RecordedEvent>>,
+}
+
+/// Registry of named pipeline stages.
+///
+/// Stages are added by [`named`](crate::NamedExt::named) and
+/// [`named_in`](crate::NamedExt::named_in) and removed when the named stream is
+/// dropped, so the registry always describes the live topology. Edges are discovered
+/// while items flow: a named stage that is polled on behalf of another named stage is
+/// recorded as its upstream. Stages connected across a spawned task (for example
+/// through [`share`](crate::ShareExt::share)) are therefore not linked.
+///
+/// Cloning a registry yields another handle to the same topology.
+#[derive(Debug, Clone)]
+pub struct PipelineRegistry {
+    inner: Arc<RegistryInner>,
+}
+
+impl PipelineRegistry {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RegistryInner {
+                id: NEXT_REGISTRY_ID.fetch_add(1, Ordering::Relaxed),
+                topology: Mutex::new(Topology::default()),
+                recording: AtomicBool::new(false),
+                events: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns the process-wide registry used by [`named`](crate::NamedExt::named).
+    pub fn global() -> &'static PipelineRegistry {
+        static GLOBAL: OnceLock<PipelineRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(PipelineRegistry::new)
+    }
+
+    /// Returns a snapshot of the current topology.
+    pub fn graph(&self) -> PipelineGraph {
+        let topology = self.inner.topology.lock();
+        PipelineGraph {
+            nodes: topology
+                .nodes
+                .iter()
+                .map(|(id, entry)| PipelineNode {
+                    id: *id,
+                    name: entry.name.clone(),
+                    items: entry.stats.items.load(Ordering::Relaxed),
+                    errors: entry.stats.errors.load(Ordering::Relaxed),
+                })
+                .collect(),
+            edges: topology.edges.iter().copied().collect(),
+        }
+    }
+
+    /// Renders the current topology in Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        self.graph().to_dot()
+    }
+
+    /// Starts capturing every item emitted by the stages of this registry, discarding
+    /// any previous recording.
+    ///
+    /// Each item is recorded as leaving the stage that emitted it and, when that stage
+    /// was polled by another named stage, as entering that stage. Operators between the
+    /// two named stages may still drop or change the item, which shows up as an item
+    /// entering a stage without a matching item leaving it. Recording renders every
+    /// item with `Debug`, so it is meant for debugging sessions rather than production.
+    pub fn start_recording(&self) {
+        self.inner.events.lock().clear();
+        self.inner.recording.store(true, Ordering::Relaxed);
+    }
+
+    /// Stops capturing and returns what was recorded.
+    pub fn stop_recording(&self) -> Recording {
+        self.inner.recording.store(false, Ordering::Relaxed);
+        Recording {
+            events: core::mem::take(&mut *self.inner.events.lock()),
+        }
+    }
+
+    /// Returns a copy of what has been recorded so far, without stopping.
+    pub fn recording(&self) -> Recording {
+        Recording {
+            events: self.inner.events.lock().clone(),
+        }
+    }
+
+    /// Returns whether items are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.inner.recording.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record(&self, id: NodeId, downstream: Option<NodeId>, item: RecordedItem) {
+        let name = |topology: &Topology, id: NodeId| {
+            topology
+                .nodes
+                .get(&id)
+                .map(|entry| entry.name.clone())
+                .unwrap_or_default()
+        };
+        let (stage, downstream) = {
+            let topology = self.inner.topology.lock();
+            (
+                name(&topology, id),
+                downstream.map(|downstream| (downstream, name(&topology, downstream))),
+            )
+        };
+
+        let mut events = self.inner.events.lock();
+        let sequence = events.len();
+        events.push(RecordedEvent {
+            sequence,
+            node: id,
+            stage,
+            transition: Transition::Left,
+            item: item.clone(),
+        });
+        if let Some((node, stage)) = downstream {
+            events.push(RecordedEvent {
+                sequence: sequence + 1,
+                node,
+                stage,
+                transition: Transition::Entered,
+                item,
+            });
+        }
+    }
+
+    pub(crate) fn register(&self, name: &str) -> (NodeId, Arc<NodeStats>) {
+        let mut topology = self.inner.topology.lock();
+        let id = topology.next_node;
+        topology.next_node += 1;
+
+        let stats = Arc::new(NodeStats::default());
+        topology.nodes.insert(
+            id,
+            NodeEntry {
+                name: String::from(name),
+                stats: Arc::clone(&stats),
+            },
+        );
+        (id, stats)
+    }
+
+    pub(crate) fn unregister(&self, id: NodeId) {
+        let mut topology = self.inner.topology.lock();
+        topology.nodes.remove(&id);
+        topology
+            .edges
+            .retain(|edge| edge.from != id && edge.to != id);
+    }
+
+    fn link(&self, from: NodeId, to: NodeId) {
+        let mut topology = self.inner.topology.lock();
+        if topology.nodes.contains_key(&from) && topology.nodes.contains_key(&to) {
+            topology.edges.insert(PipelineEdge { from, to });
+        }
+    }
+}
+
+impl Default for PipelineRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Marks a named stage as being polled for the lifetime of the guard.
+///
+/// On creation, links the stage to the named stage currently being polled on this
+/// thread (its downstream consumer), if any belongs to the same registry.
+pub(crate) struct PollScope {
+    /// The named stage polling this one, if any.
+    pub(crate) downstream: Option<NodeId>,
+}
+
+impl PollScope {
+    pub(crate) fn enter(
+        registry: &PipelineRegistry,
+        id: NodeId,
+        linked: &mut BTreeSet<NodeId>,
+    ) -> Self {
+        let registry_id = registry.inner.id;
+        let downstream = POLL_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let downstream = stack
+                .last()
+                .filter(|(owner, _)| *owner == registry_id)
+                .map(|(_, node)| *node);
+            stack.push((registry_id, id));
+            downstream
+        });
+
+        if let Some(downstream) = downstream {
+            if linked.insert(downstream) {
+                registry.link(id, downstream);
+            }
+        }
+
+        Self { downstream }
+    }
+}
+
+impl Drop for PollScope {
+    fn drop(&mut self) {
+        POLL_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
@@ -4,8 +4,12 @@
 
 macro_rules! define_on_error_impl {
     ($($bounds:tt)*) => {
+        use alloc::boxed::Box;
+        use core::future::Future;
+        use core::task::Poll;
         use fluxion_core::{FluxionError, StreamItem};
         use futures::future::ready;
+        use futures::stream::FuturesUnordered;
         use futures::{Stream, StreamExt};
 
         pub trait OnErrorExt<T>: Stream<Item = StreamItem<T>> + Sized {
@@ -13,6 +17,22 @@ macro_rules! define_on_error_impl {
             where
                 F: FnMut(&FluxionError) -> bool + $($bounds)* 'static,
                 Self: $($bounds)* 'static;
+
+            /// Consumes each error by handing it to an async `handler`, running up to
+            /// `limit` handlers at once without holding back the values.
+            ///
+            /// While `limit` handlers are in flight, the source is not polled until one of
+            /// them completes. When the source ends, the stream ends once every handler
+            /// has completed.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is 0.
+            fn on_error_async<F, Fut>(self, limit: usize, handler: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut(FluxionError) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = ()> + $($bounds)* 'static,
+                Self: $($bounds)* 'static;
         }
 
         impl<S, T> OnErrorExt<T> for S
@@ -37,6 +57,43 @@ macro_rules! define_on_error_impl {
                     })
                 })
             }
+
+            fn on_error_async<F, Fut>(self, limit: usize, mut handler: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                F: FnMut(FluxionError) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = ()> + $($bounds)* 'static,
+            {
+                assert!(limit > 0, "on_error_async requires a limit of at least 1");
+
+                let mut source = Box::pin(self);
+                let mut in_flight: FuturesUnordered<Fut> = FuturesUnordered::new();
+                let mut done = false;
+
+                futures::stream::poll_fn(move |cx| loop {
+                    // Completed handlers make room for the next errors
+                    while let Poll::Ready(Some(())) = in_flight.poll_next_unpin(cx) {}
+
+                    if done {
+                        return if in_flight.is_empty() {
+                            Poll::Ready(None)
+                        } else {
+                            Poll::Pending
+                        };
+                    }
+                    if in_flight.len() >= limit {
+                        return Poll::Pending;
+                    }
+
+                    match source.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(StreamItem::Value(value))) => {
+                            return Poll::Ready(Some(StreamItem::Value(value)));
+                        }
+                        Poll::Ready(Some(StreamItem::Error(err))) => in_flight.push(handler(err)),
+                        Poll::Ready(None) => done = true,
+                        Poll::Pending => return Poll::Pending,
+                    }
+                })
+            }
         }
     };
 }
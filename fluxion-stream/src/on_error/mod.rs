@@ -17,6 +17,14 @@
 ///
 /// Use `on_error` for side effects (logging) or error recovery (suppression).
 ///
+/// [`OnErrorExt::on_error_async`] consumes every error with an async handler instead,
+/// such as a write to an error queue or a call to an alerting API:
+///
+/// - Values are emitted while handlers run, so a slow handler does not delay them
+/// - At most `limit` handlers are in flight; once the limit is reached, the source is
+///   not polled until a handler completes
+/// - The stream ends after the source has ended and every handler has completed
+///
 /// # Examples
 ///
 /// ## Basic Error Consumption
@@ -42,9 +50,33 @@
 /// # }
 /// ```
 ///
+/// ## Async Error Handler
+///
+/// ```rust
+/// use fluxion_stream::{OnErrorExt, IntoFluxionStream};
+/// use fluxion_test_utils::sequenced::Sequenced;
+/// use futures::StreamExt;
+///
+/// # async fn example() {
+/// let (tx, rx) = async_channel::unbounded();
+/// let (alerts, _alerts_rx) = async_channel::unbounded();
+///
+/// let mut stream = rx.into_fluxion_stream().on_error_async(4, move |err| {
+///     let alerts = alerts.clone();
+///     async move {
+///         let _ = alerts.send(err.to_string()).await;
+///     }
+/// });
+///
+/// tx.try_send(Sequenced::new(1)).unwrap();
+/// assert_eq!(stream.next().await.unwrap().unwrap().into_inner(), 1);
+/// # }
+/// ```
+///
 /// # See Also
 ///
 /// - [`OnErrorExt::on_error`]
+/// - [`OnErrorExt::on_error_async`]
 #[macro_use]
 mod implementation;
 
@@ -4,6 +4,8 @@
 
 macro_rules! define_ordered_merge_impl {
     ($($bounds:tt)*) => {
+        use crate::coop::CoopBudget;
+        use crate::ordering::OrderingMode;
         use alloc::boxed::Box;
         use alloc::vec;
         use alloc::vec::Vec;
@@ -25,6 +27,29 @@ macro_rules! define_ordered_merge_impl {
             where
                 IS: IntoStream<Item = StreamItem<T>>,
                 IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static;
+
+            /// Merges the streams like [`ordered_merge`](Self::ordered_merge), in the given
+            /// [`OrderingMode`].
+            fn ordered_merge_with_mode<IS>(
+                self,
+                others: Vec<IS>,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static;
+
+            /// Merges the streams like [`ordered_merge`](Self::ordered_merge), yielding to
+            /// the executor after `budget` items in a row instead of
+            /// [`DEFAULT_COOP_BUDGET`](crate::DEFAULT_COOP_BUDGET). `0` disables yielding.
+            fn ordered_merge_with_budget<IS>(
+                self,
+                others: Vec<IS>,
+                budget: u32,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static;
         }
 
         impl<T, S> OrderedStreamExt<T> for S
@@ -39,20 +64,61 @@ macro_rules! define_ordered_merge_impl {
                 IS: IntoStream<Item = StreamItem<T>>,
                 IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
             {
-                let mut all_streams: PinnedStreams<T> = vec![];
-                all_streams.push(Box::pin(self));
-                for into_stream in others {
-                    let stream = into_stream.into_stream();
-                    all_streams.push(Box::pin(stream));
-                }
+                self.ordered_merge_with_mode(others, OrderingMode::Ordered)
+            }
 
-                StreamExt::map(
-                    OrderedMergeWithImmediateErrorsIndexed::new(all_streams),
-                    |(item, _index)| item,
-                )
+            fn ordered_merge_with_mode<IS>(
+                self,
+                others: Vec<IS>,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            {
+                merge_all(self, others, mode, CoopBudget::new())
+            }
+
+            fn ordered_merge_with_budget<IS>(
+                self,
+                others: Vec<IS>,
+                budget: u32,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            {
+                merge_all(self, others, OrderingMode::Ordered, CoopBudget::with_limit(budget))
             }
         }
 
+        fn merge_all<T, S, IS>(
+            first: S,
+            others: Vec<IS>,
+            mode: OrderingMode,
+            budget: CoopBudget,
+        ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            IS: IntoStream<Item = StreamItem<T>>,
+            IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+        {
+            let mut all_streams: PinnedStreams<T> = vec![];
+            all_streams.push(Box::pin(first));
+            for into_stream in others {
+                let stream = into_stream.into_stream();
+                all_streams.push(Box::pin(stream));
+            }
+
+            StreamExt::map(
+                OrderedMergeWithImmediateErrorsIndexed::new(all_streams, mode, budget),
+                |(item, _index)| item,
+            )
+        }
+
         pub fn ordered_merge_with_index<T>(
             streams: PinnedStreams<T>,
         ) -> impl Stream<Item = (StreamItem<T>, usize)> + $($bounds)*
@@ -61,7 +127,20 @@ macro_rules! define_ordered_merge_impl {
             T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
             T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
         {
-            OrderedMergeWithImmediateErrorsIndexed::new(streams)
+            OrderedMergeWithImmediateErrorsIndexed::new(streams, OrderingMode::Ordered, CoopBudget::new())
+        }
+
+        /// Merges `streams` like [`ordered_merge_with_index`], in the given [`OrderingMode`].
+        pub fn ordered_merge_with_index_and_mode<T>(
+            streams: PinnedStreams<T>,
+            mode: OrderingMode,
+        ) -> impl Stream<Item = (StreamItem<T>, usize)> + $($bounds)*
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            OrderedMergeWithImmediateErrorsIndexed::new(streams, mode, CoopBudget::new())
         }
 
         struct OrderedMergeWithImmediateErrorsIndexed<T>
@@ -72,6 +151,10 @@ macro_rules! define_ordered_merge_impl {
         {
             streams: PinnedStreams<T>,
             buffered: Vec<Option<T>>,
+            budget: CoopBudget,
+            mode: OrderingMode,
+            // Stream polled first in unordered mode
+            next: usize,
         }
 
         impl<T> OrderedMergeWithImmediateErrorsIndexed<T>
@@ -80,10 +163,45 @@ macro_rules! define_ordered_merge_impl {
             T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
             T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
         {
-            fn new(streams: PinnedStreams<T>) -> Self {
+            fn new(streams: PinnedStreams<T>, mode: OrderingMode, budget: CoopBudget) -> Self {
                 let count = streams.len();
                 let buffered = (0..count).map(|_| None).collect();
-                Self { streams, buffered }
+                Self {
+                    streams,
+                    buffered,
+                    budget,
+                    mode,
+                    next: 0,
+                }
+            }
+
+            /// Emits the first item ready, polling the streams round-robin from `next`, the
+            /// stream after the one that produced the previous item.
+            fn poll_unordered(&mut self, cx: &mut Context<'_>) -> Poll<Option<(StreamItem<T>, usize)>> {
+                let count = self.streams.len();
+                let mut any_pending = false;
+
+                for offset in 0..count {
+                    let i = (self.next + offset) % count;
+                    match self.streams[i].as_mut().poll_next(cx) {
+                        Poll::Ready(Some(item)) => {
+                            self.next = (i + 1) % count;
+                            self.budget.consume();
+                            return Poll::Ready(Some((item, i)));
+                        }
+                        Poll::Ready(None) => {}
+                        Poll::Pending => {
+                            any_pending = true;
+                        }
+                    }
+                }
+
+                if any_pending {
+                    self.budget.reset();
+                    Poll::Pending
+                } else {
+                    Poll::Ready(None)
+                }
             }
         }
 
@@ -96,12 +214,21 @@ macro_rules! define_ordered_merge_impl {
             type Item = (StreamItem<T>, usize);
 
             fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                if self.budget.poll_proceed(cx).is_pending() {
+                    return Poll::Pending;
+                }
+
+                if self.mode == OrderingMode::Unordered {
+                    return self.poll_unordered(cx);
+                }
+
                 let mut any_pending = false;
 
                 for i in 0..self.streams.len() {
                     if self.buffered[i].is_none() {
                         match self.streams[i].as_mut().poll_next(cx) {
                             Poll::Ready(Some(StreamItem::Error(e))) => {
+                                self.budget.consume();
                                 return Poll::Ready(Some((StreamItem::Error(e), i)));
                             }
                             Poll::Ready(Some(StreamItem::Value(item))) => {
@@ -131,11 +258,13 @@ macro_rules! define_ordered_merge_impl {
 
                 if let Some(idx) = min_idx {
                     if let Some(item) = self.buffered[idx].take() {
+                        self.budget.consume();
                         Poll::Ready(Some((StreamItem::Value(item), idx)))
                     } else {
                         unreachable!("min_idx is only Some when buffered[idx] is Some")
                     }
                 } else if any_pending {
+                    self.budget.reset();
                     Poll::Pending
                 } else {
                     Poll::Ready(None)
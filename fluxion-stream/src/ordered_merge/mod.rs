@@ -14,6 +14,10 @@
 //! - **Fair**: Merges streams fairly assuming they are reasonably synchronized.
 //! - **Buffered**: Buffers one item from each stream to determine the minimum timestamp.
 //!
+//! [`ordered_merge_with_mode`](OrderedStreamExt::ordered_merge_with_mode) with
+//! [`OrderingMode::Unordered`](crate::OrderingMode::Unordered) emits items without
+//! waiting for the other streams instead.
+//!
 //! # Example
 //!
 //! ```rust
@@ -59,7 +63,9 @@ mod multi_threaded;
     feature = "runtime-smol",
     feature = "runtime-async-std"
 ))]
-pub use multi_threaded::{ordered_merge_with_index, OrderedStreamExt};
+pub use multi_threaded::{
+    ordered_merge_with_index, ordered_merge_with_index_and_mode, OrderedStreamExt,
+};
 
 #[cfg(not(any(
     all(feature = "runtime-tokio", not(target_arch = "wasm32")),
@@ -73,4 +79,6 @@ mod single_threaded;
     feature = "runtime-smol",
     feature = "runtime-async-std"
 )))]
-pub use single_threaded::{ordered_merge_with_index, OrderedStreamExt};
+pub use single_threaded::{
+    ordered_merge_with_index, ordered_merge_with_index_and_mode, OrderedStreamExt,
+};
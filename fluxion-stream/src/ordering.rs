@@ -0,0 +1,28 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Choice between timestamp order and arrival order for merging streams.
+
+/// How a merging operator orders the items it takes from its inputs.
+///
+/// Passed per operator to the `_with_mode` variants of the merging operators, such as
+/// [`ordered_merge_with_mode`](crate::OrderedStreamExt::ordered_merge_with_mode) and
+/// [`combine_latest_with_mode`](crate::CombineLatestExt::combine_latest_with_mode), so
+/// one pipeline definition can be built in either mode, for example from a deployment's
+/// configuration. The merging operators without such a variant, such as the timestamp
+/// joins, always merge in timestamp order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OrderingMode {
+    /// Of the items ready on the inputs, the one with the smallest timestamp is emitted
+    /// first.
+    #[default]
+    Ordered,
+    /// Items are emitted as soon as an input has one ready, whatever their timestamps.
+    ///
+    /// Inputs are polled round-robin, starting with the input after the one that produced
+    /// the previous item, so an input that is always ready cannot starve the others. Items
+    /// ready on several inputs at once are therefore emitted in that rotation, not in the
+    /// order they arrived.
+    Unordered,
+}
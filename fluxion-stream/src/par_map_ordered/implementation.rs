@@ -0,0 +1,179 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::{Fluxion, StreamItem};
+use futures::Stream;
+use pin_project::pin_project;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+type WorkerResult<U> = (u64, thread::Result<U>);
+
+pub trait ParMapOrderedExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: Fluxion,
+    T::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+    T::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+{
+    /// Transforms values in parallel on a dedicated pool of `width` worker threads,
+    /// emitting results in source order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0 or if a worker thread cannot be spawned.
+    fn par_map_ordered<U, F>(
+        self,
+        width: usize,
+        f: F,
+    ) -> impl Stream<Item = StreamItem<U>> + Send + Sync
+    where
+        Self: Send + Sync + 'static,
+        U: Fluxion,
+        U::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+        U::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static;
+}
+
+impl<S, T> ParMapOrderedExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Fluxion,
+    T::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+    T::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+{
+    fn par_map_ordered<U, F>(
+        self,
+        width: usize,
+        f: F,
+    ) -> impl Stream<Item = StreamItem<U>> + Send + Sync
+    where
+        Self: Send + Sync + 'static,
+        U: Fluxion,
+        U::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+        U::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static,
+    {
+        assert!(width > 0, "par_map_ordered requires a width of at least 1");
+
+        let (jobs, job_queue) = async_channel::unbounded::<(u64, T)>();
+        let (result_sender, results) = async_channel::unbounded::<WorkerResult<U>>();
+        let f = Arc::new(f);
+
+        for worker in 0..width {
+            let job_queue = job_queue.clone();
+            let result_sender = result_sender.clone();
+            let f = Arc::clone(&f);
+            thread::Builder::new()
+                .name(format!("fluxion-par-map-{worker}"))
+                .spawn(move || {
+                    while let Ok((index, value)) = job_queue.recv_blocking() {
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| f(value)));
+                        if result_sender.send_blocking((index, result)).is_err() {
+                            break;
+                        }
+                    }
+                })
+                .expect("par_map_ordered: failed to spawn worker thread");
+        }
+
+        Box::pin(ParMapOrdered {
+            source: self,
+            source_done: false,
+            jobs: Some(jobs),
+            results,
+            ready: BTreeMap::new(),
+            width: width as u64,
+            next_index: 0,
+            next_emit: 0,
+        })
+    }
+}
+
+/// Dispatches values to the worker pool and re-sequences the results.
+///
+/// Every source item gets an index in arrival order. Values are queued for the workers;
+/// errors go straight into the reorder buffer. Items are emitted by ascending index, so a
+/// fast worker's result waits until every earlier item has been emitted.
+#[pin_project]
+struct ParMapOrdered<S, T, U> {
+    #[pin]
+    source: S,
+    source_done: bool,
+    /// Dropped once the source ends, so idle workers exit.
+    jobs: Option<async_channel::Sender<(u64, T)>>,
+    #[pin]
+    results: async_channel::Receiver<WorkerResult<U>>,
+    ready: BTreeMap<u64, StreamItem<U>>,
+    width: u64,
+    next_index: u64,
+    next_emit: u64,
+}
+
+impl<S, T, U> Stream for ParMapOrdered<S, T, U>
+where
+    S: Stream<Item = StreamItem<T>>,
+{
+    type Item = StreamItem<U>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(item) = this.ready.remove(this.next_emit) {
+                *this.next_emit += 1;
+                return Poll::Ready(Some(item));
+            }
+
+            // Keep at most `width` items between dispatch and emission
+            while !*this.source_done && *this.next_index - *this.next_emit < *this.width {
+                match this.source.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(StreamItem::Value(value))) => {
+                        if let Some(jobs) = this.jobs.as_ref() {
+                            // Workers only stop when the queue closes, so this cannot fail
+                            let _ = jobs.try_send((*this.next_index, value));
+                        }
+                        *this.next_index += 1;
+                    }
+                    Poll::Ready(Some(StreamItem::Error(e))) => {
+                        this.ready.insert(*this.next_index, StreamItem::Error(e));
+                        *this.next_index += 1;
+                    }
+                    Poll::Ready(None) => {
+                        *this.source_done = true;
+                        *this.jobs = None;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+
+            if this.ready.contains_key(this.next_emit) {
+                continue;
+            }
+
+            if this.next_emit == this.next_index {
+                return if *this.source_done {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                };
+            }
+
+            match this.results.as_mut().poll_next(cx) {
+                Poll::Ready(Some((index, Ok(value)))) => {
+                    this.ready.insert(index, StreamItem::Value(value));
+                }
+                Poll::Ready(Some((_, Err(payload)))) => panic::resume_unwind(payload),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
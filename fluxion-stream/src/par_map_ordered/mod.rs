@@ -0,0 +1,69 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Data-parallel transformation operator with ordered re-emission.
+//!
+//! [`par_map_ordered`](ParMapOrderedExt::par_map_ordered) fans values out to a pool of
+//! `width` worker threads owned by the operator, transforms them in parallel and
+//! re-sequences the results so they leave in the order, and therefore with the
+//! timestamps, they arrived in. It suits throughput-bound pipelines on multi-core
+//! machines, where the closure is the bottleneck.
+//!
+//! Unlike [`map_ordered_blocking_concurrent`](crate::MapOrderedBlockingExt::map_ordered_blocking_concurrent),
+//! which borrows threads from the runtime's shared blocking pool, the workers here are
+//! dedicated to one stream: their number is fixed by `width`, and a long-running
+//! pipeline does not compete with other blocking work for them. Use
+//! `map_ordered_blocking` for occasional blocking calls and `par_map_ordered` for a
+//! stream that keeps several cores busy.
+//!
+//! # Behavior
+//!
+//! - Results are emitted strictly in source order; a result that finishes early waits
+//!   in a reorder buffer until every earlier item has been emitted
+//! - At most `width` items are between dispatch and emission; beyond that the operator
+//!   stops pulling from the source until the oldest one is emitted
+//! - Errors are passed through unchanged and keep their position relative to the values
+//! - A panic in the closure is resumed on the task polling the stream
+//! - The workers are spawned when the operator is created and exit once the source has
+//!   ended or the stream is dropped, after finishing the value they are working on
+//!
+//! Only available with a multi-threaded runtime.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::ParMapOrderedExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//!
+//! fn collatz_steps(mut n: u64) -> u64 {
+//!     let mut steps = 0;
+//!     while n != 1 {
+//!         n = if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+//!         steps += 1;
+//!     }
+//!     steps
+//! }
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<u64>>();
+//!
+//! let mut steps = stream.par_map_ordered(4, |item: Sequenced<u64>| {
+//!     Sequenced::with_timestamp(collatz_steps(item.value), item.timestamp())
+//! });
+//!
+//! tx.unbounded_send((27, 1).into()).unwrap();
+//! tx.unbounded_send((6, 2).into()).unwrap();
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut steps, 500).await)).value, 111);
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut steps, 500).await)).value, 8);
+//! # }
+//! ```
+
+mod implementation;
+
+pub use implementation::ParMapOrderedExt;
@@ -18,11 +18,12 @@ impl Drop for TaskGuard {
 macro_rules! define_partition_impl {
     ($($bounds:tt)*) => {
         use super::implementation::TaskGuard;
+        use crate::coop::CoopBudget;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use core::fmt::Debug;
         use core::pin::Pin;
         use core::task::{Context, Poll};
+        use fluxion_core::sync::Arc;
         use fluxion_core::{Fluxion, FluxionSubject, FluxionTask, StreamItem};
         use futures::future::{select, Either};
         use futures::{Stream, StreamExt};
@@ -72,6 +73,18 @@ macro_rules! define_partition_impl {
             where
                 Self: Unpin + $($bounds)* 'static,
                 F: Fn(&T::Inner) -> bool + $($bounds)* 'static;
+
+            /// Splits the stream like [`partition`](Self::partition), yielding to the
+            /// executor after `budget` items in a row instead of
+            /// [`DEFAULT_COOP_BUDGET`](crate::DEFAULT_COOP_BUDGET). `0` disables yielding.
+            fn partition_with_budget<F>(
+                self,
+                predicate: F,
+                budget: u32,
+            ) -> (PartitionedStream<T>, PartitionedStream<T>)
+            where
+                Self: Unpin + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> bool + $($bounds)* 'static;
         }
 
         impl<S, T> PartitionExt<T> for S
@@ -82,6 +95,18 @@ macro_rules! define_partition_impl {
             T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
         {
             fn partition<F>(self, predicate: F) -> (PartitionedStream<T>, PartitionedStream<T>)
+            where
+                Self: Unpin + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> bool + $($bounds)* 'static,
+            {
+                self.partition_with_budget(predicate, crate::DEFAULT_COOP_BUDGET)
+            }
+
+            fn partition_with_budget<F>(
+                self,
+                predicate: F,
+                budget: u32,
+            ) -> (PartitionedStream<T>, PartitionedStream<T>)
             where
                 Self: Unpin + $($bounds)* 'static,
                 F: Fn(&T::Inner) -> bool + $($bounds)* 'static,
@@ -96,6 +121,7 @@ macro_rules! define_partition_impl {
                     .subscribe()
                     .unwrap_or_else(|_| unreachable!("fresh subject should allow subscription"));
 
+                let mut budget = CoopBudget::with_limit(budget);
                 let task = FluxionTask::spawn(|cancel| async move {
                     let mut stream = self;
                     while let Either::Left((stream_item, _)) =
@@ -109,6 +135,8 @@ macro_rules! define_partition_impl {
                                     }
                                 } else if false_subject.next(value).is_err() {
                                 }
+                                budget.consume();
+                                budget.proceed().await;
                             }
                             Some(StreamItem::Error(e)) => {
                                 let _ = true_subject.error(e.clone());
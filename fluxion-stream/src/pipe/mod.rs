@@ -0,0 +1,180 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Reusable pipeline composition.
+//!
+//! [`pipe`](PipeExt::pipe) hands the whole stream to a closure and returns whatever it
+//! builds, so a chain of operators can be written once and applied inline.
+//! [`pipe_with`](PipeExt::pipe_with) does the same for any [`Pipe`]:
+//!
+//! - any closure or function taking the stream is a [`Pipe`];
+//! - a [`Pipeline`] wraps such a function under a name and can be applied by reference
+//!   to many streams;
+//! - any type can implement [`Pipe`] to package a configurable chain, including chains
+//!   that are generic over the input stream.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::{HasTimestamp, StreamItem};
+//! use fluxion_stream::pipe::{PipeExt, Pipeline};
+//! use fluxion_stream::{from_iter, FilterOrderedExt, MapOrderedExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::{Stream, StreamExt};
+//!
+//! fn standard_enrichment(
+//!     stream: impl Stream<Item = StreamItem<Sequenced<i32>>> + Send + Sync + Unpin + 'static,
+//! ) -> impl Stream<Item = StreamItem<Sequenced<i32>>> + Send + Sync {
+//!     stream
+//!         .filter_ordered(|value| *value > 0)
+//!         .map_ordered(|item| Sequenced::with_timestamp(item.value * 100, item.timestamp()))
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! // Inline, for a single stream.
+//! let inline: Vec<_> = from_iter::<Sequenced<i32>, _>(vec![1, -2])
+//!     .pipe(|stream| standard_enrichment(stream))
+//!     .map(|item| item.unwrap().value)
+//!     .collect()
+//!     .await;
+//! assert_eq!(inline, vec![100]);
+//!
+//! // As a named value, for many streams.
+//! let enrichment = Pipeline::new("standard-enrichment", standard_enrichment);
+//!
+//! let first: Vec<_> = from_iter::<Sequenced<i32>, _>(vec![1, -2, 3])
+//!     .pipe_with(&enrichment)
+//!     .map(|item| item.unwrap().value)
+//!     .collect()
+//!     .await;
+//! let second: Vec<_> = from_iter::<Sequenced<i32>, _>(vec![-4, 5])
+//!     .pipe_with(&enrichment)
+//!     .map(|item| item.unwrap().value)
+//!     .collect()
+//!     .await;
+//!
+//! assert_eq!(first, vec![100, 300]);
+//! assert_eq!(second, vec![500]);
+//! # }
+//! ```
+
+use core::fmt::{self, Debug};
+
+/// A reusable transformation of a stream of type `S`.
+///
+/// Implemented for every `FnOnce(S) -> R`. Implement it for your own types to package
+/// configurable operator chains:
+///
+/// ```rust
+/// use fluxion_core::StreamItem;
+/// use fluxion_stream::boxed::{BoxedExt, BoxedFluxionStream};
+/// use fluxion_stream::pipe::Pipe;
+/// use fluxion_stream::FilterOrderedExt;
+/// use fluxion_test_utils::sequenced::Sequenced;
+/// use futures::Stream;
+///
+/// struct AboveThreshold(i32);
+///
+/// impl<S> Pipe<S> for &AboveThreshold
+/// where
+///     S: Stream<Item = StreamItem<Sequenced<i32>>> + Send + Sync + Unpin + 'static,
+/// {
+///     type Output = BoxedFluxionStream<Sequenced<i32>>;
+///
+///     fn apply(self, stream: S) -> Self::Output {
+///         let threshold = self.0;
+///         stream.filter_ordered(move |value| *value > threshold).into_boxed()
+///     }
+/// }
+/// ```
+pub trait Pipe<S> {
+    /// What the transformation produces, usually a stream.
+    type Output;
+
+    fn apply(self, stream: S) -> Self::Output;
+}
+
+impl<S, R, F> Pipe<S> for F
+where
+    F: FnOnce(S) -> R,
+{
+    type Output = R;
+
+    fn apply(self, stream: S) -> R {
+        self(stream)
+    }
+}
+
+/// A named, reusable operator chain.
+///
+/// Apply it by reference with [`pipe_with`](PipeExt::pipe_with) to use it for many streams of
+/// the same type, or by value to use it once.
+#[derive(Clone)]
+pub struct Pipeline<F> {
+    name: &'static str,
+    chain: F,
+}
+
+impl<F> Pipeline<F> {
+    pub const fn new(name: &'static str, chain: F) -> Self {
+        Self { name, chain }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<F> Debug for Pipeline<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<S, R, F> Pipe<S> for &Pipeline<F>
+where
+    F: Fn(S) -> R,
+{
+    type Output = R;
+
+    fn apply(self, stream: S) -> R {
+        (self.chain)(stream)
+    }
+}
+
+impl<S, R, F> Pipe<S> for Pipeline<F>
+where
+    F: FnOnce(S) -> R,
+{
+    type Output = R;
+
+    fn apply(self, stream: S) -> R {
+        (self.chain)(stream)
+    }
+}
+
+/// Extension trait providing [`pipe`](PipeExt::pipe) and [`pipe_with`](PipeExt::pipe_with)
+/// for every stream.
+pub trait PipeExt: Sized {
+    /// Applies `f` to the whole stream and returns its output.
+    ///
+    /// `stream.pipe(f)` is `f(stream)` written in method-chain order.
+    fn pipe<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(Self) -> R,
+    {
+        f(self)
+    }
+
+    /// Applies a [`Pipe`], such as a [`Pipeline`], to the whole stream and returns its
+    /// output.
+    fn pipe_with<P: Pipe<Self>>(self, pipe: P) -> P::Output {
+        pipe.apply(self)
+    }
+}
+
+impl<S: futures::Stream> PipeExt for S {}
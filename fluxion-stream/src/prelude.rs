@@ -21,16 +21,88 @@
 //!
 //! ## Extension Traits (Operators)
 //!
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`AbRouteExt`] - Split a stream into A and B by a ratio adjustable at runtime"
+)]
+//! - [`AlignByTimestampExt`] - Pair items of two streams by timestamp within a tolerance
+//! - [`AsOfJoinExt`] - Pair items with the latest earlier item for the same key
+//! - [`BoxedExt`] - Erase stream types for storage in fields
+//! - [`BufferWhenExt`] - Hold items while a control stream is closed
+//! - [`CacheLatestExt`] - Keep the latest value per key in a queryable cache
+#![cfg_attr(
+    feature = "tower",
+    doc = "- [`CallServiceExt`] - Drive items through a tower `Service`"
+)]
 //! - [`CombineLatestExt`] - Combine latest values from multiple streams
 //! - [`CombineWithPreviousExt`] - Pair each value with its predecessor
 //! - [`DistinctUntilChangedExt`] - Suppress consecutive duplicates
 //! - [`DistinctUntilChangedByExt`] - Suppress duplicates by custom comparison
+//! - [`DownsampleExt`] - Summarize each interval of timestamps into one item
+//! - [`DynamicConfigExt`] - Filter or transform with parameters updated at runtime
 //! - [`EmitWhenExt`] - Gate emissions based on condition
+//! - [`FilterMapOrderedExt`] - Filter and transform items in one step
 //! - [`FilterOrderedExt`] - Filter items preserving temporal order
+//! - [`FilterOrderedAsyncExt`] - Filter items with an async predicate
+//! - [`FilterOutliersExt`] - Drop or divert statistical outliers
+//! - [`FinalizeExt`] - Run a callback when a stream completes or is dropped
+//! - [`ForkExt`] - Split a stream into N bounded copies
+//! - [`InterceptExt`] - Wrap every following stage with an [`Interceptor`]
+//! - [`IntoValuesExt`] - Unwrap items into plain values for non-fluxion consumers
+//! - [`LookupJoinExt`] - Enrich items with cached async lookups
+//! - [`MapBatchExt`] - Transform or filter items in batches
 //! - [`MapOrderedExt`] - Transform items preserving temporal order
+#![cfg_attr(
+    any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    ),
+    doc = "- [`MapOrderedBlockingExt`] - Transform items on the blocking thread pool"
+)]
+//! - [`MatchPatternExt`] - Detect ordered sequences of events
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`MaterializeExt`] - Maintain a queryable table of a keyed changelog"
+)]
+//! - [`MemoryBudgetExt`] - Cap the memory held by buffering stages
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`NamedExt`] - Label stages for pipeline topology export"
+)]
 //! - [`OnErrorExt`] - Handle stream errors
 //! - [`OrderedStreamExt`] - Merge streams with temporal ordering
+#![cfg_attr(
+    any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    ),
+    doc = "- [`ParMapOrderedExt`] - Transform items in parallel on a worker pool"
+)]
+//! - [`PipeExt`] - Apply reusable operator chains
+//! - [`PriorityMergeExt`] - Merge streams preferring higher priorities
+//! - [`ProcessWithTimersExt`] - Custom stateful logic with event-time timers
+//! - [`QueryExt`] - Await the first, last or nth value, or test values with a predicate
+//! - [`ReorderExt`] - Restore timestamp order within a bounded buffer
+//! - [`ResampleExt`] - Emit items at a fixed rate of timestamps
 //! - [`ScanOrderedExt`] - Stateful accumulation
+//! - [`SequenceEqualExt`] - Compare two streams item by item
 #![cfg_attr(
     any(
         feature = "runtime-tokio",
@@ -41,19 +113,65 @@
     doc = "- [`ShareExt`] - Convert stream to multi-subscriber source"
 )]
 //! - [`SkipItemsExt`] - Skip first n items
+#![cfg_attr(
+    any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    ),
+    doc = "- [`SpillToDiskExt`] - Buffer a backlog, spilling overflow to disk"
+)]
 //! - [`StartWithExt`] - Prepend initial values
+//! - [`StartWithLatestOfExt`] - Prepend the latest value of another stream
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`StateCellExt`] - Hold the latest value of a stream in a watchable cell"
+)]
 //! - [`TakeItemsExt`] - Take first n items
 //! - [`TakeLatestWhenExt`] - Sample on trigger events
 //! - [`TakeWhileExt`] - Take while condition holds
-//! - [`TapExt`] - Side-effect observation for debugging
+//! - [`TapExt`] - Side-effect observation of values and errors for debugging
+//! - [`ThenOrderedExt`] - Async transformation preserving source order
+//! - [`ToSnapshotExt`] - Compact keyed updates into snapshots of the whole map
+#![cfg_attr(
+    feature = "tracing",
+    doc = "- [`TracedExt`] / [`TraceSpanExt`] - Trace context propagation"
+)]
+//! - [`TryMapOrderedExt`] - Fallible transformation emitting errors as stream items
+//! - [`WindowJoinExt`] - Pair items with the same key in the same time window
+//! - [`WeightedMergeExt`] - Merge streams sharing the output by weight
 //! - [`WindowByCountExt`] - Batch items into fixed-size windows
+//! - [`WindowByTimeExt`] - Aggregate event-time windows with allowed lateness
+//! - [`WindowToggleExt`] - Collect items between opening and closing triggers
+//! - [`WithIndexExt`] - Pair each value with its ordinal index
 //! - [`WithLatestFromExt`] - Combine with latest from secondary streams
 //! - [`IntoFluxionStream`] - Convert receivers to streams
 //!
 //! ## Types
 //!
+//! - [`BoxedFluxionStream`] / [`LocalBoxedFluxionStream`] - Type-erased streams
+//! - [`ForkedStream`] - One copy of a forked stream
+//! - [`LatestCache`] - Query handle of `cache_latest`
 //! - [`CombinedState`] - Combined state from multiple streams
 //! - [`WithPrevious`] - Pair of current and previous values
+//! - [`Indexed`] - Value paired with its ordinal index
+//! - [`JoinWindow`] - Window in which `window_join` pairs items
+//! - [`WindowSpan`] - Timestamp difference sizing a `JoinWindow`
+//! - [`WindowUpdate`] - Insert or retraction emitted by `window_by_time_aggregate`
+//! - [`EventTimers`] / [`TimerId`] - Timers firing when the watermark passes their event time
+//! - [`ReorderWindow`] - Buffer size or hold time of `reorder`
+//! - [`OutlierTest`] - Deviation test of `filter_outliers`
+//! - [`Alignment`] - Pair or unmatched item emitted by `align_by_timestamp`
+//! - [`Divergence`] - First difference between two streams found by `sequence_diff`
+//! - [`ResampleStrategy`] / [`Interpolate`] / [`SpanRatio`] - How `resample` computes values
+//! - [`Aggregator`] / [`OhlcBar`] - How `downsample` summarizes an interval
+//! - [`Pattern`] / [`SkipStrategy`] - Event sequence matched by `match_pattern`
+//! - [`Termination`] - How a stream stopped, passed to `on_terminate`
 #![cfg_attr(
     any(
         feature = "runtime-tokio",
@@ -63,19 +181,109 @@
     ),
     doc = "- [`FluxionShared`] - Multi-subscriber subscription factory"
 )]
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`StateCell`] - Watchable latest value of a stream"
+)]
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`MaterializedView`] / [`ViewChange`] - Queryable table built by `materialize`"
+)]
+//! - [`MemoryBudget`] / [`MemoryPolicy`] / [`BudgetedStream`] - Memory budget of `within_memory_budget`
 //! - [`MergedStream`] - Merged stream type
+#![cfg_attr(
+    any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std"
+    ),
+    doc = "- [`SpillConfig`] / [`SpillCodec`] - Spill file settings and item encoding of `spill_to_disk`"
+)]
+#![cfg_attr(
+    any(
+        feature = "runtime-tokio",
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        target_arch = "wasm32"
+    ),
+    doc = "- [`PipelineRegistry`] - Registry of named stages and their topology"
+)]
 
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use crate::ab_route::{AbRouteExt, RoutedStream, SplitRatio};
+pub use crate::align_by_timestamp::AlignByTimestampExt;
+pub use crate::as_of_join::AsOfJoinExt;
+pub use crate::boxed::{BoxedExt, BoxedFluxionStream, LocalBoxedFluxionStream};
+pub use crate::buffer_when::BufferWhenExt;
+pub use crate::cache_latest::{CacheLatestExt, LatestCache};
+#[cfg(feature = "tower")]
+pub use crate::call_service::CallServiceExt;
 pub use crate::combine_latest::CombineLatestExt;
 pub use crate::combine_with_previous::CombineWithPreviousExt;
 pub use crate::distinct_until_changed::DistinctUntilChangedExt;
 pub use crate::distinct_until_changed_by::DistinctUntilChangedByExt;
+pub use crate::downsample::{Aggregator, DownsampleExt, OhlcBar};
+pub use crate::dynamic_config::DynamicConfigExt;
 pub use crate::emit_when::EmitWhenExt;
+pub use crate::event_timers::{EventTimers, ProcessWithTimersExt, TimerId};
+pub use crate::filter_map_ordered::FilterMapOrderedExt;
 pub use crate::filter_ordered::FilterOrderedExt;
+pub use crate::filter_ordered_async::FilterOrderedAsyncExt;
+pub use crate::filter_outliers::FilterOutliersExt;
+pub use crate::finalize::{FinalizeExt, Termination};
+pub use crate::fork::{ForkExt, ForkedStream};
+pub use crate::intercept::{InterceptExt, Interceptor};
 pub use crate::into_fluxion_stream::IntoFluxionStream;
+pub use crate::into_values::{ErrorPolicy, IntoValuesExt};
+pub use crate::lookup_join::LookupJoinExt;
+pub use crate::map_batch::MapBatchExt;
 pub use crate::map_ordered::MapOrderedExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use crate::map_ordered_blocking::MapOrderedBlockingExt;
+pub use crate::match_pattern::{MatchPatternExt, Pattern, SkipStrategy};
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use crate::materialized_view::{MaterializeExt, MaterializedView, ViewChange};
+pub use crate::memory_budget::{BudgetedStream, MemoryBudget, MemoryBudgetExt, MemoryPolicy};
 pub use crate::merge_with::MergedStream;
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use crate::named::{NamedExt, PipelineRegistry};
 pub use crate::on_error::OnErrorExt;
 pub use crate::ordered_merge::OrderedStreamExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use crate::par_map_ordered::ParMapOrderedExt;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
@@ -83,8 +291,14 @@ pub use crate::ordered_merge::OrderedStreamExt;
     target_arch = "wasm32"
 ))]
 pub use crate::partition::{PartitionExt, PartitionedStream};
+pub use crate::pipe::PipeExt;
+pub use crate::priority_merge::PriorityMergeExt;
+pub use crate::query::QueryExt;
+pub use crate::reorder::ReorderExt;
+pub use crate::resample::ResampleExt;
 pub use crate::sample_ratio::SampleRatioExt;
 pub use crate::scan_ordered::ScanOrderedExt;
+pub use crate::sequence_equal::SequenceEqualExt;
 #[cfg(any(
     feature = "runtime-tokio",
     feature = "runtime-smol",
@@ -93,11 +307,38 @@ pub use crate::scan_ordered::ScanOrderedExt;
 ))]
 pub use crate::share::{FluxionShared, ShareExt};
 pub use crate::skip_items::SkipItemsExt;
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use crate::spill_to_disk::{SpillCodec, SpillConfig, SpillToDiskExt};
 pub use crate::start_with::StartWithExt;
+pub use crate::start_with_latest_of::StartWithLatestOfExt;
+#[cfg(any(
+    feature = "runtime-tokio",
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    target_arch = "wasm32"
+))]
+pub use crate::state_cell::{StateCell, StateCellExt};
 pub use crate::take_items::TakeItemsExt;
 pub use crate::take_latest_when::TakeLatestWhenExt;
 pub use crate::take_while_with::TakeWhileExt;
 pub use crate::tap::TapExt;
-pub use crate::types::{CombinedState, WithPrevious};
+pub use crate::then_ordered::ThenOrderedExt;
+pub use crate::to_snapshot::ToSnapshotExt;
+#[cfg(feature = "tracing")]
+pub use crate::traced::{TraceSpanExt, TracedExt};
+pub use crate::try_map_ordered::TryMapOrderedExt;
+pub use crate::types::{
+    Alignment, CombinedState, Divergence, Indexed, Interpolate, JoinWindow, OutlierTest,
+    ReorderWindow, ResampleStrategy, SpanRatio, WindowSpan, WindowUpdate, WithPrevious,
+};
+pub use crate::weighted_merge::WeightedMergeExt;
 pub use crate::window_by_count::WindowByCountExt;
+pub use crate::window_by_time::WindowByTimeExt;
+pub use crate::window_join::WindowJoinExt;
+pub use crate::window_toggle::WindowToggleExt;
+pub use crate::with_index::WithIndexExt;
 pub use crate::with_latest_from::WithLatestFromExt;
@@ -0,0 +1,145 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_priority_merge_impl {
+    ($($bounds:tt)*) => {
+        use crate::coop::CoopBudget;
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use fluxion_core::{into_stream::IntoStream, Fluxion, StreamItem};
+        use futures::task::{Context, Poll};
+        use futures::Stream;
+
+        type PinnedStream<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)* 'static>>;
+
+        pub trait PriorityMergeExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Merges `self`, with priority `priority`, and `others`, each paired with its
+            /// priority, emitting the ready item of the highest-priority stream first.
+            ///
+            /// Higher numbers are higher priorities. Among ready items of equal priority,
+            /// the one with the smallest timestamp goes first.
+            fn priority_merge<IS>(
+                self,
+                priority: u32,
+                others: Vec<(IS, u32)>,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static;
+        }
+
+        impl<T, S> PriorityMergeExt<T> for S
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+        {
+            fn priority_merge<IS>(
+                self,
+                priority: u32,
+                others: Vec<(IS, u32)>,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            {
+                let mut sources: Vec<(PinnedStream<T>, u32)> = vec![(Box::pin(self), priority)];
+                for (into_stream, priority) in others {
+                    sources.push((Box::pin(into_stream.into_stream()), priority));
+                }
+
+                PriorityMerge::new(sources)
+            }
+        }
+
+        /// Buffers one value per stream and emits the buffered value of the highest
+        /// priority, then the smallest timestamp.
+        struct PriorityMerge<T> {
+            streams: Vec<PinnedStream<T>>,
+            priorities: Vec<u32>,
+            buffered: Vec<Option<T>>,
+            budget: CoopBudget,
+        }
+
+        impl<T> PriorityMerge<T> {
+            fn new(sources: Vec<(PinnedStream<T>, u32)>) -> Self {
+                let (streams, priorities): (Vec<_>, Vec<_>) = sources.into_iter().unzip();
+                let buffered = streams.iter().map(|_| None).collect();
+                Self {
+                    streams,
+                    priorities,
+                    buffered,
+                    budget: CoopBudget::new(),
+                }
+            }
+        }
+
+        impl<T> Stream for PriorityMerge<T>
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                if self.budget.poll_proceed(cx).is_pending() {
+                    return Poll::Pending;
+                }
+
+                let this = &mut *self;
+                let mut any_pending = false;
+
+                for (stream, buffered) in this.streams.iter_mut().zip(this.buffered.iter_mut()) {
+                    if buffered.is_some() {
+                        continue;
+                    }
+                    match stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(StreamItem::Error(e))) => {
+                            this.budget.consume();
+                            return Poll::Ready(Some(StreamItem::Error(e)));
+                        }
+                        Poll::Ready(Some(StreamItem::Value(item))) => *buffered = Some(item),
+                        Poll::Ready(None) => {}
+                        Poll::Pending => any_pending = true,
+                    }
+                }
+
+                // Highest priority first, then smallest timestamp, then first stream
+                let mut best: Option<(usize, u32, &T)> = None;
+                for (i, item) in this.buffered.iter().enumerate() {
+                    if let Some(item) = item {
+                        let priority = this.priorities[i];
+                        let better = best.is_none_or(|(_, best_priority, best_item)| {
+                            priority > best_priority || (priority == best_priority && item < best_item)
+                        });
+                        if better {
+                            best = Some((i, priority, item));
+                        }
+                    }
+                }
+
+                if let Some((i, _, _)) = best {
+                    let item = this.buffered[i].take();
+                    this.budget.consume();
+                    Poll::Ready(item.map(StreamItem::Value))
+                } else if any_pending {
+                    this.budget.reset();
+                    Poll::Pending
+                } else {
+                    Poll::Ready(None)
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,80 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Merge that prefers higher-priority streams.
+//!
+//! [`priority_merge`](PriorityMergeExt::priority_merge) merges streams that each carry a
+//! priority. Whenever items of several streams are ready, the item of the stream with the
+//! highest priority is emitted first, so control-plane traffic such as configuration
+//! changes or shutdown commands overtakes a backlog of data-plane items in the same
+//! pipeline.
+//!
+//! # Characteristics
+//!
+//! - **Priority first**: Of the ready items, the one of the highest-priority stream goes
+//!   first; higher numbers are higher priorities
+//! - **Ordered within a priority**: Ready items of streams with the same priority are
+//!   emitted in timestamp order, as by [`ordered_merge`](crate::OrderedStreamExt::ordered_merge)
+//! - **Never waits**: Only ready items compete; a pending high-priority stream does not
+//!   hold back the others
+//! - **Buffered**: Buffers one item from each stream
+//! - **Errors first**: Errors are emitted as soon as they are read
+//!
+//! A high-priority stream that is always ready starves the streams below it. Timestamps
+//! across priorities are not ordered.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::PriorityMergeExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # async fn example() {
+//! let (data_tx, data) = test_channel::<Sequenced<&str>>();
+//! let (control_tx, control) = test_channel::<Sequenced<&str>>();
+//!
+//! let mut merged = data.priority_merge(0, vec![(control, 10)]);
+//!
+//! data_tx.unbounded_send(("reading", 1).into()).unwrap();
+//! control_tx.unbounded_send(("shutdown", 2).into()).unwrap();
+//!
+//! // Both are ready, the control stream has the higher priority
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value, "shutdown");
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value, "reading");
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::PriorityMergeExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::PriorityMergeExt;
@@ -0,0 +1,129 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{Result, StreamItem};
+use futures::{Stream, StreamExt};
+
+/// Resolves to the first value matching `f`, stopping there, or to the first error
+/// before it.
+pub(super) async fn find_value<S, T, F>(stream: S, mut f: F) -> Result<Option<T>>
+where
+    S: Stream<Item = StreamItem<T>>,
+    F: FnMut(&T) -> bool,
+{
+    let mut stream = core::pin::pin!(stream);
+    while let Some(item) = stream.next().await {
+        match item {
+            StreamItem::Value(value) if f(&value) => return Ok(Some(value)),
+            StreamItem::Value(_) => {}
+            StreamItem::Error(error) => return Err(error),
+        }
+    }
+    Ok(None)
+}
+
+/// Resolves to the last value once the stream ends, or to the first error.
+pub(super) async fn last_value<S, T>(stream: S) -> Result<Option<T>>
+where
+    S: Stream<Item = StreamItem<T>>,
+{
+    let mut stream = core::pin::pin!(stream);
+    let mut last = None;
+    while let Some(item) = stream.next().await {
+        last = Some(Result::from(item)?);
+    }
+    Ok(last)
+}
+
+macro_rules! define_query_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::{find_value, last_value};
+        use core::fmt::Debug;
+        use core::future::Future;
+        use fluxion_core::{Fluxion, Result, StreamItem};
+        use futures::Stream;
+
+        /// Awaitable queries that consume a stream and resolve to a single answer.
+        ///
+        /// Each query stops reading the stream as soon as the answer is known. An error
+        /// read before then resolves the query to `Err`; errors after it are never read.
+        pub trait QueryExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Resolves to the first value, or `None` if the stream ends without one.
+            fn first(self) -> impl Future<Output = Result<Option<T>>> + $($bounds)*;
+
+            /// Resolves to the last value once the stream ends, or `None` if it had none.
+            fn last(self) -> impl Future<Output = Result<Option<T>>> + $($bounds)*;
+
+            /// Resolves to the value at index `n`, counting values from zero, or `None` if
+            /// the stream ends before it.
+            fn nth(self, n: usize) -> impl Future<Output = Result<Option<T>>> + $($bounds)*;
+
+            /// Resolves to whether any value satisfies `predicate`, stopping at the first
+            /// that does. Named to avoid ambiguity with `StreamExt::any`.
+            fn any_value<F>(self, predicate: F) -> impl Future<Output = Result<bool>> + $($bounds)*
+            where
+                F: FnMut(&T::Inner) -> bool + $($bounds)* 'static;
+
+            /// Resolves to whether every value satisfies `predicate`, stopping at the first
+            /// that does not. Named to avoid ambiguity with `StreamExt::all`.
+            fn all_values<F>(self, predicate: F) -> impl Future<Output = Result<bool>> + $($bounds)*
+            where
+                F: FnMut(&T::Inner) -> bool + $($bounds)* 'static;
+
+            /// Resolves to whether a value equal to `value` is emitted, stopping at the
+            /// first.
+            fn contains(self, value: T::Inner) -> impl Future<Output = Result<bool>> + $($bounds)*;
+        }
+
+        impl<T, S> QueryExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn first(self) -> impl Future<Output = Result<Option<T>>> + $($bounds)* {
+                find_value(self, |_| true)
+            }
+
+            fn last(self) -> impl Future<Output = Result<Option<T>>> + $($bounds)* {
+                last_value(self)
+            }
+
+            fn nth(self, n: usize) -> impl Future<Output = Result<Option<T>>> + $($bounds)* {
+                let mut remaining = n;
+                find_value(self, move |_| {
+                    let found = remaining == 0;
+                    remaining = remaining.saturating_sub(1);
+                    found
+                })
+            }
+
+            fn any_value<F>(self, mut predicate: F) -> impl Future<Output = Result<bool>> + $($bounds)*
+            where
+                F: FnMut(&T::Inner) -> bool + $($bounds)* 'static,
+            {
+                let found = find_value(self, move |value: &T| predicate(&value.clone().into_inner()));
+                async move { Ok(found.await?.is_some()) }
+            }
+
+            fn all_values<F>(self, mut predicate: F) -> impl Future<Output = Result<bool>> + $($bounds)*
+            where
+                F: FnMut(&T::Inner) -> bool + $($bounds)* 'static,
+            {
+                let failed = find_value(self, move |value: &T| !predicate(&value.clone().into_inner()));
+                async move { Ok(failed.await?.is_none()) }
+            }
+
+            fn contains(self, value: T::Inner) -> impl Future<Output = Result<bool>> + $($bounds)* {
+                self.any_value(move |inner| *inner == value)
+            }
+        }
+    };
+}
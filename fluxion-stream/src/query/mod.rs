@@ -0,0 +1,75 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Terminal queries that resolve a stream to a single answer.
+//!
+//! Answering a simple question about a stream, such as its first value or whether any
+//! value crosses a threshold, otherwise takes a `while let` loop that also has to decide
+//! what to do with errors. [`QueryExt`] provides the common queries as futures:
+//! [`first`](QueryExt::first), [`last`](QueryExt::last), [`nth`](QueryExt::nth),
+//! [`any_value`](QueryExt::any_value), [`all_values`](QueryExt::all_values) and
+//! [`contains`](QueryExt::contains).
+//!
+//! ## Characteristics
+//!
+//! - **Short-circuiting**: A query stops reading the stream, and drops it, as soon as the
+//!   answer is known; only `last` always reads to the end
+//! - **Errors as `Err`**: The first error read before the answer is known resolves the
+//!   query to `Err` with that error
+//! - **Values, not items**: Predicates and `contains` see inner values; `first`, `last`
+//!   and `nth` resolve to the timestamped item
+//! - **No name clashes**: `any` and `all` are named `any_value` and `all_values`, so they
+//!   do not clash with `futures::StreamExt`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::{from_iter, QueryExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//!
+//! # async fn example() -> fluxion_core::Result<()> {
+//! let readings = || from_iter::<Sequenced<i32>, _>(vec![18, 21, 25, 19]);
+//!
+//! let first = readings().first().await?.unwrap();
+//! assert_eq!((first.value, first.timestamp()), (18, 0));
+//!
+//! assert!(readings().any_value(|celsius| *celsius > 24).await?);
+//! assert!(!readings().all_values(|celsius| *celsius > 20).await?);
+//! assert!(readings().contains(19).await?);
+//! assert_eq!(readings().nth(2).await?.map(|reading| reading.value), Some(25));
+//! # Ok(())
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::QueryExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::QueryExt;
@@ -0,0 +1,46 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use fluxion_runtime::default_runtime::DefaultTimer;
+use fluxion_runtime::timer::Timer;
+use pin_project::pin_project;
+
+/// Resolves to the key of a held item once it has been held for the window's time.
+#[pin_project]
+pub(super) struct Deadline<K> {
+    #[pin]
+    sleep: <DefaultTimer as Timer>::Sleep,
+    key: Option<K>,
+}
+
+impl<K> Deadline<K> {
+    pub(super) fn after(hold: Duration, key: K) -> Self {
+        Self {
+            sleep: DefaultTimer::default().sleep_future(hold),
+            key: Some(key),
+        }
+    }
+}
+
+impl<K> Future for Deadline<K> {
+    type Output = K;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.sleep.poll(cx) {
+            Poll::Ready(()) => {
+                if let Some(key) = this.key.take() {
+                    Poll::Ready(key)
+                } else {
+                    unreachable!("Deadline polled after completion")
+                }
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_reorder_impl {
+    ($($bounds:tt)*) => {
+        use crate::types::ReorderWindow;
+        use alloc::boxed::Box;
+        use alloc::collections::{BTreeMap, VecDeque};
+        use core::fmt::Debug;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use fluxion_core::{Fluxion, HasTimestamp, StreamItem};
+        use futures::Stream;
+        use pin_project::pin_project;
+
+        #[cfg(any(
+            all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+            feature = "runtime-smol",
+            feature = "runtime-async-std",
+            feature = "runtime-embassy",
+            all(feature = "runtime-wasm", target_arch = "wasm32")
+        ))]
+        use {
+            super::deadline::Deadline,
+            futures::stream::{FuturesOrdered, StreamExt},
+        };
+
+        pub trait ReorderExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Restores timestamp order within a bounded buffer, emitting items that
+            /// arrive too late for it immediately.
+            fn reorder<D>(self, window: ReorderWindow<D>) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>;
+        }
+
+        impl<T, S> ReorderExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn reorder<D>(self, window: ReorderWindow<D>) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+            {
+                Box::pin(ReorderStream {
+                    stream: self,
+                    window,
+                    state: ReorderState {
+                        held: BTreeMap::new(),
+                        arrivals: 0,
+                        newest: None,
+                        released: None,
+                        ready: VecDeque::new(),
+                        #[cfg(any(
+                            all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+                            feature = "runtime-smol",
+                            feature = "runtime-async-std",
+                            feature = "runtime-embassy",
+                            all(feature = "runtime-wasm", target_arch = "wasm32")
+                        ))]
+                        deadlines: FuturesOrdered::new(),
+                    },
+                    is_done: false,
+                })
+            }
+        }
+
+        /// Held items by timestamp and arrival, the timestamps bounding the buffer, and
+        /// the items ready to be emitted.
+        struct ReorderState<T: HasTimestamp> {
+            held: BTreeMap<(T::Timestamp, u64), T>,
+            arrivals: u64,
+            newest: Option<T::Timestamp>,
+            released: Option<T::Timestamp>,
+            ready: VecDeque<StreamItem<T>>,
+            /// One deadline per held item with [`ReorderWindow::Time`], in arrival order.
+            #[cfg(any(
+                all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+                feature = "runtime-smol",
+                feature = "runtime-async-std",
+                feature = "runtime-embassy",
+                all(feature = "runtime-wasm", target_arch = "wasm32")
+            ))]
+            deadlines: FuturesOrdered<Deadline<(T::Timestamp, u64)>>,
+        }
+
+        impl<T> ReorderState<T>
+        where
+            T: HasTimestamp,
+            T::Timestamp: Ord + Copy,
+        {
+            fn hold<D>(&mut self, value: T, window: ReorderWindow<D>)
+            where
+                D: Ord + Copy,
+                T::Timestamp: Sub<Output = D>,
+            {
+                let timestamp = value.timestamp();
+
+                // Too late to be put in order
+                if self.released.is_some_and(|released| timestamp < released) {
+                    self.ready.push_back(StreamItem::Value(value));
+                    return;
+                }
+
+                let newest = self.newest.map_or(timestamp, |n| n.max(timestamp));
+                self.newest = Some(newest);
+                let key = (timestamp, self.arrivals);
+                self.arrivals += 1;
+                self.held.insert(key, value);
+
+                match window {
+                    ReorderWindow::Items(capacity) => {
+                        while self.held.len() > capacity {
+                            self.release_oldest();
+                        }
+                    }
+                    ReorderWindow::Span(span) => {
+                        while self.held.keys().next().is_some_and(|&(oldest, _)| newest - oldest > span) {
+                            self.release_oldest();
+                        }
+                    }
+                    #[cfg(any(
+                        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+                        feature = "runtime-smol",
+                        feature = "runtime-async-std",
+                        feature = "runtime-embassy",
+                        all(feature = "runtime-wasm", target_arch = "wasm32")
+                    ))]
+                    ReorderWindow::Time(hold) => self.deadlines.push_back(Deadline::after(hold, key)),
+                }
+            }
+
+            /// Releases the held item at `key` and every held item ordered before it.
+            #[cfg(any(
+                all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+                feature = "runtime-smol",
+                feature = "runtime-async-std",
+                feature = "runtime-embassy",
+                all(feature = "runtime-wasm", target_arch = "wasm32")
+            ))]
+            fn release_through(&mut self, key: (T::Timestamp, u64)) {
+                while self.held.keys().next().is_some_and(|&oldest| oldest <= key) {
+                    self.release_oldest();
+                }
+            }
+
+            fn release_oldest(&mut self) {
+                if let Some(((timestamp, _), value)) = self.held.pop_first() {
+                    self.released = Some(timestamp);
+                    self.ready.push_back(StreamItem::Value(value));
+                }
+            }
+
+            fn release_all(&mut self) {
+                while !self.held.is_empty() {
+                    self.release_oldest();
+                }
+            }
+        }
+
+        #[pin_project]
+        struct ReorderStream<S, T: HasTimestamp, D> {
+            #[pin]
+            stream: S,
+            window: ReorderWindow<D>,
+            state: ReorderState<T>,
+            is_done: bool,
+        }
+
+        impl<S, T, D> Stream for ReorderStream<S, T, D>
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: HasTimestamp,
+            T::Timestamp: Ord + Copy + Sub<Output = D>,
+            D: Ord + Copy,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                loop {
+                    if let Some(item) = this.state.ready.pop_front() {
+                        return Poll::Ready(Some(item));
+                    }
+
+                    if *this.is_done {
+                        return Poll::Ready(None);
+                    }
+
+                    #[cfg(any(
+                        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+                        feature = "runtime-smol",
+                        feature = "runtime-async-std",
+                        feature = "runtime-embassy",
+                        all(feature = "runtime-wasm", target_arch = "wasm32")
+                    ))]
+                    if let Poll::Ready(Some(key)) = this.state.deadlines.poll_next_unpin(cx) {
+                        this.state.release_through(key);
+                        continue;
+                    }
+
+                    match this.stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(StreamItem::Value(value))) => {
+                            this.state.hold(value, *this.window);
+                        }
+                        Poll::Ready(Some(StreamItem::Error(e))) => {
+                            return Poll::Ready(Some(StreamItem::Error(e)));
+                        }
+                        Poll::Ready(None) => {
+                            this.state.release_all();
+                            *this.is_done = true;
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+            }
+        }
+    };
+}
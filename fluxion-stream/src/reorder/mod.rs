@@ -0,0 +1,91 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Bounded reordering: timestamp order within a window, arrival order beyond it.
+//!
+//! Full ordering can hold an item back for as long as an earlier one may still arrive.
+//! [`reorder`](ReorderExt::reorder) bounds that wait: it holds items in a buffer sized by
+//! a [`ReorderWindow`](crate::ReorderWindow) and emits them in timestamp order as they
+//! leave it. Most real-world disorder is small, so a small buffer restores order for most
+//! items at a fraction of the latency.
+//!
+//! # Behavior
+//!
+//! - With [`ReorderWindow::Items`](crate::ReorderWindow::Items), the oldest item is
+//!   released whenever the buffer holds more than the given number of items
+//! - With [`ReorderWindow::Span`](crate::ReorderWindow::Span), items are released once the
+//!   newest timestamp seen is more than the span ahead of theirs
+//! - With [`ReorderWindow::Time`](crate::ReorderWindow::Time), each item is held for at
+//!   most the given time on the runtime's clock, so the added latency stays bounded even
+//!   when the source goes quiet; the items ordered before it are released with it
+//! - Items older than the last released item are too late to be put in order and are
+//!   emitted immediately
+//! - Items with equal timestamps keep their arrival order
+//! - Held items are released in order when the source ends
+//! - Errors are emitted immediately and do not release held items
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{ReorderExt, ReorderWindow};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, events) = test_channel::<Sequenced<&str>>();
+//!
+//! // Tolerate items up to 10 ticks out of order
+//! let mut ordered = events.reorder(ReorderWindow::Span(10u64));
+//!
+//! tx.unbounded_send(("b", 5).into()).unwrap();
+//! tx.unbounded_send(("a", 3).into()).unwrap();
+//! tx.unbounded_send(("c", 20).into()).unwrap(); // releases a and b
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut ordered, 500).await)).value, "a");
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut ordered, 500).await)).value, "b");
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std",
+    feature = "runtime-embassy",
+    all(feature = "runtime-wasm", target_arch = "wasm32")
+))]
+mod deadline;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::ReorderExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::ReorderExt;
@@ -0,0 +1,138 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Constructors that restart a source each time it completes.
+//!
+//! A stream cannot be polled again once it has ended, so both constructors take a
+//! `factory` that builds a fresh source for every run. [`repeat`] runs it a fixed number
+//! of times back to back; [`repeat_when`] starts the next run when a companion stream
+//! emits, which suits polling-style sources, such as a paginated fetch, that should run
+//! again on a schedule or on demand.
+//!
+//! # Behavior
+//!
+//! - The first run starts on the first poll; each later run starts only after the
+//!   previous one has completed, so runs never overlap
+//! - Values and errors of every run are emitted as they are; errors do not stop a run
+//! - [`repeat_when`] ignores companion values while a run is active and starts the next
+//!   run on the first companion value after it completes
+//! - Companion errors are emitted downstream
+//! - [`repeat_when`] ends when a run completes after the companion has ended
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{from_iter, repeat};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let values: Vec<_> = repeat(|| from_iter::<Sequenced<i32>, _>([1, 2]), 3)
+//!     .map(|item| item.unwrap().into_inner())
+//!     .collect()
+//!     .await;
+//!
+//! assert_eq!(values, vec![1, 2, 1, 2, 1, 2]);
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::StreamItem;
+use futures::{Stream, StreamExt};
+
+/// Creates a stream that runs the source built by `factory` `times` times in a row.
+///
+/// With `times` of zero, the stream ends immediately without calling `factory`.
+pub fn repeat<T, S, F>(mut factory: F, times: usize) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    S: Stream<Item = StreamItem<T>>,
+    F: FnMut() -> S,
+{
+    futures::stream::iter(0..times).flat_map(move |_| Box::pin(factory()))
+}
+
+/// Creates a stream that runs the source built by `factory`, and runs it again whenever
+/// `notifier` emits after a run has completed.
+pub fn repeat_when<T, S, F, N, NT>(
+    factory: F,
+    notifier: N,
+) -> impl Stream<Item = StreamItem<T>> + Unpin
+where
+    S: Stream<Item = StreamItem<T>>,
+    F: FnMut() -> S,
+    N: Stream<Item = StreamItem<NT>>,
+{
+    RepeatWhen {
+        factory,
+        source: None,
+        started: false,
+        notifier: Box::pin(notifier),
+        notifier_ended: false,
+    }
+}
+
+struct RepeatWhen<F, S, N> {
+    factory: F,
+    source: Option<Pin<Box<S>>>,
+    started: bool,
+    notifier: Pin<Box<N>>,
+    notifier_ended: bool,
+}
+
+// The factory is only called through `&mut`, and the streams are pinned on the heap
+impl<F, S, N> Unpin for RepeatWhen<F, S, N> {}
+
+impl<T, S, F, N, NT> Stream for RepeatWhen<F, S, N>
+where
+    S: Stream<Item = StreamItem<T>>,
+    F: FnMut() -> S,
+    N: Stream<Item = StreamItem<NT>>,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if !this.started {
+            this.started = true;
+            this.source = Some(Box::pin((this.factory)()));
+        }
+
+        loop {
+            // While a run is active, companion values are only drained
+            let running = this.source.is_some();
+            while !this.notifier_ended {
+                match this.notifier.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(StreamItem::Error(e))) => {
+                        return Poll::Ready(Some(StreamItem::Error(e)))
+                    }
+                    Poll::Ready(Some(StreamItem::Value(_))) if !running => {
+                        this.source = Some(Box::pin((this.factory)()));
+                        break;
+                    }
+                    Poll::Ready(Some(StreamItem::Value(_))) => {}
+                    Poll::Ready(None) => this.notifier_ended = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            let Some(source) = this.source.as_mut() else {
+                return if this.notifier_ended {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                };
+            };
+
+            match source.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => this.source = None,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_resample_impl {
+    ($($bounds:tt)*) => {
+        use crate::types::{Interpolate, ResampleStrategy, SpanRatio};
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::{Add, Sub};
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        /// The next sample point and what is known about the items around it.
+        struct ResampleState<I, TS> {
+            next: Option<TS>,
+            latest: Option<(TS, I)>,
+            window: Vec<I>,
+            mean: Option<I>,
+        }
+
+        pub trait ResampleExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Emits one value for every `period` of timestamps, computed from the items
+            /// around it according to `strategy`.
+            ///
+            /// # Panics
+            ///
+            /// Panics on the first item if `period` is zero.
+            fn resample<D>(
+                self,
+                period: D,
+                strategy: ResampleStrategy,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                D: SpanRatio + $($bounds)* 'static,
+                T::Inner: Interpolate,
+                T::Timestamp: Sub<Output = D> + Add<D, Output = T::Timestamp>;
+        }
+
+        impl<T, S> ResampleExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn resample<D>(
+                self,
+                period: D,
+                strategy: ResampleStrategy,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                D: SpanRatio + $($bounds)* 'static,
+                T::Inner: Interpolate,
+                T::Timestamp: Sub<Output = D> + Add<D, Output = T::Timestamp>,
+            {
+                let state = Arc::new(Mutex::new(ResampleState {
+                    next: None::<T::Timestamp>,
+                    latest: None::<(T::Timestamp, T::Inner)>,
+                    window: Vec::new(),
+                    mean: None,
+                }));
+                let state_clone = Arc::clone(&state);
+
+                let sample = |value: T::Inner, at: T::Timestamp| StreamItem::Value(T::with_timestamp(value, at));
+
+                let main_stream = self.flat_map(move |item| {
+                    let mut guard = state_clone.lock();
+                    let state = &mut *guard;
+
+                    let samples: Vec<StreamItem<T>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let inner = value.into_inner();
+                            let mut next = *state.next.get_or_insert_with(|| {
+                                assert!(timestamp + period > timestamp, "resample requires a non-zero period");
+                                timestamp
+                            });
+                            let mut samples = Vec::new();
+
+                            if state.latest.as_ref().is_some_and(|(latest, _)| timestamp < *latest) {
+                                // Out of order; the sample points before it are settled
+                                return futures::stream::iter(samples);
+                            }
+
+                            match strategy {
+                                ResampleStrategy::HoldLast | ResampleStrategy::Linear => {
+                                    if let Some((latest, previous)) = &state.latest {
+                                        while next < timestamp {
+                                            let value = match strategy {
+                                                ResampleStrategy::Linear => previous
+                                                    .lerp(&inner, (next - *latest).ratio(timestamp - *latest)),
+                                                _ => previous.clone(),
+                                            };
+                                            samples.push(sample(value, next));
+                                            next = next + period;
+                                        }
+                                    }
+                                }
+                                ResampleStrategy::Mean => {
+                                    while next + period <= timestamp {
+                                        if !state.window.is_empty() {
+                                            state.mean = Some(T::Inner::mean(&state.window));
+                                            state.window.clear();
+                                        }
+                                        if let Some(mean) = &state.mean {
+                                            samples.push(sample(mean.clone(), next));
+                                        }
+                                        next = next + period;
+                                    }
+                                    state.window.push(inner.clone());
+                                }
+                            }
+
+                            state.next = Some(next);
+                            state.latest = Some((timestamp, inner));
+                            samples
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(samples)
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let mut state = state.lock();
+
+                    let last = match (state.next, strategy) {
+                        (Some(next), ResampleStrategy::Mean) if !state.window.is_empty() => {
+                            Some(sample(T::Inner::mean(&state.window), next))
+                        }
+                        (Some(next), ResampleStrategy::HoldLast | ResampleStrategy::Linear) => state
+                            .latest
+                            .take()
+                            .filter(|(latest, _)| *latest == next)
+                            .map(|(_, value)| sample(value, next)),
+                        _ => None,
+                    };
+                    last.into_iter().collect::<Vec<_>>()
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(main_stream.chain(flush_stream))
+            }
+        }
+    };
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Resampling of irregular streams to a fixed rate.
+//!
+//! [`resample`](ResampleExt::resample) places sample points every `period` of timestamps,
+//! starting at the first item, and emits one item per sample point, so readings that
+//! arrive at irregular instants can feed a consumer that expects uniform sampling. The
+//! [`ResampleStrategy`](crate::ResampleStrategy) decides how each value is computed from
+//! the items around its sample point.
+//!
+//! # Behavior
+//!
+//! - Sample points lie at the first timestamp plus whole multiples of `period`, and each
+//!   output carries its sample point as its timestamp
+//! - A sample point is emitted once an item past it arrives, so outputs lag the input by
+//!   up to one item, or one `period` with [`ResampleStrategy::Mean`](crate::ResampleStrategy::Mean)
+//! - [`HoldLast`](crate::ResampleStrategy::HoldLast) repeats the latest value through
+//!   gaps; [`Linear`](crate::ResampleStrategy::Linear) interpolates across them;
+//!   [`Mean`](crate::ResampleStrategy::Mean) repeats the previous mean for empty periods
+//! - Values are computed with [`Interpolate`](crate::Interpolate), and fractions of a
+//!   period with [`SpanRatio`](crate::SpanRatio)
+//! - Items older than the previous item are dropped
+//! - When the source ends, a sample point at the last item, or the mean of the last
+//!   period, is emitted
+//! - Errors are propagated without affecting the sample points
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{ResampleExt, ResampleStrategy};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, readings) = test_channel::<Sequenced<i32>>();
+//!
+//! // One reading every 10 ticks, interpolated between the actual readings
+//! let mut uniform = readings.resample(10u64, ResampleStrategy::Linear);
+//!
+//! tx.unbounded_send((100, 0).into()).unwrap();
+//! tx.unbounded_send((200, 20).into()).unwrap();
+//!
+//! let first = unwrap_value(Some(unwrap_stream(&mut uniform, 500).await));
+//! assert_eq!(first.value, 100);
+//! let second = unwrap_value(Some(unwrap_stream(&mut uniform, 500).await));
+//! assert_eq!(second.value, 150); // at timestamp 10, halfway between the readings
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::ResampleExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::ResampleExt;
@@ -4,9 +4,8 @@
 
 macro_rules! define_scan_ordered_impl {
     ($($bounds:tt)*) => {
-        use alloc::sync::Arc;
         use core::fmt::Debug;
-        use fluxion_core::fluxion_mutex::Mutex;
+        use fluxion_core::sync::{Arc, Mutex};
         use fluxion_core::{Fluxion, StreamItem};
         use futures::{future::ready, Stream, StreamExt};
 
@@ -0,0 +1,151 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::types::Divergence;
+use alloc::string::ToString;
+use core::ops::Sub;
+use fluxion_core::{StreamItem, Timestamped};
+use futures::{future, Stream, StreamExt};
+
+/// Whether two items at the same position count as equal: values with equal inner values
+/// and timestamps at most `tolerance` apart, or errors with the same message.
+fn items_match<T, D>(left: &StreamItem<T>, right: &StreamItem<T>, tolerance: D) -> bool
+where
+    T: Timestamped + Clone,
+    T::Inner: PartialEq,
+    T::Timestamp: Sub<Output = D>,
+    D: Ord,
+{
+    match (left, right) {
+        (StreamItem::Value(left), StreamItem::Value(right)) => {
+            let (a, b) = (left.timestamp(), right.timestamp());
+            let gap = if a >= b { a - b } else { b - a };
+            gap <= tolerance && left.clone().into_inner() == right.clone().into_inner()
+        }
+        (StreamItem::Error(left), StreamItem::Error(right)) => {
+            left.to_string() == right.to_string()
+        }
+        _ => false,
+    }
+}
+
+/// Pulls both streams item by item until the first divergence or until both end.
+pub(super) async fn first_divergence<L, R, T, D>(
+    left: L,
+    right: R,
+    tolerance: D,
+) -> Option<Divergence<T>>
+where
+    L: Stream<Item = StreamItem<T>>,
+    R: Stream<Item = StreamItem<T>>,
+    T: Timestamped + Clone,
+    T::Inner: PartialEq,
+    T::Timestamp: Sub<Output = D>,
+    D: Ord + Copy,
+{
+    let mut left = core::pin::pin!(left);
+    let mut right = core::pin::pin!(right);
+
+    let mut index = 0;
+    loop {
+        match future::join(left.next(), right.next()).await {
+            (None, None) => return None,
+            (None, Some(right)) => return Some(Divergence::LeftEnded { index, right }),
+            (Some(left), None) => return Some(Divergence::RightEnded { index, left }),
+            (Some(left), Some(right)) => {
+                if !items_match(&left, &right, tolerance) {
+                    return Some(Divergence::Mismatch { index, left, right });
+                }
+            }
+        }
+        index += 1;
+    }
+}
+
+macro_rules! define_sequence_equal_impl {
+    ($($bounds:tt)*) => {
+        use super::implementation::first_divergence;
+        use crate::types::Divergence;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use core::ops::Sub;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::Stream;
+
+        pub trait SequenceEqualExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Resolves to whether `self` and `other` emit equal sequences: the same number
+            /// of items, with equal inner values and timestamps at most `tolerance` apart
+            /// at every position, and errors with the same message at the same positions.
+            ///
+            /// Resolves to `false` at the first difference, without consuming the rest of
+            /// either stream.
+            fn sequence_equal<IS, D>(
+                self,
+                other: IS,
+                tolerance: D,
+            ) -> impl Future<Output = bool> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>;
+
+            /// Like [`sequence_equal`](Self::sequence_equal), but resolves to the first
+            /// [`Divergence`] between the streams, or `None` if they emit equal sequences.
+            fn sequence_diff<IS, D>(
+                self,
+                other: IS,
+                tolerance: D,
+            ) -> impl Future<Output = Option<Divergence<T>>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>;
+        }
+
+        impl<T, S> SequenceEqualExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn sequence_equal<IS, D>(
+                self,
+                other: IS,
+                tolerance: D,
+            ) -> impl Future<Output = bool> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+            {
+                let diff = self.sequence_diff(other, tolerance);
+                async move { diff.await.is_none() }
+            }
+
+            fn sequence_diff<IS, D>(
+                self,
+                other: IS,
+                tolerance: D,
+            ) -> impl Future<Output = Option<Divergence<T>>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                D: Ord + Copy + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+            {
+                first_divergence(self, other.into_stream(), tolerance)
+            }
+        }
+    };
+}
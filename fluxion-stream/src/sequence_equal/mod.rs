@@ -0,0 +1,75 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Comparison of two streams item by item.
+//!
+//! [`sequence_equal`](SequenceEqualExt::sequence_equal) consumes two streams and resolves
+//! to whether they emitted equal sequences; [`sequence_diff`](SequenceEqualExt::sequence_diff)
+//! resolves to the first [`Divergence`] instead, saying where and how they differ. Besides
+//! tests, this serves shadow deployments: run a changed pipeline next to the current one
+//! on the same input and compare their outputs.
+//!
+//! ## Characteristics
+//!
+//! - **Positional**: The `n`th item of one stream is compared with the `n`th of the other
+//! - **Timestamp tolerance**: Values match if their inner values are equal and their
+//!   timestamps are at most `tolerance` apart; pass `0` for exact timestamps
+//! - **Errors are items**: Two errors match if their messages are equal
+//! - **Short-circuiting**: Both streams are polled together and comparison stops at the
+//!   first divergence, leaving the rest of the streams unconsumed
+//! - **Ends together**: A stream that ends before the other is a divergence
+//!
+//! ## Example
+//!
+//! ```rust
+//! use fluxion_core::StreamItem;
+//! use fluxion_stream::{Divergence, SequenceEqualExt};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::stream;
+//!
+//! # async fn example() {
+//! let current = stream::iter(vec![
+//!     StreamItem::Value(Sequenced::with_timestamp(10, 1)),
+//!     StreamItem::Value(Sequenced::with_timestamp(20, 2)),
+//! ]);
+//! let candidate = stream::iter(vec![
+//!     StreamItem::Value(Sequenced::with_timestamp(10, 1)),
+//!     StreamItem::Value(Sequenced::with_timestamp(21, 2)),
+//! ]);
+//!
+//! let divergence = current.sequence_diff(candidate, 0).await.unwrap();
+//! assert!(matches!(divergence, Divergence::Mismatch { index: 1, .. }));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::SequenceEqualExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::SequenceEqualExt;
@@ -4,6 +4,7 @@
 
 macro_rules! define_share_impl {
     ($($bounds:tt)*) => {
+        use crate::coop::CoopBudget;
         use alloc::boxed::Box;
         use core::pin::Pin;
         use fluxion_core::{FluxionSubject, FluxionTask, StreamItem, SubjectError};
@@ -21,12 +22,22 @@ macro_rules! define_share_impl {
 
         impl<T: Clone + $($bounds)* 'static> FluxionShared<T> {
             pub fn new<S>(source: S) -> Self
+            where
+                S: Stream<Item = StreamItem<T>> + Unpin + $($bounds)* 'static,
+            {
+                Self::with_budget(source, crate::DEFAULT_COOP_BUDGET)
+            }
+
+            /// Shares `source` like [`new`](Self::new), yielding to the executor after
+            /// `budget` items in a row. `0` disables yielding.
+            pub fn with_budget<S>(source: S, budget: u32) -> Self
             where
                 S: Stream<Item = StreamItem<T>> + Unpin + $($bounds)* 'static,
             {
                 let subject = FluxionSubject::new();
                 let subject_clone = subject.clone();
 
+                let mut budget = CoopBudget::with_limit(budget);
                 let task = FluxionTask::spawn(|cancel| async move {
                     let mut stream = source;
                     while let Either::Left((stream_item, _)) =
@@ -37,6 +48,8 @@ macro_rules! define_share_impl {
                                 if subject_clone.next(v).is_err() {
                                     break;
                                 }
+                                budget.consume();
+                                budget.proceed().await;
                             }
                             Some(StreamItem::Error(e)) => {
                                 let _ = subject_clone.error(e);
@@ -79,6 +92,13 @@ macro_rules! define_share_impl {
             fn share(self) -> FluxionShared<T>
             where
                 Self: Unpin + $($bounds)* 'static;
+
+            /// Shares the stream like [`share`](Self::share), yielding to the executor
+            /// after `budget` items in a row instead of
+            /// [`DEFAULT_COOP_BUDGET`](crate::DEFAULT_COOP_BUDGET). `0` disables yielding.
+            fn share_with_budget(self, budget: u32) -> FluxionShared<T>
+            where
+                Self: Unpin + $($bounds)* 'static;
         }
 
         impl<S, T> ShareExt<T> for S
@@ -92,6 +112,13 @@ macro_rules! define_share_impl {
             {
                 FluxionShared::new(self)
             }
+
+            fn share_with_budget(self, budget: u32) -> FluxionShared<T>
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                FluxionShared::with_budget(self, budget)
+            }
         }
     };
 }
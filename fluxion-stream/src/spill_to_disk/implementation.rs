@@ -0,0 +1,538 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::coop::CoopBudget;
+use crate::map_ordered_blocking::DefaultBlockingSpawner;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::future::poll_fn;
+use core::mem;
+use core::pin::pin;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::task::{Poll, Waker};
+use fluxion_core::sync::Mutex;
+use fluxion_core::{Fluxion, FluxionError, FluxionTask, StreamItem};
+use fluxion_runtime::spawner::BlockingSpawner;
+use futures::future::{pending, select, Either};
+use futures::{Stream, StreamExt};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Converts the items of [`spill_to_disk`](SpillToDiskExt::spill_to_disk) to and from
+/// the bytes written to the spill file.
+pub trait SpillCodec<T> {
+    /// Appends the encoding of `item` to `buf`.
+    fn encode(&self, item: &T, buf: &mut Vec<u8>);
+
+    /// Decodes an item from the bytes produced by [`encode`](Self::encode).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid encoding, which ends the stream.
+    fn decode(&self, bytes: &[u8]) -> Result<T, FluxionError>;
+}
+
+/// Where and when [`spill_to_disk`](SpillToDiskExt::spill_to_disk) spills items.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpillConfig {
+    dir: PathBuf,
+    memory_items: usize,
+    #[cfg(feature = "spill-zstd")]
+    zstd_level: Option<i32>,
+}
+
+impl SpillConfig {
+    /// Spills to a file created in `dir` once more than `memory_items` items wait for
+    /// the consumer, writing them in blocks of `memory_items` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `memory_items` is 0.
+    pub fn new(dir: impl Into<PathBuf>, memory_items: usize) -> Self {
+        assert!(
+            memory_items > 0,
+            "spill_to_disk requires at least 1 item in memory"
+        );
+        Self {
+            dir: dir.into(),
+            memory_items,
+            #[cfg(feature = "spill-zstd")]
+            zstd_level: None,
+        }
+    }
+
+    /// Compresses each spilled block with zstd at `level`.
+    #[cfg(feature = "spill-zstd")]
+    pub fn with_zstd(mut self, level: i32) -> Self {
+        self.zstd_level = Some(level);
+        self
+    }
+
+    /// Returns the directory of the spill file.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the number of items written per block.
+    pub fn memory_items(&self) -> usize {
+        self.memory_items
+    }
+
+    fn compress(&self, block: Vec<u8>) -> io::Result<Vec<u8>> {
+        #[cfg(feature = "spill-zstd")]
+        if let Some(level) = self.zstd_level {
+            return zstd::encode_all(block.as_slice(), level);
+        }
+        Ok(block)
+    }
+
+    fn decompress(&self, block: Vec<u8>) -> io::Result<Vec<u8>> {
+        #[cfg(feature = "spill-zstd")]
+        if self.zstd_level.is_some() {
+            return zstd::decode_all(block.as_slice());
+        }
+        Ok(block)
+    }
+}
+
+fn io_error(e: io::Error) -> FluxionError {
+    FluxionError::stream_error(format!("spill_to_disk: {e}"))
+}
+
+/// Spill file holding the blocks between the in-memory head and tail of the queue,
+/// removed when dropped.
+struct SpillFile {
+    path: PathBuf,
+    file: File,
+    read_at: u64,
+    write_at: u64,
+    blocks: usize,
+}
+
+impl SpillFile {
+    fn create(dir: &Path) -> Result<Self, FluxionError> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        fs::create_dir_all(dir).map_err(io_error)?;
+        let path = dir.join(format!(
+            "fluxion-spill-{}-{}.bin",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(io_error)?;
+        Ok(Self {
+            path,
+            file,
+            read_at: 0,
+            write_at: 0,
+            blocks: 0,
+        })
+    }
+
+    fn write_block(&mut self, block: &[u8]) -> io::Result<()> {
+        let len = u32::try_from(block.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "block over 4 GiB"))?;
+        self.file.seek(SeekFrom::Start(self.write_at))?;
+        self.file.write_all(&len.to_le_bytes())?;
+        self.file.write_all(block)?;
+        self.write_at += 4 + u64::from(len);
+        self.blocks += 1;
+        Ok(())
+    }
+
+    fn read_block(&mut self) -> io::Result<Vec<u8>> {
+        let mut len = [0u8; 4];
+        self.file.seek(SeekFrom::Start(self.read_at))?;
+        self.file.read_exact(&mut len)?;
+        let mut block = vec![0u8; u32::from_le_bytes(len) as usize];
+        self.file.read_exact(&mut block)?;
+        self.read_at += 4 + block.len() as u64;
+        self.blocks -= 1;
+
+        // Reuse the file from the start once the consumer has caught up with it
+        if self.blocks == 0 {
+            self.file.set_len(0)?;
+            self.read_at = 0;
+            self.write_at = 0;
+        }
+        Ok(block)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Writes `block` to the spill file, creating the file first if needed. Runs on the
+/// blocking thread pool.
+fn write_block(
+    file: &mut Option<SpillFile>,
+    config: &SpillConfig,
+    block: Vec<u8>,
+) -> Result<(), FluxionError> {
+    let block = config.compress(block).map_err(io_error)?;
+    let file = match file {
+        Some(file) => file,
+        None => file.insert(SpillFile::create(&config.dir)?),
+    };
+    file.write_block(&block).map_err(io_error)
+}
+
+/// Reads the oldest block from the spill file. Runs on the blocking thread pool.
+fn read_block(file: &mut Option<SpillFile>, config: &SpillConfig) -> Result<Vec<u8>, FluxionError> {
+    let Some(file) = file else {
+        return Ok(Vec::new());
+    };
+    let block = file.read_block().map_err(io_error)?;
+    config.decompress(block).map_err(io_error)
+}
+
+/// Items waiting behind the consumer's in-memory head: the spilled blocks, then the
+/// in-memory tail.
+///
+/// Owned by the background task, so the file is never touched while the state shared
+/// with the consumer is locked. File I/O and compression run on the blocking thread
+/// pool.
+struct Backlog<T, C> {
+    config: SpillConfig,
+    codec: C,
+    tail: Vec<T>,
+    file: Option<SpillFile>,
+    /// Source errors, with the number of values queued before each of them.
+    errors: VecDeque<(u64, FluxionError)>,
+    values_in: u64,
+    values_out: u64,
+}
+
+impl<T, C: SpillCodec<T>> Backlog<T, C> {
+    fn new(config: SpillConfig, codec: C) -> Self {
+        Self {
+            config,
+            codec,
+            tail: Vec::new(),
+            file: None,
+            errors: VecDeque::new(),
+            values_in: 0,
+            values_out: 0,
+        }
+    }
+
+    fn spilled_blocks(&self) -> usize {
+        self.file.as_ref().map_or(0, |file| file.blocks)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.spilled_blocks() == 0 && self.tail.is_empty() && self.errors.is_empty()
+    }
+
+    async fn push(&mut self, value: T) -> Result<(), FluxionError> {
+        self.values_in += 1;
+        self.tail.push(value);
+        if self.tail.len() >= self.config.memory_items {
+            self.spill_tail().await?;
+        }
+        Ok(())
+    }
+
+    fn push_error(&mut self, error: FluxionError) {
+        self.errors.push_back((self.values_in, error));
+    }
+
+    /// Takes the oldest items: the next spilled block, or the tail once nothing is
+    /// spilled, with the source errors received between them.
+    async fn pop_front(&mut self) -> Result<Vec<StreamItem<T>>, FluxionError> {
+        let values = if self.spilled_blocks() > 0 {
+            self.read_head().await?
+        } else {
+            mem::take(&mut self.tail)
+        };
+
+        let mut items = Vec::with_capacity(values.len());
+        for value in values {
+            self.drain_errors(&mut items);
+            self.values_out += 1;
+            items.push(StreamItem::Value(value));
+        }
+        self.drain_errors(&mut items);
+        Ok(items)
+    }
+
+    fn drain_errors(&mut self, items: &mut Vec<StreamItem<T>>) {
+        while self
+            .errors
+            .front()
+            .is_some_and(|(position, _)| *position <= self.values_out)
+        {
+            if let Some((_, error)) = self.errors.pop_front() {
+                items.push(StreamItem::Error(error));
+            }
+        }
+    }
+
+    async fn spill_tail(&mut self) -> Result<(), FluxionError> {
+        let mut block = Vec::new();
+        let mut record = Vec::new();
+        for item in self.tail.drain(..) {
+            record.clear();
+            self.codec.encode(&item, &mut record);
+            let len = u32::try_from(record.len())
+                .map_err(|_| FluxionError::stream_error("spill_to_disk: item over 4 GiB"))?;
+            block.extend_from_slice(&len.to_le_bytes());
+            block.extend_from_slice(&record);
+        }
+
+        let mut file = self.file.take();
+        let config = self.config.clone();
+        let (file, result) = DefaultBlockingSpawner::default()
+            .spawn_blocking(move || {
+                let result = write_block(&mut file, &config, block);
+                (file, result)
+            })
+            .await;
+        self.file = file;
+        result
+    }
+
+    async fn read_head(&mut self) -> Result<Vec<T>, FluxionError> {
+        let mut file = self.file.take();
+        let config = self.config.clone();
+        let (file, block) = DefaultBlockingSpawner::default()
+            .spawn_blocking(move || {
+                let block = read_block(&mut file, &config);
+                (file, block)
+            })
+            .await;
+        self.file = file;
+        let block = block?;
+
+        let mut values = Vec::with_capacity(self.config.memory_items);
+        let mut rest = block.as_slice();
+        while !rest.is_empty() {
+            let truncated = || FluxionError::stream_error("spill_to_disk: truncated block");
+            let (len, after_len) = rest.split_first_chunk::<4>().ok_or_else(truncated)?;
+            let len = u32::from_le_bytes(*len) as usize;
+            if after_len.len() < len {
+                return Err(truncated());
+            }
+            let (record, after_record) = after_len.split_at(len);
+            values.push(self.codec.decode(record)?);
+            rest = after_record;
+        }
+        Ok(values)
+    }
+}
+
+/// In-memory head of the queue, shared by the background task and the consumer.
+struct SpillState<T> {
+    head: VecDeque<StreamItem<T>>,
+    ended: bool,
+    waker: Option<Waker>,
+    /// Background task waiting for the consumer to drain the head.
+    task_waker: Option<Waker>,
+}
+
+impl<T> SpillState<T> {
+    fn push(&mut self, items: impl IntoIterator<Item = StreamItem<T>>) {
+        self.head.extend(items);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn end(&mut self) {
+        self.ended = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_task(&mut self) {
+        if let Some(waker) = self.task_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// What woke the background task.
+enum Event<T> {
+    Source(Option<StreamItem<T>>),
+    Drained,
+    Cancelled,
+}
+
+pub trait SpillToDiskExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: Fluxion,
+    T::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+    T::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+{
+    /// Drains the stream on a background task into a queue that keeps the oldest and
+    /// newest items in memory and spills the ones in between to disk, reading them back
+    /// in order as the consumer catches up.
+    fn spill_to_disk<C>(
+        self,
+        config: SpillConfig,
+        codec: C,
+    ) -> impl Stream<Item = StreamItem<T>> + Send + Sync
+    where
+        Self: Unpin + Send + 'static,
+        C: SpillCodec<T> + Send + 'static;
+}
+
+impl<S, T> SpillToDiskExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Fluxion,
+    T::Inner: Clone + Debug + Ord + Unpin + Send + Sync + 'static,
+    T::Timestamp: Debug + Ord + Copy + Send + Sync + 'static,
+{
+    fn spill_to_disk<C>(
+        self,
+        config: SpillConfig,
+        codec: C,
+    ) -> impl Stream<Item = StreamItem<T>> + Send + Sync
+    where
+        Self: Unpin + Send + 'static,
+        C: SpillCodec<T> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(SpillState {
+            head: VecDeque::new(),
+            ended: false,
+            waker: None,
+            task_waker: None,
+        }));
+        let task_state = Arc::clone(&state);
+
+        let task = FluxionTask::spawn(|cancel| async move {
+            let mut stream = self;
+            let mut backlog = Backlog::new(config, codec);
+            let mut source_ended = false;
+            let mut budget = CoopBudget::new();
+
+            let drained = || {
+                poll_fn(|cx| {
+                    let mut state = task_state.lock();
+                    if state.head.is_empty() {
+                        Poll::Ready(())
+                    } else {
+                        state.task_waker = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                })
+            };
+
+            loop {
+                // Refill the head from the backlog once the consumer has drained it
+                if !backlog.is_empty() && task_state.lock().head.is_empty() {
+                    match backlog.pop_front().await {
+                        Ok(items) => task_state.lock().push(items),
+                        Err(e) => {
+                            // Items after an unreadable block cannot be delivered in order
+                            task_state.lock().push([StreamItem::Error(e)]);
+                            break;
+                        }
+                    }
+                    continue;
+                }
+                if source_ended && backlog.is_empty() {
+                    break;
+                }
+
+                let waiting_on_consumer = !backlog.is_empty();
+                let next = async {
+                    if source_ended {
+                        pending().await
+                    } else {
+                        Event::Source(stream.next().await)
+                    }
+                };
+                let refill = async {
+                    if waiting_on_consumer {
+                        drained().await;
+                        Event::Drained
+                    } else {
+                        pending().await
+                    }
+                };
+                let event = match select(
+                    pin!(select(pin!(next), pin!(refill))),
+                    pin!(cancel.cancelled()),
+                )
+                .await
+                {
+                    Either::Left((Either::Left((event, _)) | Either::Right((event, _)), _)) => {
+                        event
+                    }
+                    Either::Right(_) => Event::Cancelled,
+                };
+
+                match event {
+                    Event::Source(Some(StreamItem::Value(value))) => {
+                        let value = {
+                            let mut state = task_state.lock();
+                            if backlog.is_empty() && state.head.len() < backlog.config.memory_items
+                            {
+                                state.push([StreamItem::Value(value)]);
+                                None
+                            } else {
+                                Some(value)
+                            }
+                        };
+                        if let Some(value) = value {
+                            if let Err(e) = backlog.push(value).await {
+                                task_state.lock().push([StreamItem::Error(e)]);
+                                break;
+                            }
+                        }
+                    }
+                    Event::Source(Some(StreamItem::Error(e))) => {
+                        if backlog.is_empty() {
+                            task_state.lock().push([StreamItem::Error(e)]);
+                        } else {
+                            backlog.push_error(e);
+                        }
+                    }
+                    Event::Source(None) => source_ended = true,
+                    Event::Drained => {}
+                    Event::Cancelled => return,
+                }
+                budget.consume();
+                budget.proceed().await;
+            }
+            task_state.lock().end();
+        });
+        let task = Arc::new(task);
+
+        futures::stream::poll_fn(move |cx| {
+            let _task = &task;
+            let mut state = state.lock();
+            match state.head.pop_front() {
+                Some(item) => {
+                    if state.head.is_empty() {
+                        state.wake_task();
+                    }
+                    Poll::Ready(Some(item))
+                }
+                None if state.ended => Poll::Ready(None),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    state.wake_task();
+                    Poll::Pending
+                }
+            }
+        })
+    }
+}
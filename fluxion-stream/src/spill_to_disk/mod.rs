@@ -0,0 +1,73 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Utility operator that buffers a large backlog on disk.
+//!
+//! [`spill_to_disk`](SpillToDiskExt::spill_to_disk) decouples a fast producer from a
+//! slow consumer without holding the whole backlog in memory. A background task drains
+//! the source into a queue that keeps its oldest and newest items in memory and writes
+//! the ones in between to a file, in blocks of
+//! [`memory_items`](SpillConfig::memory_items) items encoded by a [`SpillCodec`]. The
+//! blocks are read back in order as the consumer catches up.
+//!
+//! With the `spill-zstd` feature, [`SpillConfig::with_zstd`] compresses each block.
+//!
+//! Only available with a multi-threaded runtime, since it needs a file system.
+//!
+//! # Behavior
+//!
+//! - Items are emitted in source order, whether they stayed in memory or went to disk
+//! - At most twice `memory_items` items are held in memory at once
+//! - The spill file is created on the first spill and removed when the stream is dropped
+//! - Source errors are kept in memory and emitted in their position among the values;
+//!   the stream continues after them
+//! - File I/O and compression run on the runtime's blocking thread pool, never on the
+//!   executor or while the consumer's side is locked
+//! - A failure to write, read or decode a block is emitted as an error after the items
+//!   already read back, and ends the stream
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::{FluxionError, HasTimestamp};
+//! use fluxion_stream::{SpillCodec, SpillConfig, SpillToDiskExt};
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//!
+//! struct U64Codec;
+//!
+//! impl SpillCodec<Sequenced<u64>> for U64Codec {
+//!     fn encode(&self, item: &Sequenced<u64>, buf: &mut Vec<u8>) {
+//!         buf.extend_from_slice(&item.value.to_le_bytes());
+//!         buf.extend_from_slice(&item.timestamp().to_le_bytes());
+//!     }
+//!
+//!     fn decode(&self, bytes: &[u8]) -> Result<Sequenced<u64>, FluxionError> {
+//!         let field = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+//!         Ok(Sequenced::with_timestamp(field(0), field(8)))
+//!     }
+//! }
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<u64>>();
+//! let config = SpillConfig::new(std::env::temp_dir(), 2);
+//!
+//! let mut buffered = stream.spill_to_disk(config, U64Codec);
+//!
+//! for value in 1..=5 {
+//!     tx.unbounded_send((value, value).into()).unwrap();
+//! }
+//!
+//! for value in 1..=5 {
+//!     let item = unwrap_value(Some(unwrap_stream(&mut buffered, 500).await));
+//!     assert_eq!(item.value, value);
+//! }
+//! # }
+//! ```
+
+mod implementation;
+
+pub use implementation::{SpillCodec, SpillConfig, SpillToDiskExt};
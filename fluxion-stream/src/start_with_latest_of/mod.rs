@@ -0,0 +1,143 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Start-with-latest-of operator - seeds a stream with the current value of another.
+//!
+//! [`start_with_latest_of`](StartWithLatestOfExt::start_with_latest_of) waits for the
+//! first value of a seed stream and emits it before forwarding the source. When the seed
+//! stream already has several values ready, only the latest is emitted, so a seed that
+//! replays its history, such as a shared configuration stream, contributes its current
+//! value. This lets combining operators start right away instead of stalling until a slow
+//! configuration stream emits.
+//!
+//! # Behavior
+//!
+//! - The source is not polled until the seed is emitted; its items stay buffered upstream
+//! - The seed keeps its own timestamp
+//! - Errors of the seed stream are passed through while waiting for its first value
+//! - If the seed stream ends without a value, the source is forwarded unseeded
+//! - The seed stream is dropped once the seed is emitted
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{from_timestamped_iter, StartWithLatestOfExt};
+//! use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, readings) = test_channel::<Sequenced<i32>>();
+//!
+//! // The configuration stream replays its history on subscription
+//! let config = from_timestamped_iter::<Sequenced<i32>, _>([(10, 1), (20, 2)]);
+//! let mut readings = readings.start_with_latest_of(config);
+//!
+//! tx.unbounded_send(Sequenced::with_timestamp(5, 3)).unwrap();
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut readings, 500).await)).value, 20);
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut readings, 500).await)).value, 5);
+//! # }
+//! ```
+//!
+//! # See Also
+//!
+//! - [`StartWithExt::start_with`](crate::StartWithExt::start_with) - Prepend fixed values
+//! - [`WithLatestFromExt::with_latest_from`](crate::WithLatestFromExt::with_latest_from) -
+//!   Combine every item with the latest value of another stream
+
+use alloc::boxed::Box;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::StreamItem;
+use futures::Stream;
+
+/// Extension trait providing [`start_with_latest_of`](Self::start_with_latest_of) for
+/// every stream.
+pub trait StartWithLatestOfExt<T>: Stream<Item = StreamItem<T>> + Sized {
+    /// Emits the latest value `seed` has ready once it has one, then the items of this
+    /// stream.
+    ///
+    /// See the [module documentation](crate::start_with_latest_of) for details.
+    fn start_with_latest_of<O>(self, seed: O) -> StartWithLatestOf<Self, O>
+    where
+        O: Stream<Item = StreamItem<T>>;
+}
+
+impl<S, T> StartWithLatestOfExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+{
+    fn start_with_latest_of<O>(self, seed: O) -> StartWithLatestOf<Self, O>
+    where
+        O: Stream<Item = StreamItem<T>>,
+    {
+        StartWithLatestOf {
+            source: Box::pin(self),
+            seed: Some(Box::pin(seed)),
+        }
+    }
+}
+
+/// Stream returned by [`start_with_latest_of`](StartWithLatestOfExt::start_with_latest_of).
+pub struct StartWithLatestOf<S, O> {
+    source: Pin<Box<S>>,
+    seed: Option<Pin<Box<O>>>,
+}
+
+// Both streams are pinned on the heap
+impl<S, O> Unpin for StartWithLatestOf<S, O> {}
+
+impl<S, O> core::fmt::Debug for StartWithLatestOf<S, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StartWithLatestOf")
+            .field("seeded", &self.seed.is_none())
+            .finish()
+    }
+}
+
+impl<S, O, T> Stream for StartWithLatestOf<S, O>
+where
+    S: Stream<Item = StreamItem<T>>,
+    O: Stream<Item = StreamItem<T>>,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(seed) = this.seed.as_mut() {
+            let mut latest = None;
+            loop {
+                match seed.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(StreamItem::Value(value))) => latest = Some(value),
+                    Poll::Ready(Some(error)) => {
+                        if latest.is_none() {
+                            return Poll::Ready(Some(error));
+                        }
+                        // The seed is already found; later errors of the seed stream are
+                        // not part of the source
+                    }
+                    Poll::Ready(None) => break,
+                    Poll::Pending if latest.is_some() => break,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+            this.seed = None;
+            if let Some(value) = latest {
+                return Poll::Ready(Some(StreamItem::Value(value)));
+            }
+        }
+
+        this.source.as_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.source.size_hint();
+        match self.seed {
+            Some(_) => (lower, None),
+            None => (lower, upper),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_state_cell_impl {
+    ($($bounds:tt)*) => {
+        use crate::coop::CoopBudget;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::task::{Poll, Waker};
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{FluxionError, FluxionTask, StreamItem};
+        use futures::future::{poll_fn, select, Either};
+        use futures::{Stream, StreamExt};
+
+        /// Latest value, how many values were written, and how the source ended.
+        struct CellState<T> {
+            value: Option<T>,
+            version: u64,
+            closed: bool,
+            error: Option<FluxionError>,
+            wakers: Vec<Waker>,
+        }
+
+        impl<T> CellState<T> {
+            fn wake_all(&mut self) {
+                for waker in self.wakers.drain(..) {
+                    waker.wake();
+                }
+            }
+        }
+
+        /// Watchable latest value of a stream, created by
+        /// [`to_state_cell`](StateCellExt::to_state_cell).
+        ///
+        /// Clones share the same value and the same background task, which runs until
+        /// the source ends or the last clone is dropped. Each clone tracks on its own
+        /// which values [`changed`](Self::changed) has reported.
+        pub struct StateCell<T> {
+            state: Arc<Mutex<CellState<T>>>,
+            seen: u64,
+            _task: Arc<FluxionTask>,
+        }
+
+        impl<T: Clone> StateCell<T> {
+            /// Returns the latest value of the stream, or `None` before its first value.
+            pub fn current(&self) -> Option<T> {
+                self.state.lock().value.clone()
+            }
+
+            /// Waits until the stream produces a value this handle has not reported yet,
+            /// and marks it as reported.
+            ///
+            /// # Errors
+            ///
+            /// Once the source has ended and every value has been reported, returns the
+            /// error that ended the source, or a
+            /// [`FluxionError::StreamProcessingError`] if it completed.
+            pub async fn changed(&mut self) -> Result<(), FluxionError> {
+                poll_fn(|cx| {
+                    let mut state = self.state.lock();
+                    if state.version > self.seen {
+                        self.seen = state.version;
+                        return Poll::Ready(Ok(()));
+                    }
+                    if state.closed {
+                        return Poll::Ready(Err(state.error.clone().unwrap_or_else(|| {
+                            FluxionError::stream_error(String::from("state cell source completed"))
+                        })));
+                    }
+                    if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                        state.wakers.push(cx.waker().clone());
+                    }
+                    Poll::Pending
+                })
+                .await
+            }
+
+            /// Returns `true` once the source has ended.
+            pub fn is_closed(&self) -> bool {
+                self.state.lock().closed
+            }
+        }
+
+        impl<T> Clone for StateCell<T> {
+            fn clone(&self) -> Self {
+                Self {
+                    state: Arc::clone(&self.state),
+                    seen: self.seen,
+                    _task: Arc::clone(&self._task),
+                }
+            }
+        }
+
+        impl<T: Debug> Debug for StateCell<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let state = self.state.lock();
+                f.debug_struct("StateCell")
+                    .field("value", &state.value)
+                    .field("closed", &state.closed)
+                    .finish_non_exhaustive()
+            }
+        }
+
+        pub trait StateCellExt<T: Clone + $($bounds)* 'static>: Stream<Item = StreamItem<T>> {
+            /// Drives the stream on a background task and returns a [`StateCell`] holding
+            /// its latest value.
+            // The cell is a new representation of the stream, consuming it is intended
+            #[allow(clippy::wrong_self_convention)]
+            fn to_state_cell(self) -> StateCell<T>
+            where
+                Self: Unpin + $($bounds)* 'static;
+        }
+
+        impl<S, T> StateCellExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Clone + $($bounds)* 'static,
+        {
+            fn to_state_cell(self) -> StateCell<T>
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                let state = Arc::new(Mutex::new(CellState {
+                    value: None,
+                    version: 0,
+                    closed: false,
+                    error: None,
+                    wakers: Vec::new(),
+                }));
+                let task_state = Arc::clone(&state);
+
+                let task = FluxionTask::spawn(|cancel| async move {
+                    let mut stream = self;
+                    let mut budget = CoopBudget::new();
+                    let mut error = None;
+                    while let Either::Left((stream_item, _)) =
+                        select(stream.next(), cancel.cancelled()).await
+                    {
+                        match stream_item {
+                            Some(StreamItem::Value(value)) => {
+                                {
+                                    let mut state = task_state.lock();
+                                    state.value = Some(value);
+                                    state.version += 1;
+                                    state.wake_all();
+                                }
+                                budget.consume();
+                                budget.proceed().await;
+                            }
+                            Some(StreamItem::Error(e)) => {
+                                error = Some(e);
+                                break;
+                            }
+                            None => {
+                                break;
+                            }
+                        }
+                    }
+
+                    let mut state = task_state.lock();
+                    state.closed = true;
+                    state.error = error;
+                    state.wake_all();
+                });
+
+                StateCell {
+                    state,
+                    seen: 0,
+                    _task: Arc::new(task),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,75 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Bridge from a stream to a watchable current value.
+//!
+//! [`to_state_cell`](StateCellExt::to_state_cell) consumes a stream on a background task
+//! and returns a [`StateCell`] that always holds its latest value. Other tasks read it
+//! with [`current`](StateCell::current) or wait for the next one with
+//! [`changed`](StateCell::changed), without writing a subscription loop around a mutex.
+//!
+//! # Runtime Requirements
+//!
+//! Like [`share`](crate::ShareExt::share), this adapter spawns a task and requires
+//! `runtime-tokio`, `runtime-smol`, `runtime-async-std` or a `wasm32` target.
+//!
+//! # Behavior
+//!
+//! - The source is consumed as fast as it produces, whether or not anyone reads the cell
+//! - Only the latest value is kept; `changed` reports that a newer value exists, not
+//!   every value in between
+//! - An error ends the source like completion does; the cell keeps its last value and
+//!   `changed` returns the error once every value has been reported
+//! - The task is cancelled when the last clone of the cell is dropped
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::StateCellExt;
+//! use fluxion_test_utils::{helpers::test_channel, sequenced::Sequenced};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, temperatures) = test_channel::<Sequenced<i32>>();
+//! let mut temperature = temperatures.to_state_cell();
+//!
+//! assert_eq!(temperature.current(), None);
+//!
+//! tx.unbounded_send(Sequenced::new(21)).unwrap();
+//! temperature.changed().await.unwrap();
+//!
+//! assert_eq!(temperature.current().map(|t| t.into_inner()), Some(21));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{StateCell, StateCellExt};
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{StateCell, StateCellExt};
@@ -4,15 +4,16 @@
 
 macro_rules! define_take_latest_when_impl {
     ($($bounds:tt)*) => {
-        use crate::ordered_merge::ordered_merge_with_index;
+        use crate::ordered_merge::ordered_merge_with_index_and_mode;
+        use crate::ordering::OrderingMode;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec;
         use alloc::vec::Vec;
         use core::fmt::Debug;
         use core::pin::Pin;
-        use fluxion_core::fluxion_mutex::Mutex;
         use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::lock_state;
+        use fluxion_core::sync::{Arc, StateMutex};
         use fluxion_core::{Fluxion, StreamItem};
         use futures::{Stream, StreamExt};
 
@@ -30,6 +31,18 @@ macro_rules! define_take_latest_when_impl {
             where
                 IS: IntoStream<Item = StreamItem<T>>,
                 IS::Stream: $($bounds)* 'static;
+
+            /// Samples the source like [`take_latest_when`](Self::take_latest_when),
+            /// merging the streams in the given [`OrderingMode`].
+            fn take_latest_when_with_mode<IS>(
+                self,
+                filter_stream: IS,
+                filter: impl Fn(&T::Inner) -> bool + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static;
         }
 
         impl<T, S> TakeLatestWhenExt<T> for S
@@ -44,6 +57,19 @@ macro_rules! define_take_latest_when_impl {
                 filter_stream: IS,
                 filter: impl Fn(&T::Inner) -> bool + $($bounds)* 'static,
             ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+            {
+                self.take_latest_when_with_mode(filter_stream, filter, OrderingMode::Ordered)
+            }
+
+            fn take_latest_when_with_mode<IS>(
+                self,
+                filter_stream: IS,
+                filter: impl Fn(&T::Inner) -> bool + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
             where
                 IS: IntoStream<Item = StreamItem<T>>,
                 IS::Stream: $($bounds)* 'static,
@@ -51,10 +77,10 @@ macro_rules! define_take_latest_when_impl {
                 let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
                     vec![Box::pin(self), Box::pin(filter_stream.into_stream())];
 
-                let source_value = Arc::new(Mutex::new(None));
+                let source_value = Arc::new(StateMutex::new(None));
                 let filter = Arc::new(filter);
 
-                let combined_stream = ordered_merge_with_index(streams).filter_map(move |(item, index)| {
+                let combined_stream = ordered_merge_with_index_and_mode(streams, mode).filter_map(move |(item, index)| {
                     let source_value = Arc::clone(&source_value);
                     let filter = Arc::clone(&filter);
                     async move {
@@ -62,12 +88,12 @@ macro_rules! define_take_latest_when_impl {
                             StreamItem::Value(ordered_value) => {
                                 match index {
                                     0 => {
-                                        let mut source = source_value.lock();
+                                        let mut source = lock_state!(source_value);
                                         *source = Some(ordered_value);
                                         None
                                     }
                                     1 => {
-                                        let source = source_value.lock();
+                                        let source = lock_state!(source_value);
 
                                         let filter_inner = ordered_value.clone().into_inner();
 
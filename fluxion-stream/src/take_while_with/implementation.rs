@@ -70,14 +70,15 @@ where
 
 macro_rules! define_take_while_with_impl {
     ($($stream_bounds:tt)*) => {
-        use crate::ordered_merge::ordered_merge_with_index;
+        use crate::ordered_merge::ordered_merge_with_index_and_mode;
+        use crate::ordering::OrderingMode;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec;
         use alloc::vec::Vec;
         use core::fmt::Debug;
         use core::pin::Pin;
-        use fluxion_core::fluxion_mutex::Mutex;
+        use fluxion_core::lock_state;
+        use fluxion_core::sync::{Arc, StateMutex};
         use fluxion_core::{Fluxion, HasTimestamp, StreamItem, Timestamped};
         use futures::stream::StreamExt;
         use futures::Stream;
@@ -99,6 +100,15 @@ macro_rules! define_take_while_with_impl {
                 filter_stream: S,
                 filter: impl Fn(&TFilter::Inner) -> bool + $($stream_bounds)* 'static,
             ) -> impl Stream<Item = StreamItem<TItem>>;
+
+            /// Takes items like [`take_while_with`](Self::take_while_with), merging the
+            /// streams in the given [`OrderingMode`].
+            fn take_while_with_with_mode(
+                self,
+                filter_stream: S,
+                filter: impl Fn(&TFilter::Inner) -> bool + $($stream_bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<TItem>>;
         }
 
         impl<TItem, TFilter, S, P> TakeWhileExt<TItem, TFilter, S> for P
@@ -115,6 +125,15 @@ macro_rules! define_take_while_with_impl {
                 self,
                 filter_stream: S,
                 filter: impl Fn(&TFilter::Inner) -> bool + $($stream_bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<TItem>> {
+                self.take_while_with_with_mode(filter_stream, filter, OrderingMode::Ordered)
+            }
+
+            fn take_while_with_with_mode(
+                self,
+                filter_stream: S,
+                filter: impl Fn(&TFilter::Inner) -> bool + $($stream_bounds)* 'static,
+                mode: OrderingMode,
             ) -> impl Stream<Item = StreamItem<TItem>> {
                 let filter = Arc::new(filter);
 
@@ -127,9 +146,9 @@ macro_rules! define_take_while_with_impl {
                 let streams: Vec<PinnedStream<Item<TItem, TFilter>>> =
                     vec![Box::pin(source_stream), Box::pin(filter_stream)];
 
-                let state = Arc::new(Mutex::new((None::<TFilter::Inner>, false)));
+                let state = Arc::new(StateMutex::new((None::<TFilter::Inner>, false)));
 
-                let combined_stream = ordered_merge_with_index(streams).filter_map({
+                let combined_stream = ordered_merge_with_index_and_mode(streams, mode).filter_map({
                     let state = Arc::clone(&state);
                     move |(stream_item, _index)| {
                         let state = Arc::clone(&state);
@@ -139,7 +158,7 @@ macro_rules! define_take_while_with_impl {
                             match stream_item {
                                 StreamItem::Error(e) => Some(StreamItem::Error(e)),
                                 StreamItem::Value(item) => {
-                                    let mut guard = state.lock();
+                                    let mut guard = lock_state!(state);
                                     let (filter_state, terminated) = &mut *guard;
 
                                     if *terminated {
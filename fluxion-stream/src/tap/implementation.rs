@@ -4,7 +4,7 @@
 
 macro_rules! define_tap_impl {
     ($($bounds:tt)*) => {
-        use fluxion_core::{Fluxion, StreamItem};
+        use fluxion_core::{Fluxion, FluxionError, StreamItem};
         use futures::{Stream, StreamExt};
         use core::fmt::Debug;
 
@@ -26,6 +26,19 @@ macro_rules! define_tap_impl {
                     item
                 })
             }
+
+            fn inspect_err<F>(self, mut f: F) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                Self: Unpin + 'static + $($bounds)*,
+                F: FnMut(&FluxionError) + 'static + $($bounds)*,
+            {
+                self.map(move |item| {
+                    if let StreamItem::Error(error) = &item {
+                        f(error);
+                    }
+                    item
+                })
+            }
         }
 
         impl<S, T> TapExt<T> for S
@@ -71,6 +71,30 @@
 //! The tap function is only called for values, not errors. Errors pass through
 //! unchanged without invoking the tap function.
 //!
+//! To observe errors, use [`inspect_err`](TapExt::inspect_err). It is called for each
+//! error and passes it on unchanged, so logging errors does not change the stream;
+//! use [`on_error`](crate::OnErrorExt::on_error) to consume or recover from them.
+//!
+//! ```
+//! use fluxion_core::{FluxionError, StreamItem};
+//! use fluxion_stream::prelude::*;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, rx) = futures::channel::mpsc::unbounded::<StreamItem<Sequenced<i32>>>();
+//!
+//! let mut logged = rx.inspect_err(|error| eprintln!("Stream error: {error}"));
+//!
+//! tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom"))).unwrap();
+//! drop(tx);
+//!
+//! // The error is still delivered downstream
+//! assert!(matches!(logged.next().await, Some(StreamItem::Error(_))));
+//! # }
+//! ```
+//!
 //! # See Also
 //!
 //! - [`MapOrderedExt::map_ordered`](crate::MapOrderedExt::map_ordered) - Transform values
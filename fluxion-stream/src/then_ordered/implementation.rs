@@ -0,0 +1,95 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_then_ordered_impl {
+    ($($bounds:tt)*) => {
+        use alloc::boxed::Box;
+        use core::fmt::Debug;
+        use core::future::Future;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::future::{ready, Either};
+        use futures::Stream;
+        use futures::{FutureExt, StreamExt};
+
+        pub trait ThenOrderedExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Transforms each value with an async closure, one value at a time.
+            fn then_ordered<U, F, Fut>(self, f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(T) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = U> + $($bounds)* 'static;
+
+            /// Transforms each value with an async closure, running up to `limit`
+            /// transformations at once while emitting results in source order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is 0.
+            fn then_ordered_concurrent<U, F, Fut>(
+                self,
+                limit: usize,
+                f: F,
+            ) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(T) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = U> + $($bounds)* 'static;
+        }
+
+        impl<S, T> ThenOrderedExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn then_ordered<U, F, Fut>(self, f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(T) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = U> + $($bounds)* 'static,
+            {
+                self.then_ordered_concurrent(1, f)
+            }
+
+            fn then_ordered_concurrent<U, F, Fut>(
+                self,
+                limit: usize,
+                mut f: F,
+            ) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                F: FnMut(T) -> Fut + $($bounds)* 'static,
+                Fut: Future<Output = U> + $($bounds)* 'static,
+            {
+                assert!(limit > 0, "then_ordered_concurrent requires a limit of at least 1");
+
+                Box::pin(
+                    self.map(move |item| match item {
+                        StreamItem::Value(value) => Either::Left(f(value).map(StreamItem::Value)),
+                        StreamItem::Error(e) => Either::Right(ready(StreamItem::Error(e))),
+                    })
+                    .buffered(limit),
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Async transformation operator that preserves source order.
+//!
+//! [`then_ordered`](ThenOrderedExt::then_ordered) transforms each value with an async
+//! closure, for I/O-bound enrichment such as a database or HTTP lookup.
+//! [`then_ordered_concurrent`](ThenOrderedExt::then_ordered_concurrent) does the same with
+//! up to `limit` transformations in flight at once.
+//!
+//! # Behavior
+//!
+//! - The closure receives the full item (`T`), like [`map_ordered`](crate::MapOrderedExt::map_ordered)
+//! - Results are emitted strictly in source order, even when a later transformation
+//!   finishes first; a slow transformation holds back the results behind it
+//! - With `limit` transformations in flight, the operator stops pulling from the source
+//!   until the oldest one completes
+//! - Errors are passed through unchanged and keep their position relative to the values
+//!
+//! # See Also
+//!
+//! - [`map_ordered`](crate::MapOrderedExt::map_ordered) - Synchronous transformation
+//! - [`filter_ordered_async`](crate::FilterOrderedAsyncExt::filter_ordered_async) - Async filtering
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::ThenOrderedExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//!
+//! async fn lookup_name(id: u32) -> String {
+//!     format!("user-{id}")
+//! }
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<u32>>();
+//!
+//! let mut names = stream.then_ordered_concurrent(4, |item: Sequenced<u32>| async move {
+//!     let timestamp = item.timestamp();
+//!     Sequenced::with_timestamp(lookup_name(item.value).await, timestamp)
+//! });
+//!
+//! tx.unbounded_send((7, 1).into()).unwrap();
+//! tx.unbounded_send((9, 2).into()).unwrap();
+//!
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut names, 500).await)).value, "user-7");
+//! assert_eq!(unwrap_value(Some(unwrap_stream(&mut names, 500).await)).value, "user-9");
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::ThenOrderedExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::ThenOrderedExt;
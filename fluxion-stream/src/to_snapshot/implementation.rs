@@ -0,0 +1,212 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_to_snapshot_impl {
+    ($($bounds:tt)*) => {
+        use crate::ordered_merge::ordered_merge_with_index;
+        use alloc::boxed::Box;
+        use alloc::collections::BTreeMap;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::Add;
+        use core::pin::Pin;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        const SOURCE: usize = 0;
+
+        /// Latest value and its timestamp per key, the end of the open period and whether
+        /// the map changed in it.
+        struct SnapshotState<K, I, TS> {
+            latest: BTreeMap<K, (TS, I)>,
+            end: Option<TS>,
+            changed: bool,
+        }
+
+        impl<K: Ord + Clone, I: Clone, TS: Ord + Copy> SnapshotState<K, I, TS> {
+            fn new() -> Self {
+                Self {
+                    latest: BTreeMap::new(),
+                    end: None,
+                    changed: false,
+                }
+            }
+
+            /// Stores `value` as the latest of `key` unless the stored one is newer.
+            fn upsert(&mut self, key: K, timestamp: TS, value: I) {
+                if self.latest.get(&key).is_some_and(|(stored, _)| *stored > timestamp) {
+                    return;
+                }
+                self.latest.insert(key, (timestamp, value));
+                self.changed = true;
+            }
+
+            fn snapshot(&self) -> BTreeMap<K, I> {
+                self.latest
+                    .iter()
+                    .map(|(key, (_, value))| (key.clone(), value.clone()))
+                    .collect()
+            }
+        }
+
+        pub trait ToSnapshotExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Keeps the latest value per key and emits the whole map at the end of every
+            /// `period` of timestamps in which it changed, timestamped with the end of
+            /// the period.
+            ///
+            /// # Panics
+            ///
+            /// Panics on the first item if `period` is zero.
+            fn to_snapshot<Out, K, F, D>(
+                self,
+                period: D,
+                key: F,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                K: Ord + Clone + Debug + Unpin + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+                D: Copy + $($bounds)* 'static,
+                T::Timestamp: Add<D, Output = T::Timestamp>,
+                Out: Fluxion<Inner = BTreeMap<K, T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+
+            /// Keeps the latest value per key and emits the whole map for every item of
+            /// `trigger`, timestamped with the trigger.
+            fn to_snapshot_when<Out, K, F, IS>(
+                self,
+                trigger: IS,
+                key: F,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                K: Ord + Clone + Debug + Unpin + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+                Out: Fluxion<Inner = BTreeMap<K, T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> ToSnapshotExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn to_snapshot<Out, K, F, D>(
+                self,
+                period: D,
+                key: F,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                K: Ord + Clone + Debug + Unpin + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+                D: Copy + $($bounds)* 'static,
+                T::Timestamp: Add<D, Output = T::Timestamp>,
+                Out: Fluxion<Inner = BTreeMap<K, T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let state = Arc::new(Mutex::new(SnapshotState::<K, T::Inner, T::Timestamp>::new()));
+                let state_clone = Arc::clone(&state);
+
+                let emit = |snapshot: BTreeMap<K, T::Inner>, at: T::Timestamp| {
+                    StreamItem::Value(Out::with_timestamp(snapshot, at.into()))
+                };
+
+                let main_stream = self.flat_map(move |item| {
+                    let mut state = state_clone.lock();
+
+                    let snapshots: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let mut end = *state.end.get_or_insert_with(|| {
+                                assert!(timestamp + period > timestamp, "to_snapshot requires a non-zero period");
+                                timestamp + period
+                            });
+                            let mut snapshots = Vec::new();
+
+                            // Close the open period, skipping the empty ones after it
+                            if timestamp >= end {
+                                if state.changed {
+                                    snapshots.push(emit(state.snapshot(), end));
+                                    state.changed = false;
+                                }
+                                while timestamp >= end {
+                                    end = end + period;
+                                }
+                                state.end = Some(end);
+                            }
+
+                            let inner = value.into_inner();
+                            state.upsert(key(&inner), timestamp, inner);
+                            snapshots
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(snapshots)
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let state = state.lock();
+                    match state.end {
+                        Some(end) if state.changed => vec![emit(state.snapshot(), end)],
+                        _ => Vec::new(),
+                    }
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(main_stream.chain(flush_stream))
+            }
+
+            fn to_snapshot_when<Out, K, F, IS>(
+                self,
+                trigger: IS,
+                key: F,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                K: Ord + Clone + Debug + Unpin + $($bounds)* 'static,
+                F: Fn(&T::Inner) -> K + $($bounds)* 'static,
+                Out: Fluxion<Inner = BTreeMap<K, T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
+                    vec![Box::pin(self), Box::pin(trigger.into_stream())];
+
+                let mut state = SnapshotState::<K, T::Inner, T::Timestamp>::new();
+
+                Box::pin(ordered_merge_with_index(streams).filter_map(move |(item, index)| {
+                    let snapshot = match item {
+                        StreamItem::Value(value) if index == SOURCE => {
+                            let timestamp = value.timestamp();
+                            let inner = value.into_inner();
+                            state.upsert(key(&inner), timestamp, inner);
+                            None
+                        }
+                        StreamItem::Value(value) => Some(StreamItem::Value(Out::with_timestamp(
+                            state.snapshot(),
+                            value.timestamp().into(),
+                        ))),
+                        StreamItem::Error(e) => Some(StreamItem::Error(e)),
+                    };
+                    futures::future::ready(snapshot)
+                }))
+            }
+        }
+    };
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Compaction of a keyed changelog into snapshots of the whole table.
+//!
+//! [`to_snapshot`](ToSnapshotExt::to_snapshot) keeps the latest value per key of a stream
+//! of updates and emits the whole map once per period of timestamps, and
+//! [`to_snapshot_when`](ToSnapshotExt::to_snapshot_when) emits it whenever a trigger
+//! stream emits. Consumers that synchronize state, such as a late-joining replica or a
+//! UI that redraws a table, receive complete snapshots instead of replaying every update.
+//!
+//! # Behavior
+//!
+//! - Snapshots are [`BTreeMap`](alloc::collections::BTreeMap)s from key to the latest
+//!   value, ordered by key
+//! - A value replaces the stored one for its key unless it has an earlier timestamp
+//! - [`to_snapshot`](ToSnapshotExt::to_snapshot): periods start at the first timestamp
+//!   plus whole multiples of `period`; a snapshot is emitted once an item past the end of
+//!   a period in which the map changed arrives, timestamped with the end of the period,
+//!   and for the open period when the source ends
+//! - [`to_snapshot_when`](ToSnapshotExt::to_snapshot_when): every trigger item emits a
+//!   snapshot with its timestamp, an empty one before any update
+//! - Errors of either stream are propagated without affecting the map
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::ToSnapshotExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//! use std::collections::BTreeMap;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, updates) = test_channel::<Sequenced<(&str, u32)>>();
+//!
+//! // The latest stock level per item, every 10 ticks
+//! let mut snapshots = updates
+//!     .to_snapshot::<Sequenced<BTreeMap<&str, (&str, u32)>>, _, _, _>(10u64, |(item, _)| *item);
+//!
+//! tx.unbounded_send((("apples", 5), 0).into()).unwrap();
+//! tx.unbounded_send((("pears", 3), 4).into()).unwrap();
+//! tx.unbounded_send((("apples", 2), 7).into()).unwrap();
+//! tx.unbounded_send((("pears", 1), 12).into()).unwrap();
+//!
+//! let snapshot = unwrap_value(Some(unwrap_stream(&mut snapshots, 500).await)).value;
+//! assert_eq!(snapshot["apples"], ("apples", 2));
+//! assert_eq!(snapshot["pears"], ("pears", 3));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::ToSnapshotExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::ToSnapshotExt;
@@ -0,0 +1,74 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_traced_impl {
+    ($($bounds:tt)*) => {
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem, Traced};
+        use futures::{Stream, StreamExt};
+
+        pub trait TracedExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn traced(self) -> impl Stream<Item = StreamItem<Traced<T>>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static;
+        }
+
+        impl<S, T> TracedExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn traced(self) -> impl Stream<Item = StreamItem<Traced<T>>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                self.map(|item| item.map(Traced::new))
+            }
+        }
+
+        pub trait TraceSpanExt<T>: Stream<Item = StreamItem<Traced<T>>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn trace_span(
+                self,
+                operator: &'static str,
+            ) -> impl Stream<Item = StreamItem<Traced<T>>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static;
+        }
+
+        impl<S, T> TraceSpanExt<T> for S
+        where
+            S: Stream<Item = StreamItem<Traced<T>>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn trace_span(
+                self,
+                operator: &'static str,
+            ) -> impl Stream<Item = StreamItem<Traced<T>>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+            {
+                self.map(move |item| {
+                    item.map(|value| {
+                        let span = value.child_span(operator);
+                        value.rebind(span)
+                    })
+                })
+            }
+        }
+    };
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Trace context propagation through operator chains.
+//!
+//! This module provides two operators that carry a [`tracing`] span alongside every item:
+//!
+//! - [`traced`](TracedExt::traced) wraps each value in a [`Traced`](fluxion_core::Traced)
+//!   item that owns a new `fluxion.item` span.
+//! - [`trace_span`](TraceSpanExt::trace_span) rebinds each item to a child
+//!   `fluxion.operator` span of its current span, recording the operator name.
+//!
+//! Because [`Traced`](fluxion_core::Traced) delegates ordering and timestamps to the
+//! wrapped item, traced items flow through every ordered operator unchanged. Its
+//! [`Inner`](fluxion_core::Timestamped::Inner) is itself traced, so operators that stamp
+//! a value with a new timestamp, such as `take_latest_when` or `combine_latest`, keep it
+//! in its trace, and closures of operators like `filter_ordered` receive the value with
+//! its span, dereferencing to the plain value. Placing `trace_span` after the stages of
+//! interest produces one nested span per stage, so a single sensor reading can be
+//! followed across `ordered_merge`, windowing and the final subscribe handler, run with
+//! `subscribe_traced` from `fluxion-exec`, once the spans are exported (e.g. via
+//! `tracing-opentelemetry`).
+//!
+//! Requires the `tracing` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use fluxion_stream::prelude::*;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, rx) = async_channel::unbounded::<Sequenced<i32>>();
+//!
+//! let mut pipeline = rx
+//!     .into_fluxion_stream()
+//!     .traced()
+//!     .filter_ordered(|x| **x > 10)
+//!     .trace_span("filter");
+//!
+//! tx.try_send(Sequenced::new(42)).unwrap();
+//! drop(tx);
+//!
+//! let item = pipeline.next().await.unwrap().unwrap();
+//! item.in_scope(|| assert_eq!(item.inner().value, 42));
+//! # }
+//! ```
+//!
+//! # Error Handling
+//!
+//! Errors carry no trace context and pass through both operators unchanged.
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::{TraceSpanExt, TracedExt};
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::{TraceSpanExt, TracedExt};
@@ -0,0 +1,55 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_try_map_ordered_impl {
+    ($($bounds:tt)*) => {
+        use core::error::Error;
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, FluxionError, StreamItem};
+        use futures::Stream;
+        use futures::StreamExt;
+
+        pub trait TryMapOrderedExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn try_map_ordered<U, E, F>(self, f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                E: Error + Send + Sync + 'static,
+                F: FnMut(T) -> Result<U, E> + $($bounds)* 'static;
+        }
+
+        impl<S, T> TryMapOrderedExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>>,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn try_map_ordered<U, E, F>(self, mut f: F) -> impl Stream<Item = StreamItem<U>> + $($bounds)*
+            where
+                Self: Unpin + $($bounds)* 'static,
+                U: Fluxion,
+                U::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                U::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+                E: Error + Send + Sync + 'static,
+                F: FnMut(T) -> Result<U, E> + $($bounds)* 'static,
+            {
+                self.map(move |item| match item {
+                    StreamItem::Value(value) => match f(value) {
+                        Ok(mapped) => StreamItem::Value(mapped),
+                        Err(e) => StreamItem::Error(FluxionError::wrap(e)),
+                    },
+                    StreamItem::Error(e) => StreamItem::Error(e),
+                })
+            }
+        }
+    };
+}
@@ -0,0 +1,87 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Fallible transformation operator.
+//!
+//! The [`try_map_ordered`](TryMapOrderedExt::try_map_ordered) operator works like
+//! [`map_ordered`](crate::MapOrderedExt::map_ordered), but the closure returns a `Result`.
+//! `Ok` values are emitted as values and `Err` values as `StreamItem::Error`, so fallible
+//! transformations don't need a `match` in every closure.
+//!
+//! # Behavior
+//!
+//! - The closure receives the full item (`T`), like [`map_ordered`](crate::MapOrderedExt::map_ordered)
+//! - `Ok(u)` is emitted as `StreamItem::Value(u)`
+//! - `Err(e)` is emitted as `StreamItem::Error(FluxionError::Wrapped { .. })`, keeping `e`
+//!   as its source so it can be recovered with [`FluxionError::downcast_ref`](fluxion_core::FluxionError::downcast_ref)
+//! - A failed item does not end the stream; later items are still transformed
+//! - Upstream errors are passed through unchanged
+//!
+//! # See Also
+//!
+//! - [`map_ordered`](crate::MapOrderedExt::map_ordered) - Infallible transformation
+//! - [`on_error`](crate::OnErrorExt::on_error) - Handle the resulting errors
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_core::{HasTimestamp, StreamItem};
+//! use fluxion_stream::TryMapOrderedExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, test_channel}
+//! };
+//! use std::num::ParseIntError;
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<&str>>();
+//!
+//! let mut numbers = stream.try_map_ordered(|item| {
+//!     let timestamp = item.timestamp();
+//!     item.value
+//!         .parse::<i32>()
+//!         .map(|n| Sequenced::with_timestamp(n, timestamp))
+//! });
+//!
+//! tx.unbounded_send(("42", 1).into()).unwrap();
+//! tx.unbounded_send(("x", 2).into()).unwrap();
+//!
+//! assert!(matches!(unwrap_stream(&mut numbers, 500).await, StreamItem::Value(n) if n.value == 42));
+//! assert!(matches!(
+//!     unwrap_stream(&mut numbers, 500).await,
+//!     StreamItem::Error(e) if e.downcast_ref::<ParseIntError>().is_some()
+//! ));
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::TryMapOrderedExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::TryMapOrderedExt;
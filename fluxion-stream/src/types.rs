@@ -8,7 +8,9 @@
 
 use alloc::vec::Vec;
 use core::fmt::Debug;
-use fluxion_core::{HasTimestamp, Timestamped};
+use core::ops::Sub;
+use core::time::Duration;
+use fluxion_core::{HasTimestamp, StreamItem, Timestamped};
 
 /// Represents a value paired with its previous value in the stream.
 ///
@@ -62,6 +64,48 @@ impl<T: Timestamped> Timestamped for WithPrevious<T> {
     }
 }
 
+/// Represents a value paired with its ordinal position in the stream.
+///
+/// Used by [`WithIndexExt`](crate::WithIndexExt) to number the values of a stream.
+/// The timestamp is the one of the wrapped value.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Indexed<T> {
+    /// Zero-based position of the value among the values emitted so far
+    pub index: u64,
+    /// The value in the stream
+    pub value: T,
+}
+
+impl<T> Indexed<T> {
+    /// Creates a new Indexed with the given index and value.
+    pub fn new(index: u64, value: T) -> Self {
+        Self { index, value }
+    }
+}
+
+impl<T: Timestamped> HasTimestamp for Indexed<T> {
+    type Timestamp = T::Timestamp;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.value.timestamp()
+    }
+}
+
+impl<T: Timestamped> Timestamped for Indexed<T> {
+    type Inner = T::Inner;
+
+    fn with_timestamp(value: Self::Inner, timestamp: Self::Timestamp) -> Self {
+        Self {
+            index: 0,
+            value: T::with_timestamp(value, timestamp),
+        }
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self.value.into_inner()
+    }
+}
+
 /// State container holding the latest values from multiple combined streams.
 ///
 /// Used by operators that combine multiple streams such as [`combine_latest`](crate::CombineLatestExt::combine_latest),
@@ -164,3 +208,274 @@ where
         self
     }
 }
+
+/// Window in which [`window_join`](crate::WindowJoinExt::window_join) pairs items.
+///
+/// `D` is the difference between two timestamps, such as `u64` for sequence numbers or
+/// [`Duration`](core::time::Duration) for instants.
+///
+/// # Examples
+///
+/// ```
+/// use fluxion_stream::JoinWindow;
+///
+/// // Items at timestamps 10 and 19 share the window [10, 20) of the first item at 10
+/// assert!(JoinWindow::Tumbling(10u64).contains(10u64, 10, 19));
+/// assert!(!JoinWindow::Tumbling(10u64).contains(10u64, 19, 20));
+/// // Items at most 5 apart are paired
+/// assert!(JoinWindow::Sliding(5u64).contains(0u64, 19, 24));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinWindow<D> {
+    /// Consecutive windows of the given size, starting at the first timestamp seen.
+    Tumbling(D),
+    /// Pairs items whose timestamps are at most the given size apart.
+    Sliding(D),
+}
+
+impl<D: WindowSpan> JoinWindow<D> {
+    /// Returns true if the items at `a` and `b` fall in the same window, given the first
+    /// timestamp seen at `origin`.
+    ///
+    /// The timestamps may be given in either order. A tumbling window never contains a
+    /// timestamp older than `origin`.
+    pub fn contains<TS>(&self, origin: TS, a: TS, b: TS) -> bool
+    where
+        TS: Sub<Output = D> + Copy + Ord,
+    {
+        let (earlier, later) = if a <= b { (a, b) } else { (b, a) };
+
+        match *self {
+            JoinWindow::Tumbling(size) => {
+                earlier >= origin && (earlier - origin).spans(size) == (later - origin).spans(size)
+            }
+            JoinWindow::Sliding(size) => later - earlier <= size,
+        }
+    }
+}
+
+/// Difference between two timestamps, used to size a [`JoinWindow`].
+pub trait WindowSpan: Copy + Ord {
+    /// Returns how many whole `size` spans fit in `self`.
+    ///
+    /// A zero `size` gives every distinct span a window of its own.
+    fn spans(self, size: Self) -> u64;
+}
+
+macro_rules! impl_window_span {
+    ($($ty:ty),*) => {
+        $(
+            impl WindowSpan for $ty {
+                fn spans(self, size: Self) -> u64 {
+                    self.checked_div(size).unwrap_or(self) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_window_span!(u32, u64, u128, usize);
+
+impl WindowSpan for Duration {
+    fn spans(self, size: Self) -> u64 {
+        let nanos = self.as_nanos();
+        nanos.checked_div(size.as_nanos()).unwrap_or(nanos) as u64
+    }
+}
+
+/// A result of [`window_by_time_aggregate`](crate::WindowByTimeExt::window_by_time_aggregate).
+///
+/// Window `n` holds the timestamps from `n * size` up to, but excluding, `(n + 1) * size`,
+/// counted from the default (zero) timestamp. A late item that changes a window already
+/// emitted produces a `Retract` of the previous aggregate followed by an `Insert` of the
+/// updated one.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WindowUpdate<A> {
+    /// The aggregate of a window, first emitted once the watermark passes the window.
+    Insert {
+        /// Index of the window
+        window: u64,
+        /// Aggregate of the items in the window
+        aggregate: A,
+    },
+    /// Withdraws an aggregate emitted before for the same window.
+    Retract {
+        /// Index of the window
+        window: u64,
+        /// The aggregate being withdrawn
+        aggregate: A,
+    },
+}
+
+/// Output of [`align_by_timestamp`](crate::AlignByTimestampExt::align_by_timestamp).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Alignment<T> {
+    /// An item of each stream, `(self, other)`, whose timestamps are within the
+    /// tolerance.
+    Pair(T, T),
+    /// An item of `self` that no item of `other` was paired with.
+    Left(T),
+    /// An item of `other` that no item of `self` was paired with.
+    Right(T),
+}
+
+/// First difference between two streams, found by
+/// [`sequence_diff`](crate::SequenceEqualExt::sequence_diff).
+#[derive(Clone, Debug)]
+pub enum Divergence<T> {
+    /// The items at `index` differ: in value, in timestamp by more than the tolerance,
+    /// or in error message, or one is an error and the other is not.
+    Mismatch {
+        /// Position of the items, counting errors
+        index: usize,
+        /// Item of `self`
+        left: StreamItem<T>,
+        /// Item of `other`
+        right: StreamItem<T>,
+    },
+    /// `self` ended after `index` items while `other` went on with `right`.
+    LeftEnded {
+        /// Number of items both streams emitted
+        index: usize,
+        /// The first item of `other` without a counterpart
+        right: StreamItem<T>,
+    },
+    /// `other` ended after `index` items while `self` went on with `left`.
+    RightEnded {
+        /// Number of items both streams emitted
+        index: usize,
+        /// The first item of `self` without a counterpart
+        left: StreamItem<T>,
+    },
+}
+
+impl<T> Divergence<T> {
+    /// Position at which the streams diverge.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Mismatch { index, .. }
+            | Self::LeftEnded { index, .. }
+            | Self::RightEnded { index, .. } => *index,
+        }
+    }
+}
+
+/// Test that [`filter_outliers`](crate::FilterOutliersExt::filter_outliers) applies to each
+/// measurement against the rolling window before it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutlierTest {
+    /// An outlier is more than the given number of standard deviations away from the mean
+    /// of the window.
+    ZScore(f64),
+    /// An outlier is more than the given number of median absolute deviations away from
+    /// the median of the window, which a few earlier outliers barely move.
+    MedianDeviation(f64),
+}
+
+/// Reorder buffer of [`reorder`](crate::ReorderExt::reorder).
+///
+/// `D` is the difference between two timestamps, such as `u64` for sequence numbers or
+/// [`Duration`](core::time::Duration) for instants.
+///
+/// [`Time`](Self::Time) only exists with a runtime feature, so matches outside this
+/// crate need a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReorderWindow<D> {
+    /// Holds up to the given number of items; the oldest is released when it overflows.
+    Items(usize),
+    /// Holds items until the newest timestamp seen is more than the given span ahead.
+    Span(D),
+    /// Holds each item for at most the given time on the runtime's clock, releasing it
+    /// together with the items ordered before it when the time runs out.
+    #[cfg(any(
+        all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+        feature = "runtime-smol",
+        feature = "runtime-async-std",
+        feature = "runtime-embassy",
+        all(feature = "runtime-wasm", target_arch = "wasm32")
+    ))]
+    Time(Duration),
+}
+
+/// How [`resample`](crate::ResampleExt::resample) computes the value at each sample point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResampleStrategy {
+    /// The value of the latest item at or before the sample point.
+    HoldLast,
+    /// The value interpolated linearly between the items around the sample point.
+    Linear,
+    /// The mean of the items from the sample point up to the next one, or the previous
+    /// mean if there are none.
+    Mean,
+}
+
+/// Difference between two timestamps that can be expressed as a fraction of another,
+/// used by [`resample`](crate::ResampleExt::resample) to interpolate.
+pub trait SpanRatio: Copy {
+    /// Returns `self / whole`.
+    fn ratio(self, whole: Self) -> f64;
+}
+
+macro_rules! impl_span_ratio {
+    ($($ty:ty),*) => {
+        $(
+            impl SpanRatio for $ty {
+                fn ratio(self, whole: Self) -> f64 {
+                    self as f64 / whole as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_span_ratio!(u32, u64, u128, usize);
+
+impl SpanRatio for Duration {
+    fn ratio(self, whole: Self) -> f64 {
+        self.as_secs_f64() / whole.as_secs_f64()
+    }
+}
+
+/// Value that [`resample`](crate::ResampleExt::resample) can interpolate and average.
+///
+/// Implemented for the integer types, rounding to the nearest integer. Implement it for
+/// a reading type to resample it field by field.
+pub trait Interpolate: Sized {
+    /// Returns the value `fraction` of the way from `self` to `next`, where `fraction` is
+    /// between 0 and 1.
+    fn lerp(&self, next: &Self, fraction: f64) -> Self;
+
+    /// Returns the mean of `values`, which is never empty.
+    fn mean(values: &[Self]) -> Self;
+}
+
+/// Offsets `value` so that truncating it with `as` rounds to the nearest integer, away
+/// from zero on ties. `f64::round` needs `std`.
+fn to_nearest(value: f64) -> f64 {
+    if value >= 0.0 {
+        value + 0.5
+    } else {
+        value - 0.5
+    }
+}
+
+macro_rules! impl_interpolate {
+    ($($ty:ty),*) => {
+        $(
+            impl Interpolate for $ty {
+                fn lerp(&self, next: &Self, fraction: f64) -> Self {
+                    let (from, to) = (*self as f64, *next as f64);
+                    to_nearest(from + (to - from) * fraction) as $ty
+                }
+
+                fn mean(values: &[Self]) -> Self {
+                    let sum: f64 = values.iter().map(|value| *value as f64).sum();
+                    to_nearest(sum / values.len() as f64) as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
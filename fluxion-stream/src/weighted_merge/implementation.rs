@@ -0,0 +1,147 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_weighted_merge_impl {
+    ($($bounds:tt)*) => {
+        use crate::coop::CoopBudget;
+        use alloc::boxed::Box;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use fluxion_core::{into_stream::IntoStream, Fluxion, StreamItem};
+        use futures::task::{Context, Poll};
+        use futures::Stream;
+
+        type PinnedStream<T> = Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)* 'static>>;
+
+        pub trait WeightedMergeExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Merges `self`, with weight `weight`, and `others`, each paired with its
+            /// weight, taking turns so that each stream gets a share of the output
+            /// proportional to its weight while it has items ready.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a weight is zero.
+            fn weighted_merge<IS>(
+                self,
+                weight: u32,
+                others: Vec<(IS, u32)>,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static;
+        }
+
+        impl<T, S> WeightedMergeExt<T> for S
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+        {
+            fn weighted_merge<IS>(
+                self,
+                weight: u32,
+                others: Vec<(IS, u32)>,
+            ) -> impl Stream<Item = StreamItem<T>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            {
+                let mut sources: Vec<(PinnedStream<T>, u32)> = vec![(Box::pin(self), weight)];
+                for (into_stream, weight) in others {
+                    sources.push((Box::pin(into_stream.into_stream()), weight));
+                }
+
+                WeightedMerge::new(sources)
+            }
+        }
+
+        /// Deficit round robin: the stream whose turn it is emits up to its weight in
+        /// items, and gives up the rest of its turn as soon as it has nothing ready.
+        struct WeightedMerge<T> {
+            streams: Vec<PinnedStream<T>>,
+            weights: Vec<u32>,
+            done: Vec<bool>,
+            // Stream whose turn it is, and how many items it may still emit in the turn
+            current: usize,
+            credit: u32,
+            budget: CoopBudget,
+        }
+
+        impl<T> WeightedMerge<T> {
+            fn new(sources: Vec<(PinnedStream<T>, u32)>) -> Self {
+                let (streams, weights): (Vec<_>, Vec<u32>) = sources.into_iter().unzip();
+                assert!(
+                    weights.iter().all(|weight| *weight >= 1),
+                    "weighted_merge: weights must be at least 1"
+                );
+                let done = vec![false; streams.len()];
+                let credit = weights[0];
+                Self {
+                    streams,
+                    weights,
+                    done,
+                    current: 0,
+                    credit,
+                    budget: CoopBudget::new(),
+                }
+            }
+
+            fn next_turn(&mut self) {
+                self.current = (self.current + 1) % self.streams.len();
+                self.credit = self.weights[self.current];
+            }
+        }
+
+        impl<T> Stream for WeightedMerge<T>
+        where
+            T: Fluxion + Unpin,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            type Item = StreamItem<T>;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                if self.budget.poll_proceed(cx).is_pending() {
+                    return Poll::Pending;
+                }
+
+                let mut any_pending = false;
+
+                for _ in 0..self.streams.len() {
+                    let i = self.current;
+                    if !self.done[i] {
+                        match self.streams[i].as_mut().poll_next(cx) {
+                            Poll::Ready(Some(item)) => {
+                                self.credit -= 1;
+                                if self.credit == 0 {
+                                    self.next_turn();
+                                }
+                                self.budget.consume();
+                                return Poll::Ready(Some(item));
+                            }
+                            Poll::Ready(None) => self.done[i] = true,
+                            Poll::Pending => any_pending = true,
+                        }
+                    }
+                    self.next_turn();
+                }
+
+                if any_pending {
+                    self.budget.reset();
+                    Poll::Pending
+                } else {
+                    Poll::Ready(None)
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,81 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Merge that shares the output between streams by weight.
+//!
+//! [`weighted_merge`](WeightedMergeExt::weighted_merge) merges streams that each carry a
+//! weight. The streams take turns, and in its turn a stream may emit up to its weight in
+//! items, so while all of them have items ready, a stream with weight 3 gets three times
+//! the share of one with weight 1. A chatty source cannot crowd out the others, however
+//! large its backlog.
+//!
+//! # Characteristics
+//!
+//! - **Proportional**: While every stream has items ready, each gets a share of the output
+//!   proportional to its weight
+//! - **Work-conserving**: A stream with nothing ready gives up the rest of its turn, so
+//!   the merge never waits while another stream has an item
+//! - **Per-source order**: The items of each stream keep their order; items of different
+//!   streams are not ordered by timestamp
+//! - **Unbuffered**: Items are passed on as they are read
+//! - **Errors count**: An error takes a share of its stream's turn like a value
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::WeightedMergeExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # async fn example() {
+//! let (chatty_tx, chatty) = test_channel::<Sequenced<&str>>();
+//! let (quiet_tx, quiet) = test_channel::<Sequenced<&str>>();
+//!
+//! let mut merged = chatty.weighted_merge(2, vec![(quiet, 1)]);
+//!
+//! for n in 0..6 {
+//!     chatty_tx.unbounded_send(("chatty", n).into()).unwrap();
+//! }
+//! quiet_tx.unbounded_send(("quiet", 10).into()).unwrap();
+//!
+//! // Two items of the chatty stream, then it is the quiet stream's turn
+//! for expected in ["chatty", "chatty", "quiet", "chatty"] {
+//!     let item = unwrap_value(Some(unwrap_stream(&mut merged, 500).await));
+//!     assert_eq!(item.value, expected);
+//! }
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::WeightedMergeExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::WeightedMergeExt;
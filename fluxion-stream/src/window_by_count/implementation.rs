@@ -5,11 +5,9 @@
 macro_rules! define_window_by_count_impl {
     ($($bounds:tt)*) => {
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec::Vec;
         use core::fmt::Debug;
-        use core::mem::take;
-        use fluxion_core::fluxion_mutex::Mutex;
+        use fluxion_core::sync::{Arc, Mutex};
         use fluxion_core::{Fluxion, StreamItem};
         use futures::{future::ready, Stream, StreamExt};
 
@@ -24,6 +22,27 @@ macro_rules! define_window_by_count_impl {
                 Out: Fluxion<Inner = Vec<T::Inner>>,
                 Out::Inner: Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
                 Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + 'static + $($bounds)*;
+
+            /// Folds every `n` items into an aggregate started by `init`, emitting only the
+            /// aggregate instead of the items.
+            fn window_by_count_aggregate<Out, A>(
+                self,
+                n: usize,
+                init: impl Fn() -> A + 'static + $($bounds)*,
+                accumulate: impl Fn(&mut A, T::Inner) + 'static + $($bounds)*,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                A: 'static + $($bounds)*,
+                Out: Fluxion<Inner = A>,
+                Out::Inner: Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + 'static + $($bounds)*;
+        }
+
+        /// The aggregate of the current window, its item count and latest timestamp.
+        struct CountWindow<A, TS> {
+            aggregate: Option<A>,
+            count: usize,
+            last_ts: Option<TS>,
         }
 
         impl<S, T> WindowByCountExt<T> for S
@@ -38,43 +57,61 @@ macro_rules! define_window_by_count_impl {
                 Out: Fluxion<Inner = Vec<T::Inner>>,
                 Out::Inner: Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
                 Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + 'static + $($bounds)*,
+            {
+                self.window_by_count_aggregate::<Out, _>(
+                    n,
+                    move || Vec::with_capacity(n),
+                    |window, value| window.push(value),
+                )
+            }
+
+            fn window_by_count_aggregate<Out, A>(
+                self,
+                n: usize,
+                init: impl Fn() -> A + 'static + $($bounds)*,
+                accumulate: impl Fn(&mut A, T::Inner) + 'static + $($bounds)*,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                A: 'static + $($bounds)*,
+                Out: Fluxion<Inner = A>,
+                Out::Inner: Clone + Debug + Ord + Unpin + 'static + $($bounds)*,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + 'static + $($bounds)*,
             {
                 assert!(n >= 1, "window_by_count: window size must be at least 1");
 
-                let state = Arc::new(Mutex::new((Vec::with_capacity(n), None::<T::Timestamp>)));
+                let state = Arc::new(Mutex::new(CountWindow {
+                    aggregate: None,
+                    count: 0,
+                    last_ts: None::<T::Timestamp>,
+                }));
 
                 let window_size = n;
                 let state_clone = Arc::clone(&state);
 
                 let main_stream = self.filter_map(move |item| {
-                    let state = Arc::clone(&state_clone);
-                    let window_size = window_size;
+                    let mut guard = state_clone.lock();
+                    let window = &mut *guard;
 
                     ready(match item {
                         StreamItem::Value(value) => {
                             let timestamp = value.timestamp();
-                            let inner = value.into_inner();
+                            accumulate(window.aggregate.get_or_insert_with(&init), value.into_inner());
+                            window.count += 1;
+                            window.last_ts = Some(timestamp);
 
-                            let mut guard = state.lock();
-                            let (buffer, last_ts) = &mut *guard;
-
-                            buffer.push(inner);
-                            *last_ts = Some(timestamp);
-
-                            if buffer.len() >= window_size {
-                                let window = take(buffer);
-                                *buffer = Vec::with_capacity(window_size);
-                                let ts = last_ts.take().expect("timestamp must exist");
-                                Some(StreamItem::Value(Out::with_timestamp(window, ts.into())))
+                            if window.count >= window_size {
+                                window.count = 0;
+                                let aggregate = window.aggregate.take().expect("aggregate must exist");
+                                let ts = window.last_ts.take().expect("timestamp must exist");
+                                Some(StreamItem::Value(Out::with_timestamp(aggregate, ts.into())))
                             } else {
                                 None
                             }
                         }
                         StreamItem::Error(e) => {
-                            let mut guard = state.lock();
-                            let (buffer, last_ts) = &mut *guard;
-                            buffer.clear();
-                            *last_ts = None;
+                            window.aggregate = None;
+                            window.count = 0;
+                            window.last_ts = None;
                             Some(StreamItem::Error(e))
                         }
                     })
@@ -83,17 +120,15 @@ macro_rules! define_window_by_count_impl {
                 let final_state = state;
                 let flush_stream = futures::stream::once(async move {
                     let mut guard = final_state.lock();
-                    let (buffer, last_ts) = &mut *guard;
+                    let window = &mut *guard;
 
-                    if !buffer.is_empty() {
-                        let window = take(buffer);
-                        let ts = last_ts
+                    window.aggregate.take().map(|aggregate| {
+                        let ts = window
+                            .last_ts
                             .take()
                             .expect("timestamp must exist for partial window");
-                        Some(StreamItem::Value(Out::with_timestamp(window, ts.into())))
-                    } else {
-                        None
-                    }
+                        StreamItem::Value(Out::with_timestamp(aggregate, ts.into()))
+                    })
                 })
                 .filter_map(ready);
 
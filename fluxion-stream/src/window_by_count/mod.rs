@@ -44,6 +44,38 @@
 //! # }
 //! ```
 //!
+//! # Incremental Aggregation
+//!
+//! [`window_by_count_aggregate`](WindowByCountExt::window_by_count_aggregate) folds each
+//! item into a running aggregate as it arrives and emits the aggregate, so a window holds
+//! one value instead of `n` items:
+//!
+//! ```
+//! use fluxion_stream::prelude::*;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, rx) = async_channel::unbounded();
+//! let stream = rx.into_fluxion_stream();
+//!
+//! let mut sums = stream.window_by_count_aggregate::<Sequenced<i32>, _>(
+//!     3,
+//!     || 0,
+//!     |sum, value: i32| *sum += value,
+//! );
+//!
+//! for value in 1..=4 {
+//!     tx.try_send(Sequenced::new(value)).unwrap();
+//! }
+//! drop(tx);
+//!
+//! assert_eq!(sums.next().await.unwrap().unwrap().into_inner(), 6);
+//! assert_eq!(sums.next().await.unwrap().unwrap().into_inner(), 4);
+//! # }
+//! ```
+//!
 //! # Use Cases
 //!
 //! - **Batch processing**: Process items in groups for efficiency
@@ -0,0 +1,200 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_window_by_time_impl {
+    ($($bounds:tt)*) => {
+        use crate::event_timers::EventTimers;
+        use crate::types::{WindowSpan, WindowUpdate};
+        use alloc::boxed::Box;
+        use alloc::collections::btree_map::Entry;
+        use alloc::collections::BTreeMap;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::Sub;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        /// Aggregate of a window and whether it has been emitted.
+        struct TimeWindow<A> {
+            aggregate: A,
+            emitted: bool,
+        }
+
+        /// Windows still accepting items, by index, and the watermark. Each window has
+        /// a timer, in window indices, firing once the watermark has passed it.
+        struct TimeWindowState<A, TS> {
+            windows: BTreeMap<u64, TimeWindow<A>>,
+            timers: EventTimers<u64, u64>,
+            watermark: Option<TS>,
+        }
+
+        pub trait WindowByTimeExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Folds items into consecutive windows of `size` on their timestamps and
+            /// emits each aggregate once the watermark passes its window.
+            ///
+            /// Windows are aligned to the default (zero) timestamp: window `n` holds the
+            /// timestamps from `n * size` up to, but excluding, `(n + 1) * size`.
+            ///
+            /// Items up to `allowed_lateness` behind the watermark still update their
+            /// window; if it was already emitted, the previous aggregate is retracted
+            /// and the updated one inserted.
+            fn window_by_time_aggregate<Out, A, D>(
+                self,
+                size: D,
+                allowed_lateness: D,
+                init: impl Fn() -> A + $($bounds)* 'static,
+                accumulate: impl Fn(&mut A, T::Inner) + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                A: Clone + $($bounds)* 'static,
+                D: WindowSpan + $($bounds)* 'static,
+                T::Timestamp: Default + Sub<Output = D> + Sub<D, Output = T::Timestamp>,
+                Out: Fluxion<Inner = WindowUpdate<A>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> WindowByTimeExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn window_by_time_aggregate<Out, A, D>(
+                self,
+                size: D,
+                allowed_lateness: D,
+                init: impl Fn() -> A + $($bounds)* 'static,
+                accumulate: impl Fn(&mut A, T::Inner) + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                A: Clone + $($bounds)* 'static,
+                D: WindowSpan + $($bounds)* 'static,
+                T::Timestamp: Default + Sub<Output = D> + Sub<D, Output = T::Timestamp>,
+                Out: Fluxion<Inner = WindowUpdate<A>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let state = Arc::new(Mutex::new(TimeWindowState {
+                    windows: BTreeMap::new(),
+                    timers: EventTimers::new(),
+                    watermark: None::<T::Timestamp>,
+                }));
+                let state_clone = Arc::clone(&state);
+
+                let insert = |window: u64, aggregate: A, timestamp: T::Timestamp| {
+                    StreamItem::Value(Out::with_timestamp(
+                        WindowUpdate::Insert { window, aggregate },
+                        timestamp.into(),
+                    ))
+                };
+
+                let main_stream = self.flat_map(move |item| {
+                    let mut guard = state_clone.lock();
+                    let state = &mut *guard;
+
+                    let updates: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let zero = T::Timestamp::default();
+                            let index_of = |at: T::Timestamp| (at - zero).spans(size);
+
+                            // The oldest window still accepting items, from the watermark
+                            // before this item
+                            let first_open = state.watermark.map_or(0, |watermark| {
+                                if watermark - zero > allowed_lateness {
+                                    index_of(watermark - allowed_lateness)
+                                } else {
+                                    0
+                                }
+                            });
+
+                            if index_of(timestamp) < first_open {
+                                Vec::new()
+                            } else {
+                                let watermark = state.watermark.map_or(timestamp, |w| w.max(timestamp));
+                                state.watermark = Some(watermark);
+                                let mut updates = Vec::new();
+
+                                let window = match state.windows.entry(index_of(timestamp)) {
+                                    Entry::Occupied(entry) => entry.into_mut(),
+                                    Entry::Vacant(entry) => {
+                                        state.timers.register(*entry.key() + 1, *entry.key());
+                                        entry.insert(TimeWindow {
+                                            aggregate: init(),
+                                            emitted: false,
+                                        })
+                                    }
+                                };
+                                let retracted = window.emitted.then(|| window.aggregate.clone());
+                                accumulate(&mut window.aggregate, value.into_inner());
+                                if let Some(aggregate) = retracted {
+                                    updates.push(StreamItem::Value(Out::with_timestamp(
+                                        WindowUpdate::Retract {
+                                            window: index_of(timestamp),
+                                            aggregate,
+                                        },
+                                        watermark.into(),
+                                    )));
+                                    updates.push(insert(
+                                        index_of(timestamp),
+                                        window.aggregate.clone(),
+                                        watermark,
+                                    ));
+                                }
+
+                                // Emit the windows the watermark has passed
+                                for (_, index) in state.timers.advance_watermark(index_of(watermark)) {
+                                    if let Some(window) = state.windows.get_mut(&index) {
+                                        window.emitted = true;
+                                        updates.push(insert(index, window.aggregate.clone(), watermark));
+                                    }
+                                }
+
+                                // Drop the windows no longer accepting items
+                                if watermark - zero > allowed_lateness {
+                                    let first_open = index_of(watermark - allowed_lateness);
+                                    state.windows = state.windows.split_off(&first_open);
+                                }
+
+                                updates
+                            }
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(updates)
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let mut guard = state.lock();
+                    let state = &mut *guard;
+                    let windows = core::mem::take(&mut state.windows);
+
+                    state
+                        .watermark
+                        .map(|watermark| {
+                            windows
+                                .into_iter()
+                                .filter(|(_, window)| !window.emitted)
+                                .map(|(index, window)| insert(index, window.aggregate, watermark))
+                                .collect::<Vec<_>>()
+                        })
+                        .unwrap_or_default()
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(main_stream.chain(flush_stream))
+            }
+        }
+    };
+}
@@ -0,0 +1,97 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Event-time tumbling windows with allowed lateness and retractions.
+//!
+//! [`window_by_time_aggregate`](WindowByTimeExt::window_by_time_aggregate) assigns every
+//! item to a window of `size` by its timestamp, folds it into that window's aggregate
+//! and emits the aggregate once the window is complete. Items may arrive out of
+//! timestamp order: a late item still updates its window for `allowed_lateness`, and if
+//! the window was already emitted, its result is corrected with a
+//! [`WindowUpdate::Retract`](crate::WindowUpdate::Retract) of the previous aggregate
+//! followed by a [`WindowUpdate::Insert`](crate::WindowUpdate::Insert) of the new one.
+//!
+//! # Behavior
+//!
+//! - Windows are aligned to the default (zero) timestamp: window `n` holds the
+//!   timestamps from `n * size` up to, but excluding, `(n + 1) * size`, whichever item
+//!   arrives first
+//! - The watermark is the highest timestamp seen so far; a window is emitted when an
+//!   item at or past its end raises the watermark beyond it
+//! - A window accepts items until the watermark is more than `allowed_lateness` past its
+//!   end; later items are dropped. Items older than the first one are no exception: they
+//!   update their window while it is within `allowed_lateness`
+//! - With an `allowed_lateness` of zero, every late item is dropped and nothing is
+//!   retracted
+//! - All results carry the watermark as their timestamp, so they stay in order
+//! - When the source ends, windows not emitted yet are emitted in order
+//! - Errors are propagated without discarding any window
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::{WindowByTimeExt, WindowUpdate};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, readings) = test_channel::<Sequenced<u32>>();
+//!
+//! // Sum readings in windows of 10 ticks, accepting items up to 10 ticks late
+//! let mut sums = readings.window_by_time_aggregate::<Sequenced<_>, _, _>(
+//!     10u64,
+//!     10u64,
+//!     || 0,
+//!     |sum, reading| *sum += reading,
+//! );
+//!
+//! tx.unbounded_send((1, 0).into()).unwrap();
+//! tx.unbounded_send((2, 5).into()).unwrap();
+//! tx.unbounded_send((4, 12).into()).unwrap(); // completes window 0
+//!
+//! let first = unwrap_value(Some(unwrap_stream(&mut sums, 500).await));
+//! assert_eq!(first.value, WindowUpdate::Insert { window: 0, aggregate: 3 });
+//!
+//! tx.unbounded_send((10, 7).into()).unwrap(); // late, corrects window 0
+//!
+//! let retracted = unwrap_value(Some(unwrap_stream(&mut sums, 500).await));
+//! assert_eq!(retracted.value, WindowUpdate::Retract { window: 0, aggregate: 3 });
+//! let corrected = unwrap_value(Some(unwrap_stream(&mut sums, 500).await));
+//! assert_eq!(corrected.value, WindowUpdate::Insert { window: 0, aggregate: 13 });
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::WindowByTimeExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::WindowByTimeExt;
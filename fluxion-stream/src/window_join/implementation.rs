@@ -0,0 +1,140 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_window_join_impl {
+    ($($bounds:tt)*) => {
+        use crate::ordered_merge::ordered_merge_with_index;
+        use crate::types::{JoinWindow, WindowSpan};
+        use alloc::boxed::Box;
+        use alloc::collections::VecDeque;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::ops::Sub;
+        use core::pin::Pin;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{Stream, StreamExt};
+
+        const LEFT: usize = 0;
+
+        /// Items of both sides still inside the window of the latest timestamp, in arrival
+        /// order.
+        struct JoinState<TS, K, I> {
+            origin: Option<TS>,
+            latest: Option<TS>,
+            sides: [VecDeque<(TS, K, I)>; 2],
+        }
+
+        pub trait WindowJoinExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Pairs every item with each earlier item of the other stream that has the
+            /// same key and falls in the same `window`, emitting `(self, other)` pairs.
+            fn window_join<Out, IS, K, D>(
+                self,
+                other: IS,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+                window: JoinWindow<D>,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                K: PartialEq + $($bounds)* 'static,
+                D: WindowSpan + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Out: Fluxion<Inner = (T::Inner, T::Inner)>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> WindowJoinExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn window_join<Out, IS, K, D>(
+                self,
+                other: IS,
+                key: impl Fn(&T::Inner) -> K + $($bounds)* 'static,
+                window: JoinWindow<D>,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                K: PartialEq + $($bounds)* 'static,
+                D: WindowSpan + $($bounds)* 'static,
+                T::Timestamp: Sub<Output = D>,
+                Out: Fluxion<Inner = (T::Inner, T::Inner)>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> =
+                    vec![Box::pin(self), Box::pin(other.into_stream())];
+
+                let state = Arc::new(Mutex::new(JoinState::<T::Timestamp, K, T::Inner> {
+                    origin: None,
+                    latest: None,
+                    sides: [VecDeque::new(), VecDeque::new()],
+                }));
+
+                let joined = ordered_merge_with_index(streams).flat_map(move |(item, index)| {
+                    let mut guard = state.lock();
+                    let state = &mut *guard;
+
+                    let pairs: Vec<StreamItem<Out>> = match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            let origin = *state.origin.get_or_insert(timestamp);
+                            let latest = state
+                                .latest
+                                .map_or(timestamp, |latest| latest.max(timestamp));
+                            state.latest = Some(latest);
+
+                            // Items of the other stream may arrive with older timestamps, so
+                            // only what left the window of the latest timestamp is evicted
+                            for side in &mut state.sides {
+                                side.retain(|(earlier, _, _)| {
+                                    window.contains(origin, *earlier, latest)
+                                });
+                            }
+
+                            let inner = value.into_inner();
+                            let item_key = key(&inner);
+                            let pairs = state.sides[1 - index]
+                                .iter()
+                                .filter(|(other_timestamp, other_key, _)| {
+                                    *other_key == item_key
+                                        && window.contains(origin, *other_timestamp, timestamp)
+                                })
+                                .map(|(_, _, other)| {
+                                    let pair = if index == LEFT {
+                                        (inner.clone(), other.clone())
+                                    } else {
+                                        (other.clone(), inner.clone())
+                                    };
+                                    StreamItem::Value(Out::with_timestamp(pair, timestamp.into()))
+                                })
+                                .collect();
+
+                            state.sides[index].push_back((timestamp, item_key, inner));
+                            pairs
+                        }
+                        StreamItem::Error(e) => vec![StreamItem::Error(e)],
+                    };
+
+                    futures::stream::iter(pairs)
+                });
+
+                Box::pin(joined)
+            }
+        }
+    };
+}
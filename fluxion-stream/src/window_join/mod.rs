@@ -0,0 +1,89 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Keyed join of two streams within a time window.
+//!
+//! [`window_join`](WindowJoinExt::window_join) merges the two streams and keeps the items
+//! of each side that are still inside the [`JoinWindow`](crate::JoinWindow) of the latest
+//! timestamp seen. When an item arrives, it is paired with every kept item of the other
+//! side that has the same key and shares its window, such as an order with the payments
+//! for the same order id.
+//!
+//! # Behavior
+//!
+//! - Each pair is emitted as `(item of self, item of other)`, with the timestamp of
+//!   whichever of the two arrived last
+//! - Pairs for one arriving item follow the arrival order of the items it matches
+//! - Items are ordered only when both streams have one ready, so an item may arrive with
+//!   an older timestamp than the other stream's latest item; it is still paired with the
+//!   kept items in its window
+//! - [`JoinWindow::Tumbling`](crate::JoinWindow::Tumbling) windows are aligned to the
+//!   first timestamp seen on either stream; older items are never paired
+//! - [`JoinWindow::Sliding`](crate::JoinWindow::Sliding) pairs items at most the window
+//!   size apart
+//! - Memory grows with the number of items in one window
+//! - Errors from either stream are propagated without discarding the kept items
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::HasTimestamp;
+//! use fluxion_stream::{JoinWindow, WindowJoinExt};
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! // (order id, amount)
+//! let (tx_orders, orders) = test_channel::<Sequenced<(u32, u32)>>();
+//! let (tx_payments, payments) = test_channel::<Sequenced<(u32, u32)>>();
+//!
+//! let mut matched = orders.window_join::<Sequenced<_>, _, _, _>(
+//!     payments,
+//!     |(order_id, _)| *order_id,
+//!     JoinWindow::Sliding(10u64),
+//! );
+//!
+//! tx_orders.unbounded_send(((1, 250), 1).into()).unwrap();
+//! tx_payments.unbounded_send(((2, 100), 4).into()).unwrap(); // no order 2 yet
+//! tx_payments.unbounded_send(((1, 250), 8).into()).unwrap();
+//!
+//! let pair = unwrap_value(Some(unwrap_stream(&mut matched, 500).await));
+//! assert_eq!(pair.value, ((1, 250), (1, 250)));
+//! assert_eq!(pair.timestamp(), 8);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::WindowJoinExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::WindowJoinExt;
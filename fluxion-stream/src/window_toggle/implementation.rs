@@ -0,0 +1,208 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_window_toggle_impl {
+    ($($bounds:tt)*) => {
+        use crate::ordered_merge::ordered_merge_with_index;
+        use alloc::boxed::Box;
+        use alloc::collections::VecDeque;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::fmt::Debug;
+        use core::pin::Pin;
+        use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::sync::{Arc, Mutex};
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{future::ready, Stream, StreamExt};
+
+        const SOURCE: usize = 0;
+        const OPEN: usize = 1;
+        const CLOSE: usize = 2;
+
+        /// Aggregates of the stretches between consecutive openings, oldest first, and
+        /// the timestamp of the latest event.
+        ///
+        /// Every open window started at one of the panes and covers it and all later
+        /// ones, so each source value is folded into the newest pane only.
+        struct WindowState<A, TS> {
+            panes: VecDeque<A>,
+            last_timestamp: Option<TS>,
+        }
+
+        impl<A: Clone, TS> WindowState<A, TS> {
+            /// Removes the oldest window and merges its panes.
+            fn close_oldest(&mut self, merge: &impl Fn(&mut A, A)) -> Option<A> {
+                let mut window = self.panes.pop_front()?;
+                for pane in &self.panes {
+                    merge(&mut window, pane.clone());
+                }
+                Some(window)
+            }
+        }
+
+        pub trait WindowToggleExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            /// Collects source values into windows opened by `openings` and closed by
+            /// `closings`, emitting each window when it closes.
+            ///
+            /// Every opening starts a new window, so windows may overlap; every closing
+            /// ends the oldest window still open.
+            fn window_toggle<Out, IO, IC>(
+                self,
+                openings: IO,
+                closings: IC,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IO: IntoStream<Item = StreamItem<T>>,
+                IO::Stream: $($bounds)* 'static,
+                IC: IntoStream<Item = StreamItem<T>>,
+                IC::Stream: $($bounds)* 'static,
+                Out: Fluxion<Inner = Vec<T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+
+            /// Aggregates the windows of [`window_toggle`](Self::window_toggle) instead of
+            /// collecting their values.
+            ///
+            /// `accumulate` folds a value into an aggregate started by `init`; `merge`
+            /// appends a later aggregate to an earlier one. Overlapping windows share
+            /// their common part, so each value is folded once whatever the number of
+            /// open windows.
+            fn window_toggle_aggregate<Out, A, IO, IC>(
+                self,
+                openings: IO,
+                closings: IC,
+                init: impl Fn() -> A + $($bounds)* 'static,
+                accumulate: impl Fn(&mut A, T::Inner) + $($bounds)* 'static,
+                merge: impl Fn(&mut A, A) + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IO: IntoStream<Item = StreamItem<T>>,
+                IO::Stream: $($bounds)* 'static,
+                IC: IntoStream<Item = StreamItem<T>>,
+                IC::Stream: $($bounds)* 'static,
+                A: $($bounds)* 'static,
+                Out: Fluxion<Inner = A>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+        }
+
+        impl<T, S> WindowToggleExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn window_toggle<Out, IO, IC>(
+                self,
+                openings: IO,
+                closings: IC,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IO: IntoStream<Item = StreamItem<T>>,
+                IO::Stream: $($bounds)* 'static,
+                IC: IntoStream<Item = StreamItem<T>>,
+                IC::Stream: $($bounds)* 'static,
+                Out: Fluxion<Inner = Vec<T::Inner>>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                self.window_toggle_aggregate::<Out, _, _, _>(
+                    openings,
+                    closings,
+                    Vec::new,
+                    |window, value| window.push(value),
+                    |window, later| window.extend(later),
+                )
+            }
+
+            fn window_toggle_aggregate<Out, A, IO, IC>(
+                self,
+                openings: IO,
+                closings: IC,
+                init: impl Fn() -> A + $($bounds)* 'static,
+                accumulate: impl Fn(&mut A, T::Inner) + $($bounds)* 'static,
+                merge: impl Fn(&mut A, A) + $($bounds)* 'static,
+            ) -> impl Stream<Item = StreamItem<Out>> + $($bounds)*
+            where
+                IO: IntoStream<Item = StreamItem<T>>,
+                IO::Stream: $($bounds)* 'static,
+                IC: IntoStream<Item = StreamItem<T>>,
+                IC::Stream: $($bounds)* 'static,
+                A: $($bounds)* 'static,
+                Out: Fluxion<Inner = A>,
+                Out::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                Out::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                let streams: Vec<Pin<Box<dyn Stream<Item = StreamItem<T>> + $($bounds)*>>> = vec![
+                    Box::pin(self),
+                    Box::pin(openings.into_stream()),
+                    Box::pin(closings.into_stream()),
+                ];
+
+                let state = Arc::new(Mutex::new(WindowState {
+                    panes: VecDeque::new(),
+                    last_timestamp: None::<T::Timestamp>,
+                }));
+                let state_clone = Arc::clone(&state);
+                let merge = Arc::new(merge);
+                let flush_merge = Arc::clone(&merge);
+
+                let main_stream = ordered_merge_with_index(streams).filter_map(move |(item, index)| {
+                    let mut guard = state_clone.lock();
+                    let state = &mut *guard;
+
+                    ready(match item {
+                        StreamItem::Value(value) => {
+                            let timestamp = value.timestamp();
+                            state.last_timestamp = Some(timestamp);
+
+                            match index {
+                                SOURCE => {
+                                    if let Some(pane) = state.panes.back_mut() {
+                                        accumulate(pane, value.into_inner());
+                                    }
+                                    None
+                                }
+                                OPEN => {
+                                    state.panes.push_back(init());
+                                    None
+                                }
+                                CLOSE => state.close_oldest(&*merge).map(|window| {
+                                    StreamItem::Value(Out::with_timestamp(window, timestamp.into()))
+                                }),
+                                _ => None,
+                            }
+                        }
+                        StreamItem::Error(e) => {
+                            state.panes.clear();
+                            Some(StreamItem::Error(e))
+                        }
+                    })
+                });
+
+                let flush_stream = futures::stream::once(async move {
+                    let mut guard = state.lock();
+                    let state = &mut *guard;
+                    let mut windows = Vec::new();
+
+                    if let Some(timestamp) = state.last_timestamp {
+                        while let Some(window) = state.close_oldest(&*flush_merge) {
+                            windows.push(StreamItem::Value(Out::with_timestamp(window, timestamp.into())));
+                        }
+                    }
+                    windows
+                })
+                .flat_map(futures::stream::iter);
+
+                Box::pin(main_stream.chain(flush_stream))
+            }
+        }
+    };
+}
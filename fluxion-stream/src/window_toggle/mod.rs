@@ -0,0 +1,94 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Windowing operator controlled by an opening and a closing trigger stream.
+//!
+//! [`window_toggle`](WindowToggleExt::window_toggle) merges the source with the two
+//! trigger streams in timestamp order. Each item of `openings` opens a new window, each
+//! item of `closings` closes the oldest window still open and emits the source values it
+//! collected as one `Vec`.
+//!
+//! # Behavior
+//!
+//! - Windows may overlap: a source value is added to every window open at the time
+//! - The n-th closing closes the window of the n-th opening still open; closings while
+//!   no window is open are ignored
+//! - A window is emitted when it closes, with the timestamp of the closing item, even
+//!   if it collected nothing
+//! - Source values while no window is open are dropped
+//! - When all three streams have ended, windows still open are emitted, oldest first,
+//!   with the timestamp of the latest item seen
+//! - An error from any stream discards all open windows and is propagated
+//!
+//! # Incremental Aggregation
+//!
+//! [`window_toggle_aggregate`](WindowToggleExt::window_toggle_aggregate) takes `init`,
+//! `accumulate` and `merge` functions and emits an aggregate per window instead of a
+//! `Vec`. The source values between two consecutive openings are folded into one
+//! partial aggregate, shared by every window open at the time; closing a window merges
+//! the partial aggregates it spans. Memory is one aggregate per open window, and each
+//! value is folded once however many windows overlap.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_stream::WindowToggleExt;
+//! use fluxion_test_utils::{
+//!     helpers::{test_channel, unwrap_stream, unwrap_value},
+//!     sequenced::Sequenced,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx_source, source) = test_channel::<Sequenced<u32>>();
+//! let (tx_open, openings) = test_channel::<Sequenced<u32>>();
+//! let (tx_close, closings) = test_channel::<Sequenced<u32>>();
+//!
+//! let mut windows = source.window_toggle::<Sequenced<Vec<u32>>, _, _>(openings, closings);
+//!
+//! tx_source.unbounded_send((1, 1).into()).unwrap(); // dropped, no window open
+//! tx_open.unbounded_send((0, 2).into()).unwrap();
+//! tx_source.unbounded_send((3, 3).into()).unwrap();
+//! tx_open.unbounded_send((0, 4).into()).unwrap();
+//! tx_source.unbounded_send((5, 5).into()).unwrap();
+//! tx_close.unbounded_send((0, 6).into()).unwrap();
+//! tx_close.unbounded_send((0, 7).into()).unwrap();
+//!
+//! let first = unwrap_value(Some(unwrap_stream(&mut windows, 500).await));
+//! assert_eq!(first.value, vec![3, 5]);
+//! let second = unwrap_value(Some(unwrap_stream(&mut windows, 500).await));
+//! assert_eq!(second.value, vec![5]);
+//! # }
+//! ```
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::WindowToggleExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::WindowToggleExt;
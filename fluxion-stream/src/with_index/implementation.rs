@@ -0,0 +1,43 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+macro_rules! define_with_index_impl {
+    ($($bounds:tt)*) => {
+        use crate::types::Indexed;
+        use alloc::boxed::Box;
+        use core::fmt::Debug;
+        use fluxion_core::{Fluxion, StreamItem};
+        use futures::{future::ready, Stream, StreamExt};
+
+        pub trait WithIndexExt<T>: Stream<Item = StreamItem<T>> + Sized
+        where
+            T: Fluxion,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn with_index(self) -> impl Stream<Item = StreamItem<Indexed<T>>> + $($bounds)*;
+        }
+
+        impl<T, S> WithIndexExt<T> for S
+        where
+            S: Stream<Item = StreamItem<T>> + Sized + $($bounds)* 'static,
+            T: Fluxion,
+            T::Inner: Debug + Ord + Unpin + $($bounds)* 'static,
+            T::Timestamp: Debug + Ord + Copy + $($bounds)* 'static,
+        {
+            fn with_index(self) -> impl Stream<Item = StreamItem<Indexed<T>>> + $($bounds)* {
+                Box::pin(self.scan(0u64, |next_index, item: StreamItem<T>| {
+                    ready(Some(match item {
+                        StreamItem::Value(value) => {
+                            let index = *next_index;
+                            *next_index += 1;
+                            StreamItem::Value(Indexed::new(index, value))
+                        }
+                        StreamItem::Error(e) => StreamItem::Error(e),
+                    }))
+                }))
+            }
+        }
+    };
+}
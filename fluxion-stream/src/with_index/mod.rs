@@ -0,0 +1,88 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Enumeration operator that pairs each item with its ordinal position.
+//!
+//! The [`with_index`](WithIndexExt::with_index) operator emits [`Indexed`](crate::Indexed)
+//! items, containing the zero-based index of the value and the value itself.
+//!
+//! # Behavior
+//!
+//! - The first value gets index `0`, each following value the next index
+//! - Indices count values since subscription, not since the start of the source
+//! - The timestamp of each item is the timestamp of the wrapped value
+//! - Preserves temporal ordering from the source stream
+//!
+//! # Errors
+//!
+//! Errors are passed through unchanged and do not consume an index, so the indices of
+//! the values stay contiguous.
+//!
+//! # See Also
+//!
+//! - [`combine_with_previous`](crate::CombineWithPreviousExt::combine_with_previous) - Pairs each value with its predecessor
+//! - [`scan_ordered`](crate::ScanOrderedExt::scan_ordered) - General stateful accumulation
+//!
+//! # Examples
+//!
+//! ```rust
+//! use fluxion_stream::WithIndexExt;
+//! use fluxion_test_utils::{
+//!     sequenced::Sequenced,
+//!     helpers::{unwrap_stream, unwrap_value, test_channel}
+//! };
+//! use fluxion_core::HasTimestamp;
+//!
+//! # async fn example() {
+//! let (tx, stream) = test_channel::<Sequenced<&str>>();
+//! let mut indexed = stream.with_index();
+//!
+//! tx.unbounded_send(("a", 10).into()).unwrap();
+//! tx.unbounded_send(("b", 20).into()).unwrap();
+//!
+//! let first = unwrap_value(Some(unwrap_stream(&mut indexed, 500).await));
+//! assert_eq!((first.index, first.value.value), (0, "a"));
+//! assert_eq!(first.timestamp(), 10);
+//!
+//! let second = unwrap_value(Some(unwrap_stream(&mut indexed, 500).await));
+//! assert_eq!((second.index, second.value.value), (1, "b"));
+//! # }
+//! ```
+//!
+//! # Use Cases
+//!
+//! - Sequence-gap detection (comparing indices against expected counts)
+//! - Debugging (labelling items by position)
+//! - Alternating or position-based processing
+
+#[macro_use]
+mod implementation;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+mod multi_threaded;
+
+#[cfg(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+))]
+pub use multi_threaded::WithIndexExt;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+mod single_threaded;
+
+#[cfg(not(any(
+    all(feature = "runtime-tokio", not(target_arch = "wasm32")),
+    feature = "runtime-smol",
+    feature = "runtime-async-std"
+)))]
+pub use single_threaded::WithIndexExt;
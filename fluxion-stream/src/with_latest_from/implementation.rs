@@ -39,16 +39,17 @@ impl<T: Clone> IntermediateState<T> {
 macro_rules! define_with_latest_from_impl {
     ($($bounds:tt)*) => {
         use super::implementation::IntermediateState;
-        use crate::ordered_merge::ordered_merge_with_index;
+        use crate::ordered_merge::ordered_merge_with_index_and_mode;
+        use crate::ordering::OrderingMode;
         use crate::types::CombinedState;
         use alloc::boxed::Box;
-        use alloc::sync::Arc;
         use alloc::vec;
         use alloc::vec::Vec;
         use core::fmt::Debug;
         use core::pin::Pin;
-        use fluxion_core::fluxion_mutex::Mutex;
         use fluxion_core::into_stream::IntoStream;
+        use fluxion_core::lock_state;
+        use fluxion_core::sync::{Arc, StateMutex};
         use fluxion_core::{Fluxion, StreamItem};
         use futures::{Stream, StreamExt};
 
@@ -71,6 +72,21 @@ macro_rules! define_with_latest_from_impl {
                 R: Fluxion,
                 R::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
                 R::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
+
+            /// Combines the streams like [`with_latest_from`](Self::with_latest_from),
+            /// merging them in the given [`OrderingMode`].
+            fn with_latest_from_with_mode<IS, R>(
+                self,
+                other: IS,
+                result_selector: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> R + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<R>>
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                R: Fluxion,
+                R::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                R::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static;
         }
 
         impl<T, S> WithLatestFromExt<T> for S
@@ -85,6 +101,22 @@ macro_rules! define_with_latest_from_impl {
                 other: IS,
                 result_selector: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> R + $($bounds)* 'static,
             ) -> impl Stream<Item = StreamItem<R>>
+            where
+                IS: IntoStream<Item = StreamItem<T>>,
+                IS::Stream: $($bounds)* 'static,
+                R: Fluxion,
+                R::Inner: Clone + Debug + Ord + Unpin + $($bounds)* 'static,
+                R::Timestamp: From<T::Timestamp> + Debug + Ord + Copy + $($bounds)* 'static,
+            {
+                self.with_latest_from_with_mode(other, result_selector, OrderingMode::Ordered)
+            }
+
+            fn with_latest_from_with_mode<IS, R>(
+                self,
+                other: IS,
+                result_selector: impl Fn(&CombinedState<T::Inner, T::Timestamp>) -> R + $($bounds)* 'static,
+                mode: OrderingMode,
+            ) -> impl Stream<Item = StreamItem<R>>
             where
                 IS: IntoStream<Item = StreamItem<T>>,
                 IS::Stream: $($bounds)* 'static,
@@ -95,10 +127,10 @@ macro_rules! define_with_latest_from_impl {
                 let streams: Vec<PinnedStream<T>> = vec![Box::pin(self), Box::pin(other.into_stream())];
 
                 let num_streams = streams.len();
-                let state = Arc::new(Mutex::new(IntermediateState::new(num_streams)));
+                let state = Arc::new(StateMutex::new(IntermediateState::new(num_streams)));
                 let selector = Arc::new(result_selector);
 
-                let combined_stream = ordered_merge_with_index(streams).filter_map({
+                let combined_stream = ordered_merge_with_index_and_mode(streams, mode).filter_map({
                     let state = Arc::clone(&state);
                     let selector = Arc::clone(&selector);
 
@@ -110,7 +142,7 @@ macro_rules! define_with_latest_from_impl {
                             match item {
                                 StreamItem::Value(value) => {
                                     let timestamp = value.timestamp();
-                                    let mut guard = state.lock();
+                                    let mut guard = lock_state!(state);
                                     guard.insert(stream_index, value);
 
                                     if guard.is_complete() && stream_index == 0 {
@@ -0,0 +1,32 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{AbRouteExt, SplitRatio};
+use fluxion_test_utils::helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_ab_route_propagates_error_to_both_streams() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u32>>();
+    let (mut a, mut b) = stream.ab_route_by_key(SplitRatio::new(0.5), |user| *user);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut a, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut b, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_stream_ended(&mut a, 500).await;
+    assert_stream_ended(&mut b, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{AbRouteExt, SplitRatio};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::StreamExt;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_ab_route_extreme_ratios_send_everything_to_one_side() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let split = SplitRatio::new(0.0);
+    let (mut a, mut b) = stream.ab_route(split.clone(), 42);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut a, 500).await)).value,
+        1
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut a, 500).await)).value,
+        2
+    );
+    assert_no_element_emitted(&mut b, 100).await;
+
+    // Act
+    split.set(1.0);
+    tx.unbounded_send(Sequenced::new(3))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut b, 500).await)).value,
+        3
+    );
+    assert_no_element_emitted(&mut a, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ab_route_is_reproducible_with_seed() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let (a1, b1) = stream1.ab_route(SplitRatio::new(0.3), 7);
+    let (a2, b2) = stream2.ab_route(SplitRatio::new(0.3), 7);
+
+    // Act
+    for i in 0..200 {
+        tx1.unbounded_send(Sequenced::new(i))?;
+        tx2.unbounded_send(Sequenced::new(i))?;
+    }
+    drop(tx1);
+    drop(tx2);
+
+    let values = |s: fluxion_stream::RoutedStream<Sequenced<i32>>| {
+        s.map(|item| item.unwrap().into_inner()).collect::<Vec<_>>()
+    };
+    let (a1, b1, a2, b2) = (
+        values(a1).await,
+        values(b1).await,
+        values(a2).await,
+        values(b2).await,
+    );
+
+    // Assert
+    assert_eq!(a1, a2);
+    assert_eq!(b1, b2);
+    assert_eq!(a1.len() + b1.len(), 200);
+    assert!((30..90).contains(&b1.len()), "b got {} items", b1.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ab_route_by_key_keeps_keys_on_one_side() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<(u32, u32)>>();
+    let (a, b) = stream.ab_route_by_key(SplitRatio::new(0.5), |(user, _)| *user);
+
+    // Act
+    for round in 0..3 {
+        for user in 0..50 {
+            tx.unbounded_send(Sequenced::new((user, round)))?;
+        }
+    }
+    drop(tx);
+
+    let users = |s: fluxion_stream::RoutedStream<Sequenced<(u32, u32)>>| {
+        s.map(|item| item.unwrap().into_inner().0)
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (users(a).await, users(b).await);
+
+    // Assert
+    assert_eq!(a.len() + b.len(), 150);
+    assert!(!a.is_empty() && !b.is_empty());
+    assert!(a.iter().all(|user| !b.contains(user)));
+    assert_eq!(a.len() % 3, 0);
+    assert_eq!(b.len() % 3, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ab_route_by_key_raising_ratio_only_moves_keys_to_b() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<(u32, u32)>>();
+    let split = SplitRatio::new(0.2);
+    let (a, mut b) = stream.ab_route_by_key(split.clone(), |(user, _)| *user);
+
+    // Act
+    for user in 0..100 {
+        tx.unbounded_send(Sequenced::new((user, 0)))?;
+    }
+    let mut canary_before = Vec::new();
+    while canary_before.len() < 10 {
+        canary_before.push(unwrap_value(Some(unwrap_stream(&mut b, 500).await)).value.0);
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    split.set(0.6);
+    for user in 0..100 {
+        tx.unbounded_send(Sequenced::new((user, 1)))?;
+    }
+    drop(tx);
+    drop(a);
+
+    let rest: Vec<_> = b.map(|item| item.unwrap().into_inner()).collect().await;
+    canary_before.extend(
+        rest.iter()
+            .filter(|(_, round)| *round == 0)
+            .map(|(user, _)| *user),
+    );
+    let canary_after: Vec<_> = rest
+        .iter()
+        .filter(|(_, round)| *round == 1)
+        .map(|(user, _)| *user)
+        .collect();
+
+    // Assert
+    assert!(canary_before.iter().all(|user| canary_after.contains(user)));
+    assert!(canary_after.len() > canary_before.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ab_route_ends_both_streams_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (mut a, mut b) = stream.ab_route(SplitRatio::new(0.5), 1);
+
+    // Act
+    drop(tx);
+
+    // Assert
+    assert_stream_ended(&mut a, 500).await;
+    assert_stream_ended(&mut b, 500).await;
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "ab_route: ratio must be between 0.0 and 1.0")]
+fn test_split_ratio_rejects_out_of_range() {
+    SplitRatio::new(0.5).set(1.5);
+}
@@ -0,0 +1,41 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `align_by_timestamp` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{AlignByTimestampExt, Alignment};
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_align_by_timestamp_error_keeps_waiting_items() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel_with_errors::<Sequenced<u32>>();
+    let (tx_right, right) = test_channel_with_errors::<Sequenced<u32>>();
+    let mut result = left.align_by_timestamp::<Sequenced<Alignment<u32>>, _, _>(right, 5u64);
+
+    // Act
+    tx_left.unbounded_send(StreamItem::Value((1, 10).into()))?;
+    tx_right.unbounded_send(StreamItem::Error(FluxionError::stream_error("sensor")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx_right.unbounded_send(StreamItem::Value((2, 12).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        Alignment::Pair(1, 2)
+    );
+
+    Ok(())
+}
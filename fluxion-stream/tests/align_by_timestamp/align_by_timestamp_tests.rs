@@ -0,0 +1,141 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{AlignByTimestampExt, Alignment};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_align_by_timestamp_pairs_items_within_tolerance() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<u32>>();
+    let (tx_right, right) = test_channel::<Sequenced<u32>>();
+    let mut result = left.align_by_timestamp::<Sequenced<Alignment<u32>>, _, _>(right, 2u64);
+
+    // Act
+    tx_left.unbounded_send((1, 10).into())?;
+    tx_right.unbounded_send((2, 11).into())?;
+
+    // Assert
+    let aligned = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (aligned.timestamp(), aligned.value),
+        (11, Alignment::Pair(1, 2))
+    );
+
+    // Act
+    tx_right.unbounded_send((3, 20).into())?;
+    tx_left.unbounded_send((4, 22).into())?;
+
+    // Assert
+    let aligned = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (aligned.timestamp(), aligned.value),
+        (22, Alignment::Pair(4, 3))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_align_by_timestamp_takes_closest_partner() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<u32>>();
+    let (tx_right, right) = test_channel::<Sequenced<u32>>();
+    let mut result = left.align_by_timestamp::<Sequenced<Alignment<u32>>, _, _>(right, 5u64);
+
+    // Act
+    tx_left.unbounded_send((1, 10).into())?;
+    tx_left.unbounded_send((2, 13).into())?;
+    tx_right.unbounded_send((3, 14).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        Alignment::Pair(2, 3)
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_align_by_timestamp_pairs_each_item_once() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<u32>>();
+    let (tx_right, right) = test_channel::<Sequenced<u32>>();
+    let mut result = left.align_by_timestamp::<Sequenced<Alignment<u32>>, _, _>(right, 5u64);
+
+    // Act
+    tx_left.unbounded_send((1, 10).into())?;
+    tx_right.unbounded_send((2, 11).into())?;
+    tx_right.unbounded_send((3, 12).into())?;
+    tx_left.unbounded_send((4, 13).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        Alignment::Pair(1, 2)
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        Alignment::Pair(4, 3)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_align_by_timestamp_emits_unmatched_items_beyond_tolerance() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<u32>>();
+    let (tx_right, right) = test_channel::<Sequenced<u32>>();
+    let mut result = left.align_by_timestamp::<Sequenced<Alignment<u32>>, _, _>(right, 5u64);
+
+    // Act
+    tx_left.unbounded_send((1, 10).into())?;
+    tx_right.unbounded_send((2, 20).into())?;
+
+    // Assert
+    let aligned = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (aligned.timestamp(), aligned.value),
+        (20, Alignment::Left(1))
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_align_by_timestamp_emits_waiting_items_when_streams_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<u32>>();
+    let (tx_right, right) = test_channel::<Sequenced<u32>>();
+    let mut result = left.align_by_timestamp::<Sequenced<Alignment<u32>>, _, _>(right, 5u64);
+
+    // Act
+    tx_right.unbounded_send((1, 10).into())?;
+    tx_left.unbounded_send((2, 12).into())?;
+    tx_left.unbounded_send((3, 14).into())?;
+    drop(tx_left);
+    drop(tx_right);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        Alignment::Pair(2, 1)
+    );
+    let aligned = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (aligned.timestamp(), aligned.value),
+        (14, Alignment::Left(3))
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
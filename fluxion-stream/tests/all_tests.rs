@@ -2,26 +2,72 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+pub mod ab_route;
+pub mod align_by_timestamp;
+pub mod as_of_join;
+pub mod boxed;
+pub mod buffer_when;
+pub mod cache_latest;
+#[cfg(feature = "tower")]
+pub mod call_service;
 pub mod combine_latest;
 pub mod combine_with_previous;
 pub mod distinct_until_changed;
 pub mod distinct_until_changed_by;
+pub mod downsample;
+pub mod dynamic_config;
 pub mod emit_when;
+pub mod event_timers;
+pub mod filter_map_ordered;
 pub mod filter_ordered;
+pub mod filter_ordered_async;
+pub mod filter_outliers;
+pub mod finalize;
 pub mod fluxion_shared;
 pub mod fluxion_subject;
+pub mod fork;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+pub mod intercept;
+pub mod into_values;
+pub mod lookup_join;
+pub mod map_batch;
 pub mod map_ordered;
+pub mod map_ordered_blocking;
+pub mod match_pattern;
+pub mod materialized_view;
+pub mod memory_budget;
 pub mod merge_with;
+pub mod named;
 pub mod on_error;
 pub mod ordered_merge;
+pub mod par_map_ordered;
 pub mod partition;
+pub mod pipe;
+pub mod priority_merge;
+pub mod query;
+pub mod reorder;
+pub mod resample;
 pub mod sample_ratio;
 pub mod scan_ordered;
+pub mod sequence_equal;
 pub mod skip_items;
+pub mod spill_to_disk;
 pub mod start_with;
+pub mod state_cell;
 pub mod take_items;
 pub mod take_latest_when;
 pub mod take_while_with;
 pub mod tap;
+pub mod then_ordered;
+pub mod to_snapshot;
+#[cfg(feature = "tracing")]
+pub mod traced;
+pub mod try_map_ordered;
+pub mod weighted_merge;
 pub mod window_by_count;
+pub mod window_by_time;
+pub mod window_join;
+pub mod window_toggle;
+pub mod with_index;
 pub mod with_latest_from;
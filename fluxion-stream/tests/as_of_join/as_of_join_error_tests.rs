@@ -0,0 +1,44 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `as_of_join` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::AsOfJoinExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+type Tick = (&'static str, u32);
+
+#[tokio::test]
+async fn test_as_of_join_error_keeps_latest_values() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_trades, trades) = test_channel_with_errors::<Sequenced<Tick>>();
+    let (tx_quotes, quotes) = test_channel_with_errors::<Sequenced<Tick>>();
+    let mut result =
+        trades.as_of_join::<Sequenced<(Tick, Tick)>, _, _>(quotes, |tick: &Tick| tick.0);
+
+    // Act
+    tx_quotes.unbounded_send(StreamItem::Value((("ACME", 100), 1).into()))?;
+    tx_quotes.unbounded_send(StreamItem::Error(FluxionError::stream_error("feed")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx_trades.unbounded_send(StreamItem::Value((("ACME", 7), 2).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        (("ACME", 7), ("ACME", 100))
+    );
+
+    Ok(())
+}
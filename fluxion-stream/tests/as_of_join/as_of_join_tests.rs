@@ -0,0 +1,128 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::AsOfJoinExt;
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+type Tick = (&'static str, u32);
+
+fn symbol(tick: &Tick) -> &'static str {
+    tick.0
+}
+
+#[tokio::test]
+async fn test_as_of_join_pairs_with_latest_earlier_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_trades, trades) = test_channel::<Sequenced<Tick>>();
+    let (tx_quotes, quotes) = test_channel::<Sequenced<Tick>>();
+    let mut result = trades.as_of_join::<Sequenced<(Tick, Tick)>, _, _>(quotes, symbol);
+
+    // Act
+    tx_quotes.unbounded_send((("ACME", 100), 1).into())?;
+    tx_quotes.unbounded_send((("ACME", 101), 2).into())?;
+    tx_trades.unbounded_send((("ACME", 7), 3).into())?;
+
+    // Assert
+    let trade = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(trade.value, (("ACME", 7), ("ACME", 101)));
+    assert_eq!(trade.timestamp(), 3);
+
+    // Act
+    tx_quotes.unbounded_send((("ACME", 99), 4).into())?;
+    tx_trades.unbounded_send((("ACME", 8), 5).into())?;
+
+    // Assert
+    let trade = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(trade.value, (("ACME", 8), ("ACME", 99)));
+    assert_eq!(trade.timestamp(), 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_as_of_join_matches_by_key() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_trades, trades) = test_channel::<Sequenced<Tick>>();
+    let (tx_quotes, quotes) = test_channel::<Sequenced<Tick>>();
+    let mut result = trades.as_of_join::<Sequenced<(Tick, Tick)>, _, _>(quotes, symbol);
+
+    // Act
+    tx_quotes.unbounded_send((("ACME", 100), 1).into())?;
+    tx_quotes.unbounded_send((("INITECH", 50), 2).into())?;
+    tx_trades.unbounded_send((("ACME", 7), 3).into())?;
+    tx_trades.unbounded_send((("INITECH", 8), 4).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        (("ACME", 7), ("ACME", 100))
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        (("INITECH", 8), ("INITECH", 50))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_as_of_join_drops_values_without_earlier_match() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_trades, trades) = test_channel::<Sequenced<Tick>>();
+    let (tx_quotes, quotes) = test_channel::<Sequenced<Tick>>();
+    let mut result = trades.as_of_join::<Sequenced<(Tick, Tick)>, _, _>(quotes, symbol);
+
+    // Act
+    tx_trades.unbounded_send((("ACME", 7), 1).into())?;
+    tx_quotes.unbounded_send((("INITECH", 50), 2).into())?;
+    tx_trades.unbounded_send((("ACME", 8), 3).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_as_of_join_does_not_pair_dropped_value_with_later_match() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_trades, trades) = test_channel::<Sequenced<Tick>>();
+    let (tx_quotes, quotes) = test_channel::<Sequenced<Tick>>();
+    let mut result = trades.as_of_join::<Sequenced<(Tick, Tick)>, _, _>(quotes, symbol);
+
+    // Act
+    tx_trades.unbounded_send((("ACME", 7), 1).into())?;
+    assert_no_element_emitted(&mut result, 100).await;
+    tx_quotes.unbounded_send((("ACME", 100), 2).into())?;
+    tx_trades.unbounded_send((("ACME", 8), 3).into())?;
+
+    // Assert
+    let trade = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(trade.value, (("ACME", 8), ("ACME", 100)));
+    assert_eq!(trade.timestamp(), 3);
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_as_of_join_ends_when_both_streams_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_trades, trades) = test_channel::<Sequenced<Tick>>();
+    let (tx_quotes, quotes) = test_channel::<Sequenced<Tick>>();
+    let mut result = trades.as_of_join::<Sequenced<(Tick, Tick)>, _, _>(quotes, symbol);
+
+    // Act
+    drop(tx_trades);
+    drop(tx_quotes);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
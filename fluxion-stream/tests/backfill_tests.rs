@@ -0,0 +1,174 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{backfill, backfill_by_key, from_timestamped_iter};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, test_channel, test_channel_with_errors, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::StreamExt;
+
+async fn values<S, T>(stream: S) -> Vec<T>
+where
+    S: futures::Stream<Item = StreamItem<Sequenced<T>>>,
+    T: Clone + Ord,
+{
+    stream.map(|item| item.unwrap().value).collect().await
+}
+
+#[tokio::test]
+async fn test_backfill_emits_history_then_live() -> anyhow::Result<()> {
+    // Arrange
+    let history = from_timestamped_iter::<Sequenced<i32>, _>([(1, 1), (2, 2)]);
+    let live = from_timestamped_iter::<Sequenced<i32>, _>([(3, 3), (4, 4)]);
+
+    // Act
+    let values = values(backfill(history, live)).await;
+
+    // Assert
+    assert_eq!(values, vec![1, 2, 3, 4]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_drops_live_items_covered_by_history() -> anyhow::Result<()> {
+    // Arrange
+    let history = from_timestamped_iter::<Sequenced<i32>, _>([(1, 1), (2, 5), (3, 3)]);
+    let live = from_timestamped_iter::<Sequenced<i32>, _>([(20, 4), (30, 5), (40, 6), (50, 2)]);
+
+    // Act
+    let values = values(backfill(history, live)).await;
+
+    // Assert
+    assert_eq!(values, vec![1, 2, 3, 40, 50]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_does_not_poll_live_before_history_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (history_tx, history) = test_channel::<Sequenced<i32>>();
+    let (live_tx, live) = test_channel::<Sequenced<i32>>();
+    let mut result = backfill(history, live);
+
+    // Act
+    live_tx.unbounded_send(Sequenced::with_timestamp(10, 10))?;
+    history_tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    drop(history_tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        10
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_with_empty_history_emits_all_live_items() -> anyhow::Result<()> {
+    // Arrange
+    let history = from_timestamped_iter::<Sequenced<i32>, _>([]);
+    let live = from_timestamped_iter::<Sequenced<i32>, _>([(1, 1), (2, 2)]);
+
+    // Act
+    let values = values(backfill(history, live)).await;
+
+    // Assert
+    assert_eq!(values, vec![1, 2]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_by_key_drops_only_covered_keys() -> anyhow::Result<()> {
+    // Arrange
+    let history =
+        from_timestamped_iter::<Sequenced<(&str, i32)>, _>([(("alice", 1), 1), (("bob", 1), 4)]);
+    let live = from_timestamped_iter::<Sequenced<(&str, i32)>, _>([
+        (("bob", 1), 4),
+        (("alice", 2), 3),
+        (("carol", 1), 2),
+        (("bob", 2), 5),
+    ]);
+
+    // Act
+    let values = values(backfill_by_key(history, live, |item| item.value.0)).await;
+
+    // Assert
+    assert_eq!(
+        values,
+        vec![
+            ("alice", 1),
+            ("bob", 1),
+            ("alice", 2),
+            ("carol", 1),
+            ("bob", 2)
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_stops_deduplicating_after_boundary() -> anyhow::Result<()> {
+    // Arrange
+    let history = from_timestamped_iter::<Sequenced<i32>, _>([(1, 5)]);
+    let live = from_timestamped_iter::<Sequenced<i32>, _>([(2, 6), (3, 4)]);
+
+    // Act
+    let values = values(backfill(history, live)).await;
+
+    // Assert
+    assert_eq!(values, vec![1, 2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_passes_errors_from_both_streams() -> anyhow::Result<()> {
+    // Arrange
+    let (history_tx, history) = test_channel_with_errors::<Sequenced<i32>>();
+    let (live_tx, live) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = backfill(history, live);
+
+    // Act
+    history_tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("history")))?;
+    history_tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    drop(history_tx);
+    live_tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("live")))?;
+    live_tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        2
+    );
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::boxed::{BoxedExt, BoxedFluxionStream, LocalBoxedFluxionStream};
+use fluxion_stream::{FilterOrderedExt, MapOrderedExt, OrderedStreamExt};
+use fluxion_test_utils::helpers::{
+    assert_stream_ended, test_channel, test_channel_with_errors, unwrap_stream,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData};
+use futures::StreamExt;
+use std::rc::Rc;
+
+trait Source {
+    fn stream(&self) -> BoxedFluxionStream<Sequenced<TestData>>;
+}
+
+struct Fixed(Vec<TestData>);
+
+impl Source for Fixed {
+    fn stream(&self) -> BoxedFluxionStream<Sequenced<TestData>> {
+        futures::stream::iter(self.0.clone())
+            .enumerate()
+            .map(|(index, data)| StreamItem::Value(Sequenced::with_timestamp(data, index as u64)))
+            .filter_ordered(|data| *data != animal_dog())
+            .into_boxed()
+    }
+}
+
+struct Holder {
+    output: BoxedFluxionStream<Sequenced<TestData>>,
+}
+
+#[tokio::test]
+async fn test_boxed_stream_can_be_stored_in_field() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut holder = Holder {
+        output: stream
+            .filter_ordered(|data| *data != animal_dog())
+            .map_ordered(|item| item)
+            .into_boxed(),
+    };
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut holder.output, 500)
+            .await
+            .unwrap()
+            .into_inner(),
+        person_alice()
+    );
+    assert_stream_ended(&mut holder.output, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_boxed_stream_returned_from_trait_method() -> anyhow::Result<()> {
+    // Arrange
+    let source: Box<dyn Source> = Box::new(Fixed(vec![person_alice(), animal_dog(), plant_rose()]));
+
+    // Act
+    let values: Vec<TestData> = source
+        .stream()
+        .map(|item| item.unwrap().into_inner())
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(values, vec![person_alice(), plant_rose()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_boxed_streams_of_different_shapes_merge() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let streams: Vec<BoxedFluxionStream<Sequenced<TestData>>> = vec![
+        stream1.into_boxed(),
+        stream2
+            .filter_ordered(|data| *data != animal_dog())
+            .into_boxed(),
+    ];
+    let mut streams = streams.into_iter();
+    let first = streams.next().unwrap();
+    let mut merged = first.ordered_merge(streams.collect());
+
+    // Act
+    tx1.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx2.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut merged, 500).await.unwrap().into_inner(),
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut merged, 500).await.unwrap().into_inner(),
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_boxed_stream_propagates_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut boxed = stream.into_boxed();
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+
+    // Assert
+    assert!(unwrap_stream(&mut boxed, 500).await.is_error());
+    assert_eq!(
+        unwrap_stream(&mut boxed, 500).await.unwrap().into_inner(),
+        person_alice()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_boxed_local_accepts_non_send_streams() -> anyhow::Result<()> {
+    // Arrange
+    let label = Rc::new(person_bob());
+    let mut boxed: LocalBoxedFluxionStream<Sequenced<TestData>> =
+        futures::stream::iter(vec![1u64, 2])
+            .map(move |timestamp| {
+                StreamItem::Value(Sequenced::with_timestamp((*label).clone(), timestamp))
+            })
+            .into_boxed_local();
+
+    // Act
+    let values: Vec<TestData> = (&mut boxed)
+        .map(|item| item.unwrap().into_inner())
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(values, vec![person_bob(), person_bob()]);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `buffer_when` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::BufferWhenExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+    test_data::{person_alice, plant_sunflower, TestData},
+};
+
+#[tokio::test]
+async fn test_buffer_when_error_keeps_held_values() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (tx_control, control) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.buffer_when(control, |data| *data == plant_sunflower());
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+    tx_control.unbounded_send(StreamItem::Error(FluxionError::stream_error("offline")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx_control.unbounded_send(StreamItem::Value(Sequenced::new(plant_sunflower())))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::BufferWhenExt;
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{
+    animal_dog, person_alice, person_bob, person_charlie, plant_rose, plant_sunflower, TestData,
+};
+
+fn is_open(data: &TestData) -> bool {
+    *data == plant_sunflower()
+}
+
+#[tokio::test]
+async fn test_buffer_when_holds_until_control_opens() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_control, control) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.buffer_when(control, is_open);
+
+    // Act
+    let alice = Sequenced::new(person_alice());
+    let alice_timestamp = alice.timestamp();
+    tx.unbounded_send(alice)?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_control.unbounded_send(Sequenced::new(plant_sunflower()))?;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (first.timestamp(), first.value),
+        (alice_timestamp, person_alice())
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buffer_when_passes_through_while_open() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_control, control) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.buffer_when(control, is_open);
+
+    // Act
+    tx_control.unbounded_send(Sequenced::new(plant_sunflower()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    tx_control.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    tx.unbounded_send(Sequenced::new(person_charlie()))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_control.unbounded_send(Sequenced::new(plant_sunflower()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+
+    // Assert
+    for expected in [person_bob(), person_charlie(), animal_dog()] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buffer_when_drops_held_values_on_completion() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_control, control) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.buffer_when(control, is_open);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+    drop(tx_control);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `cache_latest` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::CacheLatestExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_cache_latest_propagates_errors_and_keeps_cache() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<(char, u32)>>();
+    let (mut result, cache) = stream.cache_latest(|(key, _)| *key, 100u64);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((('a', 1), 1).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value((('a', 2), 2).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        ('a', 1)
+    );
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(cache.get(&'a'), Some(('a', 1)));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        ('a', 2)
+    );
+    assert_eq!(cache.get(&'a'), Some(('a', 2)));
+
+    Ok(())
+}
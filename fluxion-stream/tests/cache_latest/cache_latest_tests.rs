@@ -0,0 +1,103 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::CacheLatestExt;
+use fluxion_test_utils::helpers::{assert_stream_ended, test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_cache_latest_passes_items_through_and_keeps_latest_per_key() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<(char, u32)>>();
+    let (mut result, cache) = stream.cache_latest(|(key, _)| *key, 100u64);
+
+    // Act
+    tx.unbounded_send((('a', 1), 1).into())?;
+    tx.unbounded_send((('b', 2), 2).into())?;
+    tx.unbounded_send((('a', 3), 3).into())?;
+    drop(tx);
+
+    // Assert
+    for expected in [('a', 1), ('b', 2), ('a', 3)] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(cache.get(&'a'), Some(('a', 3)));
+    assert_eq!(cache.get(&'b'), Some(('b', 2)));
+    assert_eq!(cache.get(&'c'), None);
+    assert_eq!(cache.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_latest_expires_values_older_than_ttl() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<(char, u32)>>();
+    let (mut result, cache) = stream.cache_latest(|(key, _)| *key, 10u64);
+
+    // Act
+    tx.unbounded_send((('a', 1), 0).into())?;
+    tx.unbounded_send((('b', 2), 10).into())?;
+    unwrap_stream(&mut result, 500).await;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert_eq!(cache.get(&'a'), Some(('a', 1)));
+
+    // Act
+    tx.unbounded_send((('b', 3), 11).into())?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert_eq!(cache.get(&'a'), None);
+    assert_eq!(cache.get(&'b'), Some(('b', 3)));
+    assert_eq!(cache.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_latest_ignores_values_older_than_cached() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<(char, u32)>>();
+    let (mut result, cache) = stream.cache_latest(|(key, _)| *key, 100u64);
+
+    // Act
+    tx.unbounded_send((('a', 2), 5).into())?;
+    tx.unbounded_send((('a', 1), 3).into())?;
+    unwrap_stream(&mut result, 500).await;
+    let late = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    assert_eq!(late.value, ('a', 1));
+    assert_eq!(cache.get(&'a'), Some(('a', 2)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_latest_handle_clones_share_the_cache() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<(char, u32)>>();
+    let (mut result, cache) = stream.cache_latest(|(key, _)| *key, 100u64);
+    let handler_cache = cache.clone();
+
+    // Assert
+    assert!(handler_cache.is_empty());
+
+    // Act
+    tx.unbounded_send((('a', 1), 1).into())?;
+    unwrap_stream(&mut result, 500).await;
+    drop(result);
+
+    // Assert
+    assert_eq!(handler_cache.get(&'a'), Some(('a', 1)));
+    assert_eq!(cache.get(&'a'), Some(('a', 1)));
+
+    Ok(())
+}
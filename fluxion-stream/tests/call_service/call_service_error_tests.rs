@@ -0,0 +1,190 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+use futures::future::{ready, Ready};
+use std::fmt::{self, Display, Formatter};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tower::timeout::error::Elapsed;
+use tower::{service_fn, Service, ServiceBuilder};
+
+#[derive(Debug, PartialEq)]
+enum ServiceError {
+    Rejected(u64),
+    Closed,
+}
+
+impl Display for ServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rejected(request) => write!(f, "rejected {request}"),
+            Self::Closed => write!(f, "service closed"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+/// Service that fails `poll_ready` once it has taken `capacity` requests.
+struct Exhausting {
+    capacity: usize,
+}
+
+impl Service<u64> for Exhausting {
+    type Response = u64;
+    type Error = ServiceError;
+    type Future = Ready<Result<u64, ServiceError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), ServiceError>> {
+        if self.capacity > 0 {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Ready(Err(ServiceError::Closed))
+        }
+    }
+
+    fn call(&mut self, request: u64) -> Self::Future {
+        self.capacity -= 1;
+        ready(Ok(request))
+    }
+}
+
+#[tokio::test]
+async fn test_call_service_emits_failed_requests_as_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let service = service_fn(|request: u64| async move {
+        if request.is_multiple_of(2) {
+            Err(ServiceError::Rejected(request))
+        } else {
+            Ok(request)
+        }
+    });
+    let mut result = stream.call_service::<Sequenced<u64>, _>(service);
+
+    // Act
+    for request in 1..=3 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+            request, request,
+        )))?;
+    }
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(response) if response.value == 1
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(error) if error.downcast_ref() == Some(&ServiceError::Rejected(2))
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(response) if response.value == 3
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_service_passes_source_errors_through_in_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let service = service_fn(|request: u64| async move { Ok::<_, ServiceError>(request) });
+    let mut result = stream.call_service_concurrent::<Sequenced<u64>, _>(4, service);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("source")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(response) if response.value == 1
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(response) if response.value == 2
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_service_ends_when_service_fails_readiness() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.call_service::<Sequenced<u64>, _>(Exhausting { capacity: 2 });
+
+    // Act
+    for request in 1..=4 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+            request, request,
+        )))?;
+    }
+
+    // Assert
+    for request in 1..=2 {
+        assert!(matches!(
+            unwrap_stream(&mut result, 500).await,
+            StreamItem::Value(response) if response.value == request
+        ));
+    }
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(error) if error.downcast_ref() == Some(&ServiceError::Closed)
+    ));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_service_accepts_tower_middleware_box_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let service = ServiceBuilder::new()
+        .timeout(Duration::from_millis(50))
+        .service(service_fn(|request: u64| async move {
+            if request == 2 {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            Ok::<_, ServiceError>(request)
+        }));
+    let mut result = stream.call_service::<Sequenced<u64>, _>(service);
+
+    // Act
+    for request in 1..=3 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+            request, request,
+        )))?;
+    }
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(response) if response.value == 1
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(error) if error.downcast_ref::<Elapsed>().is_some()
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(response) if response.value == 3
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{
+        assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+    },
+    sequenced::Sequenced,
+};
+use futures::future::{ready, Ready};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use tokio::time::sleep;
+use tower::{service_fn, Service};
+
+/// Service that takes one request per permit and waits for permits otherwise.
+#[derive(Clone, Default)]
+struct Permits {
+    available: Arc<AtomicUsize>,
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl Permits {
+    fn grant(&self, permits: usize) {
+        self.available.fetch_add(permits, Ordering::SeqCst);
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Service<u64> for Permits {
+    type Response = u64;
+    type Error = Infallible;
+    type Future = Ready<Result<u64, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        *self.waker.lock().unwrap() = Some(cx.waker().clone());
+        if self.available.load(Ordering::SeqCst) > 0 {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn call(&mut self, request: u64) -> Self::Future {
+        self.available.fetch_sub(1, Ordering::SeqCst);
+        ready(Ok(request + 100))
+    }
+}
+
+#[tokio::test]
+async fn test_call_service_emits_responses_with_request_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let service = service_fn(|request: u64| async move { Ok::<_, Infallible>(request * 10) });
+    let mut result = stream.call_service::<Sequenced<u64>, _>(service);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 5))?;
+    tx.unbounded_send(Sequenced::with_timestamp(2, 7))?;
+    drop(tx);
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((first.value, first.timestamp()), (10, 5));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((second.value, second.timestamp()), (20, 7));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_service_concurrent_emits_responses_in_source_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let service = service_fn(|request: u64| async move {
+        // Earlier requests take longer, so they would finish last without ordering
+        sleep(Duration::from_millis(60 - request * 15)).await;
+        Ok::<_, Infallible>(request)
+    });
+    let mut result = stream.call_service_concurrent::<Sequenced<u64>, _>(4, service);
+
+    // Act
+    for request in 1..=4 {
+        tx.unbounded_send(Sequenced::with_timestamp(request, request))?;
+    }
+
+    // Assert
+    for request in 1..=4 {
+        let response = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(response.value, request);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_service_concurrent_runs_requests_in_parallel() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let service = service_fn({
+        let (running, peak) = (running.clone(), peak.clone());
+        move |request: u64| {
+            let (running, peak) = (running.clone(), peak.clone());
+            async move {
+                peak.fetch_max(running.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                sleep(Duration::from_millis(30)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok::<_, Infallible>(request)
+            }
+        }
+    });
+    let mut result = stream.call_service_concurrent::<Sequenced<u64>, _>(2, service);
+
+    // Act
+    for request in 1..=6 {
+        tx.unbounded_send(Sequenced::with_timestamp(request, request))?;
+    }
+    for _ in 1..=6 {
+        unwrap_stream(&mut result, 500).await;
+    }
+
+    // Assert
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_service_waits_for_service_readiness() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let permits = Permits::default();
+    let mut result = stream.call_service_concurrent::<Sequenced<u64>, _>(8, permits.clone());
+
+    // Act
+    for request in 1..=3 {
+        tx.unbounded_send(Sequenced::with_timestamp(request, request))?;
+    }
+    permits.grant(1);
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(first.value, 101);
+    assert_no_element_emitted(&mut result, 100).await;
+
+    permits.grant(2);
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(second.value, 102);
+    let third = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(third.value, 103);
+
+    Ok(())
+}
@@ -5,4 +5,5 @@
 pub mod combine_latest_composition_error_tests;
 pub mod combine_latest_composition_tests;
 pub mod combine_latest_error_tests;
+pub mod combine_latest_interleaving_tests;
 pub mod combine_latest_tests;
@@ -0,0 +1,40 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `downsample` operator.
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::downsample::Max;
+use fluxion_stream::DownsampleExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_downsample_error_keeps_open_interval() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u32>>();
+    let mut result = stream.downsample::<Sequenced<u32>, _, _>(10u64, Max);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((4, 0).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("sensor")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((9, 5).into()))?;
+    tx.unbounded_send(StreamItem::Value((1, 10).into()))?;
+
+    // Assert
+    let summary = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((summary.timestamp(), summary.value), (10, 9));
+
+    Ok(())
+}
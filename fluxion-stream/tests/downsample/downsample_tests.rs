@@ -0,0 +1,166 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::downsample::{First, Last, Max, Min, Ohlc};
+use fluxion_stream::{Aggregator, DownsampleExt, OhlcBar};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_downsample_ohlc_summarizes_each_interval() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = stream.downsample::<Sequenced<OhlcBar<u32>>, _, _>(10u64, Ohlc);
+
+    // Act
+    tx.unbounded_send((100, 0).into())?;
+    tx.unbounded_send((120, 3).into())?;
+    tx.unbounded_send((90, 6).into())?;
+    tx.unbounded_send((95, 9).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((105, 12).into())?;
+
+    // Assert
+    let bar = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(bar.timestamp(), 10);
+    assert_eq!(
+        bar.value,
+        OhlcBar {
+            open: 100,
+            high: 120,
+            low: 90,
+            close: 95
+        }
+    );
+
+    // Act
+    drop(tx);
+
+    // Assert
+    let bar = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(bar.timestamp(), 20);
+    assert_eq!(
+        bar.value,
+        OhlcBar {
+            open: 105,
+            high: 105,
+            low: 105,
+            close: 105
+        }
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_downsample_built_in_aggregators() -> anyhow::Result<()> {
+    async fn summarize<A>(aggregator: A) -> anyhow::Result<u32>
+    where
+        A: Aggregator<u32, Output = u32> + Send + Sync + 'static,
+    {
+        let (tx, stream) = test_channel::<Sequenced<u32>>();
+        let mut result = stream.downsample::<Sequenced<u32>, _, _>(10u64, aggregator);
+        for (value, timestamp) in [(3, 0), (7, 2), (1, 4), (5, 6)] {
+            tx.unbounded_send((value, timestamp).into())?;
+        }
+        drop(tx);
+        Ok(unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value)
+    }
+
+    // Act & Assert
+    assert_eq!(summarize(First).await?, 3);
+    assert_eq!(summarize(Last).await?, 5);
+    assert_eq!(summarize(Min).await?, 1);
+    assert_eq!(summarize(Max).await?, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_downsample_skips_empty_intervals() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = stream.downsample::<Sequenced<u32>, _, _>(10u64, Last);
+
+    // Act
+    tx.unbounded_send((1, 5).into())?;
+    tx.unbounded_send((2, 47).into())?;
+    drop(tx);
+
+    // Assert
+    for expected in [(15, 1), (55, 2)] {
+        let summary = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((summary.timestamp(), summary.value), expected);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_downsample_drops_items_before_open_interval() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = stream.downsample::<Sequenced<u32>, _, _>(10u64, Max);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 12).into())?;
+    tx.unbounded_send((50, 8).into())?;
+    tx.unbounded_send((3, 18).into())?;
+    drop(tx);
+
+    // Assert
+    for expected in [(10, 1), (20, 3)] {
+        let summary = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((summary.timestamp(), summary.value), expected);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_downsample_custom_aggregator() -> anyhow::Result<()> {
+    // Arrange
+    struct Count;
+
+    impl Aggregator<u32> for Count {
+        type Output = usize;
+
+        fn start(&self, _value: u32) -> usize {
+            1
+        }
+
+        fn update(&self, count: &mut usize, _value: u32) {
+            *count += 1;
+        }
+    }
+
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = stream.downsample::<Sequenced<usize>, _, _>(10u64, Count);
+
+    // Act
+    for timestamp in [0, 1, 2, 10, 11] {
+        tx.unbounded_send((0, timestamp).into())?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in [(10, 3), (20, 2)] {
+        let summary = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((summary.timestamp(), summary.value), expected);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
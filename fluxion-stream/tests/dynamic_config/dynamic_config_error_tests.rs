@@ -0,0 +1,53 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::DynamicConfigExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_dynamic_filter_passes_source_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let (_config_tx, config) = test_channel_with_errors::<i32>();
+    let mut result = stream.dynamic_filter(config, 10, |threshold, value| value > threshold);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(15)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 15);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_filter_passes_config_errors_and_keeps_config() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let (config_tx, config) = test_channel_with_errors::<i32>();
+    let mut result = stream.dynamic_filter(config, 10, |threshold, value| value > threshold);
+
+    // Act
+    config_tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("bad config")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(5)))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(15)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 15);
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::DynamicConfigExt;
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_dynamic_filter_uses_initial_config() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (_config_tx, config) = test_channel::<i32>();
+    let mut result = stream.dynamic_filter(config, 10, |threshold, value| value > threshold);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(5))?;
+    tx.unbounded_send(Sequenced::new(15))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 15);
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_filter_applies_update_before_next_item() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (config_tx, config) = test_channel::<i32>();
+    let mut result = stream.dynamic_filter(config, 10, |threshold, value| value > threshold);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(15))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 15);
+
+    // Act
+    config_tx.unbounded_send(20)?;
+    tx.unbounded_send(Sequenced::new(15))?;
+    tx.unbounded_send(Sequenced::new(25))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 25);
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_filter_applies_latest_of_several_updates() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (config_tx, config) = test_channel::<i32>();
+    let mut result = stream.dynamic_filter(config, 0, |threshold, value| value > threshold);
+
+    // Act
+    config_tx.unbounded_send(50)?;
+    config_tx.unbounded_send(30)?;
+    tx.unbounded_send(Sequenced::new(40))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 40);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_map_uses_latest_config_and_keeps_timestamp() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (config_tx, config) = test_channel::<i32>();
+    let mut result = stream.dynamic_map(config, 2, |factor, value: Sequenced<i32>| {
+        Sequenced::with_timestamp(value.value * factor, value.timestamp())
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(3, 1))?;
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.value, first.timestamp()), (6, 1));
+
+    // Act
+    config_tx.unbounded_send(10)?;
+    tx.unbounded_send(Sequenced::with_timestamp(3, 2))?;
+
+    // Assert
+    let second = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((second.value, second.timestamp()), (30, 2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_map_keeps_last_config_after_config_stream_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (config_tx, config) = test_channel::<i32>();
+    let mut result = stream.dynamic_map(config, 1, |offset, value: Sequenced<i32>| {
+        Sequenced::new(value.value + offset)
+    });
+
+    // Act
+    config_tx.unbounded_send(100)?;
+    drop(config_tx);
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 101);
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 102);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_map_ends_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (_config_tx, config) = test_channel::<i32>();
+    let mut result = stream.dynamic_map(config, 1, |offset, value: Sequenced<i32>| {
+        Sequenced::new(value.value + offset)
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 2);
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::EmitWhenExt;
+use fluxion_test_utils::fuzz::{run_scenario, Scenario};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_emit_when_invariants_hold_under_any_interleaving(
+        data in prop::collection::vec(any::<u8>(), 8..128),
+    ) {
+        // Arrange
+        let scenario = Scenario::from_bytes(&data, 2);
+
+        // Act
+        let trace = run_scenario(&scenario, |mut streams| {
+            let filter = streams.pop().unwrap();
+            let source = streams.pop().unwrap();
+            source.emit_when(filter, |_| true)
+        });
+
+        // Assert
+        prop_assert_eq!(trace.check_ordered(), Ok(()));
+        prop_assert_eq!(trace.check_no_loss_after_warmup(), Ok(()));
+        prop_assert_eq!(trace.check_errors_preserved(), Ok(()));
+    }
+}
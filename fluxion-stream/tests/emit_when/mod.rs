@@ -5,4 +5,5 @@
 pub mod emit_when_composition_error_tests;
 pub mod emit_when_composition_tests;
 pub mod emit_when_error_tests;
+pub mod emit_when_interleaving_tests;
 pub mod emit_when_tests;
@@ -0,0 +1,39 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::ProcessWithTimersExt;
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_process_with_timers_propagates_errors_and_keeps_timers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.process_with_timers::<Sequenced<i32>, _, _>(
+        (),
+        |_, item, timers| {
+            timers.register(item.timestamp() + 10, item.value);
+            Vec::new()
+        },
+        |_, _, value, _| vec![value],
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 0)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 10)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{EventTimers, ProcessWithTimersExt};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::StreamExt;
+
+#[test]
+fn test_event_timers_fire_in_time_then_registration_order() {
+    // Arrange
+    let mut timers = EventTimers::new();
+    timers.register(20u64, "c");
+    timers.register(10, "a");
+    timers.register(10, "b");
+    timers.register(30, "d");
+
+    // Act
+    let fired = timers.advance_watermark(20);
+
+    // Assert
+    assert_eq!(fired, vec![(10, "a"), (10, "b"), (20, "c")]);
+    assert_eq!(timers.watermark(), Some(20));
+    assert_eq!(timers.next_timer(), Some(30));
+    assert_eq!(timers.len(), 1);
+}
+
+#[test]
+fn test_event_timers_watermark_never_moves_back() {
+    // Arrange
+    let mut timers = EventTimers::new();
+    timers.advance_watermark(50u64);
+
+    // Act
+    timers.register(40, "overdue");
+    let fired = timers.advance_watermark(10);
+
+    // Assert
+    assert_eq!(fired, vec![(40, "overdue")]);
+    assert_eq!(timers.watermark(), Some(50));
+    assert!(timers.is_empty());
+}
+
+#[test]
+fn test_event_timers_cancel_removes_timer() {
+    // Arrange
+    let mut timers = EventTimers::new();
+    let cancelled = timers.register(10u64, "cancelled");
+    timers.register(10, "kept");
+
+    // Act
+    let payload = timers.cancel(cancelled);
+
+    // Assert
+    assert_eq!(payload, Some("cancelled"));
+    assert_eq!(timers.cancel(cancelled), None);
+    assert_eq!(timers.advance_watermark(10), vec![(10, "kept")]);
+}
+
+#[test]
+fn test_event_timers_fire_all_drains_in_order() {
+    // Arrange
+    let mut timers = EventTimers::new();
+    timers.register(30u64, 3);
+    timers.register(10, 1);
+
+    // Act
+    let fired = timers.fire_all();
+
+    // Assert
+    assert_eq!(fired, vec![(10, 1), (30, 3)]);
+    assert!(timers.is_empty());
+    assert_eq!(timers.watermark(), None);
+}
+
+#[tokio::test]
+async fn test_process_with_timers_fires_when_watermark_passes() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.process_with_timers::<Sequenced<i32>, _, _>(
+        (),
+        |_, item, timers| {
+            timers.register(item.timestamp() + 10, item.value);
+            Vec::new()
+        },
+        |_, _, value, _| vec![value],
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 0))?;
+    tx.unbounded_send(Sequenced::with_timestamp(2, 5))?;
+    tx.unbounded_send(Sequenced::with_timestamp(3, 9))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(4, 15))?;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((first.value, first.timestamp()), (1, 15));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((second.value, second.timestamp()), (2, 15));
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_process_with_timers_emits_item_outputs_before_timers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.process_with_timers::<Sequenced<i32>, _, _>(
+        0,
+        |count, item, timers| {
+            *count += 1;
+            // A timer at the item's own time is already due
+            timers.register(item.timestamp(), -item.value);
+            vec![item.value]
+        },
+        |count, _, value, _| vec![value * *count],
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(5, 1))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        5
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        -5
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_process_with_timers_chains_due_timers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.process_with_timers::<Sequenced<u64>, _, _>(
+        (),
+        |_, item, timers| {
+            timers.register(item.timestamp(), 3);
+            Vec::new()
+        },
+        |_, at, remaining, timers| {
+            if remaining > 1 {
+                timers.register(at, remaining - 1);
+            }
+            vec![remaining]
+        },
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(0, 7))?;
+
+    // Assert
+    for expected in [3, 2, 1] {
+        let fired = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((fired.value, fired.timestamp()), (expected, 7));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_process_with_timers_fires_remaining_timers_on_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let result = stream.process_with_timers::<Sequenced<i32>, _, _>(
+        (),
+        |_, item, timers| {
+            timers.register(item.timestamp() + 100, item.value);
+            Vec::new()
+        },
+        |_, _, value, _| vec![value],
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(2, 20))?;
+    tx.unbounded_send(Sequenced::with_timestamp(1, 10))?;
+    drop(tx);
+    let fired: Vec<_> = result
+        .map(|item| {
+            let item = item.unwrap();
+            (item.value, item.timestamp())
+        })
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(fired, vec![(1, 110), (2, 120)]);
+
+    Ok(())
+}
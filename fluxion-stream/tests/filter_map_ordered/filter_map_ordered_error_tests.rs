@@ -0,0 +1,38 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `filter_map_ordered` operator.
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::FilterMapOrderedExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_filter_map_ordered_propagates_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.filter_map_ordered(|item| {
+        (item.value % 2 == 0).then(|| Sequenced::with_timestamp(item.value * 10, item.timestamp()))
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("Error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Value(item) if item.value == 20
+    ));
+
+    Ok(())
+}
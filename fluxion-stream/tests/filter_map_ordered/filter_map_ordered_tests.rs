@@ -0,0 +1,101 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData},
+};
+
+fn person_name(item: Sequenced<TestData>) -> Option<Sequenced<String>> {
+    let timestamp = item.timestamp();
+    match item.value {
+        TestData::Person(person) => Some(Sequenced::with_timestamp(person.name, timestamp)),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_filter_map_ordered_keeps_some_and_drops_none() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.filter_map_ordered(person_name);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        "Alice"
+    );
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, "Bob");
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_map_ordered_preserves_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.filter_map_ordered(person_name);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 10))?;
+    tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 20))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 30))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().timestamp(),
+        10
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().timestamp(),
+        30
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_map_ordered_all_none_emits_nothing() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.filter_map_ordered(person_name);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(plant_rose()))?;
+    drop(tx);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_map_ordered_chained_with_filter_ordered() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream
+        .filter_map_ordered(person_name)
+        .filter_ordered(|name| name.starts_with('B'));
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, "Bob");
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::FilterOrderedExt;
+use fluxion_test_utils::property::{
+    check_errors_preserved, check_subsequence, run_stream, timestamped_stream, StreamProfile,
+};
+use futures::stream;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_filter_ordered_emits_subsequence_and_keeps_errors(
+        items in timestamped_stream(any::<i32>(), StreamProfile::default()),
+    ) {
+        // Act
+        let output = run_stream(stream::iter(items.clone()).filter_ordered(|value| value % 2 == 0));
+
+        // Assert
+        check_subsequence(&items, &output)?;
+        check_errors_preserved(&items, &output)?;
+        prop_assert!(output
+            .iter()
+            .filter_map(|item| item.clone().ok())
+            .all(|item| item.value % 2 == 0));
+    }
+}
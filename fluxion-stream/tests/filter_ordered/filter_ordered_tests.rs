@@ -5,6 +5,7 @@
 use fluxion_core::HasTimestamp;
 
 use fluxion_stream::FilterOrderedExt;
+use fluxion_test_utils::assert_emits;
 use fluxion_test_utils::helpers::{assert_stream_ended, test_channel, unwrap_stream, unwrap_value};
 use fluxion_test_utils::sequenced::Sequenced;
 use fluxion_test_utils::test_data::{
@@ -54,14 +55,7 @@ async fn test_filter_ordered_age_threshold() -> anyhow::Result<()> {
     tx.unbounded_send(Sequenced::new(person_diane()))?;
 
     // Assert
-    assert_eq!(
-        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
-        person_charlie()
-    );
-    assert_eq!(
-        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
-        person_diane()
-    );
+    assert_emits!(result, [person_charlie(), person_diane()]);
 
     Ok(())
 }
@@ -111,18 +105,7 @@ async fn test_filter_ordered_none_filtered() -> anyhow::Result<()> {
     tx.unbounded_send(Sequenced::new(plant_rose()))?;
 
     // Assert
-    assert_eq!(
-        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
-        person_alice()
-    );
-    assert_eq!(
-        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
-        animal_dog()
-    );
-    assert_eq!(
-        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
-        plant_rose()
-    );
+    assert_emits!(result, [person_alice(), animal_dog(), plant_rose()]);
 
     Ok(())
 }
@@ -5,4 +5,5 @@
 pub mod filter_ordered_composition_error_tests;
 pub mod filter_ordered_composition_tests;
 pub mod filter_ordered_error_tests;
+pub mod filter_ordered_property_tests;
 pub mod filter_ordered_tests;
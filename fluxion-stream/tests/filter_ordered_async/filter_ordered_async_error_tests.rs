@@ -0,0 +1,39 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `filter_ordered_async` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::FilterOrderedAsyncExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_filter_ordered_async_propagates_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.filter_ordered_async(|value: &i32| {
+        let even = value % 2 == 0;
+        async move { even }
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("Error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Value(item) if item.value == 2
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::HasTimestamp;
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData},
+};
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_filter_ordered_async_keeps_passing_items() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.filter_ordered_async(|data: &TestData| {
+        let is_person = matches!(data, TestData::Person(_));
+        async move { is_person }
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_ordered_async_slow_predicate_keeps_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.filter_ordered_async(|data: &TestData| {
+        // The first item takes longest to check
+        let delay = if *data == person_alice() { 50 } else { 1 };
+        async move {
+            sleep(Duration::from_millis(delay)).await;
+            true
+        }
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+    tx.unbounded_send(Sequenced::with_timestamp(plant_rose(), 3))?;
+
+    // Assert
+    for expected in 1..=3 {
+        assert_eq!(
+            unwrap_stream(&mut result, 500).await.unwrap().timestamp(),
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_ordered_async_ends_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.filter_ordered_async(|_: &TestData| async { false });
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
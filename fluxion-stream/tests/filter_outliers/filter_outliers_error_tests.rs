@@ -0,0 +1,47 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `filter_outliers` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{FilterOutliersExt, OutlierTest};
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_divert_outliers_error_stays_on_main_stream() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let (mut clean, mut outliers) =
+        stream.divert_outliers(2, OutlierTest::ZScore(2.0), |r| *r as f64);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(10)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("sensor")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(12)))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(40)))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut clean, 500).await)).value,
+        10
+    );
+    assert!(matches!(
+        unwrap_stream(&mut clean, 100).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut clean, 500).await)).value,
+        12
+    );
+    assert_no_element_emitted(&mut clean, 100).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut outliers, 500).await)).value,
+        40
+    );
+
+    Ok(())
+}
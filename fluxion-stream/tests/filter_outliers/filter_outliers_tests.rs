@@ -0,0 +1,131 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{FilterOutliersExt, OutlierTest};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_filter_outliers_z_score_drops_spike() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.filter_outliers(4, OutlierTest::ZScore(3.0), |r| *r as f64);
+
+    // Act
+    for reading in [10, 12, 10, 12, 50, 11] {
+        tx.unbounded_send(Sequenced::new(reading))?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in [10, 12, 10, 12, 11] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_outliers_passes_items_until_window_is_full() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.filter_outliers(3, OutlierTest::ZScore(1.0), |r| *r as f64);
+
+    // Act
+    for reading in [1, 100, -50] {
+        tx.unbounded_send(Sequenced::new(reading))?;
+    }
+
+    // Assert
+    for expected in [1, 100, -50] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_outliers_median_deviation_ignores_earlier_outliers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.filter_outliers(5, OutlierTest::MedianDeviation(3.0), |r| *r as f64);
+
+    // Act
+    for reading in [20, 21, 19, 20, 21, 90, 95, 22] {
+        tx.unbounded_send(Sequenced::new(reading))?;
+    }
+
+    // Assert
+    for expected in [20, 21, 19, 20, 21, 22] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_outliers_accepts_lasting_level_change() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.filter_outliers(3, OutlierTest::MedianDeviation(2.0), |r| *r as f64);
+
+    // Act
+    for reading in [10, 11, 12, 50, 51, 52, 51] {
+        tx.unbounded_send(Sequenced::new(reading))?;
+    }
+
+    // Assert
+    for expected in [10, 11, 12, 52, 51] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_divert_outliers_sends_outliers_to_side_stream() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (mut clean, mut outliers) =
+        stream.divert_outliers(4, OutlierTest::ZScore(3.0), |r| *r as f64);
+
+    // Act
+    for reading in [10, 12, 10, 12, 50, 11] {
+        tx.unbounded_send(Sequenced::new(reading))?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in [10, 12, 10, 12, 11] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut clean, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut clean, 500).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut outliers, 500).await)).value,
+        50
+    );
+    assert_stream_ended(&mut outliers, 500).await;
+
+    Ok(())
+}
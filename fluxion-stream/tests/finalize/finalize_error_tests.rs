@@ -0,0 +1,69 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `on_terminate` and `on_complete` operators.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{FinalizeExt, Termination};
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_on_terminate_reports_error_ending_the_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let termination = Arc::new(Mutex::new(None));
+    let recorded = Arc::clone(&termination);
+    let mut result = stream.on_terminate(move |t| *recorded.lock().unwrap() = Some(t));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("fatal")))?;
+    drop(tx);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(*termination.lock().unwrap(), Some(Termination::Errored));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_complete_skips_source_ending_with_error() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let completed = Arc::new(Mutex::new(false));
+    let recorded = Arc::clone(&completed);
+    let mut result = stream.on_complete(move || *recorded.lock().unwrap() = true);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("fatal")))?;
+    drop(tx);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_stream_ended(&mut result, 500).await;
+    assert!(!*completed.lock().unwrap());
+
+    Ok(())
+}
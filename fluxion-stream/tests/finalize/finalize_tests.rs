@@ -0,0 +1,88 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{FinalizeExt, Termination};
+use fluxion_test_utils::helpers::{assert_stream_ended, test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::sync::{Arc, Mutex};
+
+fn recorder() -> (
+    Arc<Mutex<Vec<Termination>>>,
+    impl FnOnce(Termination) + Send + Sync + 'static,
+) {
+    let terminations = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&terminations);
+    (terminations, move |termination| {
+        recorded.lock().unwrap().push(termination)
+    })
+}
+
+#[tokio::test]
+async fn test_on_terminate_reports_completion_before_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (terminations, hook) = recorder();
+    let mut result = stream.on_terminate(hook);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert!(terminations.lock().unwrap().is_empty());
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(*terminations.lock().unwrap(), vec![Termination::Completed]);
+
+    // Act
+    drop(result);
+
+    // Assert
+    assert_eq!(*terminations.lock().unwrap(), vec![Termination::Completed]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_terminate_reports_cancellation_on_drop() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (terminations, hook) = recorder();
+    let mut result = stream.on_terminate(hook);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    unwrap_stream(&mut result, 500).await;
+    drop(result);
+
+    // Assert
+    assert_eq!(*terminations.lock().unwrap(), vec![Termination::Cancelled]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_complete_runs_on_completion_only() -> anyhow::Result<()> {
+    // Arrange
+    let (completed_tx, completed) = test_channel::<Sequenced<i32>>();
+    let (dropped_tx, dropped) = test_channel::<Sequenced<i32>>();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let (first, second) = (Arc::clone(&calls), Arc::clone(&calls));
+    let mut completed = completed.on_complete(move || first.lock().unwrap().push("completed"));
+    let dropped = dropped.on_complete(move || second.lock().unwrap().push("dropped"));
+
+    // Act
+    drop(completed_tx);
+    assert_stream_ended(&mut completed, 500).await;
+    drop(dropped);
+    drop(dropped_tx);
+
+    // Assert
+    assert_eq!(*calls.lock().unwrap(), vec!["completed"]);
+
+    Ok(())
+}
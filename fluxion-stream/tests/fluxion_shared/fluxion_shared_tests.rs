@@ -393,3 +393,27 @@ async fn high_subscriber_count_broadcasts_correctly() {
     // Verify subscriber count
     assert_eq!(shared.subscriber_count(), SUBSCRIBER_COUNT);
 }
+
+#[tokio::test]
+async fn share_yields_to_other_tasks() {
+    // Arrange
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&produced);
+    let source = futures::stream::iter(0..10_000u64).map(move |i| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        StreamItem::Value(Sequenced::with_timestamp(i, i))
+    });
+    let shared = source.share();
+    let _subscriber = shared.subscribe().unwrap();
+
+    // Act
+    let observed = tokio::spawn(async move { produced.load(Ordering::SeqCst) })
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(
+        observed < 10_000,
+        "share drained every item without yielding"
+    );
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{FilterOrderedExt, ForkExt, MapOrderedExt, OrderedStreamExt};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_tee_feeds_independent_pipelines() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (database, cache) = stream.tee(8);
+    let mut people = database.filter_ordered(|data| matches!(data, TestData::Person(_)));
+    let mut names = cache.map_ordered(|item| {
+        let name = item.value.to_string();
+        Sequenced::with_timestamp(name, 0)
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut people, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut names, 500).await)).value,
+        animal_dog().to_string()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut names, 500).await)).value,
+        person_alice().to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_forked_copies_merge_back_in_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (first, second) = stream.tee(8);
+    let mut merged = first
+        .filter_ordered(|data| *data == person_alice())
+        .ordered_merge(vec![second.filter_ordered(|data| *data == person_bob())]);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_bob()
+    );
+
+    Ok(())
+}
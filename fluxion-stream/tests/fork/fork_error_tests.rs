@@ -0,0 +1,56 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::ForkExt;
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_fork_delivers_errors_to_every_copy() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut copies = stream.fork(3, 4);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+
+    // Assert
+    for copy in &mut copies {
+        assert!(matches!(
+            unwrap_stream(copy, 500).await,
+            StreamItem::Error(FluxionError::StreamProcessingError { .. })
+        ));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_continues_after_error() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (mut first, mut second) = stream.tee(4);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_bob())))?;
+
+    // Assert
+    for copy in [&mut first, &mut second] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(copy, 500).await)).value,
+            person_alice()
+        );
+        assert!(unwrap_stream(copy, 500).await.is_error());
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(copy, 500).await)).value,
+            person_bob()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{ForkExt, ForkedStream};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::spawn;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_tee_delivers_every_item_to_both_copies() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (mut first, mut second) = stream.tee(8);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut first, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut first, 500).await)).value,
+        animal_dog()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut second, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut second, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_creates_requested_number_of_copies() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut copies = stream.fork(3, 4);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(plant_rose()))?;
+
+    // Assert
+    assert_eq!(copies.len(), 3);
+    for copy in &mut copies {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(copy, 500).await)).value,
+            plant_rose()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_preserves_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (mut first, mut second) = stream.tee(4);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 42))?;
+
+    // Assert
+    let expected = Sequenced::with_timestamp(person_bob(), 42);
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut first, 500).await)),
+        expected
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut second, 500).await)),
+        expected
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_bounds_how_far_a_copy_runs_ahead() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (mut fast, mut slow) = stream.tee(2);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut fast, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut fast, 500).await)).value,
+        person_bob()
+    );
+    assert_no_element_emitted(&mut fast, 100).await;
+
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut slow, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut fast, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dropped_copy_does_not_block_others() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (mut kept, dropped) = stream.tee(1);
+    drop(dropped);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut kept, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut kept, 500).await)).value,
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut kept, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dropping_blocking_copy_unblocks_waiting_copy() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (mut fast, slow) = stream.tee(1);
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut fast, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    let waiting = spawn(async move { unwrap_stream(&mut fast, 1000).await });
+    sleep(Duration::from_millis(50)).await;
+    drop(slow);
+
+    // Assert
+    assert_eq!(unwrap_value(Some(waiting.await?)).value, person_bob());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_ends_all_copies_when_source_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (mut first, mut second) = stream.tee(4);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut first, 500).await)).value,
+        person_alice()
+    );
+    assert_stream_ended(&mut first, 500).await;
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut second, 500).await)).value,
+        person_alice()
+    );
+    assert_stream_ended(&mut second, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copies_consumed_concurrently() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let (first, second) = stream.tee(3);
+    let consume = |copy: ForkedStream<Sequenced<u64>>| {
+        spawn(async move {
+            copy.map(|item| item.unwrap().value)
+                .collect::<Vec<_>>()
+                .await
+        })
+    };
+    let first = consume(first);
+    let second = consume(second);
+
+    // Act
+    for value in 0..100 {
+        tx.unbounded_send(Sequenced::with_timestamp(value, value))?;
+    }
+    drop(tx);
+
+    // Assert
+    let expected: Vec<u64> = (0..100).collect();
+    assert_eq!(first.await?, expected);
+    assert_eq!(second.await?, expected);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "fork: capacity must be at least 1")]
+fn test_fork_rejects_zero_capacity() {
+    let (_tx, stream) = test_channel::<Sequenced<TestData>>();
+    let _ = stream.fork(2, 0);
+}
@@ -0,0 +1,7 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+pub mod fork_composition_tests;
+pub mod fork_error_tests;
+pub mod fork_tests;
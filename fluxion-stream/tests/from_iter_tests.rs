@@ -0,0 +1,102 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::{from_iter, from_timestamped_iter, MapOrderedExt, OrderedStreamExt};
+use fluxion_test_utils::helpers::{assert_stream_ended, unwrap_stream};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, plant_rose, TestData};
+use futures::StreamExt;
+
+fn timestamps<T: HasTimestamp>(items: Vec<StreamItem<T>>) -> Vec<T::Timestamp> {
+    items
+        .into_iter()
+        .map(|item| item.unwrap().timestamp())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_from_iter_assigns_increasing_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let mut stream =
+        from_iter::<Sequenced<TestData>, _>(vec![person_alice(), animal_dog(), plant_rose()]);
+
+    // Act & Assert
+    assert_eq!(
+        unwrap_stream(&mut stream, 500).await.unwrap(),
+        Sequenced::with_timestamp(person_alice(), 0)
+    );
+    assert_eq!(
+        unwrap_stream(&mut stream, 500).await.unwrap(),
+        Sequenced::with_timestamp(animal_dog(), 1)
+    );
+    assert_eq!(
+        unwrap_stream(&mut stream, 500).await.unwrap(),
+        Sequenced::with_timestamp(plant_rose(), 2)
+    );
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_from_iter_empty() -> anyhow::Result<()> {
+    // Arrange
+    let mut stream = from_iter::<Sequenced<i32>, _>(Vec::new());
+
+    // Act & Assert
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_from_iter_is_lazy() -> anyhow::Result<()> {
+    // Arrange
+    let stream = from_iter::<Sequenced<u64>, _>((0..).map(|value| value * 10));
+
+    // Act
+    let items: Vec<_> = stream.take(3).collect().await;
+
+    // Assert
+    let values: Vec<u64> = items.into_iter().map(|item| item.unwrap().value).collect();
+    assert_eq!(values, vec![0, 10, 20]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_from_timestamped_iter_keeps_given_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let stream =
+        from_timestamped_iter::<Sequenced<TestData>, _>([(person_alice(), 30), (animal_dog(), 10)]);
+
+    // Act
+    let items: Vec<_> = stream.collect().await;
+
+    // Assert
+    assert_eq!(timestamps(items), vec![30, 10]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_from_iter_composes_with_operators() -> anyhow::Result<()> {
+    // Arrange
+    let evens = from_timestamped_iter::<Sequenced<i32>, _>([(0, 0), (2, 2), (4, 4)]);
+    let odds = from_timestamped_iter::<Sequenced<i32>, _>([(1, 1), (3, 3)]);
+
+    // Act
+    let values: Vec<i32> = evens
+        .ordered_merge(vec![odds])
+        .map_ordered(|item| Sequenced::with_timestamp(item.value * 10, item.timestamp()))
+        .map(|item| item.unwrap().value)
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(values, vec![0, 10, 20, 30, 40]);
+
+    Ok(())
+}
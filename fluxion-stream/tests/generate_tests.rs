@@ -0,0 +1,109 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{CancellationToken, HasTimestamp};
+use fluxion_stream::{generate, generate_cancellable};
+use fluxion_test_utils::helpers::{assert_stream_ended, unwrap_stream};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::StreamExt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_generate_emits_items_until_step_returns_none() -> anyhow::Result<()> {
+    // Arrange
+    let stream = generate(1u64, |n| async move {
+        (n <= 3).then(|| (Sequenced::with_timestamp(n * n, n), n + 1))
+    });
+
+    // Act
+    let items: Vec<_> = stream
+        .map(|item| {
+            let item = item.unwrap();
+            (item.timestamp(), item.into_inner())
+        })
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(items, vec![(1, 1), (2, 4), (3, 9)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_calls_step_only_when_polled() -> anyhow::Result<()> {
+    // Arrange
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut stream = generate(0u64, move |n| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        async move { Some((Sequenced::with_timestamp(n, n), n + 1)) }
+    });
+
+    // Assert
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    // Act
+    unwrap_stream(&mut stream, 500).await;
+    unwrap_stream(&mut stream, 500).await;
+
+    // Assert
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_cancellable_ends_during_pending_step() -> anyhow::Result<()> {
+    // Arrange
+    let cancel = CancellationToken::new();
+    let mut stream = generate_cancellable(
+        0u64,
+        |n| async move {
+            if n > 0 {
+                futures::future::pending::<()>().await;
+            }
+            Some((Sequenced::with_timestamp(n, n), n + 1))
+        },
+        cancel.clone(),
+    );
+
+    // Act
+    let first = unwrap_stream(&mut stream, 500).await.unwrap();
+    let canceller = tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        cancel.cancel();
+    });
+
+    // Assert
+    assert_eq!(first.into_inner(), 0);
+    assert_stream_ended(&mut stream, 500).await;
+    canceller.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_cancellable_does_not_start_when_cancelled() -> anyhow::Result<()> {
+    // Arrange
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut stream = generate_cancellable(
+        0u64,
+        move |n| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Some((Sequenced::with_timestamp(n, n), n + 1)) }
+        },
+        cancel,
+    );
+
+    // Act & Assert
+    assert_stream_ended(&mut stream, 500).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::pin::pin;
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::heapless::{CombineLatestHeaplessExt, HeaplessCombinedState};
+use fluxion_test_utils::{
+    helpers::{
+        assert_no_element_emitted, test_channel, test_channel_with_errors, unwrap_stream,
+        unwrap_value,
+    },
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData},
+};
+
+fn values<const N: usize>(state: &HeaplessCombinedState<TestData, u64, N>) -> Vec<TestData> {
+    state.values().cloned().collect()
+}
+
+#[tokio::test]
+async fn test_combine_latest_heapless_emits_once_all_streams_emitted() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let mut combined = pin!(stream1.combine_latest_heapless::<2, _>([stream2], |_| true));
+
+    // Act
+    tx1.unbounded_send((person_alice(), 1).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut combined, 100).await;
+
+    // Act
+    tx2.unbounded_send((animal_dog(), 2).into())?;
+
+    // Assert
+    let state = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!(values(&state), vec![person_alice(), animal_dog()]);
+    assert_eq!(state.timestamp(), 2);
+
+    // Act
+    tx1.unbounded_send((person_bob(), 3).into())?;
+
+    // Assert
+    let state = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!(values(&state), vec![person_bob(), animal_dog()]);
+    assert_eq!(state.pairs()[0].1, 3);
+    assert_eq!(state.pairs()[1].1, 2);
+    assert_eq!(state.timestamp(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_combine_latest_heapless_applies_filter() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let mut combined = pin!(stream1.combine_latest_heapless::<2, _>([stream2], |state| {
+        state.values().next() != Some(&person_alice())
+    }));
+
+    // Act
+    tx1.unbounded_send((person_alice(), 1).into())?;
+    tx2.unbounded_send((animal_dog(), 2).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut combined, 100).await;
+
+    // Act
+    tx1.unbounded_send((person_bob(), 3).into())?;
+
+    // Assert
+    let state = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!(values(&state), vec![person_bob(), animal_dog()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_combine_latest_heapless_reports_too_many_streams() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let (_tx3, stream3) = test_channel::<Sequenced<TestData>>();
+    let mut combined = pin!(stream1.combine_latest_heapless::<2, _>([stream2, stream3], |_| true));
+
+    // Act
+    tx1.unbounded_send((person_alice(), 1).into())?;
+    tx2.unbounded_send((plant_rose(), 2).into())?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut combined, 500).await,
+        StreamItem::Error(FluxionError::ResourceLimitExceeded { limit: 2, .. })
+    ));
+    let state = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!(values(&state), vec![person_alice(), plant_rose()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_combine_latest_heapless_propagates_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut combined = pin!(stream1.combine_latest_heapless::<2, _>([stream2], |_| true));
+
+    // Act
+    tx1.unbounded_send(StreamItem::Value((person_alice(), 1).into()))?;
+    tx2.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut combined, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+
+    // Act
+    tx2.unbounded_send(StreamItem::Value((animal_dog(), 2).into()))?;
+
+    // Assert
+    let state = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!(values(&state), vec![person_alice(), animal_dog()]);
+
+    Ok(())
+}
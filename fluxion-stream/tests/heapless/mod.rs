@@ -0,0 +1,7 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+pub mod combine_latest_heapless_tests;
+pub mod ordered_merge_heapless_tests;
+pub mod window_by_count_heapless_tests;
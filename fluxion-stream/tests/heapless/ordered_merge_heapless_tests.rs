@@ -0,0 +1,136 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::pin::pin;
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::heapless::OrderedMergeHeaplessExt;
+use fluxion_test_utils::{
+    helpers::{
+        assert_no_element_emitted, assert_stream_ended, test_channel, test_channel_with_errors,
+        unwrap_stream, unwrap_value,
+    },
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData},
+};
+
+#[tokio::test]
+async fn test_ordered_merge_heapless_emits_in_timestamp_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let (tx3, stream3) = test_channel::<Sequenced<TestData>>();
+    let mut merged = pin!(stream1.ordered_merge_heapless::<3>([stream2, stream3]));
+
+    // Act
+    tx3.unbounded_send((plant_rose(), 3).into())?;
+    tx2.unbounded_send((animal_dog(), 2).into())?;
+    tx1.unbounded_send((person_alice(), 1).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        animal_dog()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        plant_rose()
+    );
+    assert_no_element_emitted(&mut merged, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ordered_merge_heapless_ends_when_all_streams_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let mut merged = pin!(stream1.ordered_merge_heapless::<2>([stream2]));
+
+    // Act
+    tx1.unbounded_send((person_alice(), 1).into())?;
+    drop(tx1);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    tx2.unbounded_send((person_bob(), 2).into())?;
+    drop(tx2);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_bob()
+    );
+    assert_stream_ended(&mut merged, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ordered_merge_heapless_reports_too_many_streams() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let (tx3, stream3) = test_channel::<Sequenced<TestData>>();
+    let mut merged = pin!(stream1.ordered_merge_heapless::<2>([stream2, stream3]));
+
+    // Act
+    tx2.unbounded_send((animal_dog(), 2).into())?;
+    tx1.unbounded_send((person_alice(), 3).into())?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut merged, 500).await,
+        StreamItem::Error(FluxionError::ResourceLimitExceeded { limit: 2, .. })
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        animal_dog()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_alice()
+    );
+    assert_no_element_emitted(&mut merged, 100).await;
+    assert!(tx3.is_closed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ordered_merge_heapless_propagates_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut merged = pin!(stream1.ordered_merge_heapless::<2>([stream2]));
+
+    // Act
+    tx2.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut merged, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+
+    // Act
+    tx1.unbounded_send(StreamItem::Value((person_alice(), 1).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value,
+        person_alice()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::pin::pin;
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::heapless::WindowByCountHeaplessExt;
+use fluxion_test_utils::{
+    helpers::{
+        assert_no_element_emitted, assert_stream_ended, test_channel, test_channel_with_errors,
+        unwrap_stream, unwrap_value,
+    },
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, person_charlie, plant_rose, TestData},
+};
+
+type Window<const N: usize> = Sequenced<::heapless::Vec<TestData, N>>;
+
+#[tokio::test]
+async fn test_window_by_count_heapless_emits_full_windows() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut windowed = pin!(stream.window_by_count_heapless::<2, Window<2>>());
+
+    // Act
+    tx.unbounded_send((person_alice(), 1).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut windowed, 100).await;
+
+    // Act
+    tx.unbounded_send((person_bob(), 2).into())?;
+
+    // Assert
+    let window = unwrap_value(Some(unwrap_stream(&mut windowed, 500).await));
+    assert_eq!(window.value.as_slice(), [person_alice(), person_bob()]);
+    assert_eq!(window.timestamp(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_count_heapless_flushes_partial_window_on_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut windowed = pin!(stream.window_by_count_heapless::<2, Window<2>>());
+
+    // Act
+    tx.unbounded_send((person_alice(), 1).into())?;
+    tx.unbounded_send((person_bob(), 2).into())?;
+    tx.unbounded_send((person_charlie(), 3).into())?;
+    drop(tx);
+
+    // Assert
+    let window = unwrap_value(Some(unwrap_stream(&mut windowed, 500).await));
+    assert_eq!(window.value.as_slice(), [person_alice(), person_bob()]);
+    let window = unwrap_value(Some(unwrap_stream(&mut windowed, 500).await));
+    assert_eq!(window.value.as_slice(), [person_charlie()]);
+    assert_eq!(window.timestamp(), 3);
+    assert_stream_ended(&mut windowed, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_count_heapless_error_discards_window() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut windowed = pin!(stream.window_by_count_heapless::<2, Window<2>>());
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((person_alice(), 1).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut windowed, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((animal_dog(), 2).into()))?;
+    tx.unbounded_send(StreamItem::Value((plant_rose(), 3).into()))?;
+
+    // Assert
+    let window = unwrap_value(Some(unwrap_stream(&mut windowed, 500).await));
+    assert_eq!(window.value.as_slice(), [animal_dog(), plant_rose()]);
+
+    Ok(())
+}
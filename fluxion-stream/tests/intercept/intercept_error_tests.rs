@@ -0,0 +1,72 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::intercept::{InterceptExt, Interceptor};
+use fluxion_stream::FilterOrderedExt;
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::fmt::Debug;
+
+/// Replaces errors leaving a stage with nothing, as a policy that contains failures.
+#[derive(Clone)]
+struct SwallowErrors;
+
+impl Interceptor for SwallowErrors {
+    fn on_output<T: HasTimestamp + Debug>(
+        &self,
+        _stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        match item {
+            StreamItem::Error(_) => None,
+            value => Some(value),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_intercept_passes_errors_to_the_interceptor() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream
+        .intercept(SwallowErrors)
+        .stage("positive", |s| s.filter_ordered(|x| *x > 0));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_intercept_default_hooks_pass_errors_through() -> anyhow::Result<()> {
+    // Arrange
+    #[derive(Clone)]
+    struct PassThrough;
+    impl Interceptor for PassThrough {}
+
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream
+        .intercept(PassThrough)
+        .stage("positive", |s| s.filter_ordered(|x| *x > 0));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::intercept::{InterceptExt, Interceptor};
+use fluxion_stream::{FilterOrderedExt, MapOrderedExt, Pipeline};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::Stream;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Records every boundary crossing as `"<name> <stage> <in|out> <item>"`.
+#[derive(Clone)]
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Recorder {
+    fn new(name: &'static str, log: &Arc<Mutex<Vec<String>>>) -> Self {
+        Self {
+            name,
+            log: Arc::clone(log),
+        }
+    }
+
+    fn record<T: Debug>(&self, stage: &str, side: &str, item: &StreamItem<T>) {
+        let item = match item {
+            StreamItem::Value(value) => format!("{value:?}"),
+            StreamItem::Error(_) => "error".to_string(),
+        };
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} {stage} {side} {item}", self.name));
+    }
+}
+
+impl Interceptor for Recorder {
+    fn on_input<T: HasTimestamp + Debug>(
+        &self,
+        stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        self.record(stage, "in", &item);
+        Some(item)
+    }
+
+    fn on_output<T: HasTimestamp + Debug>(
+        &self,
+        stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        self.record(stage, "out", &item);
+        Some(item)
+    }
+}
+
+/// Lets at most `limit` values into the stages and drops the rest, as a quota policy.
+#[derive(Clone)]
+struct Quota {
+    limit: usize,
+    admitted: Arc<AtomicUsize>,
+}
+
+impl Interceptor for Quota {
+    fn on_input<T: HasTimestamp + Debug>(
+        &self,
+        _stage: &'static str,
+        item: StreamItem<T>,
+    ) -> Option<StreamItem<T>> {
+        match item {
+            StreamItem::Value(_) if self.admitted.fetch_add(1, Ordering::SeqCst) >= self.limit => {
+                None
+            }
+            item => Some(item),
+        }
+    }
+}
+
+fn double(
+    stream: impl Stream<Item = StreamItem<Sequenced<i32>>> + Send + Sync + Unpin + 'static,
+) -> impl Stream<Item = StreamItem<Sequenced<i32>>> + Send + Sync {
+    stream.map_ordered(|item| Sequenced::with_timestamp(item.value * 2, item.timestamp()))
+}
+
+#[tokio::test]
+async fn test_intercept_sees_input_and_output_of_every_stage() -> anyhow::Result<()> {
+    // Arrange
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .intercept(Recorder::new("log", &log))
+        .stage("positive", |s| s.filter_ordered(|x| *x > 0))
+        .stage_with("double", Pipeline::new("double", double));
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(-1, 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(3, 2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        6
+    );
+    let entries: Vec<_> = log
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|entry| {
+            entry
+                .split_whitespace()
+                .take(3)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            "log positive in",
+            "log positive in",
+            "log positive out",
+            "log double in",
+            "log double out",
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_intercept_drops_items_returned_as_none() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .intercept(Quota {
+            limit: 1,
+            admitted: Arc::new(AtomicUsize::new(0)),
+        })
+        .stage("double", double);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(2, 2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        2
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_intercept_layers_nest_like_an_onion() -> anyhow::Result<()> {
+    // Arrange
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .intercept((Recorder::new("outer", &log), Recorder::new("inner", &log)))
+        .stage("double", double);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    let entries: Vec<_> = log
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|entry| {
+            entry
+                .split_whitespace()
+                .take(3)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            "outer double in",
+            "inner double in",
+            "inner double out",
+            "outer double out",
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_intercept_into_inner_ends_interception() -> anyhow::Result<()> {
+    // Arrange
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .intercept(Recorder::new("log", &log))
+        .stage("double", double)
+        .into_inner()
+        .filter_ordered(|x| *x > 0);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        2
+    );
+    assert_eq!(log.lock().unwrap().len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,267 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{IntoFluxionStream, OrderedStreamExt};
+use fluxion_test_utils::helpers::{assert_stream_ended, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_std_mpsc_receiver() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, rx) = std::sync::mpsc::channel::<Sequenced<TestData>>();
+    let mut stream = rx.into_fluxion_stream();
+
+    // Act
+    tx.send(Sequenced::new(person_alice()))?;
+    tx.send(Sequenced::new(person_bob()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_bob()
+    );
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_std_mpsc_receiver_with_map() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, rx) = std::sync::mpsc::sync_channel::<TestData>(1);
+    let mut stream = rx.into_fluxion_stream_map(|data| Sequenced::with_timestamp(data, 7));
+
+    // Act
+    tx.send(animal_dog())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)),
+        Sequenced::with_timestamp(animal_dog(), 7)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tokio_bounded_receiver() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, rx) = tokio::sync::mpsc::channel::<Sequenced<TestData>>(4);
+    let mut stream = rx.into_fluxion_stream();
+
+    // Act
+    tx.send(Sequenced::new(person_alice())).await?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_alice()
+    );
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tokio_unbounded_receiver_composes_with_operators() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, rx1) = tokio::sync::mpsc::unbounded_channel::<Sequenced<TestData>>();
+    let (tx2, rx2) = std::sync::mpsc::channel::<Sequenced<TestData>>();
+    let mut merged = rx1
+        .into_fluxion_stream()
+        .ordered_merge(vec![rx2.into_fluxion_stream()]);
+
+    // Act
+    tx2.send(Sequenced::with_timestamp(person_bob(), 2))?;
+    tx1.send(Sequenced::with_timestamp(person_alice(), 1))?;
+    drop(tx1);
+    drop(tx2);
+
+    // Assert
+    let mut values = vec![
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).into_inner(),
+        unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).into_inner(),
+    ];
+    values.sort();
+    assert_eq!(values, vec![person_alice(), person_bob()]);
+    assert_stream_ended(&mut merged, 500).await;
+
+    Ok(())
+}
+
+#[cfg(feature = "flume")]
+#[tokio::test]
+async fn test_flume_receiver() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, rx) = flume::bounded::<Sequenced<TestData>>(2);
+    let mut stream = rx.into_fluxion_stream();
+
+    // Act
+    tx.send_async(Sequenced::new(person_alice())).await?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_alice()
+    );
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[cfg(feature = "crossbeam-channel")]
+#[tokio::test]
+async fn test_crossbeam_receiver() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, rx) = crossbeam_channel::unbounded::<Sequenced<TestData>>();
+    let mut stream = rx.into_fluxion_stream();
+
+    // Act
+    tx.send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_alice()
+    );
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[cfg(feature = "kanal")]
+#[tokio::test]
+async fn test_kanal_receivers() -> anyhow::Result<()> {
+    // Arrange
+    let (sync_tx, sync_rx) = kanal::unbounded::<Sequenced<TestData>>();
+    let (async_tx, async_rx) = kanal::unbounded_async::<Sequenced<TestData>>();
+    let mut from_sync = sync_rx.into_fluxion_stream();
+    let mut from_async = async_rx.into_fluxion_stream();
+
+    // Act
+    sync_tx.send(Sequenced::new(person_alice()))?;
+    async_tx.send(Sequenced::new(person_bob())).await?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut from_sync, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut from_async, 500).await)).value,
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "embassy-sync")]
+#[tokio::test]
+async fn test_embassy_sync_channel_receiver() -> anyhow::Result<()> {
+    use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+    use embassy_sync::channel::Channel;
+    use fluxion_core::HasTimestamp;
+
+    // Arrange
+    static CHANNEL: Channel<CriticalSectionRawMutex, TestData, 4> = Channel::new();
+    let mut stream = CHANNEL
+        .receiver()
+        .into_fluxion_stream_map(|data| Sequenced::with_timestamp(data, 3));
+
+    // Act
+    CHANNEL.send(person_alice()).await;
+    CHANNEL.send(person_bob()).await;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut stream, 500).await));
+    assert_eq!(first.timestamp(), 3);
+    assert_eq!(first.value, person_alice());
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "embassy-sync")]
+#[tokio::test]
+async fn test_embassy_sync_signal_emits_latest_value() -> anyhow::Result<()> {
+    use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+    use embassy_sync::signal::Signal;
+    use fluxion_test_utils::helpers::assert_no_element_emitted;
+
+    // Arrange
+    static SIGNAL: Signal<CriticalSectionRawMutex, Sequenced<TestData>> = Signal::new();
+    let mut stream = SIGNAL.into_fluxion_stream();
+
+    // Act
+    SIGNAL.signal(Sequenced::new(person_alice()));
+    SIGNAL.signal(Sequenced::new(person_bob()));
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_bob()
+    );
+    assert_no_element_emitted(&mut stream, 100).await;
+
+    // Act
+    SIGNAL.signal(Sequenced::new(animal_dog()));
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "embassy-sync")]
+#[tokio::test]
+async fn test_embassy_sync_pubsub_subscriber_reports_lag() -> anyhow::Result<()> {
+    use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+    use embassy_sync::pubsub::PubSubChannel;
+    use fluxion_core::{FluxionError, StreamItem};
+
+    // Arrange
+    static PUBSUB: PubSubChannel<CriticalSectionRawMutex, Sequenced<TestData>, 2, 1, 1> =
+        PubSubChannel::new();
+    let publisher = PUBSUB.immediate_publisher();
+    let mut stream = PUBSUB
+        .subscriber()
+        .map_err(|e| anyhow::anyhow!("{e:?}"))?
+        .into_fluxion_stream();
+
+    // Act
+    publisher.publish_immediate(Sequenced::new(person_alice()));
+    publisher.publish_immediate(Sequenced::new(person_bob()));
+    publisher.publish_immediate(Sequenced::new(animal_dog()));
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut stream, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{ErrorPolicy, IntoValuesExt};
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel_with_errors},
+    sequenced::Sequenced,
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn test_into_values_skip_drops_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.into_values(ErrorPolicy::Skip);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+
+    // Assert
+    assert_eq!(result.next().await, Some(1));
+    assert_eq!(result.next().await, Some(2));
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_into_values_terminate_ends_at_first_error() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.into_values(ErrorPolicy::Terminate);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+
+    // Assert
+    assert_eq!(result.next().await, Some(1));
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(result.next().await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "into_values: stream error")]
+async fn test_into_values_panic_panics_on_error() {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.into_values(ErrorPolicy::Panic);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))
+        .unwrap();
+
+    // Assert
+    result.next().await;
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{ErrorPolicy, IntoValuesExt, MapOrderedExt};
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel},
+    sequenced::Sequenced,
+    test_data::{person_alice, person_bob, TestData},
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn test_into_values_emits_inner_values() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.into_values(ErrorPolicy::Skip);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 5))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 9))?;
+
+    // Assert
+    assert_eq!(result.next().await, Some(person_alice()));
+    assert_eq!(result.next().await, Some(person_bob()));
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_into_values_ends_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.into_values(ErrorPolicy::Terminate);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(result.next().await, Some(1));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_into_values_after_operator_chain() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let result = stream
+        .map_ordered(|value: Sequenced<i32>| Sequenced::new(value.into_inner() * 10))
+        .into_values(ErrorPolicy::Panic);
+
+    // Act
+    for value in 1..=3 {
+        tx.unbounded_send(Sequenced::new(value))?;
+    }
+    drop(tx);
+
+    // Assert
+    assert_eq!(result.collect::<Vec<_>>().await, vec![10, 20, 30]);
+
+    Ok(())
+}
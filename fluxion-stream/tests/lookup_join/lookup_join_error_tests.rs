@@ -0,0 +1,92 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `lookup_join` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::LookupJoinExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+use std::io::{Error, ErrorKind};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_lookup_join_failed_lookup_is_emitted_and_not_cached() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let mut result = stream.lookup_join::<Sequenced<(u64, u64)>, _, _, _, _, _>(
+        |id| *id,
+        {
+            let attempts = attempts.clone();
+            move |id| {
+                let first = attempts.fetch_add(1, Ordering::SeqCst) == 0;
+                async move {
+                    if first {
+                        Err(Error::from(ErrorKind::ConnectionRefused))
+                    } else {
+                        Ok(id * 100)
+                    }
+                }
+            }
+        },
+        10u64,
+        1,
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(7, 1)))?;
+
+    // Assert
+    match unwrap_stream(&mut result, 500).await {
+        StreamItem::Error(error) => assert_eq!(
+            error.downcast_ref::<Error>().map(Error::kind),
+            Some(ErrorKind::ConnectionRefused)
+        ),
+        StreamItem::Value(_) => panic!("expected an error"),
+    }
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(7, 2)))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        (7, 700)
+    );
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lookup_join_propagates_upstream_error() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.lookup_join::<Sequenced<(u64, u64)>, _, _, _, _, _>(
+        |id| *id,
+        |id| async move { Ok::<_, Error>(id * 100) },
+        10u64,
+        2,
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("upstream")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(7, 1)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        (7, 700)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::HasTimestamp;
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+use std::io::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::time::sleep;
+
+async fn delayed_name(id: u64) -> Result<String, Error> {
+    // Lower ids take longer, so they would finish last without ordering
+    sleep(Duration::from_millis(40 - id * 10)).await;
+    Ok(format!("user-{id}"))
+}
+
+fn counted_lookup(
+    lookups: &Arc<AtomicUsize>,
+) -> impl Fn(u64) -> futures::future::Ready<Result<u64, Error>> + Send + Sync + 'static {
+    let lookups = lookups.clone();
+    move |id| {
+        lookups.fetch_add(1, Ordering::SeqCst);
+        futures::future::ready(Ok(id * 100))
+    }
+}
+
+#[tokio::test]
+async fn test_lookup_join_emits_in_source_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.lookup_join::<Sequenced<(u64, String)>, _, _, _, _, _>(
+        |id| *id,
+        delayed_name,
+        100u64,
+        3,
+    );
+
+    // Act
+    for id in 1..=3 {
+        tx.unbounded_send(Sequenced::with_timestamp(id, id * 10))?;
+    }
+
+    // Assert
+    for id in 1..=3 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(item.value, (id, format!("user-{id}")));
+        assert_eq!(item.timestamp(), id * 10);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lookup_join_reuses_cached_result_within_ttl() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let mut result = stream.lookup_join::<Sequenced<(u64, u64)>, _, _, _, _, _>(
+        |id| *id,
+        counted_lookup(&lookups),
+        10u64,
+        1,
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(7, 100))?;
+    tx.unbounded_send(Sequenced::with_timestamp(7, 110))?;
+
+    // Assert
+    for _ in 0..2 {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            (7, 700)
+        );
+    }
+    assert_eq!(lookups.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lookup_join_looks_up_again_after_ttl() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let mut result = stream.lookup_join::<Sequenced<(u64, u64)>, _, _, _, _, _>(
+        |id| *id,
+        counted_lookup(&lookups),
+        10u64,
+        1,
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(7, 100))?;
+    tx.unbounded_send(Sequenced::with_timestamp(8, 105))?;
+    tx.unbounded_send(Sequenced::with_timestamp(7, 111))?;
+
+    // Assert
+    for expected in [(7, 700), (8, 800), (7, 700)] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_eq!(lookups.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lookup_join_ends_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let mut result = stream.lookup_join::<Sequenced<(u64, u64)>, _, _, _, _, _>(
+        |id| *id,
+        counted_lookup(&lookups),
+        10u64,
+        2,
+    );
+
+    // Act
+    drop(tx);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(lookups.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Loom model tests for operators with shared state.
+//!
+//! Run with:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg fluxion_loom" cargo test -p fluxion-stream --test loom_tests --release
+//! ```
+
+#![cfg(fluxion_loom)]
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::{ShareExt, TakeLatestWhenExt};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::channel::mpsc::unbounded;
+use futures::StreamExt;
+use loom::future::block_on;
+use loom::model::Builder;
+use loom::thread;
+
+fn model(test: impl Fn() + Sync + Send + 'static) {
+    let mut builder = Builder::new();
+    builder.preemption_bound = Some(3);
+    builder.check(test);
+}
+
+fn values(items: Vec<StreamItem<Sequenced<i32>>>) -> Vec<(i32, u64)> {
+    items
+        .into_iter()
+        .map(|item| {
+            let value = item.unwrap();
+            (value.value, value.timestamp())
+        })
+        .collect()
+}
+
+#[test]
+fn loom_take_latest_when_never_emits_stale_or_unfiltered_values() {
+    model(|| {
+        // Arrange
+        let (source_tx, source_rx) = unbounded::<StreamItem<Sequenced<i32>>>();
+        let (filter_tx, filter_rx) = unbounded::<StreamItem<Sequenced<i32>>>();
+
+        let source = thread::spawn(move || {
+            for (value, timestamp) in [(10, 1), (20, 3)] {
+                source_tx
+                    .unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+                        value, timestamp,
+                    )))
+                    .unwrap();
+            }
+        });
+        let filter = thread::spawn(move || {
+            for (value, timestamp) in [(1, 2), (0, 4), (1, 5)] {
+                filter_tx
+                    .unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+                        value, timestamp,
+                    )))
+                    .unwrap();
+            }
+        });
+
+        // Act
+        let output = block_on(
+            source_rx
+                .take_latest_when(filter_rx, |flag| *flag == 1)
+                .collect::<Vec<_>>(),
+        );
+
+        // Assert
+        source.join().unwrap();
+        filter.join().unwrap();
+
+        // ordered_merge only orders what is ready, so which source value is the latest
+        // when a filter item arrives depends on the interleaving. What must hold in
+        // every interleaving: only passing filter items trigger, in order, and the
+        // sampled value never goes back to an older one.
+        let output = values(output);
+        assert!(output.len() <= 2);
+        assert!(output
+            .iter()
+            .all(|(_, timestamp)| [2, 5].contains(timestamp)));
+        assert!(output.windows(2).all(|pair| pair[0].1 < pair[1].1));
+        assert!(output.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    });
+}
+
+#[test]
+fn loom_share_delivers_every_item_to_every_subscriber() {
+    model(|| {
+        // Arrange
+        let (tx, rx) = unbounded::<StreamItem<Sequenced<i32>>>();
+        let shared = rx.share();
+        let first = shared.subscribe().unwrap();
+        let second = shared.subscribe().unwrap();
+
+        let producer = thread::spawn(move || {
+            for value in 1..=2 {
+                tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+                    value,
+                    value as u64,
+                )))
+                .unwrap();
+            }
+        });
+
+        // Act
+        let reader = thread::spawn(move || block_on(first.collect::<Vec<_>>()));
+        let second_items = block_on(second.collect::<Vec<_>>());
+        let first_items = reader.join().unwrap();
+
+        // Assert
+        producer.join().unwrap();
+        assert_eq!(values(first_items), vec![(1, 1), (2, 2)]);
+        assert_eq!(values(second_items), vec![(1, 1), (2, 2)]);
+        drop(shared);
+    });
+}
+
+#[test]
+fn loom_share_drop_completes_pending_subscribers() {
+    model(|| {
+        // Arrange
+        let (tx, rx) = unbounded::<StreamItem<Sequenced<i32>>>();
+        let shared = rx.share();
+        let subscriber = shared.subscribe().unwrap();
+
+        // Act
+        let reader = thread::spawn(move || block_on(subscriber.collect::<Vec<_>>()));
+        drop(shared);
+
+        // Assert
+        assert!(reader.join().unwrap().is_empty());
+        drop(tx);
+    });
+}
@@ -0,0 +1,65 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::MapBatchExt;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_map_batch_passes_errors_in_batch() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.map_batch(8, |batch, output| {
+        output.extend(
+            batch
+                .drain(..)
+                .map(|item| item.map(|value| Sequenced::new(value.value + 1))),
+        );
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(value) if value.value == 2
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(value) if value.value == 3
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_batch_can_drop_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.filter_batch(8, |batch| batch.retain(StreamItem::is_value));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    drop(tx);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(value) if value.value == 1
+    ));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::MapBatchExt;
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_map_batch_transforms_values_preserving_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.map_batch(8, |batch, output| {
+        output.extend(batch.drain(..).map(|item| {
+            item.map(|value| Sequenced::with_timestamp(value.value * 10, value.timestamp()))
+        }));
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 5))?;
+    tx.unbounded_send(Sequenced::with_timestamp(2, 7))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)),
+        Sequenced::with_timestamp(10, 5)
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)),
+        Sequenced::with_timestamp(20, 7)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_map_batch_batches_ready_items_up_to_max() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let sizes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&sizes);
+    let mut result = stream.map_batch(2, move |batch, output| {
+        recorded.lock().unwrap().push(batch.len());
+        output.append(batch);
+    });
+
+    // Act
+    for value in 1..=5 {
+        tx.unbounded_send(Sequenced::new(value))?;
+    }
+    drop(tx);
+
+    // Assert
+    for value in 1..=5 {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            value
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(*sizes.lock().unwrap(), vec![2, 2, 1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_map_batch_does_not_wait_for_full_batch() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.map_batch(1024, |batch, output| output.append(batch));
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send(Sequenced::new(2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_map_batch_may_emit_more_or_fewer_items_than_batch() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.map_batch(8, |batch, output| {
+        // Duplicate odd values, leave even ones behind to be dropped
+        for item in batch.iter() {
+            if let StreamItem::Value(value) = item {
+                if value.value % 2 == 1 {
+                    output.push(item.clone());
+                    output.push(item.clone());
+                }
+            }
+        }
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+    tx.unbounded_send(Sequenced::new(3))?;
+    drop(tx);
+
+    // Assert
+    for expected in [1, 1, 3, 3] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_batch_emits_retained_items_in_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.filter_batch(4, |batch| {
+        batch.retain(|item| matches!(item, StreamItem::Value(value) if value.value > 2));
+    });
+
+    // Act
+    for value in [3, 1, 4, 1, 5, 2, 6] {
+        tx.unbounded_send(Sequenced::new(value))?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in [3, 4, 5, 6] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_batch_dropping_whole_batch_waits_for_more() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.filter_batch(4, |batch| {
+        batch.retain(|item| matches!(item, StreamItem::Value(value) if value.value >= 0));
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::new(-1))?;
+    tx.unbounded_send(Sequenced::new(-2))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send(Sequenced::new(7))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        7
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "map_batch: batch size must be at least 1")]
+async fn test_map_batch_panics_on_zero_batch_size() {
+    let (_tx, stream) = test_channel::<Sequenced<i32>>();
+    let _ = stream.map_batch(0, |batch: &mut Vec<_>, output: &mut Vec<_>| {
+        output.append(batch)
+    });
+}
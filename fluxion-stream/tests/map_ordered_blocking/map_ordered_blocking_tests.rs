@@ -0,0 +1,73 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+use std::thread;
+use std::time::Duration;
+
+fn slow_double(item: Sequenced<u64>) -> Sequenced<u64> {
+    // Earlier items take longer, so they would finish last without ordering
+    thread::sleep(Duration::from_millis(40 - item.value * 10));
+    Sequenced::with_timestamp(item.value * 2, item.timestamp())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_map_ordered_blocking_runs_off_the_executor_thread() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let executor_thread = thread::current().id();
+    let mut result = stream.map_ordered_blocking(move |item: Sequenced<u64>| {
+        Sequenced::with_timestamp(thread::current().id() != executor_thread, item.timestamp())
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 10)))?;
+
+    // Assert
+    let item = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((item.value, item.timestamp()), (true, 10));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_map_ordered_blocking_concurrent_emits_in_source_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.map_ordered_blocking_concurrent(3, slow_double);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(3, 3)))?;
+    drop(tx);
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.value, first.timestamp()), (2, 1));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    for expected in 2..=3 {
+        let item = unwrap_stream(&mut result, 500).await.unwrap();
+        assert_eq!((item.value, item.timestamp()), (expected * 2, expected));
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "limit of at least 1")]
+fn test_map_ordered_blocking_concurrent_rejects_zero_limit() {
+    let (_tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let _ = stream.map_ordered_blocking_concurrent(0, |item: Sequenced<u64>| item);
+}
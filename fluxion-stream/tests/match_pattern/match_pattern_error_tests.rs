@@ -0,0 +1,41 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `match_pattern` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{MatchPatternExt, Pattern};
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_match_pattern_error_keeps_partial_matches() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u32>>();
+    let pattern = Pattern::begin(|value: &u32| *value == 1).then(|value: &u32| *value == 2);
+    let mut result = stream.match_pattern::<Sequenced<Vec<u32>>, _>(pattern);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((1, 1).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("glitch")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((2, 2).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![1, 2]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{MatchPatternExt, Pattern, SkipStrategy};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+type Event = (&'static str, u32);
+
+fn kind(name: &'static str) -> impl Fn(&Event) -> bool + Send + Sync + 'static {
+    move |event| event.0 == name
+}
+
+#[tokio::test]
+async fn test_match_pattern_matches_sequence_skipping_other_events() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Event>>();
+    let pattern = Pattern::begin(kind("a")).then(kind("b")).then(kind("c"));
+    let mut result = stream.match_pattern::<Sequenced<Vec<Event>>, _>(pattern);
+
+    // Act
+    tx.unbounded_send((("a", 1), 1).into())?;
+    tx.unbounded_send((("x", 2), 2).into())?;
+    tx.unbounded_send((("b", 3), 3).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((("c", 4), 4).into())?;
+
+    // Assert
+    let matched = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(matched.value, vec![("a", 1), ("b", 3), ("c", 4)]);
+    assert_eq!(matched.timestamp(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_match_pattern_without_discards_partial_match() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Event>>();
+    let pattern = Pattern::begin(kind("a")).without(kind("c")).then(kind("b"));
+    let mut result = stream.match_pattern::<Sequenced<Vec<Event>>, _>(pattern);
+
+    // Act
+    tx.unbounded_send((("a", 1), 1).into())?;
+    tx.unbounded_send((("c", 2), 2).into())?;
+    tx.unbounded_send((("b", 3), 3).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((("a", 4), 4).into())?;
+    tx.unbounded_send((("b", 5), 5).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 4), ("b", 5)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_match_pattern_within_discards_expired_partial_match() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Event>>();
+    let pattern = Pattern::begin(kind("a")).then(kind("b")).within(5u64);
+    let mut result = stream.match_pattern::<Sequenced<Vec<Event>>, _>(pattern);
+
+    // Act
+    tx.unbounded_send((("a", 1), 10).into())?;
+    tx.unbounded_send((("b", 2), 16).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((("a", 3), 20).into())?;
+    tx.unbounded_send((("b", 4), 25).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 3), ("b", 4)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_match_pattern_no_skip_emits_overlapping_matches() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Event>>();
+    let pattern = Pattern::begin(kind("a")).then(kind("b"));
+    let mut result = stream.match_pattern::<Sequenced<Vec<Event>>, _>(pattern);
+
+    // Act
+    tx.unbounded_send((("a", 1), 1).into())?;
+    tx.unbounded_send((("a", 2), 2).into())?;
+    tx.unbounded_send((("b", 3), 3).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 1), ("b", 3)]
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 2), ("b", 3)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_match_pattern_skip_past_last_event_does_not_reuse_events() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Event>>();
+    let pattern = Pattern::begin(kind("a"))
+        .then(kind("b"))
+        .skip(SkipStrategy::SkipPastLastEvent);
+    let mut result = stream.match_pattern::<Sequenced<Vec<Event>>, _>(pattern);
+
+    // Act
+    tx.unbounded_send((("a", 1), 1).into())?;
+    tx.unbounded_send((("a", 2), 2).into())?;
+    tx.unbounded_send((("b", 3), 3).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 1), ("b", 3)]
+    );
+
+    // Act
+    tx.unbounded_send((("b", 4), 4).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_match_pattern_single_step_matches_every_event() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Event>>();
+    let mut result = stream.match_pattern::<Sequenced<Vec<Event>>, _>(Pattern::begin(kind("a")));
+
+    // Act
+    tx.unbounded_send((("a", 1), 1).into())?;
+    tx.unbounded_send((("b", 2), 2).into())?;
+    tx.unbounded_send((("a", 3), 3).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 1)]
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![("a", 3)]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "match_pattern requires a step after `without`")]
+fn test_match_pattern_rejects_trailing_without() {
+    // Arrange
+    let (_tx, stream) = test_channel::<Sequenced<Event>>();
+    let pattern = Pattern::begin(kind("a")).then(kind("b")).without(kind("c"));
+
+    // Act
+    let _ = stream.match_pattern::<Sequenced<Vec<Event>>, _>(pattern.within(5u64));
+}
@@ -0,0 +1,37 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `materialize` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::MaterializeExt;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_materialized_view_error_ends_source_and_reaches_subscribers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<(u32, Option<u32>)>>();
+    let view = stream.materialize(|(key, value)| (key, value));
+    let mut changes = view.subscribe()?;
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new((1, Some(10)))))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("changelog")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new((1, Some(20)))))?;
+
+    // Assert
+    unwrap_stream(&mut changes, 500).await;
+    assert!(matches!(
+        unwrap_stream(&mut changes, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_stream_ended(&mut changes, 500).await;
+    assert!(view.is_closed());
+    assert_eq!(view.get(&1).await, Some(10));
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::{StreamItem, SubjectError};
+use fluxion_stream::{MaterializeExt, ViewChange};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use tokio::time::sleep;
+
+type Change = (u32, Option<&'static str>);
+
+#[tokio::test]
+async fn test_materialized_view_applies_upserts_and_deletes() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Change>>();
+    let view = stream.materialize(|(key, value)| (key, value));
+
+    // Act
+    tx.unbounded_send(Sequenced::new((1, Some("a"))))?;
+    tx.unbounded_send(Sequenced::new((2, Some("b"))))?;
+    tx.unbounded_send(Sequenced::new((1, Some("c"))))?;
+    tx.unbounded_send(Sequenced::new((2, None)))?;
+    sleep(Duration::from_millis(100)).await;
+
+    // Assert
+    assert_eq!(view.get(&1).await, Some("c"));
+    assert_eq!(view.get(&2).await, None);
+    assert_eq!(view.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_materialized_view_range_query() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Change>>();
+    let view = stream.materialize(|(key, value)| (key, value));
+
+    // Act
+    for key in [5, 1, 3, 4, 2] {
+        tx.unbounded_send(Sequenced::new((key, Some("x"))))?;
+    }
+    sleep(Duration::from_millis(100)).await;
+
+    // Assert
+    assert_eq!(view.range(2..=4).await, vec![(2, "x"), (3, "x"), (4, "x")]);
+    assert_eq!(view.range(4..).await, vec![(4, "x"), (5, "x")]);
+    assert!(view.range(6..).await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_materialized_view_subscribers_receive_later_changes() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Change>>();
+    let view = stream.materialize(|(key, value)| (key, value));
+
+    tx.unbounded_send(Sequenced::new((1, Some("a"))))?;
+    sleep(Duration::from_millis(100)).await;
+    let mut changes = view.subscribe()?;
+
+    // Act
+    tx.unbounded_send(Sequenced::new((2, Some("b"))))?;
+    tx.unbounded_send(Sequenced::new((3, None)))?;
+    tx.unbounded_send(Sequenced::new((1, None)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut changes, 500).await,
+        StreamItem::Value(ViewChange::Upsert(2, "b"))
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut changes, 500).await,
+        StreamItem::Value(ViewChange::Delete(1, "a"))
+    ));
+    assert_no_element_emitted(&mut changes, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_materialized_view_keeps_state_after_source_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Change>>();
+    let view = stream.materialize(|(key, value)| (key, value));
+    let mut changes = view.subscribe()?;
+
+    // Act
+    tx.unbounded_send(Sequenced::new((1, Some("a"))))?;
+    drop(tx);
+
+    // Assert
+    unwrap_stream(&mut changes, 500).await;
+    assert_stream_ended(&mut changes, 500).await;
+    assert!(view.is_closed());
+    assert_eq!(view.clone().get(&1).await, Some("a"));
+    assert!(matches!(view.subscribe(), Err(SubjectError::Closed)));
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `within_memory_budget` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{MemoryBudget, MemoryBudgetExt, MemoryPolicy, ReorderExt, ReorderWindow};
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_memory_budget_source_error_reserves_nothing() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u32>>();
+    let budget = MemoryBudget::new(8, MemoryPolicy::Error);
+    let mut result = stream.within_memory_budget(
+        &budget,
+        |_| 8,
+        |stream| stream.reorder(ReorderWindow::Items(0)),
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("source")))?;
+    tx.unbounded_send(StreamItem::Value((1, 1).into()))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert_eq!(budget.used(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,254 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::{
+    BufferWhenExt, FilterOrderedExt, MemoryBudget, MemoryBudgetExt, MemoryPolicy, ReorderExt,
+    ReorderWindow, SpillCodec, SpillConfig, SpillToDiskExt,
+};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::fs;
+
+const ITEM: usize = 8;
+
+struct U64Codec;
+
+impl SpillCodec<Sequenced<u64>> for U64Codec {
+    fn encode(&self, item: &Sequenced<u64>, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&item.value.to_le_bytes());
+        buf.extend_from_slice(&item.timestamp().to_le_bytes());
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Sequenced<u64>, FluxionError> {
+        let field = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        Ok(Sequenced::with_timestamp(field(0), field(8)))
+    }
+}
+
+#[tokio::test]
+async fn test_memory_budget_error_policy_reports_exhaustion() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let budget = MemoryBudget::new(2 * ITEM, MemoryPolicy::Error);
+    let mut result = stream.within_memory_budget(
+        &budget,
+        |_| ITEM,
+        |stream| stream.reorder(ReorderWindow::Items(8)),
+    );
+
+    // Act
+    for timestamp in [1, 2, 3] {
+        tx.unbounded_send((timestamp, timestamp).into())?;
+    }
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::ResourceLimitExceeded { limit: 16, .. })
+    ));
+    assert_eq!(budget.used(), 2 * ITEM);
+
+    // Act
+    drop(tx);
+
+    // Assert
+    for expected in [1, 2] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(budget.used(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_budget_drop_policy_discards_items_that_do_not_fit() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let (tx_gate, gate) = test_channel::<Sequenced<u64>>();
+    let budget = MemoryBudget::new(2 * ITEM, MemoryPolicy::Drop);
+    let mut result = stream.within_memory_budget(
+        &budget,
+        |_| ITEM,
+        |stream| stream.buffer_when(gate, |open| *open == 1),
+    );
+
+    // Act
+    for timestamp in [1, 2, 3] {
+        tx.unbounded_send((timestamp, timestamp).into())?;
+    }
+    tx_gate.unbounded_send((1, 4).into())?;
+
+    // Assert
+    for expected in [1, 2] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+    assert_eq!(budget.used(), 0);
+
+    // Act
+    tx.unbounded_send((5, 5).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        5
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_budget_backpressure_policy_holds_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let budget = MemoryBudget::new(2 * ITEM, MemoryPolicy::Backpressure);
+    let mut result = stream.within_memory_budget(
+        &budget,
+        |_| ITEM,
+        |stream| stream.reorder(ReorderWindow::Items(1)),
+    );
+
+    // Act
+    for timestamp in [3, 1, 2, 4] {
+        tx.unbounded_send((timestamp, timestamp).into())?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in [1, 2, 3, 4] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+        assert!(budget.used() <= 2 * ITEM);
+    }
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(budget.used(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_budget_backpressure_releases_items_filtered_out_by_stage() -> anyhow::Result<()>
+{
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let budget = MemoryBudget::new(2 * ITEM, MemoryPolicy::Backpressure);
+    let mut result = stream.within_memory_budget(
+        &budget,
+        |_| ITEM,
+        |stream| stream.filter_ordered(|value| value % 2 == 0),
+    );
+
+    // Act
+    for timestamp in 1..=8 {
+        tx.unbounded_send((timestamp, timestamp).into())?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in [2, 4, 6, 8] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+        assert_eq!(budget.used(), 0);
+    }
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(budget.used(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_budget_is_shared_between_stages() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_a, stream_a) = test_channel::<Sequenced<u64>>();
+    let (tx_b, stream_b) = test_channel::<Sequenced<u64>>();
+    let budget = MemoryBudget::new(3 * ITEM, MemoryPolicy::Error);
+    let mut result_a = stream_a.within_memory_budget(
+        &budget,
+        |_| ITEM,
+        |stream| stream.reorder(ReorderWindow::Items(8)),
+    );
+    let mut result_b = stream_b.within_memory_budget(
+        &budget,
+        |_| ITEM,
+        |stream| stream.reorder(ReorderWindow::Items(8)),
+    );
+
+    // Act
+    tx_a.unbounded_send((1, 1).into())?;
+    tx_a.unbounded_send((2, 2).into())?;
+    tx_b.unbounded_send((3, 3).into())?;
+    tx_b.unbounded_send((4, 4).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result_a, 100).await;
+    assert!(matches!(
+        unwrap_stream(&mut result_b, 500).await,
+        StreamItem::Error(FluxionError::ResourceLimitExceeded { .. })
+    ));
+    assert_eq!(budget.used(), 3 * ITEM);
+
+    // Act
+    drop(result_a);
+
+    // Assert
+    assert_eq!(budget.used(), ITEM);
+    assert!(budget.try_reserve(2 * ITEM));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_budget_backpressure_spills_held_back_items_to_disk() -> anyhow::Result<()> {
+    // Arrange
+    let dir = std::env::temp_dir().join("fluxion-memory-budget-spill");
+    let _ = fs::remove_dir_all(&dir);
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let (tx_gate, gate) = test_channel::<Sequenced<u64>>();
+    let budget = MemoryBudget::new(2 * ITEM, MemoryPolicy::Backpressure);
+    let mut result = stream
+        .spill_to_disk(SpillConfig::new(&dir, 2), U64Codec)
+        .within_memory_budget(
+            &budget,
+            |_| ITEM,
+            |stream| stream.buffer_when(gate, |open| *open == 1),
+        );
+
+    // Act
+    for timestamp in 1..=10 {
+        tx.unbounded_send((timestamp, timestamp).into())?;
+    }
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+    assert_eq!(budget.used(), 2 * ITEM);
+    assert_eq!(fs::read_dir(&dir)?.count(), 1);
+
+    // Act
+    tx_gate.unbounded_send((1, 11).into())?;
+
+    // Assert
+    for expected in 1..=10 {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_eq!(budget.used(), 0);
+
+    Ok(())
+}
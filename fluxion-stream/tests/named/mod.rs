@@ -0,0 +1,8 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+pub mod named_composition_tests;
+pub mod named_error_tests;
+pub mod named_recording_tests;
+pub mod named_tests;
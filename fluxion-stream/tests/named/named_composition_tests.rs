@@ -0,0 +1,67 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::named::PipelineRegistry;
+use fluxion_stream::{FilterOrderedExt, MapOrderedExt, NamedExt, OrderedStreamExt};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, TestData};
+
+#[tokio::test]
+async fn test_named_ordered_merge_records_fan_in() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (people_tx, people) = test_channel::<Sequenced<TestData>>();
+    let (animals_tx, animals) = test_channel::<Sequenced<TestData>>();
+
+    let people = people.named_in(&registry, "people");
+    let animals = animals.named_in(&registry, "animals");
+    let mut result = people
+        .ordered_merge(vec![animals])
+        .named_in(&registry, "merged");
+
+    // Act
+    people_tx.unbounded_send(Sequenced::new(person_alice()))?;
+    animals_tx.unbounded_send(Sequenced::new(animal_dog()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+    let graph = registry.graph();
+    assert_eq!(graph.upstream("merged"), vec!["people", "animals"]);
+    assert_eq!(graph.downstream("people"), vec!["merged"]);
+    assert_eq!(graph.downstream("animals"), vec!["merged"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_skips_unnamed_operators_in_between() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .filter_ordered(|data| matches!(data, TestData::Person(_)))
+        .map_ordered(|item| item)
+        .named_in(&registry, "enrich-orders");
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+    let graph = registry.graph();
+    assert_eq!(graph.upstream("enrich-orders"), vec!["source"]);
+    assert_eq!(graph.node("source").map(|node| node.items), Some(2));
+    assert_eq!(graph.node("enrich-orders").map(|node| node.items), Some(1));
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::named::PipelineRegistry;
+use fluxion_stream::NamedExt;
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_named_passes_through_and_counts_errors() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.named_in(&registry, "source");
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_bob())))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_bob()
+    );
+    let node = registry
+        .graph()
+        .node("source")
+        .cloned()
+        .expect("node registered");
+    assert_eq!(node.items, 2);
+    assert_eq!(node.errors, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_links_stages_on_error() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .named_in(&registry, "sink");
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    let graph = registry.graph();
+    assert_eq!(graph.upstream("sink"), vec!["source"]);
+    assert_eq!(graph.node("sink").map(|node| node.errors), Some(1));
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::named::{PipelineRegistry, RecordedItem, Transition};
+use fluxion_stream::{FilterOrderedExt, NamedExt};
+use fluxion_test_utils::helpers::{test_channel, test_channel_with_errors, unwrap_stream};
+use fluxion_test_utils::sequenced::Sequenced;
+
+fn value(timestamp: u64, value: i32) -> RecordedItem {
+    RecordedItem::Value {
+        timestamp: timestamp.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_named_does_not_record_by_default() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.named_in(&registry, "source");
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert!(!registry.is_recording());
+    assert!(registry.stop_recording().events.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_records_items_leaving_and_entering_stages() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .named_in(&registry, "sink");
+
+    // Act
+    registry.start_recording();
+    tx.unbounded_send(Sequenced::with_timestamp(7, 1))?;
+    unwrap_stream(&mut result, 500).await;
+    let recording = registry.stop_recording();
+
+    // Assert
+    let events: Vec<_> = recording
+        .events
+        .iter()
+        .map(|event| (event.sequence, event.stage.as_str(), event.transition))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            (0, "source", Transition::Left),
+            (1, "sink", Transition::Entered),
+            (2, "sink", Transition::Left),
+        ]
+    );
+    assert!(recording
+        .events
+        .iter()
+        .all(|event| event.item == value(1, 7)));
+    assert_eq!(recording.events[2].node, result.node_id());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_recording_shows_dropped_items() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .filter_ordered(|x| *x > 0)
+        .named_in(&registry, "positive");
+
+    // Act
+    registry.start_recording();
+    tx.unbounded_send(Sequenced::with_timestamp(-3, 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(4, 2))?;
+    unwrap_stream(&mut result, 500).await;
+    let recording = registry.stop_recording();
+
+    // Assert
+    assert_eq!(recording.left("source"), vec![&value(1, -3), &value(2, 4)]);
+    assert_eq!(
+        recording.entered("positive"),
+        vec![&value(1, -3), &value(2, 4)]
+    );
+    assert_eq!(recording.left("positive"), vec![&value(2, 4)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_recording_restarts_empty() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.named_in(&registry, "source");
+
+    registry.start_recording();
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    registry.start_recording();
+    tx.unbounded_send(Sequenced::with_timestamp(2, 2))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert!(registry.is_recording());
+    assert_eq!(registry.recording().left("source"), vec![&value(2, 2)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replay_steps_through_recording() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .named_in(&registry, "sink");
+
+    registry.start_recording();
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(2, 2))?;
+    unwrap_stream(&mut result, 500).await;
+    unwrap_stream(&mut result, 500).await;
+    let mut replay = registry.stop_recording().replay();
+
+    // Act & Assert
+    assert_eq!(replay.step().map(|event| event.sequence), Some(0));
+    assert_eq!(
+        replay.step_stage("source").map(|event| event.sequence),
+        Some(3)
+    );
+    assert_eq!(replay.position(), 4);
+    assert_eq!(
+        replay.stage_state("sink"),
+        (vec![&value(1, 1)], vec![&value(1, 1)])
+    );
+
+    assert_eq!(replay.step_back().map(|event| event.sequence), Some(3));
+    assert_eq!(replay.history().len(), 3);
+
+    replay.seek(4);
+    assert_eq!(
+        replay.stage_state("sink").0,
+        vec![&value(1, 1), &value(2, 2)]
+    );
+    assert_eq!(replay.step().map(|event| event.sequence), Some(5));
+    assert!(replay.step().is_none());
+    assert!(replay.step_stage("source").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_records_errors() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.named_in(&registry, "source");
+
+    // Act
+    registry.start_recording();
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    unwrap_stream(&mut result, 500).await;
+    let recording = registry.stop_recording();
+
+    // Assert
+    let left = recording.left("source");
+    assert_eq!(left.len(), 1);
+    assert!(matches!(left[0], RecordedItem::Error(message) if message.contains("boom")));
+
+    Ok(())
+}
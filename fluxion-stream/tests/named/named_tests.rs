@@ -0,0 +1,150 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::named::PipelineRegistry;
+use fluxion_stream::NamedExt;
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_named_values_pass_through_unchanged() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel();
+    let mut result = stream.named_in(&registry, "source");
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_registers_node_and_counts_items() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.named_in(&registry, "enrich-orders");
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    unwrap_stream(&mut result, 500).await;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    let graph = registry.graph();
+    assert_eq!(graph.nodes.len(), 1);
+    let node = graph.node("enrich-orders").expect("node registered");
+    assert_eq!(node.id, result.node_id());
+    assert_eq!(node.items, 2);
+    assert_eq!(node.errors, 0);
+    assert!(graph.edges.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_links_consecutive_stages() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .named_in(&registry, "sink");
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    let graph = registry.graph();
+    assert_eq!(graph.downstream("source"), vec!["sink"]);
+    assert_eq!(graph.upstream("sink"), vec!["source"]);
+    assert_eq!(graph.edges.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_removed_from_registry_on_drop() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .named_in(&registry, "sink");
+
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Act
+    drop(result);
+
+    // Assert
+    let graph = registry.graph();
+    assert!(graph.nodes.is_empty());
+    assert!(graph.edges.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_registries_are_isolated() -> anyhow::Result<()> {
+    // Arrange
+    let first = PipelineRegistry::new();
+    let second = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.named_in(&first, "source").named_in(&second, "sink");
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert_eq!(first.graph().nodes.len(), 1);
+    assert_eq!(second.graph().nodes.len(), 1);
+    assert!(first.graph().edges.is_empty());
+    assert!(second.graph().edges.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_exports_dot() -> anyhow::Result<()> {
+    // Arrange
+    let registry = PipelineRegistry::new();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream
+        .named_in(&registry, "source")
+        .named_in(&registry, "sink");
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    unwrap_stream(&mut result, 500).await;
+
+    // Assert
+    assert_eq!(
+        registry.to_dot(),
+        "digraph pipeline {\n    rankdir=LR;\n    n0 [label=\"source\\nitems: 1, errors: 0\"];\n    n1 [label=\"sink\\nitems: 1, errors: 0\"];\n    n0 -> n1;\n}\n"
+    );
+
+    Ok(())
+}
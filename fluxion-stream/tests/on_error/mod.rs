@@ -2,6 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+pub mod on_error_async_tests;
 pub mod on_error_composition_error_tests;
 pub mod on_error_composition_tests;
 pub mod on_error_error_tests;
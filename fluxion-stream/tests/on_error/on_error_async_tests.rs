@@ -0,0 +1,151 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Tests for the `on_error_async` operator.
+
+use fluxion_core::fluxion_mutex::Mutex;
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::OnErrorExt;
+use fluxion_test_utils::{
+    helpers::{
+        assert_no_element_emitted, assert_stream_ended, test_channel_with_errors, unwrap_stream,
+        unwrap_value,
+    },
+    sequenced::Sequenced,
+};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_on_error_async_hands_errors_to_handler() -> anyhow::Result<()> {
+    // Arrange
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let handled_clone = Arc::clone(&handled);
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.on_error_async(4, move |err| {
+        let handled = Arc::clone(&handled_clone);
+        async move {
+            handled.lock().push(err.to_string());
+        }
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("first")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("second")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 100).await)).value,
+        1
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 100).await)).value,
+        2
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+    let handled = handled.lock().clone();
+    assert_eq!(handled.len(), 2);
+    assert!(handled[0].contains("first"));
+    assert!(handled[1].contains("second"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_error_async_does_not_hold_back_values() -> anyhow::Result<()> {
+    // Arrange
+    let (release, gate) = async_channel::unbounded::<()>();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.on_error_async(4, move |_| {
+        let gate = gate.clone();
+        async move {
+            let _ = gate.recv().await;
+        }
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("slow")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 100).await)).value,
+        1
+    );
+    drop(release);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_error_async_stops_polling_source_at_limit() -> anyhow::Result<()> {
+    // Arrange
+    let (release, gate) = async_channel::unbounded::<()>();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.on_error_async(1, move |_| {
+        let gate = gate.clone();
+        async move {
+            let _ = gate.recv().await;
+        }
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("slow")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    release.try_send(())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 100).await)).value,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_error_async_ends_after_handlers_complete() -> anyhow::Result<()> {
+    // Arrange
+    let (release, gate) = async_channel::unbounded::<()>();
+    let handled = Arc::new(Mutex::new(0));
+    let handled_clone = Arc::clone(&handled);
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.on_error_async(4, move |_| {
+        let gate = gate.clone();
+        let handled = Arc::clone(&handled_clone);
+        async move {
+            let _ = gate.recv().await;
+            *handled.lock() += 1;
+        }
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("pending")))?;
+    drop(tx);
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    release.try_send(())?;
+
+    // Assert
+    assert_stream_ended(&mut result, 100).await;
+    assert_eq!(*handled.lock(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "on_error_async requires a limit of at least 1")]
+async fn test_on_error_async_panics_on_zero_limit() {
+    let (_tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let _ = stream.on_error_async(0, |_| async {});
+}
@@ -5,4 +5,6 @@
 pub mod ordered_merge_composition_error_tests;
 pub mod ordered_merge_composition_tests;
 pub mod ordered_merge_error_tests;
+pub mod ordered_merge_interleaving_tests;
+pub mod ordered_merge_property_tests;
 pub mod ordered_merge_tests;
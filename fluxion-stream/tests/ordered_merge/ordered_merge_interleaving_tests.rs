@@ -0,0 +1,28 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::OrderedStreamExt;
+use fluxion_test_utils::fuzz::{run_scenario, Scenario};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_ordered_merge_invariants_hold_under_any_interleaving(
+        data in prop::collection::vec(any::<u8>(), 8..128),
+    ) {
+        // Arrange
+        let scenario = Scenario::from_bytes(&data, 3);
+
+        // Act
+        let trace = run_scenario(&scenario, |mut streams| {
+            let first = streams.remove(0);
+            first.ordered_merge(streams)
+        });
+
+        // Assert
+        prop_assert_eq!(trace.check_ordered(), Ok(()));
+        prop_assert_eq!(trace.check_no_loss(), Ok(()));
+        prop_assert_eq!(trace.check_errors_preserved(), Ok(()));
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::OrderedStreamExt;
+use fluxion_test_utils::property::{
+    check_errors_preserved, check_monotone_timestamps, check_same_values, run_stream,
+    timestamped_stream, StreamProfile,
+};
+use futures::stream;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_ordered_merge_of_ordered_sources_is_monotone(
+        left in timestamped_stream(any::<i32>(), StreamProfile::ordered()),
+        right in timestamped_stream(any::<i32>(), StreamProfile::ordered()),
+    ) {
+        // Act
+        let output = run_stream(
+            stream::iter(left.clone()).ordered_merge(vec![stream::iter(right.clone())]),
+        );
+
+        // Assert
+        let input: Vec<_> = left.into_iter().chain(right).collect();
+        check_monotone_timestamps(&output)?;
+        check_same_values(&input, &output)?;
+    }
+
+    #[test]
+    fn test_ordered_merge_keeps_every_error(
+        left in timestamped_stream(any::<i32>(), StreamProfile::ordered().with_error_rate(0.2)),
+        right in timestamped_stream(any::<i32>(), StreamProfile::ordered().with_error_rate(0.2)),
+    ) {
+        // Act
+        let output = run_stream(
+            stream::iter(left.clone()).ordered_merge(vec![stream::iter(right.clone())]),
+        );
+
+        // Assert
+        let input: Vec<_> = left.into_iter().chain(right).collect();
+        check_errors_preserved(&input, &output)?;
+        check_same_values(&input, &output)?;
+    }
+}
@@ -2,14 +2,20 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_core::{HasTimestamp, StreamItem, Timestamped};
 use fluxion_stream::OrderedStreamExt;
+use fluxion_test_utils::deterministic::{check_seeds, run_interleaved};
+use fluxion_test_utils::expect_marble;
 use fluxion_test_utils::helpers::{assert_stream_ended, test_channel, unwrap_stream};
+use fluxion_test_utils::marble::cold;
 use fluxion_test_utils::sequenced::Sequenced;
 use fluxion_test_utils::test_data::{
     animal_bird, animal_dog, animal_spider, person_alice, person_bob, person_charlie, person_dave,
     plant_oak, plant_rose, plant_sunflower, TestData,
 };
+use futures::StreamExt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use tokio::spawn;
 
 #[tokio::test]
@@ -318,3 +324,112 @@ async fn test_ordered_merge_mixed_types_in_enum() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(start_paused = true)]
+async fn test_ordered_merge_marble_interleaves_by_frame() -> anyhow::Result<()> {
+    // Arrange
+    let left = cold("a---c-|", &[('a', 1), ('c', 3)]);
+    let right = cold("--b---d|", &[('b', 2), ('d', 4)]);
+
+    // Act
+    let mut merged = left.ordered_merge(vec![right]);
+
+    // Assert
+    expect_marble!(merged, "a-b-c-d|", { 'a' => 1, 'b' => 2, 'c' => 3, 'd' => 4 });
+
+    Ok(())
+}
+
+#[test]
+fn test_ordered_merge_keeps_every_value_under_any_interleaving() {
+    check_seeds(0..200, |seed| {
+        // Arrange
+        let source = |timestamps: [u64; 3]| -> Vec<StreamItem<Sequenced<u64>>> {
+            timestamps
+                .into_iter()
+                .map(|ts| StreamItem::Value(Sequenced::with_timestamp(ts, ts)))
+                .collect()
+        };
+        let sources = vec![source([1, 4, 5]), source([2, 3, 6])];
+
+        // Act
+        let output = run_interleaved(seed, sources, |mut streams| {
+            let right = streams.pop().unwrap();
+            let left = streams.pop().unwrap();
+            left.ordered_merge(vec![right])
+        });
+
+        // Assert
+        let mut timestamps: Vec<u64> = output
+            .into_iter()
+            .map(|item| item.unwrap().timestamp())
+            .collect();
+        let left: Vec<u64> = timestamps
+            .iter()
+            .copied()
+            .filter(|ts| [1, 4, 5].contains(ts))
+            .collect();
+        let right: Vec<u64> = timestamps
+            .iter()
+            .copied()
+            .filter(|ts| [2, 3, 6].contains(ts))
+            .collect();
+        assert_eq!(left, vec![1, 4, 5], "seed {seed}");
+        assert_eq!(right, vec![2, 3, 6], "seed {seed}");
+        timestamps.sort_unstable();
+        assert_eq!(timestamps, vec![1, 2, 3, 4, 5, 6], "seed {seed}");
+    });
+}
+
+#[tokio::test]
+async fn test_ordered_merge_yields_to_other_tasks() -> anyhow::Result<()> {
+    // Arrange
+    let ready = |offset: u64| {
+        futures::stream::iter(0..10_000u64)
+            .map(move |i| StreamItem::Value(Sequenced::with_timestamp(i, i * 2 + offset)))
+    };
+    let mut merged = ready(0).ordered_merge(vec![ready(1)]);
+    let other_task_ran = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&other_task_ran);
+    spawn(async move { flag.store(true, Ordering::SeqCst) });
+
+    // Act
+    let mut drained = 0;
+    while !other_task_ran.load(Ordering::SeqCst) && merged.next().await.is_some() {
+        drained += 1;
+    }
+
+    // Assert
+    assert!(other_task_ran.load(Ordering::SeqCst));
+    assert!(
+        drained < 20_000,
+        "merge drained every item without yielding"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ordered_merge_with_zero_budget_never_yields() -> anyhow::Result<()> {
+    // Arrange
+    let ready = |offset: u64| {
+        futures::stream::iter(0..1_000u64)
+            .map(move |i| StreamItem::Value(Sequenced::with_timestamp(i, i * 2 + offset)))
+    };
+    let mut merged = ready(0).ordered_merge_with_budget(vec![ready(1)], 0);
+    let other_task_ran = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&other_task_ran);
+    spawn(async move { flag.store(true, Ordering::SeqCst) });
+
+    // Act
+    let mut drained = 0;
+    while merged.next().await.is_some() {
+        drained += 1;
+    }
+
+    // Assert
+    assert_eq!(drained, 2_000);
+    assert!(!other_task_ran.load(Ordering::SeqCst));
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{
+    CombineLatestExt, CombinedState, EmitWhenExt, MergedStream, OrderedStreamExt, OrderingMode,
+    TakeLatestWhenExt, TakeWhileExt, WithLatestFromExt,
+};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_unordered_merge_emits_without_waiting_for_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let mut merged = stream1.ordered_merge_with_mode(vec![stream2], OrderingMode::Unordered);
+
+    // Act
+    tx2.unbounded_send((1, 300).into())?;
+    tx1.unbounded_send((2, 200).into())?;
+    tx1.unbounded_send((3, 100).into())?;
+
+    // Assert
+    let mut values = Vec::new();
+    for _ in 0..3 {
+        values.push(unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value);
+    }
+    assert_eq!(values, vec![2, 1, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ordered_mode_emits_in_timestamp_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let mut merged = stream1.ordered_merge_with_mode(vec![stream2], OrderingMode::Ordered);
+
+    // Act
+    tx2.unbounded_send((1, 300).into())?;
+    tx1.unbounded_send((2, 200).into())?;
+    tx1.unbounded_send((3, 100).into())?;
+
+    // Assert
+    let mut values = Vec::new();
+    for _ in 0..3 {
+        values.push(unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value);
+    }
+    assert_eq!(values, vec![2, 3, 1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_merge_polls_inputs_round_robin() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let mut merged = stream1.ordered_merge_with_mode(vec![stream2], OrderingMode::Unordered);
+
+    // Act
+    tx1.unbounded_send((1, 100).into())?;
+    tx1.unbounded_send((2, 200).into())?;
+    tx2.unbounded_send((3, 300).into())?;
+    tx2.unbounded_send((4, 400).into())?;
+
+    // Assert
+    let mut values = Vec::new();
+    for _ in 0..4 {
+        values.push(unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value);
+    }
+    assert_eq!(values, vec![1, 3, 2, 4]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_modes_of_pipelines_are_independent() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let (tx3, stream3) = test_channel::<Sequenced<i32>>();
+    let (tx4, stream4) = test_channel::<Sequenced<i32>>();
+    let mut unordered = stream1.ordered_merge_with_mode(vec![stream2], OrderingMode::Unordered);
+    let mut ordered = stream3.ordered_merge(vec![stream4]);
+
+    // Act
+    tx1.unbounded_send((1, 300).into())?;
+    tx2.unbounded_send((2, 100).into())?;
+    tx3.unbounded_send((1, 300).into())?;
+    tx4.unbounded_send((2, 100).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut unordered, 500).await)).value,
+        1
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut ordered, 500).await)).value,
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_combine_latest_processes_items_without_waiting() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let mut combined =
+        stream1.combine_latest_with_mode(vec![stream2], |_| true, OrderingMode::Unordered);
+
+    // Act
+    tx2.unbounded_send((1, 50).into())?;
+    tx1.unbounded_send((2, 300).into())?;
+    tx1.unbounded_send((3, 100).into())?;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!((first.values(), first.timestamp()), (vec![2, 1], 50));
+    let second = unwrap_value(Some(unwrap_stream(&mut combined, 500).await));
+    assert_eq!(second.values(), vec![3, 1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_with_latest_from_uses_secondary_value_that_arrived_first(
+) -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let mut result = stream1.with_latest_from_with_mode(
+        stream2,
+        |state: &CombinedState<i32, u64>| state.clone(),
+        OrderingMode::Unordered,
+    );
+
+    // Act
+    tx2.unbounded_send((10, 300).into())?;
+    tx1.unbounded_send((1, 100).into())?;
+    tx1.unbounded_send((2, 200).into())?;
+
+    // Assert
+    let combined = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (combined.values(), combined.timestamp()),
+        (vec![2, 10], 200)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_emit_when_processes_items_without_waiting() -> anyhow::Result<()> {
+    // Arrange
+    let (source_tx, source) = test_channel::<Sequenced<i32>>();
+    let (filter_tx, filter) = test_channel::<Sequenced<i32>>();
+    let mut result = source.emit_when_with_mode(filter, |_| true, OrderingMode::Unordered);
+
+    // Act
+    source_tx.unbounded_send((5, 400).into())?;
+    filter_tx.unbounded_send((1, 300).into())?;
+
+    // Assert
+    let emitted = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((emitted.value, emitted.timestamp()), (5, 300));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_take_latest_when_samples_source_that_arrived_first() -> anyhow::Result<()> {
+    // Arrange
+    let (source_tx, source) = test_channel::<Sequenced<i32>>();
+    let (trigger_tx, trigger) = test_channel::<Sequenced<i32>>();
+    let mut result = source.take_latest_when_with_mode(trigger, |_| true, OrderingMode::Unordered);
+
+    // Act
+    source_tx.unbounded_send((5, 400).into())?;
+    trigger_tx.unbounded_send((1, 300).into())?;
+
+    // Assert
+    let emitted = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((emitted.value, emitted.timestamp()), (5, 300));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_take_while_with_uses_filter_that_arrived_first() -> anyhow::Result<()> {
+    // Arrange
+    let (source_tx, source) = test_channel::<Sequenced<i32>>();
+    let (filter_tx, filter) = test_channel::<Sequenced<i32>>();
+    let mut result =
+        source.take_while_with_with_mode(filter, |f: &i32| *f > 0, OrderingMode::Unordered);
+
+    // Act
+    filter_tx.unbounded_send((1, 300).into())?;
+    source_tx.unbounded_send((5, 100).into())?;
+    source_tx.unbounded_send((6, 200).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        6
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unordered_merge_with_emits_without_waiting_for_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<i32>>();
+    let (tx2, stream2) = test_channel::<Sequenced<i32>>();
+    let mut merged = MergedStream::seed::<Sequenced<i32>>(0)
+        .merge_with_with_mode(
+            stream1,
+            |value: i32, _state: &mut i32| value,
+            OrderingMode::Unordered,
+        )
+        .merge_with_with_mode(
+            stream2,
+            |value: i32, _state: &mut i32| value,
+            OrderingMode::Unordered,
+        );
+
+    // Act
+    tx2.unbounded_send((1, 100).into())?;
+    tx1.unbounded_send((2, 300).into())?;
+
+    // Assert
+    let mut values = Vec::new();
+    for _ in 0..2 {
+        values.push(unwrap_value(Some(unwrap_stream(&mut merged, 500).await)).value);
+    }
+    assert_eq!(values, vec![2, 1]);
+
+    Ok(())
+}
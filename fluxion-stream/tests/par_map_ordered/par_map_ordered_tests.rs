@@ -0,0 +1,105 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+fn slow_double(item: Sequenced<u64>) -> Sequenced<u64> {
+    // Earlier items take longer, so they would finish last without re-sequencing
+    thread::sleep(Duration::from_millis(40 - item.value * 10));
+    Sequenced::with_timestamp(item.value * 2, item.timestamp())
+}
+
+#[tokio::test]
+async fn test_par_map_ordered_emits_in_source_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.par_map_ordered(3, slow_double);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(3, 3)))?;
+    drop(tx);
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.value, first.timestamp()), (2, 1));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    for expected in 2..=3 {
+        let item = unwrap_stream(&mut result, 500).await.unwrap();
+        assert_eq!((item.value, item.timestamp()), (expected * 2, expected));
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_par_map_ordered_spreads_work_across_workers() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let workers = Arc::new(Mutex::new(HashSet::new()));
+    let seen = Arc::clone(&workers);
+    let mut result = stream.par_map_ordered(4, move |item: Sequenced<u64>| {
+        seen.lock().unwrap().insert(thread::current().id());
+        thread::sleep(Duration::from_millis(50));
+        item
+    });
+
+    // Act
+    for value in 1..=4 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(value, value)))?;
+    }
+
+    // Assert
+    for expected in 1..=4 {
+        assert_eq!(
+            unwrap_stream(&mut result, 500).await.unwrap().value,
+            expected
+        );
+    }
+    assert_eq!(workers.lock().unwrap().len(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "transform failed")]
+async fn test_par_map_ordered_resumes_worker_panic() {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.par_map_ordered(2, |item: Sequenced<u64>| -> Sequenced<u64> {
+        if item.value == 2 {
+            panic!("transform failed");
+        }
+        item
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 1)))
+        .unwrap();
+
+    // Assert
+    let _ = unwrap_stream(&mut result, 500).await;
+}
+
+#[test]
+#[should_panic(expected = "width of at least 1")]
+fn test_par_map_ordered_rejects_zero_width() {
+    let (_tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let _ = stream.par_map_ordered(0, |item: Sequenced<u64>| item);
+}
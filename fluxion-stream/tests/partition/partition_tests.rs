@@ -2,8 +2,11 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
+use fluxion_core::StreamItem;
 use fluxion_stream::PartitionExt;
 use fluxion_test_utils::helpers::{assert_stream_ended, test_channel, unwrap_stream, unwrap_value};
 use fluxion_test_utils::sequenced::Sequenced;
@@ -11,6 +14,8 @@ use fluxion_test_utils::test_data::{
     animal_bird, animal_cat, animal_dog, animal_spider, person_alice, person_bob, person_charlie,
     person_dave, person_diane, plant_fern, plant_rose, plant_sunflower, TestData,
 };
+use futures::StreamExt;
+use tokio::spawn;
 use tokio::time::sleep;
 
 #[tokio::test]
@@ -422,3 +427,26 @@ async fn test_partition_drop_both_streams_gracefully() -> anyhow::Result<()> {
     // Test passes if no panic occurred - resources are cleaned up properly
     Ok(())
 }
+
+#[tokio::test]
+async fn test_partition_yields_to_other_tasks() -> anyhow::Result<()> {
+    // Arrange
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&produced);
+    let source = futures::stream::iter(0..10_000u64).map(move |i| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        StreamItem::Value(Sequenced::with_timestamp(i, i))
+    });
+    let (_evens, _odds) = source.partition(|value| value % 2 == 0);
+
+    // Act
+    let observed = spawn(async move { produced.load(Ordering::SeqCst) }).await?;
+
+    // Assert
+    assert!(
+        observed < 10_000,
+        "partition drained every item without yielding"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::boxed::{BoxedExt, BoxedFluxionStream};
+use fluxion_stream::pipe::{Pipe, PipeExt, Pipeline};
+use fluxion_stream::{FilterOrderedExt, MapOrderedExt, OnErrorExt};
+use fluxion_test_utils::helpers::{
+    assert_stream_ended, test_channel, test_channel_with_errors, unwrap_stream,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+use futures::Stream;
+
+type BoxedTestStream = BoxedFluxionStream<Sequenced<TestData>>;
+
+struct OnlyPeople {
+    min_age: u32,
+}
+
+impl<S> Pipe<S> for &OnlyPeople
+where
+    S: Stream<Item = StreamItem<Sequenced<TestData>>> + Send + Sync + Unpin + 'static,
+{
+    type Output = BoxedTestStream;
+
+    fn apply(self, stream: S) -> Self::Output {
+        let min_age = self.min_age;
+        stream
+            .filter_ordered(
+                move |data| matches!(data, TestData::Person(person) if person.age >= min_age),
+            )
+            .into_boxed()
+    }
+}
+
+#[tokio::test]
+async fn test_pipe_applies_closure() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut piped = stream.pipe(|stream| stream.filter_ordered(|data| *data != animal_dog()));
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut piped, 500).await.unwrap().into_inner(),
+        person_alice()
+    );
+    assert_stream_ended(&mut piped, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pipeline_is_reusable_across_streams() -> anyhow::Result<()> {
+    // Arrange
+    let adults = Pipeline::new("adults", |stream: BoxedTestStream| {
+        stream.filter_ordered(|data| matches!(data, TestData::Person(person) if person.age >= 30))
+    });
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let mut first = stream1.into_boxed().pipe_with(&adults);
+    let mut second = stream2.into_boxed().pipe_with(&adults);
+
+    // Act
+    tx1.unbounded_send(Sequenced::new(person_alice()))?;
+    tx1.unbounded_send(Sequenced::new(person_bob()))?;
+    tx2.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_eq!(adults.name(), "adults");
+    assert_eq!(
+        unwrap_stream(&mut first, 500).await.unwrap().into_inner(),
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_stream(&mut second, 500).await.unwrap().into_inner(),
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_pipe_is_generic_over_input() -> anyhow::Result<()> {
+    // Arrange
+    let only_people = OnlyPeople { min_age: 26 };
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut from_channel = stream.pipe_with(&only_people);
+    let mut from_mapped = futures::stream::iter(vec![StreamItem::Value(
+        Sequenced::with_timestamp(person_alice(), 1),
+    )])
+    .map_ordered(|item| Sequenced::with_timestamp(person_bob(), item.timestamp()))
+    .pipe_with(&only_people);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut from_channel, 500)
+            .await
+            .unwrap()
+            .into_inner(),
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_stream(&mut from_mapped, 500)
+            .await
+            .unwrap()
+            .into_inner(),
+        person_bob()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pipe_propagates_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut piped = stream.pipe(|stream| stream.filter_ordered(|_| true));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+
+    // Assert
+    assert!(unwrap_stream(&mut piped, 500).await.is_error());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pipe_composes_with_error_handling() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut piped = stream
+        .pipe(|stream| stream.on_error(|_| true))
+        .pipe(|stream| stream.filter_ordered(|data| *data == person_alice()));
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("boom")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut piped, 500).await.unwrap().into_inner(),
+        person_alice()
+    );
+
+    Ok(())
+}
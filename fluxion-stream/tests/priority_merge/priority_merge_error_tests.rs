@@ -0,0 +1,46 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::PriorityMergeExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, TestData},
+};
+
+#[tokio::test]
+async fn test_priority_merge_emits_low_priority_error_immediately() -> anyhow::Result<()> {
+    // Arrange
+    let (low_tx, low) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (high_tx, high) = test_channel_with_errors::<Sequenced<TestData>>();
+    high_tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+        animal_dog(),
+        1,
+    )))?;
+    low_tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    low_tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+        person_alice(),
+        2,
+    )))?;
+
+    // Act
+    let mut result = low.priority_merge(0, vec![(high, 1)]);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        animal_dog()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::PriorityMergeExt;
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_cat, animal_dog, person_alice, person_bob, plant_rose, TestData},
+};
+
+#[tokio::test]
+async fn test_priority_merge_prefers_higher_priority() -> anyhow::Result<()> {
+    // Arrange
+    let (data_tx, data) = test_channel::<Sequenced<TestData>>();
+    let (control_tx, control) = test_channel::<Sequenced<TestData>>();
+    data_tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    data_tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+    control_tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 3))?;
+    control_tx.unbounded_send(Sequenced::with_timestamp(animal_cat(), 4))?;
+
+    // Act
+    let mut result = data.priority_merge(0, vec![(control, 1)]);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        animal_dog()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        animal_cat()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_priority_merge_orders_equal_priorities_by_timestamp() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    tx1.unbounded_send(Sequenced::with_timestamp(person_alice(), 3))?;
+    tx2.unbounded_send(Sequenced::with_timestamp(person_bob(), 1))?;
+    tx2.unbounded_send(Sequenced::with_timestamp(plant_rose(), 5))?;
+
+    // Act
+    let mut result = stream1.priority_merge(2, vec![(stream2, 2)]);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_bob()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        plant_rose()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_priority_merge_does_not_wait_for_pending_higher_priority() -> anyhow::Result<()> {
+    // Arrange
+    let (data_tx, data) = test_channel::<Sequenced<TestData>>();
+    let (control_tx, control) = test_channel::<Sequenced<TestData>>();
+    let mut result = data.priority_merge(0, vec![(control, 1)]);
+
+    // Act
+    data_tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 2))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+
+    // Act
+    control_tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 1))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        animal_dog()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_priority_merge_three_levels() -> anyhow::Result<()> {
+    // Arrange
+    let (low_tx, low) = test_channel::<Sequenced<TestData>>();
+    let (mid_tx, mid) = test_channel::<Sequenced<TestData>>();
+    let (high_tx, high) = test_channel::<Sequenced<TestData>>();
+    low_tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    mid_tx.unbounded_send(Sequenced::with_timestamp(plant_rose(), 2))?;
+    high_tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 3))?;
+
+    // Act
+    let mut result = mid.priority_merge(5, vec![(low, 1), (high, 9)]);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        animal_dog()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        plant_rose()
+    );
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_priority_merge_ends_when_all_streams_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, stream2) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream1.priority_merge(0, vec![(stream2, 1)]);
+
+    // Act
+    tx1.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    drop(tx2);
+
+    // Assert
+    assert_eq!(
+        unwrap_stream(&mut result, 500).await.unwrap().value,
+        person_alice()
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    drop(tx1);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
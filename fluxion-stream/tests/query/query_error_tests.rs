@@ -0,0 +1,77 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::QueryExt;
+use fluxion_test_utils::{helpers::test_channel_with_errors, sequenced::Sequenced};
+
+#[tokio::test]
+async fn test_first_returns_error_before_first_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+
+    // Act
+    let first = stream.first().await;
+
+    // Assert
+    assert!(matches!(
+        first,
+        Err(FluxionError::StreamProcessingError { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_first_ignores_error_after_answer() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Act
+    let first = stream.first().await?;
+
+    // Assert
+    assert_eq!(first.map(|item| item.value), Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_last_returns_error_read_before_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+    drop(tx);
+
+    // Act
+    let last = stream.last().await;
+
+    // Assert
+    assert!(last.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_all_values_returns_error_before_mismatch() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(3)))?;
+
+    // Act
+    let all = stream.all_values(|value| value % 2 == 0).await;
+
+    // Assert
+    assert!(all.is_err());
+
+    Ok(())
+}
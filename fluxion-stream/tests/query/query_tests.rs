@@ -0,0 +1,199 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::QueryExt;
+use fluxion_test_utils::{
+    helpers::test_channel,
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, TestData},
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn test_first_resolves_without_waiting_for_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 3))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 4))?;
+
+    // Act
+    let first = stream.first().await?;
+
+    // Assert
+    let first = first.expect("a first value");
+    assert_eq!(first.value, person_alice());
+    assert_eq!(first.timestamp(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_first_of_empty_stream_is_none() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    drop(tx);
+
+    // Act
+    let first = stream.first().await?;
+
+    // Assert
+    assert!(first.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_last_resolves_when_stream_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    drop(tx);
+
+    // Act
+    let last = stream.last().await?;
+
+    // Assert
+    assert_eq!(last.map(|item| item.value), Some(animal_dog()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nth_counts_from_zero() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    for value in [10, 20, 30] {
+        tx.unbounded_send(Sequenced::new(value))?;
+    }
+
+    // Act
+    let nth = stream.nth(2).await?;
+
+    // Assert
+    assert_eq!(nth.map(|item| item.value), Some(30));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nth_beyond_end_is_none() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    tx.unbounded_send(Sequenced::new(10))?;
+    drop(tx);
+
+    // Act
+    let nth = stream.nth(1).await?;
+
+    // Assert
+    assert!(nth.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_any_value_stops_at_first_match() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let (seen_tx, seen_rx) = futures::channel::mpsc::unbounded();
+    let stream = stream.inspect(move |item| {
+        seen_tx.unbounded_send(item.clone().unwrap().value).unwrap();
+    });
+    for value in [1, 5, 2] {
+        tx.unbounded_send(Sequenced::new(value))?;
+    }
+
+    // Act
+    let any = stream.any_value(|value| *value > 3).await?;
+
+    // Assert
+    assert!(any);
+    assert_eq!(seen_rx.collect::<Vec<_>>().await, vec![1, 5]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_any_value_without_match_is_false() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    tx.unbounded_send(Sequenced::new(1))?;
+    tx.unbounded_send(Sequenced::new(2))?;
+    drop(tx);
+
+    // Act
+    let any = stream.any_value(|value| *value > 3).await?;
+
+    // Assert
+    assert!(!any);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_all_values_stops_at_first_mismatch() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    tx.unbounded_send(Sequenced::new(2))?;
+    tx.unbounded_send(Sequenced::new(3))?;
+
+    // Act
+    let all = stream.all_values(|value| value % 2 == 0).await?;
+
+    // Assert
+    assert!(!all);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_all_values_of_matching_stream_is_true() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    tx.unbounded_send(Sequenced::new(2))?;
+    tx.unbounded_send(Sequenced::new(4))?;
+    drop(tx);
+
+    // Act
+    let all = stream.all_values(|value| value % 2 == 0).await?;
+
+    // Assert
+    assert!(all);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_contains_finds_inner_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Act
+    let contains = stream.contains(person_bob()).await?;
+
+    // Assert
+    assert!(contains);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_contains_missing_value_is_false() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Act
+    let contains = stream.contains(animal_dog()).await?;
+
+    // Assert
+    assert!(!contains);
+
+    Ok(())
+}
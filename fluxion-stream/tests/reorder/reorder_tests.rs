@@ -0,0 +1,178 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::{ReorderExt, ReorderWindow};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+#[cfg(feature = "runtime-tokio")]
+use std::time::Duration;
+#[cfg(feature = "runtime-tokio")]
+use tokio::time::{advance, pause};
+
+#[tokio::test]
+async fn test_reorder_items_releases_oldest_when_buffer_overflows() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.reorder(ReorderWindow::Items(2));
+
+    // Act
+    tx.unbounded_send((3, 3).into())?;
+    tx.unbounded_send((1, 1).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((2, 2).into())?;
+    tx.unbounded_send((4, 4).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        2
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reorder_span_releases_items_behind_newest() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.reorder(ReorderWindow::Span(10u64));
+
+    // Act
+    tx.unbounded_send((2, 12).into())?;
+    tx.unbounded_send((1, 5).into())?;
+    tx.unbounded_send((3, 15).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((4, 23).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        2
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[cfg(feature = "runtime-tokio")]
+#[tokio::test]
+async fn test_reorder_time_releases_items_held_for_the_window() -> anyhow::Result<()> {
+    // Arrange
+    pause();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.reorder(ReorderWindow::<u64>::Time(Duration::from_millis(100)));
+
+    // Act
+    tx.unbounded_send((5, 5).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 10).await;
+
+    // Act
+    advance(Duration::from_millis(50)).await;
+    tx.unbounded_send((7, 7).into())?;
+    tx.unbounded_send((6, 6).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 10).await;
+
+    // Act
+    advance(Duration::from_millis(30)).await;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 10).await)).value,
+        5
+    );
+    assert_no_element_emitted(&mut result, 10).await;
+
+    // Act
+    advance(Duration::from_millis(50)).await;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 10).await)).value,
+        6
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 10).await)).value,
+        7
+    );
+    assert_no_element_emitted(&mut result, 200).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reorder_emits_too_late_items_immediately() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.reorder(ReorderWindow::Items(1));
+
+    // Act
+    tx.unbounded_send((1, 10).into())?;
+    tx.unbounded_send((2, 20).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+
+    // Act
+    tx.unbounded_send((0, 5).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        0
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reorder_flushes_held_items_in_order_on_completion() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.reorder(ReorderWindow::Items(10));
+
+    // Act
+    tx.unbounded_send((3, 30).into())?;
+    tx.unbounded_send((1, 10).into())?;
+    tx.unbounded_send((4, 30).into())?;
+    tx.unbounded_send((2, 20).into())?;
+    drop(tx);
+
+    // Assert
+    for expected in [1, 2, 3, 4] {
+        assert_eq!(
+            unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{from_iter, repeat, repeat_when};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, test_channel_with_errors,
+    unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::StreamExt;
+use std::collections::VecDeque;
+
+#[tokio::test]
+async fn test_repeat_runs_source_given_number_of_times() -> anyhow::Result<()> {
+    // Arrange
+    let mut runs = 0;
+    let stream = repeat(
+        || {
+            runs += 1;
+            from_iter::<Sequenced<i32>, _>([runs * 10, runs * 10 + 1])
+        },
+        3,
+    );
+
+    // Act
+    let values: Vec<_> = stream
+        .map(|item| item.unwrap().into_inner())
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(values, vec![10, 11, 20, 21, 30, 31]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_repeat_zero_times_never_builds_source() -> anyhow::Result<()> {
+    // Arrange
+    let mut runs = 0;
+    let mut stream = repeat(
+        || {
+            runs += 1;
+            from_iter::<Sequenced<i32>, _>([1])
+        },
+        0,
+    );
+
+    // Act & Assert
+    assert_stream_ended(&mut stream, 500).await;
+    drop(stream);
+    assert_eq!(runs, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_repeat_when_restarts_after_completion_on_notifier() -> anyhow::Result<()> {
+    // Arrange
+    let (notify, notifier) = test_channel::<Sequenced<()>>();
+    let (first_tx, first) = test_channel::<Sequenced<i32>>();
+    let (second_tx, second) = test_channel::<Sequenced<i32>>();
+    let mut sources = VecDeque::from([first, second]);
+    let mut stream = repeat_when(
+        move || sources.pop_front().expect("one source per run"),
+        notifier,
+    );
+
+    // Act
+    first_tx.unbounded_send(Sequenced::new(1))?;
+    notify.unbounded_send(Sequenced::new(()))?; // ignored, the first run is active
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        1
+    );
+
+    // Act
+    drop(first_tx);
+    second_tx.unbounded_send(Sequenced::new(2))?;
+
+    // Assert
+    assert_no_element_emitted(&mut stream, 100).await;
+
+    // Act
+    notify.unbounded_send(Sequenced::new(()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_repeat_when_ends_when_notifier_ended_and_run_completes() -> anyhow::Result<()> {
+    // Arrange
+    let (notify, notifier) = test_channel::<Sequenced<()>>();
+    let mut stream = repeat_when(|| from_iter::<Sequenced<i32>, _>([7]), notifier);
+
+    // Act
+    drop(notify);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        7
+    );
+    assert_stream_ended(&mut stream, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_repeat_when_emits_notifier_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (notify, notifier) = test_channel_with_errors::<Sequenced<()>>();
+    let mut stream = repeat_when(|| from_iter::<Sequenced<i32>, _>([7]), notifier);
+
+    // Act
+    notify.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut stream, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut stream, 500).await)).value,
+        7
+    );
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `resample` operator.
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::{ResampleExt, ResampleStrategy};
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_resample_error_keeps_sample_points() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.resample(10u64, ResampleStrategy::Linear);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((0, 0).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("sensor")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((20, 20).into()))?;
+
+    // Assert
+    for expected in [(0, 0), (10, 10)] {
+        let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((sample.timestamp(), sample.value), expected);
+    }
+
+    Ok(())
+}
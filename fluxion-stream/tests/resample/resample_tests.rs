@@ -0,0 +1,111 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{ResampleExt, ResampleStrategy};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_resample_hold_last_repeats_latest_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.resample(10u64, ResampleStrategy::HoldLast);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 25).into())?;
+    drop(tx);
+
+    // Assert
+    for expected in [(0, 1), (10, 1), (20, 1)] {
+        let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((sample.timestamp(), sample.value), expected);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resample_linear_interpolates_between_items() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.resample(10u64, ResampleStrategy::Linear);
+
+    // Act
+    tx.unbounded_send((100, 0).into())?;
+    tx.unbounded_send((200, 40).into())?;
+
+    // Assert
+    for expected in [(0, 100), (10, 125), (20, 150), (30, 175)] {
+        let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((sample.timestamp(), sample.value), expected);
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    drop(tx);
+
+    // Assert
+    let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((sample.timestamp(), sample.value), (40, 200));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resample_mean_averages_each_period() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.resample(10u64, ResampleStrategy::Mean);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((3, 5).into())?;
+    tx.unbounded_send((10, 12).into())?;
+    tx.unbounded_send((20, 35).into())?;
+    drop(tx);
+
+    // Assert
+    for expected in [(0, 2), (10, 10), (20, 10), (30, 20)] {
+        let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((sample.timestamp(), sample.value), expected);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resample_drops_out_of_order_items() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.resample(10u64, ResampleStrategy::HoldLast);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 15).into())?;
+    tx.unbounded_send((3, 5).into())?;
+    tx.unbounded_send((4, 20).into())?;
+
+    // Assert
+    for expected in [(0, 1), (10, 1)] {
+        let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((sample.timestamp(), sample.value), expected);
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    drop(tx);
+
+    // Assert
+    let sample = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((sample.timestamp(), sample.value), (20, 4));
+
+    Ok(())
+}
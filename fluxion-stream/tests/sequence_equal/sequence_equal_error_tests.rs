@@ -0,0 +1,77 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{Divergence, SequenceEqualExt};
+use fluxion_test_utils::{helpers::test_channel_with_errors, sequenced::Sequenced};
+
+#[tokio::test]
+async fn test_sequence_equal_matches_errors_with_same_message() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel_with_errors::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel_with_errors::<Sequenced<i32>>();
+    for tx in [&tx_left, &tx_right] {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+        tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+    }
+    drop(tx_left);
+    drop(tx_right);
+
+    // Act
+    let equal = left.sequence_equal(right, 0).await;
+
+    // Assert
+    assert!(equal);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_reports_errors_with_different_messages() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel_with_errors::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel_with_errors::<Sequenced<i32>>();
+    tx_left.unbounded_send(StreamItem::Error(FluxionError::stream_error("left")))?;
+    tx_right.unbounded_send(StreamItem::Error(FluxionError::stream_error("right")))?;
+
+    // Act
+    let diff = left.sequence_diff(right, 0).await;
+
+    // Assert
+    assert!(matches!(
+        diff,
+        Some(Divergence::Mismatch {
+            index: 0,
+            left: StreamItem::Error(_),
+            right: StreamItem::Error(_),
+        })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_reports_error_against_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel_with_errors::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel_with_errors::<Sequenced<i32>>();
+    tx_left.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx_right.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Act
+    let diff = left.sequence_diff(right, 0).await;
+
+    // Assert
+    assert!(matches!(
+        diff,
+        Some(Divergence::Mismatch {
+            index: 0,
+            left: StreamItem::Value(_),
+            right: StreamItem::Error(_),
+        })
+    ));
+
+    Ok(())
+}
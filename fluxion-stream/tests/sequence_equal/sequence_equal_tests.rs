@@ -0,0 +1,194 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::{Divergence, SequenceEqualExt};
+use fluxion_test_utils::{
+    helpers::test_channel,
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, TestData},
+};
+
+#[tokio::test]
+async fn test_sequence_equal_equal_streams() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<TestData>>();
+    let (tx_right, right) = test_channel::<Sequenced<TestData>>();
+    for (value, timestamp) in [(person_alice(), 1), (person_bob(), 2)] {
+        tx_left.unbounded_send(Sequenced::with_timestamp(value.clone(), timestamp))?;
+        tx_right.unbounded_send(Sequenced::with_timestamp(value, timestamp))?;
+    }
+    drop(tx_left);
+    drop(tx_right);
+
+    // Act
+    let equal = left.sequence_equal(right, 0).await;
+
+    // Assert
+    assert!(equal);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_equal_empty_streams() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel::<Sequenced<i32>>();
+    drop(tx_left);
+    drop(tx_right);
+
+    // Act
+    let diff = left.sequence_diff(right, 0).await;
+
+    // Assert
+    assert!(diff.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_equal_timestamps_within_tolerance() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel::<Sequenced<i32>>();
+    tx_left.unbounded_send(Sequenced::with_timestamp(1, 10))?;
+    tx_left.unbounded_send(Sequenced::with_timestamp(2, 20))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(1, 13))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(2, 17))?;
+    drop(tx_left);
+    drop(tx_right);
+
+    // Act
+    let equal = left.sequence_equal(right, 3).await;
+
+    // Assert
+    assert!(equal);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_reports_timestamp_beyond_tolerance() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel::<Sequenced<i32>>();
+    tx_left.unbounded_send(Sequenced::with_timestamp(1, 10))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(1, 14))?;
+
+    // Act
+    let diff = left.sequence_diff(right, 3).await;
+
+    // Assert
+    match diff {
+        Some(Divergence::Mismatch {
+            index: 0,
+            left: StreamItem::Value(left),
+            right: StreamItem::Value(right),
+        }) => {
+            assert_eq!(left.timestamp(), 10);
+            assert_eq!(right.timestamp(), 14);
+        }
+        other => panic!("Expected a mismatch at index 0, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_reports_first_differing_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<TestData>>();
+    let (tx_right, right) = test_channel::<Sequenced<TestData>>();
+    tx_left.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx_left.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(animal_dog(), 2))?;
+
+    // Act
+    let diff = left.sequence_diff(right, 0).await;
+
+    // Assert
+    match diff {
+        Some(Divergence::Mismatch {
+            index,
+            left: StreamItem::Value(left),
+            right: StreamItem::Value(right),
+        }) => {
+            assert_eq!(index, 1);
+            assert_eq!(left.value, person_bob());
+            assert_eq!(right.value, animal_dog());
+        }
+        other => panic!("Expected a mismatch at index 1, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_stops_at_divergence_without_waiting_for_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel::<Sequenced<i32>>();
+    tx_left.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(2, 1))?;
+
+    // Act
+    let equal = left.sequence_equal(right, 0).await;
+
+    // Assert
+    assert!(!equal);
+    drop((tx_left, tx_right));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_reports_left_ending_first() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel::<Sequenced<i32>>();
+    tx_left.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    tx_right.unbounded_send(Sequenced::with_timestamp(2, 2))?;
+    drop(tx_left);
+
+    // Act
+    let diff = left.sequence_diff(right, 0).await;
+
+    // Assert
+    match diff {
+        Some(Divergence::LeftEnded {
+            index,
+            right: StreamItem::Value(right),
+        }) => {
+            assert_eq!(index, 1);
+            assert_eq!(right.value, 2);
+        }
+        other => panic!("Expected the left stream to end first, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequence_diff_reports_right_ending_first() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_left, left) = test_channel::<Sequenced<i32>>();
+    let (tx_right, right) = test_channel::<Sequenced<i32>>();
+    tx_left.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+    drop(tx_right);
+
+    // Act
+    let diff = left.sequence_diff(right, 0).await;
+
+    // Assert
+    assert!(matches!(
+        diff,
+        Some(Divergence::RightEnded { index: 0, .. })
+    ));
+    assert_eq!(diff.map(|divergence| divergence.index()), Some(0));
+
+    Ok(())
+}
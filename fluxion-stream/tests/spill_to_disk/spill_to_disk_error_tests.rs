@@ -0,0 +1,125 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::{SpillCodec, SpillConfig, SpillToDiskExt};
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+use std::fs;
+use std::path::PathBuf;
+
+/// Refuses to decode values above `max`.
+struct CappedCodec {
+    max: u64,
+}
+
+impl SpillCodec<Sequenced<u64>> for CappedCodec {
+    fn encode(&self, item: &Sequenced<u64>, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&item.value.to_le_bytes());
+        buf.extend_from_slice(&item.timestamp().to_le_bytes());
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Sequenced<u64>, FluxionError> {
+        let field = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        match field(0) {
+            value if value > self.max => Err(FluxionError::stream_error("corrupt record")),
+            value => Ok(Sequenced::with_timestamp(value, field(8))),
+        }
+    }
+}
+
+fn spill_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fluxion-spill-to-disk-{test}"));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_forwards_source_error_in_order_and_continues() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("source_error");
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 2), CappedCodec { max: 100 });
+
+    // Act
+    for value in 1..=7 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(value, value)))?;
+    }
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error(
+        "source failed",
+    )))?;
+    for value in 8..=11 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(value, value)))?;
+    }
+    drop(tx);
+
+    // Assert
+    for value in 1..=7 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(item.value, value);
+    }
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    for value in 8..=11 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(item.value, value);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_forwards_source_error_without_backlog() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("source_error_no_backlog");
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 2), CappedCodec { max: 100 });
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error(
+        "source failed",
+    )))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(item.value, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_ends_with_error_on_undecodable_block() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("decode_error");
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 2), CappedCodec { max: 2 });
+
+    // Act
+    for value in 1..=8 {
+        tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(value, value)))?;
+    }
+
+    // Assert
+    for value in 1..=2 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(item.value, value);
+    }
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp};
+use fluxion_stream::{SpillCodec, SpillConfig, SpillToDiskExt};
+use fluxion_test_utils::{
+    helpers::{
+        assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+    },
+    sequenced::Sequenced,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::time::sleep;
+
+struct U64Codec;
+
+impl SpillCodec<Sequenced<u64>> for U64Codec {
+    fn encode(&self, item: &Sequenced<u64>, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&item.value.to_le_bytes());
+        buf.extend_from_slice(&item.timestamp().to_le_bytes());
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Sequenced<u64>, FluxionError> {
+        let field = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        Ok(Sequenced::with_timestamp(field(0), field(8)))
+    }
+}
+
+fn spill_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fluxion-spill-to-disk-{test}"));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn spill_files(dir: &Path) -> usize {
+    fs::read_dir(dir).map_or(0, |entries| entries.count())
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_emits_spilled_items_in_order() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("in_order");
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 2), U64Codec);
+
+    // Act
+    for value in 1..=9 {
+        tx.unbounded_send(Sequenced::with_timestamp(value, value))?;
+    }
+
+    // Assert
+    for value in 1..=9 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((item.value, item.timestamp()), (value, value));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_keeps_order_when_consumer_catches_up_between_spills(
+) -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("catch_up");
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 3), U64Codec);
+
+    // Act & Assert
+    let mut next = 1;
+    for burst in [10, 2, 7, 1, 12] {
+        for value in next..next + burst {
+            tx.unbounded_send(Sequenced::with_timestamp(value, value))?;
+        }
+        for value in next..next + burst {
+            let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+            assert_eq!(item.value, value);
+        }
+        next += burst;
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_drains_backlog_after_source_ends() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("source_ends");
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 4), U64Codec);
+
+    // Act
+    for value in 1..=11 {
+        tx.unbounded_send(Sequenced::with_timestamp(value * 10, value))?;
+    }
+    drop(tx);
+
+    // Assert
+    for value in 1..=11 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(item.value, value * 10);
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spill_to_disk_removes_spill_file_when_dropped() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("removes_file");
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.spill_to_disk(SpillConfig::new(&dir, 1), U64Codec);
+
+    // Act
+    for value in 1..=5 {
+        tx.unbounded_send(Sequenced::with_timestamp(value, value))?;
+    }
+    sleep(Duration::from_millis(100)).await;
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(first.value, 1);
+    assert_eq!(spill_files(&dir), 1);
+    drop(result);
+    sleep(Duration::from_millis(100)).await;
+
+    // Assert
+    assert_eq!(spill_files(&dir), 0);
+
+    Ok(())
+}
+
+#[cfg(feature = "spill-zstd")]
+#[tokio::test]
+async fn test_spill_to_disk_reads_back_zstd_compressed_blocks() -> anyhow::Result<()> {
+    // Arrange
+    let dir = spill_dir("zstd");
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let config = SpillConfig::new(&dir, 16).with_zstd(3);
+    let mut result = stream.spill_to_disk(config, U64Codec);
+
+    // Act
+    for value in 0..200 {
+        tx.unbounded_send(Sequenced::with_timestamp(value % 4, value))?;
+    }
+    drop(tx);
+
+    // Assert
+    for value in 0..200 {
+        let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!((item.value, item.timestamp()), (value % 4, value));
+    }
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -5,4 +5,5 @@
 pub mod start_with_composition_error_tests;
 pub mod start_with_composition_tests;
 pub mod start_with_error_tests;
+pub mod start_with_latest_of_tests;
 pub mod start_with_tests;
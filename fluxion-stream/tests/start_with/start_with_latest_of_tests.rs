@@ -0,0 +1,139 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_stream::{from_timestamped_iter, StartWithLatestOfExt};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, test_channel_with_errors,
+    unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+#[tokio::test]
+async fn test_start_with_latest_of_waits_for_seed() -> anyhow::Result<()> {
+    // Arrange
+    let (seed_tx, seed) = test_channel::<Sequenced<i32>>();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.start_with_latest_of(seed);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 5))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    seed_tx.unbounded_send(Sequenced::with_timestamp(100, 1))?;
+
+    // Assert
+    let seeded = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((seeded.value, seeded.timestamp()), (100, 1));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_start_with_latest_of_takes_latest_ready_seed() -> anyhow::Result<()> {
+    // Arrange
+    let seed = from_timestamped_iter::<Sequenced<i32>, _>([(10, 1), (20, 2), (30, 3)]);
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.start_with_latest_of(seed);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 4))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        30
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_start_with_latest_of_ignores_later_seed_values() -> anyhow::Result<()> {
+    // Arrange
+    let (seed_tx, seed) = test_channel::<Sequenced<i32>>();
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.start_with_latest_of(seed);
+
+    // Act
+    seed_tx.unbounded_send(Sequenced::with_timestamp(10, 1))?;
+    unwrap_stream(&mut result, 500).await;
+    seed_tx
+        .unbounded_send(Sequenced::with_timestamp(20, 2))
+        .ok();
+    tx.unbounded_send(Sequenced::with_timestamp(1, 3))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_start_with_latest_of_forwards_source_when_seed_ends_empty() -> anyhow::Result<()> {
+    // Arrange
+    let seed = from_timestamped_iter::<Sequenced<i32>, _>([]);
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.start_with_latest_of(seed);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 1))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_start_with_latest_of_passes_seed_errors_while_waiting() -> anyhow::Result<()> {
+    // Arrange
+    let (seed_tx, seed) = test_channel_with_errors::<Sequenced<i32>>();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.start_with_latest_of(seed);
+
+    // Act
+    seed_tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("config")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    seed_tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(10, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("source")))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        10
+    );
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+
+    Ok(())
+}
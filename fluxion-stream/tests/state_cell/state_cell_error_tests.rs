@@ -0,0 +1,34 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::StateCellExt;
+use fluxion_test_utils::helpers::test_channel_with_errors;
+use fluxion_test_utils::sequenced::Sequenced;
+use tokio::time::timeout;
+
+#[tokio::test]
+async fn test_state_cell_error_ends_source_and_keeps_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut cell = stream.to_state_cell();
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(2)))?;
+
+    // Assert
+    timeout(Duration::from_millis(500), cell.changed()).await??;
+    let result = timeout(Duration::from_millis(500), cell.changed()).await?;
+    assert!(matches!(
+        result,
+        Err(FluxionError::StreamProcessingError { context }) if context == "test error"
+    ));
+    assert_eq!(cell.current().map(Sequenced::into_inner), Some(1));
+    assert!(cell.is_closed());
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::FluxionError;
+use fluxion_stream::StateCellExt;
+use fluxion_test_utils::helpers::test_channel;
+use fluxion_test_utils::sequenced::Sequenced;
+use tokio::time::timeout;
+
+#[tokio::test]
+async fn test_state_cell_holds_latest_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut cell = stream.to_state_cell();
+
+    // Assert
+    assert_eq!(cell.current(), None);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    timeout(Duration::from_millis(500), cell.changed()).await??;
+
+    // Assert
+    assert_eq!(cell.current().map(Sequenced::into_inner), Some(1));
+
+    // Act
+    tx.unbounded_send(Sequenced::new(2))?;
+    tx.unbounded_send(Sequenced::new(3))?;
+    drop(tx);
+    while timeout(Duration::from_millis(500), cell.changed())
+        .await?
+        .is_ok()
+    {}
+
+    // Assert
+    assert_eq!(cell.current().map(Sequenced::into_inner), Some(3));
+    assert!(cell.is_closed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_cell_changed_fails_once_source_completed() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut cell = stream.to_state_cell();
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    drop(tx);
+
+    // Assert
+    timeout(Duration::from_millis(500), cell.changed()).await??;
+    let result = timeout(Duration::from_millis(500), cell.changed()).await?;
+    assert!(matches!(
+        result,
+        Err(FluxionError::StreamProcessingError { .. })
+    ));
+    assert_eq!(cell.current().map(Sequenced::into_inner), Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_cell_clones_report_changes_independently() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut first = stream.to_state_cell();
+    let mut second = first.clone();
+
+    // Act
+    tx.unbounded_send(Sequenced::new(1))?;
+    timeout(Duration::from_millis(500), first.changed()).await??;
+
+    // Assert
+    timeout(Duration::from_millis(500), second.changed()).await??;
+    assert!(timeout(Duration::from_millis(100), first.changed())
+        .await
+        .is_err());
+    assert_eq!(second.current().map(Sequenced::into_inner), Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_cell_keeps_running_while_a_clone_is_alive() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let cell = stream.to_state_cell();
+    let mut clone = cell.clone();
+    drop(cell);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(7))?;
+
+    // Assert
+    timeout(Duration::from_millis(500), clone.changed()).await??;
+    assert_eq!(clone.current().map(Sequenced::into_inner), Some(7));
+
+    Ok(())
+}
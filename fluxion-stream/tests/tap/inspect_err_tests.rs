@@ -0,0 +1,103 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{OnErrorExt, TapExt};
+use fluxion_test_utils::helpers::{
+    assert_stream_ended, test_channel_with_errors, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_inspect_err_observes_errors_without_consuming_them() -> anyhow::Result<()> {
+    // Arrange
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.inspect_err(move |error| {
+        seen_clone.lock().unwrap().push(error.to_string());
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("first")))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::user_error("second")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(FluxionError::UserError { .. })
+    ));
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            "Stream processing error: first".to_string(),
+            "User error: second".to_string()
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inspect_err_not_called_for_values() -> anyhow::Result<()> {
+    // Arrange
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.inspect_err(move |error: &FluxionError| {
+        seen_clone.lock().unwrap().push(error.to_string());
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_bob())))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_bob()
+    );
+    assert_stream_ended(&mut result, 500).await;
+    assert!(seen.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inspect_err_before_on_error_sees_consumed_errors() -> anyhow::Result<()> {
+    // Arrange
+    let seen = Arc::new(Mutex::new(0));
+    let seen_clone = seen.clone();
+
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream
+        .inspect_err(move |_| *seen_clone.lock().unwrap() += 1)
+        .on_error(|_| true);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("logged")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        person_alice()
+    );
+    assert_eq!(*seen.lock().unwrap(), 1);
+
+    Ok(())
+}
@@ -2,6 +2,7 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+pub mod inspect_err_tests;
 pub mod tap_composition_error_tests;
 pub mod tap_composition_tests;
 pub mod tap_error_tests;
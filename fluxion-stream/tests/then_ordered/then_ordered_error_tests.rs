@@ -0,0 +1,45 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `then_ordered` operator.
+
+use core::time::Duration;
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::ThenOrderedExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_then_ordered_concurrent_keeps_error_position() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.then_ordered_concurrent(4, |item: Sequenced<i32>| async move {
+        sleep(Duration::from_millis(30)).await;
+        item
+    });
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(1, 1)))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("Error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(item) if item.value == 1
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(item) if item.value == 2
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::HasTimestamp;
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::time::sleep;
+
+async fn delayed_double(item: Sequenced<u64>) -> Sequenced<u64> {
+    // Earlier items take longer, so they would finish last without ordering
+    sleep(Duration::from_millis(40 - item.value * 10)).await;
+    Sequenced::with_timestamp(item.value * 2, item.timestamp())
+}
+
+#[tokio::test]
+async fn test_then_ordered_transforms_each_value() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.then_ordered(delayed_double);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(1, 10))?;
+    tx.unbounded_send(Sequenced::with_timestamp(2, 20))?;
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.value, first.timestamp()), (2, 10));
+    let second = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((second.value, second.timestamp()), (4, 20));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_then_ordered_concurrent_emits_in_source_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let mut result = stream.then_ordered_concurrent(3, delayed_double);
+
+    // Act
+    for value in 1..=3 {
+        tx.unbounded_send(Sequenced::with_timestamp(value, value))?;
+    }
+
+    // Assert
+    for expected in 1..=3 {
+        let item = unwrap_stream(&mut result, 500).await.unwrap();
+        assert_eq!((item.value, item.timestamp()), (expected * 2, expected));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_then_ordered_concurrent_respects_limit() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u64>>();
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let mut result = stream.then_ordered_concurrent(2, {
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        move |item: Sequenced<u64>| {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                item
+            }
+        }
+    });
+
+    // Act
+    for value in 1..=6 {
+        tx.unbounded_send(Sequenced::with_timestamp(value, value))?;
+    }
+    drop(tx);
+
+    // Assert
+    for expected in 1..=6 {
+        assert_eq!(
+            unwrap_stream(&mut result, 500).await.unwrap().value,
+            expected
+        );
+    }
+    assert_stream_ended(&mut result, 500).await;
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "limit of at least 1")]
+fn test_then_ordered_concurrent_rejects_zero_limit() {
+    let (_tx, stream) = test_channel::<Sequenced<u64>>();
+    let _ = stream.then_ordered_concurrent(0, |item: Sequenced<u64>| async move { item });
+}
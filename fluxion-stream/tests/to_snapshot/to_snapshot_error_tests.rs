@@ -0,0 +1,43 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `to_snapshot` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::ToSnapshotExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+use std::collections::BTreeMap;
+
+#[tokio::test]
+async fn test_to_snapshot_error_keeps_map() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<(&str, u32)>>();
+    let mut result = stream
+        .to_snapshot::<Sequenced<BTreeMap<&str, (&str, u32)>>, _, _, _>(10u64, |(key, _)| *key);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((("a", 1), 0).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("changelog")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((("b", 2), 5).into()))?;
+    tx.unbounded_send(StreamItem::Value((("a", 3), 10).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        BTreeMap::from([("a", ("a", 1)), ("b", ("b", 2))])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::ToSnapshotExt;
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use std::collections::BTreeMap;
+
+type Update = (&'static str, u32);
+type Snapshot = Sequenced<BTreeMap<&'static str, Update>>;
+
+#[tokio::test]
+async fn test_to_snapshot_emits_map_per_changed_period() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Update>>();
+    let mut result = stream.to_snapshot::<Snapshot, _, _, _>(10u64, |(key, _)| *key);
+
+    // Act
+    tx.unbounded_send((("a", 1), 0).into())?;
+    tx.unbounded_send((("b", 2), 5).into())?;
+    tx.unbounded_send((("a", 3), 8).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((("b", 4), 42).into())?;
+
+    // Assert
+    let snapshot = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(snapshot.timestamp(), 10);
+    assert_eq!(
+        snapshot.value,
+        BTreeMap::from([("a", ("a", 3)), ("b", ("b", 2))])
+    );
+
+    // Act
+    drop(tx);
+
+    // Assert
+    let snapshot = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(snapshot.timestamp(), 50);
+    assert_eq!(
+        snapshot.value,
+        BTreeMap::from([("a", ("a", 3)), ("b", ("b", 4))])
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_to_snapshot_keeps_newer_value_over_late_one() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Update>>();
+    let mut result = stream.to_snapshot::<Snapshot, _, _, _>(10u64, |(key, _)| *key);
+
+    // Act
+    tx.unbounded_send((("a", 2), 6).into())?;
+    tx.unbounded_send((("a", 1), 3).into())?;
+    tx.unbounded_send((("b", 1), 4).into())?;
+    drop(tx);
+
+    // Assert
+    let snapshot = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        snapshot.value,
+        BTreeMap::from([("a", ("a", 2)), ("b", ("b", 1))])
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_to_snapshot_when_emits_map_on_trigger() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Update>>();
+    let (trigger_tx, trigger) = test_channel::<Sequenced<Update>>();
+    let mut result = stream.to_snapshot_when::<Snapshot, _, _, _>(trigger, |(key, _)| *key);
+
+    // Act
+    trigger_tx.unbounded_send((("sync", 0), 1).into())?;
+
+    // Assert
+    let snapshot = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(snapshot.timestamp(), 1);
+    assert!(snapshot.value.is_empty());
+
+    // Act
+    tx.unbounded_send((("a", 1), 2).into())?;
+    tx.unbounded_send((("a", 5), 3).into())?;
+    tx.unbounded_send((("b", 7), 4).into())?;
+    trigger_tx.unbounded_send((("sync", 0), 5).into())?;
+
+    // Assert
+    let snapshot = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(snapshot.timestamp(), 5);
+    assert_eq!(
+        snapshot.value,
+        BTreeMap::from([("a", ("a", 5)), ("b", ("b", 7))])
+    );
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_to_snapshot_when_repeats_unchanged_map() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<Update>>();
+    let (trigger_tx, trigger) = test_channel::<Sequenced<Update>>();
+    let mut result = stream.to_snapshot_when::<Snapshot, _, _, _>(trigger, |(key, _)| *key);
+
+    // Act
+    tx.unbounded_send((("a", 1), 1).into())?;
+    trigger_tx.unbounded_send((("sync", 0), 2).into())?;
+    trigger_tx.unbounded_send((("sync", 0), 3).into())?;
+
+    // Assert
+    for timestamp in [2, 3] {
+        let snapshot = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+        assert_eq!(snapshot.timestamp(), timestamp);
+        assert_eq!(snapshot.value, BTreeMap::from([("a", ("a", 1))]));
+    }
+
+    Ok(())
+}
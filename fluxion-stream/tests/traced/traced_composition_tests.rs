@@ -0,0 +1,104 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, Traced};
+use fluxion_stream::{
+    FilterOrderedExt, OrderedStreamExt, TakeLatestWhenExt, TraceSpanExt, TracedExt,
+};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::span_recorder::SpanRecorder;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_traced_through_ordered_merge_preserves_order_and_context() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (tx1, s1) = test_channel::<Sequenced<TestData>>();
+    let (tx2, s2) = test_channel::<Sequenced<TestData>>();
+
+    let mut result = s1
+        .traced()
+        .ordered_merge(vec![s2.traced()])
+        .trace_span("merge");
+
+    // Act
+    tx1.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+    tx2.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    assert_eq!(first.timestamp(), 1);
+    assert_eq!(second.timestamp(), 2);
+
+    let first_id = first
+        .span()
+        .id()
+        .expect("span should be enabled")
+        .into_u64();
+    assert_eq!(
+        recorder.ancestry(first_id),
+        vec!["fluxion.operator", "fluxion.item"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_traced_filter_then_trace_span() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+
+    let mut result = stream
+        .traced()
+        .trace_span("source")
+        .filter_ordered(|data| **data != animal_dog())
+        .trace_span("filter");
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 2))?;
+    let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    assert_eq!(item.inner().value, person_alice());
+    let id = item.span().id().expect("span should be enabled").into_u64();
+    assert_eq!(
+        recorder.ancestry(id),
+        vec!["fluxion.operator", "fluxion.operator", "fluxion.item"]
+    );
+    assert_eq!(recorder.spans_named("fluxion.operator").len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_traced_keeps_span_through_take_latest_when() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (source_tx, source) = test_channel::<Traced<Sequenced<TestData>>>();
+    let (trigger_tx, trigger) = test_channel::<Traced<Sequenced<TestData>>>();
+
+    let mut result = source.take_latest_when(trigger, |_| true);
+
+    let item = Traced::new(Sequenced::with_timestamp(person_alice(), 1));
+    let span_id = item.span().id().expect("span should be enabled");
+
+    // Act
+    source_tx.unbounded_send(item)?;
+    trigger_tx.unbounded_send(Traced::new(Sequenced::with_timestamp(person_bob(), 2)))?;
+    let sampled = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    assert_eq!(sampled.timestamp(), 2);
+    assert_eq!(sampled.inner().value, person_alice());
+    assert_eq!(sampled.span().id(), Some(span_id));
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{TraceSpanExt, TracedExt};
+use fluxion_test_utils::helpers::{test_channel_with_errors, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::span_recorder::SpanRecorder;
+use fluxion_test_utils::test_data::{person_alice, person_bob, TestData};
+
+#[tokio::test]
+async fn test_traced_passes_through_errors() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.traced().trace_span("stage");
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+        person_alice(),
+        1,
+    )))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(
+        person_bob(),
+        2,
+    )))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await))
+            .inner()
+            .value,
+        person_alice()
+    );
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await))
+            .inner()
+            .value,
+        person_bob()
+    );
+    assert_eq!(recorder.spans_named("fluxion.item").len(), 2);
+    assert_eq!(recorder.spans_named("fluxion.operator").len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{TraceSpanExt, TracedExt};
+use fluxion_test_utils::helpers::{test_channel, unwrap_stream, unwrap_value};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::span_recorder::SpanRecorder;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, TestData};
+
+#[tokio::test]
+async fn test_traced_values_pass_through_unchanged() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.traced();
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+
+    // Assert
+    let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(item.inner().value, person_alice());
+    assert_eq!(item.timestamp(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_traced_creates_item_span_per_value() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.traced();
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 2))?;
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    let spans = recorder.spans_named("fluxion.item");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[0].field("timestamp"), Some("1"));
+    assert_eq!(spans[1].field("timestamp"), Some("2"));
+    assert_ne!(first.span().id(), second.span().id());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_trace_span_nests_operator_spans() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.traced().trace_span("first").trace_span("second");
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    let item = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    let id = item.span().id().expect("span should be enabled").into_u64();
+    assert_eq!(
+        recorder.ancestry(id),
+        vec!["fluxion.operator", "fluxion.operator", "fluxion.item"]
+    );
+
+    let operators = recorder.spans_named("fluxion.operator");
+    assert_eq!(operators[0].field("operator"), Some("first"));
+    assert_eq!(operators[1].field("operator"), Some("second"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_trace_span_keeps_traces_separate_per_item() -> anyhow::Result<()> {
+    // Arrange
+    let recorder = SpanRecorder::new();
+    let _guard = recorder.set_default();
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.traced().trace_span("stage");
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(animal_dog(), 2))?;
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+
+    // Assert
+    let roots = recorder.spans_named("fluxion.item");
+    let first_parent = recorder
+        .spans()
+        .into_iter()
+        .find(|span| Some(span.id) == first.span().id().map(|id| id.into_u64()))
+        .and_then(|span| span.parent);
+    let second_parent = recorder
+        .spans()
+        .into_iter()
+        .find(|span| Some(span.id) == second.span().id().map(|id| id.into_u64()))
+        .and_then(|span| span.parent);
+    assert_eq!(first_parent, Some(roots[0].id));
+    assert_eq!(second_parent, Some(roots[1].id));
+
+    Ok(())
+}
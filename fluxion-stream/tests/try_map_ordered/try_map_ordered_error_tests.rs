@@ -0,0 +1,76 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error tests for `try_map_ordered` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::TryMapOrderedExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, PartialEq)]
+struct Overflow(i32);
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} overflows", self.0)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+fn checked_double(item: Sequenced<i32>) -> Result<Sequenced<i32>, Overflow> {
+    item.value
+        .checked_mul(2)
+        .map(|value| Sequenced::with_timestamp(value, 0))
+        .ok_or(Overflow(item.value))
+}
+
+#[tokio::test]
+async fn test_try_map_ordered_wraps_err() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.try_map_ordered(checked_double);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(i32::MAX, 1)))?;
+
+    // Assert
+    match unwrap_stream(&mut result, 100).await {
+        StreamItem::Error(error) => {
+            assert!(matches!(error, FluxionError::Wrapped { .. }));
+            assert_eq!(error.root_cause().to_string(), "2147483647 overflows");
+            assert_eq!(error.downcast_ref::<Overflow>(), Some(&Overflow(i32::MAX)));
+        }
+        StreamItem::Value(_) => panic!("expected an error"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_try_map_ordered_propagates_upstream_errors() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<i32>>();
+    let mut result = stream.try_map_ordered(checked_double);
+
+    // Act
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("upstream")))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::with_timestamp(2, 2)))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Value(item) if item.value == 4
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, TestData},
+};
+use std::io::Error;
+
+fn person_age(item: Sequenced<TestData>) -> Result<Sequenced<u32>, Error> {
+    let timestamp = item.timestamp();
+    match item.value {
+        TestData::Person(person) => Ok(Sequenced::with_timestamp(person.age, timestamp)),
+        other => Err(Error::other(format!("not a person: {other}"))),
+    }
+}
+
+#[tokio::test]
+async fn test_try_map_ordered_emits_ok_values() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.try_map_ordered(person_age);
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.value, first.timestamp()), (25, 1));
+    let second = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((second.value, second.timestamp()), (30, 2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_try_map_ordered_continues_after_err() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.try_map_ordered(person_age);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    drop(tx);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(age) if age.value == 25
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Value(age) if age.value == 30
+    ));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_try_map_ordered_errors_recovered_by_on_error() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.try_map_ordered(person_age).on_error(|_| true);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 30);
+
+    Ok(())
+}
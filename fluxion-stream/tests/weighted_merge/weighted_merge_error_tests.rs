@@ -0,0 +1,33 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::WeightedMergeExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_weighted_merge_errors_take_a_share_of_the_turn() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel_with_errors::<Sequenced<char>>();
+    let (tx2, stream2) = test_channel_with_errors::<Sequenced<char>>();
+    tx1.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+    tx1.unbounded_send(StreamItem::Value(Sequenced::with_timestamp('a', 1)))?;
+    tx2.unbounded_send(StreamItem::Value(Sequenced::with_timestamp('b', 2)))?;
+
+    // Act
+    let mut result = stream1.weighted_merge(1, vec![(stream2, 1)]);
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 500).await,
+        StreamItem::Error(_)
+    ));
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 'b');
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 'a');
+
+    Ok(())
+}
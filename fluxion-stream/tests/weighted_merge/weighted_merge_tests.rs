@@ -0,0 +1,125 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_stream::WeightedMergeExt;
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_weighted_merge_shares_output_by_weight() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<char>>();
+    let (tx2, stream2) = test_channel::<Sequenced<char>>();
+    let (tx3, stream3) = test_channel::<Sequenced<char>>();
+    for n in 0..6 {
+        tx1.unbounded_send(Sequenced::with_timestamp('a', n))?;
+        tx2.unbounded_send(Sequenced::with_timestamp('b', 100 + n))?;
+        tx3.unbounded_send(Sequenced::with_timestamp('c', 200 + n))?;
+    }
+
+    // Act
+    let mut result = stream1.weighted_merge(3, vec![(stream2, 1), (stream3, 2)]);
+
+    // Assert
+    let mut emitted = String::new();
+    for _ in 0..12 {
+        emitted.push(unwrap_stream(&mut result, 500).await.unwrap().value);
+    }
+    assert_eq!(emitted, "aaabccaaabcc");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_weighted_merge_preserves_per_source_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<u64>>();
+    let (tx2, stream2) = test_channel::<Sequenced<u64>>();
+    for n in 0..4 {
+        tx1.unbounded_send(Sequenced::with_timestamp(n, 10 - n))?;
+        tx2.unbounded_send(Sequenced::with_timestamp(100 + n, n))?;
+    }
+    drop(tx1);
+    drop(tx2);
+
+    // Act
+    let mut result = stream1.weighted_merge(1, vec![(stream2, 1)]);
+
+    // Assert
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+    for _ in 0..8 {
+        let value = unwrap_stream(&mut result, 500).await.unwrap().value;
+        if value < 100 {
+            first.push(value);
+        } else {
+            second.push(value);
+        }
+    }
+    assert_eq!(first, vec![0, 1, 2, 3]);
+    assert_eq!(second, vec![100, 101, 102, 103]);
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_weighted_merge_does_not_wait_for_stream_without_items() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<char>>();
+    let (_tx2, stream2) = test_channel::<Sequenced<char>>();
+    let mut result = stream1.weighted_merge(1, vec![(stream2, 5)]);
+
+    // Act
+    for n in 0..3 {
+        tx1.unbounded_send(Sequenced::with_timestamp('a', n))?;
+    }
+
+    // Assert
+    for _ in 0..3 {
+        assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 'a');
+    }
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_weighted_merge_continues_after_stream_ends() -> anyhow::Result<()> {
+    // Arrange
+    let (tx1, stream1) = test_channel::<Sequenced<char>>();
+    let (tx2, stream2) = test_channel::<Sequenced<char>>();
+    tx1.unbounded_send(Sequenced::with_timestamp('a', 1))?;
+    drop(tx1);
+    for n in 0..3 {
+        tx2.unbounded_send(Sequenced::with_timestamp('b', 10 + n))?;
+    }
+    drop(tx2);
+
+    // Act
+    let mut result = stream1.weighted_merge(2, vec![(stream2, 1)]);
+
+    // Assert
+    let mut emitted = String::new();
+    for _ in 0..4 {
+        emitted.push(unwrap_stream(&mut result, 500).await.unwrap().value);
+    }
+    assert_eq!(emitted, "abbb");
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "weighted_merge: weights must be at least 1")]
+fn test_weighted_merge_rejects_zero_weight() {
+    // Arrange
+    let (_tx1, stream1) = test_channel::<Sequenced<char>>();
+    let (_tx2, stream2) = test_channel::<Sequenced<char>>();
+
+    // Act
+    let _ = stream1.weighted_merge(1, vec![(stream2, 0)]);
+}
@@ -247,3 +247,33 @@ async fn test_window_by_count_partial_after_error_on_close() -> anyhow::Result<(
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_window_by_count_aggregate_error_discards_partial_aggregate() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u32>>();
+    let mut result =
+        stream.window_by_count_aggregate::<Sequenced<u32>, _>(2, || 0, |sum, value| *sum += value);
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((5, 1).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((1, 2).into()))?;
+    tx.unbounded_send(StreamItem::Value((2, 3).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 100).await)).value,
+        3
+    );
+
+    Ok(())
+}
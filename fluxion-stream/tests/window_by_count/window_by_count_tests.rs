@@ -320,3 +320,26 @@ async fn test_window_zero_panics() {
     // Act & Assert
     let _ = stream.window_by_count::<Sequenced<Vec<TestData>>>(0);
 }
+
+#[tokio::test]
+async fn test_window_by_count_aggregate_emits_aggregates() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result =
+        stream.window_by_count_aggregate::<Sequenced<u32>, _>(2, || 0, |sum, value| *sum += value);
+
+    // Act
+    tx.unbounded_send((1, 10).into())?;
+    tx.unbounded_send((2, 20).into())?;
+    tx.unbounded_send((3, 30).into())?;
+    drop(tx);
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((first.value, first.timestamp()), (3, 20));
+    let partial = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((partial.value, partial.timestamp()), (3, 30));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
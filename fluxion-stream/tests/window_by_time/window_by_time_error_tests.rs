@@ -0,0 +1,49 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `window_by_time_aggregate` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{WindowByTimeExt, WindowUpdate};
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+#[tokio::test]
+async fn test_window_by_time_error_keeps_windows() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<u32>>();
+    let mut result = stream.window_by_time_aggregate::<Sequenced<_>, _, _>(
+        10u64,
+        0u64,
+        || 0,
+        |sum, value| *sum += value,
+    );
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((1, 0).into()))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx.unbounded_send(StreamItem::Value((2, 5).into()))?;
+    tx.unbounded_send(StreamItem::Value((4, 10).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        WindowUpdate::Insert {
+            window: 0,
+            aggregate: 3
+        }
+    );
+
+    Ok(())
+}
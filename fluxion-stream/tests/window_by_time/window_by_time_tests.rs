@@ -0,0 +1,237 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_stream::{WindowByTimeExt, WindowUpdate};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::Stream;
+
+fn sums(
+    source: impl Stream<Item = StreamItem<Sequenced<u32>>> + Send + Sync + 'static,
+    allowed_lateness: u64,
+) -> impl Stream<Item = StreamItem<Sequenced<WindowUpdate<u32>>>> + Send + Sync {
+    source.window_by_time_aggregate::<Sequenced<_>, _, _>(
+        10u64,
+        allowed_lateness,
+        || 0,
+        |sum, value| *sum += value,
+    )
+}
+
+fn insert(window: u64, aggregate: u32) -> WindowUpdate<u32> {
+    WindowUpdate::Insert { window, aggregate }
+}
+
+fn retract(window: u64, aggregate: u32) -> WindowUpdate<u32> {
+    WindowUpdate::Retract { window, aggregate }
+}
+
+#[tokio::test]
+async fn test_window_by_time_emits_windows_passed_by_watermark() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 0);
+
+    // Act
+    tx.unbounded_send((1, 100).into())?;
+    tx.unbounded_send((2, 109).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx.unbounded_send((4, 112).into())?;
+    tx.unbounded_send((8, 135).into())?;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((first.timestamp(), first.value), (112, insert(10, 3)));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((second.timestamp(), second.value), (135, insert(11, 4)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_late_item_retracts_and_updates() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 10);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 15).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(0, 1)
+    );
+
+    // Act
+    tx.unbounded_send((10, 3).into())?;
+
+    // Assert
+    let retracted = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (retracted.timestamp(), retracted.value),
+        (15, retract(0, 1))
+    );
+    let corrected = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(
+        (corrected.timestamp(), corrected.value),
+        (15, insert(0, 11))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_drops_items_later_than_allowed() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 5);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 16).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(0, 1)
+    );
+
+    // Act
+    tx.unbounded_send((10, 9).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_without_lateness_drops_late_items() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 0);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 10).into())?;
+    tx.unbounded_send((10, 9).into())?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(0, 1)
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(1, 2)
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_flushes_pending_windows_on_completion() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 10);
+
+    // Act
+    tx.unbounded_send((1, 0).into())?;
+    tx.unbounded_send((2, 4).into())?;
+    drop(tx);
+
+    // Assert
+    let flushed = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((flushed.timestamp(), flushed.value), (4, insert(0, 3)));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_aligns_windows_to_zero() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 0);
+
+    // Act
+    tx.unbounded_send((1, 5).into())?;
+    tx.unbounded_send((2, 12).into())?;
+    tx.unbounded_send((4, 20).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(0, 1)
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(1, 2)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_accepts_late_item_older_than_first_item() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 20);
+
+    // Act
+    tx.unbounded_send((1, 15).into())?;
+    tx.unbounded_send((2, 25).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(1, 1)
+    );
+
+    // Act
+    tx.unbounded_send((10, 3).into())?;
+
+    // Assert
+    let late = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((late.timestamp(), late.value), (25, insert(0, 10)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_by_time_drops_item_older_than_first_item_beyond_lateness() -> anyhow::Result<()>
+{
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let mut result = sums(stream, 5);
+
+    // Act
+    tx.unbounded_send((1, 15).into())?;
+    tx.unbounded_send((2, 25).into())?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        insert(1, 1)
+    );
+
+    // Act
+    tx.unbounded_send((10, 3).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `window_join` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::{JoinWindow, WindowJoinExt};
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+};
+
+type Event = (u32, &'static str);
+
+#[tokio::test]
+async fn test_window_join_error_keeps_window_contents() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel_with_errors::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel_with_errors::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        |event: &Event| event.0,
+        JoinWindow::Sliding(10u64),
+    );
+
+    // Act
+    tx_orders.unbounded_send(StreamItem::Value(((1, "order"), 1).into()))?;
+    tx_payments.unbounded_send(StreamItem::Error(FluxionError::stream_error("gateway")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx_payments.unbounded_send(StreamItem::Value(((1, "payment"), 2).into()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        ((1, "order"), (1, "payment"))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::{JoinWindow, WindowJoinExt};
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+
+type Event = (u32, &'static str);
+
+fn id(event: &Event) -> u32 {
+    event.0
+}
+
+#[tokio::test]
+async fn test_window_join_sliding_pairs_same_key_within_window() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        id,
+        JoinWindow::Sliding(5u64),
+    );
+
+    // Act
+    tx_orders.unbounded_send(((1, "order"), 10).into())?;
+    tx_payments.unbounded_send(((2, "payment"), 12).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_payments.unbounded_send(((1, "payment"), 15).into())?;
+
+    // Assert
+    let pair = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(pair.value, ((1, "order"), (1, "payment")));
+    assert_eq!(pair.timestamp(), 15);
+
+    // Act
+    tx_payments.unbounded_send(((1, "late payment"), 16).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_join_tumbling_does_not_pair_across_windows() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        id,
+        JoinWindow::Tumbling(10u64),
+    );
+
+    // Act
+    tx_orders.unbounded_send(((1, "order"), 100).into())?;
+    tx_payments.unbounded_send(((1, "payment"), 109).into())?;
+
+    // Assert
+    let pair = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(pair.value, ((1, "order"), (1, "payment")));
+    assert_eq!(pair.timestamp(), 109);
+
+    // Act
+    tx_orders.unbounded_send(((1, "second order"), 110).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_join_sliding_pairs_item_with_older_timestamp() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        id,
+        JoinWindow::Sliding(5u64),
+    );
+
+    // Act
+    tx_orders.unbounded_send(((1, "order"), 10).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_payments.unbounded_send(((1, "payment"), 8).into())?;
+
+    // Assert
+    let pair = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(pair.value, ((1, "order"), (1, "payment")));
+    assert_eq!(pair.timestamp(), 8);
+
+    // Act
+    tx_payments.unbounded_send(((1, "stale payment"), 2).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_join_tumbling_ignores_item_older_than_first() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        id,
+        JoinWindow::Tumbling(10u64),
+    );
+
+    // Act
+    tx_orders.unbounded_send(((1, "order"), 10).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_payments.unbounded_send(((1, "payment"), 8).into())?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_payments.unbounded_send(((1, "payment"), 15).into())?;
+
+    // Assert
+    let pair = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(pair.value, ((1, "order"), (1, "payment")));
+    assert_eq!(pair.timestamp(), 15);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_join_pairs_with_every_match_in_arrival_order() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        id,
+        JoinWindow::Sliding(10u64),
+    );
+
+    // Act
+    tx_payments.unbounded_send(((1, "deposit"), 1).into())?;
+    tx_payments.unbounded_send(((1, "balance"), 2).into())?;
+    tx_orders.unbounded_send(((1, "order"), 3).into())?;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(first.value, ((1, "order"), (1, "deposit")));
+    assert_eq!(first.timestamp(), 3);
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(second.value, ((1, "order"), (1, "balance")));
+    assert_eq!(second.timestamp(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_join_ends_when_both_streams_end() -> anyhow::Result<()> {
+    // Arrange
+    let (tx_orders, orders) = test_channel::<Sequenced<Event>>();
+    let (tx_payments, payments) = test_channel::<Sequenced<Event>>();
+    let mut result = orders.window_join::<Sequenced<(Event, Event)>, _, _, _>(
+        payments,
+        id,
+        JoinWindow::Sliding(10u64),
+    );
+
+    // Act
+    tx_orders.unbounded_send(((1, "order"), 1).into())?;
+    drop(tx_orders);
+    drop(tx_payments);
+
+    // Assert
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
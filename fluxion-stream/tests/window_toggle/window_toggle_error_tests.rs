@@ -0,0 +1,66 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Error propagation tests for `window_toggle` operator.
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_stream::WindowToggleExt;
+use fluxion_test_utils::{
+    helpers::{test_channel_with_errors, unwrap_stream, unwrap_value},
+    sequenced::Sequenced,
+    test_data::{person_alice, person_bob, plant_rose, TestData},
+};
+
+#[tokio::test]
+async fn test_window_toggle_propagates_trigger_error() -> anyhow::Result<()> {
+    // Arrange
+    let (_tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (tx_open, openings) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (_tx_close, closings) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.window_toggle::<Sequenced<Vec<TestData>>, _, _>(openings, closings);
+
+    // Act
+    tx_open.unbounded_send(StreamItem::Error(FluxionError::stream_error("open failed")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_toggle_error_discards_open_windows() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (tx_open, openings) = test_channel_with_errors::<Sequenced<TestData>>();
+    let (tx_close, closings) = test_channel_with_errors::<Sequenced<TestData>>();
+    let mut result = stream.window_toggle::<Sequenced<Vec<TestData>>, _, _>(openings, closings);
+
+    // Act
+    tx_open.unbounded_send(StreamItem::Value(Sequenced::new(plant_rose())))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))?;
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("test error")))?;
+
+    // Assert
+    assert!(matches!(
+        unwrap_stream(&mut result, 100).await,
+        StreamItem::Error(_)
+    ));
+
+    // Act
+    tx_open.unbounded_send(StreamItem::Value(Sequenced::new(plant_rose())))?;
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_bob())))?;
+    tx_close.unbounded_send(StreamItem::Value(Sequenced::new(plant_rose())))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![person_bob()]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::WindowToggleExt;
+use fluxion_test_utils::helpers::{
+    assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream, unwrap_value,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{
+    animal_cat, animal_dog, person_alice, person_bob, person_charlie, plant_rose, TestData,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_window_toggle_collects_between_open_and_close() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_open, openings) = test_channel::<Sequenced<TestData>>();
+    let (tx_close, closings) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.window_toggle::<Sequenced<Vec<TestData>>, _, _>(openings, closings);
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx_open.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    tx.unbounded_send(Sequenced::new(person_charlie()))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    let close = Sequenced::new(plant_rose());
+    let close_timestamp = close.timestamp();
+    tx_close.unbounded_send(close)?;
+
+    // Assert
+    let window = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!(window.value, vec![person_bob(), person_charlie()]);
+    assert_eq!(window.timestamp(), close_timestamp);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_toggle_overlapping_windows() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_open, openings) = test_channel::<Sequenced<TestData>>();
+    let (tx_close, closings) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.window_toggle::<Sequenced<Vec<TestData>>, _, _>(openings, closings);
+
+    // Act
+    tx_open.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx_open.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+    tx_close.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx_close.unbounded_send(Sequenced::new(plant_rose()))?;
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![person_alice(), person_bob()]
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![person_bob(), animal_dog()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_toggle_emits_empty_window_and_ignores_unmatched_close() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_open, openings) = test_channel::<Sequenced<TestData>>();
+    let (tx_close, closings) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.window_toggle::<Sequenced<Vec<TestData>>, _, _>(openings, closings);
+
+    // Act
+    tx_close.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+
+    // Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    // Act
+    tx_open.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx_close.unbounded_send(Sequenced::new(plant_rose()))?;
+
+    // Assert
+    assert!(unwrap_value(Some(unwrap_stream(&mut result, 500).await))
+        .value
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_toggle_flushes_open_windows_on_completion() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let (tx_open, openings) = test_channel::<Sequenced<TestData>>();
+    let (tx_close, closings) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.window_toggle::<Sequenced<Vec<TestData>>, _, _>(openings, closings);
+
+    // Act
+    tx_open.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(animal_cat()))?;
+    tx_open.unbounded_send(Sequenced::new(plant_rose()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    drop(tx);
+    drop(tx_open);
+    drop(tx_close);
+
+    // Assert
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![animal_cat(), animal_dog()]
+    );
+    assert_eq!(
+        unwrap_value(Some(unwrap_stream(&mut result, 500).await)).value,
+        vec![animal_dog()]
+    );
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_toggle_aggregate_folds_each_value_once() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<u32>>();
+    let (tx_open, openings) = test_channel::<Sequenced<u32>>();
+    let (tx_close, closings) = test_channel::<Sequenced<u32>>();
+    let folds = Arc::new(AtomicUsize::new(0));
+    let mut result = stream.window_toggle_aggregate::<Sequenced<u32>, _, _, _>(
+        openings,
+        closings,
+        || 0,
+        {
+            let folds = folds.clone();
+            move |sum, value| {
+                folds.fetch_add(1, Ordering::SeqCst);
+                *sum += value;
+            }
+        },
+        |sum, later| *sum += later,
+    );
+
+    // Act
+    tx_open.unbounded_send((0, 1).into())?;
+    tx.unbounded_send((1, 2).into())?;
+    tx_open.unbounded_send((0, 3).into())?;
+    tx.unbounded_send((10, 4).into())?;
+    tx_open.unbounded_send((0, 5).into())?;
+    tx.unbounded_send((100, 6).into())?;
+    tx_close.unbounded_send((0, 7).into())?;
+    tx_close.unbounded_send((0, 8).into())?;
+
+    // Assert
+    let first = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((first.value, first.timestamp()), (111, 7));
+    let second = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((second.value, second.timestamp()), (110, 8));
+    assert_eq!(folds.load(Ordering::SeqCst), 3);
+
+    // Act
+    drop(tx);
+    drop(tx_open);
+    drop(tx_close);
+
+    // Assert
+    let flushed = unwrap_value(Some(unwrap_stream(&mut result, 500).await));
+    assert_eq!((flushed.value, flushed.timestamp()), (100, 8));
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_stream::prelude::*;
+use fluxion_test_utils::{
+    helpers::{test_channel, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, person_charlie, TestData},
+};
+
+#[tokio::test]
+async fn test_filter_ordered_with_index_counts_only_passing_values() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream
+        .filter_ordered(|data| matches!(data, TestData::Person(_)))
+        .with_index();
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(person_bob()))?;
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.index, first.value.value), (0, person_alice()));
+
+    let second = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((second.index, second.value.value), (1, person_bob()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ordered_merge_with_index_numbers_in_timestamp_order() -> anyhow::Result<()> {
+    // Arrange
+    let (first_tx, first) = test_channel::<Sequenced<TestData>>();
+    let (second_tx, second) = test_channel::<Sequenced<TestData>>();
+    let mut result = first.ordered_merge(vec![second]).with_index();
+
+    // Act
+    first_tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 1))?;
+    second_tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 2))?;
+    first_tx.unbounded_send(Sequenced::with_timestamp(person_charlie(), 3))?;
+
+    // Assert
+    for (expected_index, expected_timestamp) in [(0, 1), (1, 2), (2, 3)] {
+        let item = unwrap_stream(&mut result, 500).await.unwrap();
+        assert_eq!(item.index, expected_index);
+        assert_eq!(item.timestamp(), expected_timestamp);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_index_detects_sequence_gaps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<i32>>();
+    let mut result = stream.with_index().map_ordered(|item| {
+        Sequenced::with_timestamp(item.value.value as u64 - item.index, item.timestamp())
+    });
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(0, 1))?;
+    tx.unbounded_send(Sequenced::with_timestamp(1, 2))?;
+    tx.unbounded_send(Sequenced::with_timestamp(3, 3))?;
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 0);
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 0);
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().value, 1);
+
+    Ok(())
+}
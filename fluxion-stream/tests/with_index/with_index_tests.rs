@@ -0,0 +1,104 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_stream::{Indexed, WithIndexExt};
+use fluxion_test_utils::{
+    helpers::{assert_no_element_emitted, assert_stream_ended, test_channel, unwrap_stream},
+    sequenced::Sequenced,
+    test_data::{animal_dog, person_alice, person_bob, plant_rose, TestData},
+};
+
+#[tokio::test]
+async fn test_with_index_numbers_values_from_zero() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.with_index();
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    tx.unbounded_send(Sequenced::new(animal_dog()))?;
+    tx.unbounded_send(Sequenced::new(plant_rose()))?;
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((first.index, first.value.value), (0, person_alice()));
+
+    let second = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((second.index, second.value.value), (1, animal_dog()));
+
+    let third = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!((third.index, third.value.value), (2, plant_rose()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_index_preserves_timestamps() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.with_index();
+
+    // Act
+    tx.unbounded_send(Sequenced::with_timestamp(person_alice(), 10))?;
+    tx.unbounded_send(Sequenced::with_timestamp(person_bob(), 25))?;
+
+    // Assert
+    let first = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!(first.index, 0);
+    assert_eq!(first.timestamp(), 10);
+
+    let second = unwrap_stream(&mut result, 500).await.unwrap();
+    assert_eq!(second.index, 1);
+    assert_eq!(second.timestamp(), 25);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_index_waits_for_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.with_index();
+
+    // Act & Assert
+    assert_no_element_emitted(&mut result, 100).await;
+
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().index, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_index_ends_with_source() -> anyhow::Result<()> {
+    // Arrange
+    let (tx, stream) = test_channel::<Sequenced<TestData>>();
+    let mut result = stream.with_index();
+
+    // Act
+    tx.unbounded_send(Sequenced::new(person_alice()))?;
+    drop(tx);
+
+    // Assert
+    assert_eq!(unwrap_stream(&mut result, 500).await.unwrap().index, 0);
+    assert_stream_ended(&mut result, 500).await;
+
+    Ok(())
+}
+
+#[test]
+fn test_indexed_unwraps_to_inner_value() {
+    // Arrange
+    let indexed = Indexed::new(7, Sequenced::with_timestamp(person_alice(), 3));
+
+    // Act
+    let rebuilt = Indexed::<Sequenced<TestData>>::with_timestamp(person_bob(), 9);
+
+    // Assert
+    assert_eq!(indexed.timestamp(), 3);
+    assert_eq!(indexed.into_inner(), person_alice());
+    assert_eq!(rebuilt.index, 0);
+    assert_eq!(rebuilt.timestamp(), 9);
+}
@@ -0,0 +1,293 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! A deterministic, single-threaded executor for reproducible ordering tests.
+//!
+//! [`DeterministicExecutor`] polls exactly one ready task per step and picks it with a
+//! seeded random number generator, so every seed describes one interleaving of
+//! producers and consumers and replays it exactly. Nothing depends on wall-clock time:
+//! when no task can make progress the executor reports a stall instead of waiting.
+//!
+//! [`run_interleaved`] covers the common case of feeding several sources into an
+//! operator: each source is a script of items sent one at a time, with the executor
+//! deciding when each send happens relative to polls of the operator. [`check_seeds`]
+//! runs a scenario for many seeds and names the failing seed, which can then be replayed
+//! in isolation.
+//!
+//! Operators that spawn runtime tasks (for example `share` or `partition`) need a real
+//! runtime and cannot run on this executor, and neither can code using tokio timers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::StreamItem;
+//! use fluxion_test_utils::deterministic::{check_seeds, run_interleaved};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::StreamExt;
+//!
+//! check_seeds(0..50, |seed| {
+//!     let sources = vec![
+//!         vec![StreamItem::Value(Sequenced::with_timestamp(1, 1))],
+//!         vec![StreamItem::Value(Sequenced::with_timestamp(2, 2))],
+//!     ];
+//!
+//!     let output = run_interleaved(seed, sources, |mut streams| {
+//!         let second = streams.pop().unwrap();
+//!         let first = streams.pop().unwrap();
+//!         futures::stream::select(first, second)
+//!     });
+//!
+//!     assert_eq!(output.len(), 2);
+//! });
+//! ```
+
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fastrand::Rng;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+use futures::future::LocalBoxFuture;
+use futures::task::{waker, ArcWake};
+use futures::{FutureExt, Stream, StreamExt};
+use std::collections::BTreeSet;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// Maximum number of polls before [`DeterministicExecutor::run`] assumes a livelock.
+pub const DEFAULT_MAX_STEPS: usize = 1_000_000;
+
+type TaskId = usize;
+
+struct ReadyQueue {
+    ready: Mutex<BTreeSet<TaskId>>,
+}
+
+struct TaskWaker {
+    id: TaskId,
+    queue: Arc<ReadyQueue>,
+}
+
+impl ArcWake for TaskWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.queue.ready.lock().unwrap().insert(arc_self.id);
+    }
+}
+
+#[derive(Default)]
+struct Spawned {
+    next_id: TaskId,
+    tasks: Vec<(TaskId, LocalBoxFuture<'static, ()>)>,
+}
+
+/// Handle for spawning tasks onto a [`DeterministicExecutor`], also from inside tasks.
+#[derive(Clone)]
+pub struct Spawner {
+    spawned: Rc<RefCell<Spawned>>,
+    queue: Arc<ReadyQueue>,
+}
+
+impl Spawner {
+    /// Spawns a task. Tasks do not need to be `Send`.
+    pub fn spawn(&self, future: impl Future<Output = ()> + 'static) {
+        let mut spawned = self.spawned.borrow_mut();
+        let id = spawned.next_id;
+        spawned.next_id += 1;
+        spawned.tasks.push((id, future.boxed_local()));
+        self.queue.ready.lock().unwrap().insert(id);
+    }
+}
+
+/// A single-threaded executor that interleaves tasks according to a seed.
+///
+/// See the [module](self) documentation.
+pub struct DeterministicExecutor {
+    rng: Rng,
+    seed: u64,
+    max_steps: usize,
+    spawner: Spawner,
+    tasks: Vec<(TaskId, LocalBoxFuture<'static, ()>)>,
+    trace: Vec<TaskId>,
+}
+
+impl DeterministicExecutor {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng::with_seed(seed),
+            seed,
+            max_steps: DEFAULT_MAX_STEPS,
+            spawner: Spawner {
+                spawned: Rc::new(RefCell::new(Spawned::default())),
+                queue: Arc::new(ReadyQueue {
+                    ready: Mutex::new(BTreeSet::new()),
+                }),
+            },
+            tasks: Vec::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn spawner(&self) -> Spawner {
+        self.spawner.clone()
+    }
+
+    /// Spawns a task. Tasks do not need to be `Send`.
+    pub fn spawn(&self, future: impl Future<Output = ()> + 'static) {
+        self.spawner.spawn(future);
+    }
+
+    /// Ids of the tasks in the order they were polled. Task ids are assigned in spawn
+    /// order, starting at 0; the future passed to [`run`](Self::run) is spawned last.
+    pub fn trace(&self) -> &[usize] {
+        &self.trace
+    }
+
+    /// Runs `future` together with all spawned tasks until `future` completes.
+    ///
+    /// Tasks still pending when `future` completes are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no task is ready while `future` is still pending (a stall), or after
+    /// `max_steps` polls (a livelock).
+    pub fn run<T: 'static>(&mut self, future: impl Future<Output = T> + 'static) -> T {
+        let output = Rc::new(RefCell::new(None));
+        let slot = Rc::clone(&output);
+        self.spawn(async move {
+            let value = future.await;
+            *slot.borrow_mut() = Some(value);
+        });
+
+        for _ in 0..self.max_steps {
+            if let Some(value) = output.borrow_mut().take() {
+                return value;
+            }
+
+            self.tasks
+                .append(&mut self.spawner.spawned.borrow_mut().tasks);
+
+            let ready: Vec<TaskId> = self
+                .spawner
+                .queue
+                .ready
+                .lock()
+                .unwrap()
+                .iter()
+                .copied()
+                .collect();
+            if ready.is_empty() {
+                panic!(
+                    "deterministic executor (seed {}) stalled: no task can make progress",
+                    self.seed
+                );
+            }
+
+            let id = ready[self.rng.usize(..ready.len())];
+            self.spawner.queue.ready.lock().unwrap().remove(&id);
+            self.poll_task(id);
+        }
+
+        panic!(
+            "deterministic executor (seed {}) exceeded {} steps",
+            self.seed, self.max_steps
+        );
+    }
+
+    fn poll_task(&mut self, id: TaskId) {
+        let Some(index) = self.tasks.iter().position(|(task, _)| *task == id) else {
+            return;
+        };
+        self.trace.push(id);
+
+        let waker = waker(Arc::new(TaskWaker {
+            id,
+            queue: Arc::clone(&self.spawner.queue),
+        }));
+        let mut cx = Context::from_waker(&waker);
+
+        if self.tasks[index].1.as_mut().poll(&mut cx).is_ready() {
+            drop(self.tasks.swap_remove(index));
+        }
+    }
+}
+
+/// Yields once to the executor, letting it schedule other tasks.
+pub fn yield_now() -> impl Future<Output = ()> {
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    YieldNow(false)
+}
+
+/// Feeds `sources` into the stream built by `build` and collects its output.
+///
+/// Each source becomes a channel whose items are sent one at a time by a dedicated
+/// task, which yields after every send; the channel closes after its last item. The
+/// seed decides how sends and polls of the built stream interleave.
+///
+/// # Panics
+///
+/// Panics if the built stream stalls, for example because it never completes.
+pub fn run_interleaved<I, S, F>(seed: u64, sources: Vec<Vec<I>>, build: F) -> Vec<S::Item>
+where
+    I: 'static,
+    S: Stream + 'static,
+    F: FnOnce(Vec<UnboundedReceiver<I>>) -> S,
+{
+    let mut executor = DeterministicExecutor::new(seed);
+    let mut receivers = Vec::with_capacity(sources.len());
+
+    for items in sources {
+        let (tx, rx) = unbounded();
+        receivers.push(rx);
+        executor.spawn(async move {
+            for item in items {
+                if tx.unbounded_send(item).is_err() {
+                    break;
+                }
+                yield_now().await;
+            }
+        });
+    }
+
+    let stream = build(receivers);
+    executor.run(stream.collect())
+}
+
+/// Runs `scenario` once per seed, reporting the first seed that fails.
+///
+/// # Panics
+///
+/// Re-raises the first failure after printing the seed that caused it.
+pub fn check_seeds(seeds: impl IntoIterator<Item = u64>, scenario: impl Fn(u64)) {
+    for seed in seeds {
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| scenario(seed))) {
+            eprintln!("scenario failed with seed {seed}; replay it with that seed");
+            resume_unwind(panic);
+        }
+    }
+}
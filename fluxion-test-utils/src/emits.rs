@@ -0,0 +1,236 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Sequence assertions over the next emissions of a stream.
+//!
+//! [`assert_emits!`](crate::assert_emits) awaits the next emissions of a stream, one
+//! per expectation, and compares them in order. Each emission is awaited with its own
+//! timeout. Values are compared after unwrapping them with
+//! [`Timestamped::into_inner`]. On failure, the panic message lists every expectation
+//! next to what was actually received:
+//!
+//! ```text
+//! assert_emits! failed at emission 1:
+//!      #  expected                         actual
+//!   ok 0  Value(1)                         Value(1)
+//!   !! 1  Value(2)                         Value(3)
+//!      2  End                              <not checked>
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_core::{FluxionError, StreamItem};
+//! use fluxion_test_utils::assert_emits;
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use futures::stream;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut stream = stream::iter(vec![
+//!     StreamItem::Value(Sequenced::with_timestamp(1, 1)),
+//!     StreamItem::Value(Sequenced::with_timestamp(20, 2)),
+//!     StreamItem::Error(FluxionError::stream_error("boom")),
+//!     StreamItem::Value(Sequenced::with_timestamp(3, 3)),
+//! ]);
+//!
+//! assert_emits!(stream, [1, matches 10..=30, error("boom"), _, end]);
+//! # }
+//! ```
+
+use core::fmt::{self, Debug};
+use fluxion_core::{StreamItem, Timestamped};
+use futures::{Stream, StreamExt};
+use std::time::Duration;
+use tokio::time::timeout;
+
+/// Default per-emission timeout of [`assert_emits!`](crate::assert_emits), in milliseconds.
+pub const DEFAULT_TIMEOUT_MS: u64 = 500;
+
+/// An expectation about a single emission.
+pub enum Expected<V> {
+    /// A value equal to the given one.
+    Value(V),
+    /// A value matching a pattern, described by its source text.
+    Matches(&'static str, Box<dyn Fn(&V) -> bool>),
+    /// Any value.
+    AnyValue,
+    /// Any error.
+    Error,
+    /// An error whose message contains the given text.
+    ErrorContaining(String),
+    /// The end of the stream.
+    End,
+}
+
+impl<V: Debug> Debug for Expected<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "Value({value:?})"),
+            Self::Matches(pattern, _) => write!(f, "Value(matches {pattern})"),
+            Self::AnyValue => f.write_str("Value(_)"),
+            Self::Error => f.write_str("Error"),
+            Self::ErrorContaining(text) => write!(f, "Error(containing {text:?})"),
+            Self::End => f.write_str("End"),
+        }
+    }
+}
+
+/// What a stream actually did when an emission was awaited.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Emitted<V> {
+    Value(V),
+    Error(String),
+    End,
+    Timeout(u64),
+}
+
+impl<V: PartialEq> Expected<V> {
+    fn accepts(&self, emitted: &Emitted<V>) -> bool {
+        match (self, emitted) {
+            (Self::Value(expected), Emitted::Value(actual)) => expected == actual,
+            (Self::Matches(_, predicate), Emitted::Value(actual)) => predicate(actual),
+            (Self::AnyValue, Emitted::Value(_)) => true,
+            (Self::Error, Emitted::Error(_)) => true,
+            (Self::ErrorContaining(text), Emitted::Error(message)) => message.contains(text),
+            (Self::End, Emitted::End) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Awaits the next `expected.len()` emissions of `stream` and compares them in order.
+///
+/// Prefer the [`assert_emits!`](crate::assert_emits) macro.
+///
+/// # Panics
+///
+/// Panics at the first emission that does not meet its expectation, including a
+/// timeout after `timeout_ms` milliseconds.
+pub async fn assert_emits<S, T>(stream: &mut S, timeout_ms: u64, expected: Vec<Expected<T::Inner>>)
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped,
+    T::Inner: PartialEq + Debug,
+{
+    let mut received = Vec::with_capacity(expected.len());
+
+    for expectation in &expected {
+        let emitted = match timeout(Duration::from_millis(timeout_ms), stream.next()).await {
+            Ok(Some(StreamItem::Value(value))) => Emitted::Value(value.into_inner()),
+            Ok(Some(StreamItem::Error(error))) => Emitted::Error(error.to_string()),
+            Ok(None) => Emitted::End,
+            Err(_) => Emitted::Timeout(timeout_ms),
+        };
+        let accepted = expectation.accepts(&emitted);
+        received.push(emitted);
+
+        if !accepted {
+            panic!("{}", report(&expected, &received));
+        }
+    }
+}
+
+fn report<V: Debug>(expected: &[Expected<V>], received: &[Emitted<V>]) -> String {
+    let failed = received.len() - 1;
+    let mut message = format!(
+        "assert_emits! failed at emission {failed}:\n     #  {:<32} actual\n",
+        "expected"
+    );
+
+    for (index, expectation) in expected.iter().enumerate() {
+        let marker = match index.cmp(&failed) {
+            core::cmp::Ordering::Less => "ok",
+            core::cmp::Ordering::Equal => "!!",
+            core::cmp::Ordering::Greater => "  ",
+        };
+        let actual = match received.get(index) {
+            Some(Emitted::Value(value)) => format!("Value({value:?})"),
+            Some(Emitted::Error(error)) => format!("Error({error:?})"),
+            Some(Emitted::End) => String::from("End"),
+            Some(Emitted::Timeout(ms)) => format!("<nothing within {ms} ms>"),
+            None => String::from("<not checked>"),
+        };
+        message.push_str(&format!(
+            "  {marker} {index}  {:<32} {actual}\n",
+            format!("{expectation:?}")
+        ));
+    }
+
+    message
+}
+
+/// Asserts the next emissions of a stream.
+///
+/// Each element of the list is one expectation, checked in order:
+///
+/// | Syntax | Expects |
+/// |--------|---------|
+/// | `expr` | a value equal to `expr` |
+/// | `matches pat` | a value matching the pattern `pat` |
+/// | `_` | any value |
+/// | `error` | any error |
+/// | `error("text")` | an error whose message contains `text` |
+/// | `end` | the end of the stream |
+///
+/// Each emission is awaited for [`DEFAULT_TIMEOUT_MS`](crate::emits::DEFAULT_TIMEOUT_MS)
+/// unless a timeout is given as `timeout = ms`:
+///
+/// ```rust,ignore
+/// assert_emits!(stream, [person_alice(), person_bob(), end]);
+/// assert_emits!(stream, timeout = 1000, [matches TestData::Person(_), error]);
+/// ```
+#[macro_export]
+macro_rules! assert_emits {
+    ($stream:expr, timeout = $timeout:expr, [$($expected:tt)*]) => {
+        $crate::emits::assert_emits(
+            &mut $stream,
+            $timeout,
+            $crate::__emits_expectations!([] $($expected)*),
+        )
+        .await
+    };
+    ($stream:expr, [$($expected:tt)*]) => {
+        $crate::assert_emits!(
+            $stream,
+            timeout = $crate::emits::DEFAULT_TIMEOUT_MS,
+            [$($expected)*]
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __emits_expectations {
+    ([$($done:expr),*]) => {
+        vec![$($done),*]
+    };
+    ([$($done:expr),*] end $(, $($rest:tt)*)?) => {
+        $crate::__emits_expectations!([$($done,)* $crate::emits::Expected::End] $($($rest)*)?)
+    };
+    ([$($done:expr),*] error ($text:expr) $(, $($rest:tt)*)?) => {
+        $crate::__emits_expectations!(
+            [$($done,)* $crate::emits::Expected::ErrorContaining(::std::string::ToString::to_string(&$text))]
+            $($($rest)*)?
+        )
+    };
+    ([$($done:expr),*] error $(, $($rest:tt)*)?) => {
+        $crate::__emits_expectations!([$($done,)* $crate::emits::Expected::Error] $($($rest)*)?)
+    };
+    ([$($done:expr),*] _ $(, $($rest:tt)*)?) => {
+        $crate::__emits_expectations!([$($done,)* $crate::emits::Expected::AnyValue] $($($rest)*)?)
+    };
+    ([$($done:expr),*] matches $pattern:pat $(, $($rest:tt)*)?) => {
+        $crate::__emits_expectations!(
+            [$($done,)* $crate::emits::Expected::Matches(
+                stringify!($pattern),
+                ::std::boxed::Box::new(|value| matches!(value, $pattern)),
+            )]
+            $($($rest)*)?
+        )
+    };
+    ([$($done:expr),*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__emits_expectations!([$($done,)* $crate::emits::Expected::Value($value)] $($($rest)*)?)
+    };
+}
@@ -5,12 +5,20 @@
 //! Test utilities for error injection in streams.
 //!
 //! This module provides stream wrappers that can inject `StreamItem::Error` values
-//! into streams for testing error propagation behavior in stream operators.
+//! into streams for testing error propagation behavior in stream operators, and
+//! [`ChaosStream`], which additionally reorders, duplicates, drops and delays values.
 
+use core::future::Future;
 use core::pin::Pin;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use core::task::{Context, Poll};
+use fastrand::Rng;
 use fluxion_core::{FluxionError, StreamItem, Timestamped};
 use futures::Stream;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, Sleep};
 
 /// A stream wrapper that injects errors at specified positions.
 ///
@@ -92,3 +100,250 @@ where
         }
     }
 }
+
+/// Fault probabilities applied by [`ChaosStream`].
+///
+/// All probabilities are per value and default to `0.0`, so a default configuration
+/// passes the stream through unchanged. The seed makes every run reproducible.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChaosConfig {
+    pub seed: u64,
+    /// Probability that a value is held back and released after up to
+    /// `reorder_window` later values have arrived.
+    pub reorder: f64,
+    pub reorder_window: usize,
+    /// Probability that a value is emitted twice.
+    pub duplicate: f64,
+    /// Probability that a value is silently discarded.
+    pub drop: f64,
+    /// Probability that emitting a value is delayed by up to `max_delay`.
+    pub delay: f64,
+    pub max_delay: Duration,
+    /// Probability that an error is injected before a value.
+    pub error: f64,
+}
+
+impl ChaosConfig {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            reorder: 0.0,
+            reorder_window: 1,
+            duplicate: 0.0,
+            drop: 0.0,
+            delay: 0.0,
+            max_delay: Duration::ZERO,
+            error: 0.0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_reorder(mut self, probability: f64, window: usize) -> Self {
+        self.reorder = probability;
+        self.reorder_window = window.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn with_duplicate(mut self, probability: f64) -> Self {
+        self.duplicate = probability;
+        self
+    }
+
+    #[must_use]
+    pub fn with_drop(mut self, probability: f64) -> Self {
+        self.drop = probability;
+        self
+    }
+
+    #[must_use]
+    pub fn with_delay(mut self, probability: f64, max_delay: Duration) -> Self {
+        self.delay = probability;
+        self.max_delay = max_delay;
+        self
+    }
+
+    #[must_use]
+    pub fn with_errors(mut self, probability: f64) -> Self {
+        self.error = probability;
+        self
+    }
+}
+
+impl Default for ChaosConfig {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Counts of the faults injected by a [`ChaosStream`].
+#[derive(Debug, Default)]
+pub struct ChaosStats {
+    pub reordered: AtomicUsize,
+    pub duplicated: AtomicUsize,
+    pub dropped: AtomicUsize,
+    pub delayed: AtomicUsize,
+    pub errors: AtomicUsize,
+}
+
+/// A stream wrapper that injects faults into a stream of [`StreamItem`]s.
+///
+/// Values may be reordered within a window, duplicated, dropped, delayed or preceded by
+/// an injected error, each with the probability configured in [`ChaosConfig`]. Errors
+/// from the inner stream are passed through untouched. Values still held back for
+/// reordering are flushed when the inner stream ends.
+///
+/// Delays use tokio timers, so they can be skipped with paused time.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxion_core::StreamItem;
+/// use fluxion_test_utils::error_injection::{ChaosConfig, ChaosStream};
+/// use fluxion_test_utils::sequenced::Sequenced;
+/// use futures::{stream, StreamExt};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let source = stream::iter((0..100).map(|i| StreamItem::Value(Sequenced::with_timestamp(i, i))));
+/// let chaos = ChaosStream::new(source, ChaosConfig::new(7).with_duplicate(0.1));
+/// let stats = chaos.stats();
+///
+/// let items: Vec<_> = chaos.collect().await;
+///
+/// assert_eq!(items.len(), 100 + stats.duplicated.load(std::sync::atomic::Ordering::Relaxed));
+/// # }
+/// ```
+pub struct ChaosStream<S, T> {
+    inner: S,
+    config: ChaosConfig,
+    rng: Rng,
+    stats: Arc<ChaosStats>,
+    ready: VecDeque<StreamItem<T>>,
+    held: Vec<(T, usize)>,
+    delay: Option<Pin<Box<Sleep>>>,
+    done: bool,
+}
+
+impl<S, T> ChaosStream<S, T> {
+    pub fn new(inner: S, config: ChaosConfig) -> Self {
+        Self {
+            inner,
+            rng: Rng::with_seed(config.seed),
+            config,
+            stats: Arc::new(ChaosStats::default()),
+            ready: VecDeque::new(),
+            held: Vec::new(),
+            delay: None,
+            done: false,
+        }
+    }
+
+    /// Returns a handle to the fault counters, which stays valid after the stream is
+    /// moved into an operator chain.
+    pub fn stats(&self) -> Arc<ChaosStats> {
+        Arc::clone(&self.stats)
+    }
+
+    fn roll(&mut self, probability: f64) -> bool {
+        probability > 0.0 && self.rng.f64() < probability
+    }
+
+    fn count(counter: &AtomicUsize) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn accept(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        if self.roll(self.config.error) {
+            Self::count(&self.stats.errors);
+            self.ready
+                .push_back(StreamItem::Error(FluxionError::stream_error(
+                    "Injected chaos error",
+                )));
+        }
+
+        if self.roll(self.config.drop) {
+            Self::count(&self.stats.dropped);
+        } else if self.roll(self.config.reorder) {
+            Self::count(&self.stats.reordered);
+            let after = self.rng.usize(1..=self.config.reorder_window);
+            self.release_held(false);
+            self.held.push((value, after));
+            return;
+        } else {
+            if self.roll(self.config.duplicate) {
+                Self::count(&self.stats.duplicated);
+                self.ready.push_back(StreamItem::Value(value.clone()));
+            }
+            self.ready.push_back(StreamItem::Value(value));
+        }
+
+        self.release_held(false);
+    }
+
+    fn release_held(&mut self, all: bool) {
+        let mut index = 0;
+        while index < self.held.len() {
+            let (_, after) = &mut self.held[index];
+            *after = after.saturating_sub(1);
+            if all || *after == 0 {
+                let (value, _) = self.held.remove(index);
+                self.ready.push_back(StreamItem::Value(value));
+            } else {
+                index += 1;
+            }
+        }
+    }
+}
+
+impl<S, T> Stream for ChaosStream<S, T>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Clone + Unpin,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(delay) = this.delay.as_mut() {
+                if delay.as_mut().poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                this.delay = None;
+                return Poll::Ready(this.ready.pop_front());
+            }
+
+            if !this.ready.is_empty() {
+                if this.ready.front().is_some_and(StreamItem::is_value)
+                    && this.roll(this.config.delay)
+                {
+                    Self::count(&this.stats.delayed);
+                    let max = this.config.max_delay.as_micros() as u64;
+                    let delay = Duration::from_micros(this.rng.u64(0..=max));
+                    this.delay = Some(Box::pin(sleep(delay)));
+                    continue;
+                }
+                return Poll::Ready(this.ready.pop_front());
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => this.accept(value),
+                Poll::Ready(Some(error)) => this.ready.push_back(error),
+                Poll::Ready(None) => {
+                    this.done = true;
+                    this.release_held(true);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
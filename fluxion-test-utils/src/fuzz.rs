@@ -0,0 +1,372 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Invariant checks for ordered operators under randomized interleavings.
+//!
+//! A [`Scenario`] decodes arbitrary bytes, such as the input of a fuzz target, into a
+//! set of source streams and a seed. [`run_scenario`] feeds the sources into an operator
+//! on a [`DeterministicExecutor`], which interleaves the sends with polls of the operator
+//! as the seed decides, and records a [`Trace`] of when every item was sent and every
+//! output emitted. The `check_*` methods of the trace verify the invariants ordered
+//! operators promise and return an [`InvariantViolation`] naming the offending items.
+//!
+//! Timestamps are unique across all sources and increase within each source, so every
+//! output carrying a timestamp can be traced back to the input that triggered it. Values
+//! are equal to their timestamps.
+//!
+//! The fuzz targets in the `fuzz` directory of the repository run these checks for
+//! `ordered_merge`, `combine_latest` and `emit_when`; the same checks run on fixed seeds
+//! in the tests of `fluxion-stream`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_test_utils::fuzz::{run_scenario, Scenario};
+//!
+//! let scenario = Scenario::from_bytes(b"an arbitrary fuzz input", 2);
+//!
+//! let trace = run_scenario(&scenario, |mut streams| {
+//!     let right = streams.pop().unwrap();
+//!     let left = streams.pop().unwrap();
+//!     futures::stream::select(left, right)
+//! });
+//!
+//! trace.check_no_loss().unwrap();
+//! trace.check_errors_preserved().unwrap();
+//! ```
+
+use crate::deterministic::{yield_now, DeterministicExecutor};
+use crate::sequenced::Sequenced;
+use core::cell::{Cell, RefCell};
+use core::fmt::{self, Debug, Display};
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+use futures::{Stream, StreamExt};
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+/// Maximum number of items [`Scenario::from_bytes`] decodes, keeping runs short.
+pub const MAX_SCENARIO_ITEMS: usize = 256;
+
+/// Item fed into an operator by a [`Scenario`].
+pub type FuzzItem = StreamItem<Sequenced<u64>>;
+
+/// Source streams and the seed interleaving them.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// Seed of the [`DeterministicExecutor`] running the scenario.
+    pub seed: u64,
+    /// Items of each source, in the order they are sent.
+    pub sources: Vec<Vec<FuzzItem>>,
+}
+
+impl Scenario {
+    /// Decodes `data` into `sources` source streams.
+    ///
+    /// The first eight bytes form the seed. Every following byte adds one item: its low
+    /// bits pick the source, a few more make it an error one time in eight, and the top
+    /// bits widen the gap to the previous timestamp. At most [`MAX_SCENARIO_ITEMS`] items
+    /// are decoded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sources` is zero or greater than 8.
+    pub fn from_bytes(data: &[u8], sources: usize) -> Self {
+        assert!(
+            (1..=8).contains(&sources),
+            "fuzz: a scenario needs between 1 and 8 sources"
+        );
+
+        let split = data.len().min(8);
+        let mut seed = [0u8; 8];
+        seed[..split].copy_from_slice(&data[..split]);
+
+        let mut streams = vec![Vec::new(); sources];
+        let mut timestamp = 0;
+        for &byte in data[split..].iter().take(MAX_SCENARIO_ITEMS) {
+            let source = usize::from(byte & 0b111) % sources;
+            if byte & 0b11_1000 == 0b11_1000 {
+                streams[source].push(StreamItem::Error(FluxionError::stream_error(format!(
+                    "injected error after {timestamp}"
+                ))));
+            } else {
+                timestamp += 1 + u64::from(byte >> 6);
+                streams[source].push(StreamItem::Value(Sequenced::with_timestamp(
+                    timestamp, timestamp,
+                )));
+            }
+        }
+
+        Self {
+            seed: u64::from_le_bytes(seed),
+            sources: streams,
+        }
+    }
+
+    fn values(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.sources.iter().enumerate().flat_map(|(source, items)| {
+            items.iter().filter_map(move |item| match item {
+                StreamItem::Value(value) => Some((source, value.timestamp())),
+                StreamItem::Error(_) => None,
+            })
+        })
+    }
+}
+
+/// An invariant that a [`Trace`] does not hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvariantViolation {
+    /// Name of the violated invariant.
+    pub invariant: &'static str,
+    /// What was observed.
+    pub details: String,
+}
+
+impl InvariantViolation {
+    fn new(invariant: &'static str, details: String) -> Self {
+        Self { invariant, details }
+    }
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} violated: {}", self.invariant, self.details)
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+/// An output of the operator and the step at which it was emitted.
+#[derive(Debug)]
+pub struct Emission<O> {
+    /// Number of items sent before the output was emitted.
+    pub step: u64,
+    /// The output.
+    pub item: StreamItem<O>,
+}
+
+/// What happened while a [`Scenario`] ran.
+#[derive(Debug)]
+pub struct Trace<O> {
+    scenario: Scenario,
+    /// Step at which the value of each timestamp was sent. Step `n` is the `n`th send.
+    sent: BTreeMap<u64, u64>,
+    outputs: Vec<Emission<O>>,
+}
+
+/// Runs `scenario` through the operator returned by `build`, which receives one stream
+/// per source.
+///
+/// # Panics
+///
+/// Panics if the operator stalls or does not terminate once all sources have ended.
+pub fn run_scenario<O, S, F>(scenario: &Scenario, build: F) -> Trace<O>
+where
+    O: 'static,
+    S: Stream<Item = StreamItem<O>> + 'static,
+    F: FnOnce(Vec<UnboundedReceiver<FuzzItem>>) -> S,
+{
+    let mut executor = DeterministicExecutor::new(scenario.seed);
+    let step = Rc::new(Cell::new(0u64));
+    let sent = Rc::new(RefCell::new(BTreeMap::new()));
+    let mut receivers = Vec::with_capacity(scenario.sources.len());
+
+    for items in scenario.sources.clone() {
+        let (tx, rx) = unbounded();
+        receivers.push(rx);
+        let step = Rc::clone(&step);
+        let sent = Rc::clone(&sent);
+        executor.spawn(async move {
+            for item in items {
+                step.set(step.get() + 1);
+                if let StreamItem::Value(value) = &item {
+                    sent.borrow_mut().insert(value.timestamp(), step.get());
+                }
+                if tx.unbounded_send(item).is_err() {
+                    break;
+                }
+                yield_now().await;
+            }
+        });
+    }
+
+    let emitted = Rc::clone(&step);
+    let outputs = executor.run(
+        build(receivers)
+            .map(move |item| Emission {
+                step: emitted.get(),
+                item,
+            })
+            .collect(),
+    );
+
+    Trace {
+        scenario: scenario.clone(),
+        sent: sent.take(),
+        outputs,
+    }
+}
+
+impl<O> Trace<O> {
+    /// The scenario that was run.
+    pub fn scenario(&self) -> &Scenario {
+        &self.scenario
+    }
+
+    /// Outputs of the operator, in emission order.
+    pub fn outputs(&self) -> &[Emission<O>] {
+        &self.outputs
+    }
+
+    /// Checks that the operator emitted as many errors as the sources sent.
+    pub fn check_errors_preserved(&self) -> Result<(), InvariantViolation> {
+        let sent = self
+            .scenario
+            .sources
+            .iter()
+            .flatten()
+            .filter(|item| item.is_error())
+            .count();
+        let emitted = self
+            .outputs
+            .iter()
+            .filter(|output| output.item.is_error())
+            .count();
+        if sent == emitted {
+            Ok(())
+        } else {
+            Err(InvariantViolation::new(
+                "errors preserved",
+                format!("{sent} errors were sent but {emitted} emitted"),
+            ))
+        }
+    }
+}
+
+impl<O: HasTimestamp<Timestamp = u64>> Trace<O> {
+    fn emitted(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.outputs.iter().filter_map(|output| match &output.item {
+            StreamItem::Value(value) => Some((output.step, value.timestamp())),
+            StreamItem::Error(_) => None,
+        })
+    }
+
+    /// Checks that output timestamps never decrease, except for outputs triggered by
+    /// items that had not been sent yet when the larger timestamp was emitted.
+    ///
+    /// An ordered operator can only order the items it has seen: once it emitted `t`, an
+    /// item with a smaller timestamp sent afterwards is late and may still follow `t`. An
+    /// item sent before `t` was emitted must precede it.
+    pub fn check_ordered(&self) -> Result<(), InvariantViolation> {
+        // Latest emission step of each timestamp emitted so far
+        let mut emitted_at = BTreeMap::new();
+        for (step, timestamp) in self.emitted() {
+            let Some(&sent) = self.sent.get(&timestamp) else {
+                return Err(InvariantViolation::new(
+                    "ordered output",
+                    format!("timestamp {timestamp} was emitted but never sent"),
+                ));
+            };
+            if let Some((&larger, &larger_step)) = emitted_at
+                .range(timestamp + 1..)
+                .max_by_key(|(_, &step)| step)
+            {
+                if sent <= larger_step {
+                    return Err(InvariantViolation::new(
+                        "ordered output",
+                        format!(
+                            "timestamp {timestamp}, sent at step {sent}, was emitted after \
+                             {larger}, emitted at step {larger_step}"
+                        ),
+                    ));
+                }
+            }
+            emitted_at.insert(timestamp, step);
+        }
+        Ok(())
+    }
+
+    /// Checks that every value sent triggered exactly one output, as by an operator that
+    /// emits every item of every source.
+    pub fn check_no_loss(&self) -> Result<(), InvariantViolation> {
+        let triggered = self.triggered()?;
+        match self
+            .scenario
+            .values()
+            .find(|(_, timestamp)| !triggered.contains(timestamp))
+        {
+            Some((source, timestamp)) => Err(InvariantViolation::new(
+                "no item loss",
+                format!("timestamp {timestamp} of source {source} was never emitted"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that, once the operator emitted its first output, every value triggered
+    /// exactly one output, as by an operator combining the latest values of its sources.
+    ///
+    /// Before every source has a value such an operator emits nothing and only keeps the
+    /// latest value of each source, so the values it drops must come first in their
+    /// source and have been sent before the first output. If every source sent a value,
+    /// there must be an output.
+    pub fn check_no_loss_after_warmup(&self) -> Result<(), InvariantViolation> {
+        let triggered = self.triggered()?;
+        let Some((first_step, _)) = self.emitted().next() else {
+            return match self
+                .scenario
+                .sources
+                .iter()
+                .position(|items| !items.iter().any(StreamItem::is_value))
+            {
+                Some(_) => Ok(()),
+                None => Err(InvariantViolation::new(
+                    "no item loss after warmup",
+                    "every source sent a value but nothing was emitted".to_string(),
+                )),
+            };
+        };
+
+        let mut warmed_up = vec![false; self.scenario.sources.len()];
+        for (source, timestamp) in self.scenario.values() {
+            if triggered.contains(&timestamp) {
+                warmed_up[source] = true;
+                continue;
+            }
+            if warmed_up[source] {
+                return Err(InvariantViolation::new(
+                    "no item loss after warmup",
+                    format!(
+                        "timestamp {timestamp} of source {source} was dropped after an \
+                         earlier value of the source was emitted"
+                    ),
+                ));
+            }
+            if self.sent[&timestamp] > first_step {
+                return Err(InvariantViolation::new(
+                    "no item loss after warmup",
+                    format!(
+                        "timestamp {timestamp} of source {source} was sent after the first \
+                         output, at step {first_step}, but never emitted"
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the timestamps of the outputs, failing if one was emitted twice.
+    fn triggered(&self) -> Result<BTreeSet<u64>, InvariantViolation> {
+        let mut triggered = BTreeSet::new();
+        for (_, timestamp) in self.emitted() {
+            if !triggered.insert(timestamp) {
+                return Err(InvariantViolation::new(
+                    "no duplicates",
+                    format!("timestamp {timestamp} was emitted twice"),
+                ));
+            }
+        }
+        Ok(triggered)
+    }
+}
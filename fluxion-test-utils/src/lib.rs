@@ -3,10 +3,17 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 pub mod animal;
+pub mod deterministic;
+pub mod emits;
 pub mod error_injection;
+pub mod fuzz;
 pub mod helpers;
+pub mod marble;
 pub mod person;
 pub mod plant;
+pub mod property;
+pub mod recorder;
 pub mod sequenced;
+pub mod span_recorder;
 pub mod test_data;
 pub mod test_wrapper;
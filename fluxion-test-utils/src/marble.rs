@@ -0,0 +1,290 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Marble diagrams for stream tests.
+//!
+//! A marble diagram describes the emissions of a stream over virtual time, one
+//! character per frame:
+//!
+//! | Symbol | Meaning |
+//! |--------|---------|
+//! | `-` | A frame in which nothing is emitted |
+//! | `a`, `1`, ... | A value, looked up in the values table |
+//! | `(ab)` | Several events emitted in the same frame |
+//! | `#` | An error (`FluxionError::StreamProcessingError`) |
+//! | `\|` | Completion; nothing may follow |
+//! | ` ` | Ignored, useful for alignment |
+//!
+//! [`cold`] turns a diagram into a stream of [`Sequenced`] items whose timestamp is
+//! the frame index, and [`expect_marble!`](crate::expect_marble) records the
+//! emissions of a stream and compares them against an expected diagram. Frames are
+//! [`FRAME`] long and driven by tokio's virtual clock, so tests must run with paused
+//! time (`#[tokio::test(start_paused = true)]`).
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_test_utils::expect_marble;
+//! use fluxion_test_utils::marble::cold;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main(flavor = "current_thread", start_paused = true)]
+//! # async fn main() {
+//! let source = cold("a-b--c|", &[('a', 1), ('b', 2), ('c', 3)]);
+//!
+//! let mut skipped = source.skip(1);
+//!
+//! expect_marble!(skipped, "--b--c|", { 'b' => 2, 'c' => 3 });
+//! # }
+//! ```
+
+use crate::sequenced::Sequenced;
+use core::fmt::Debug;
+use fluxion_core::{FluxionError, StreamItem, Timestamped};
+use futures::stream::{self, StreamExt};
+use futures::Stream;
+use std::time::Duration;
+use tokio::time::{sleep_until, timeout_at, Instant};
+
+/// Virtual duration of one frame of a marble diagram.
+pub const FRAME: Duration = Duration::from_millis(10);
+
+/// A single event of a marble diagram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarbleEvent<T> {
+    Value(T),
+    Error,
+    Complete,
+}
+
+/// A parsed marble diagram: events with the frame they occur in, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Marble<T> {
+    pub events: Vec<(u64, MarbleEvent<T>)>,
+    /// Number of frames described by the diagram.
+    pub frames: u64,
+}
+
+impl<T: Clone> Marble<T> {
+    /// Parses `diagram`, resolving value symbols through `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the diagram references a symbol missing from `values`, contains an
+    /// unterminated or nested group, or has events after the completion marker.
+    pub fn parse(diagram: &str, values: &[(char, T)]) -> Self {
+        let mut events = Vec::new();
+        let mut frame = 0;
+        let mut in_group = false;
+        let mut completed = false;
+
+        for symbol in diagram.chars() {
+            if completed && !matches!(symbol, ' ' | ')') {
+                panic!("marble: event '{symbol}' after completion in \"{diagram}\"");
+            }
+
+            let event = match symbol {
+                ' ' => continue,
+                '-' => None,
+                '(' => {
+                    assert!(!in_group, "marble: nested group in \"{diagram}\"");
+                    in_group = true;
+                    continue;
+                }
+                ')' => {
+                    assert!(in_group, "marble: unmatched ')' in \"{diagram}\"");
+                    in_group = false;
+                    frame += 1;
+                    continue;
+                }
+                '#' => Some(MarbleEvent::Error),
+                '|' => {
+                    completed = true;
+                    Some(MarbleEvent::Complete)
+                }
+                symbol => {
+                    let value = values
+                        .iter()
+                        .find(|(key, _)| *key == symbol)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or_else(|| {
+                            panic!("marble: no value for '{symbol}' in \"{diagram}\"")
+                        });
+                    Some(MarbleEvent::Value(value))
+                }
+            };
+
+            if let Some(event) = event {
+                events.push((frame, event));
+            }
+            if !in_group {
+                frame += 1;
+            }
+        }
+
+        assert!(!in_group, "marble: unterminated group in \"{diagram}\"");
+
+        Self {
+            events,
+            frames: frame,
+        }
+    }
+}
+
+impl<T: PartialEq> Marble<T> {
+    /// Renders the events back into a diagram, using `values` to find the symbol of
+    /// each value. Values without a symbol are rendered as `?`.
+    pub fn render(&self, values: &[(char, T)]) -> String {
+        let last = self.events.last().map_or(0, |(frame, _)| *frame + 1);
+        let mut diagram = String::new();
+
+        for frame in 0..self.frames.max(last) {
+            let symbols: Vec<char> = self
+                .events
+                .iter()
+                .filter(|(at, _)| *at == frame)
+                .map(|(_, event)| match event {
+                    MarbleEvent::Value(value) => values
+                        .iter()
+                        .find(|(_, candidate)| candidate == value)
+                        .map_or('?', |(key, _)| *key),
+                    MarbleEvent::Error => '#',
+                    MarbleEvent::Complete => '|',
+                })
+                .collect();
+
+            match symbols.as_slice() {
+                [] => diagram.push('-'),
+                [symbol] => diagram.push(*symbol),
+                group => {
+                    diagram.push('(');
+                    diagram.extend(group);
+                    diagram.push(')');
+                }
+            }
+        }
+
+        diagram
+    }
+}
+
+/// Creates a stream emitting the events of `diagram` in virtual time.
+///
+/// Frames are counted from the first poll. Values are wrapped in [`Sequenced`] with the
+/// frame index as timestamp. Without a completion marker the stream stays open after
+/// its last event. The stream is fused, so it may be polled after completion.
+pub fn cold<T>(
+    diagram: &str,
+    values: &[(char, T)],
+) -> impl Stream<Item = StreamItem<Sequenced<T>>> + Send + Sync + Unpin
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let marble = Marble::parse(diagram, values);
+    let events = marble.events.into_iter();
+
+    Box::pin(stream::unfold(
+        (events, None),
+        |(mut events, start)| async move {
+            let start = start.unwrap_or_else(Instant::now);
+            match events.next() {
+                Some((frame, event)) => {
+                    sleep_until(start + FRAME * frame as u32).await;
+                    let item = match event {
+                        MarbleEvent::Value(value) => {
+                            StreamItem::Value(Sequenced::with_timestamp(value, frame))
+                        }
+                        MarbleEvent::Error => {
+                            StreamItem::Error(FluxionError::stream_error("marble error"))
+                        }
+                        MarbleEvent::Complete => return None,
+                    };
+                    Some((item, (events, Some(start))))
+                }
+                None => {
+                    stream::pending::<()>().next().await;
+                    None
+                }
+            }
+        },
+    ))
+    .fuse()
+}
+
+/// Records the emissions of `stream` during the first `frames` frames.
+///
+/// Recording stops at completion or once the last frame has elapsed. Values are unwrapped with [`Timestamped::into_inner`].
+pub async fn record_marble<S, T>(stream: &mut S, frames: u64) -> Marble<T::Inner>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped,
+{
+    let start = Instant::now();
+    let deadline = start + FRAME * frames.saturating_sub(1) as u32 + FRAME / 2;
+    let frame_of = |at: Instant| {
+        let elapsed = at.duration_since(start).as_micros();
+        let frame = FRAME.as_micros();
+        ((elapsed + frame / 2) / frame) as u64
+    };
+
+    let mut events = Vec::new();
+    while let Ok(item) = timeout_at(deadline, stream.next()).await {
+        let frame = frame_of(Instant::now());
+        match item {
+            Some(StreamItem::Value(value)) => {
+                events.push((frame, MarbleEvent::Value(value.into_inner())))
+            }
+            Some(StreamItem::Error(_)) => events.push((frame, MarbleEvent::Error)),
+            None => {
+                events.push((frame, MarbleEvent::Complete));
+                break;
+            }
+        }
+    }
+
+    Marble { events, frames }
+}
+
+/// Asserts that `stream` emits exactly the events of `expected`.
+///
+/// Prefer the [`expect_marble!`](crate::expect_marble) macro.
+///
+/// # Panics
+///
+/// Panics with both diagrams if the recorded emissions differ from `expected`.
+pub async fn assert_marble<S, T>(stream: &mut S, expected: &str, values: &[(char, T::Inner)])
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped,
+    T::Inner: Clone + PartialEq + Debug,
+{
+    let expected_marble = Marble::parse(expected, values);
+    let actual = record_marble(stream, expected_marble.frames).await;
+
+    assert!(
+        actual.events == expected_marble.events,
+        "marble mismatch\n  expected: {}\n  actual:   {}\n  expected events: {:?}\n  actual events:   {:?}",
+        expected_marble.render(values),
+        actual.render(values),
+        expected_marble.events,
+        actual.events,
+    );
+}
+
+/// Asserts that a stream emits the events of a marble diagram.
+///
+/// ```rust,ignore
+/// expect_marble!(stream, "a-b-|", { 'a' => 1, 'b' => 2 });
+/// ```
+///
+/// See the [`marble`](crate::marble) module for the syntax.
+#[macro_export]
+macro_rules! expect_marble {
+    ($stream:expr, $expected:expr, { $($symbol:literal => $value:expr),* $(,)? }) => {
+        $crate::marble::assert_marble(&mut $stream, $expected, &[$(($symbol, $value)),*]).await
+    };
+    ($stream:expr, $expected:expr) => {
+        $crate::marble::assert_marble(&mut $stream, $expected, &[]).await
+    };
+}
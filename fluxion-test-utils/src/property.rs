@@ -0,0 +1,276 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Property-based testing support built on [`proptest`].
+//!
+//! [`timestamped_stream`] generates arbitrary sequences of [`StreamItem`]s shaped by a
+//! [`StreamProfile`]: how far timestamps may run backwards (ordering skew), how often
+//! errors are injected and how bursty the emissions are. The `check_*` functions verify
+//! common operator invariants and return [`TestCaseError`]s, so they compose with `?`
+//! inside `proptest!` blocks. [`run_stream`] drives a stream to completion on a
+//! dedicated tokio runtime, since property bodies are synchronous.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_test_utils::property::{check_monotone_timestamps, run_stream, sorted_by_timestamp, timestamped_stream, StreamProfile};
+//! use futures::stream;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn sorting_restores_order(items in timestamped_stream(any::<u8>(), StreamProfile::default())) {
+//!         let output = run_stream(stream::iter(sorted_by_timestamp(items)));
+//!         check_monotone_timestamps(&output)?;
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+use crate::sequenced::Sequenced;
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use futures::{Stream, StreamExt};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+/// Shape of the streams produced by [`timestamped_stream`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamProfile {
+    /// Number of items per stream.
+    pub len: RangeInclusive<usize>,
+    /// Maximum distance a timestamp may lag behind the latest one. `0` yields streams
+    /// with strictly increasing timestamps.
+    pub max_skew: u64,
+    /// Probability that an item is an error instead of a value.
+    pub error_rate: f64,
+    /// Maximum number of items emitted back to back, one timestamp apart.
+    pub max_burst: usize,
+    /// Maximum timestamp gap between two bursts.
+    pub max_gap: u64,
+}
+
+impl StreamProfile {
+    /// Strictly ordered, error-free streams.
+    pub fn ordered() -> Self {
+        Self {
+            max_skew: 0,
+            error_rate: 0.0,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_len(mut self, len: RangeInclusive<usize>) -> Self {
+        self.len = len;
+        self
+    }
+
+    #[must_use]
+    pub fn with_skew(mut self, max_skew: u64) -> Self {
+        self.max_skew = max_skew;
+        self
+    }
+
+    #[must_use]
+    pub fn with_error_rate(mut self, error_rate: f64) -> Self {
+        self.error_rate = error_rate;
+        self
+    }
+
+    #[must_use]
+    pub fn with_bursts(mut self, max_burst: usize, max_gap: u64) -> Self {
+        self.max_burst = max_burst;
+        self.max_gap = max_gap;
+        self
+    }
+}
+
+impl Default for StreamProfile {
+    fn default() -> Self {
+        Self {
+            len: 0..=32,
+            max_skew: 4,
+            error_rate: 0.05,
+            max_burst: 4,
+            max_gap: 10,
+        }
+    }
+}
+
+/// Generates streams of values drawn from `values`, shaped by `profile`.
+///
+/// Each item is assigned a nominal time that grows by one within a burst and by up to
+/// `max_gap` between bursts; its timestamp is the nominal time minus a random skew of
+/// up to `max_skew`. Errors are [`FluxionError::StreamProcessingError`]s.
+///
+/// # Panics
+///
+/// Panics if `error_rate` is not within `0.0..=1.0`.
+pub fn timestamped_stream<V>(
+    values: V,
+    profile: StreamProfile,
+) -> impl Strategy<Value = Vec<StreamItem<Sequenced<V::Value>>>>
+where
+    V: Strategy,
+    V::Value: Clone,
+{
+    let burst_start = 1.0 / profile.max_burst.max(1) as f64;
+    let entry = (
+        values,
+        prop::bool::weighted(profile.error_rate),
+        prop::bool::weighted(burst_start),
+        1..=profile.max_gap.max(1),
+        0..=profile.max_skew,
+    );
+
+    prop::collection::vec(entry, profile.len).prop_map(move |entries| {
+        let mut now = profile.max_skew;
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, (value, is_error, starts_burst, gap, skew))| {
+                now += if starts_burst && index > 0 { gap } else { 1 };
+                if is_error {
+                    StreamItem::Error(FluxionError::stream_error(format!(
+                        "injected error at {now}"
+                    )))
+                } else {
+                    StreamItem::Value(Sequenced::with_timestamp(value, now - skew))
+                }
+            })
+            .collect()
+    })
+}
+
+/// Returns the items reordered by timestamp, errors kept at their original positions
+/// relative to the values they followed.
+pub fn sorted_by_timestamp<T: HasTimestamp>(items: Vec<StreamItem<T>>) -> Vec<StreamItem<T>> {
+    let mut keyed: Vec<(Option<T::Timestamp>, usize, StreamItem<T>)> = Vec::new();
+    let mut last = None;
+    for (index, item) in items.into_iter().enumerate() {
+        if let StreamItem::Value(value) = &item {
+            last = Some(value.timestamp());
+        }
+        keyed.push((last, index, item));
+    }
+    keyed.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    keyed.into_iter().map(|(_, _, item)| item).collect()
+}
+
+/// Drives `stream` to completion on a fresh current-thread tokio runtime.
+///
+/// # Panics
+///
+/// Panics if the runtime cannot be created.
+pub fn run_stream<S: Stream>(stream: S) -> Vec<S::Item> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("property: cannot build runtime")
+        .block_on(stream.collect())
+}
+
+fn timestamps<T: HasTimestamp>(items: &[StreamItem<T>]) -> Vec<T::Timestamp> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            StreamItem::Value(value) => Some(value.timestamp()),
+            StreamItem::Error(_) => None,
+        })
+        .collect()
+}
+
+fn values<T: Clone>(items: &[StreamItem<T>]) -> Vec<T> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            StreamItem::Value(value) => Some(value.clone()),
+            StreamItem::Error(_) => None,
+        })
+        .collect()
+}
+
+/// Checks that value timestamps never decrease.
+pub fn check_monotone_timestamps<T: HasTimestamp>(
+    items: &[StreamItem<T>],
+) -> Result<(), TestCaseError> {
+    match timestamps(items).windows(2).find(|pair| pair[1] < pair[0]) {
+        Some(pair) => Err(TestCaseError::fail(format!(
+            "timestamps are not monotone: {:?} followed by {:?}",
+            pair[0], pair[1]
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks that value timestamps strictly increase.
+pub fn check_strictly_monotone_timestamps<T: HasTimestamp>(
+    items: &[StreamItem<T>],
+) -> Result<(), TestCaseError> {
+    match timestamps(items).windows(2).find(|pair| pair[1] <= pair[0]) {
+        Some(pair) => Err(TestCaseError::fail(format!(
+            "timestamps are not strictly monotone: {:?} followed by {:?}",
+            pair[0], pair[1]
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `output` contains as many errors as `input`.
+pub fn check_errors_preserved<I, O>(
+    input: &[StreamItem<I>],
+    output: &[StreamItem<O>],
+) -> Result<(), TestCaseError> {
+    let expected = input.iter().filter(|item| item.is_error()).count();
+    let actual = output.iter().filter(|item| item.is_error()).count();
+    prop_assert_eq!(expected, actual, "error count changed");
+    Ok(())
+}
+
+/// Checks that `output` emits exactly the values of `input`, in any order.
+pub fn check_same_values<T>(
+    input: &[StreamItem<T>],
+    output: &[StreamItem<T>],
+) -> Result<(), TestCaseError>
+where
+    T: Clone + PartialEq + Debug,
+{
+    let mut missing = values(input);
+    for value in values(output) {
+        match missing.iter().position(|candidate| *candidate == value) {
+            Some(position) => {
+                missing.swap_remove(position);
+            }
+            None => {
+                return Err(TestCaseError::fail(format!(
+                    "{value:?} was emitted but is not part of the input"
+                )))
+            }
+        }
+    }
+    prop_assert!(missing.is_empty(), "values were dropped: {:?}", missing);
+    Ok(())
+}
+
+/// Checks that the values of `output` appear in `input` in the same relative order.
+pub fn check_subsequence<T>(
+    input: &[StreamItem<T>],
+    output: &[StreamItem<T>],
+) -> Result<(), TestCaseError>
+where
+    T: Clone + PartialEq + Debug,
+{
+    let input = values(input);
+    let mut remaining = input.iter();
+    for value in values(output) {
+        if !remaining.any(|candidate| *candidate == value) {
+            return Err(TestCaseError::fail(format!(
+                "{value:?} is not part of the input or is out of order"
+            )));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,314 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Stream recordings and golden-snapshot assertions.
+//!
+//! [`record`] drains a stream into a [`Recording`]: every value with its timestamp,
+//! every error with its message, and whether the stream completed. Recordings are
+//! serializable, so a full emission sequence can be stored as a golden file and
+//! compared on every run with [`assert_recording_snapshot!`](crate::assert_recording_snapshot).
+//!
+//! Snapshots are stored as pretty-printed JSON under `tests/snapshots/<name>.json` of
+//! the crate running the test:
+//!
+//! - a missing snapshot is written and the assertion passes, unless the `CI`
+//!   environment variable is set, in which case it fails;
+//! - a mismatching snapshot fails the assertion and the new recording is written next
+//!   to it as `<name>.json.new` for review;
+//! - setting `FLUXION_UPDATE_SNAPSHOTS=1` overwrites existing snapshots.
+//!
+//! Timestamps produced by [`Sequenced::new`](crate::sequenced::Sequenced::new) depend on
+//! test execution order; use [`Recording::normalize_timestamps`] before snapshotting
+//! them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use fluxion_test_utils::recorder::{record, RecordedItem};
+//! use fluxion_test_utils::sequenced::Sequenced;
+//! use fluxion_core::StreamItem;
+//! use futures::stream;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let source = stream::iter(vec![
+//!     StreamItem::Value(Sequenced::with_timestamp("a", 1)),
+//!     StreamItem::Value(Sequenced::with_timestamp("b", 2)),
+//! ]);
+//!
+//! let recording = record(source).await;
+//!
+//! assert!(recording.completed);
+//! assert_eq!(recording.values(), vec![&"a", &"b"]);
+//! assert_eq!(
+//!     recording.items[0],
+//!     RecordedItem::Value { timestamp: 1, value: "a" }
+//! );
+//! # }
+//! ```
+
+use fluxion_core::{StreamItem, Timestamped};
+use futures::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs};
+use tokio::time::{timeout_at, Instant};
+
+/// A single recorded emission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RecordedItem<T, TS = u64> {
+    Value { timestamp: TS, value: T },
+    Error { message: String },
+}
+
+/// The emissions of a stream, in order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recording<T, TS = u64> {
+    pub items: Vec<RecordedItem<T, TS>>,
+    /// Whether the stream ended before recording stopped.
+    pub completed: bool,
+}
+
+impl<T, TS> Recording<T, TS> {
+    /// Returns the recorded values, in order.
+    pub fn values(&self) -> Vec<&T> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                RecordedItem::Value { value, .. } => Some(value),
+                RecordedItem::Error { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Returns the messages of the recorded errors, in order.
+    pub fn errors(&self) -> Vec<&str> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                RecordedItem::Error { message } => Some(message.as_str()),
+                RecordedItem::Value { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Replaces every timestamp with its rank among the distinct recorded timestamps.
+    ///
+    /// The relative order of the emissions is preserved, but the recording no longer
+    /// depends on absolute timestamps.
+    pub fn normalize_timestamps(self) -> Recording<T, u64>
+    where
+        TS: Ord + Copy,
+    {
+        let mut timestamps: Vec<TS> = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                RecordedItem::Value { timestamp, .. } => Some(*timestamp),
+                RecordedItem::Error { .. } => None,
+            })
+            .collect();
+        timestamps.sort();
+        timestamps.dedup();
+
+        let items = self
+            .items
+            .into_iter()
+            .map(|item| match item {
+                RecordedItem::Value { timestamp, value } => RecordedItem::Value {
+                    timestamp: timestamps.binary_search(&timestamp).unwrap_or_default() as u64,
+                    value,
+                },
+                RecordedItem::Error { message } => RecordedItem::Error { message },
+            })
+            .collect();
+
+        Recording {
+            items,
+            completed: self.completed,
+        }
+    }
+}
+
+impl<T: Serialize, TS: Serialize> Recording<T, TS> {
+    /// Serializes the recording as pretty-printed JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value cannot be serialized.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("recording: serialization failed")
+    }
+}
+
+impl<T: DeserializeOwned, TS: DeserializeOwned> Recording<T, TS> {
+    /// Parses a recording previously produced by [`to_json`](Self::to_json).
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
+fn recorded<T: Timestamped>(item: StreamItem<T>) -> RecordedItem<T::Inner, T::Timestamp> {
+    match item {
+        StreamItem::Value(value) => RecordedItem::Value {
+            timestamp: value.timestamp(),
+            value: value.into_inner(),
+        },
+        StreamItem::Error(error) => RecordedItem::Error {
+            message: error.to_string(),
+        },
+    }
+}
+
+/// Records every emission of `stream` until it completes.
+pub async fn record<S, T>(stream: S) -> Recording<T::Inner, T::Timestamp>
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+{
+    let items = stream.map(recorded).collect().await;
+    Recording {
+        items,
+        completed: true,
+    }
+}
+
+/// Records the emissions of `stream` until it completes or `duration` has elapsed.
+///
+/// Unlike [`record`], this works with streams that never complete; `completed`
+/// tells whether the stream ended within `duration`.
+pub async fn record_for<S, T>(
+    stream: &mut S,
+    duration: Duration,
+) -> Recording<T::Inner, T::Timestamp>
+where
+    S: Stream<Item = StreamItem<T>> + Unpin,
+    T: Timestamped,
+{
+    let deadline = Instant::now() + duration;
+    let mut items = Vec::new();
+
+    loop {
+        match timeout_at(deadline, stream.next()).await {
+            Ok(Some(item)) => items.push(recorded(item)),
+            Ok(None) => {
+                return Recording {
+                    items,
+                    completed: true,
+                }
+            }
+            Err(_) => {
+                return Recording {
+                    items,
+                    completed: false,
+                }
+            }
+        }
+    }
+}
+
+/// Compares `recording` against the golden file at `path`.
+///
+/// Prefer the [`assert_recording_snapshot!`](crate::assert_recording_snapshot) macro,
+/// which derives the path from the snapshot name. See the [module](self) documentation
+/// for how snapshots are created and updated.
+///
+/// # Panics
+///
+/// Panics with a line diff if the recording differs from the snapshot, or if the
+/// snapshot is missing while running in CI.
+pub fn assert_snapshot<T, TS>(path: impl AsRef<Path>, recording: &Recording<T, TS>)
+where
+    T: Serialize,
+    TS: Serialize,
+{
+    let path = path.as_ref();
+    let actual = recording.to_json() + "\n";
+    let update = env::var("FLUXION_UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1");
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) if !update => expected,
+        Ok(_) => return write_snapshot(path, &actual),
+        Err(_) if env::var_os("CI").is_some() => {
+            panic!("snapshot {} is missing", path.display())
+        }
+        Err(_) => return write_snapshot(path, &actual),
+    };
+
+    if normalize_newlines(&expected) == actual {
+        let _ = fs::remove_file(pending_path(path));
+        return;
+    }
+
+    write_snapshot(&pending_path(path), &actual);
+    panic!(
+        "snapshot {} does not match; new recording written to {}\n{}",
+        path.display(),
+        pending_path(path).display(),
+        line_diff(&normalize_newlines(&expected), &actual),
+    );
+}
+
+/// Asserts that a [`Recording`] matches the golden snapshot called `name`.
+///
+/// The snapshot lives at `tests/snapshots/<name>.json` in the crate being tested.
+///
+/// ```rust,ignore
+/// let recording = record(stream).await;
+/// assert_recording_snapshot!("combine_latest_basic", recording);
+/// ```
+#[macro_export]
+macro_rules! assert_recording_snapshot {
+    ($name:expr, $recording:expr) => {
+        $crate::recorder::assert_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots")
+                .join(format!("{}.json", $name)),
+            &$recording,
+        )
+    };
+}
+
+fn pending_path(path: &Path) -> PathBuf {
+    let mut pending = path.as_os_str().to_owned();
+    pending.push(".new");
+    PathBuf::from(pending)
+}
+
+fn write_snapshot(path: &Path, contents: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("snapshot: cannot create directory");
+    }
+    fs::write(path, contents).expect("snapshot: cannot write file");
+}
+
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+
+    for line in 0..expected.len().max(actual.len()) {
+        match (expected.get(line), actual.get(line)) {
+            (Some(old), Some(new)) if old == new => {}
+            (old, new) => {
+                if let Some(old) = old {
+                    diff.push_str(&format!("{:>4} - {old}\n", line + 1));
+                }
+                if let Some(new) = new {
+                    diff.push_str(&format!("{:>4} + {new}\n", line + 1));
+                }
+            }
+        }
+    }
+
+    diff
+}
@@ -0,0 +1,187 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! A minimal `tracing` subscriber that records spans for assertions.
+//!
+//! [`SpanRecorder`] keeps every span created while it is the default subscriber,
+//! together with its parent and fields, so tests can verify how trace context is
+//! propagated through operator chains.
+
+use core::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::DefaultGuard;
+use tracing::{Event, Metadata, Subscriber};
+
+/// A span captured by [`SpanRecorder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedSpan {
+    pub id: u64,
+    pub name: &'static str,
+    pub parent: Option<u64>,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl RecordedSpan {
+    /// Returns the recorded value of `field`, formatted with `Debug`.
+    pub fn field(&self, field: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    spans: Vec<RecordedSpan>,
+    stack: Vec<u64>,
+}
+
+/// Records all spans created while installed as the default subscriber.
+///
+/// # Example
+///
+/// ```rust
+/// use fluxion_test_utils::span_recorder::SpanRecorder;
+///
+/// let recorder = SpanRecorder::new();
+/// let _guard = recorder.set_default();
+///
+/// let outer = tracing::info_span!("outer");
+/// outer.in_scope(|| {
+///     let _inner = tracing::info_span!("inner");
+/// });
+///
+/// let inner = recorder.find("inner").unwrap();
+/// assert_eq!(recorder.ancestry(inner.id), vec!["inner", "outer"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SpanRecorder {
+    state: Arc<Mutex<State>>,
+}
+
+impl SpanRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Installs the recorder as the default subscriber for the current thread.
+    pub fn set_default(&self) -> DefaultGuard {
+        tracing::subscriber::set_default(self.clone())
+    }
+
+    /// Returns all recorded spans in creation order.
+    pub fn spans(&self) -> Vec<RecordedSpan> {
+        self.state.lock().unwrap().spans.clone()
+    }
+
+    /// Returns all recorded spans with the given name, in creation order.
+    pub fn spans_named(&self, name: &str) -> Vec<RecordedSpan> {
+        self.spans()
+            .into_iter()
+            .filter(|span| span.name == name)
+            .collect()
+    }
+
+    /// Returns the first recorded span with the given name.
+    pub fn find(&self, name: &str) -> Option<RecordedSpan> {
+        self.spans().into_iter().find(|span| span.name == name)
+    }
+
+    /// Returns the names of the span with `id` and all of its ancestors, innermost first.
+    pub fn ancestry(&self, id: u64) -> Vec<&'static str> {
+        let spans = self.spans();
+        let mut names = Vec::new();
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            match spans.iter().find(|span| span.id == id) {
+                Some(span) => {
+                    names.push(span.name);
+                    current = span.parent;
+                }
+                None => break,
+            }
+        }
+
+        names
+    }
+}
+
+struct FieldVisitor<'a> {
+    fields: &'a mut Vec<(&'static str, String)>,
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.fields.push((field.name(), format!("{value:?}")));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.push((field.name(), value.to_string()));
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let mut state = self.state.lock().unwrap();
+        let id = state.spans.len() as u64 + 1;
+
+        let parent = if let Some(parent) = attrs.parent() {
+            Some(parent.into_u64())
+        } else if attrs.is_contextual() {
+            state.stack.last().copied()
+        } else {
+            None
+        };
+
+        let mut fields = Vec::new();
+        attrs.record(&mut FieldVisitor {
+            fields: &mut fields,
+        });
+
+        state.spans.push(RecordedSpan {
+            id,
+            name: attrs.metadata().name(),
+            parent,
+            fields,
+        });
+
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(recorded) = state
+            .spans
+            .iter_mut()
+            .find(|recorded| recorded.id == span.into_u64())
+        {
+            values.record(&mut FieldVisitor {
+                fields: &mut recorded.fields,
+            });
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, span: &Id) {
+        self.state.lock().unwrap().stack.push(span.into_u64());
+    }
+
+    fn exit(&self, span: &Id) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(position) = state.stack.iter().rposition(|id| *id == span.into_u64()) {
+            state.stack.remove(position);
+        }
+    }
+}
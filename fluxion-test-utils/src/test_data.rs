@@ -2,8 +2,14 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
-use crate::{animal::Animal, person::Person, plant::Plant};
+use crate::{animal::Animal, person::Person, plant::Plant, sequenced::Sequenced};
 use core::fmt::{self, Display};
+use fastrand::Rng;
+use fluxion_core::{HasTimestamp, StreamItem};
+use futures::{stream, Stream};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::time::{sleep_until, Instant};
 
 #[derive(Debug, Clone)]
 pub enum DataVariant {
@@ -12,7 +18,7 @@ pub enum DataVariant {
     Plant,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum TestData {
     Person(Person),
     Animal(Animal),
@@ -113,3 +119,166 @@ impl Display for TestData {
         }
     }
 }
+
+/// Distribution of the timestamp gaps between items produced by [`TestStreamBuilder`].
+///
+/// Gaps are measured in timestamp ticks and are always at least one, so generated
+/// timestamps are strictly increasing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arrival {
+    /// Every item arrives the given number of ticks after the previous one.
+    Constant(u64),
+    /// Exponentially distributed gaps with the given mean, as in a Poisson process.
+    Poisson { mean: f64 },
+    /// Bursts of `size` items `spacing` ticks apart, with `gap` ticks between bursts.
+    Bursty { size: usize, spacing: u64, gap: u64 },
+}
+
+/// Generates large, reproducible sequences of [`Sequenced`] items for load tests and
+/// benchmarks.
+///
+/// The builder produces `TestData` values by default, cycling through people, animals and
+/// plants with distinct names; [`values`](Self::values) swaps in any other generator.
+/// Timestamps follow the configured [`Arrival`] distribution, drawn from a seeded random
+/// number generator so the same configuration always yields the same items.
+///
+/// # Example
+///
+/// ```rust
+/// use fluxion_test_utils::test_data::{Arrival, TestStreamBuilder};
+///
+/// let items = TestStreamBuilder::new(10_000)
+///     .arrival(Arrival::Poisson { mean: 5.0 })
+///     .seed(7)
+///     .values(|index| vec![index as u8; 64])
+///     .build();
+///
+/// assert_eq!(items.len(), 10_000);
+/// ```
+pub struct TestStreamBuilder<T> {
+    count: usize,
+    start: u64,
+    arrival: Arrival,
+    seed: u64,
+    generator: Box<dyn FnMut(usize) -> T>,
+}
+
+impl TestStreamBuilder<TestData> {
+    /// Creates a builder for `count` items arriving one tick apart, starting at timestamp 1.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            start: 1,
+            arrival: Arrival::Constant(1),
+            seed: 0,
+            generator: Box::new(generated_test_data),
+        }
+    }
+}
+
+impl<T> TestStreamBuilder<T> {
+    /// Replaces the value generator. It is called with the index of each item.
+    pub fn values<U>(self, generator: impl FnMut(usize) -> U + 'static) -> TestStreamBuilder<U> {
+        TestStreamBuilder {
+            count: self.count,
+            start: self.start,
+            arrival: self.arrival,
+            seed: self.seed,
+            generator: Box::new(generator),
+        }
+    }
+
+    #[must_use]
+    pub fn arrival(mut self, arrival: Arrival) -> Self {
+        self.arrival = arrival;
+        self
+    }
+
+    #[must_use]
+    pub fn starting_at(mut self, timestamp: u64) -> Self {
+        self.start = timestamp;
+        self
+    }
+
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Generates the items.
+    pub fn build(mut self) -> Vec<Sequenced<T>> {
+        let mut rng = Rng::with_seed(self.seed);
+        let mut timestamp = self.start;
+
+        (0..self.count)
+            .map(|index| {
+                if index > 0 {
+                    timestamp += next_gap(&self.arrival, index, &mut rng);
+                }
+                Sequenced::with_timestamp((self.generator)(index), timestamp)
+            })
+            .collect()
+    }
+
+    /// Generates the items wrapped in [`StreamItem::Value`].
+    pub fn build_items(self) -> Vec<StreamItem<Sequenced<T>>> {
+        self.build().into_iter().map(StreamItem::Value).collect()
+    }
+
+    /// Generates the items as a stream that emits them all immediately.
+    pub fn build_stream(self) -> impl Stream<Item = StreamItem<Sequenced<T>>> {
+        stream::iter(self.build_items())
+    }
+}
+
+impl<T> TestStreamBuilder<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Generates the items as a stream that replays their arrival pattern in time.
+    ///
+    /// Each item is emitted `(timestamp - start) * tick` after the stream is first polled,
+    /// using tokio timers, so it composes with `tokio::time::pause`.
+    pub fn build_paced(self, tick: Duration) -> impl Stream<Item = StreamItem<Sequenced<T>>> {
+        let start = self.start;
+        let items = self.build();
+
+        stream::unfold(
+            (items.into_iter(), None),
+            move |(mut items, origin): (_, Option<Instant>)| async move {
+                let item = items.next()?;
+                let origin = origin.unwrap_or_else(Instant::now);
+                let ticks = u32::try_from(item.timestamp() - start).unwrap_or(u32::MAX);
+                sleep_until(origin + tick * ticks).await;
+                Some((StreamItem::Value(item), (items, Some(origin))))
+            },
+        )
+    }
+}
+
+fn next_gap(arrival: &Arrival, index: usize, rng: &mut Rng) -> u64 {
+    match arrival {
+        Arrival::Constant(gap) => (*gap).max(1),
+        Arrival::Poisson { mean } => {
+            let gap = -mean * (1.0 - rng.f64()).ln();
+            (gap.ceil() as u64).max(1)
+        }
+        Arrival::Bursty { size, spacing, gap } => {
+            if index.is_multiple_of((*size).max(1)) {
+                (*gap).max(1)
+            } else {
+                (*spacing).max(1)
+            }
+        }
+    }
+}
+
+fn generated_test_data(index: usize) -> TestData {
+    let number = u32::try_from(index).unwrap_or(u32::MAX);
+    match index % 3 {
+        0 => person(format!("Person{index}"), number % 100),
+        1 => animal(format!("Animal{index}"), number % 9),
+        _ => plant(format!("Plant{index}"), number % 1000),
+    }
+}
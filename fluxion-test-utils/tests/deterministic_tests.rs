@@ -0,0 +1,118 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_test_utils::deterministic::{
+    check_seeds, run_interleaved, yield_now, DeterministicExecutor,
+};
+use futures::channel::oneshot;
+use futures::stream;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+fn interleave(seed: u64) -> Vec<char> {
+    run_interleaved(
+        seed,
+        vec![vec!['a', 'b', 'c'], vec!['x', 'y', 'z']],
+        |mut streams| {
+            let right = streams.pop().unwrap();
+            let left = streams.pop().unwrap();
+            stream::select(left, right)
+        },
+    )
+}
+
+#[test]
+fn test_same_seed_replays_same_interleaving() {
+    // Act
+    let first = interleave(17);
+    let second = interleave(17);
+
+    // Assert
+    assert_eq!(first, second);
+    assert_eq!(first.len(), 6);
+}
+
+#[test]
+fn test_seeds_explore_different_interleavings() {
+    // Act
+    let outcomes: BTreeSet<Vec<char>> = (0..64).map(interleave).collect();
+
+    // Assert
+    assert!(outcomes.len() > 1);
+    for outcome in &outcomes {
+        let left: Vec<char> = outcome.iter().copied().filter(|c| *c < 'x').collect();
+        assert_eq!(left, vec!['a', 'b', 'c']);
+    }
+}
+
+#[test]
+fn test_trace_is_reproducible() {
+    // Arrange
+    let run = |seed| {
+        let mut executor = DeterministicExecutor::new(seed);
+        for _ in 0..3 {
+            executor.spawn(async {
+                yield_now().await;
+                yield_now().await;
+            });
+        }
+        executor.run(async {
+            yield_now().await;
+        });
+        executor.trace().to_vec()
+    };
+
+    // Act & Assert
+    assert_eq!(run(5), run(5));
+}
+
+#[test]
+fn test_tasks_can_spawn_tasks() {
+    // Arrange
+    let mut executor = DeterministicExecutor::new(1);
+    let spawner = executor.spawner();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = oneshot::channel();
+
+    let inner_log = Rc::clone(&log);
+    executor.spawn(async move {
+        inner_log.borrow_mut().push("parent");
+        spawner.spawn(async move {
+            inner_log.borrow_mut().push("child");
+            tx.send(()).unwrap();
+        });
+    });
+
+    // Act
+    executor.run(async move { rx.await.unwrap() });
+
+    // Assert
+    assert_eq!(*log.borrow(), vec!["parent", "child"]);
+}
+
+#[test]
+fn test_stall_is_reported_with_seed() {
+    // Arrange
+    let mut executor = DeterministicExecutor::new(9);
+    let (_tx, rx) = oneshot::channel::<()>();
+
+    // Act
+    let panic = catch_unwind(AssertUnwindSafe(move || executor.run(rx))).unwrap_err();
+
+    // Assert
+    let message = panic.downcast::<String>().unwrap();
+    assert!(message.contains("seed 9"));
+    assert!(message.contains("stalled"));
+}
+
+#[test]
+fn test_check_seeds_propagates_failure() {
+    // Act
+    let result = catch_unwind(|| check_seeds(0..10, |seed| assert!(seed < 3, "boom")));
+
+    // Assert
+    assert!(result.is_err());
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_test_utils::assert_emits;
+use fluxion_test_utils::helpers::{test_channel, test_channel_with_errors};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, person_bob, TestData};
+use futures::FutureExt;
+use std::panic::AssertUnwindSafe;
+
+async fn failure_message(future: impl core::future::Future<Output = ()>) -> String {
+    let panic = AssertUnwindSafe(future).catch_unwind().await.unwrap_err();
+    *panic.downcast::<String>().unwrap()
+}
+
+#[tokio::test]
+async fn test_assert_emits_values_and_end() {
+    // Arrange
+    let (tx, mut stream) = test_channel();
+    tx.unbounded_send(Sequenced::new(person_alice())).unwrap();
+    tx.unbounded_send(Sequenced::new(person_bob())).unwrap();
+    drop(tx);
+
+    // Act & Assert
+    assert_emits!(stream, [person_alice(), person_bob(), end]);
+}
+
+#[tokio::test]
+async fn test_assert_emits_patterns_wildcards_and_errors() {
+    // Arrange
+    let (tx, mut stream) = test_channel_with_errors();
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(animal_dog())))
+        .unwrap();
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("disk full")))
+        .unwrap();
+    tx.unbounded_send(StreamItem::Value(Sequenced::new(person_alice())))
+        .unwrap();
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("other")))
+        .unwrap();
+
+    // Act & Assert
+    assert_emits!(
+        stream,
+        timeout = 100,
+        [matches TestData::Animal(_), error("disk full"), _, error]
+    );
+}
+
+#[tokio::test]
+async fn test_assert_emits_reports_mismatch_with_table() {
+    // Arrange
+    let (tx, mut stream) = test_channel();
+    tx.unbounded_send(Sequenced::new(1)).unwrap();
+    tx.unbounded_send(Sequenced::new(3)).unwrap();
+
+    // Act
+    let message = failure_message(async move {
+        assert_emits!(stream, [1, 2, end]);
+    })
+    .await;
+
+    // Assert
+    assert!(message.contains("failed at emission 1"));
+    assert!(message.contains("ok 0  Value(1)"));
+    assert!(message.contains("!! 1  Value(2)"));
+    assert!(message.contains("Value(3)"));
+    assert!(message.contains("<not checked>"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_assert_emits_times_out_per_item() {
+    // Arrange
+    let (tx, mut stream) = test_channel();
+    tx.unbounded_send(Sequenced::new(1)).unwrap();
+
+    // Act
+    let message = failure_message(async move {
+        assert_emits!(stream, timeout = 50, [1, 2]);
+    })
+    .await;
+    drop(tx);
+
+    // Assert
+    assert!(message.contains("<nothing within 50 ms>"));
+}
+
+#[tokio::test]
+async fn test_assert_emits_rejects_error_with_other_message() {
+    // Arrange
+    let (tx, mut stream) = test_channel_with_errors::<Sequenced<i32>>();
+    tx.unbounded_send(StreamItem::Error(FluxionError::stream_error("timeout")))
+        .unwrap();
+
+    // Act
+    let message = failure_message(async move {
+        assert_emits!(stream, [error("overflow")]);
+    })
+    .await;
+
+    // Assert
+    assert!(message.contains("Error(containing \"overflow\")"));
+}
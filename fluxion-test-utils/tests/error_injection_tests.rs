@@ -3,8 +3,12 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 use fluxion_core::{FluxionError, StreamItem};
-use fluxion_test_utils::{error_injection::ErrorInjectingStream, sequenced::Sequenced};
+use fluxion_test_utils::error_injection::{ChaosConfig, ChaosStream, ErrorInjectingStream};
+use fluxion_test_utils::sequenced::Sequenced;
 use futures::{stream, StreamExt};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::time::Instant;
 
 #[tokio::test]
 async fn test_error_injection_at_position() {
@@ -59,3 +63,129 @@ async fn test_error_injection_at_start() {
     // Assert
     assert!(matches!(second, StreamItem::Value(_)));
 }
+
+fn values(count: u64) -> impl futures::Stream<Item = StreamItem<Sequenced<u64>>> + Unpin {
+    stream::iter((0..count).map(|i| StreamItem::Value(Sequenced::with_timestamp(i, i))))
+}
+
+fn inner_values(items: &[StreamItem<Sequenced<u64>>]) -> Vec<u64> {
+    items
+        .iter()
+        .filter_map(|item| item.clone().ok().map(|value| value.value))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_chaos_default_config_is_transparent() {
+    // Arrange
+    let chaos = ChaosStream::new(values(20), ChaosConfig::default());
+
+    // Act
+    let items: Vec<_> = chaos.collect().await;
+
+    // Assert
+    assert_eq!(inner_values(&items), (0..20).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn test_chaos_duplicate_and_drop_are_counted() {
+    // Arrange
+    let config = ChaosConfig::new(42).with_duplicate(0.2).with_drop(0.2);
+    let chaos = ChaosStream::new(values(200), config);
+    let stats = chaos.stats();
+
+    // Act
+    let items: Vec<_> = chaos.collect().await;
+
+    // Assert
+    let duplicated = stats.duplicated.load(Ordering::Relaxed);
+    let dropped = stats.dropped.load(Ordering::Relaxed);
+    assert!(duplicated > 0 && dropped > 0);
+    assert_eq!(items.len(), 200 + duplicated - dropped);
+
+    let emitted = inner_values(&items);
+    assert!(emitted.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[tokio::test]
+async fn test_chaos_reorder_stays_within_window() {
+    // Arrange
+    let config = ChaosConfig::new(7).with_reorder(0.3, 3);
+    let chaos = ChaosStream::new(values(200), config);
+    let stats = chaos.stats();
+
+    // Act
+    let items: Vec<_> = chaos.collect().await;
+
+    // Assert
+    let emitted = inner_values(&items);
+    let mut sorted = emitted.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..200).collect::<Vec<_>>());
+    assert_ne!(emitted, sorted);
+    assert!(stats.reordered.load(Ordering::Relaxed) > 0);
+
+    for (position, value) in emitted.iter().enumerate() {
+        assert!(position as u64 <= value + 3, "{value} emitted too late");
+    }
+}
+
+#[tokio::test]
+async fn test_chaos_is_deterministic_for_a_seed() {
+    // Arrange
+    let config = ChaosConfig::new(3)
+        .with_reorder(0.2, 4)
+        .with_duplicate(0.1)
+        .with_drop(0.1)
+        .with_errors(0.05);
+
+    // Act
+    let first: Vec<_> = ChaosStream::new(values(100), config.clone())
+        .collect()
+        .await;
+    let second: Vec<_> = ChaosStream::new(values(100), config).collect().await;
+
+    // Assert
+    assert_eq!(inner_values(&first), inner_values(&second));
+    assert_eq!(
+        first.iter().filter(|item| item.is_error()).count(),
+        second.iter().filter(|item| item.is_error()).count()
+    );
+}
+
+#[tokio::test]
+async fn test_chaos_injects_errors_and_passes_inner_errors() {
+    // Arrange
+    let source = stream::iter(vec![
+        StreamItem::Value(Sequenced::with_timestamp(1, 1)),
+        StreamItem::Error(FluxionError::stream_error("inner")),
+        StreamItem::Value(Sequenced::with_timestamp(2, 2)),
+    ]);
+    let chaos = ChaosStream::new(source, ChaosConfig::new(1).with_errors(1.0));
+    let stats = chaos.stats();
+
+    // Act
+    let items: Vec<_> = chaos.collect().await;
+
+    // Assert
+    assert_eq!(stats.errors.load(Ordering::Relaxed), 2);
+    assert_eq!(items.iter().filter(|item| item.is_error()).count(), 3);
+    assert_eq!(inner_values(&items), vec![1, 2]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_chaos_delay_postpones_values() {
+    // Arrange
+    let config = ChaosConfig::new(5).with_delay(1.0, Duration::from_millis(100));
+    let chaos = ChaosStream::new(values(10), config);
+    let stats = chaos.stats();
+    let start = Instant::now();
+
+    // Act
+    let items: Vec<_> = chaos.collect().await;
+
+    // Assert
+    assert_eq!(inner_values(&items), (0..10).collect::<Vec<_>>());
+    assert_eq!(stats.delayed.load(Ordering::Relaxed), 10);
+    assert!(start.elapsed() > Duration::from_millis(100));
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_test_utils::fuzz::{run_scenario, FuzzItem, Scenario};
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::{future, stream, FutureExt, StreamExt};
+
+fn select_all(streams: Vec<UnboundedReceiver<FuzzItem>>) -> impl futures::Stream<Item = FuzzItem> {
+    stream::select_all(streams)
+}
+
+fn scenario(seed: u64) -> Scenario {
+    let mut data = seed.to_le_bytes().to_vec();
+    data.extend((0..64u8).map(|byte| byte.wrapping_mul(37)));
+    Scenario::from_bytes(&data, 3)
+}
+
+#[test]
+fn test_scenario_decodes_seed_and_ordered_unique_timestamps() {
+    // Act
+    let scenario = Scenario::from_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0b0011_1000, 2, 0xC0], 2);
+
+    // Assert
+    assert_eq!(scenario.seed, 1);
+    assert_eq!(scenario.sources.len(), 2);
+    let timestamps: Vec<Vec<Option<u64>>> = scenario
+        .sources
+        .iter()
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| match item {
+                    StreamItem::Value(value) => Some(value.timestamp()),
+                    StreamItem::Error(_) => None,
+                })
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        timestamps,
+        vec![vec![Some(1), None, Some(3), Some(7)], vec![Some(2)]]
+    );
+}
+
+#[test]
+fn test_scenario_of_short_input_is_empty() {
+    // Act
+    let scenario = Scenario::from_bytes(&[7, 7], 3);
+
+    // Assert
+    assert!(scenario.sources.iter().all(Vec::is_empty));
+}
+
+#[test]
+fn test_select_holds_no_loss_and_errors_preserved() {
+    for seed in 0..100 {
+        // Act
+        let trace = run_scenario(&scenario(seed), select_all);
+
+        // Assert
+        trace.check_no_loss().unwrap();
+        trace.check_errors_preserved().unwrap();
+    }
+}
+
+#[test]
+fn test_check_ordered_detects_reordering_of_available_items() {
+    // Arrange
+    let scenario = scenario(3);
+
+    // Act
+    let trace = run_scenario(&scenario, |streams| {
+        // Hold everything until the sources end, then emit it backwards
+        select_all(streams)
+            .collect::<Vec<_>>()
+            .map(|mut items| {
+                items.reverse();
+                stream::iter(items)
+            })
+            .flatten_stream()
+    });
+
+    // Assert
+    let violation = trace.check_ordered().unwrap_err();
+    assert_eq!(violation.invariant, "ordered output");
+    trace.check_no_loss().unwrap();
+}
+
+#[test]
+fn test_check_no_loss_detects_dropped_value() {
+    // Act
+    let trace = run_scenario(&scenario(5), |streams| {
+        select_all(streams).filter(|item| {
+            future::ready(!matches!(item, StreamItem::Value(value) if value.timestamp() == 4))
+        })
+    });
+
+    // Assert
+    let violation = trace.check_no_loss().unwrap_err();
+    assert!(violation.to_string().contains("timestamp 4"), "{violation}");
+}
+
+#[test]
+fn test_check_no_loss_detects_duplicate() {
+    // Act
+    let trace = run_scenario(&scenario(9), |streams| {
+        select_all(streams).flat_map(|item| match item {
+            StreamItem::Value(value) => stream::iter(vec![
+                StreamItem::Value(value.clone()),
+                StreamItem::Value(value),
+            ]),
+            StreamItem::Error(err) => stream::iter(vec![StreamItem::Error(err)]),
+        })
+    });
+
+    // Assert
+    assert_eq!(
+        trace.check_no_loss().unwrap_err().invariant,
+        "no duplicates"
+    );
+}
+
+#[test]
+fn test_check_no_loss_after_warmup_allows_dropping_leading_values_only() {
+    // Arrange
+    let scenario = scenario(11);
+
+    // Act
+    let skipped_first = run_scenario(&scenario, |streams| {
+        let mut skipped = false;
+        select_all(streams).filter(move |item| {
+            let skip = !skipped && item.is_value();
+            skipped |= skip;
+            future::ready(!skip)
+        })
+    });
+    let skipped_last = run_scenario(&scenario, |streams| {
+        select_all(streams).filter(|item| {
+            future::ready(!matches!(item, StreamItem::Value(value) if value.timestamp() > 30))
+        })
+    });
+
+    // Assert
+    skipped_first.check_no_loss_after_warmup().unwrap();
+    assert!(skipped_first.check_no_loss().is_err());
+    assert!(skipped_last.check_no_loss_after_warmup().is_err());
+}
+
+#[test]
+fn test_check_errors_preserved_detects_swallowed_error() {
+    // Act
+    let trace = run_scenario(&scenario(2), |streams| {
+        select_all(streams).filter(|item| future::ready(item.is_value()))
+    });
+
+    // Assert
+    assert_eq!(
+        trace.check_errors_preserved().unwrap_err().invariant,
+        "errors preserved"
+    );
+}
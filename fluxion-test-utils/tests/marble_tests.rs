@@ -0,0 +1,142 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{HasTimestamp, StreamItem};
+use fluxion_test_utils::expect_marble;
+use fluxion_test_utils::marble::{cold, Marble, MarbleEvent};
+use futures::{future, StreamExt};
+
+#[test]
+fn test_marble_parse_values_errors_and_completion() {
+    // Arrange
+    let values = [('a', 1), ('b', 2)];
+
+    // Act
+    let marble = Marble::parse("a-#-b|", &values);
+
+    // Assert
+    assert_eq!(
+        marble.events,
+        vec![
+            (0, MarbleEvent::Value(1)),
+            (2, MarbleEvent::Error),
+            (4, MarbleEvent::Value(2)),
+            (5, MarbleEvent::Complete),
+        ]
+    );
+    assert_eq!(marble.frames, 6);
+}
+
+#[test]
+fn test_marble_parse_groups_share_one_frame() {
+    // Arrange
+    let values = [('a', 1), ('b', 2), ('c', 3)];
+
+    // Act
+    let marble = Marble::parse("-(ab) c", &values);
+
+    // Assert
+    assert_eq!(
+        marble.events,
+        vec![
+            (1, MarbleEvent::Value(1)),
+            (1, MarbleEvent::Value(2)),
+            (2, MarbleEvent::Value(3)),
+        ]
+    );
+    assert_eq!(marble.frames, 3);
+}
+
+#[test]
+fn test_marble_render_round_trips() {
+    // Arrange
+    let values = [('a', 1), ('b', 2), ('c', 3)];
+    let marble = Marble::parse("a-(bc)--#|", &values);
+
+    // Act
+    let rendered = marble.render(&values);
+
+    // Assert
+    assert_eq!(rendered, "a-(bc)--#|");
+}
+
+#[test]
+#[should_panic(expected = "marble: no value for 'z'")]
+fn test_marble_parse_unknown_symbol_panics() {
+    Marble::parse("a-z", &[('a', 1)]);
+}
+
+#[test]
+#[should_panic(expected = "marble: event 'a' after completion")]
+fn test_marble_parse_event_after_completion_panics() {
+    Marble::parse("|a", &[('a', 1)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cold_emits_values_at_their_frames() {
+    // Arrange
+    let mut source = cold("a--b-|", &[('a', 1), ('b', 2)]);
+
+    // Act & Assert
+    expect_marble!(source, "a--b-|", { 'a' => 1, 'b' => 2 });
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cold_timestamps_are_frame_indices() {
+    // Arrange
+    let mut source = cold("-a--(bc)|", &[('a', 1), ('b', 2), ('c', 3)]);
+
+    // Act
+    let mut timestamps = Vec::new();
+    while let Some(item) = source.next().await {
+        if let StreamItem::Value(value) = item {
+            timestamps.push(value.timestamp());
+        }
+    }
+
+    // Assert
+    assert_eq!(timestamps, vec![1, 4, 4]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cold_continues_after_error() {
+    // Arrange
+    let mut source = cold("a#b|", &[('a', 1), ('b', 2)]);
+
+    // Act & Assert
+    expect_marble!(source, "a#b|", { 'a' => 1, 'b' => 2 });
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cold_without_completion_stays_open() {
+    // Arrange
+    let mut source = cold("a", &[('a', 1)]);
+
+    // Act & Assert
+    expect_marble!(source, "a-----", { 'a' => 1 });
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_expect_marble_observes_operator_timing() {
+    // Arrange
+    let source = cold("a-b-c-|", &[('a', 1), ('b', 2), ('c', 3)]);
+
+    // Act
+    let mut filtered = source.filter(|item| {
+        future::ready(!matches!(item, StreamItem::Value(value) if value.value == 2))
+    });
+
+    // Assert
+    expect_marble!(filtered, "a---c-|", { 'a' => 1, 'c' => 3 });
+}
+
+#[tokio::test(start_paused = true)]
+#[should_panic(expected = "marble mismatch")]
+async fn test_expect_marble_reports_mismatch() {
+    // Arrange
+    let mut source = cold("a-b|", &[('a', 1), ('b', 2)]);
+
+    // Act & Assert
+    expect_marble!(source, "a--b|", { 'a' => 1, 'b' => 2 });
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, HasTimestamp, StreamItem};
+use fluxion_test_utils::property::{
+    check_errors_preserved, check_monotone_timestamps, check_same_values,
+    check_strictly_monotone_timestamps, check_subsequence, run_stream, sorted_by_timestamp,
+    timestamped_stream, StreamProfile,
+};
+use fluxion_test_utils::sequenced::Sequenced;
+use futures::stream;
+use proptest::prelude::*;
+
+fn value(value: i32, timestamp: u64) -> StreamItem<Sequenced<i32>> {
+    StreamItem::Value(Sequenced::with_timestamp(value, timestamp))
+}
+
+proptest! {
+    #[test]
+    fn test_ordered_profile_generates_strictly_monotone_values(
+        items in timestamped_stream(any::<i32>(), StreamProfile::ordered())
+    ) {
+        prop_assert!(items.iter().all(StreamItem::is_value));
+        check_strictly_monotone_timestamps(&items)?;
+    }
+
+    #[test]
+    fn test_skew_is_bounded_by_profile(
+        items in timestamped_stream(any::<i32>(), StreamProfile::default().with_error_rate(0.0).with_skew(3))
+    ) {
+        let mut latest = 0;
+        for item in &items {
+            let timestamp = item.clone().unwrap().timestamp();
+            prop_assert!(timestamp + 3 >= latest);
+            latest = latest.max(timestamp);
+        }
+    }
+
+    #[test]
+    fn test_error_rate_one_generates_only_errors(
+        items in timestamped_stream(any::<i32>(), StreamProfile::default().with_len(1..=8).with_error_rate(1.0))
+    ) {
+        prop_assert!(items.iter().all(StreamItem::is_error));
+    }
+
+    #[test]
+    fn test_sorted_by_timestamp_keeps_items(
+        items in timestamped_stream(any::<i32>(), StreamProfile::default().with_bursts(8, 50))
+    ) {
+        let output = run_stream(stream::iter(sorted_by_timestamp(items.clone())));
+
+        check_monotone_timestamps(&output)?;
+        check_same_values(&items, &output)?;
+        check_errors_preserved(&items, &output)?;
+    }
+}
+
+#[test]
+fn test_check_monotone_timestamps_reports_regression() {
+    // Arrange
+    let items = vec![value(1, 1), value(2, 3), value(3, 2)];
+
+    // Act
+    let result = check_monotone_timestamps(&items);
+
+    // Assert
+    assert!(result.unwrap_err().to_string().contains("3 followed by 2"));
+}
+
+#[test]
+fn test_check_strictly_monotone_rejects_duplicates() {
+    // Arrange
+    let items = vec![value(1, 1), value(2, 1)];
+
+    // Act & Assert
+    assert!(check_monotone_timestamps(&items).is_ok());
+    assert!(check_strictly_monotone_timestamps(&items).is_err());
+}
+
+#[test]
+fn test_check_errors_preserved_detects_dropped_error() {
+    // Arrange
+    let input = vec![
+        value(1, 1),
+        StreamItem::Error(FluxionError::stream_error("boom")),
+    ];
+    let output = vec![value(1, 1)];
+
+    // Act & Assert
+    assert!(check_errors_preserved(&input, &output).is_err());
+}
+
+#[test]
+fn test_check_same_values_detects_dropped_and_invented_values() {
+    // Arrange
+    let input = vec![value(1, 1), value(2, 2)];
+
+    // Act & Assert
+    assert!(check_same_values(&input, &[value(2, 2), value(1, 1)]).is_ok());
+    assert!(check_same_values(&input, &[value(1, 1)]).is_err());
+    assert!(check_same_values(&input, &[value(1, 1), value(2, 2), value(3, 3)]).is_err());
+}
+
+#[test]
+fn test_check_subsequence_requires_input_order() {
+    // Arrange
+    let input = vec![value(1, 1), value(2, 2), value(3, 3)];
+
+    // Act & Assert
+    assert!(check_subsequence(&input, &[value(1, 1), value(3, 3)]).is_ok());
+    assert!(check_subsequence(&input, &[value(3, 3), value(1, 1)]).is_err());
+}
@@ -0,0 +1,143 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_test_utils::assert_recording_snapshot;
+use fluxion_test_utils::helpers::test_channel;
+use fluxion_test_utils::recorder::{assert_snapshot, record, record_for, RecordedItem, Recording};
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{animal_dog, person_alice, plant_rose, TestData};
+use futures::stream;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+use std::{env, fs};
+
+fn sample_stream() -> impl futures::Stream<Item = StreamItem<Sequenced<TestData>>> {
+    stream::iter(vec![
+        StreamItem::Value(Sequenced::with_timestamp(person_alice(), 1)),
+        StreamItem::Error(FluxionError::stream_error("sensor offline")),
+        StreamItem::Value(Sequenced::with_timestamp(animal_dog(), 2)),
+        StreamItem::Value(Sequenced::with_timestamp(plant_rose(), 5)),
+    ])
+}
+
+#[tokio::test]
+async fn test_record_captures_values_errors_and_completion() {
+    // Act
+    let recording = record(sample_stream()).await;
+
+    // Assert
+    assert!(recording.completed);
+    assert_eq!(
+        recording.values(),
+        vec![&person_alice(), &animal_dog(), &plant_rose()]
+    );
+    assert_eq!(recording.errors().len(), 1);
+    assert!(recording.errors()[0].contains("sensor offline"));
+    assert_eq!(
+        recording.items[2],
+        RecordedItem::Value {
+            timestamp: 2,
+            value: animal_dog()
+        }
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_record_for_stops_on_open_stream() {
+    // Arrange
+    let (tx, mut stream) = test_channel::<Sequenced<i32>>();
+    tx.unbounded_send(Sequenced::with_timestamp(7, 3)).unwrap();
+
+    // Act
+    let recording = record_for(&mut stream, Duration::from_millis(100)).await;
+
+    // Assert
+    assert!(!recording.completed);
+    assert_eq!(recording.values(), vec![&7]);
+}
+
+#[tokio::test]
+async fn test_recording_json_round_trip() {
+    // Arrange
+    let recording = record(sample_stream()).await;
+
+    // Act
+    let parsed = Recording::<TestData>::from_json(&recording.to_json()).unwrap();
+
+    // Assert
+    assert_eq!(parsed, recording);
+}
+
+#[test]
+fn test_normalize_timestamps_preserves_relative_order() {
+    // Arrange
+    let recording = Recording {
+        items: vec![
+            RecordedItem::Value {
+                timestamp: 1042,
+                value: 'a',
+            },
+            RecordedItem::Value {
+                timestamp: 1007,
+                value: 'b',
+            },
+            RecordedItem::Value {
+                timestamp: 1042,
+                value: 'c',
+            },
+        ],
+        completed: true,
+    };
+
+    // Act
+    let normalized = recording.normalize_timestamps();
+
+    // Assert
+    let timestamps: Vec<u64> = normalized
+        .items
+        .iter()
+        .map(|item| match item {
+            RecordedItem::Value { timestamp, .. } => *timestamp,
+            RecordedItem::Error { .. } => unreachable!(),
+        })
+        .collect();
+    assert_eq!(timestamps, vec![1, 0, 1]);
+}
+
+#[tokio::test]
+async fn test_recording_matches_golden_snapshot() {
+    // Act
+    let recording = record(sample_stream()).await;
+
+    // Assert
+    assert_recording_snapshot!("recorder_sample_stream", recording);
+}
+
+#[tokio::test]
+async fn test_snapshot_mismatch_fails_and_writes_pending_file() {
+    // Arrange
+    let path = env::temp_dir().join(format!("fluxion_recorder_{}.json", std::process::id()));
+    let pending = path.with_extension("json.new");
+    let original = record(sample_stream()).await;
+    fs::write(&path, original.to_json() + "\n").unwrap();
+
+    let mut changed = original.clone();
+    changed.items.pop();
+
+    // Act
+    let result = catch_unwind(AssertUnwindSafe(|| assert_snapshot(&path, &changed)));
+
+    // Assert
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("does not match"));
+    assert!(message.contains("- "));
+    assert_eq!(
+        fs::read_to_string(&pending).unwrap(),
+        changed.to_json() + "\n"
+    );
+
+    fs::remove_file(&path).unwrap();
+    fs::remove_file(&pending).unwrap();
+}
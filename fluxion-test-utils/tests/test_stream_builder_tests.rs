@@ -0,0 +1,114 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use fluxion_core::HasTimestamp;
+use fluxion_test_utils::sequenced::Sequenced;
+use fluxion_test_utils::test_data::{Arrival, TestData, TestStreamBuilder};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::time::Instant;
+
+fn gaps<T: Clone + Send + Sync + 'static>(items: &[Sequenced<T>]) -> Vec<u64> {
+    items
+        .windows(2)
+        .map(|pair| pair[1].timestamp() - pair[0].timestamp())
+        .collect()
+}
+
+#[test]
+fn test_builder_generates_distinct_test_data() {
+    // Act
+    let items = TestStreamBuilder::new(3000).build();
+
+    // Assert
+    assert_eq!(items.len(), 3000);
+    assert_eq!(items[0].timestamp(), 1);
+    assert!(gaps(&items).iter().all(|gap| *gap == 1));
+    assert!(matches!(items[0].value, TestData::Person(_)));
+    assert!(matches!(items[1].value, TestData::Animal(_)));
+    assert!(matches!(items[2].value, TestData::Plant(_)));
+    assert_ne!(items[0].value, items[3].value);
+}
+
+#[test]
+fn test_constant_arrival_from_start() {
+    // Act
+    let items = TestStreamBuilder::new(4)
+        .starting_at(100)
+        .arrival(Arrival::Constant(10))
+        .values(|index| index)
+        .build();
+
+    // Assert
+    let timestamps: Vec<u64> = items.iter().map(HasTimestamp::timestamp).collect();
+    assert_eq!(timestamps, vec![100, 110, 120, 130]);
+    assert_eq!(items[3].value, 3);
+}
+
+#[test]
+fn test_poisson_arrival_is_seeded_and_has_expected_mean() {
+    // Arrange
+    let build = |seed| {
+        TestStreamBuilder::new(10_000)
+            .arrival(Arrival::Poisson { mean: 20.0 })
+            .seed(seed)
+            .values(|index| index)
+            .build()
+    };
+
+    // Act
+    let first = build(3);
+    let replay = build(3);
+    let other = build(4);
+
+    // Assert
+    assert_eq!(first, replay);
+    assert_ne!(first, other);
+    let gaps = gaps(&first);
+    assert!(gaps.iter().all(|gap| *gap >= 1));
+    let mean = gaps.iter().sum::<u64>() as f64 / gaps.len() as f64;
+    assert!((19.0..=22.0).contains(&mean), "mean gap was {mean}");
+}
+
+#[test]
+fn test_bursty_arrival() {
+    // Act
+    let items = TestStreamBuilder::new(7)
+        .arrival(Arrival::Bursty {
+            size: 3,
+            spacing: 1,
+            gap: 50,
+        })
+        .values(|_| ())
+        .build();
+
+    // Assert
+    assert_eq!(gaps(&items), vec![1, 1, 50, 1, 1, 50]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_paced_stream_replays_arrival_times() {
+    // Arrange
+    let stream = TestStreamBuilder::new(3)
+        .arrival(Arrival::Constant(5))
+        .values(|index| index)
+        .build_paced(Duration::from_millis(10));
+    let started = Instant::now();
+
+    // Act
+    let arrivals: Vec<(usize, Duration)> = stream
+        .map(|item| (item.unwrap().value, started.elapsed()))
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(
+        arrivals,
+        vec![
+            (0, Duration::ZERO),
+            (1, Duration::from_millis(50)),
+            (2, Duration::from_millis(100)),
+        ]
+    );
+}
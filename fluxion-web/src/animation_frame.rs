@@ -0,0 +1,186 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::cell::{Cell, RefCell};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use fluxion_core::{FluxionError, StreamItem};
+use futures::Stream;
+use js_sys::Function;
+use pin_project::pin_project;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Extension trait that paces a stream to the browser's rendering.
+pub trait SampleOnAnimationFrameExt<T>: Stream<Item = StreamItem<T>> + Sized {
+    /// Emits the latest value once per animation frame.
+    ///
+    /// When a value arrives, the stream asks the browser for the next animation frame
+    /// with `requestAnimationFrame`; when the frame comes, the most recent value is
+    /// emitted and any older ones are dropped. Frames without new values emit nothing,
+    /// and nothing is scheduled while the source is idle. The result is at most one
+    /// render per frame, at the display's refresh rate, without choosing a sample
+    /// interval. Browsers pause animation frames in background tabs, and so does this
+    /// stream.
+    ///
+    /// - Values keep their own timestamps.
+    /// - Errors are passed through immediately.
+    /// - When the source ends, a value still waiting for its frame is emitted at that
+    ///   frame, then the stream ends.
+    ///
+    /// If `requestAnimationFrame` is not available, each affected value is replaced by
+    /// a [`FluxionError::StreamProcessingError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use fluxion_web::{inputs, SampleOnAnimationFrameExt};
+    /// use futures::StreamExt;
+    /// use wasm_bindgen::JsValue;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// # async fn example(slider: HtmlInputElement) -> Result<(), JsValue> {
+    /// let mut frames = inputs(&slider)?.sample_on_animation_frame();
+    ///
+    /// while let Some(item) = frames.next().await {
+    ///     if let Some(event) = item.ok() {
+    ///         // redraw with event.value at most once per frame
+    ///         # drop(event);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sample_on_animation_frame(self) -> AnimationFrameSample<Self, T>;
+}
+
+impl<S, T> SampleOnAnimationFrameExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+{
+    fn sample_on_animation_frame(self) -> AnimationFrameSample<Self, T> {
+        AnimationFrameSample {
+            stream: self,
+            latest: None,
+            frame: None,
+            source_done: false,
+        }
+    }
+}
+
+/// Stream returned by [`SampleOnAnimationFrameExt::sample_on_animation_frame`].
+///
+/// Dropping it cancels a pending animation frame request.
+#[pin_project]
+pub struct AnimationFrameSample<S, T> {
+    #[pin]
+    stream: S,
+    latest: Option<T>,
+    frame: Option<FrameRequest>,
+    source_done: bool,
+}
+
+impl<S, T> Stream for AnimationFrameSample<S, T>
+where
+    S: Stream<Item = StreamItem<T>>,
+{
+    type Item = StreamItem<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.source_done {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(StreamItem::Value(value))) => *this.latest = Some(value),
+                Poll::Ready(Some(StreamItem::Error(e))) => {
+                    return Poll::Ready(Some(StreamItem::Error(e)))
+                }
+                Poll::Ready(None) => *this.source_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if this.latest.is_none() {
+            return if *this.source_done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        let frame = match this.frame {
+            Some(frame) => frame,
+            None => match FrameRequest::next_frame() {
+                Ok(frame) => this.frame.insert(frame),
+                Err(error) => {
+                    this.latest.take();
+                    return Poll::Ready(Some(StreamItem::Error(FluxionError::stream_error(
+                        format!("requestAnimationFrame failed: {error:?}"),
+                    ))));
+                }
+            },
+        };
+
+        if frame.fired.get() {
+            this.frame.take();
+            return Poll::Ready(this.latest.take().map(StreamItem::Value));
+        }
+
+        *frame.waker.borrow_mut() = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// A pending `requestAnimationFrame` callback that wakes the stream when it runs.
+struct FrameRequest {
+    id: JsValue,
+    fired: Rc<Cell<bool>>,
+    waker: Rc<RefCell<Option<Waker>>>,
+    _callback: Closure<dyn FnMut(f64)>,
+}
+
+impl FrameRequest {
+    /// Requests the next animation frame from the global scope, which works in
+    /// windows and in dedicated workers.
+    fn next_frame() -> Result<Self, JsValue> {
+        let fired = Rc::new(Cell::new(false));
+        let waker = Rc::new(RefCell::new(None::<Waker>));
+
+        let callback = {
+            let fired = Rc::clone(&fired);
+            let waker = Rc::clone(&waker);
+            Closure::<dyn FnMut(f64)>::new(move |_: f64| {
+                fired.set(true);
+                if let Some(waker) = waker.borrow_mut().take() {
+                    waker.wake();
+                }
+            })
+        };
+
+        let id = global_function("requestAnimationFrame")?
+            .call1(&js_sys::global(), callback.as_ref())?;
+
+        Ok(Self {
+            id,
+            fired,
+            waker,
+            _callback: callback,
+        })
+    }
+}
+
+impl Drop for FrameRequest {
+    fn drop(&mut self) {
+        if !self.fired.get() {
+            if let Ok(cancel) = global_function("cancelAnimationFrame") {
+                let _ = cancel.call1(&js_sys::global(), &self.id);
+            }
+        }
+    }
+}
+
+fn global_function(name: &str) -> Result<Function, JsValue> {
+    js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str(name))?.dyn_into()
+}
@@ -0,0 +1,94 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::source::{events, DomEventStream};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, EventTarget, HtmlInputElement, MouseEvent, VisibilityState, Window};
+
+/// A `click` event: pointer position in viewport coordinates and the pressed button.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Click {
+    pub x: i32,
+    pub y: i32,
+    pub button: i16,
+}
+
+/// Inner size of the window in CSS pixels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewportSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Whether the document is visible to the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Visibility {
+    Visible,
+    Hidden,
+}
+
+/// Emits a [`Click`] for each `click` on `target`.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the listener cannot be registered.
+pub fn clicks(target: &EventTarget) -> Result<DomEventStream<Click>, JsValue> {
+    events(target, "click", |event| {
+        event
+            .dyn_ref::<MouseEvent>()
+            .map(|mouse| Click {
+                x: mouse.client_x(),
+                y: mouse.client_y(),
+                button: mouse.button(),
+            })
+            .unwrap_or(Click {
+                x: 0,
+                y: 0,
+                button: 0,
+            })
+    })
+}
+
+/// Emits the value of `input` each time the user edits it.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the listener cannot be registered.
+pub fn inputs(input: &HtmlInputElement) -> Result<DomEventStream<String>, JsValue> {
+    let element = input.clone();
+    events(input, "input", move |_| element.value())
+}
+
+/// Emits the new [`ViewportSize`] each time `window` is resized.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the listener cannot be registered.
+pub fn resizes(window: &Window) -> Result<DomEventStream<ViewportSize>, JsValue> {
+    let source = window.clone();
+    events(window, "resize", move |_| ViewportSize {
+        width: css_pixels(source.inner_width()),
+        height: css_pixels(source.inner_height()),
+    })
+}
+
+/// Emits the new [`Visibility`] each time `document` is shown or hidden, for example when
+/// the user switches tabs.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the listener cannot be registered.
+pub fn visibility_changes(document: &Document) -> Result<DomEventStream<Visibility>, JsValue> {
+    let source = document.clone();
+    events(document, "visibilitychange", move |_| {
+        match source.visibility_state() {
+            VisibilityState::Visible => Visibility::Visible,
+            _ => Visibility::Hidden,
+        }
+    })
+}
+
+fn css_pixels(size: Result<JsValue, JsValue>) -> u32 {
+    size.ok().and_then(|value| value.as_f64()).unwrap_or(0.0) as u32
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::timestamp::WebTimestamp;
+use fluxion_core::{HasTimestamp, Timestamped};
+
+/// A value extracted from a DOM event, stamped with the time the event was dispatched.
+///
+/// Ordering compares timestamps first, so sorting or merging events puts them in the
+/// order they happened.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WebEvent<T> {
+    pub timestamp: WebTimestamp,
+    pub value: T,
+}
+
+impl<T> WebEvent<T> {
+    pub fn new(value: T, timestamp: WebTimestamp) -> Self {
+        Self { timestamp, value }
+    }
+}
+
+impl<T> HasTimestamp for WebEvent<T> {
+    type Timestamp = WebTimestamp;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.timestamp
+    }
+}
+
+impl<T: Clone> Timestamped for WebEvent<T> {
+    type Inner = T;
+
+    fn with_timestamp(value: T, timestamp: WebTimestamp) -> Self {
+        Self::new(value, timestamp)
+    }
+
+    fn into_inner(self) -> T {
+        self.value
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::event::WebEvent;
+use crate::timestamp::WebTimestamp;
+use fluxion_core::{FluxionError, StreamItem};
+use futures::future::ready;
+use futures::{stream, Stream, StreamExt};
+use js_sys::{Function, Promise, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ReadableStreamDefaultReader, Response};
+
+/// Fetches `url` and emits the response body chunk by chunk, as the browser receives
+/// it, each chunk stamped with `performance.now()` on arrival.
+///
+/// The request is sent when the stream is first polled. The stream ends with the body;
+/// dropping it earlier cancels the download. A failed request, a response with an
+/// error status or a broken connection produces a single
+/// [`FluxionError::StreamProcessingError`], after which the stream ends.
+///
+/// Chunk boundaries depend on the network; use [`fetch_lines`] for line-oriented
+/// bodies such as NDJSON.
+pub fn fetch_chunks(url: &str) -> impl Stream<Item = StreamItem<WebEvent<Vec<u8>>>> {
+    Box::pin(stream::unfold(
+        Body::Requested(url.to_string()),
+        |body| async move {
+            let mut reader = match body {
+                Body::Requested(url) => match BodyReader::open(&url).await {
+                    Ok(reader) => reader,
+                    Err(error) => return Some((failed(&error), Body::Finished)),
+                },
+                Body::Reading(reader) => reader,
+                Body::Finished => return None,
+            };
+
+            match reader.next_chunk().await {
+                Ok(Some(chunk)) => Some((
+                    StreamItem::Value(WebEvent::new(chunk, WebTimestamp::now())),
+                    Body::Reading(reader),
+                )),
+                Ok(None) => None,
+                Err(error) => Some((failed(&error), Body::Finished)),
+            }
+        },
+    ))
+}
+
+/// Fetches `url` and emits the response body line by line as it arrives.
+///
+/// Lines are split on `\n`, with a trailing `\r` removed, and decoded as UTF-8 with
+/// invalid sequences replaced. Each line is stamped with the arrival time of the chunk
+/// that completed it. A final line without a terminating newline is emitted when the
+/// body ends.
+///
+/// Requests and errors behave as in [`fetch_chunks`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use fluxion_web::fetch_lines;
+/// use futures::StreamExt;
+///
+/// # async fn example() {
+/// let mut updates = fetch_lines("/api/updates.ndjson");
+///
+/// while let Some(item) = updates.next().await {
+///     if let Some(line) = item.ok() {
+///         // parse line.value as one JSON document
+///         # drop(line);
+///     }
+/// }
+/// # }
+/// ```
+pub fn fetch_lines(url: &str) -> impl Stream<Item = StreamItem<WebEvent<String>>> {
+    let mut pending = Vec::new();
+    let mut received_at = WebTimestamp::default();
+
+    fetch_chunks(url)
+        .map(Some)
+        .chain(stream::once(ready(None)))
+        .flat_map(move |item| {
+            let mut lines = Vec::new();
+            match item {
+                Some(StreamItem::Value(chunk)) => {
+                    pending.extend_from_slice(&chunk.value);
+                    received_at = chunk.timestamp;
+                    while let Some(end) = pending.iter().position(|&byte| byte == b'\n') {
+                        let line: Vec<u8> = pending.drain(..=end).collect();
+                        lines.push(StreamItem::Value(WebEvent::new(
+                            decode(&line[..end]),
+                            received_at,
+                        )));
+                    }
+                }
+                Some(StreamItem::Error(e)) => lines.push(StreamItem::Error(e)),
+                None if !pending.is_empty() => {
+                    let line = core::mem::take(&mut pending);
+                    lines.push(StreamItem::Value(WebEvent::new(decode(&line), received_at)));
+                }
+                None => {}
+            }
+            stream::iter(lines)
+        })
+}
+
+enum Body {
+    Requested(String),
+    Reading(BodyReader),
+    Finished,
+}
+
+/// Reader of a response body that cancels the download if dropped before the end.
+struct BodyReader {
+    reader: ReadableStreamDefaultReader,
+    done: bool,
+}
+
+impl BodyReader {
+    /// Fetches `url` from the global scope, which works in windows and in workers.
+    async fn open(url: &str) -> Result<Self, JsValue> {
+        let global = js_sys::global();
+        let fetch: Function =
+            js_sys::Reflect::get(&global, &JsValue::from_str("fetch"))?.dyn_into()?;
+        let request: Promise = fetch.call1(&global, &JsValue::from_str(url))?.dyn_into()?;
+        let response: Response = JsFuture::from(request).await?.dyn_into()?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "GET {url} failed with status {} {}",
+                response.status(),
+                response.status_text()
+            )));
+        }
+
+        let body = response
+            .body()
+            .ok_or_else(|| JsValue::from_str(&format!("GET {url} returned no body")))?;
+
+        Ok(Self {
+            reader: body.get_reader().unchecked_into(),
+            done: false,
+        })
+    }
+
+    /// Resolves to the next chunk, or `None` at the end of the body.
+    async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, JsValue> {
+        let result = JsFuture::from(self.reader.read()).await.inspect_err(|_| {
+            self.done = true;
+        })?;
+
+        if js_sys::Reflect::get(&result, &JsValue::from_str("done"))?.as_bool() == Some(true) {
+            self.done = true;
+            return Ok(None);
+        }
+
+        let chunk: Uint8Array =
+            js_sys::Reflect::get(&result, &JsValue::from_str("value"))?.dyn_into()?;
+        Ok(Some(chunk.to_vec()))
+    }
+}
+
+impl Drop for BodyReader {
+    fn drop(&mut self) {
+        if !self.done {
+            let _ = self.reader.cancel();
+        }
+    }
+}
+
+fn failed<T>(error: &JsValue) -> StreamItem<T> {
+    StreamItem::Error(FluxionError::stream_error(describe(error)))
+}
+
+fn decode(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
+fn describe(error: &JsValue) -> String {
+    error.as_string().unwrap_or_else(|| format!("{error:?}"))
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Browser sources for Fluxion streams.
+//!
+//! This crate turns DOM events into timestamped streams that plug straight into
+//! `fluxion-stream` operators. Every event is stamped with `performance.now()` when the
+//! browser dispatches it, so events from different sources merge in the order they
+//! actually happened.
+//!
+//! # Sources
+//!
+//! - [`clicks`] - `click` events on any element, with pointer position and button
+//! - [`inputs`] - `input` events on an `<input>`, with the current value
+//! - [`resizes`] - `resize` events on the window, with the viewport size
+//! - [`visibility_changes`] - `visibilitychange` events on the document
+//! - [`events`] - any event type, with a custom extraction function
+//!
+//! Each source returns a [`DomEventStream`] of [`WebEvent`] items. The listener is
+//! removed from the DOM when the stream is dropped.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use fluxion_web::{clicks, inputs};
+//! use futures::StreamExt;
+//! use wasm_bindgen::{JsCast, JsValue};
+//! use web_sys::HtmlInputElement;
+//!
+//! # async fn example() -> Result<(), JsValue> {
+//! let document = web_sys::window().unwrap().document().unwrap();
+//! let button = document.get_element_by_id("submit").unwrap();
+//! let search: HtmlInputElement = document.get_element_by_id("search").unwrap().dyn_into()?;
+//!
+//! let mut submits = clicks(&button)?;
+//! let queries = inputs(&search)?;
+//!
+//! if let Some(click) = submits.next().await {
+//!     let click = click.unwrap();
+//!     assert!(click.timestamp.as_micros() > 0);
+//! }
+//! # drop(queries);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Events are extracted into plain Rust values inside the listener, so items carry no
+//! references to DOM objects. The listeners run on the browser's main thread, so the
+//! streams are meant for the single-threaded (`runtime-wasm`) build of Fluxion.
+//!
+//! # Network connectors
+//!
+//! - [`websocket`] - a WebSocket as a [`WebSocketStream`] of received messages and a
+//!   [`WebSocketSink`] for sending
+//! - [`fetch_chunks`] - the body of a `fetch` response, chunk by chunk as it downloads
+//! - [`fetch_lines`] - the body of a `fetch` response, line by line, for NDJSON and
+//!   similar feeds
+//!
+//! Received data is stamped with `performance.now()` on arrival, like DOM events, so
+//! backend feeds merge with local sources in arrival order. Connection failures arrive
+//! as [`fluxion_core::FluxionError::StreamProcessingError`] items, after which the
+//! stream ends.
+//!
+//! # Rendering
+//!
+//! [`SampleOnAnimationFrameExt::sample_on_animation_frame`] emits the latest value once
+//! per animation frame, so a render pipeline draws at most once per frame instead of
+//! once per event.
+//!
+//! # Web Workers
+//!
+//! [`MapInWorkerExt::map_in_worker`] moves a heavy per-item computation off the main
+//! thread. The computation is registered inside the worker with [`register_task`], the
+//! worker starts answering with [`serve`], and the page sends items to a [`WorkerPool`]
+//! of such workers. Results come back in source order, keeping their timestamps.
+//!
+//! # Persistent history
+//!
+//! [`PersistExt::persist`] appends stream values to a [`ReplayStore`] in IndexedDB, which
+//! keeps a bounded, age-limited history across page reloads. [`ReplayStore::replay`]
+//! emits that history ahead of a live stream, so a view opened late starts populated.
+
+mod animation_frame;
+mod dom;
+mod event;
+mod fetch;
+mod map_in_worker;
+mod replay;
+mod source;
+mod timestamp;
+mod websocket;
+mod worker;
+
+pub use animation_frame::{AnimationFrameSample, SampleOnAnimationFrameExt};
+pub use dom::{clicks, inputs, resizes, visibility_changes, Click, ViewportSize, Visibility};
+pub use event::WebEvent;
+pub use fetch::{fetch_chunks, fetch_lines};
+pub use map_in_worker::MapInWorkerExt;
+pub use replay::{PersistExt, ReplayStore};
+pub use source::{events, DomEventStream};
+pub use timestamp::WebTimestamp;
+pub use websocket::{websocket, WebSocketMessage, WebSocketSink, WebSocketStream};
+pub use worker::{register_task, serve, WorkerPool};
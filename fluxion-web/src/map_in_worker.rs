@@ -0,0 +1,107 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::worker::{WorkerFailure, WorkerPool};
+use fluxion_core::{FluxionError, StreamItem, Timestamped};
+use futures::future::{ready, Either};
+use futures::{FutureExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Extension trait that runs a per-item computation on a [`WorkerPool`].
+pub trait MapInWorkerExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: Timestamped,
+{
+    /// Sends each value to a worker in `pool`, runs the task registered there as `task`
+    /// and emits the result with the source value's timestamp.
+    ///
+    /// Up to one value per worker is in flight at a time, and results are emitted in
+    /// source order however long each one takes. The value is serialized with
+    /// `serde-wasm-bindgen` on the way to the worker and the result deserialized on the
+    /// way back.
+    ///
+    /// A task that fails, or whose input or output cannot be converted, produces a
+    /// [`FluxionError::UserError`] in place of its result. A worker that fails, for
+    /// example because its script does not load, produces a
+    /// [`FluxionError::StreamProcessingError`] for each value sent to it. Errors from
+    /// the source pass through in order. Either way the stream continues.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use fluxion_core::StreamItem;
+    /// use fluxion_web::{MapInWorkerExt, WebEvent, WorkerPool};
+    /// use futures::stream;
+    /// use wasm_bindgen::JsValue;
+    ///
+    /// # fn example(frames: Vec<WebEvent<Vec<u8>>>) -> Result<(), JsValue> {
+    /// let pool = WorkerPool::new("./worker.js", 4)?;
+    ///
+    /// // "checksum" is registered in worker.js with register_task::<Vec<u8>, u32, _>
+    /// let checksums = stream::iter(frames.into_iter().map(StreamItem::Value))
+    ///     .map_in_worker::<WebEvent<u32>>(&pool, "checksum");
+    /// # drop(checksums);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn map_in_worker<U>(self, pool: &WorkerPool, task: &str) -> impl Stream<Item = StreamItem<U>>
+    where
+        T::Inner: Serialize,
+        U: Timestamped<Timestamp = T::Timestamp>,
+        U::Inner: DeserializeOwned;
+}
+
+impl<S, T> MapInWorkerExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+{
+    fn map_in_worker<U>(self, pool: &WorkerPool, task: &str) -> impl Stream<Item = StreamItem<U>>
+    where
+        T::Inner: Serialize,
+        U: Timestamped<Timestamp = T::Timestamp>,
+        U::Inner: DeserializeOwned,
+    {
+        let limit = pool.size();
+        let pool = pool.clone();
+        let task = task.to_string();
+
+        Box::pin(
+            self.map(move |item| {
+                let value = match item {
+                    StreamItem::Value(value) => value,
+                    StreamItem::Error(e) => return Either::Left(ready(StreamItem::Error(e))),
+                };
+
+                let timestamp = value.timestamp();
+                let payload = match serde_wasm_bindgen::to_value(&value.into_inner()) {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        return Either::Left(ready(StreamItem::Error(FluxionError::user_error(
+                            e.to_string(),
+                        ))))
+                    }
+                };
+
+                Either::Right(pool.request(&task, payload).map(move |reply| {
+                    let result = reply.and_then(|result| {
+                        serde_wasm_bindgen::from_value(result)
+                            .map_err(|e| WorkerFailure::Task(e.to_string()))
+                    });
+                    match result {
+                        Ok(result) => StreamItem::Value(U::with_timestamp(result, timestamp)),
+                        Err(WorkerFailure::Task(message)) => {
+                            StreamItem::Error(FluxionError::user_error(message))
+                        }
+                        Err(WorkerFailure::Worker(message)) => {
+                            StreamItem::Error(FluxionError::stream_error(message))
+                        }
+                    }
+                }))
+            })
+            .buffered(limit),
+        )
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::{FluxionError, StreamItem, Timestamped};
+use futures::{stream, Stream, StreamExt};
+use js_sys::{Function, Promise};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{
+    Event, IdbDatabase, IdbFactory, IdbKeyRange, IdbObjectStore, IdbObjectStoreParameters,
+    IdbOpenDbRequest, IdbRequest, IdbTransactionMode,
+};
+
+const STORE: &str = "items";
+const SAVED_AT: &str = "saved_at";
+
+/// A bounded history of stream values kept in IndexedDB.
+///
+/// Values written with [`PersistExt::persist`] survive page reloads and can be replayed
+/// ahead of a live stream with [`replay`](Self::replay), for example to fill a panel
+/// that opens late. The store keeps at most `max_items` values, none older than
+/// `max_age`; older values are evicted as new ones arrive and skipped when reading.
+///
+/// Each store is its own IndexedDB database, named after the store. Cloning a store is
+/// cheap and shares the connection.
+#[derive(Clone, Debug)]
+pub struct ReplayStore {
+    db: IdbDatabase,
+    max_items: usize,
+    max_age: Duration,
+}
+
+impl ReplayStore {
+    /// Opens the store called `name`, creating it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JavaScript error if IndexedDB is unavailable or the database cannot
+    /// be opened.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_items` is 0.
+    pub async fn open(name: &str, max_items: usize, max_age: Duration) -> Result<Self, JsValue> {
+        assert!(
+            max_items > 0,
+            "ReplayStore requires max_items of at least 1"
+        );
+
+        let factory: IdbFactory =
+            js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("indexedDB"))?.dyn_into()?;
+        let request: IdbOpenDbRequest = factory.open_with_u32(name, 1)?;
+
+        let upgrade_request = request.clone();
+        let on_upgrade = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+            let Ok(db) = upgrade_request
+                .result()
+                .and_then(|db| db.dyn_into::<IdbDatabase>())
+            else {
+                return;
+            };
+            let parameters = IdbObjectStoreParameters::new();
+            parameters.set_auto_increment(true);
+            if let Ok(store) = db.create_object_store_with_optional_parameters(STORE, &parameters) {
+                let _ = store.create_index_with_str(SAVED_AT, SAVED_AT);
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+
+        let db = completed(&request).await?.dyn_into()?;
+        drop(on_upgrade);
+
+        Ok(Self {
+            db,
+            max_items,
+            max_age,
+        })
+    }
+
+    /// Reads the stored values, oldest first, skipping values older than `max_age`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JavaScript error if reading fails, or a description of the problem
+    /// if a stored value cannot be deserialized into `V`.
+    pub async fn history<V: DeserializeOwned>(&self) -> Result<Vec<V>, JsValue> {
+        let store = self.object_store(IdbTransactionMode::Readonly)?;
+        let fresh = IdbKeyRange::lower_bound(&JsValue::from_f64(self.cutoff()))?;
+        let records: js_sys::Array = completed(&store.index(SAVED_AT)?.get_all_with_key(&fresh)?)
+            .await?
+            .dyn_into()?;
+
+        records
+            .iter()
+            .map(|record| {
+                let value = js_sys::Reflect::get(&record, &JsValue::from_str("value"))?;
+                serde_wasm_bindgen::from_value(value).map_err(JsValue::from)
+            })
+            .collect()
+    }
+
+    /// Emits the stored values, stamped by `stamp`, and then everything from `live`.
+    ///
+    /// Timestamps are not persisted: `performance.now()` starts over with every page
+    /// load, so a stored timestamp means nothing to the page reading it. `stamp`
+    /// attaches a timestamp from the reader's clock instead, typically "now".
+    ///
+    /// A failed read produces a single [`FluxionError::StreamProcessingError`] before
+    /// `live` starts.
+    pub fn replay<T, S, F>(&self, live: S, mut stamp: F) -> impl Stream<Item = StreamItem<T>>
+    where
+        T: Timestamped,
+        T::Inner: DeserializeOwned,
+        S: Stream<Item = StreamItem<T>>,
+        F: FnMut(T::Inner) -> T,
+    {
+        let store = self.clone();
+
+        Box::pin(
+            stream::once(async move { store.history::<T::Inner>().await })
+                .flat_map(move |history| {
+                    let items: Vec<StreamItem<T>> = match history {
+                        Ok(values) => values
+                            .into_iter()
+                            .map(|value| StreamItem::Value(stamp(value)))
+                            .collect(),
+                        Err(error) => vec![StreamItem::Error(FluxionError::stream_error(
+                            describe(&error),
+                        ))],
+                    };
+                    stream::iter(items)
+                })
+                .chain(live),
+        )
+    }
+
+    /// Removes every stored value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JavaScript error if the store cannot be cleared.
+    pub async fn clear(&self) -> Result<(), JsValue> {
+        let store = self.object_store(IdbTransactionMode::Readwrite)?;
+        completed(&store.clear()?).await.map(drop)
+    }
+
+    fn append(&self, value: JsValue) -> Result<(), JsValue> {
+        let record = js_sys::Object::new();
+        js_sys::Reflect::set(
+            &record,
+            &JsValue::from_str(SAVED_AT),
+            &JsValue::from_f64(js_sys::Date::now()),
+        )?;
+        js_sys::Reflect::set(&record, &JsValue::from_str("value"), &value)?;
+
+        let store = self.object_store(IdbTransactionMode::Readwrite)?;
+        store.add(&record)?;
+
+        let this = self.clone();
+        spawn_local(async move {
+            let _ = this.evict().await;
+        });
+        Ok(())
+    }
+
+    /// Deletes the oldest values until at most `max_items` remain and none is older
+    /// than `max_age`. Keys grow with insertion order, which is also `saved_at` order,
+    /// so the values to delete are always a prefix of the keys.
+    async fn evict(&self) -> Result<(), JsValue> {
+        let store = self.object_store(IdbTransactionMode::Readwrite)?;
+        let keys: js_sys::Array = completed(&store.get_all_keys()?).await?.dyn_into()?;
+        let stale = IdbKeyRange::upper_bound_with_open(&JsValue::from_f64(self.cutoff()), true)?;
+        let expired = completed(&store.index(SAVED_AT)?.count_with_key(&stale)?)
+            .await?
+            .as_f64()
+            .unwrap_or(0.0) as u32;
+
+        let excess = keys.length().saturating_sub(self.max_items as u32);
+        let delete = excess.max(expired);
+        if delete > 0 {
+            let range = IdbKeyRange::bound(&keys.get(0), &keys.get(delete - 1))?;
+            completed(&store.delete(&range)?).await?;
+        }
+        Ok(())
+    }
+
+    fn object_store(&self, mode: IdbTransactionMode) -> Result<IdbObjectStore, JsValue> {
+        self.db
+            .transaction_with_str_and_mode(STORE, mode)?
+            .object_store(STORE)
+    }
+
+    fn cutoff(&self) -> f64 {
+        js_sys::Date::now() - self.max_age.as_secs_f64() * 1000.0
+    }
+}
+
+/// Extension trait that writes stream values to a [`ReplayStore`].
+pub trait PersistExt<T>: Stream<Item = StreamItem<T>> + Sized
+where
+    T: Timestamped,
+    T::Inner: Serialize,
+{
+    /// Passes every item through unchanged, appending each value to `store`.
+    ///
+    /// Writes happen in the background and never hold the stream up. A value that
+    /// cannot be written is dropped from the history but still emitted. Errors pass
+    /// through and are not stored.
+    fn persist(self, store: &ReplayStore) -> impl Stream<Item = StreamItem<T>>;
+}
+
+impl<S, T> PersistExt<T> for S
+where
+    S: Stream<Item = StreamItem<T>>,
+    T: Timestamped,
+    T::Inner: Serialize,
+{
+    fn persist(self, store: &ReplayStore) -> impl Stream<Item = StreamItem<T>> {
+        let store = store.clone();
+
+        self.inspect(move |item| {
+            if let StreamItem::Value(value) = item {
+                if let Ok(value) = serde_wasm_bindgen::to_value(&value.clone().into_inner()) {
+                    let _ = store.append(value);
+                }
+            }
+        })
+    }
+}
+
+/// Resolves once `request` succeeds, with its result.
+async fn completed(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    let outcome = JsFuture::from(promise).await;
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+
+    match outcome {
+        Ok(_) => request.result(),
+        Err(event) => Err(request
+            .error()
+            .ok()
+            .flatten()
+            .map(JsValue::from)
+            .unwrap_or(event)),
+    }
+}
+
+fn describe(error: &JsValue) -> String {
+    error.as_string().unwrap_or_else(|| format!("{error:?}"))
+}
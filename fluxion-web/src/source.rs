@@ -0,0 +1,85 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::event::WebEvent;
+use crate::timestamp::WebTimestamp;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fluxion_core::StreamItem;
+use futures::Stream;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Event, EventTarget};
+
+/// Stream of values extracted from DOM events of one type on one target.
+///
+/// Created by [`events`] and the typed sources built on it. The event listener stays
+/// registered for as long as the stream lives and is removed when it is dropped. The
+/// stream never ends on its own.
+pub struct DomEventStream<T> {
+    receiver: UnboundedReceiver<WebEvent<T>>,
+    _listener: EventListener,
+}
+
+struct EventListener {
+    target: EventTarget,
+    event_type: String,
+    callback: Closure<dyn FnMut(Event)>,
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        let _ = self.target.remove_event_listener_with_callback(
+            &self.event_type,
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+/// Listens for `event_type` on `target` and emits the value `extract` returns for each
+/// event, stamped with `performance.now()` at dispatch.
+///
+/// `extract` runs inside the listener, so it can read event and element state (such as
+/// an input's current value) before the browser changes it again.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the listener cannot be registered.
+pub fn events<T, F>(
+    target: &EventTarget,
+    event_type: &str,
+    mut extract: F,
+) -> Result<DomEventStream<T>, JsValue>
+where
+    T: 'static,
+    F: FnMut(&Event) -> T + 'static,
+{
+    let (sender, receiver) = unbounded();
+
+    let callback = Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+        let timestamp = WebTimestamp::now();
+        let _ = sender.unbounded_send(WebEvent::new(extract(&event), timestamp));
+    });
+    target.add_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref())?;
+
+    Ok(DomEventStream {
+        receiver,
+        _listener: EventListener {
+            target: target.clone(),
+            event_type: event_type.to_string(),
+            callback,
+        },
+    })
+}
+
+impl<T> Stream for DomEventStream<T> {
+    type Item = StreamItem<WebEvent<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver)
+            .poll_next(cx)
+            .map(|event| event.map(StreamItem::Value))
+    }
+}
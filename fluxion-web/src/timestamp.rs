@@ -0,0 +1,73 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::ops::{Add, Sub};
+use core::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::Performance;
+
+/// A point in time measured by `performance.now()`, in microseconds since the time origin
+/// of the page or worker.
+///
+/// `performance.now()` is monotonic and has sub-millisecond resolution, unlike
+/// `Date.now()`, so events in quick succession still get distinct, ordered timestamps.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WebTimestamp(u64);
+
+impl WebTimestamp {
+    /// Reads `performance.now()` from the global scope, which works both on the main
+    /// thread and inside Web Workers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global scope has no `performance` object.
+    pub fn now() -> Self {
+        let performance = js_sys::Reflect::get(&js_sys::global(), &"performance".into())
+            .ok()
+            .and_then(|value| value.dyn_into::<Performance>().ok())
+            .expect("performance.now() is not available in this global scope");
+
+        Self::from_millis_f64(performance.now())
+    }
+
+    /// Creates a timestamp from a `DOMHighResTimeStamp` (fractional milliseconds), such as
+    /// `performance.now()` or `Event.timeStamp`.
+    pub fn from_millis_f64(millis: f64) -> Self {
+        Self((millis * 1000.0) as u64)
+    }
+
+    /// Creates a timestamp from microseconds since the time origin.
+    pub const fn from_micros(micros: u64) -> Self {
+        Self(micros)
+    }
+
+    /// Microseconds since the time origin.
+    pub const fn as_micros(self) -> u64 {
+        self.0
+    }
+}
+
+impl Sub for WebTimestamp {
+    type Output = Duration;
+
+    fn sub(self, other: WebTimestamp) -> Duration {
+        Duration::from_micros(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Add<Duration> for WebTimestamp {
+    type Output = WebTimestamp;
+
+    fn add(self, duration: Duration) -> WebTimestamp {
+        WebTimestamp(self.0 + duration.as_micros() as u64)
+    }
+}
+
+impl Sub<Duration> for WebTimestamp {
+    type Output = WebTimestamp;
+
+    fn sub(self, duration: Duration) -> WebTimestamp {
+        WebTimestamp(self.0.saturating_sub(duration.as_micros() as u64))
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::event::WebEvent;
+use crate::timestamp::WebTimestamp;
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use fluxion_core::{FluxionError, StreamItem};
+use futures::{Sink, Stream};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use js_sys::{ArrayBuffer, Uint8Array};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+
+type Incoming = StreamItem<WebEvent<WebSocketMessage>>;
+
+/// A message sent or received over a WebSocket.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WebSocketMessage {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Opens a WebSocket to `url` and returns its sending and receiving halves.
+///
+/// The receiving half emits every message, stamped with `performance.now()` on
+/// arrival, and ends when the connection closes. A connection that closes abnormally,
+/// including one that never opens, first emits a
+/// [`FluxionError::StreamProcessingError`] with the close code and reason.
+///
+/// The connection stays open while either half is alive and is closed when both have
+/// been dropped.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if `url` is not a valid WebSocket URL.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use fluxion_web::{websocket, WebSocketMessage};
+/// use futures::{SinkExt, StreamExt};
+/// use wasm_bindgen::JsValue;
+///
+/// # async fn example() -> Result<(), JsValue> {
+/// let (mut outgoing, mut incoming) = websocket("wss://example.com/prices")?;
+///
+/// outgoing
+///     .send(WebSocketMessage::Text("subscribe BTC".into()))
+///     .await?;
+///
+/// while let Some(item) = incoming.next().await {
+///     if let Some(message) = item.ok() {
+///         // message.value is the WebSocketMessage
+///         # drop(message);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn websocket(url: &str) -> Result<(WebSocketSink, WebSocketStream), JsValue> {
+    let socket = WebSocket::new(url)?;
+    socket.set_binary_type(BinaryType::Arraybuffer);
+
+    let (sender, receiver) = unbounded();
+    let sender = Rc::new(RefCell::new(Some(sender)));
+    let ready_waker = Rc::new(RefCell::new(None::<Waker>));
+
+    let on_open = {
+        let ready_waker = Rc::clone(&ready_waker);
+        Closure::<dyn FnMut(Event)>::new(move |_: Event| wake(&ready_waker))
+    };
+
+    let on_message = {
+        let sender = Rc::clone(&sender);
+        Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let timestamp = WebTimestamp::now();
+            let data = event.data();
+            let message = match data.as_string() {
+                Some(text) => WebSocketMessage::Text(text),
+                None => match data.dyn_into::<ArrayBuffer>() {
+                    Ok(buffer) => WebSocketMessage::Binary(Uint8Array::new(&buffer).to_vec()),
+                    Err(_) => return,
+                },
+            };
+            send(
+                &sender,
+                StreamItem::Value(WebEvent::new(message, timestamp)),
+            );
+        })
+    };
+
+    // Browsers follow every `error` event with a `close` event, which carries the
+    // useful information, so only `close` is handled.
+    let on_close = {
+        let sender = Rc::clone(&sender);
+        let ready_waker = Rc::clone(&ready_waker);
+        Closure::<dyn FnMut(CloseEvent)>::new(move |event: CloseEvent| {
+            if !event.was_clean() || !matches!(event.code(), 1000 | 1001 | 1005) {
+                send(
+                    &sender,
+                    StreamItem::Error(FluxionError::stream_error(format!(
+                        "websocket closed with code {}: {}",
+                        event.code(),
+                        event.reason()
+                    ))),
+                );
+            }
+            sender.borrow_mut().take();
+            wake(&ready_waker);
+        })
+    };
+
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+    let connection = Rc::new(Connection {
+        socket,
+        ready_waker,
+        _on_open: on_open,
+        _on_message: on_message,
+        _on_close: on_close,
+    });
+
+    Ok((
+        WebSocketSink {
+            connection: Rc::clone(&connection),
+        },
+        WebSocketStream {
+            receiver,
+            _connection: connection,
+        },
+    ))
+}
+
+struct Connection {
+    socket: WebSocket,
+    ready_waker: Rc<RefCell<Option<Waker>>>,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        if matches!(
+            self.socket.ready_state(),
+            WebSocket::CONNECTING | WebSocket::OPEN
+        ) {
+            let _ = self.socket.close();
+        }
+    }
+}
+
+/// Receiving half of a connection opened with [`websocket`].
+pub struct WebSocketStream {
+    receiver: UnboundedReceiver<Incoming>,
+    _connection: Rc<Connection>,
+}
+
+impl Stream for WebSocketStream {
+    type Item = Incoming;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// Sending half of a connection opened with [`websocket`].
+///
+/// Sending waits until the connection is open and fails once it is closing or closed.
+/// Closing the sink closes the connection, which also ends the receiving half.
+pub struct WebSocketSink {
+    connection: Rc<Connection>,
+}
+
+impl Sink<WebSocketMessage> for WebSocketSink {
+    type Error = JsValue;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+        match self.connection.socket.ready_state() {
+            WebSocket::CONNECTING => {
+                *self.connection.ready_waker.borrow_mut() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            WebSocket::OPEN => Poll::Ready(Ok(())),
+            _ => Poll::Ready(Err(JsValue::from_str("websocket is closed"))),
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, message: WebSocketMessage) -> Result<(), JsValue> {
+        match message {
+            WebSocketMessage::Text(text) => self.connection.socket.send_with_str(&text),
+            WebSocketMessage::Binary(bytes) => self.connection.socket.send_with_u8_array(&bytes),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+        // The browser buffers outgoing messages itself.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+        Poll::Ready(self.connection.socket.close())
+    }
+}
+
+fn send(sender: &RefCell<Option<UnboundedSender<Incoming>>>, item: Incoming) {
+    if let Some(sender) = sender.borrow().as_ref() {
+        let _ = sender.unbounded_send(item);
+    }
+}
+
+fn wake(waker: &RefCell<Option<Waker>>) {
+    if let Some(waker) = waker.borrow_mut().take() {
+        waker.wake();
+    }
+}
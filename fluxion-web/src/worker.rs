@@ -0,0 +1,294 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::cell::{Cell, RefCell};
+use futures_channel::oneshot;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    DedicatedWorkerGlobalScope, ErrorEvent, Event, MessageEvent, Worker, WorkerOptions, WorkerType,
+};
+
+type TaskFn = Box<dyn Fn(JsValue) -> Result<JsValue, String>>;
+type Reply = Result<JsValue, WorkerFailure>;
+
+thread_local! {
+    static TASKS: RefCell<HashMap<String, TaskFn>> = RefCell::new(HashMap::new());
+}
+
+/// Why a request sent to a [`WorkerPool`] produced no result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum WorkerFailure {
+    /// The task ran and failed, or its input or output could not be converted.
+    Task(String),
+    /// The worker itself failed, for example because its script did not load.
+    Worker(String),
+}
+
+/// A fixed set of Web Workers that run tasks registered with [`register_task`].
+///
+/// The workers are module workers started from `script_url`. The script must load the
+/// same wasm module as the page, register its tasks and call [`serve`]:
+///
+/// ```js
+/// import init, { worker_main } from "./my_app.js";
+/// await init();
+/// worker_main(); // calls fluxion_web::register_task(..) and fluxion_web::serve()
+/// ```
+///
+/// Requests sent before a worker has called [`serve`] are held back and delivered once
+/// it has. Requests are spread over the workers round-robin. Cloning the pool is cheap
+/// and shares the workers, which are terminated when the last clone (including the
+/// clones held by running operators) is dropped.
+#[derive(Clone)]
+pub struct WorkerPool {
+    inner: Rc<PoolInner>,
+}
+
+struct PoolInner {
+    slots: Vec<WorkerSlot>,
+    next_slot: Cell<usize>,
+    next_id: Cell<u32>,
+}
+
+struct WorkerSlot {
+    worker: Worker,
+    state: Rc<RefCell<SlotState>>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+#[derive(Default)]
+struct SlotState {
+    ready: bool,
+    failed: Option<String>,
+    backlog: Vec<JsValue>,
+    pending: HashMap<u32, oneshot::Sender<Reply>>,
+}
+
+impl WorkerPool {
+    /// Starts `size` module workers from `script_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JavaScript exception if a worker cannot be created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn new(script_url: &str, size: usize) -> Result<Self, JsValue> {
+        assert!(size > 0, "WorkerPool requires at least one worker");
+
+        let options = WorkerOptions::new();
+        options.set_type(WorkerType::Module);
+
+        let slots = (0..size)
+            .map(|_| WorkerSlot::start(script_url, &options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            inner: Rc::new(PoolInner {
+                slots,
+                next_slot: Cell::new(0),
+                next_id: Cell::new(0),
+            }),
+        })
+    }
+
+    /// Number of workers in the pool.
+    pub fn size(&self) -> usize {
+        self.inner.slots.len()
+    }
+
+    /// Sends `payload` to the next worker to run `task` and resolves to its result.
+    pub(crate) fn request(
+        &self,
+        task: &str,
+        payload: JsValue,
+    ) -> impl core::future::Future<Output = Reply> {
+        let inner = &self.inner;
+        let slot = &inner.slots[inner.next_slot.get()];
+        inner
+            .next_slot
+            .set((inner.next_slot.get() + 1) % inner.slots.len());
+
+        let id = inner.next_id.get();
+        inner.next_id.set(id.wrapping_add(1));
+
+        let message = object(&[
+            ("id", JsValue::from(id)),
+            ("task", JsValue::from_str(task)),
+            ("payload", payload),
+        ]);
+
+        let (sender, receiver) = oneshot::channel();
+        let mut state = slot.state.borrow_mut();
+        if let Some(message) = &state.failed {
+            let _ = sender.send(Err(WorkerFailure::Worker(message.clone())));
+        } else if state.ready {
+            match slot.worker.post_message(&message) {
+                Ok(()) => {
+                    state.pending.insert(id, sender);
+                }
+                Err(error) => {
+                    let _ = sender.send(Err(WorkerFailure::Worker(describe(&error))));
+                }
+            }
+        } else {
+            state.pending.insert(id, sender);
+            state.backlog.push(message);
+        }
+
+        async move {
+            receiver.await.unwrap_or_else(|_| {
+                Err(WorkerFailure::Worker("worker pool was dropped".to_string()))
+            })
+        }
+    }
+}
+
+impl WorkerSlot {
+    fn start(script_url: &str, options: &WorkerOptions) -> Result<Self, JsValue> {
+        let worker = Worker::new_with_options(script_url, options)?;
+        let state = Rc::new(RefCell::new(SlotState::default()));
+
+        let on_message = {
+            let worker = worker.clone();
+            let state = Rc::clone(&state);
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                let data = event.data();
+                let mut state = state.borrow_mut();
+
+                if field(&data, "ready").as_bool() == Some(true) {
+                    state.ready = true;
+                    for message in core::mem::take(&mut state.backlog) {
+                        let _ = worker.post_message(&message);
+                    }
+                    return;
+                }
+
+                let Some(id) = field(&data, "id").as_f64() else {
+                    return;
+                };
+                if let Some(sender) = state.pending.remove(&(id as u32)) {
+                    let error = field(&data, "error");
+                    let reply = match error.as_string() {
+                        Some(message) => Err(WorkerFailure::Task(message)),
+                        None => Ok(field(&data, "ok")),
+                    };
+                    let _ = sender.send(reply);
+                }
+            })
+        };
+
+        let on_error = {
+            let state = Rc::clone(&state);
+            // A script that fails to load reports a plain `Event` without a message.
+            Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+                let message = event
+                    .dyn_ref::<ErrorEvent>()
+                    .map(ErrorEvent::message)
+                    .unwrap_or_else(|| "worker failed to start".to_string());
+                let mut state = state.borrow_mut();
+                state.backlog.clear();
+                for (_, sender) in state.pending.drain() {
+                    let _ = sender.send(Err(WorkerFailure::Worker(message.clone())));
+                }
+                state.failed = Some(message);
+            })
+        };
+
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            worker,
+            state,
+            _on_message: on_message,
+            _on_error: on_error,
+        })
+    }
+}
+
+impl Drop for WorkerSlot {
+    fn drop(&mut self) {
+        self.worker.set_onmessage(None);
+        self.worker.set_onerror(None);
+        self.worker.terminate();
+    }
+}
+
+/// Registers `task` under `name` in the current worker, to be run by [`serve`].
+///
+/// Inputs arrive as the serialized item value and are deserialized into `I`; the `O`
+/// returned is serialized back to the page. Registering a name twice replaces the
+/// earlier task.
+pub fn register_task<I, O, F>(name: &str, task: F)
+where
+    I: DeserializeOwned,
+    O: Serialize,
+    F: Fn(I) -> O + 'static,
+{
+    let task: TaskFn = Box::new(move |payload| {
+        let input = serde_wasm_bindgen::from_value(payload).map_err(|e| e.to_string())?;
+        serde_wasm_bindgen::to_value(&task(input)).map_err(|e| e.to_string())
+    });
+    TASKS.with(|tasks| tasks.borrow_mut().insert(name.to_string(), task));
+}
+
+/// Starts answering [`WorkerPool`] requests in the current worker.
+///
+/// Call this once from the worker's entry point, after registering tasks. Requests for
+/// a task that was never registered are answered with an error.
+///
+/// # Errors
+///
+/// Returns an error if the code is not running in a dedicated worker.
+pub fn serve() -> Result<(), JsValue> {
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().dyn_into()?;
+
+    let reply_scope = scope.clone();
+    let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let data = event.data();
+        let id = field(&data, "id");
+        let task = field(&data, "task").as_string().unwrap_or_default();
+
+        let result = TASKS.with(|tasks| match tasks.borrow().get(&task) {
+            Some(run) => run(field(&data, "payload")),
+            None => Err(format!("no worker task registered as '{task}'")),
+        });
+
+        let reply = match result {
+            Ok(value) => object(&[("id", id), ("ok", value)]),
+            Err(message) => object(&[("id", id), ("error", JsValue::from_str(&message))]),
+        };
+        let _ = reply_scope.post_message(&reply);
+    });
+
+    scope.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    // The handler lives as long as the worker.
+    on_message.forget();
+
+    scope.post_message(&object(&[("ready", JsValue::TRUE)]))
+}
+
+fn object(fields: &[(&str, JsValue)]) -> JsValue {
+    let object = js_sys::Object::new();
+    for (key, value) in fields {
+        let _ = js_sys::Reflect::set(&object, &JsValue::from_str(key), value);
+    }
+    object.into()
+}
+
+fn field(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED)
+}
+
+fn describe(error: &JsValue) -> String {
+    error.as_string().unwrap_or_else(|| format!("{error:?}"))
+}
@@ -0,0 +1,69 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Browser tests. Run with `wasm-pack test --headless --chrome`.
+
+#![cfg(target_arch = "wasm32")]
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_web::{SampleOnAnimationFrameExt, WebEvent, WebTimestamp};
+use futures::channel::mpsc::unbounded;
+use futures::{stream, StreamExt};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn event(value: u32, micros: u64) -> StreamItem<WebEvent<u32>> {
+    StreamItem::Value(WebEvent::new(value, WebTimestamp::from_micros(micros)))
+}
+
+#[wasm_bindgen_test]
+async fn test_sample_on_animation_frame_coalesces_values_into_one_frame() {
+    // Arrange
+    let source = stream::iter(vec![event(1, 10), event(2, 20), event(3, 30)]);
+
+    // Act
+    let frames: Vec<_> = source.sample_on_animation_frame().collect().await;
+
+    // Assert
+    assert_eq!(frames, vec![event(3, 30)]);
+}
+
+#[wasm_bindgen_test]
+async fn test_sample_on_animation_frame_emits_values_from_separate_frames() {
+    // Arrange
+    let (tx, rx) = unbounded();
+    let mut frames = rx.sample_on_animation_frame();
+
+    // Act & Assert
+    tx.unbounded_send(event(1, 10)).unwrap();
+    assert_eq!(frames.next().await, Some(event(1, 10)));
+
+    tx.unbounded_send(event(2, 20)).unwrap();
+    assert_eq!(frames.next().await, Some(event(2, 20)));
+
+    drop(tx);
+    assert_eq!(frames.next().await, None);
+}
+
+#[wasm_bindgen_test]
+async fn test_sample_on_animation_frame_passes_errors_through_immediately() {
+    // Arrange
+    let source = stream::iter(vec![
+        event(1, 10),
+        StreamItem::Error(FluxionError::stream_error("sensor offline")),
+        event(2, 20),
+    ]);
+
+    // Act
+    let frames: Vec<_> = source.sample_on_animation_frame().collect().await;
+
+    // Assert
+    assert_eq!(frames.len(), 2);
+    assert!(matches!(
+        &frames[0],
+        StreamItem::Error(FluxionError::StreamProcessingError { context }) if context == "sensor offline"
+    ));
+    assert_eq!(frames[1], event(2, 20));
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Browser tests. Run with `wasm-pack test --headless --chrome`.
+
+#![cfg(target_arch = "wasm32")]
+
+use fluxion_core::StreamItem;
+use fluxion_web::{clicks, events, inputs, Click};
+use futures::StreamExt;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Event, HtmlInputElement};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn document() -> web_sys::Document {
+    web_sys::window().unwrap().document().unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_clicks_emits_dispatched_clicks_in_order() {
+    // Arrange
+    let button = document().create_element("button").unwrap();
+    let mut stream = clicks(&button).unwrap();
+
+    // Act
+    button
+        .dispatch_event(&Event::new("click").unwrap())
+        .unwrap();
+    button
+        .dispatch_event(&Event::new("click").unwrap())
+        .unwrap();
+
+    // Assert
+    let StreamItem::Value(first) = stream.next().await.unwrap() else {
+        panic!("expected a value");
+    };
+    let StreamItem::Value(second) = stream.next().await.unwrap() else {
+        panic!("expected a value");
+    };
+    assert_eq!(
+        first.value,
+        Click {
+            x: 0,
+            y: 0,
+            button: 0
+        }
+    );
+    assert!(first.timestamp <= second.timestamp);
+}
+
+#[wasm_bindgen_test]
+async fn test_inputs_reads_value_at_dispatch() {
+    // Arrange
+    let input: HtmlInputElement = document()
+        .create_element("input")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+    let mut stream = inputs(&input).unwrap();
+
+    // Act
+    input.set_value("flux");
+    input.dispatch_event(&Event::new("input").unwrap()).unwrap();
+    input.set_value("fluxion");
+
+    // Assert
+    let StreamItem::Value(event) = stream.next().await.unwrap() else {
+        panic!("expected a value");
+    };
+    assert_eq!(event.value, "flux");
+}
+
+#[wasm_bindgen_test]
+async fn test_dropping_stream_removes_listener() {
+    // Arrange
+    let element = document().create_element("div").unwrap();
+    let calls = std::rc::Rc::new(core::cell::Cell::new(0));
+    let counter = calls.clone();
+    let stream = events(&element, "custom", move |_| counter.set(counter.get() + 1)).unwrap();
+
+    // Act
+    element
+        .dispatch_event(&Event::new("custom").unwrap())
+        .unwrap();
+    drop(stream);
+    element
+        .dispatch_event(&Event::new("custom").unwrap())
+        .unwrap();
+
+    // Assert
+    assert_eq!(calls.get(), 1);
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Browser tests. Run with `wasm-pack test --headless --chrome`.
+
+#![cfg(target_arch = "wasm32")]
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_web::{fetch_chunks, fetch_lines, websocket, WebSocketMessage};
+use futures::{SinkExt, StreamExt};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn test_fetch_chunks_emits_whole_body() {
+    // Arrange
+    let chunks = fetch_chunks("data:application/octet-stream,abc%00def");
+
+    // Act
+    let items: Vec<_> = chunks.collect().await;
+
+    // Assert
+    let body: Vec<u8> = items
+        .into_iter()
+        .flat_map(|item| item.unwrap().value)
+        .collect();
+    assert_eq!(body, b"abc\0def");
+}
+
+#[wasm_bindgen_test]
+async fn test_fetch_lines_splits_body_into_lines() {
+    // Arrange
+    let lines = fetch_lines("data:text/plain,first%0D%0Asecond%0A%0Alast");
+
+    // Act
+    let items: Vec<_> = lines.collect().await;
+
+    // Assert
+    let values: Vec<String> = items.into_iter().map(|item| item.unwrap().value).collect();
+    assert_eq!(values, vec!["first", "second", "", "last"]);
+}
+
+#[wasm_bindgen_test]
+async fn test_fetch_lines_emits_error_for_failed_status() {
+    // Arrange
+    let lines = fetch_lines("/fluxion-web-missing-resource");
+
+    // Act
+    let items: Vec<_> = lines.collect().await;
+
+    // Assert
+    assert_eq!(items.len(), 1);
+    assert!(matches!(
+        &items[0],
+        StreamItem::Error(FluxionError::StreamProcessingError { context }) if context.contains("404")
+    ));
+}
+
+#[wasm_bindgen_test]
+fn test_websocket_rejects_invalid_url() {
+    // Act
+    let result = websocket("not a url");
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_websocket_failing_to_connect_emits_error_and_ends() {
+    // Arrange
+    let (mut outgoing, incoming) = websocket("ws://127.0.0.1:9").unwrap();
+
+    // Act
+    let items: Vec<_> = incoming.collect().await;
+    let send = outgoing.send(WebSocketMessage::Text("hello".into())).await;
+
+    // Assert
+    assert_eq!(items.len(), 1);
+    assert!(matches!(
+        &items[0],
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    ));
+    assert!(send.is_err());
+}
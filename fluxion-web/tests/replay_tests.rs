@@ -0,0 +1,106 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Browser tests. Run with `wasm-pack test --headless --chrome`.
+
+#![cfg(target_arch = "wasm32")]
+
+use core::time::Duration;
+use fluxion_core::StreamItem;
+use fluxion_web::{PersistExt, ReplayStore, WebEvent, WebTimestamp};
+use futures::{stream, StreamExt};
+use js_sys::{Function, Promise};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const HOUR: Duration = Duration::from_secs(3600);
+
+fn event(value: u32, micros: u64) -> StreamItem<WebEvent<u32>> {
+    StreamItem::Value(WebEvent::new(value, WebTimestamp::from_micros(micros)))
+}
+
+async fn open(name: &str, max_items: usize, max_age: Duration) -> ReplayStore {
+    let store = ReplayStore::open(name, max_items, max_age).await.unwrap();
+    store.clear().await.unwrap();
+    store
+}
+
+/// Lets background writes and evictions finish.
+async fn settle() {
+    let promise = Promise::new(&mut |resolve: Function, _: Function| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 50)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_persist_passes_items_through_and_stores_values_in_order() {
+    // Arrange
+    let store = open("replay-order", 10, HOUR).await;
+    let source = stream::iter(vec![event(1, 10), event(2, 20), event(3, 30)]);
+
+    // Act
+    let emitted: Vec<StreamItem<WebEvent<u32>>> = source.persist(&store).collect().await;
+    settle().await;
+
+    // Assert
+    assert_eq!(emitted, vec![event(1, 10), event(2, 20), event(3, 30)]);
+    assert_eq!(store.history::<u32>().await.unwrap(), vec![1, 2, 3]);
+}
+
+#[wasm_bindgen_test]
+async fn test_persist_evicts_oldest_values_beyond_max_items() {
+    // Arrange
+    let store = open("replay-size", 2, HOUR).await;
+    let source = stream::iter(vec![event(1, 10), event(2, 20), event(3, 30)]);
+
+    // Act
+    let _: Vec<_> = source.persist(&store).collect().await;
+    settle().await;
+
+    // Assert
+    assert_eq!(store.history::<u32>().await.unwrap(), vec![2, 3]);
+}
+
+#[wasm_bindgen_test]
+async fn test_history_skips_values_older_than_max_age() {
+    // Arrange
+    let store = open("replay-age", 10, Duration::ZERO).await;
+    let source = stream::iter(vec![event(1, 10)]);
+
+    // Act
+    let _: Vec<_> = source.persist(&store).collect().await;
+    settle().await;
+
+    // Assert
+    assert!(store.history::<u32>().await.unwrap().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn test_replay_emits_history_before_live_items() {
+    // Arrange
+    let store = open("replay-live", 10, HOUR).await;
+    let _: Vec<_> = stream::iter(vec![event(1, 10), event(2, 20)])
+        .persist(&store)
+        .collect()
+        .await;
+    settle().await;
+    let live = stream::iter(vec![event(3, 500)]);
+
+    // Act
+    let replayed: Vec<StreamItem<WebEvent<u32>>> = store
+        .replay(live, |value| {
+            WebEvent::new(value, WebTimestamp::from_micros(100))
+        })
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(replayed, vec![event(1, 100), event(2, 100), event(3, 500)]);
+}
@@ -0,0 +1,59 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use core::time::Duration;
+use fluxion_core::{HasTimestamp, Timestamped};
+use fluxion_web::{WebEvent, WebTimestamp};
+
+#[test]
+fn test_web_timestamp_keeps_sub_millisecond_precision() {
+    // Arrange
+    let earlier = WebTimestamp::from_millis_f64(1.25);
+    let later = WebTimestamp::from_millis_f64(1.5);
+
+    // Act
+    let elapsed = later - earlier;
+
+    // Assert
+    assert!(earlier < later);
+    assert_eq!(elapsed, Duration::from_micros(250));
+}
+
+#[test]
+fn test_web_timestamp_duration_arithmetic() {
+    // Arrange
+    let timestamp = WebTimestamp::from_micros(1_000);
+
+    // Act
+    let forward = timestamp + Duration::from_millis(2);
+    let backward = timestamp - Duration::from_millis(2);
+
+    // Assert
+    assert_eq!(forward.as_micros(), 3_000);
+    assert_eq!(backward.as_micros(), 0);
+}
+
+#[test]
+fn test_web_event_orders_by_timestamp() {
+    // Arrange
+    let first = WebEvent::new("z", WebTimestamp::from_micros(10));
+    let second = WebEvent::new("a", WebTimestamp::from_micros(20));
+
+    // Act
+    let mut events = vec![second.clone(), first.clone()];
+    events.sort();
+
+    // Assert
+    assert_eq!(events, vec![first, second]);
+}
+
+#[test]
+fn test_web_event_is_timestamped() {
+    // Arrange
+    let event = WebEvent::<String>::with_timestamp("query".into(), WebTimestamp::from_micros(5));
+
+    // Act & Assert
+    assert_eq!(event.timestamp(), WebTimestamp::from_micros(5));
+    assert_eq!(event.into_inner(), "query");
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Browser tests. Run with `wasm-pack test --headless --chrome`.
+
+#![cfg(target_arch = "wasm32")]
+
+use fluxion_core::{FluxionError, StreamItem};
+use fluxion_web::{serve, MapInWorkerExt, WebEvent, WebTimestamp, WorkerPool};
+use futures::{stream, StreamExt};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn event(value: u32, micros: u64) -> StreamItem<WebEvent<u32>> {
+    StreamItem::Value(WebEvent::new(value, WebTimestamp::from_micros(micros)))
+}
+
+#[wasm_bindgen_test]
+async fn test_map_in_worker_reports_a_worker_that_fails_to_start() {
+    // Arrange
+    let pool = WorkerPool::new("./does-not-exist.js", 1).unwrap();
+    let source = stream::iter(vec![event(1, 10), event(2, 20)]);
+
+    // Act
+    let results: Vec<StreamItem<WebEvent<u32>>> = source
+        .map_in_worker::<WebEvent<u32>>(&pool, "double")
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|item| matches!(
+        item,
+        StreamItem::Error(FluxionError::StreamProcessingError { .. })
+    )));
+}
+
+#[wasm_bindgen_test]
+async fn test_map_in_worker_passes_source_errors_through() {
+    // Arrange
+    let pool = WorkerPool::new("./does-not-exist.js", 2).unwrap();
+    let source = stream::iter(vec![StreamItem::<WebEvent<u32>>::Error(
+        FluxionError::stream_error("upstream"),
+    )]);
+
+    // Act
+    let results: Vec<StreamItem<WebEvent<u32>>> = source
+        .map_in_worker::<WebEvent<u32>>(&pool, "double")
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        &results[0],
+        StreamItem::Error(FluxionError::StreamProcessingError { context }) if context == "upstream"
+    ));
+}
+
+#[wasm_bindgen_test]
+fn test_serve_outside_a_worker_is_an_error() {
+    // Act & Assert
+    assert!(serve().is_err());
+}
@@ -87,9 +87,9 @@
 // fluxion_error - Error types and handling
 
 pub use fluxion_core::into_stream::IntoStream;
-pub use fluxion_core::{HasTimestamp, Timestamped};
+pub use fluxion_core::{HasTimestamp, SelfTimestamped, Timestamped};
 pub use fluxion_exec;
-pub use fluxion_stream::{CombinedState, WithPrevious};
+pub use fluxion_stream::{CombinedState, Indexed, WithPrevious};
 
 /// Prelude module for convenient imports.
 ///
@@ -102,13 +102,13 @@ pub use fluxion_stream::{CombinedState, WithPrevious};
 /// // - Extension traits via fluxion_stream::prelude
 /// // - Timestamped trait
 /// // - IntoStream trait
-/// // - CombinedState, WithPrevious
+/// // - CombinedState, Indexed, WithPrevious
 /// ```
 ///
 /// This is the recommended way to use Fluxion in most applications.
 pub mod prelude {
     pub use fluxion_core::into_stream::IntoStream;
-    pub use fluxion_core::{HasTimestamp, Timestamped};
+    pub use fluxion_core::{HasTimestamp, SelfTimestamped, Timestamped};
     pub use fluxion_stream::prelude::*;
-    pub use fluxion_stream::{CombinedState, WithPrevious};
+    pub use fluxion_stream::{CombinedState, Indexed, WithPrevious};
 }
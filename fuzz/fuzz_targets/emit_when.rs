@@ -0,0 +1,28 @@
+// Copyright 2025 Umberto Gotti <umberto.gotti@umbertogotti.dev>
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+#![no_main]
+
+use fluxion_stream::EmitWhenExt;
+use fluxion_test_utils::fuzz::{run_scenario, Scenario};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let scenario = Scenario::from_bytes(data, 2);
+    let trace = run_scenario(&scenario, |mut streams| {
+        let filter = streams.pop().unwrap();
+        let source = streams.pop().unwrap();
+        source.emit_when(filter, |_| true)
+    });
+
+    for check in [
+        trace.check_ordered(),
+        trace.check_no_loss_after_warmup(),
+        trace.check_errors_preserved(),
+    ] {
+        if let Err(violation) = check {
+            panic!("{violation}\n{scenario:?}");
+        }
+    }
+});